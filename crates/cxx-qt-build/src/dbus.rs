@@ -0,0 +1,833 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Generation of typed Rust proxies from D-Bus introspection XML, similar to qdbusxml2cpp.
+
+use convert_case::{Case, Casing};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use std::path::Path;
+
+/// An element of an XML document, the text content is not needed for D-Bus introspection data
+struct XmlElement {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<XmlElement>,
+}
+
+impl XmlElement {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a XmlElement> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+}
+
+/// A minimal XML parser which supports the subset of XML used by D-Bus introspection data
+struct XmlParser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> XmlParser<'a> {
+    fn parse(input: &'a str) -> Result<XmlElement, String> {
+        let mut parser = Self { input, pos: 0 };
+        parser.skip_misc()?;
+        let root = parser.parse_element()?;
+        parser.skip_misc()?;
+        if parser.pos < parser.input.len() {
+            return Err(parser.error("unexpected content after the root element"));
+        }
+        Ok(root)
+    }
+
+    fn error(&self, message: &str) -> String {
+        let line = self.input[..self.pos].matches('\n').count() + 1;
+        format!("{message} on line {line}")
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn skip_past(&mut self, terminator: &str) -> Result<(), String> {
+        match self.rest().find(terminator) {
+            Some(index) => {
+                self.pos += index + terminator.len();
+                Ok(())
+            }
+            None => Err(self.error(&format!("missing {terminator}"))),
+        }
+    }
+
+    /// Skip text, comments, processing instructions and the document type declaration
+    fn skip_misc(&mut self) -> Result<(), String> {
+        loop {
+            match self.rest().find('<') {
+                Some(index) => self.pos += index,
+                None => {
+                    self.pos = self.input.len();
+                    return Ok(());
+                }
+            }
+
+            let rest = self.rest();
+            if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with("<!") {
+                self.skip_past(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn parse_name(&mut self) -> Result<String, String> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '=' | '>' | '/'))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error("expected a name"));
+        }
+        self.pos += len;
+        Ok(rest[..len].to_owned())
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), String> {
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected {token}")))
+        }
+    }
+
+    fn parse_element(&mut self) -> Result<XmlElement, String> {
+        self.expect("<")?;
+        let name = self.parse_name()?;
+        let mut attributes = vec![];
+
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.pos += 2;
+                return Ok(XmlElement {
+                    name,
+                    attributes,
+                    children: vec![],
+                });
+            }
+            if self.rest().starts_with('>') {
+                self.pos += 1;
+                break;
+            }
+
+            let key = self.parse_name()?;
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            let quote = match self.rest().chars().next() {
+                Some(quote @ ('"' | '\'')) => quote,
+                _ => return Err(self.error("expected a quoted attribute value")),
+            };
+            self.pos += 1;
+            let len = self
+                .rest()
+                .find(quote)
+                .ok_or_else(|| self.error("unterminated attribute value"))?;
+            let value = unescape(&self.rest()[..len]);
+            self.pos += len + 1;
+            attributes.push((key, value));
+        }
+
+        let mut children = vec![];
+        loop {
+            self.skip_misc()?;
+            if self.rest().starts_with("</") {
+                self.pos += 2;
+                let end_name = self.parse_name()?;
+                if end_name != name {
+                    return Err(self.error(&format!("expected </{name}> but found </{end_name}>")));
+                }
+                self.skip_whitespace();
+                self.expect(">")?;
+                return Ok(XmlElement {
+                    name,
+                    attributes,
+                    children,
+                });
+            }
+            if self.rest().is_empty() {
+                return Err(self.error(&format!("missing </{name}>")));
+            }
+            children.push(self.parse_element()?);
+        }
+    }
+}
+
+fn unescape(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The Rust type used for a D-Bus type signature
+///
+/// Types which do not have a direct equivalent, such as object paths, structs and maps,
+/// are passed as a QVariant so that they can be converted by the caller.
+enum DBusType {
+    Value(TokenStream),
+    Variant,
+}
+
+impl DBusType {
+    fn from_signature(signature: &str) -> Self {
+        let ty = match signature {
+            "b" => quote! { bool },
+            "y" => quote! { u8 },
+            "n" => quote! { i16 },
+            "q" => quote! { u16 },
+            "i" => quote! { i32 },
+            "u" => quote! { u32 },
+            "x" => quote! { i64 },
+            "t" => quote! { u64 },
+            "d" => quote! { f64 },
+            "s" => quote! { ::cxx_qt_lib::QString },
+            "as" => quote! { ::cxx_qt_lib::QStringList },
+            "ay" => quote! { ::cxx_qt_lib::QByteArray },
+            _ => return Self::Variant,
+        };
+        Self::Value(ty)
+    }
+
+    fn is_copy(signature: &str) -> bool {
+        matches!(
+            signature,
+            "b" | "y" | "n" | "q" | "i" | "u" | "x" | "t" | "d"
+        )
+    }
+
+    fn owned(&self) -> TokenStream {
+        match self {
+            Self::Value(ty) => ty.clone(),
+            Self::Variant => quote! { ::cxx_qt_lib::QVariant },
+        }
+    }
+
+    /// Read the value at the given index of a QList<QVariant> named dbus_values
+    fn read(&self, index: isize) -> TokenStream {
+        match self {
+            Self::Value(ty) => {
+                quote! { dbus_values.get(#index).and_then(|value| value.value::<#ty>()) }
+            }
+            Self::Variant => quote! { dbus_values.get(#index).cloned() },
+        }
+    }
+}
+
+struct DBusArgument {
+    ident: Ident,
+    signature: String,
+}
+
+impl DBusArgument {
+    fn parse(element: &XmlElement, index: usize) -> Result<Self, String> {
+        let signature = element
+            .attribute("type")
+            .ok_or("an <arg> is missing the type attribute")?
+            .to_owned();
+        let ident = match element.attribute("name") {
+            Some(name) if !name.is_empty() => rust_ident(name),
+            _ => format_ident!("arg{index}"),
+        };
+        Ok(Self { ident, signature })
+    }
+
+    fn ty(&self) -> DBusType {
+        DBusType::from_signature(&self.signature)
+    }
+}
+
+/// Create a snake case Rust identifier from a D-Bus name, avoiding Rust keywords
+fn rust_ident(name: &str) -> Ident {
+    const KEYWORDS: [&str; 38] = [
+        "as", "async", "await", "box", "break", "const", "continue", "crate", "dyn", "else",
+        "enum", "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod",
+        "move", "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait",
+        "true", "type", "unsafe", "use", "where", "while",
+    ];
+    let name = name.to_case(Case::Snake);
+    if KEYWORDS.contains(&name.as_str()) {
+        format_ident!("{name}_")
+    } else {
+        Ident::new(&name, Span::call_site())
+    }
+}
+
+/// The functions of each proxy which are not generated from the D-Bus members
+const PROXY_FUNCTIONS: [&str; 2] = ["new", "as_qdbusinterface"];
+
+/// Create the identifier of the function for a D-Bus method,
+/// adding a call_ prefix if it would collide with one of the PROXY_FUNCTIONS
+fn method_ident(name: &str) -> Ident {
+    let ident = rust_ident(name);
+    if PROXY_FUNCTIONS.contains(&ident.to_string().as_str()) {
+        format_ident!("call_{ident}")
+    } else {
+        ident
+    }
+}
+
+fn generate_method(element: &XmlElement) -> Result<(Ident, TokenStream), String> {
+    let name = element
+        .attribute("name")
+        .ok_or("a <method> is missing the name attribute")?;
+    let ident = method_ident(name);
+
+    let mut inputs = vec![];
+    let mut outputs = vec![];
+    for (index, arg) in element.children_named("arg").enumerate() {
+        let argument = DBusArgument::parse(arg, index)?;
+        if arg.attribute("direction") == Some("out") {
+            outputs.push(argument);
+        } else {
+            inputs.push(argument);
+        }
+    }
+
+    let parameters = inputs.iter().map(|argument| {
+        let ident = &argument.ident;
+        let ty = argument.ty().owned();
+        if DBusType::is_copy(&argument.signature) {
+            quote! { #ident: #ty }
+        } else {
+            quote! { #ident: &#ty }
+        }
+    });
+    let appends = inputs.iter().map(|argument| {
+        let ident = &argument.ident;
+        match argument.ty() {
+            DBusType::Value(_) if DBusType::is_copy(&argument.signature) => {
+                quote! { dbus_arguments.append(::cxx_qt_lib::QVariant::from(&#ident)); }
+            }
+            DBusType::Value(_) => {
+                quote! { dbus_arguments.append(::cxx_qt_lib::QVariant::from(#ident)); }
+            }
+            DBusType::Variant => quote! { dbus_arguments.append_clone(#ident); },
+        }
+    });
+
+    let output_types = outputs.iter().map(|argument| argument.ty().owned());
+    let return_type = if outputs.len() == 1 {
+        quote! { #(#output_types)* }
+    } else {
+        quote! { (#(#output_types),*) }
+    };
+    let output_idents: Vec<_> = outputs.iter().map(|argument| &argument.ident).collect();
+    let reads = outputs.iter().enumerate().map(|(index, argument)| {
+        let ident = &argument.ident;
+        let read = argument.ty().read(index as isize);
+        quote! {
+            let #ident = #read.ok_or_else(|| {
+                ::cxx_qt_lib::QString::from(concat!("Unexpected type of the ", stringify!(#ident), " argument of the reply"))
+            })?;
+        }
+    });
+    let result = if outputs.len() == 1 {
+        quote! { #(#output_idents)* }
+    } else {
+        quote! { (#(#output_idents),*) }
+    };
+
+    let doc = format!(
+        " Call the `{name}` method, returning the error message of the reply if the call failed"
+    );
+    let tokens = quote! {
+        #[doc = #doc]
+        #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+        pub fn #ident(&mut self, #(#parameters),*) -> ::core::result::Result<#return_type, ::cxx_qt_lib::QString> {
+            #[allow(unused_mut)]
+            let mut dbus_arguments = ::cxx_qt_lib::QList::<::cxx_qt_lib::QVariant>::default();
+            #(#appends)*
+            let dbus_reply = self
+                .interface
+                .pin_mut()
+                .call(&::cxx_qt_lib::QString::from(#name), &dbus_arguments);
+            if dbus_reply.message_type() == ::cxx_qt_lib_extras::QDBusMessageMessageType::ErrorMessage {
+                return Err(dbus_reply.error_message());
+            }
+            #[allow(unused_variables)]
+            let dbus_values = dbus_reply.arguments();
+            #(#reads)*
+            Ok(#result)
+        }
+    };
+    Ok((ident, tokens))
+}
+
+fn generate_signal(element: &XmlElement) -> Result<(Ident, TokenStream), String> {
+    let name = element
+        .attribute("name")
+        .ok_or("a <signal> is missing the name attribute")?;
+    let ident = format_ident!("on_{}", rust_ident(name).to_string().trim_end_matches('_'));
+
+    let arguments = element
+        .children_named("arg")
+        .enumerate()
+        .map(|(index, arg)| DBusArgument::parse(arg, index))
+        .collect::<Result<Vec<_>, _>>()?;
+    let types = arguments.iter().map(|argument| argument.ty().owned());
+    let idents: Vec<_> = arguments.iter().map(|argument| &argument.ident).collect();
+    let reads = arguments.iter().enumerate().map(|(index, argument)| {
+        let ident = &argument.ident;
+        let read = argument.ty().read(index as isize);
+        quote! {
+            let Some(#ident) = #read else {
+                return;
+            };
+        }
+    });
+
+    let doc = format!(" Connect a closure which is called with the arguments of each emission of the `{name}` signal");
+    let tokens = quote! {
+        #[doc = #doc]
+        ///
+        /// Emissions with unexpected argument types are ignored.
+        /// Returns true if the connection was successful, otherwise false.
+        pub fn #ident<F>(&mut self, mut closure: F) -> bool
+        where
+            F: FnMut(#(#types),*) + 'static,
+        {
+            self.interface.pin_mut().on_signal(
+                &::cxx_qt_lib::QString::from(#name),
+                move |dbus_message| {
+                    #[allow(unused_variables)]
+                    let dbus_values = dbus_message.arguments();
+                    #(#reads)*
+                    closure(#(#idents),*)
+                },
+            )
+        }
+    };
+    Ok((ident, tokens))
+}
+
+fn generate_interface(element: &XmlElement) -> Result<TokenStream, String> {
+    let name = element
+        .attribute("name")
+        .ok_or("an <interface> is missing the name attribute")?;
+    let proxy_ident = format_ident!("{}Proxy", name.replace('.', "_").to_case(Case::Pascal));
+
+    let methods = element
+        .children_named("method")
+        .map(generate_method)
+        .collect::<Result<Vec<_>, _>>()?;
+    let signals = element
+        .children_named("signal")
+        .map(generate_signal)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Different D-Bus names can have the same snake case name, such as GetValue and get_value
+    let mut idents: Vec<&Ident> = vec![];
+    for (ident, _) in methods.iter().chain(signals.iter()) {
+        if idents.contains(&ident) {
+            return Err(format!(
+                "multiple members of the {name} interface generate the function {ident}"
+            ));
+        }
+        idents.push(ident);
+    }
+    let methods = methods.iter().map(|(_, tokens)| tokens);
+    let signals = signals.iter().map(|(_, tokens)| tokens);
+
+    let doc = format!(" Proxy for the `{name}` D-Bus interface of a remote object");
+    Ok(quote! {
+        #[doc = #doc]
+        pub struct #proxy_ident {
+            interface: ::cxx::UniquePtr<::cxx_qt_lib_extras::QDBusInterface>,
+        }
+
+        impl #proxy_ident {
+            /// The name of the D-Bus interface
+            pub const INTERFACE: &'static str = #name;
+
+            /// Create a proxy for the object at path on the given service
+            pub fn new(
+                service: &::cxx_qt_lib::QString,
+                path: &::cxx_qt_lib::QString,
+                connection: &::cxx_qt_lib_extras::QDBusConnection,
+            ) -> Self {
+                Self {
+                    interface: ::cxx_qt_lib_extras::QDBusInterface::new(
+                        service,
+                        path,
+                        &::cxx_qt_lib::QString::from(Self::INTERFACE),
+                        connection,
+                    ),
+                }
+            }
+
+            /// The QDBusInterface which is used by the proxy, for example to check if it is valid
+            pub fn as_qdbusinterface(&self) -> &::cxx_qt_lib_extras::QDBusInterface {
+                &self.interface
+            }
+
+            #(#methods)*
+
+            #(#signals)*
+        }
+    })
+}
+
+/// Generate the Rust proxies for the interfaces in the given D-Bus introspection XML
+fn generate_proxies(xml: &str) -> Result<TokenStream, String> {
+    let root = XmlParser::parse(xml)?;
+    if root.name != "node" {
+        return Err(format!(
+            "expected a <node> as the root element but found <{}>",
+            root.name
+        ));
+    }
+
+    let interfaces = root
+        .children_named("interface")
+        .map(generate_interface)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(quote! { #(#interfaces)* })
+}
+
+/// Generate the Rust proxies for the D-Bus introspection XML at xml_path and write them to output_path
+pub(crate) fn write_proxies(xml_path: &Path, output_path: &Path) {
+    let xml = std::fs::read_to_string(xml_path).unwrap_or_else(|err| {
+        panic!(
+            "Failed to read D-Bus introspection XML {}: {err}",
+            xml_path.display()
+        )
+    });
+    let tokens = generate_proxies(&xml).unwrap_or_else(|err| {
+        panic!(
+            "Failed to generate D-Bus proxies from {}: {err}",
+            xml_path.display()
+        )
+    });
+
+    if let Some(parent) = output_path.parent() {
+        std::fs::create_dir_all(parent).expect("Failed to create D-Bus proxy directory!");
+    }
+    std::fs::write(output_path, tokens.to_string()).expect("Failed to write D-Bus proxy file!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_element(xml: &str) -> XmlElement {
+        XmlParser::parse(xml).unwrap()
+    }
+
+    fn assert_contains(tokens: &TokenStream, expected: TokenStream) {
+        let tokens = tokens.to_string();
+        let expected = expected.to_string();
+        assert!(
+            tokens.contains(&expected),
+            "{expected} was not found in {tokens}"
+        );
+    }
+
+    #[test]
+    fn test_parse_comments_and_doctype() {
+        let root = parse_element(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE node PUBLIC "-//freedesktop//DTD D-BUS Object Introspection 1.0//EN"
+ "http://www.freedesktop.org/standards/dbus/1.0/introspect.dtd">
+<!-- A comment before the root element -->
+<node>
+  <!-- A comment with <elements/> inside -->
+  <interface name="org.example.Test">
+    <method name="Ping"/>
+    <!-- <method name="Disabled"/> -->
+  </interface>
+</node>
+<!-- A comment after the root element -->
+"#,
+        );
+        assert_eq!(root.name, "node");
+        assert_eq!(root.children.len(), 1);
+
+        let interface = &root.children[0];
+        assert_eq!(interface.name, "interface");
+        assert_eq!(interface.attribute("name"), Some("org.example.Test"));
+        assert_eq!(interface.children_named("method").count(), 1);
+        assert_eq!(interface.children[0].attribute("name"), Some("Ping"));
+    }
+
+    #[test]
+    fn test_parse_unescape() {
+        let root = parse_element(
+            r#"<node name="&lt;a&gt; &amp; &quot;b&quot; &apos;c&apos; &amp;lt;" other='"d"'/>"#,
+        );
+        assert_eq!(root.attribute("name"), Some(r#"<a> & "b" 'c' &lt;"#));
+        assert_eq!(root.attribute("other"), Some(r#""d""#));
+        assert_eq!(root.attribute("missing"), None);
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        let error = |xml: &str| XmlParser::parse(xml).err().unwrap();
+
+        assert_eq!(
+            error("<node><interface></node>"),
+            "expected </interface> but found </node> on line 1"
+        );
+        assert_eq!(error("<node>\n<interface/>\n"), "missing </node> on line 3");
+        assert_eq!(
+            error(r#"<node name="test></node>"#),
+            "unterminated attribute value on line 1"
+        );
+        assert_eq!(
+            error("<node\nname=test/>"),
+            "expected a quoted attribute value on line 2"
+        );
+        assert_eq!(error("<node name/>"), "expected = on line 1");
+        assert_eq!(error("< node/>"), "expected a name on line 1");
+        assert_eq!(
+            error("<node/><node/>"),
+            "unexpected content after the root element on line 1"
+        );
+        assert_eq!(error("<!-- <node/>"), "missing --> on line 1");
+
+        assert_eq!(
+            generate_proxies("<interface/>").err().unwrap(),
+            "expected a <node> as the root element but found <interface>"
+        );
+        assert_eq!(
+            generate_proxies("<node><interface><method/></interface></node>")
+                .err()
+                .unwrap(),
+            "an <interface> is missing the name attribute"
+        );
+    }
+
+    #[test]
+    fn test_generate_method_in_out() {
+        let (ident, tokens) = generate_method(&parse_element(
+            r#"<method name="GetValues">
+                <arg name="key" type="s" direction="in"/>
+                <arg name="count" type="i"/>
+                <arg name="value" type="s" direction="out"/>
+                <arg name="found" type="b" direction="out"/>
+            </method>"#,
+        ))
+        .unwrap();
+        assert_eq!(ident, "get_values");
+        assert_contains(
+            &tokens,
+            quote! {
+                pub fn get_values(&mut self, key: &::cxx_qt_lib::QString, count: i32)
+                    -> ::core::result::Result<(::cxx_qt_lib::QString, bool), ::cxx_qt_lib::QString>
+            },
+        );
+        assert_contains(
+            &tokens,
+            quote! {
+                dbus_arguments.append(::cxx_qt_lib::QVariant::from(key));
+                dbus_arguments.append(::cxx_qt_lib::QVariant::from(&count));
+            },
+        );
+        assert_contains(
+            &tokens,
+            quote! { .call(&::cxx_qt_lib::QString::from("GetValues"), &dbus_arguments) },
+        );
+        assert_contains(
+            &tokens,
+            quote! { let value = dbus_values.get(0isize).and_then(|value| value.value::<::cxx_qt_lib::QString>()) },
+        );
+        assert_contains(
+            &tokens,
+            quote! { let found = dbus_values.get(1isize).and_then(|value| value.value::<bool>()) },
+        );
+        assert_contains(&tokens, quote! { Ok((value, found)) });
+    }
+
+    #[test]
+    fn test_generate_method_outputs() {
+        let (_, tokens) = generate_method(&parse_element(
+            r#"<method name="Version"><arg name="version" type="u" direction="out"/></method>"#,
+        ))
+        .unwrap();
+        assert_contains(
+            &tokens,
+            quote! {
+                pub fn version(&mut self,) -> ::core::result::Result<u32, ::cxx_qt_lib::QString>
+            },
+        );
+        assert_contains(&tokens, quote! { Ok(version) });
+
+        let (_, tokens) = generate_method(&parse_element(r#"<method name="Quit"/>"#)).unwrap();
+        assert_contains(
+            &tokens,
+            quote! {
+                pub fn quit(&mut self,) -> ::core::result::Result<(), ::cxx_qt_lib::QString>
+            },
+        );
+        assert_contains(&tokens, quote! { Ok(()) });
+
+        let (_, tokens) = generate_method(&parse_element(
+            r#"<method name="Lookup">
+                <arg name="path" type="o" direction="out"/>
+                <arg name="names" type="as" direction="out"/>
+                <arg name="data" type="ay" direction="out"/>
+            </method>"#,
+        ))
+        .unwrap();
+        assert_contains(
+            &tokens,
+            quote! {
+                ::core::result::Result<
+                    (::cxx_qt_lib::QVariant, ::cxx_qt_lib::QStringList, ::cxx_qt_lib::QByteArray),
+                    ::cxx_qt_lib::QString
+                >
+            },
+        );
+        assert_contains(
+            &tokens,
+            quote! { let path = dbus_values.get(0isize).cloned() },
+        );
+        assert_contains(&tokens, quote! { Ok((path, names, data)) });
+    }
+
+    #[test]
+    fn test_generate_unnamed_arguments() {
+        let (_, tokens) = generate_method(&parse_element(
+            r#"<method name="Add">
+                <arg type="i"/>
+                <arg name="" type="i"/>
+                <arg type="i" direction="out"/>
+            </method>"#,
+        ))
+        .unwrap();
+        assert_contains(
+            &tokens,
+            quote! {
+                pub fn add(&mut self, arg0: i32, arg1: i32)
+                    -> ::core::result::Result<i32, ::cxx_qt_lib::QString>
+            },
+        );
+        assert_contains(&tokens, quote! { Ok(arg2) });
+
+        let (ident, tokens) = generate_signal(&parse_element(
+            r#"<signal name="ValueChanged"><arg type="s"/><arg type="a{sv}"/></signal>"#,
+        ))
+        .unwrap();
+        assert_eq!(ident, "on_value_changed");
+        assert_contains(
+            &tokens,
+            quote! { F: FnMut(::cxx_qt_lib::QString, ::cxx_qt_lib::QVariant) + 'static, },
+        );
+        assert_contains(
+            &tokens,
+            quote! { &::cxx_qt_lib::QString::from("ValueChanged") },
+        );
+        assert_contains(&tokens, quote! { closure(arg0, arg1) });
+
+        assert_eq!(
+            generate_method(&parse_element(
+                r#"<method name="Add"><arg name="a"/></method>"#
+            ))
+            .err()
+            .unwrap(),
+            "an <arg> is missing the type attribute"
+        );
+    }
+
+    #[test]
+    fn test_generate_keywords() {
+        assert_eq!(rust_ident("GetValue"), "get_value");
+        assert_eq!(rust_ident("type"), "type_");
+        assert_eq!(rust_ident("Move"), "move_");
+        assert_eq!(rust_ident("Self"), "self_");
+
+        let (ident, tokens) = generate_method(&parse_element(
+            r#"<method name="Type"><arg name="in" type="v"/><arg name="ref" type="v" direction="out"/></method>"#,
+        ))
+        .unwrap();
+        assert_eq!(ident, "type_");
+        assert_contains(
+            &tokens,
+            quote! {
+                pub fn type_(&mut self, in_: &::cxx_qt_lib::QVariant)
+                    -> ::core::result::Result<::cxx_qt_lib::QVariant, ::cxx_qt_lib::QString>
+            },
+        );
+        assert_contains(&tokens, quote! { dbus_arguments.append_clone(in_); });
+        assert_contains(&tokens, quote! { Ok(ref_) });
+
+        let (ident, _) = generate_signal(&parse_element(r#"<signal name="Loop"/>"#)).unwrap();
+        assert_eq!(ident, "on_loop");
+    }
+
+    #[test]
+    fn test_generate_proxy_function_collisions() {
+        let tokens = generate_proxies(
+            r#"<node><interface name="org.example.Test">
+                <method name="New"/>
+                <method name="as_qdbusinterface"/>
+            </interface></node>"#,
+        )
+        .unwrap();
+        assert_contains(&tokens, quote! { pub struct OrgExampleTestProxy });
+        assert_contains(
+            &tokens,
+            quote! { pub fn call_new(&mut self,) -> ::core::result::Result<(), ::cxx_qt_lib::QString> },
+        );
+        assert_contains(
+            &tokens,
+            quote! { .call(&::cxx_qt_lib::QString::from("New"), &dbus_arguments) },
+        );
+        assert_contains(
+            &tokens,
+            quote! { pub fn call_as_qdbusinterface(&mut self,) },
+        );
+
+        assert_eq!(
+            generate_proxies(
+                r#"<node><interface name="org.example.Test">
+                    <method name="GetValue"/>
+                    <method name="get_value"/>
+                </interface></node>"#,
+            )
+            .err()
+            .unwrap(),
+            "multiple members of the org.example.Test interface generate the function get_value"
+        );
+        assert_eq!(
+            generate_proxies(
+                r#"<node><interface name="org.example.Test">
+                    <method name="New"/>
+                    <method name="CallNew"/>
+                </interface></node>"#,
+            )
+            .err()
+            .unwrap(),
+            "multiple members of the org.example.Test interface generate the function call_new"
+        );
+    }
+}
@@ -13,6 +13,8 @@
 
 mod cfg_evaluator;
 
+mod dbus;
+
 mod diagnostics;
 use diagnostics::{Diagnostic, GeneratedError};
 
@@ -30,6 +32,9 @@ mod qml_modules;
 use qml_modules::OwningQmlModule;
 pub use qml_modules::QmlModule;
 
+mod report;
+use report::BuildReport;
+
 pub use qt_build_utils::MocArguments;
 use qt_build_utils::SemVer;
 use quote::ToTokens;
@@ -39,11 +44,13 @@ use std::{
     fs::File,
     io::Write,
     path::{Path, PathBuf},
+    time::{Duration, Instant},
 };
 
+use cxx_qt::hotreload::{self, HotReloadSource};
 use cxx_qt_gen::{
-    parse_qt_file, write_cpp, write_rust, CppFragment, CxxQtItem, GeneratedCppBlocks,
-    GeneratedRustBlocks, Parser,
+    parse_qt_file, write_cpp, write_rep, write_rust, CppFragment, CxxQtItem, GeneratedCppBlocks,
+    GeneratedRepBlocks, GeneratedRustBlocks, Parser,
 };
 
 // TODO: we need to eventually support having multiple modules defined in a single file. This
@@ -58,12 +65,18 @@ struct GeneratedCppFilePaths {
     plain_cpp: PathBuf,
     qobject: Option<PathBuf>,
     qobject_header: Option<PathBuf>,
+    rust_file: PathBuf,
+    qobjects: Vec<String>,
+    generation_time: Duration,
 }
 
 struct GeneratedCpp {
     cxx_qt: Option<CppFragment>,
+    rep: Option<String>,
     cxx: cxx_gen::GeneratedCode,
     file_ident: String,
+    rust_file: PathBuf,
+    qobjects: Vec<String>,
 }
 
 impl GeneratedCpp {
@@ -72,6 +85,7 @@ impl GeneratedCpp {
         rust_file_path: impl AsRef<Path>,
         relative_path: impl AsRef<Path>,
         include_prefix: &str,
+        remote_objects_rep: bool,
     ) -> Result<Self, Diagnostic> {
         let to_diagnostic = |err| Diagnostic::new(rust_file_path.as_ref().to_owned(), err);
 
@@ -82,6 +96,8 @@ impl GeneratedCpp {
             .map_err(to_diagnostic)?;
 
         let mut cxx_qt = None;
+        let mut rep = None;
+        let mut qobjects = vec![];
         let mut tokens = proc_macro2::TokenStream::new();
 
         // Add any attributes in the file into the tokenstream
@@ -128,7 +144,13 @@ impl GeneratedCpp {
                     }
                     found_bridge = true;
 
-                    let parser = Parser::from(m.clone())
+                    let mut parser = Parser::from(m.clone())
+                        .map_err(GeneratedError::from)
+                        .map_err(to_diagnostic)?;
+                    // Remove any items which are disabled by a #[cfg] for the current build,
+                    // the Rust generated by the macro keeps them for the compiler to evaluate
+                    parser
+                        .evaluate_cfgs(&cfg_evaluator::CargoEnvCfgEvaluator)
                         .map_err(GeneratedError::from)
                         .map_err(to_diagnostic)?;
                     let generated_cpp = GeneratedCppBlocks::from(&parser)
@@ -138,6 +160,21 @@ impl GeneratedCpp {
                         .map_err(GeneratedError::from)
                         .map_err(to_diagnostic)?;
 
+                    qobjects = generated_cpp
+                        .qobjects
+                        .iter()
+                        .map(|qobject| qobject.name.cxx_qualified())
+                        .collect();
+
+                    if remote_objects_rep {
+                        let generated_rep = GeneratedRepBlocks::from(&parser)
+                            .map_err(GeneratedError::from)
+                            .map_err(to_diagnostic)?;
+                        if !generated_rep.classes.is_empty() {
+                            rep = Some(write_rep(&generated_rep));
+                        }
+                    }
+
                     // TODO: we'll have to extend the C++ data here rather than overwriting
                     // assuming we share the same file
                     cxx_qt = Some(write_cpp(&generated_cpp, &include_ident));
@@ -161,8 +198,11 @@ impl GeneratedCpp {
 
         Ok(GeneratedCpp {
             cxx_qt,
+            rep,
             cxx,
             file_ident,
+            rust_file: relative_path.as_ref().to_path_buf(),
+            qobjects,
         })
     }
 
@@ -179,6 +219,9 @@ impl GeneratedCpp {
             plain_cpp: PathBuf::new(),
             qobject: None,
             qobject_header: None,
+            rust_file: self.rust_file,
+            qobjects: self.qobjects,
+            generation_time: Duration::ZERO,
         };
         if let Some(cxx_qt_generated) = &self.cxx_qt {
             let header_path = PathBuf::from(format!(
@@ -222,6 +265,20 @@ impl GeneratedCpp {
             cpp_file_paths.qobject = Some(cpp_path);
         }
 
+        if let Some(rep) = &self.rep {
+            // Write the .rep file next to the headers, so that it is exported for C++ builds
+            let rep_path = PathBuf::from(format!(
+                "{}/{}.rep",
+                header_directory.display(),
+                self.file_ident
+            ));
+            if let Some(directory) = rep_path.parent() {
+                std::fs::create_dir_all(directory)
+                    .expect("Could not create directory to write cxx-qt generated files");
+            }
+            std::fs::write(&rep_path, rep).expect("Could not write cxx-qt rep file");
+        }
+
         let header_path = PathBuf::from(format!(
             "{}/{}.cxx.h",
             header_directory.display(),
@@ -259,6 +316,7 @@ fn generate_cxxqt_cpp_files(
     rs_source: &[impl AsRef<Path>],
     header_dir: impl AsRef<Path>,
     include_prefix: &str,
+    remote_objects_rep: bool,
 ) -> Vec<GeneratedCppFilePaths> {
     let cxx_qt_dir = dir::out().join("cxx-qt-gen");
     std::fs::create_dir_all(&cxx_qt_dir).expect("Failed to create cxx-qt-gen directory!");
@@ -272,14 +330,18 @@ fn generate_cxxqt_cpp_files(
         let path = manifest_dir.join(rs_path);
         println!("cargo:rerun-if-changed={}", path.to_string_lossy());
 
-        let generated_code = match GeneratedCpp::new(&path, rs_path, include_prefix) {
-            Ok(v) => v,
-            Err(diagnostic) => {
-                diagnostic.report();
-                std::process::exit(1);
-            }
-        };
-        generated_file_paths.push(generated_code.write_to_directories(&cxx_qt_dir, &header_dir));
+        let start = Instant::now();
+        let generated_code =
+            match GeneratedCpp::new(&path, rs_path, include_prefix, remote_objects_rep) {
+                Ok(v) => v,
+                Err(diagnostic) => {
+                    diagnostic.report();
+                    std::process::exit(1);
+                }
+            };
+        let mut file_paths = generated_code.write_to_directories(&cxx_qt_dir, &header_dir);
+        file_paths.generation_time = start.elapsed();
+        generated_file_paths.push(file_paths);
     }
 
     generated_file_paths
@@ -348,12 +410,16 @@ pub struct CxxQtBuilder {
     rust_sources: Vec<PathBuf>,
     qobject_headers: Vec<QObjectHeaderOpts>,
     qrc_files: Vec<PathBuf>,
+    dbus_proxies: Vec<PathBuf>,
     qt_modules: HashSet<String>,
     qml_modules: Vec<OwningQmlModule>,
     cc_builder: cc::Build,
     public_interface: Option<Interface>,
     include_prefix: String,
     initializers: Vec<String>,
+    qml_hot_reload: bool,
+    remote_objects_rep: bool,
+    report: Option<BuildReport>,
 }
 
 impl CxxQtBuilder {
@@ -365,12 +431,16 @@ impl CxxQtBuilder {
             rust_sources: vec![],
             qobject_headers: vec![],
             qrc_files: vec![],
+            dbus_proxies: vec![],
             qt_modules,
             qml_modules: vec![],
             cc_builder: cc::Build::new(),
             initializers: vec![],
             public_interface: None,
             include_prefix: crate_name(),
+            qml_hot_reload: false,
+            remote_objects_rep: false,
+            report: None,
         }
     }
 
@@ -428,6 +498,39 @@ impl CxxQtBuilder {
         self
     }
 
+    /// Generate typed Rust proxies for the interfaces in a
+    /// [D-Bus introspection XML](https://dbus.freedesktop.org/doc/dbus-specification.html#introspection-format) file,
+    /// similar to what [qdbusxml2cpp](https://doc.qt.io/qt-6/qdbusxml2cpp.html) generates for C++.
+    ///
+    /// For each interface a struct, such as `OrgFreedesktopNotificationsProxy`, is generated
+    /// with a method for each D-Bus method and an `on_` method to connect a closure to each D-Bus signal.
+    /// The names are converted to snake case, with a `call_` prefix for D-Bus methods which would collide with
+    /// the `new` and `as_qdbusinterface` methods of the proxy.
+    /// The proxies are written to the `cxx-qt-build/dbus` folder of the `OUT_DIR` with the same name as the XML file,
+    /// so that they can be included into the crate.
+    ///
+    /// ```no_run
+    /// # use cxx_qt_build::CxxQtBuilder;
+    /// CxxQtBuilder::new()
+    ///     .file("src/cxxqt_module.rs")
+    ///     .dbus_proxy("dbus/org.freedesktop.Notifications.xml")
+    ///     .build();
+    /// ```
+    ///
+    /// ```ignore
+    /// include!(concat!(env!("OUT_DIR"), "/cxx-qt-build/dbus/org.freedesktop.Notifications.rs"));
+    /// ```
+    ///
+    /// The generated code uses `QDBusInterface` from cxx-qt-lib-extras,
+    /// so the crate needs to depend on cxx-qt-lib-extras with the `qt_dbus` feature enabled.
+    /// D-Bus types which do not map to a Rust or Qt type, such as structs and dictionaries, are passed as a `QVariant`.
+    pub fn dbus_proxy(mut self, xml_file: impl AsRef<Path>) -> Self {
+        let xml_file = xml_file.as_ref();
+        self.dbus_proxies.push(xml_file.to_path_buf());
+        println!("cargo:rerun-if-changed={}", xml_file.display());
+        self
+    }
+
     /// Link additional [Qt modules](https://doc.qt.io/qt-6/qtmodules.html).
     /// Specify their names without the `Qt` prefix, for example `"Widgets"`.
     /// The `Core` module and any modules from dependencies are linked automatically; there is no need to specify them.
@@ -489,6 +592,79 @@ impl CxxQtBuilder {
         self
     }
 
+    /// Load the QML and JavaScript files of the QML modules from their source directory in debug builds.
+    ///
+    /// This is intended for development, so that changes to QML files do not require rebuilding the application.
+    /// In debug builds the prefix of each QML module in the Qt resources, the crate directory and the directories
+    /// containing the `qml_files` of the modules are written to the `CXX_QT_QML_HOT_RELOAD` environment variable of the crate,
+    /// which can be passed to `QQmlApplicationEngine::enable_hot_reload` in cxx-qt-lib.
+    /// Release builds always load the QML from the Qt resources.
+    ///
+    /// ```no_run
+    /// use cxx_qt_build::{CxxQtBuilder, QmlModule};
+    ///
+    /// CxxQtBuilder::new()
+    ///     .qml_module(QmlModule {
+    ///         uri: "com.kdab.cxx_qt.demo",
+    ///         rust_files: &["src/cxxqt_object.rs"],
+    ///         qml_files: &["qml/main.qml"],
+    ///         ..Default::default()
+    ///     })
+    ///     .qml_hot_reload()
+    ///     .build();
+    /// ```
+    pub fn qml_hot_reload(mut self) -> Self {
+        self.qml_hot_reload = true;
+        self
+    }
+
+    /// Write a Qt Remote Objects `.rep` file for each bridge, describing the properties, signals,
+    /// and invokables of its QObjects.
+    ///
+    /// The `.rep` file is written next to the generated header of the bridge, for example
+    /// `my_crate/src/cxxqt_object.rep` in the include directory, so it is also available
+    /// in the directory given by `CXX_QT_EXPORT_DIR`. A C++ application in another process can compile it
+    /// with `repc`, for example with `qt_add_repc_replicas` in CMake, to acquire the QObjects as static replicas
+    /// once they have been enabled for remoting with `QRemoteObjectHost::enable_remoting` in cxx-qt-lib-extras.
+    ///
+    /// ```no_run
+    /// # use cxx_qt_build::CxxQtBuilder;
+    /// CxxQtBuilder::new()
+    ///     .file("src/cxxqt_object.rs")
+    ///     .remote_objects_rep()
+    ///     .build();
+    /// ```
+    ///
+    /// Properties with a custom `NOTIFY` signal cannot be described in a `.rep` file and cause an error.
+    /// Inherited signals, signals with `#[private_signal]`, and methods which are not `#[qinvokable]`, are not part of the `.rep` file.
+    pub fn remote_objects_rep(mut self) -> Self {
+        self.remote_objects_rep = true;
+        self
+    }
+
+    /// Write a report of the generated files and the time spent in each step of the build.
+    ///
+    /// This is intended to help large projects find which bridges are slowing down the build.
+    /// The report is written as JSON to `build-report.json` in the crate's target directory,
+    /// the path is printed as a cargo warning when the build finishes.
+    ///
+    /// For each bridge it lists the QObjects declared, the time taken to generate the C++ code,
+    /// and the size of each generated file. The header that caused each moc file is also listed.
+    ///
+    /// ```no_run
+    /// # use cxx_qt_build::CxxQtBuilder;
+    /// CxxQtBuilder::new()
+    ///     .file("src/cxxqt_module.rs")
+    ///     .build_report()
+    ///     .build();
+    /// ```
+    ///
+    /// Note that the C++ compiler builds all files in parallel, so only the total compile time is reported.
+    pub fn build_report(mut self) -> Self {
+        self.report = Some(BuildReport::default());
+        self
+    }
+
     /// Specify a C++ header containing a Q_OBJECT macro to run [moc](https://doc.qt.io/qt-6/moc.html) on.
     /// This allows building QObject C++ subclasses besides the ones autogenerated by cxx-qt.
     pub fn qobject_header(mut self, opts: impl Into<QObjectHeaderOpts>) -> Self {
@@ -669,6 +845,17 @@ impl CxxQtBuilder {
         }
     }
 
+    fn generate_dbus_proxies(&self) {
+        let proxy_dir = dir::out().join("cxx-qt-build").join("dbus");
+        for xml_file in &self.dbus_proxies {
+            let file_stem = xml_file
+                .file_stem()
+                .expect("D-Bus introspection XML path must have a file name");
+            let output_path = proxy_dir.join(format!("{}.rs", file_stem.to_string_lossy()));
+            dbus::write_proxies(xml_file, &output_path);
+        }
+    }
+
     fn moc_qobject_headers(&mut self, qtbuild: &mut qt_build_utils::QtBuild) {
         for QObjectHeaderOpts {
             path,
@@ -676,6 +863,9 @@ impl CxxQtBuilder {
         } in &self.qobject_headers
         {
             let moc_products = qtbuild.moc(path, moc_arguments.clone());
+            if let Some(report) = &mut self.report {
+                report.add_moc(path, &moc_products.cpp);
+            }
             // Include the moc folder
             if let Some(dir) = moc_products.cpp.parent() {
                 self.cc_builder.include(dir);
@@ -689,7 +879,15 @@ impl CxxQtBuilder {
         header_dir: impl AsRef<Path>,
         include_prefix: &str,
     ) {
-        for files in generate_cxxqt_cpp_files(&self.rust_sources, &header_dir, include_prefix) {
+        for files in generate_cxxqt_cpp_files(
+            &self.rust_sources,
+            &header_dir,
+            include_prefix,
+            self.remote_objects_rep,
+        ) {
+            if let Some(report) = &mut self.report {
+                report.add_bridge(&files);
+            }
             self.cc_builder.file(files.plain_cpp);
             if let (Some(qobject), Some(qobject_header)) = (files.qobject, files.qobject_header) {
                 self.cc_builder.file(&qobject);
@@ -787,7 +985,11 @@ impl CxxQtBuilder {
                 &qml_module.rust_files,
                 &generated_header_dir,
                 header_prefix,
+                self.remote_objects_rep,
             ) {
+                if let Some(report) = &mut self.report {
+                    report.add_bridge(&files);
+                }
                 cc_builder.file(files.plain_cpp);
                 if let (Some(qobject), Some(qobject_header)) = (files.qobject, files.qobject_header)
                 {
@@ -799,9 +1001,12 @@ impl CxxQtBuilder {
 
                     cc_builder.file(&qobject);
                     let moc_products = qtbuild.moc(
-                        qobject_header,
+                        &qobject_header,
                         MocArguments::default().uri(qml_module.uri.clone()),
                     );
+                    if let Some(report) = &mut self.report {
+                        report.add_moc(&qobject_header, &moc_products.cpp);
+                    }
                     // Include the moc folder
                     if let Some(dir) = moc_products.cpp.parent() {
                         moc_include_paths.insert(dir.to_path_buf());
@@ -875,6 +1080,60 @@ impl CxxQtBuilder {
         }
     }
 
+    fn write_qml_hot_reload_sources(&self) {
+        if !self.qml_hot_reload || env::var("PROFILE").as_deref() != Ok("debug") {
+            return;
+        }
+
+        let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+        // The sources are separated by semicolons, so they cannot be parsed from such a path
+        if !hotreload::is_valid_directory(&manifest_dir) {
+            println!("cargo:warning=CxxQtBuilder::qml_hot_reload is not supported for the crate directory {manifest_dir} as it contains a semicolon.");
+            return;
+        }
+
+        // The files of a QML module are stored at the same path relative to the crate directory in the Qt resources
+        let prefixes: Vec<String> = self
+            .qml_modules
+            .iter()
+            .map(|qml_module| format!("qrc:/qt/qml/{}/", qml_module.uri.replace('.', "/")))
+            .collect();
+
+        // Only the directories containing the QML files are watched for changes,
+        // rather than the whole crate directory which also contains the target directory
+        let mut directories: Vec<String> = vec![];
+        for qml_file in self
+            .qml_modules
+            .iter()
+            .flat_map(|qml_module| &qml_module.qml_files)
+        {
+            let path = Path::new(&manifest_dir).join(qml_file);
+            if let Some(directory) = path.parent() {
+                let directory = directory.to_string_lossy().into_owned();
+                if !hotreload::is_valid_directory(&directory) {
+                    println!("cargo:warning=CxxQtBuilder::qml_hot_reload cannot watch the directory {directory} as it contains a semicolon.");
+                } else if !directories.contains(&directory) {
+                    directories.push(directory);
+                }
+            }
+        }
+
+        let sources = hotreload::write_sources(
+            prefixes
+                .iter()
+                .map(|prefix| HotReloadSource::Prefix {
+                    prefix,
+                    directory: &manifest_dir,
+                })
+                .chain(
+                    directories
+                        .iter()
+                        .map(|directory| HotReloadSource::Watch(directory)),
+                ),
+        );
+        println!("cargo:rustc-env={}={sources}", hotreload::ENV_VAR);
+    }
+
     fn setup_qt5_compatibility(&mut self, qtbuild: &qt_build_utils::QtBuild) {
         // If we are using Qt 5 then write the std_types source
         // This registers std numbers as a type for use in QML
@@ -985,6 +1244,12 @@ impl CxxQtBuilder {
         qt_modules
     }
 
+    fn report_step(&mut self, name: &str, start: Instant) {
+        if let Some(report) = &mut self.report {
+            report.add_step(name, start.elapsed());
+        }
+    }
+
     fn write_interface_include_dirs(&self) {
         if let Some(interface) = &self.public_interface {
             for (header_dir, symlink) in &interface.exported_include_directories {
@@ -1055,28 +1320,43 @@ impl CxxQtBuilder {
         let init_builder = init_builder;
 
         // Generate files
+        let start = Instant::now();
         self.generate_cpp_files_from_cxxqt_bridges(&header_root, &self.include_prefix.clone());
+        self.report_step("generate", start);
 
+        let start = Instant::now();
+        self.generate_dbus_proxies();
+        self.report_step("dbus", start);
+
+        let start = Instant::now();
         self.moc_qobject_headers(&mut qtbuild);
+        self.report_step("moc", start);
 
         // Bridges for QML modules are handled separately because
         // the metatypes_json generated by moc needs to be passed to qmltyperegistrar
+        let start = Instant::now();
         self.build_qml_modules(
             &init_builder,
             &mut qtbuild,
             &header_root,
             &self.include_prefix.clone(),
         );
+        self.report_step("qml_modules", start);
+        self.write_qml_hot_reload_sources();
 
+        let start = Instant::now();
         let mut initializers = self.generate_cpp_from_qrc_files(&mut qtbuild);
         initializers.extend(dependencies::initializer_paths(
             self.public_interface.as_ref(),
             &dependencies,
         ));
+        self.report_step("qrc", start);
 
         self.setup_qt5_compatibility(&qtbuild);
 
+        let start = Instant::now();
         self.build_initializers(&init_builder, &initializers);
+        self.report_step("initializers", start);
 
         // Only compile if we have added files to the builder
         // otherwise we end up with no static library but ask cargo to link to it which causes an error
@@ -1089,9 +1369,15 @@ impl CxxQtBuilder {
                 println!("cargo::rustc-link-arg=-l{}", static_lib_name());
             }
 
+            let start = Instant::now();
             self.cc_builder.compile(&static_lib_name());
+            self.report_step("compile", start);
         }
 
         self.write_manifest(&dependencies, qt_modules, initializers);
+
+        if let Some(report) = &self.report {
+            report.write(dir::crate_target().join("build-report.json"));
+        }
     }
 }
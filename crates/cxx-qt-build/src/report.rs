@@ -0,0 +1,109 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use serde::Serialize;
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::GeneratedCppFilePaths;
+
+/// A file which was generated during the build, with its size on disk
+#[derive(Serialize)]
+struct GeneratedFileReport {
+    path: PathBuf,
+    size_bytes: u64,
+}
+
+impl GeneratedFileReport {
+    fn new(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref().to_path_buf();
+        // The file may not exist if generation failed, in which case the build panics later anyway
+        let size_bytes = std::fs::metadata(&path)
+            .map(|metadata| metadata.len())
+            .unwrap_or_default();
+        Self { path, size_bytes }
+    }
+}
+
+/// The files which were generated from a single bridge
+#[derive(Serialize)]
+struct BridgeReport {
+    rust_file: PathBuf,
+    /// The QObjects defined in the bridge, these cause the .cxxqt.cpp and moc translation units
+    qobjects: Vec<String>,
+    generation_time_ms: u128,
+    generated_files: Vec<GeneratedFileReport>,
+}
+
+/// The file generated by moc for a given header
+#[derive(Serialize)]
+struct MocReport {
+    header: PathBuf,
+    generated_file: GeneratedFileReport,
+}
+
+/// The time spent in a given step of the build
+#[derive(Serialize)]
+struct StepReport {
+    name: String,
+    time_ms: u128,
+}
+
+/// A report of the generated files and time spent while building a crate with CxxQtBuilder
+#[derive(Default, Serialize)]
+pub(crate) struct BuildReport {
+    bridges: Vec<BridgeReport>,
+    moc: Vec<MocReport>,
+    steps: Vec<StepReport>,
+}
+
+impl BuildReport {
+    /// Record the files generated from a bridge
+    pub(crate) fn add_bridge(&mut self, files: &GeneratedCppFilePaths) {
+        let generated_files = [&files.qobject_header, &files.qobject]
+            .into_iter()
+            .flatten()
+            .chain(std::iter::once(&files.plain_cpp))
+            .map(GeneratedFileReport::new)
+            .collect();
+
+        self.bridges.push(BridgeReport {
+            rust_file: files.rust_file.clone(),
+            qobjects: files.qobjects.clone(),
+            generation_time_ms: files.generation_time.as_millis(),
+            generated_files,
+        });
+    }
+
+    /// Record the file generated by moc for a header
+    pub(crate) fn add_moc(&mut self, header: impl AsRef<Path>, cpp: impl AsRef<Path>) {
+        self.moc.push(MocReport {
+            header: header.as_ref().to_path_buf(),
+            generated_file: GeneratedFileReport::new(cpp),
+        });
+    }
+
+    /// Record the time spent in a step of the build
+    pub(crate) fn add_step(&mut self, name: &str, duration: Duration) {
+        self.steps.push(StepReport {
+            name: name.to_owned(),
+            time_ms: duration.as_millis(),
+        });
+    }
+
+    /// Write the report as JSON to the given path
+    pub(crate) fn write(&self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        let report_json =
+            serde_json::to_string_pretty(self).expect("Failed to convert BuildReport to JSON!");
+        std::fs::write(path, report_json).expect("Failed to write build report!");
+        println!(
+            "cargo:warning=CXX-Qt build report written to {}",
+            path.to_string_lossy()
+        );
+    }
+}
@@ -4,16 +4,25 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::{
-    generator::cpp::signal::generate_cpp_signal, naming::TypeNames,
-    parser::externcxxqt::ParsedExternCxxQt, CppFragment,
+    generator::{
+        cpp::signal::generate_cpp_signal,
+        naming::property::{QPropertyExternHelperNames, QPropertyExternNames},
+    },
+    naming::{cpp::syn_type_to_cpp_type, TypeNames},
+    parser::{externcxxqt::ParsedExternCxxQt, externqobject::ParsedExternQObject},
+    writer::cpp::namespaced,
+    CppFragment,
 };
+use indoc::formatdoc;
 use std::collections::BTreeSet;
-use syn::Result;
+use syn::{Path, Result};
 
 #[derive(Default)]
 pub struct GeneratedCppExternCxxQtBlocks {
     /// List of includes
     pub includes: BTreeSet<String>,
+    /// List of bridges whose generated headers need to be included
+    pub aliased_bridges: Vec<AliasedBridge>,
     /// List of forward declares before the class and include of the generated CXX header
     pub forward_declares: Vec<String>,
     /// List of fragments
@@ -37,17 +46,206 @@ pub fn generate(
             debug_assert!(data.methods.is_empty());
             out.push(block);
         }
+
+        // QObjects from another bridge are forward declared, so that bridges can refer to
+        // each other, and the header of the other bridge is included for the full class definition
+        for (qobject, alias) in block
+            .qobjects
+            .iter()
+            .filter_map(|qobject| Some((qobject, qobject.alias.as_ref()?)))
+        {
+            let mut block = GeneratedCppExternCxxQtBlocks::default();
+            block
+                .aliased_bridges
+                .extend(AliasedBridge::from_alias(alias));
+            block.forward_declares.push(namespaced(
+                qobject.name.namespace().unwrap_or_default(),
+                &format!("class {};", qobject.name.cxx_unqualified()),
+            ));
+            out.push(block);
+        }
+
+        for qobject in block
+            .qobjects
+            .iter()
+            .filter(|qobject| !qobject.properties.is_empty())
+        {
+            out.push(generate_properties(
+                qobject,
+                Some(&block.helper_namespace),
+                type_names,
+            )?);
+        }
     }
 
     Ok(out)
 }
 
+/// The location of the bridge which generates a QObject that is aliased from another bridge
+#[derive(Debug, PartialEq, Eq)]
+pub struct AliasedBridge {
+    /// How many modules the path goes up from the file of this bridge,
+    /// or [None] if the path starts at the crate root
+    pub supers: Option<usize>,
+    /// The modules which lead from there to the file of the other bridge
+    pub modules: Vec<String>,
+}
+
+impl AliasedBridge {
+    /// Find the bridge of an alias like `crate::other::qobject::OtherObject`
+    ///
+    /// The last two segments are the bridge module and the QObject, any segments before them
+    /// lead to the file of the other bridge. Paths into other crates are not resolved,
+    /// as the include prefix of another crate is not known.
+    fn from_alias(alias: &Path) -> Option<Self> {
+        let segments = alias
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<_>>();
+        if segments.len() < 3 {
+            return None;
+        }
+
+        let modules = &segments[..segments.len() - 2];
+        match modules[0].as_str() {
+            "crate" => Some(Self {
+                supers: None,
+                modules: modules[1..].to_vec(),
+            }),
+            "super" => {
+                // The first super leaves the bridge module into the module of its file
+                let supers = modules
+                    .iter()
+                    .take_while(|module| *module == "super")
+                    .count();
+                Some(Self {
+                    supers: Some(supers - 1),
+                    modules: modules[supers..].to_vec(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// The include of the generated header of the other bridge, if it is not this bridge
+    ///
+    /// The include path of this bridge is the include prefix followed by the path of its file,
+    /// eg `my_crate/src/module/file`, the other bridge is found relative to the `src` folder.
+    pub fn include(&self, include_path: &str) -> Option<String> {
+        let segments = include_path.split('/').collect::<Vec<_>>();
+        // If there is no src folder, assume that the file of this bridge is next to the crate root
+        let root_len = segments
+            .iter()
+            .skip(1)
+            .position(|segment| *segment == "src")
+            .map_or(segments.len() - 1, |index| index + 2);
+        let (root, file) = segments.split_at(root_len);
+
+        let mut module = file.to_vec();
+        if matches!(module.last(), Some(&"lib" | &"main" | &"mod")) {
+            module.pop();
+        }
+        let mut module = match self.supers {
+            Some(supers) => {
+                for _ in 0..supers {
+                    module.pop()?;
+                }
+                module
+            }
+            None => vec![],
+        };
+        module.extend(self.modules.iter().map(String::as_str));
+        if module.is_empty() {
+            // The crate root is either a library or a binary
+            module.push(if file == ["main"] { "main" } else { "lib" });
+        }
+
+        let path = [root, &module].concat().join("/");
+        (path != include_path).then(|| format!("#include \"{path}.cxxqt.h\""))
+    }
+}
+
+/// Generate free functions which call the accessors of the existing properties,
+/// so that the return and parameter types of the accessors do not need to match exactly
+fn generate_properties(
+    qobject: &ParsedExternQObject,
+    helper_namespace: Option<&str>,
+    type_names: &TypeNames,
+) -> Result<GeneratedCppExternCxxQtBlocks> {
+    let mut generated = GeneratedCppExternCxxQtBlocks::default();
+    let qobject_ident_namespaced = qobject.name.cxx_qualified();
+
+    for property in &qobject.properties {
+        let idents = QPropertyExternNames::from(property);
+        let helper_idents =
+            QPropertyExternHelperNames::new(&idents, &qobject.name, helper_namespace);
+        let namespace = &helper_idents.namespace;
+        let cxx_ty = syn_type_to_cpp_type(&property.ty, type_names)?;
+
+        let getter_helper = helper_idents.getter.cxx_unqualified();
+        let getter = idents.getter.cxx_unqualified();
+        generated.fragments.push(CppFragment::Pair {
+            header: formatdoc! {
+                r#"
+                namespace {namespace} {{
+                {cxx_ty}
+                {getter_helper}({qobject_ident_namespaced} const& self);
+                }} // namespace {namespace}
+                "#
+            },
+            source: formatdoc! {
+                r#"
+                namespace {namespace} {{
+                {cxx_ty}
+                {getter_helper}({qobject_ident_namespaced} const& self)
+                {{
+                    return self.{getter}();
+                }}
+                }} // namespace {namespace}
+                "#
+            },
+        });
+
+        if let (Some(setter), Some(setter_helper)) = (&idents.setter, &helper_idents.setter) {
+            let setter = setter.cxx_unqualified();
+            let setter_helper = setter_helper.cxx_unqualified();
+            generated.fragments.push(CppFragment::Pair {
+                header: formatdoc! {
+                    r#"
+                    namespace {namespace} {{
+                    void
+                    {setter_helper}({qobject_ident_namespaced}& self, {cxx_ty} value);
+                    }} // namespace {namespace}
+                    "#
+                },
+                source: formatdoc! {
+                    r#"
+                    namespace {namespace} {{
+                    void
+                    {setter_helper}({qobject_ident_namespaced}& self, {cxx_ty} value)
+                    {{
+                        self.{setter}(::std::move(value));
+                    }}
+                    }} // namespace {namespace}
+                    "#
+                },
+            });
+        }
+    }
+
+    Ok(generated)
+}
+
 #[cfg(test)]
 mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
     use quote::format_ident;
     use syn::parse_quote;
 
     use super::*;
+    use crate::generator::cpp::property::tests::require_pair;
 
     #[test]
     fn test_generate_cpp_extern_qt() {
@@ -76,6 +274,83 @@ mod tests {
         assert_eq!(generated.len(), 2);
     }
 
+    #[test]
+    fn test_generate_cpp_extern_qt_properties() {
+        let blocks = vec![ParsedExternCxxQt::parse(
+            parse_quote! {
+                #[helper_namespace = "my_crate"]
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    #[qproperty(i32, interval)]
+                    #[qproperty(bool, active, READ = isActive)]
+                    type QTimer;
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        )
+        .unwrap()];
+        let mut type_names = TypeNames::default();
+        type_names.mock_insert("QTimer", None, None, None);
+
+        let generated = generate(&blocks, &type_names).unwrap();
+        // The notify signal of interval, and the accessors of both properties
+        assert_eq!(generated.len(), 2);
+
+        let fragments = &generated[1].fragments;
+        assert_eq!(fragments.len(), 3);
+
+        let (header, source) = require_pair(&fragments[0]).unwrap();
+        assert_str_eq!(
+            header,
+            indoc! {r#"
+                namespace rust::cxxqtgen1::my_crate {
+                ::std::int32_t
+                QTimer_intervalGetter(QTimer const& self);
+                } // namespace rust::cxxqtgen1::my_crate
+            "#}
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+                namespace rust::cxxqtgen1::my_crate {
+                ::std::int32_t
+                QTimer_intervalGetter(QTimer const& self)
+                {
+                    return self.interval();
+                }
+                } // namespace rust::cxxqtgen1::my_crate
+            "#}
+        );
+
+        let (header, source) = require_pair(&fragments[1]).unwrap();
+        assert_str_eq!(
+            header,
+            indoc! {r#"
+                namespace rust::cxxqtgen1::my_crate {
+                void
+                QTimer_intervalSetter(QTimer& self, ::std::int32_t value);
+                } // namespace rust::cxxqtgen1::my_crate
+            "#}
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+                namespace rust::cxxqtgen1::my_crate {
+                void
+                QTimer_intervalSetter(QTimer& self, ::std::int32_t value)
+                {
+                    self.setInterval(::std::move(value));
+                }
+                } // namespace rust::cxxqtgen1::my_crate
+            "#}
+        );
+
+        // A read only property only has a getter
+        let (_, source) = require_pair(&fragments[2]).unwrap();
+        assert!(source.contains("return self.isActive();"));
+    }
+
     #[test]
     fn test_generate_cpp_extern_qt_mapping() {
         let blocks = vec![ParsedExternCxxQt::parse(
@@ -100,4 +375,109 @@ mod tests {
         let generated = generate(&blocks, &type_names).unwrap();
         assert_eq!(generated.len(), 1);
     }
+
+    #[test]
+    fn test_generate_cpp_extern_qt_alias() {
+        let blocks = vec![ParsedExternCxxQt::parse(
+            parse_quote! {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    #[namespace = "other"]
+                    type OtherObject = crate::other::qobject::OtherObject;
+
+                    #[qobject]
+                    type QTimer;
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        )
+        .unwrap()];
+
+        let generated = generate(&blocks, &TypeNames::default()).unwrap();
+        // Only the QObject from another bridge is forward declared
+        assert_eq!(generated.len(), 1);
+        assert!(generated[0].fragments.is_empty());
+        assert_eq!(generated[0].forward_declares.len(), 1);
+        assert_str_eq!(
+            generated[0].forward_declares[0],
+            indoc! {r#"
+                namespace other {
+                class OtherObject;
+                } // namespace other
+            "#}
+        );
+        assert_eq!(
+            generated[0].aliased_bridges,
+            vec![AliasedBridge {
+                supers: None,
+                modules: vec!["other".to_owned()],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_aliased_bridge_from_alias() {
+        let from_alias = |path: Path| AliasedBridge::from_alias(&path);
+        assert_eq!(
+            from_alias(parse_quote! { crate::qobject::OtherObject }),
+            Some(AliasedBridge {
+                supers: None,
+                modules: vec![],
+            })
+        );
+        assert_eq!(
+            from_alias(parse_quote! { super::super::a::b::qobject::OtherObject }),
+            Some(AliasedBridge {
+                supers: Some(1),
+                modules: vec!["a".to_owned(), "b".to_owned()],
+            })
+        );
+        // Other crates and paths without a bridge module are not resolved
+        assert_eq!(
+            from_alias(parse_quote! { other_crate::qobject::OtherObject }),
+            None
+        );
+        assert_eq!(from_alias(parse_quote! { super::OtherObject }), None);
+    }
+
+    #[test]
+    fn test_aliased_bridge_include() {
+        let bridge = |supers: Option<usize>, modules: &[&str]| AliasedBridge {
+            supers,
+            modules: modules.iter().map(|module| module.to_string()).collect(),
+        };
+        assert_eq!(
+            bridge(None, &["other"]).include("my_crate/src/module/file"),
+            Some("#include \"my_crate/src/other.cxxqt.h\"".to_owned())
+        );
+        assert_eq!(
+            bridge(None, &[]).include("my_crate/src/module/file"),
+            Some("#include \"my_crate/src/lib.cxxqt.h\"".to_owned())
+        );
+        assert_eq!(bridge(None, &[]).include("my_crate/src/main"), None);
+        // The first super is the module of the file
+        assert_eq!(
+            bridge(Some(0), &["child"]).include("my_crate/src/module/file"),
+            Some("#include \"my_crate/src/module/file/child.cxxqt.h\"".to_owned())
+        );
+        assert_eq!(
+            bridge(Some(1), &["other"]).include("my_crate/src/module/file"),
+            Some("#include \"my_crate/src/module/other.cxxqt.h\"".to_owned())
+        );
+        assert_eq!(
+            bridge(Some(1), &["other"]).include("my_crate/src/module/mod"),
+            Some("#include \"my_crate/src/other.cxxqt.h\"".to_owned())
+        );
+        // Going above the crate root cannot be resolved
+        assert_eq!(
+            bridge(Some(3), &["other"]).include("my_crate/src/module/file"),
+            None
+        );
+        // Without a src folder the file is assumed to be next to the crate root
+        assert_eq!(
+            bridge(None, &["other"]).include("directory/file_ident"),
+            Some("#include \"directory/other.cxxqt.h\"".to_owned())
+        );
+    }
 }
@@ -19,6 +19,33 @@ pub enum CppFragment {
     Source(String),
 }
 
+impl CppFragment {
+    /// Wrap the fragment in an `#if` for the given preprocessor condition, if there is one
+    pub fn with_condition(self, condition: Option<&str>) -> Self {
+        match self {
+            CppFragment::Pair { header, source } => CppFragment::Pair {
+                header: with_condition(header, condition),
+                source: with_condition(source, condition),
+            },
+            CppFragment::Header(header) => CppFragment::Header(with_condition(header, condition)),
+            CppFragment::Source(source) => CppFragment::Source(with_condition(source, condition)),
+        }
+    }
+}
+
+/// Wrap the given code in an `#if` for the given preprocessor condition, if there is one
+pub fn with_condition(code: String, condition: Option<&str>) -> String {
+    if let Some(condition) = condition {
+        let trailing_newline = if code.ends_with('\n') { "\n" } else { "" };
+        format!(
+            "#if {condition}\n{code}\n#endif{trailing_newline}",
+            code = code.trim_end()
+        )
+    } else {
+        code
+    }
+}
+
 impl Default for CppFragment {
     fn default() -> Self {
         CppFragment::Pair {
@@ -37,6 +64,34 @@ pub struct CppNamedType {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_with_condition() {
+        let condition = Some("QT_VERSION >= QT_VERSION_CHECK(6, 5, 0)");
+        assert_eq!(
+            CppFragment::Header("void a();".to_owned()).with_condition(condition),
+            CppFragment::Header(
+                "#if QT_VERSION >= QT_VERSION_CHECK(6, 5, 0)\nvoid a();\n#endif".to_owned()
+            )
+        );
+        assert_eq!(
+            CppFragment::Pair {
+                header: "void a();\n".to_owned(),
+                source: "void a() {}\n".to_owned(),
+            }
+            .with_condition(condition),
+            CppFragment::Pair {
+                header: "#if QT_VERSION >= QT_VERSION_CHECK(6, 5, 0)\nvoid a();\n#endif\n"
+                    .to_owned(),
+                source: "#if QT_VERSION >= QT_VERSION_CHECK(6, 5, 0)\nvoid a() {}\n#endif\n"
+                    .to_owned(),
+            }
+        );
+        assert_eq!(
+            CppFragment::Source("void a() {}".to_owned()).with_condition(None),
+            CppFragment::Source("void a() {}".to_owned())
+        );
+    }
+
     #[test]
     fn test_default_creation() {
         assert_eq!(
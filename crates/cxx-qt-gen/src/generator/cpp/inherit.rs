@@ -27,19 +27,22 @@ pub fn generate(
         // So a default of QObject is fine here
         let base_class = base_class.as_deref().unwrap_or("QObject");
 
-        result.methods.push(CppFragment::Header(formatdoc! {
-        r#"
+        result.methods.push(
+            CppFragment::Header(formatdoc! {
+            r#"
               template <class... Args>
               {return_type} {wrapper_ident}(Args ...args){mutability}
               {{
                   return {base_class}::{func_ident}(args...);
               }}"#,
-        mutability = if method.mutable { "" } else { " const" },
-        func_ident = method.name.cxx_unqualified(),
-        wrapper_ident = method.wrapper_ident(),
-        return_type = return_type.unwrap_or_else(|| "void".to_string()),
-        base_class = base_class
-        }));
+            mutability = if method.mutable { "" } else { " const" },
+            func_ident = method.name.cxx_unqualified(),
+            wrapper_ident = method.wrapper_ident(),
+            return_type = return_type.unwrap_or_else(|| "void".to_string()),
+            base_class = base_class
+            })
+            .with_condition(method.cpp_condition().as_deref()),
+        );
     }
 
     Ok(result)
@@ -0,0 +1,169 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        cpp::{
+            fragment::CppFragment, qobject::GeneratedCppQObjectBlocks, signal::generate_cpp_signals,
+        },
+        naming::qobject::QObjectNames,
+    },
+    naming::TypeNames,
+    parser::signals::ParsedSignal,
+    syntax::safety::Safety,
+};
+use indoc::formatdoc;
+use syn::{ForeignItemFn, Result};
+
+pub fn generate(
+    qobject_idents: &QObjectNames,
+    base_class: &str,
+    type_names: &TypeNames,
+) -> Result<GeneratedCppQObjectBlocks> {
+    let mut result = GeneratedCppQObjectBlocks::default();
+
+    let cpp_class = &qobject_idents.name.cxx_unqualified();
+
+    result
+        .includes
+        .insert("#include <cxx-qt/lazy.h>".to_owned());
+
+    result
+        .includes
+        .insert("#include <QtCore/QMetaMethod>".to_owned());
+
+    result
+        .base_classes
+        .push("::rust::cxxqt1::CxxQtLazyInitialization".to_owned());
+
+    // These are invokable, so that QML can also trigger the initialization
+    result.methods.push(CppFragment::Pair {
+        header: "Q_INVOKABLE void ensureInitialized() noexcept;".to_owned(),
+        source: formatdoc! {
+            r#"
+            void
+            {cpp_class}::ensureInitialized() noexcept
+            {{
+              ::rust::cxxqt1::lazyEnsureInitialized(*this);
+            }}
+            "#
+        },
+    });
+    result.methods.push(CppFragment::Header(
+        "Q_INVOKABLE bool isInitialized() const noexcept;".to_owned(),
+    ));
+    // This is implemented in Rust and runs the LazyInitialize implementation
+    result.methods.push(CppFragment::Header(
+        "void cxxQtLazyInitialize() noexcept;".to_owned(),
+    ));
+
+    // Connecting to any signal of the QObject, which QML does when a binding first reads a
+    // property, is the first access. The initialization is queued as connectNotify can be
+    // called from any thread and while the connection is being made.
+    result.private_methods.push(CppFragment::Pair {
+        header: "void connectNotify(const QMetaMethod& signal) override;".to_owned(),
+        source: formatdoc! {
+            r#"
+            void
+            {cpp_class}::connectNotify(const QMetaMethod& signal)
+            {{
+              ::rust::cxxqt1::lazyEnsureInitializedQueued(*this);
+              {base_class}::connectNotify(signal);
+            }}
+            "#
+        },
+    });
+
+    // We build our signal in the generation phase as we need to use the naming
+    // structs to build the signal name
+    let cpp_class_rust = &qobject_idents.name.rust_unqualified();
+    let method: ForeignItemFn = syn::parse_quote! {
+        #[doc = "This signal is emitted once the lazy singleton has been initialized"]
+        fn initialized(self: Pin<&mut #cpp_class_rust>);
+    };
+    let signal = ParsedSignal::parse(method, Safety::Safe)?;
+    result.append(&mut generate_cpp_signals(
+        &vec![&signal],
+        qobject_idents,
+        type_names,
+    )?);
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::cpp::property::tests::{require_header, require_pair};
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+
+    #[test]
+    fn test_generate_cpp_lazy() {
+        let qobject_idents = create_qobjectname();
+
+        let generated = generate(&qobject_idents, "QObject", &TypeNames::mock()).unwrap();
+
+        assert!(generated.includes.contains("#include <cxx-qt/lazy.h>"));
+        assert!(generated.includes.contains("#include <QtCore/QMetaMethod>"));
+        assert_eq!(
+            generated.base_classes,
+            vec!["::rust::cxxqt1::CxxQtLazyInitialization".to_owned()]
+        );
+
+        // methods
+        assert_eq!(generated.methods.len(), 4);
+        let (header, source) = require_pair(&generated.methods[0]).unwrap();
+        assert_str_eq!(header, "Q_INVOKABLE void ensureInitialized() noexcept;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::ensureInitialized() noexcept
+            {
+              ::rust::cxxqt1::lazyEnsureInitialized(*this);
+            }
+            "#}
+        );
+        assert_str_eq!(
+            require_header(&generated.methods[1]).unwrap(),
+            "Q_INVOKABLE bool isInitialized() const noexcept;"
+        );
+        assert_str_eq!(
+            require_header(&generated.methods[2]).unwrap(),
+            "void cxxQtLazyInitialize() noexcept;"
+        );
+        assert_str_eq!(
+            require_header(&generated.methods[3]).unwrap(),
+            indoc! {r#"
+            /// This signal is emitted once the lazy singleton has been initialized
+            Q_SIGNAL void initialized();"#}
+        );
+
+        // the first access
+        assert_eq!(generated.private_methods.len(), 1);
+        let (header, source) = require_pair(&generated.private_methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "void connectNotify(const QMetaMethod& signal) override;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::connectNotify(const QMetaMethod& signal)
+            {
+              ::rust::cxxqt1::lazyEnsureInitializedQueued(*this);
+              QObject::connectNotify(signal);
+            }
+            "#}
+        );
+
+        // the signal connect helpers
+        assert_eq!(generated.fragments.len(), 2);
+    }
+}
@@ -8,6 +8,7 @@ use crate::{
     generator::cpp::{
         fragment::{CppFragment, CppNamedType},
         qobject::GeneratedCppQObjectBlocks,
+        utils::doc_comment,
     },
     naming::cpp::{syn_return_type_to_cpp_except, syn_type_to_cpp_return_type},
     naming::TypeNames,
@@ -63,10 +64,14 @@ pub fn generate_cpp_methods(
         //
         // CXX generates the source and we just need the matching header.
         let has_noexcept = syn_return_type_to_cpp_except(&invokable.method.sig.output);
-        generated.methods.push(CppFragment::Header(format!(
-            "{is_qinvokable}{is_virtual}{return_cxx_ty} {ident}({parameter_types}){is_const} {has_noexcept}{is_final}{is_override};",
-            ident = invokable.name.cxx_unqualified(),
-        )));
+        generated.methods.push(
+            CppFragment::Header(format!(
+                "{docs}{is_qinvokable}{is_virtual}{return_cxx_ty} {ident}({parameter_types}){is_const} {has_noexcept}{is_final}{is_override};",
+                docs = doc_comment(&invokable.docs),
+                ident = invokable.name.cxx_unqualified(),
+            ))
+            .with_condition(invokable.cpp_condition().as_deref()),
+        );
     }
 
     Ok(generated)
@@ -77,6 +82,7 @@ mod tests {
     use super::*;
 
     use crate::generator::cpp::property::tests::require_header;
+    use indoc::indoc;
     use pretty_assertions::assert_str_eq;
     use std::collections::HashSet;
     use syn::{parse_quote, ForeignItemFn};
@@ -167,4 +173,82 @@ mod tests {
         // private methods
         assert_eq!(generated.private_methods.len(), 0);
     }
+
+    #[test]
+    fn test_generate_cpp_invokables_qt_since() {
+        let method_declaration: ForeignItemFn = parse_quote! {
+            #[qt(since = "6.5")]
+            fn new_invokable(self: &MyObject);
+        };
+
+        let method = ParsedMethod::mock_qinvokable(&method_declaration);
+        let invokables = vec![&method];
+
+        let generated = generate_cpp_methods(&invokables, &TypeNames::mock()).unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            indoc! {"
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 5, 0)
+            Q_INVOKABLE void newInvokable() const noexcept;
+            #endif"}
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_docs() {
+        let method_declaration: ForeignItemFn = parse_quote! {
+            /// Returns the number
+            ///
+            /// This is used from QML
+            fn number(self: &MyObject) -> i32;
+        };
+
+        let method = ParsedMethod::mock_qinvokable(&method_declaration);
+        let invokables = vec![&method];
+
+        let generated = generate_cpp_methods(&invokables, &TypeNames::mock()).unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            indoc! {"
+            /// Returns the number
+            ///
+            /// This is used from QML
+            Q_INVOKABLE ::std::int32_t number() const noexcept;"}
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_overloaded() {
+        let method1: ForeignItemFn = parse_quote! {
+            #[cxx_name = "update"]
+            fn update_value(self: Pin<&mut MyObject>, value: i32);
+        };
+        let method2: ForeignItemFn = parse_quote! {
+            #[cxx_name = "update"]
+            fn update_flag(self: Pin<&mut MyObject>, flag: bool);
+        };
+        let invokables = [
+            ParsedMethod::mock_qinvokable(&method1).make_mutable(),
+            ParsedMethod::mock_qinvokable(&method2).make_mutable(),
+        ];
+
+        let generated =
+            generate_cpp_methods(&invokables.iter().collect(), &TypeNames::mock()).unwrap();
+
+        // Each overload is declared as a C++ member function with the same name
+        assert_eq!(generated.methods.len(), 2);
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE void update(::std::int32_t value) noexcept;"
+        );
+        let header = require_header(&generated.methods[1]).unwrap();
+        assert_str_eq!(header, "Q_INVOKABLE void update(bool flag) noexcept;");
+    }
 }
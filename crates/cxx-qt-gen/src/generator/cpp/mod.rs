@@ -8,12 +8,14 @@ pub mod cxxqttype;
 pub mod externcxxqt;
 pub mod fragment;
 pub mod inherit;
+pub mod lazy;
 pub mod method;
 pub mod property;
 pub mod qenum;
 pub mod qnamespace;
 pub mod qobject;
 pub mod signal;
+pub mod signature;
 pub mod threading;
 
 mod utils;
@@ -5,7 +5,7 @@
 
 use crate::generator::structuring::StructuredQObject;
 use crate::generator::{
-    cpp::{qobject::GeneratedCppQObjectBlocks, signal::generate_cpp_signals},
+    cpp::{qobject::GeneratedCppQObjectBlocks, signal::generate_cpp_signals, utils::doc_comment},
     naming::{property::QPropertyNames, qobject::QObjectNames},
 };
 use crate::{
@@ -32,9 +32,11 @@ pub fn generate_cpp_properties(
         let idents = QPropertyNames::try_from_property(property, structured_qobject)?;
         let cxx_ty = syn_type_to_cpp_type(&property.ty, type_names)?;
 
-        generated
-            .metaobjects
-            .push(meta::generate(&idents, &property.flags, &cxx_ty));
+        generated.metaobjects.push(format!(
+            "{docs}{meta}",
+            docs = doc_comment(&property.docs),
+            meta = meta::generate(&idents, &property.flags, &cxx_ty)
+        ));
 
         if let Some(getter) = getter::generate(&idents, &cxx_ty) {
             generated.methods.push(getter);
@@ -65,6 +67,7 @@ pub mod tests {
     use crate::generator::naming::property::property_name_from_rust_name;
     use crate::generator::naming::qobject::tests::create_qobjectname;
     use crate::generator::structuring::Structures;
+    use crate::naming::AutoCamel;
     use crate::parser::property::{mock_property, QPropertyFlags};
     use crate::parser::qobject::ParsedQObject;
     use crate::{CppFragment, Parser};
@@ -95,7 +98,7 @@ pub mod tests {
     }
 
     fn setup_generated(input: &mut ItemStruct) -> Result<GeneratedCppQObjectBlocks> {
-        let property = ParsedQProperty::parse(&input.attrs.remove(0))?;
+        let property = ParsedQProperty::parse(&input.attrs.remove(0), AutoCamel::Enabled)?;
 
         let properties = vec![property];
 
@@ -278,6 +281,22 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_docs() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(i32, num, READ, CONSTANT, doc = " The number")]
+            struct MyStruct;
+        };
+        let generated = setup_generated(&mut input).unwrap();
+
+        assert_str_eq!(
+            generated.metaobjects[0],
+            indoc! {"
+            /// The number
+            Q_PROPERTY(::std::int32_t num READ getNum CONSTANT)"}
+        );
+    }
+
     #[test]
     fn test_generate_cpp_properties() {
         let input1: ItemStruct = parse_quote! {
@@ -342,12 +361,22 @@ pub mod tests {
         );
 
         let header = require_header(&generated.methods[4]).unwrap();
-        assert_str_eq!(header, "Q_SIGNAL void trivialPropertyChanged();");
+        assert_str_eq!(
+            header,
+            indoc! {r#"
+            /// Notify for the Q_PROPERTY
+            Q_SIGNAL void trivialPropertyChanged();"#}
+        );
 
         let header = require_header(&generated.methods[5]).unwrap();
-        assert_str_eq!(header, "Q_SIGNAL void opaquePropertyChanged();");
+        assert_str_eq!(
+            header,
+            indoc! {r#"
+            /// Notify for the Q_PROPERTY
+            Q_SIGNAL void opaquePropertyChanged();"#}
+        );
 
-        assert_eq!(generated.fragments.len(), 2);
+        assert_eq!(generated.fragments.len(), 4);
         let (header, source) = require_pair(&generated.fragments[0]).unwrap();
         // This call just exists to ensure full coverage on require_header:
         assert!(require_header(&generated.fragments[0]).is_err());
@@ -406,7 +435,7 @@ pub mod tests {
             "#}
         );
 
-        let (header, source) = require_pair(&generated.fragments[1]).unwrap();
+        let (header, source) = require_pair(&generated.fragments[2]).unwrap();
 
         assert_str_eq!(
             header,
@@ -472,6 +501,8 @@ pub mod tests {
             name: property_name_from_rust_name(format_ident!("mapped_property")),
             ty: parse_quote! { A },
             flags: QPropertyFlags::default(),
+            cfgs: vec![],
+            docs: vec![],
         }];
         let qobject_idents = create_qobjectname();
 
@@ -505,9 +536,14 @@ pub mod tests {
         assert_str_eq!(header, "Q_SLOT void setMappedProperty(A1 value) noexcept;");
 
         let header = require_header(&generated.methods[2]).unwrap();
-        assert_str_eq!(header, "Q_SIGNAL void mappedPropertyChanged();");
+        assert_str_eq!(
+            header,
+            indoc! {r#"
+            /// Notify for the Q_PROPERTY
+            Q_SIGNAL void mappedPropertyChanged();"#}
+        );
 
-        assert_eq!(generated.fragments.len(), 1);
+        assert_eq!(generated.fragments.len(), 2);
 
         let (header, source) = require_pair(&generated.fragments[0]).unwrap();
         assert_str_eq!(
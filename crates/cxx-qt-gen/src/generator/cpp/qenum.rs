@@ -5,22 +5,30 @@
 
 use std::collections::BTreeSet;
 
+use convert_case::{Case, Casing};
 use indoc::formatdoc;
 use syn::Result;
 
-use crate::{parser::qenum::ParsedQEnum, writer::cpp::namespaced};
+use crate::{
+    generator::naming::qobject::QObjectNames, parser::qenum::ParsedQEnum, writer::cpp::namespaced,
+};
 
-use super::{qobject::GeneratedCppQObjectBlocks, utils::Indent};
+use super::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks, utils::Indent};
 
 fn generate_definition(qenum: &ParsedQEnum) -> String {
     let enum_name = &qenum.name.cxx_unqualified();
 
-    let enum_values = qenum
-        .variants
-        .iter()
-        .map(ToString::to_string)
-        .collect::<Vec<_>>()
-        .join(",\n");
+    let enum_values = if let Some(values) = qenum.variant_values() {
+        qenum
+            .variants
+            .iter()
+            .zip(values)
+            .map(|(variant, value)| format!("{variant} = {value}"))
+            .collect::<Vec<_>>()
+    } else {
+        qenum.variants.iter().map(ToString::to_string).collect()
+    }
+    .join(",\n");
 
     formatdoc! { r#"
         enum class {enum_name} : ::std::int32_t {{
@@ -54,10 +62,42 @@ pub fn generate_declaration(qenum: &ParsedQEnum, includes: &mut BTreeSet<String>
     )
 }
 
+/// Generate the roleNames() override of the model, so that QML delegates can use the roles by name
+fn generate_role_names(qenum: &ParsedQEnum, qobject_idents: &QObjectNames) -> CppFragment {
+    let class_name = qobject_idents.name.cxx_unqualified();
+    let role_names = qenum
+        .variants
+        .iter()
+        .zip(qenum.variant_values().into_iter().flatten())
+        .map(|(variant, value)| {
+            format!(
+                "{{ {value}, QByteArrayLiteral(\"{name}\") }}",
+                name = variant.to_string().to_case(Case::Camel)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",\n");
+
+    CppFragment::Pair {
+        header: "QHash<int, QByteArray> roleNames() const override;".to_owned(),
+        source: formatdoc! {r#"
+            QHash<int, QByteArray>
+            {class_name}::roleNames() const
+            {{
+              return {{
+            {role_names}
+              }};
+            }}
+            "#, role_names = role_names.indented(4) },
+    }
+}
+
 pub fn generate_on_qobject<'a>(
     qenums: impl Iterator<Item = &'a ParsedQEnum>,
+    qobject_idents: &QObjectNames,
 ) -> Result<GeneratedCppQObjectBlocks> {
     let mut generated = GeneratedCppQObjectBlocks::default();
+    let mut has_model_roles = false;
 
     for qenum in qenums {
         let mut qualified_name = qenum.name.cxx_qualified();
@@ -80,6 +120,26 @@ pub fn generate_on_qobject<'a>(
               Q_ENUM({enum_name})
             #endif
         "#, enum_definition = enum_definition.indented(2)});
+
+        if qenum.model_roles {
+            if has_model_roles {
+                return Err(syn::Error::new_spanned(
+                    &qenum.item.ident,
+                    "Only one QEnum with #[model_roles] can be associated to a QObject!",
+                ));
+            }
+            has_model_roles = true;
+
+            generated
+                .includes
+                .insert("#include <QtCore/QByteArray>".to_string());
+            generated
+                .includes
+                .insert("#include <QtCore/QHash>".to_string());
+            generated
+                .methods
+                .push(generate_role_names(qenum, qobject_idents));
+        }
     }
 
     Ok(generated)
@@ -90,6 +150,7 @@ mod tests {
     use std::assert_eq;
 
     use super::*;
+    use crate::generator::naming::qobject::tests::create_qobjectname;
     use indoc::indoc;
     use pretty_assertions::assert_str_eq;
     use quote::format_ident;
@@ -109,7 +170,7 @@ mod tests {
         )
         .unwrap()];
 
-        let generated = generate_on_qobject(qenums.iter()).unwrap();
+        let generated = generate_on_qobject(qenums.iter(), &create_qobjectname()).unwrap();
         assert_eq!(generated.includes.len(), 1);
         assert!(generated.includes.contains("#include <cstdint>"));
         assert_eq!(generated.metaobjects.len(), 1);
@@ -131,4 +192,80 @@ mod tests {
         );
         assert_eq!(generated.forward_declares.len(), 0);
     }
+
+    #[test]
+    fn generates_model_roles() {
+        let qenums = [ParsedQEnum::parse(
+            parse_quote! {
+                #[model_roles]
+                enum Roles {
+                    Id, DisplayName
+                }
+            },
+            Some(format_ident!("MyObject")),
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap()];
+
+        let generated = generate_on_qobject(qenums.iter(), &create_qobjectname()).unwrap();
+        assert!(generated.includes.contains("#include <QtCore/QHash>"));
+        assert_str_eq!(
+            indoc! {r#"
+                #ifdef Q_MOC_RUN
+                  enum class Roles : ::std::int32_t {
+                    Id = 256,
+                    DisplayName = 257
+                  };
+                  Q_ENUM(Roles)
+                #else
+                  using Roles = ::Roles;
+                  Q_ENUM(Roles)
+                #endif
+            "#},
+            generated.metaobjects[0],
+        );
+
+        assert_eq!(generated.methods.len(), 1);
+        let (header, source) = if let CppFragment::Pair { header, source } = &generated.methods[0] {
+            (header, source)
+        } else {
+            panic!("Expected pair")
+        };
+        assert_str_eq!(header, "QHash<int, QByteArray> roleNames() const override;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+                QHash<int, QByteArray>
+                MyObject::roleNames() const
+                {
+                  return {
+                    { 256, QByteArrayLiteral("id") },
+                    { 257, QByteArrayLiteral("displayName") }
+                  };
+                }
+            "#}
+        );
+    }
+
+    #[test]
+    fn generates_model_roles_twice() {
+        let qenums = ["Roles", "OtherRoles"].map(|name| {
+            let ident = format_ident!("{name}");
+            ParsedQEnum::parse(
+                parse_quote! {
+                    #[model_roles]
+                    enum #ident {
+                        A
+                    }
+                },
+                Some(format_ident!("MyObject")),
+                None,
+                &format_ident!("qobject"),
+            )
+            .unwrap()
+        });
+
+        assert!(generate_on_qobject(qenums.iter(), &create_qobjectname()).is_err());
+    }
 }
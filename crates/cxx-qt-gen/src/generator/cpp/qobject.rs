@@ -6,15 +6,19 @@
 use crate::{
     generator::{
         cpp::{
-            constructor, cxxqttype, fragment::CppFragment, inherit, method::generate_cpp_methods,
-            property::generate_cpp_properties, qenum, signal::generate_cpp_signals, threading,
+            constructor, cxxqttype, fragment::CppFragment, inherit, lazy,
+            method::generate_cpp_methods, property::generate_cpp_properties, qenum,
+            signal::generate_cpp_signals, signature, threading, utils::doc_comment,
         },
         naming::{namespace::NamespaceName, qobject::QObjectNames},
         structuring::StructuredQObject,
     },
     naming::Name,
 };
-use crate::{naming::TypeNames, parser::qobject::ParsedQObject};
+use crate::{
+    naming::TypeNames,
+    parser::{extract_docs, qobject::ParsedQObject},
+};
 use std::collections::BTreeSet;
 use syn::Result;
 
@@ -75,6 +79,12 @@ impl GeneratedCppQObjectBlocks {
                 qml_specifiers.push("QML_SINGLETON".to_owned());
             }
         }
+        if let Some(dbus_interface) = &qobject.dbus_interface {
+            // QDBusConnection::registerObject uses this as the name of the exported interface
+            qml_specifiers.push(format!(
+                "Q_CLASSINFO(\"D-Bus Interface\", \"{dbus_interface}\")"
+            ));
+        }
         GeneratedCppQObjectBlocks {
             metaobjects: qml_specifiers,
             ..Default::default()
@@ -93,6 +103,8 @@ pub struct GeneratedCppQObject {
     pub blocks: GeneratedCppQObjectBlocks,
     /// Whether this type has a #[qobject] / Q_OBJECT macro
     pub has_qobject_macro: bool,
+    /// The doc comment of the QObject class, if it has any docs
+    pub docs: String,
 }
 
 impl GeneratedCppQObject {
@@ -111,6 +123,7 @@ impl GeneratedCppQObject {
             namespace_internals: namespace_idents.internal,
             blocks: GeneratedCppQObjectBlocks::from(qobject),
             has_qobject_macro: qobject.has_qobject_macro,
+            docs: doc_comment(&extract_docs(&qobject.declaration.attrs)),
         };
 
         let base_class = if let Some(ident) = &qobject.base_class {
@@ -154,27 +167,63 @@ impl GeneratedCppQObject {
         )?);
         generated.blocks.append(&mut qenum::generate_on_qobject(
             structured_qobject.qenums.iter().cloned(),
+            &qobject_idents,
         )?);
 
+        // If this type is a lazy singleton then add the initialization state and signal
+        if qobject
+            .qml_metadata
+            .as_ref()
+            .is_some_and(|qml_metadata| qml_metadata.lazy)
+        {
+            generated.blocks.append(&mut lazy::generate(
+                &qobject_idents,
+                &base_class,
+                type_names,
+            )?);
+        }
+
         let mut class_initializers = vec![];
 
         // If this type has threading enabled then add generation
         //
         // Note that threading also includes locking C++ generation
         if structured_qobject.threading {
-            let (initializer, mut blocks) = threading::generate(&qobject_idents)?;
+            let (initializer, mut blocks) = threading::generate(&qobject_idents, &base_class)?;
             generated.blocks.append(&mut blocks);
             class_initializers.push(initializer);
         }
 
+        let mut class_statements = vec![];
+
+        // If this type has aliases with a notify signal, then forward the changed signals of the
+        // children once the object is constructed
+        if qobject
+            .properties
+            .iter()
+            .any(|property| property.flags.alias.is_some() && property.flags.notify.is_some())
+        {
+            class_statements.push(format!(
+                "::{namespace_internals}::connectAliases(*this);",
+                namespace_internals = generated.namespace_internals
+            ));
+        }
+
         generated.blocks.append(&mut constructor::generate(
             &generated,
             &structured_qobject.constructors,
             base_class,
             &class_initializers,
+            &class_statements,
             type_names,
         )?);
 
+        // Refer to the signature of the bridge so that a stale header fails to link
+        generated.blocks.append(&mut signature::generate(
+            &generated.namespace_internals,
+            structured_qobject.signature_hash(),
+        ));
+
         Ok(generated)
     }
 }
@@ -328,4 +377,62 @@ mod tests {
             "Q_CLASSINFO(\"QML.Creatable\", \"false\")"
         );
     }
+    #[test]
+    fn test_generated_cpp_qobject_dbus_interface() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[dbus_interface = "org.kde.cxx_qt.MyObject"]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp =
+            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &TypeNames::mock())
+                .unwrap();
+        assert_eq!(cpp.blocks.metaobjects.len(), 1);
+        assert_eq!(
+            cpp.blocks.metaobjects[0],
+            "Q_CLASSINFO(\"D-Bus Interface\", \"org.kde.cxx_qt.MyObject\")"
+        );
+    }
+
+    #[test]
+    fn test_generated_cpp_qobject_alias() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, value, ALIAS = child.value)]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let cpp =
+            GeneratedCppQObject::from(structures.qobjects.first().unwrap(), &TypeNames::mock())
+                .unwrap();
+        let constructor = cpp
+            .blocks
+            .methods
+            .iter()
+            .find_map(|method| match method {
+                CppFragment::Pair { header, source }
+                    if header.starts_with("explicit MyObject(") =>
+                {
+                    Some(source)
+                }
+                _ => None,
+            })
+            .unwrap();
+        assert!(constructor.contains("::cxx_qt::cxx_qt_my_object::connectAliases(*this);"));
+    }
 }
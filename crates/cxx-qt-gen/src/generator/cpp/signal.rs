@@ -5,7 +5,11 @@
 
 use crate::{
     generator::{
-        cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+        cpp::{
+            fragment::{with_condition, CppFragment},
+            qobject::GeneratedCppQObjectBlocks,
+            utils::doc_comment,
+        },
         naming::{
             qobject::QObjectNames,
             signals::{QSignalHelperNames, QSignalNames},
@@ -38,8 +42,12 @@ struct Parameters {
     named_types: String,
     /// name with type of parameters including self
     named_types_with_self: String,
+    /// Raw types of the parameters
+    types: String,
     /// Raw types of the parameters including self
     types_with_self: String,
+    /// Raw ::std::move values of the parameters
+    values: String,
     /// Raw ::std::move values of the parameters including self
     values_with_self: String,
 }
@@ -63,6 +71,8 @@ fn parameter_types_and_values(
     }
 
     let parameter_named_types = parameter_named_types_with_self.join(", ");
+    let parameter_types = parameter_types_with_self.join(", ");
+    let parameter_values = parameter_values_with_self.join(", ");
 
     // Insert the extra argument into the closure
     let self_ty = self_ty.cxx_qualified();
@@ -73,7 +83,9 @@ fn parameter_types_and_values(
     Ok(Parameters {
         named_types: parameter_named_types,
         named_types_with_self: parameter_named_types_with_self.join(", "),
+        types: parameter_types,
         types_with_self: parameter_types_with_self.join(", "),
+        values: parameter_values,
         values_with_self: parameter_values_with_self.join(", "),
     })
 }
@@ -89,6 +101,9 @@ pub fn generate_cpp_signal(
     generated
         .includes
         .insert("#include <cxx-qt/signalhandler.h>".to_owned());
+    generated
+        .includes
+        .insert("#include <QtCore/QPointer>".to_owned());
 
     // Build a namespace that includes any namespace for the T
     let qobject_ident_namespaced = qobject_name.cxx_qualified();
@@ -99,6 +114,8 @@ pub fn generate_cpp_signal(
 
     let signal_ident = idents.name.cxx_unqualified();
     let free_connect_ident_cpp = idents_helper.connect_name.cxx_unqualified();
+    let free_connect_with_context_ident_cpp =
+        idents_helper.connect_with_context_name.cxx_unqualified();
 
     // Retrieve the parameters for the signal
     let parameters = parameter_types_and_values(&signal.parameters, type_names, qobject_name)?;
@@ -106,6 +123,11 @@ pub fn generate_cpp_signal(
     let parameters_named_types_with_self = parameters.named_types_with_self;
     let parameter_types_with_self = parameters.types_with_self;
     let parameter_values_with_self = parameters.values_with_self;
+    let parameter_values_with_sender = if parameters.values.is_empty() {
+        "*sender".to_owned()
+    } else {
+        format!("*sender, {values}", values = parameters.values)
+    };
 
     let param_struct = idents_helper.struct_param;
     let signal_handler_alias = idents_helper.handler_alias;
@@ -116,6 +138,16 @@ pub fn generate_cpp_signal(
 
     let signal_handler_type = format!("SignalHandler<::{namespace}::{param_struct} *>");
 
+    // An overloaded signal needs its parameter types to select the right member function pointer
+    let signal_pointer = if signal.overloaded {
+        format!(
+            "QOverload<{parameter_types}>::of(&{qobject_ident_namespaced}::{signal_ident})",
+            parameter_types = parameters.types
+        )
+    } else {
+        format!("&{qobject_ident_namespaced}::{signal_ident}")
+    };
+
     generated.forward_declares.push(formatdoc! {
         r#"
         namespace {namespace} {{
@@ -126,9 +158,55 @@ pub fn generate_cpp_signal(
 
     // Generate the Q_SIGNAL if this is not an existing signal
     if !signal.inherit {
-        generated.methods.push(CppFragment::Header(format!(
-            "Q_SIGNAL void {signal_ident}({parameters_named_types});"
-        )));
+        if signal.private_signal {
+            // A private signal has an extra QPrivateSignal parameter which can only be
+            // constructed by the QObject, so emitting goes through a friend free function
+            let free_emit_ident_cpp = idents_helper.emit_name.cxx_unqualified();
+            let parameters_values = parameters.values;
+            let parameters_private = if parameters_named_types.is_empty() {
+                "QPrivateSignal".to_owned()
+            } else {
+                format!("{parameters_named_types}, QPrivateSignal")
+            };
+            let values_private = if parameters_values.is_empty() {
+                format!("{qobject_ident_namespaced}::QPrivateSignal()")
+            } else {
+                format!("{parameters_values}, {qobject_ident_namespaced}::QPrivateSignal()")
+            };
+
+            generated.methods.push(CppFragment::Header(format!(
+                "Q_SIGNAL void {signal_ident}({parameters_private});"
+            )));
+            generated.methods.push(CppFragment::Header(format!(
+                "friend void ::{namespace}::{free_emit_ident_cpp}({parameters_named_types_with_self});"
+            )));
+            generated.fragments.push(CppFragment::Pair {
+                header: formatdoc! {
+                    r#"
+                    namespace {namespace} {{
+                    void
+                    {free_emit_ident_cpp}({parameters_named_types_with_self});
+                    }} // namespace {namespace}
+                    "#
+                },
+                source: formatdoc! {
+                    r#"
+                    namespace {namespace} {{
+                    void
+                    {free_emit_ident_cpp}({parameters_named_types_with_self})
+                    {{
+                        Q_EMIT self.{signal_ident}({values_private});
+                    }}
+                    }} // namespace {namespace}
+                    "#
+                },
+            });
+        } else {
+            generated.methods.push(CppFragment::Header(format!(
+                "{docs}Q_SIGNAL void {signal_ident}({parameters_named_types});",
+                docs = doc_comment(&signal.docs)
+            )));
+        }
     }
 
     generated.fragments.push(CppFragment::Pair {
@@ -173,7 +251,7 @@ pub fn generate_cpp_signal(
             {{
                 return ::QObject::connect(
                     &self,
-                    &{qobject_ident_namespaced}::{signal_ident},
+                    {signal_pointer},
                     &self,
                     [&, closure = ::std::move(closure)]({parameters_named_types}) mutable {{
                         closure.template operator()<{parameter_types_with_self}>({parameter_values_with_self});
@@ -185,6 +263,62 @@ pub fn generate_cpp_signal(
         }
     });
 
+    // Connect with a separate context object, so that the closure is executed in the thread of
+    // the context and is disconnected when the context is destroyed.
+    //
+    // The sender is not captured by reference, as a queued emission can be delivered to the
+    // context after the sender has been destroyed, in which case the closure is not called.
+    generated.fragments.push(CppFragment::Pair {
+        header: formatdoc! {
+            r#"
+            namespace {namespace} {{
+            ::QMetaObject::Connection
+            {free_connect_with_context_ident_cpp}({qobject_ident_namespaced}& self, const ::QObject& context, {signal_handler_alias_namespaced} closure, ::Qt::ConnectionType type);
+            }} // namespace {namespace}
+            "#
+        },
+        source: formatdoc! {
+            r#"
+            namespace {namespace} {{
+            ::QMetaObject::Connection
+            {free_connect_with_context_ident_cpp}({qobject_ident_namespaced}& self, const ::QObject& context, {signal_handler_alias_namespaced} closure, ::Qt::ConnectionType type)
+            {{
+                return ::QObject::connect(
+                    &self,
+                    {signal_pointer},
+                    &context,
+                    [sender = ::QPointer<{qobject_ident_namespaced}>(&self), closure = ::std::move(closure)]({parameters_named_types}) mutable {{
+                        if (sender) {{
+                            closure.template operator()<{parameter_types_with_self}>({parameter_values_with_sender});
+                        }}
+                    }},
+                    type);
+            }}
+            }} // namespace {namespace}
+            "#
+        },
+    });
+
+    // Only declare the signal and its helpers if the Qt version is new enough
+    if let Some(condition) = signal.cpp_condition() {
+        let condition = Some(condition.as_str());
+        generated.forward_declares = generated
+            .forward_declares
+            .into_iter()
+            .map(|forward_declare| with_condition(forward_declare, condition))
+            .collect();
+        generated.fragments = generated
+            .fragments
+            .into_iter()
+            .map(|fragment| fragment.with_condition(condition))
+            .collect();
+        generated.methods = generated
+            .methods
+            .into_iter()
+            .map(|method| method.with_condition(condition))
+            .collect();
+    }
+
     Ok(generated)
 }
 
@@ -231,6 +365,8 @@ mod tests {
         type_names.mock_insert("QColor", None, None, None);
         let generated = generate_cpp_signals(&signals, &qobject_idents, &type_names).unwrap();
 
+        assert!(generated.includes.contains("#include <QtCore/QPointer>"));
+
         assert_eq!(generated.methods.len(), 1);
         let header = require_header(&generated.methods[0]).unwrap();
 
@@ -239,7 +375,7 @@ mod tests {
             "Q_SIGNAL void dataChanged(::std::int32_t trivial, ::std::unique_ptr<QColor> opaque);"
         );
 
-        assert_eq!(generated.fragments.len(), 1);
+        assert_eq!(generated.fragments.len(), 2);
         let (header, source) = require_pair(&generated.fragments[0]).unwrap();
 
         assert_str_eq!(
@@ -295,6 +431,38 @@ mod tests {
             } // namespace rust::cxxqtgen1
             "#}
         );
+
+        let (header, source) = require_pair(&generated.fragments[1]).unwrap();
+        assert_str_eq!(
+            header,
+            indoc! {r#"
+            namespace rust::cxxqtgen1 {
+            ::QMetaObject::Connection
+            MyObject_dataChangedConnectWithContext(MyObject& self, const ::QObject& context, ::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerdataChanged closure, ::Qt::ConnectionType type);
+            } // namespace rust::cxxqtgen1
+            "#}
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            namespace rust::cxxqtgen1 {
+            ::QMetaObject::Connection
+            MyObject_dataChangedConnectWithContext(MyObject& self, const ::QObject& context, ::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerdataChanged closure, ::Qt::ConnectionType type)
+            {
+                return ::QObject::connect(
+                    &self,
+                    &MyObject::dataChanged,
+                    &context,
+                    [sender = ::QPointer<MyObject>(&self), closure = ::std::move(closure)](::std::int32_t trivial, ::std::unique_ptr<QColor> opaque) mutable {
+                        if (sender) {
+                            closure.template operator()<MyObject&, ::std::int32_t, ::std::unique_ptr<QColor>>(*sender, ::std::move(trivial), ::std::move(opaque));
+                        }
+                    },
+                    type);
+            }
+            } // namespace rust::cxxqtgen1
+            "#}
+        );
     }
 
     #[test]
@@ -315,7 +483,7 @@ mod tests {
         let header = require_header(&generated.methods[0]).unwrap();
         assert_str_eq!(header, "Q_SIGNAL void dataChanged(A1 mapped);");
 
-        assert_eq!(generated.fragments.len(), 1);
+        assert_eq!(generated.fragments.len(), 2);
         let (header, source) = require_pair(&generated.fragments[0]).unwrap();
 
         assert_str_eq!(
@@ -373,6 +541,92 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_cpp_signals_private_signal() {
+        let method: ForeignItemFn = parse_quote! {
+            #[private_signal]
+            fn data_changed(self: Pin<&mut MyObject>, trivial: i32);
+        };
+        let signal = ParsedSignal::mock(&method);
+        let signals = vec![&signal];
+        let qobject_idents = create_qobjectname();
+        let generated =
+            generate_cpp_signals(&signals, &qobject_idents, &TypeNames::mock()).unwrap();
+
+        assert_eq!(generated.methods.len(), 2);
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "Q_SIGNAL void dataChanged(::std::int32_t trivial, QPrivateSignal);"
+        );
+        let header = require_header(&generated.methods[1]).unwrap();
+        assert_str_eq!(
+            header,
+            "friend void ::rust::cxxqtgen1::MyObject_dataChangedEmit(MyObject& self, ::std::int32_t trivial);"
+        );
+
+        assert_eq!(generated.fragments.len(), 3);
+        let (header, source) = require_pair(&generated.fragments[0]).unwrap();
+        assert_str_eq!(
+            header,
+            indoc! {r#"
+            namespace rust::cxxqtgen1 {
+            void
+            MyObject_dataChangedEmit(MyObject& self, ::std::int32_t trivial);
+            } // namespace rust::cxxqtgen1
+            "#}
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            namespace rust::cxxqtgen1 {
+            void
+            MyObject_dataChangedEmit(MyObject& self, ::std::int32_t trivial)
+            {
+                Q_EMIT self.dataChanged(::std::move(trivial), MyObject::QPrivateSignal());
+            }
+            } // namespace rust::cxxqtgen1
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_signals_qt_since() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qt(since = "6.5")]
+            fn ready(self: Pin<&mut MyObject>);
+        };
+        let signal = ParsedSignal::mock(&method);
+        let signals = vec![&signal];
+        let qobject_idents = create_qobjectname();
+        let generated =
+            generate_cpp_signals(&signals, &qobject_idents, &TypeNames::mock()).unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            indoc! {"
+            #if QT_VERSION >= QT_VERSION_CHECK(6, 5, 0)
+            Q_SIGNAL void ready();
+            #endif"}
+        );
+
+        assert_eq!(generated.forward_declares_namespaced.len(), 1);
+        assert!(generated.forward_declares_namespaced[0]
+            .starts_with("#if QT_VERSION >= QT_VERSION_CHECK(6, 5, 0)\n"));
+        assert!(generated.forward_declares_namespaced[0].ends_with("\n#endif\n"));
+
+        assert_eq!(generated.fragments.len(), 2);
+        for fragment in &generated.fragments {
+            let (header, source) = require_pair(fragment).unwrap();
+            for code in [header, source] {
+                assert!(code.starts_with("#if QT_VERSION >= QT_VERSION_CHECK(6, 5, 0)\n"));
+                assert!(code.ends_with("\n#endif\n"));
+            }
+        }
+    }
+
     #[test]
     fn test_generate_cpp_signals_existing_cxx_name() {
         let method: ForeignItemFn = parse_quote! {
@@ -390,7 +644,7 @@ mod tests {
             generate_cpp_signals(&signals, &qobject_idents, &TypeNames::mock()).unwrap();
 
         assert_eq!(generated.methods.len(), 0);
-        assert_eq!(generated.fragments.len(), 1);
+        assert_eq!(generated.fragments.len(), 2);
 
         let (header, source) = require_pair(&generated.fragments[0]).unwrap();
         assert_str_eq!(
@@ -448,6 +702,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_cpp_signals_overloaded() {
+        let method: ForeignItemFn = parse_quote! {
+            #[cxx_name = "valueChanged"]
+            fn value_changed_int(self: Pin<&mut MyObject>, value: i32);
+        };
+        let signal = ParsedSignal {
+            overloaded: true,
+            ..ParsedSignal::mock(&method)
+        };
+
+        let signals = vec![&signal];
+        let qobject_idents = create_qobjectname();
+        let generated =
+            generate_cpp_signals(&signals, &qobject_idents, &TypeNames::mock()).unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(header, "Q_SIGNAL void valueChanged(::std::int32_t value);");
+
+        assert_eq!(generated.fragments.len(), 2);
+        let (header, source) = require_pair(&generated.fragments[0]).unwrap();
+        assert_str_eq!(
+            header,
+            indoc! {r#"
+            namespace rust::cxxqtgen1 {
+            ::QMetaObject::Connection
+            MyObject_value_changed_intConnect(MyObject& self, ::rust::cxxqtgen1::MyObjectCxxQtSignalHandlervalue_changed_int closure, ::Qt::ConnectionType type);
+            } // namespace rust::cxxqtgen1
+            "#}
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            // Define namespace otherwise we hit a GCC bug
+            // https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+            namespace rust::cxxqt1 {
+            template <>
+            SignalHandler<::rust::cxxqtgen1::MyObjectCxxQtSignalParamsvalue_changed_int *>::~SignalHandler() noexcept
+            {
+                if (data[0] == nullptr && data[1] == nullptr)
+                {
+                    return;
+                }
+
+                drop_MyObject_signal_handler_value_changed_int(::std::move(*this));
+            }
+
+            template <>
+            template <>
+            void SignalHandler<::rust::cxxqtgen1::MyObjectCxxQtSignalParamsvalue_changed_int *>::operator()<MyObject&, ::std::int32_t>(MyObject& self, ::std::int32_t value)
+            {
+                call_MyObject_signal_handler_value_changed_int(*this, self, ::std::move(value));
+            }
+
+            static_assert(alignof(SignalHandler<::rust::cxxqtgen1::MyObjectCxxQtSignalParamsvalue_changed_int *>) <= alignof(::std::size_t), "unexpected aligment");
+            static_assert(sizeof(SignalHandler<::rust::cxxqtgen1::MyObjectCxxQtSignalParamsvalue_changed_int *>) == sizeof(::std::size_t[2]), "unexpected size");
+            } // namespace rust::cxxqt1
+
+            namespace rust::cxxqtgen1 {
+            ::QMetaObject::Connection
+            MyObject_value_changed_intConnect(MyObject& self, ::rust::cxxqtgen1::MyObjectCxxQtSignalHandlervalue_changed_int closure, ::Qt::ConnectionType type)
+            {
+                return ::QObject::connect(
+                    &self,
+                    QOverload<::std::int32_t>::of(&MyObject::valueChanged),
+                    &self,
+                    [&, closure = ::std::move(closure)](::std::int32_t value) mutable {
+                        closure.template operator()<MyObject&, ::std::int32_t>(self, ::std::move(value));
+                    },
+                    type);
+            }
+            } // namespace rust::cxxqtgen1
+            "#}
+        );
+    }
+
     #[test]
     fn test_generate_cpp_signal_free() {
         let method: ForeignItemFn = parse_quote! {
@@ -465,7 +796,7 @@ mod tests {
 
         assert_eq!(generated.methods.len(), 0);
 
-        assert_eq!(generated.fragments.len(), 1);
+        assert_eq!(generated.fragments.len(), 2);
         let (header, source) = require_pair(&generated.fragments[0]).unwrap();
 
         assert_str_eq!(
@@ -542,7 +873,7 @@ mod tests {
 
         assert_eq!(generated.methods.len(), 0);
 
-        assert_eq!(generated.fragments.len(), 1);
+        assert_eq!(generated.fragments.len(), 2);
         let (header, source) = require_pair(&generated.fragments[0]).unwrap();
 
         assert_str_eq!(
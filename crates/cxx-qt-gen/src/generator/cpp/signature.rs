@@ -0,0 +1,48 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks};
+use indoc::formatdoc;
+
+pub fn generate(namespace_internals: &str, signature_hash: u64) -> GeneratedCppQObjectBlocks {
+    let mut result = GeneratedCppQObjectBlocks::default();
+
+    // The function is defined by the Rust side of the bridge with the hash in its name.
+    // Calling it from a static initializer ensures that every translation unit including
+    // this header refers to the symbol, so a stale header fails to link.
+    result.fragments.push(CppFragment::Header(formatdoc! {
+        r#"
+        namespace {namespace_internals} {{
+        [[maybe_unused]] static const bool cxxQtSignatureCheck = (cxxQtSignature{signature_hash:016x}(), true);
+        }} // namespace {namespace_internals}
+        "#
+    }));
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::cpp::property::tests::require_header;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+
+    #[test]
+    fn test_generate_cpp_signature() {
+        let generated = generate("cxx_qt::my_object::cxx_qt_my_object", 0x0123_4567_89ab_cdef);
+
+        assert_eq!(generated.fragments.len(), 1);
+        assert_str_eq!(
+            require_header(&generated.fragments[0]).unwrap(),
+            indoc! {r#"
+            namespace cxx_qt::my_object::cxx_qt_my_object {
+            [[maybe_unused]] static const bool cxxQtSignatureCheck = (cxxQtSignature0123456789abcdef(), true);
+            } // namespace cxx_qt::my_object::cxx_qt_my_object
+            "#}
+        );
+    }
+}
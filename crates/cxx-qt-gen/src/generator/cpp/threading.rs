@@ -10,7 +10,10 @@ use crate::generator::{
 use indoc::formatdoc;
 use syn::Result;
 
-pub fn generate(qobject_idents: &QObjectNames) -> Result<(String, GeneratedCppQObjectBlocks)> {
+pub fn generate(
+    qobject_idents: &QObjectNames,
+    base_class: &str,
+) -> Result<(String, GeneratedCppQObjectBlocks)> {
     let mut result = GeneratedCppQObjectBlocks::default();
 
     let cpp_class = &qobject_idents.name.cxx_unqualified();
@@ -28,6 +31,22 @@ pub fn generate(qobject_idents: &QObjectNames) -> Result<(String, GeneratedCppQO
         "#
     }));
 
+    // The closures queued with CxxQtThread are posted as events to the QObject
+    result.methods.push(CppFragment::Pair {
+        header: "void customEvent(QEvent* event) override;".to_owned(),
+        source: formatdoc! {
+            r#"
+            void
+            {cpp_class}::customEvent(QEvent* event)
+            {{
+              if (!::rust::cxxqt1::cxxQtThreadEvent(event)) {{
+                {base_class}::customEvent(event);
+              }}
+            }}
+            "#
+        },
+    });
+
     result
         .includes
         .insert("#include <cxx-qt/threading.h>".to_owned());
@@ -45,7 +64,7 @@ pub fn generate(qobject_idents: &QObjectNames) -> Result<(String, GeneratedCppQO
 mod tests {
     use super::*;
 
-    use crate::generator::cpp::property::tests::require_source;
+    use crate::generator::cpp::property::tests::{require_pair, require_source};
     use crate::generator::naming::qobject::tests::create_qobjectname;
     use indoc::indoc;
     use pretty_assertions::assert_str_eq;
@@ -54,7 +73,7 @@ mod tests {
     fn test_generate_cpp_threading() {
         let qobject_idents = create_qobjectname();
 
-        let (initializer, generated) = generate(&qobject_idents).unwrap();
+        let (initializer, generated) = generate(&qobject_idents, "QObject").unwrap();
 
         // forward declares
         assert_eq!(generated.forward_declares.len(), 1);
@@ -71,7 +90,7 @@ mod tests {
         );
 
         // methods
-        assert_eq!(generated.methods.len(), 1);
+        assert_eq!(generated.methods.len(), 2);
 
         let source = require_source(&generated.methods[0]).unwrap();
         assert_str_eq!(
@@ -82,6 +101,21 @@ mod tests {
             "#}
         );
 
+        let (header, source) = require_pair(&generated.methods[1]).unwrap();
+        assert_str_eq!(header, "void customEvent(QEvent* event) override;");
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+            void
+            MyObject::customEvent(QEvent* event)
+            {
+              if (!::rust::cxxqt1::cxxQtThreadEvent(event)) {
+                QObject::customEvent(event);
+              }
+            }
+            "#}
+        );
+
         // includes
         assert_eq!(generated.includes.len(), 1);
         assert!(generated.includes.contains("#include <cxx-qt/threading.h>"));
@@ -3,6 +3,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::syntax::expr::expr_to_string;
+use syn::{Attribute, Meta};
+
 /// A trait to allow indenting multi-line string
 /// This is specifically useful when using formatdoc! with a multi-line string argument.
 /// As the formatdoc! formatting doesn't support indenting multi-line arguments, we can indent
@@ -20,12 +23,43 @@ impl Indent for str {
     }
 }
 
+/// Convert the given `#[doc = "..."]` attributes into a Doxygen style comment
+///
+/// The comment ends with a newline so that it can be prepended to a declaration,
+/// if there are no docs then an empty string is returned.
+pub(crate) fn doc_comment(docs: &[Attribute]) -> String {
+    docs.iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(name_value) => expr_to_string(&name_value.value).ok(),
+            _ => None,
+        })
+        .flat_map(|doc| {
+            doc.split('\n')
+                // A trailing backslash would continue the comment onto the next line of C++,
+                // even if it is followed by whitespace, so it is removed
+                .map(|line| {
+                    line.trim_end_matches(|char: char| char == '\\' || char.is_whitespace())
+                })
+                // Rust doc comments are usually written with a space after the slashes
+                .map(|line| {
+                    if line.is_empty() || line.starts_with(' ') {
+                        format!("///{line}\n")
+                    } else {
+                        format!("/// {line}\n")
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use indoc::{formatdoc, indoc};
     use pretty_assertions::assert_str_eq;
+    use syn::parse_quote;
 
     #[test]
     fn indent_string() {
@@ -48,4 +82,27 @@ mod tests {
         "#}
         );
     }
+
+    #[test]
+    fn test_doc_comment() {
+        assert_str_eq!(doc_comment(&[]), "");
+        assert_str_eq!(
+            doc_comment(&[
+                parse_quote! { #[doc = " The first line"] },
+                parse_quote! { #[doc = ""] },
+                parse_quote! { #[doc = "A multi-line\n    doc with an indented line"] },
+                parse_quote! { #[doc = r" A path like C:\\"] },
+                parse_quote! { #[doc = " A continued line \\ \nfollowed by another line"] },
+            ]),
+            indoc! { r#"
+            /// The first line
+            ///
+            /// A multi-line
+            ///    doc with an indented line
+            /// A path like C:
+            /// A continued line
+            /// followed by another line
+        "#}
+        );
+    }
 }
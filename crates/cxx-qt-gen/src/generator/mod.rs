@@ -7,6 +7,7 @@
 use syn::{parse_quote, ItemMod};
 pub mod cpp;
 pub mod naming;
+pub mod rep;
 pub mod rust;
 pub mod structuring;
 
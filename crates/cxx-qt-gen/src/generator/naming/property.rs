@@ -3,12 +3,15 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use crate::{
+    generator::naming::signals::helper_namespace as helper_namespace_for,
     naming::Name,
     parser::property::{FlagState, ParsedQProperty},
 };
 use convert_case::{Case, Casing};
 use quote::format_ident;
-use syn::{Ident, Result};
+#[cfg(test)]
+use syn::Ident;
+use syn::Result;
 
 use crate::generator::structuring::StructuredQObject;
 use core::ops::Deref;
@@ -117,16 +120,97 @@ impl QPropertyNames {
     }
 }
 
+/// Names for parts of an existing Q_PROPERTY which is declared in an extern "C++Qt" block
+///
+/// The getter is named after the property as is usual for Qt classes, and the custom names
+/// in the flags refer to the existing C++ methods and signal instead of Rust methods.
+pub struct QPropertyExternNames {
+    pub name: Name,
+    pub getter: Name,
+    pub setter: Option<Name>,
+    pub notify: Option<Name>,
+}
+
+impl From<&ParsedQProperty> for QPropertyExternNames {
+    fn from(property: &ParsedQProperty) -> Self {
+        let property_name = &property.name;
+        let flags = &property.flags;
+
+        Self {
+            getter: extern_name_from_flag(&flags.read, || property_name.clone()),
+            setter: flags.write.as_ref().map(|setter| {
+                extern_name_from_flag(setter, || setter_name_from_property(property_name))
+            }),
+            notify: flags.notify.as_ref().map(|notify| {
+                extern_name_from_flag(notify, || notify_name_from_property(property_name))
+            }),
+            name: property_name.clone(),
+        }
+    }
+}
+
+/// Names of the free functions which call the accessors of an existing Q_PROPERTY in C++
+pub struct QPropertyExternHelperNames {
+    pub getter: Name,
+    pub setter: Option<Name>,
+    pub namespace: String,
+}
+
+impl QPropertyExternHelperNames {
+    pub fn new(
+        idents: &QPropertyExternNames,
+        qobject_name: &Name,
+        helper_namespace: Option<&str>,
+    ) -> Self {
+        let helper_name = |suffix: &str| {
+            Name::new(format_ident!(
+                "{}_{}_{}",
+                qobject_name.rust_unqualified(),
+                idents.name.rust_unqualified(),
+                suffix.to_case(Case::Snake)
+            ))
+            .with_cxx_name(format!(
+                "{}_{}{suffix}",
+                qobject_name.cxx_unqualified(),
+                idents.name.cxx_unqualified()
+            ))
+        };
+
+        Self {
+            getter: helper_name("Getter"),
+            setter: idents.setter.as_ref().map(|_| helper_name("Setter")),
+            namespace: helper_namespace_for(qobject_name, helper_namespace),
+        }
+    }
+}
+
+/// Use the auto name, or the snake case of the C++ name for a custom flag
+fn extern_name_from_flag(state: &FlagState, auto_fn: impl Fn() -> Name) -> Name {
+    match state {
+        FlagState::Auto => auto_fn(),
+        FlagState::Custom(ident) => {
+            Name::new(format_ident!("{}", ident.to_string().to_case(Case::Snake)))
+                .with_cxx_name(ident.to_string())
+        }
+    }
+}
+
+/// Create the name of a property with a camelCase C++ name, as ParsedQProperty does by default
+#[cfg(test)]
 pub fn property_name_from_rust_name(ident: Ident) -> Name {
-    // TODO: ParsedQProperty should probably take care of this already and allow the user to set
-    // their own name for C++ if they want to.
-    // REMOVE THIS FN
     let cxx_name = ident.to_string().to_case(Case::Camel);
     Name::new(ident).with_cxx_name(cxx_name)
 }
 
 /// For a given property name generate the getter name
+///
+/// If the property has no C++ name, as camelCase conversion is disabled,
+/// then the C++ names of the getter, setter, and notify signal are the Rust names.
 fn getter_name_from_property(name: &Name) -> Name {
+    if !name.has_cxx_name() {
+        return name.clone();
+    }
+
     name.clone().with_cxx_name(format!(
         "get{}",
         name.cxx_unqualified().to_case(Case::Pascal)
@@ -135,19 +219,29 @@ fn getter_name_from_property(name: &Name) -> Name {
 
 /// For a given property name generate the setter name
 fn setter_name_from_property(name: &Name) -> Name {
-    name.clone()
-        .with_rust_name(format_ident!("set_{}", name.rust_unqualified()))
-        .with_cxx_name(format!(
-            "set{}",
-            name.cxx_unqualified().to_case(Case::Pascal)
-        ))
+    let setter = name
+        .clone()
+        .with_rust_name(format_ident!("set_{}", name.rust_unqualified()));
+    if !name.has_cxx_name() {
+        return setter;
+    }
+
+    setter.with_cxx_name(format!(
+        "set{}",
+        name.cxx_unqualified().to_case(Case::Pascal)
+    ))
 }
 
 /// For a given property name generate the notify signal name
 fn notify_name_from_property(name: &Name) -> Name {
-    name.clone()
-        .with_rust_name(format_ident!("{}_changed", name.rust_unqualified()))
-        .with_cxx_name(format!("{}Changed", name.cxx_unqualified()))
+    let notify = name
+        .clone()
+        .with_rust_name(format_ident!("{}_changed", name.rust_unqualified()));
+    if !name.has_cxx_name() {
+        return notify;
+    }
+
+    notify.with_cxx_name(format!("{}Changed", name.cxx_unqualified()))
 }
 
 #[cfg(test)]
@@ -155,6 +249,7 @@ pub mod tests {
     use syn::parse_quote;
 
     use super::*;
+    use crate::naming::AutoCamel;
     use crate::parser::property::QPropertyFlags;
     use crate::parser::qobject::ParsedQObject;
 
@@ -163,6 +258,8 @@ pub mod tests {
             name: property_name_from_rust_name(format_ident!("my_property")),
             ty: parse_quote! { i32 },
             flags: QPropertyFlags::default(),
+            cfgs: vec![],
+            docs: vec![],
         };
 
         let obj = ParsedQObject::mock();
@@ -196,4 +293,102 @@ pub mod tests {
             "my_property_changed"
         );
     }
+
+    #[test]
+    fn test_extern_property() {
+        let property = ParsedQProperty::parse(
+            &parse_quote! {
+                #[qproperty(bool, enabled, READ = isEnabled, WRITE, NOTIFY = enabledStateChanged)]
+            },
+            AutoCamel::Enabled,
+        )
+        .unwrap();
+
+        let names = QPropertyExternNames::from(&property);
+        assert_eq!(names.getter.cxx_unqualified(), "isEnabled");
+        assert_eq!(names.getter.rust_unqualified(), "is_enabled");
+        assert_eq!(
+            names.setter.as_ref().unwrap().cxx_unqualified(),
+            "setEnabled"
+        );
+        assert_eq!(
+            names.setter.as_ref().unwrap().rust_unqualified(),
+            "set_enabled"
+        );
+        assert_eq!(
+            names.notify.as_ref().unwrap().cxx_unqualified(),
+            "enabledStateChanged"
+        );
+        assert_eq!(
+            names.notify.as_ref().unwrap().rust_unqualified(),
+            "enabled_state_changed"
+        );
+    }
+
+    #[test]
+    fn test_property_auto_camel_disabled() {
+        let property = ParsedQProperty::parse(
+            &parse_quote! {
+                #[qproperty(i32, my_property)]
+            },
+            AutoCamel::Disabled,
+        )
+        .unwrap();
+
+        let obj = ParsedQObject::mock();
+        let structured_qobject = StructuredQObject::mock(&obj);
+        let names = QPropertyNames::try_from_property(&property, &structured_qobject).unwrap();
+        assert_eq!(names.name.cxx_unqualified(), "my_property");
+        assert_eq!(names.getter.cxx_unqualified(), "my_property");
+        assert_eq!(
+            names.setter.as_ref().unwrap().cxx_unqualified(),
+            "set_my_property"
+        );
+        assert_eq!(
+            names.notify.as_ref().unwrap().cxx_unqualified(),
+            "my_property_changed"
+        );
+    }
+
+    #[test]
+    fn test_extern_property_auto() {
+        let property = ParsedQProperty::parse(
+            &parse_quote! {
+                #[qproperty(i32, my_property)]
+            },
+            AutoCamel::Enabled,
+        )
+        .unwrap();
+
+        let names = QPropertyExternNames::from(&property);
+        assert_eq!(names.getter.cxx_unqualified(), "myProperty");
+        assert_eq!(names.getter.rust_unqualified(), "my_property");
+        assert_eq!(
+            names.setter.as_ref().unwrap().cxx_unqualified(),
+            "setMyProperty"
+        );
+        assert_eq!(
+            names.notify.as_ref().unwrap().cxx_unqualified(),
+            "myPropertyChanged"
+        );
+
+        let helpers = QPropertyExternHelperNames::new(
+            &names,
+            &Name::new(format_ident!("MyObject")).with_namespace("my_namespace".to_owned()),
+            Some("my_crate"),
+        );
+        assert_eq!(
+            helpers.getter.cxx_unqualified(),
+            "MyObject_myPropertyGetter"
+        );
+        assert_eq!(
+            helpers.getter.rust_unqualified(),
+            "MyObject_my_property_getter"
+        );
+        assert_eq!(
+            helpers.setter.as_ref().unwrap().cxx_unqualified(),
+            "MyObject_myPropertySetter"
+        );
+        assert_eq!(helpers.namespace, "my_namespace::rust::cxxqtgen1::my_crate");
+    }
 }
@@ -11,7 +11,11 @@ use syn::{Ident, Result};
 pub struct QSignalNames {
     pub name: Name,
     pub connect_name: Name,
+    pub connect_with_context_name: Ident,
     pub on_name: Ident,
+    pub on_with_context_name: Ident,
+    pub overloaded: bool,
+    pub helper_namespace: Option<String>,
 }
 
 impl From<&ParsedSignal> for QSignalNames {
@@ -19,7 +23,17 @@ impl From<&ParsedSignal> for QSignalNames {
         Self {
             name: signal.name.clone(),
             connect_name: connect_name_from_signal(&signal.name),
+            connect_with_context_name: format_ident!(
+                "connect_{}_with_context",
+                signal.name.rust_unqualified()
+            ),
             on_name: on_from_signal(signal.name.rust_unqualified()),
+            on_with_context_name: format_ident!(
+                "{}_with_context",
+                on_from_signal(signal.name.rust_unqualified())
+            ),
+            overloaded: signal.overloaded,
+            helper_namespace: signal.helper_namespace.clone(),
         }
     }
 }
@@ -38,6 +52,8 @@ fn on_from_signal(ident: &Ident) -> Ident {
 
 pub struct QSignalHelperNames {
     pub connect_name: Name,
+    pub connect_with_context_name: Name,
+    pub emit_name: Name,
     pub function_call: Ident,
     pub function_drop: Ident,
     pub handler_alias: Ident,
@@ -49,29 +65,15 @@ pub struct QSignalHelperNames {
 
 impl QSignalHelperNames {
     pub fn new(idents: &QSignalNames, qobject_name: &Name) -> Result<Self> {
-        let signal_ident = &idents.name.cxx_unqualified();
+        // Overloaded signals share the same C++ name, so use the unique Rust name for the helpers
+        let signal_ident = &if idents.overloaded {
+            idents.name.rust_unqualified().to_string()
+        } else {
+            idents.name.cxx_unqualified()
+        };
         let qobject_ident = qobject_name.rust_unqualified().to_string();
         let handler_alias = format_ident!("{qobject_ident}CxxQtSignalHandler{signal_ident}");
-        let namespace = {
-            // This namespace will take the form of:
-            // qobject_namespace::rust::cxxqtgen1
-            //
-            // We experimented with using rust::cxxqtgen1::qobject_namespace.
-            // However, this currently doesn't work, as we can't fully-qualify all C++ access.
-            // Therefore when refering to the QObject type (e.g. qobject_namespace::QObject),
-            // It would fail, as it would look up in this helper namespace, instead of the actual
-            // qobject_namespace.
-            //
-            // See the comment on TypeNames::cxx_qualified for why fully qualifying is
-            // unfortunately not possible.
-            let qobject_namespace = qobject_name.namespace();
-            let namespace: Vec<_> = qobject_namespace
-                .into_iter()
-                .chain(vec!["rust::cxxqtgen1"])
-                .collect();
-
-            namespace.join("::")
-        };
+        let namespace = helper_namespace(qobject_name, idents.helper_namespace.as_deref());
 
         let connect_name = Name::new(format_ident!(
             "{}_{}",
@@ -79,15 +81,34 @@ impl QSignalHelperNames {
             idents.connect_name.rust_unqualified()
         ))
         .with_cxx_name(format!(
+            "{}_{signal_ident}Connect",
+            qobject_name.cxx_unqualified()
+        ));
+
+        let connect_with_context_name = Name::new(format_ident!(
             "{}_{}",
-            qobject_name.cxx_unqualified(),
-            idents.connect_name.cxx_unqualified()
+            qobject_name.rust_unqualified(),
+            idents.connect_with_context_name
+        ))
+        .with_cxx_name(format!(
+            "{}_{signal_ident}ConnectWithContext",
+            qobject_name.cxx_unqualified()
+        ));
+
+        let emit_name = Name::new(format_ident!(
+            "{}_emit_{}",
+            qobject_name.rust_unqualified(),
+            idents.name.rust_unqualified()
+        ))
+        .with_cxx_name(format!(
+            "{}_{signal_ident}Emit",
+            qobject_name.cxx_unqualified()
         ));
 
-        // TODO: in the future we might improve the naming of the methods
-        // to avoid collisions (maybe use a separator similar to how CXX uses $?)
         Ok(Self {
             connect_name,
+            connect_with_context_name,
+            emit_name,
             function_drop: format_ident!("drop_{qobject_ident}_signal_handler_{signal_ident}"),
             function_call: format_ident!("call_{qobject_ident}_signal_handler_{signal_ident}"),
             handler_alias_namespaced: format!("::{namespace}::{handler_alias}"),
@@ -99,6 +120,32 @@ impl QSignalHelperNames {
     }
 }
 
+/// The namespace of the generated helpers for an existing QObject
+pub fn helper_namespace(qobject_name: &Name, helper_namespace: Option<&str>) -> String {
+    // This namespace will take the form of:
+    // qobject_namespace::rust::cxxqtgen1::helper_namespace
+    //
+    // The helper namespace is used for extern "C++Qt" blocks and allows for bridges which
+    // declare signals on the same existing QObject to not define the same helper symbols.
+    //
+    // We experimented with using rust::cxxqtgen1::qobject_namespace.
+    // However, this currently doesn't work, as we can't fully-qualify all C++ access.
+    // Therefore when refering to the QObject type (e.g. qobject_namespace::QObject),
+    // It would fail, as it would look up in this helper namespace, instead of the actual
+    // qobject_namespace.
+    //
+    // See the comment on TypeNames::cxx_qualified for why fully qualifying is
+    // unfortunately not possible.
+    let qobject_namespace = qobject_name.namespace();
+    let namespace: Vec<_> = qobject_namespace
+        .into_iter()
+        .chain(vec!["rust::cxxqtgen1"])
+        .chain(helper_namespace)
+        .collect();
+
+    namespace.join("::")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -123,7 +170,15 @@ mod tests {
             names.connect_name.rust_unqualified(),
             &format_ident!("connect_data_changed")
         );
+        assert_eq!(
+            names.connect_with_context_name,
+            format_ident!("connect_data_changed_with_context")
+        );
         assert_eq!(names.on_name, format_ident!("on_data_changed"));
+        assert_eq!(
+            names.on_with_context_name,
+            format_ident!("on_data_changed_with_context")
+        );
     }
 
     #[test]
@@ -147,4 +202,60 @@ mod tests {
         );
         assert_eq!(names.on_name, format_ident!("on_existing_signal"));
     }
+
+    #[test]
+    fn test_signal_helper_overloaded() {
+        let method = parse_quote! {
+            #[cxx_name = "valueChanged"]
+            fn value_changed_int(self: Pin<&mut MyObject>, value: i32);
+        };
+        let qsignal = ParsedSignal {
+            overloaded: true,
+            ..ParsedSignal::mock(&method)
+        };
+        let qobject_name = Name::new(format_ident!("MyObject"));
+
+        let names = QSignalNames::from(&qsignal);
+        let helper_names = QSignalHelperNames::new(&names, &qobject_name).unwrap();
+        assert_eq!(
+            helper_names.connect_name.cxx_unqualified(),
+            "MyObject_value_changed_intConnect"
+        );
+        assert_eq!(
+            helper_names.connect_with_context_name.cxx_unqualified(),
+            "MyObject_value_changed_intConnectWithContext"
+        );
+        assert_eq!(
+            helper_names.handler_alias,
+            format_ident!("MyObjectCxxQtSignalHandlervalue_changed_int")
+        );
+        assert_eq!(
+            helper_names.struct_closure,
+            format_ident!("MyObjectCxxQtSignalClosurevalue_changed_int")
+        );
+    }
+
+    #[test]
+    fn test_signal_helper_namespace() {
+        let method = parse_quote! {
+            fn timeout(self: Pin<&mut QTimer>);
+        };
+        let qsignal = ParsedSignal {
+            helper_namespace: Some("my_crate".to_owned()),
+            ..ParsedSignal::mock(&method)
+        };
+        let qobject_name = Name::new(format_ident!("QTimer"));
+
+        let names = QSignalNames::from(&qsignal);
+        let helper_names = QSignalHelperNames::new(&names, &qobject_name).unwrap();
+        assert_eq!(helper_names.namespace, "rust::cxxqtgen1::my_crate");
+        assert_eq!(
+            helper_names.handler_alias_namespaced,
+            "::rust::cxxqtgen1::my_crate::QTimerCxxQtSignalHandlertimeout"
+        );
+        assert_eq!(
+            helper_names.connect_name.cxx_unqualified(),
+            "QTimer_timeoutConnect"
+        );
+    }
 }
@@ -0,0 +1,234 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        cpp::get_cpp_params,
+        naming::property::{NameState, QPropertyNames},
+        structuring::{StructuredQObject, Structures},
+    },
+    naming::{
+        cpp::{syn_type_to_cpp_return_type, syn_type_to_cpp_type},
+        TypeNames,
+    },
+    parser::Parser,
+};
+use syn::{Error, Result};
+
+/// Representation of a QObject as a class of a Qt Remote Objects `.rep` file
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct GeneratedRepClass {
+    /// The C++ name of the QObject
+    pub name: String,
+    /// The `PROP` declarations of the class
+    pub properties: Vec<String>,
+    /// The `SIGNAL` declarations of the class
+    pub signals: Vec<String>,
+    /// The `SLOT` declarations of the class
+    pub slots: Vec<String>,
+}
+
+impl GeneratedRepClass {
+    fn from(structured_qobject: &StructuredQObject, type_names: &TypeNames) -> Result<Self> {
+        let qobject = structured_qobject.declaration;
+        let mut class = Self {
+            name: qobject.name.cxx_unqualified(),
+            ..Default::default()
+        };
+
+        for property in &qobject.properties {
+            let idents = QPropertyNames::try_from_property(property, structured_qobject)?;
+            // A replica always notifies with a <property>Changed signal
+            if let Some(NameState::Custom(notify)) = &idents.notify {
+                return Err(Error::new_spanned(
+                    notify.rust_unqualified(),
+                    "Properties with a custom notify signal cannot be written to a .rep file",
+                ));
+            }
+
+            let modifier = if property.flags.constant {
+                "CONSTANT"
+            } else if idents.setter.is_some() {
+                "READWRITE"
+            } else {
+                "READONLY"
+            };
+            class.properties.push(format!(
+                "PROP({ty} {ident} {modifier});",
+                ty = syn_type_to_cpp_type(&property.ty, type_names)?,
+                ident = idents.name.cxx_unqualified(),
+            ));
+        }
+
+        // Inherited signals belong to the base class and private signals cannot be
+        // emitted by a replica, so neither is part of the remote interface
+        for signal in structured_qobject
+            .signals
+            .iter()
+            .filter(|signal| !signal.inherit && !signal.private_signal)
+        {
+            let parameters = signal
+                .parameters
+                .iter()
+                .map(|parameter| {
+                    Ok(format!(
+                        "{ty} {ident}",
+                        ty = syn_type_to_cpp_type(&parameter.ty, type_names)?,
+                        ident = parameter.ident
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            class.signals.push(format!(
+                "SIGNAL({ident}({parameters}));",
+                ident = signal.name.cxx_unqualified(),
+                parameters = parameters.join(", ")
+            ));
+        }
+
+        for method in structured_qobject
+            .methods
+            .iter()
+            .filter(|method| method.is_qinvokable)
+        {
+            let return_ty = syn_type_to_cpp_return_type(&method.method.sig.output, type_names)?;
+            let parameters = get_cpp_params(&method.method, type_names)?
+                .into_iter()
+                .map(|parameter| format!("{} {}", parameter.ty, parameter.ident))
+                .collect::<Vec<_>>();
+            class.slots.push(format!(
+                "SLOT({return_ty} {ident}({parameters}));",
+                return_ty = return_ty.as_deref().unwrap_or("void"),
+                ident = method.name.cxx_unqualified(),
+                parameters = parameters.join(", ")
+            ));
+        }
+
+        Ok(class)
+    }
+}
+
+/// Representation of the Qt Remote Objects `.rep` file for a group of QObjects
+///
+/// The `.rep` file can be compiled by `repc` into a static replica of the QObjects,
+/// so that other processes can acquire them with compile time checked types.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct GeneratedRepBlocks {
+    /// The classes of the QObjects
+    pub classes: Vec<GeneratedRepClass>,
+}
+
+impl GeneratedRepBlocks {
+    /// Create a [GeneratedRepBlocks] from the given [Parser] object
+    pub fn from(parser: &Parser) -> Result<GeneratedRepBlocks> {
+        let structures = Structures::new(&parser.cxx_qt_data)?;
+
+        Ok(GeneratedRepBlocks {
+            classes: structures
+                .qobjects
+                .iter()
+                .filter(|qobject| qobject.declaration.has_qobject_macro)
+                .map(|qobject| GeneratedRepClass::from(qobject, &parser.type_names))
+                .collect::<Result<Vec<_>>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::{parse_quote, ItemMod};
+
+    fn generate(module: ItemMod) -> Result<GeneratedRepBlocks> {
+        GeneratedRepBlocks::from(&Parser::from(module).unwrap())
+    }
+
+    #[test]
+    fn test_generated_rep_blocks() {
+        let generated = generate(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qstring.h");
+                    type QString = cxx_qt_lib::QString;
+                }
+
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, number)]
+                    #[qproperty(QString, name, READ)]
+                    #[qproperty(f64, ratio, READ, CONSTANT)]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qsignal]
+                    fn ready(self: Pin<&mut MyObject>);
+
+                    #[qsignal]
+                    fn data_changed(self: Pin<&mut MyObject>, first: i32, second: &QString);
+
+                    #[inherit]
+                    #[qsignal]
+                    fn object_name_changed(self: Pin<&mut MyObject>);
+
+                    #[qinvokable]
+                    fn say_hi(self: &MyObject, string: &QString, number: i32);
+
+                    #[qinvokable]
+                    fn double_number(self: &MyObject, number: i32) -> i32;
+
+                    fn rust_only(self: &MyObject);
+                }
+
+                unsafe extern "RustQt" {
+                    #[base = QObject]
+                    type NoQObjectMacro = super::NoQObjectMacroRust;
+                }
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            generated,
+            GeneratedRepBlocks {
+                classes: vec![GeneratedRepClass {
+                    name: "MyObject".to_owned(),
+                    properties: vec![
+                        "PROP(::std::int32_t number READWRITE);".to_owned(),
+                        "PROP(QString name READONLY);".to_owned(),
+                        "PROP(double ratio CONSTANT);".to_owned(),
+                    ],
+                    signals: vec![
+                        "SIGNAL(ready());".to_owned(),
+                        "SIGNAL(dataChanged(::std::int32_t first, QString const& second));"
+                            .to_owned(),
+                    ],
+                    slots: vec![
+                        "SLOT(void sayHi(QString const& string, ::std::int32_t number));"
+                            .to_owned(),
+                        "SLOT(::std::int32_t doubleNumber(::std::int32_t number));".to_owned(),
+                    ],
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn test_generated_rep_blocks_custom_notify() {
+        assert!(generate(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, number, READ, NOTIFY = number_updated)]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qsignal]
+                    fn number_updated(self: Pin<&mut MyObject>);
+                }
+            }
+        })
+        .is_err());
+    }
+}
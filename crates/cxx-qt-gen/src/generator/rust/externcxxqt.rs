@@ -3,12 +3,15 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use crate::{
-    generator::rust::{
-        fragment::{GeneratedRustFragment, RustFragmentPair},
-        signals::generate_rust_signal,
+    generator::{
+        naming::property::{QPropertyExternHelperNames, QPropertyExternNames},
+        rust::{
+            fragment::{GeneratedRustFragment, RustFragmentPair},
+            signals::generate_rust_signal,
+        },
     },
-    naming::TypeNames,
-    parser::externcxxqt::ParsedExternCxxQt,
+    naming::{rust::syn_type_cxx_bridge_to_qualified, TypeNames},
+    parser::{externcxxqt::ParsedExternCxxQt, externqobject::ParsedExternQObject},
     syntax::path::path_compare_str,
 };
 use quote::quote;
@@ -57,11 +60,13 @@ impl GeneratedRustFragment {
                     .iter()
                     .filter(|attr| path_compare_str(attr.meta.path(), &["doc"]))
                     .collect();
+                // A QObject from another bridge is an alias, so that the Rust type is shared
+                let alias = ty.alias.as_ref().map(|path| quote! { = #path });
                 quote! {
                     #namespace
                     #cxx_name
                     #(#docs)*
-                    #vis type #ident;
+                    #vis type #ident #alias;
                 }
             })
             .collect::<Vec<_>>();
@@ -88,6 +93,255 @@ impl GeneratedRustFragment {
             generated.append(&mut generate_rust_signal(signal, qobject_name, type_names)?);
         }
 
+        // Build the accessors of the properties
+        for qobject in extern_cxxqt_block
+            .qobjects
+            .iter()
+            .filter(|qobject| !qobject.properties.is_empty())
+        {
+            generated.append(&mut generate_rust_properties(
+                qobject,
+                Some(&extern_cxxqt_block.helper_namespace),
+                type_names,
+            )?);
+        }
+
         Ok(generated)
     }
 }
+
+fn generate_rust_properties(
+    qobject: &ParsedExternQObject,
+    helper_namespace: Option<&str>,
+    type_names: &TypeNames,
+) -> Result<GeneratedRustFragment> {
+    let mut generated = GeneratedRustFragment::default();
+    let qobject_name_rust = qobject.name.rust_unqualified();
+    let qualified_impl = qobject.name.rust_qualified();
+    let module_ident = qobject.name.require_module()?;
+
+    for property in &qobject.properties {
+        let idents = QPropertyExternNames::from(property);
+        let helper_idents =
+            QPropertyExternHelperNames::new(&idents, &qobject.name, helper_namespace);
+        let namespace = &helper_idents.namespace;
+        let cxx_ty = &property.ty;
+        let qualified_ty = syn_type_cxx_bridge_to_qualified(cxx_ty, type_names)?;
+        let property_name_cpp = idents.name.cxx_unqualified();
+
+        let getter_rust = idents.getter.rust_unqualified();
+        let getter_helper_cpp = helper_idents.getter.cxx_unqualified();
+        let getter_helper_rust = helper_idents.getter.rust_unqualified();
+
+        let mut cxx_bridge = vec![quote! {
+            unsafe extern "C++" {
+                #[doc(hidden)]
+                #[namespace = #namespace]
+                #[cxx_name = #getter_helper_cpp]
+                fn #getter_helper_rust(self_value: &#qobject_name_rust) -> #cxx_ty;
+            }
+        }];
+        let mut implementation = vec![quote! {
+            impl #qualified_impl {
+                #[doc = "Getter for the Q_PROPERTY "]
+                #[doc = #property_name_cpp]
+                pub fn #getter_rust(&self) -> #qualified_ty {
+                    #module_ident::#getter_helper_rust(self)
+                }
+            }
+        }];
+
+        if let (Some(setter), Some(setter_helper)) = (&idents.setter, &helper_idents.setter) {
+            let setter_rust = setter.rust_unqualified();
+            let setter_helper_cpp = setter_helper.cxx_unqualified();
+            let setter_helper_rust = setter_helper.rust_unqualified();
+
+            cxx_bridge.push(quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[namespace = #namespace]
+                    #[cxx_name = #setter_helper_cpp]
+                    fn #setter_helper_rust(self_value: Pin<&mut #qobject_name_rust>, value: #cxx_ty);
+                }
+            });
+            implementation.push(quote! {
+                impl #qualified_impl {
+                    #[doc = "Setter for the Q_PROPERTY "]
+                    #[doc = #property_name_cpp]
+                    pub fn #setter_rust(self: core::pin::Pin<&mut Self>, value: #qualified_ty) {
+                        #module_ident::#setter_helper_rust(self, value)
+                    }
+                }
+            });
+        }
+
+        let fragment = RustFragmentPair {
+            cxx_bridge,
+            implementation,
+        };
+        generated
+            .cxx_mod_contents
+            .append(&mut fragment.cxx_bridge_as_items()?);
+        generated
+            .cxx_qt_mod_contents
+            .append(&mut fragment.implementation_as_items()?);
+    }
+
+    Ok(generated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_tokens_eq;
+    use quote::format_ident;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_rust_extern_qt_properties() {
+        let block = ParsedExternCxxQt::parse(
+            parse_quote! {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    #[qproperty(i32, interval, READ, WRITE)]
+                    type QTimer;
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        )
+        .unwrap();
+        let mut type_names = TypeNames::default();
+        type_names.mock_insert("QTimer", Some(format_ident!("qobject")), None, None);
+
+        let generated = GeneratedRustFragment::from_extern_cxx_qt(&block, &type_names).unwrap();
+        assert_eq!(generated.cxx_mod_contents.len(), 3);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtgen1::qobject"]
+                    #[cxx_name = "QTimer_intervalGetter"]
+                    fn QTimer_interval_getter(self_value: &QTimer) -> i32;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[2],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtgen1::qobject"]
+                    #[cxx_name = "QTimer_intervalSetter"]
+                    fn QTimer_interval_setter(self_value: Pin<&mut QTimer>, value: i32);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::QTimer {
+                    #[doc = "Getter for the Q_PROPERTY "]
+                    #[doc = "interval"]
+                    pub fn interval(&self) -> i32 {
+                        qobject::QTimer_interval_getter(self)
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                impl qobject::QTimer {
+                    #[doc = "Setter for the Q_PROPERTY "]
+                    #[doc = "interval"]
+                    pub fn set_interval(self: core::pin::Pin<&mut Self>, value: i32) {
+                        qobject::QTimer_interval_setter(self, value)
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_extern_qt_overloaded_methods() {
+        let block = ParsedExternCxxQt::parse(
+            parse_quote! {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    type QComboBox;
+
+                    #[cxx_name = "addItem"]
+                    fn add_item(self: Pin<&mut QComboBox>, text: &QString);
+
+                    #[cxx_name = "addItem"]
+                    fn add_item_with_icon(self: Pin<&mut QComboBox>, icon: &QIcon, text: &QString);
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        )
+        .unwrap();
+        let mut type_names = TypeNames::default();
+        type_names.mock_insert("QComboBox", Some(format_ident!("qobject")), None, None);
+
+        let generated = GeneratedRustFragment::from_extern_cxx_qt(&block, &type_names).unwrap();
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 0);
+
+        // The overloads are passed through to CXX, which selects them by their signature
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[cxx_name = "addItem"]
+                    fn add_item(self: Pin<&mut QComboBox>, text: &QString);
+
+                    #[cxx_name = "addItem"]
+                    fn add_item_with_icon(self: Pin<&mut QComboBox>, icon: &QIcon, text: &QString);
+
+                    type QComboBox;
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_extern_qt_alias() {
+        let block = ParsedExternCxxQt::parse(
+            parse_quote! {
+                unsafe extern "C++Qt" {
+                    include!("my_crate/src/other.cxxqt.h");
+
+                    #[qobject]
+                    #[namespace = "other"]
+                    type OtherObject = crate::other::qobject::OtherObject;
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        )
+        .unwrap();
+        let type_names = TypeNames::default();
+
+        let generated = GeneratedRustFragment::from_extern_cxx_qt(&block, &type_names).unwrap();
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 0);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    include!("my_crate/src/other.cxxqt.h");
+
+                    #[namespace = "other"]
+                    type OtherObject = crate::other::qobject::OtherObject;
+                }
+            },
+        );
+    }
+}
@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use proc_macro2::TokenStream;
-use syn::{Item, Result};
+use syn::{Attribute, Item, Result};
 
 #[derive(Default, Eq, PartialEq, Debug)]
 pub struct GeneratedRustFragment {
@@ -20,6 +20,44 @@ impl GeneratedRustFragment {
         self.cxx_qt_mod_contents
             .append(&mut other.cxx_qt_mod_contents);
     }
+
+    /// Add the given `#[cfg(...)]` attributes to all of the generated items,
+    /// so that they are only compiled when the item they were generated from is enabled
+    pub fn with_cfgs(mut self, cfgs: &[Attribute]) -> Self {
+        if !cfgs.is_empty() {
+            for item in self
+                .cxx_mod_contents
+                .iter_mut()
+                .chain(self.cxx_qt_mod_contents.iter_mut())
+            {
+                if let Some(attrs) = item_attrs_mut(item) {
+                    attrs.splice(0..0, cfgs.iter().cloned());
+                }
+            }
+        }
+        self
+    }
+}
+
+fn item_attrs_mut(item: &mut Item) -> Option<&mut Vec<Attribute>> {
+    match item {
+        Item::Const(item) => Some(&mut item.attrs),
+        Item::Enum(item) => Some(&mut item.attrs),
+        Item::ExternCrate(item) => Some(&mut item.attrs),
+        Item::Fn(item) => Some(&mut item.attrs),
+        Item::ForeignMod(item) => Some(&mut item.attrs),
+        Item::Impl(item) => Some(&mut item.attrs),
+        Item::Macro(item) => Some(&mut item.attrs),
+        Item::Mod(item) => Some(&mut item.attrs),
+        Item::Static(item) => Some(&mut item.attrs),
+        Item::Struct(item) => Some(&mut item.attrs),
+        Item::Trait(item) => Some(&mut item.attrs),
+        Item::TraitAlias(item) => Some(&mut item.attrs),
+        Item::Type(item) => Some(&mut item.attrs),
+        Item::Union(item) => Some(&mut item.attrs),
+        Item::Use(item) => Some(&mut item.attrs),
+        _ => None,
+    }
 }
 
 /// A generic Rust CXX bridge definition and the corresponding implementation
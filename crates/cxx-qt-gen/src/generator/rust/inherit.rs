@@ -47,9 +47,11 @@ pub fn generate(
             }
             let doc_comments = &method.docs;
             let namespace = qobject_names.namespace_tokens();
+            let cfgs = method.rust_cfgs();
 
             syn::parse2(quote_spanned! {
                 method.method.span() =>
+                #(#cfgs)*
                 #unsafe_block extern "C++" {
                     #[cxx_name = #cxx_name_string]
                     #namespace
@@ -0,0 +1,198 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        naming::qobject::QObjectNames,
+        rust::{
+            fragment::{GeneratedRustFragment, RustFragmentPair},
+            signals::generate_rust_signals,
+        },
+    },
+    naming::TypeNames,
+    parser::signals::ParsedSignal,
+    syntax::safety::Safety,
+};
+use quote::quote;
+use syn::{ForeignItemFn, Result};
+
+pub fn generate(
+    qobject_names: &QObjectNames,
+    type_names: &TypeNames,
+) -> Result<GeneratedRustFragment> {
+    let mut blocks = GeneratedRustFragment::default();
+
+    let cpp_struct_ident = qobject_names.name.rust_unqualified();
+    let qualified_impl = type_names.rust_qualified(cpp_struct_ident)?;
+    let namespace = qobject_names.namespace_tokens();
+
+    let (ensure_name, ensure_attrs, ensure_qualified) = qobject_names
+        .cxx_qt_ffi_method("lazyEnsureInitialized")
+        .into_cxx_parts();
+    let (finish_name, finish_attrs, finish_qualified) = qobject_names
+        .cxx_qt_ffi_method("lazyInitializeFinish")
+        .into_cxx_parts();
+    let (is_initialized_name, is_initialized_attrs, is_initialized_qualified) = qobject_names
+        .cxx_qt_ffi_method("lazyIsInitialized")
+        .into_cxx_parts();
+
+    let fragment = RustFragmentPair {
+        cxx_bridge: vec![
+            quote! {
+                unsafe extern "C++" {
+                    include!("cxx-qt/lazy.h");
+
+                    #[doc(hidden)]
+                    #(#ensure_attrs)*
+                    fn #ensure_name(qobject: Pin<&mut #cpp_struct_ident>);
+
+                    #[doc(hidden)]
+                    #(#finish_attrs)*
+                    fn #finish_name(qobject: Pin<&mut #cpp_struct_ident>);
+
+                    #[doc(hidden)]
+                    #(#is_initialized_attrs)*
+                    fn #is_initialized_name(qobject: &#cpp_struct_ident) -> bool;
+                }
+            },
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "cxxQtLazyInitialize"]
+                    #namespace
+                    #[doc(hidden)]
+                    fn cxx_qt_lazy_initialize(self: Pin<&mut #cpp_struct_ident>);
+                }
+            },
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "isInitialized"]
+                    #namespace
+                    #[doc(hidden)]
+                    fn is_initialized(self: &#cpp_struct_ident) -> bool;
+                }
+            },
+        ],
+        implementation: vec![quote! {
+            impl #qualified_impl {
+                /// Initializes the QObject with [cxx_qt::LazyInitialize] if this is the first call,
+                /// then emits the initialized signal.
+                pub fn ensure_initialized(self: core::pin::Pin<&mut Self>) {
+                    #ensure_qualified(self);
+                }
+
+                /// Returns whether the QObject has been initialized with [cxx_qt::LazyInitialize].
+                pub fn is_initialized(&self) -> bool {
+                    #is_initialized_qualified(self)
+                }
+
+                #[doc(hidden)]
+                pub fn cxx_qt_lazy_initialize(mut self: core::pin::Pin<&mut Self>) {
+                    <Self as cxx_qt::LazyInitialize>::lazy_initialize(self.as_mut());
+                    #finish_qualified(self.as_mut());
+                    self.initialized();
+                }
+            }
+        }],
+    };
+
+    blocks
+        .cxx_mod_contents
+        .append(&mut fragment.cxx_bridge_as_items()?);
+    blocks
+        .cxx_qt_mod_contents
+        .append(&mut fragment.implementation_as_items()?);
+
+    // We build our signal in the generation phase as we need to use the naming
+    // structs to build the signal name
+    let method: ForeignItemFn = syn::parse_quote! {
+        #[doc = "This signal is emitted once the lazy singleton has been initialized"]
+        fn initialized(self: Pin<&mut #cpp_struct_ident>);
+    };
+    let signal = ParsedSignal::parse(method, Safety::Safe)?;
+    blocks.append(&mut generate_rust_signals(
+        &vec![&signal],
+        qobject_names,
+        type_names,
+    )?);
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::tests::assert_tokens_eq;
+
+    #[test]
+    fn test_generate_rust_lazy() {
+        let qobject_names = create_qobjectname();
+
+        let generated = generate(&qobject_names, &TypeNames::mock()).unwrap();
+
+        // The lazy state and invokables, then the initialized signal
+        assert!(generated.cxx_mod_contents.len() > 3);
+        assert!(generated.cxx_qt_mod_contents.len() > 1);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    include!("cxx-qt/lazy.h");
+
+                    #[doc(hidden)]
+                    #[cxx_name = "lazyEnsureInitialized"]
+                    #[namespace = "rust::cxxqt1"]
+                    fn cxx_qt_ffi_my_object_lazy_ensure_initialized(qobject: Pin<&mut MyObject>);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "lazyInitializeFinish"]
+                    #[namespace = "rust::cxxqt1"]
+                    fn cxx_qt_ffi_my_object_lazy_initialize_finish(qobject: Pin<&mut MyObject>);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "lazyIsInitialized"]
+                    #[namespace = "rust::cxxqt1"]
+                    fn cxx_qt_ffi_my_object_lazy_is_initialized(qobject: &MyObject) -> bool;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "cxxQtLazyInitialize"]
+                    #[doc(hidden)]
+                    fn cxx_qt_lazy_initialize(self: Pin<&mut MyObject>);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    /// Initializes the QObject with [cxx_qt::LazyInitialize] if this is the first call,
+                    /// then emits the initialized signal.
+                    pub fn ensure_initialized(self: core::pin::Pin<&mut Self>) {
+                        qobject::cxx_qt_ffi_my_object_lazy_ensure_initialized(self);
+                    }
+
+                    /// Returns whether the QObject has been initialized with [cxx_qt::LazyInitialize].
+                    pub fn is_initialized(&self) -> bool {
+                        qobject::cxx_qt_ffi_my_object_lazy_is_initialized(self)
+                    }
+
+                    #[doc(hidden)]
+                    pub fn cxx_qt_lazy_initialize(mut self: core::pin::Pin<&mut Self>) {
+                        <Self as cxx_qt::LazyInitialize>::lazy_initialize(self.as_mut());
+                        qobject::cxx_qt_ffi_my_object_lazy_initialize_finish(self.as_mut());
+                        self.initialized();
+                    }
+                }
+            },
+        );
+    }
+}
@@ -63,12 +63,13 @@ pub fn generate_rust_methods(
             implementation: vec![],
         };
 
-        generated
-            .cxx_mod_contents
-            .append(&mut fragment.cxx_bridge_as_items()?);
-        generated
-            .cxx_qt_mod_contents
-            .append(&mut fragment.implementation_as_items()?);
+        generated.append(
+            &mut GeneratedRustFragment {
+                cxx_mod_contents: fragment.cxx_bridge_as_items()?,
+                cxx_qt_mod_contents: fragment.implementation_as_items()?,
+            }
+            .with_cfgs(&invokable.rust_cfgs()),
+        );
     }
 
     Ok(generated)
@@ -152,4 +153,99 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_generate_rust_invokables_cfg() {
+        let method: ForeignItemFn = parse_quote! {
+            #[cfg(feature = "extra")]
+            fn cfg_invokable(self: &MyObject);
+        };
+        let invokables = [ParsedMethod::mock_qinvokable(&method)];
+        let qobject_names = create_qobjectname();
+
+        let generated =
+            generate_rust_methods(&invokables.iter().collect(), &qobject_names).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                #[cfg(feature = "extra")]
+                extern "Rust" {
+                    #[cxx_name = "cfgInvokable"]
+                    #[doc(hidden)]
+                    fn cfg_invokable(self: &MyObject);
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_invokables_qt_since() {
+        let method: ForeignItemFn = parse_quote! {
+            #[qt(since = "6.5")]
+            fn new_invokable(self: &MyObject);
+        };
+        let invokables = [ParsedMethod::mock_qinvokable(&method)];
+        let qobject_names = create_qobjectname();
+
+        let generated =
+            generate_rust_methods(&invokables.iter().collect(), &qobject_names).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                #[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_5))]
+                extern "Rust" {
+                    #[cxx_name = "newInvokable"]
+                    #[doc(hidden)]
+                    fn new_invokable(self: &MyObject);
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_invokables_overloaded() {
+        let method1: ForeignItemFn = parse_quote! {
+            #[cxx_name = "update"]
+            fn update_value(self: Pin<&mut MyObject>, value: i32);
+        };
+        let method2: ForeignItemFn = parse_quote! {
+            #[cxx_name = "update"]
+            fn update_flag(self: Pin<&mut MyObject>, flag: bool);
+        };
+        let invokables = [
+            ParsedMethod::mock_qinvokable(&method1).make_mutable(),
+            ParsedMethod::mock_qinvokable(&method2).make_mutable(),
+        ];
+        let qobject_names = create_qobjectname();
+
+        let generated =
+            generate_rust_methods(&invokables.iter().collect(), &qobject_names).unwrap();
+
+        // The overloads share a C++ name but keep their distinct Rust names
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "update"]
+                    #[doc(hidden)]
+                    fn update_value(self: Pin<&mut MyObject>, value: i32);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "update"]
+                    #[doc(hidden)]
+                    fn update_flag(self: Pin<&mut MyObject>, flag: bool);
+                }
+            },
+        );
+    }
 }
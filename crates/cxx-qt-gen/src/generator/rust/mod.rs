@@ -8,11 +8,13 @@ pub mod cxxqttype;
 pub mod externcxxqt;
 pub mod fragment;
 pub mod inherit;
+pub mod lazy;
 pub mod method;
 pub mod property;
 pub mod qenum;
 pub mod qobject;
 pub mod signals;
+pub mod signature;
 pub mod threading;
 
 use crate::generator::{rust::fragment::GeneratedRustFragment, structuring};
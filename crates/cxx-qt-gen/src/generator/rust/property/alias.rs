@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        naming::{namespace::NamespaceName, property::QPropertyNames, qobject::QObjectNames},
+        rust::fragment::GeneratedRustFragment,
+        structuring::StructuredQObject,
+    },
+    naming::TypeNames,
+    parser::property::{ParsedQProperty, QPropertyAlias},
+};
+use convert_case::{Case, Casing};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Result};
+
+/// Generate the function which is called from the C++ constructor to forward the changed signals
+/// of the child properties to the notify signals of the aliases
+pub fn generate(
+    properties: &[ParsedQProperty],
+    qobject_names: &QObjectNames,
+    type_names: &TypeNames,
+    structured_qobject: &StructuredQObject,
+) -> Result<Option<GeneratedRustFragment>> {
+    let mut connections = vec![];
+
+    for property in properties {
+        let Some(QPropertyAlias {
+            field,
+            property: child_property,
+        }) = &property.flags.alias
+        else {
+            continue;
+        };
+        let idents = QPropertyNames::try_from_property(property, structured_qobject)?;
+        let Some(notify) = &idents.notify else {
+            continue;
+        };
+
+        let notify_rust = notify.rust_unqualified();
+        let on_child_changed = format_ident!("on_{child_property}_changed_with_context");
+        let cfgs = &property.cfgs;
+
+        connections.push(quote! {
+            #(#cfgs)*
+            if let Some(child) = outer.as_mut().rust_mut().#field.as_mut() {
+                let mut this = cxx_qt::signalhandler::CxxQtContextPointer::new(outer_ptr);
+                child
+                    .#on_child_changed(&*context, move |_| this.pin_mut().#notify_rust())
+                    .release();
+            }
+        });
+    }
+
+    if connections.is_empty() {
+        return Ok(None);
+    }
+
+    let cpp_class_name_rust = qobject_names.name.rust_unqualified();
+    let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
+    let namespace_internals = NamespaceName::from(structured_qobject.declaration).internal;
+    let connect_aliases_ident = format_ident!(
+        "connect_aliases_{object_name}",
+        object_name = cpp_class_name_rust.to_string().to_case(Case::Snake)
+    );
+
+    Ok(Some(GeneratedRustFragment {
+        cxx_mod_contents: vec![parse_quote! {
+            extern "Rust" {
+                #[cxx_name = "connectAliases"]
+                #[namespace = #namespace_internals]
+                #[doc(hidden)]
+                fn #connect_aliases_ident(outer: Pin<&mut #cpp_class_name_rust>);
+            }
+        }],
+        cxx_qt_mod_contents: vec![parse_quote! {
+            #[doc(hidden)]
+            pub fn #connect_aliases_ident(mut outer: core::pin::Pin<&mut #qualified_impl>) {
+                use cxx_qt::CxxQtType;
+                let context: *const cxx_qt::QObject =
+                    cxx_qt::Upcast::<cxx_qt::QObject>::upcast(&*outer);
+                // SAFETY: the connections use the QObject as their context object, so the closures
+                // are only called in the thread of the QObject while it is alive
+                unsafe {
+                    let outer_ptr = outer.as_mut().get_unchecked_mut() as *mut #qualified_impl;
+                    #(#connections)*
+                }
+            }
+        }],
+    }))
+}
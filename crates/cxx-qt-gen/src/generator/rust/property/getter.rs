@@ -13,6 +13,7 @@ use crate::{
     },
     naming::rust::syn_type_cxx_bridge_to_qualified,
     naming::TypeNames,
+    parser::property::QPropertyAlias,
 };
 use quote::quote;
 use syn::{Result, Type};
@@ -22,6 +23,7 @@ pub fn generate(
     qobject_names: &QObjectNames,
     cxx_ty: &Type,
     type_names: &TypeNames,
+    alias: Option<&QPropertyAlias>,
 ) -> Result<Option<RustFragmentPair>> {
     if let NameState::Auto(getter) = &idents.getter {
         let cpp_class_name_rust = &qobject_names.name.rust_unqualified();
@@ -34,6 +36,19 @@ pub fn generate(
 
         let cxx_namespace = qobject_names.namespace_tokens();
 
+        // An alias reads from the property of the child QObject instead of the Rust struct,
+        // or the default value of the type while there is no child
+        let value = if let Some(QPropertyAlias { field, property }) = alias {
+            quote! {
+                match self.#field.as_ref() {
+                    Some(child) => child.#property(),
+                    None => cxx_qt::alias::default_ref(),
+                }
+            }
+        } else {
+            quote! { &self.#ident }
+        };
+
         Ok(Some(RustFragmentPair {
             cxx_bridge: vec![quote! {
                 extern "Rust" {
@@ -51,7 +66,7 @@ pub fn generate(
                     #[doc = "Getter for the Q_PROPERTY "]
                     #[doc = #ident_str]
                     pub fn #getter_rust(&self) -> &#qualified_ty {
-                        &self.#ident
+                        #value
                     }
                 }
             }],
@@ -3,6 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+pub mod alias;
 pub mod getter;
 pub mod setter;
 pub mod signal;
@@ -31,25 +32,39 @@ pub fn generate_rust_properties(
     for property in properties {
         let idents = QPropertyNames::try_from_property(property, structured_qobject)?;
 
-        if let Some(getter) = getter::generate(&idents, qobject_names, &property.ty, type_names)? {
-            generated
-                .cxx_mod_contents
-                .append(&mut getter.cxx_bridge_as_items()?);
-            generated
-                .cxx_qt_mod_contents
-                .append(&mut getter.implementation_as_items()?);
+        if let Some(getter) = getter::generate(
+            &idents,
+            qobject_names,
+            &property.ty,
+            type_names,
+            property.flags.alias.as_ref(),
+        )? {
+            generated.append(
+                &mut GeneratedRustFragment {
+                    cxx_mod_contents: getter.cxx_bridge_as_items()?,
+                    cxx_qt_mod_contents: getter.implementation_as_items()?,
+                }
+                .with_cfgs(&property.cfgs),
+            );
         };
 
-        if let Some(setter) = setter::generate(&idents, qobject_names, &property.ty, type_names)? {
-            generated
-                .cxx_mod_contents
-                .append(&mut setter.cxx_bridge_as_items()?);
-            generated
-                .cxx_qt_mod_contents
-                .append(&mut setter.implementation_as_items()?);
+        if let Some(setter) = setter::generate(
+            &idents,
+            qobject_names,
+            &property.ty,
+            type_names,
+            property.flags.alias.as_ref(),
+        )? {
+            generated.append(
+                &mut GeneratedRustFragment {
+                    cxx_mod_contents: setter.cxx_bridge_as_items()?,
+                    cxx_qt_mod_contents: setter.implementation_as_items()?,
+                }
+                .with_cfgs(&property.cfgs),
+            );
         }
 
-        if let Some(notify) = signal::generate(&idents, qobject_names) {
+        if let Some(notify) = signal::generate(&idents, qobject_names, &property.cfgs) {
             signals.push(notify)
         }
     }
@@ -60,6 +75,12 @@ pub fn generate_rust_properties(
         type_names,
     )?);
 
+    if let Some(mut aliases) =
+        alias::generate(properties, qobject_names, type_names, structured_qobject)?
+    {
+        generated.append(&mut aliases);
+    }
+
     Ok(generated)
 }
 
@@ -68,7 +89,7 @@ mod tests {
     use super::*;
 
     use crate::generator::naming::property::property_name_from_rust_name;
-    use crate::parser::property::QPropertyFlags;
+    use crate::parser::property::{QPropertyAlias, QPropertyFlags};
     use crate::parser::qobject::ParsedQObject;
     use crate::{generator::naming::qobject::tests::create_qobjectname, tests::assert_tokens_eq};
     use quote::format_ident;
@@ -81,16 +102,22 @@ mod tests {
                 name: property_name_from_rust_name(format_ident!("trivial_property")),
                 ty: parse_quote! { i32 },
                 flags: QPropertyFlags::default(),
+                cfgs: vec![],
+                docs: vec![],
             },
             ParsedQProperty {
                 name: property_name_from_rust_name(format_ident!("opaque_property")),
                 ty: parse_quote! { UniquePtr<QColor> },
                 flags: QPropertyFlags::default(),
+                cfgs: vec![],
+                docs: vec![],
             },
             ParsedQProperty {
                 name: property_name_from_rust_name(format_ident!("unsafe_property")),
                 ty: parse_quote! { *mut T },
                 flags: QPropertyFlags::default(),
+                cfgs: vec![],
+                docs: vec![],
             },
         ];
         let qobject_names = create_qobjectname();
@@ -112,7 +139,7 @@ mod tests {
 
         // Check that we have the expected number of blocks
         assert_eq!(generated.cxx_mod_contents.len(), 15);
-        assert_eq!(generated.cxx_qt_mod_contents.len(), 30);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 36);
 
         // Trivial Property
 
@@ -299,6 +326,11 @@ mod tests {
                     #[namespace = "rust::cxxqtgen1"]
                     #[cxx_name = "MyObject_trivialPropertyChangedConnect"]
                     fn MyObject_connect_trivial_property_changed(self_value: Pin<&mut MyObject>, signal_handler: MyObjectCxxQtSignalHandlertrivialPropertyChanged, conn_type: CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtgen1"]
+                    #[cxx_name = "MyObject_trivialPropertyChangedConnectWithContext"]
+                    fn MyObject_connect_trivial_property_changed_with_context(self_value: Pin<&mut MyObject>, context: &CxxQtQObject, signal_handler: MyObjectCxxQtSignalHandlertrivialPropertyChanged, conn_type: CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
                 }
             },
         );
@@ -355,14 +387,14 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[8],
+            &generated.cxx_qt_mod_contents[10],
             parse_quote! {
                 #[doc(hidden)]
                 pub struct MyObjectCxxQtSignalClosuretrivialPropertyChanged {}
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[9],
+            &generated.cxx_qt_mod_contents[11],
             parse_quote! {
                 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosuretrivialPropertyChanged {
                     type Id = cxx::type_id!("::rust::cxxqtgen1::MyObjectCxxQtSignalHandlertrivialPropertyChanged");
@@ -371,13 +403,13 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[10],
+            &generated.cxx_qt_mod_contents[12],
             parse_quote! {
                 use core::mem::drop as drop_MyObject_signal_handler_trivialPropertyChanged;
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[11],
+            &generated.cxx_qt_mod_contents[13],
             parse_quote! {
                 fn call_MyObject_signal_handler_trivialPropertyChanged(
                     handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuretrivialPropertyChanged>,
@@ -388,13 +420,13 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[12],
+            &generated.cxx_qt_mod_contents[14],
             parse_quote! {
                 cxx_qt::static_assertions::assert_eq_align!(cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuretrivialPropertyChanged>, usize);
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[13],
+            &generated.cxx_qt_mod_contents[15],
             parse_quote! {
                 cxx_qt::static_assertions::assert_eq_size!(cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuretrivialPropertyChanged>, [usize; 2]);
             },
@@ -424,6 +456,11 @@ mod tests {
                     #[namespace = "rust::cxxqtgen1"]
                     #[cxx_name = "MyObject_opaquePropertyChangedConnect"]
                     fn MyObject_connect_opaque_property_changed(self_value: Pin<&mut MyObject>, signal_handler: MyObjectCxxQtSignalHandleropaquePropertyChanged, conn_type: CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtgen1"]
+                    #[cxx_name = "MyObject_opaquePropertyChangedConnectWithContext"]
+                    fn MyObject_connect_opaque_property_changed_with_context(self_value: Pin<&mut MyObject>, context: &CxxQtQObject, signal_handler: MyObjectCxxQtSignalHandleropaquePropertyChanged, conn_type: CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
                 }
             },
         );
@@ -442,7 +479,7 @@ mod tests {
         );
 
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[14],
+            &generated.cxx_qt_mod_contents[16],
             parse_quote! {
                 impl qobject::MyObject {
                     #[doc = "Connect the given function pointer to the signal "]
@@ -460,7 +497,7 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[15],
+            &generated.cxx_qt_mod_contents[17],
             parse_quote! {
                 impl qobject::MyObject {
                     #[doc = "Connect the given function pointer to the signal "]
@@ -480,14 +517,14 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[16],
+            &generated.cxx_qt_mod_contents[20],
             parse_quote! {
                 #[doc(hidden)]
                 pub struct MyObjectCxxQtSignalClosureopaquePropertyChanged {}
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[17],
+            &generated.cxx_qt_mod_contents[21],
             parse_quote! {
                 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosureopaquePropertyChanged {
                     type Id = cxx::type_id!("::rust::cxxqtgen1::MyObjectCxxQtSignalHandleropaquePropertyChanged");
@@ -496,13 +533,13 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[18],
+            &generated.cxx_qt_mod_contents[22],
             parse_quote! {
                 use core::mem::drop as drop_MyObject_signal_handler_opaquePropertyChanged;
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[19],
+            &generated.cxx_qt_mod_contents[23],
             parse_quote! {
                 fn call_MyObject_signal_handler_opaquePropertyChanged(
                     handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureopaquePropertyChanged>,
@@ -513,13 +550,13 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[20],
+            &generated.cxx_qt_mod_contents[24],
             parse_quote! {
                 cxx_qt::static_assertions::assert_eq_align!(cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureopaquePropertyChanged>, usize);
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[21],
+            &generated.cxx_qt_mod_contents[25],
             parse_quote! {
                 cxx_qt::static_assertions::assert_eq_size!(cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureopaquePropertyChanged>, [usize; 2]);
             },
@@ -549,6 +586,11 @@ mod tests {
                     #[namespace = "rust::cxxqtgen1"]
                     #[cxx_name = "MyObject_unsafePropertyChangedConnect"]
                     fn MyObject_connect_unsafe_property_changed(self_value: Pin<&mut MyObject>, signal_handler: MyObjectCxxQtSignalHandlerunsafePropertyChanged, conn_type: CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtgen1"]
+                    #[cxx_name = "MyObject_unsafePropertyChangedConnectWithContext"]
+                    fn MyObject_connect_unsafe_property_changed_with_context(self_value: Pin<&mut MyObject>, context: &CxxQtQObject, signal_handler: MyObjectCxxQtSignalHandlerunsafePropertyChanged, conn_type: CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
                 }
             },
         );
@@ -567,7 +609,7 @@ mod tests {
         );
 
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[22],
+            &generated.cxx_qt_mod_contents[26],
             parse_quote! {
                 impl qobject::MyObject {
                     #[doc = "Connect the given function pointer to the signal "]
@@ -585,7 +627,7 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[23],
+            &generated.cxx_qt_mod_contents[27],
             parse_quote! {
                 impl qobject::MyObject {
                     #[doc = "Connect the given function pointer to the signal "]
@@ -605,14 +647,14 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[24],
+            &generated.cxx_qt_mod_contents[30],
             parse_quote! {
                 #[doc(hidden)]
                 pub struct MyObjectCxxQtSignalClosureunsafePropertyChanged {}
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[25],
+            &generated.cxx_qt_mod_contents[31],
             parse_quote! {
                 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosureunsafePropertyChanged {
                     type Id = cxx::type_id!("::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerunsafePropertyChanged");
@@ -621,13 +663,13 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[26],
+            &generated.cxx_qt_mod_contents[32],
             parse_quote! {
                 use core::mem::drop as drop_MyObject_signal_handler_unsafePropertyChanged;
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[27],
+            &generated.cxx_qt_mod_contents[33],
             parse_quote! {
                 fn call_MyObject_signal_handler_unsafePropertyChanged(
                     handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureunsafePropertyChanged>,
@@ -638,16 +680,121 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[28],
+            &generated.cxx_qt_mod_contents[34],
             parse_quote! {
                 cxx_qt::static_assertions::assert_eq_align!(cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureunsafePropertyChanged>, usize);
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[29],
+            &generated.cxx_qt_mod_contents[35],
             parse_quote! {
                 cxx_qt::static_assertions::assert_eq_size!(cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureunsafePropertyChanged>, [usize; 2]);
             },
         );
     }
+
+    #[test]
+    fn test_generate_rust_properties_alias() {
+        let properties = vec![ParsedQProperty {
+            name: property_name_from_rust_name(format_ident!("aliased_property")),
+            ty: parse_quote! { i32 },
+            flags: QPropertyFlags {
+                alias: Some(QPropertyAlias {
+                    field: format_ident!("child"),
+                    property: format_ident!("child_property"),
+                }),
+                ..Default::default()
+            },
+            cfgs: vec![],
+            docs: vec![],
+        }];
+        let qobject_names = create_qobjectname();
+
+        let obj = ParsedQObject::mock();
+
+        let structured_qobject = StructuredQObject::mock(&obj);
+
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_names,
+            &TypeNames::mock(),
+            &structured_qobject,
+        )
+        .unwrap();
+
+        // The alias still generates the getter, setter, and notify signal of a normal property,
+        // as well as the connection from the changed signal of the child
+        assert_eq!(generated.cxx_mod_contents.len(), 6);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 13);
+
+        // Getter
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Getter for the Q_PROPERTY "]
+                    #[doc = "aliased_property"]
+                    pub fn aliased_property(&self) -> &i32 {
+                        match self.child.as_ref() {
+                            Some(child) => child.child_property(),
+                            None => cxx_qt::alias::default_ref(),
+                        }
+                    }
+                }
+            },
+        );
+
+        // Setter
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Setter for the Q_PROPERTY "]
+                    #[doc = "aliased_property"]
+                    pub fn set_aliased_property(mut self: core::pin::Pin<&mut Self>, value: i32) {
+                        use cxx_qt::CxxQtType;
+                        if self.child.as_ref().map(|child| child.child_property()) == Some(&value) {
+                            return;
+                        }
+                        if let Some(child) = self.as_mut().rust_mut().child.as_mut() {
+                            child.set_child_property(value);
+                        }
+                    }
+                }
+            },
+        );
+
+        // Connection from the changed signal of the child to the notify signal
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[5],
+            parse_quote! {
+                extern "Rust" {
+                    #[cxx_name = "connectAliases"]
+                    #[namespace = "cxx_qt_my_object"]
+                    #[doc(hidden)]
+                    fn connect_aliases_my_object(outer: Pin<&mut MyObject>);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[12],
+            parse_quote! {
+                #[doc(hidden)]
+                pub fn connect_aliases_my_object(mut outer: core::pin::Pin<&mut qobject::MyObject>) {
+                    use cxx_qt::CxxQtType;
+                    let context: *const cxx_qt::QObject =
+                        cxx_qt::Upcast::<cxx_qt::QObject>::upcast(&*outer);
+                    unsafe {
+                        let outer_ptr = outer.as_mut().get_unchecked_mut() as *mut qobject::MyObject;
+                        if let Some(child) = outer.as_mut().rust_mut().child.as_mut() {
+                            let mut this = cxx_qt::signalhandler::CxxQtContextPointer::new(outer_ptr);
+                            child
+                                .on_child_property_changed_with_context(&*context, move |_| this.pin_mut().aliased_property_changed())
+                                .release();
+                        }
+                    }
+                }
+            },
+        );
+    }
 }
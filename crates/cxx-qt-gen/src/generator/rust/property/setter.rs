@@ -13,8 +13,9 @@ use crate::{
     },
     naming::rust::{syn_type_cxx_bridge_to_qualified, syn_type_is_cxx_bridge_unsafe},
     naming::TypeNames,
+    parser::property::QPropertyAlias,
 };
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{Result, Type};
 
 pub fn generate(
@@ -22,6 +23,7 @@ pub fn generate(
     qobject_names: &QObjectNames,
     cxx_ty: &Type,
     type_names: &TypeNames,
+    alias: Option<&QPropertyAlias>,
 ) -> Result<Option<RustFragmentPair>> {
     let cpp_class_name_rust = &qobject_names.name.rust_unqualified();
 
@@ -33,12 +35,14 @@ pub fn generate(
         let ident_str = ident.to_string();
 
         // Generate a notify name if it was provided, otherwise return empty
+        //
+        // An alias does not emit its notify signal, as the changed signal of the child is forwarded
         let notify_binding = match &idents.notify {
-            Some(notify) => {
+            Some(notify) if alias.is_none() => {
                 let notify_ident = notify.rust_unqualified();
                 quote! {self.as_mut().#notify_ident();}
             }
-            None => quote! {},
+            _ => quote! {},
         };
 
         let qualified_ty = syn_type_cxx_bridge_to_qualified(cxx_ty, type_names)?;
@@ -53,6 +57,25 @@ pub fn generate(
 
         let cxx_namespace = qobject_names.namespace_tokens();
 
+        // An alias compares against and writes to the property of the child QObject
+        // instead of the field on the Rust struct, the value is dropped while there is no child
+        let (current_value, set_value) = if let Some(QPropertyAlias { field, property }) = alias {
+            let child_setter = format_ident!("set_{property}");
+            (
+                quote! { self.#field.as_ref().map(|child| child.#property()) == Some(&value) },
+                quote! {
+                    if let Some(child) = self.as_mut().rust_mut().#field.as_mut() {
+                        child.#child_setter(value);
+                    }
+                },
+            )
+        } else {
+            (
+                quote! { self.#ident == value },
+                quote! { self.as_mut().rust_mut().#ident = value; },
+            )
+        };
+
         Ok(Some(RustFragmentPair {
             cxx_bridge: vec![quote! {
                 extern "Rust" {
@@ -71,12 +94,12 @@ pub fn generate(
                     #[doc = #ident_str]
                     pub fn #setter_rust(mut self: core::pin::Pin<&mut Self>, value: #qualified_ty) {
                         use cxx_qt::CxxQtType;
-                        if self.#ident == value {
+                        if #current_value {
                             // don't want to set the value again and reemit the signal,
                             // as this can cause binding loops
                             return;
                         }
-                        self.as_mut().rust_mut().#ident = value;
+                        #set_value
                         #notify_binding
                     }
                 }
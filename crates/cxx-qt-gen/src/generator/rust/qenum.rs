@@ -6,7 +6,7 @@
 use crate::parser::qenum::ParsedQEnum;
 use crate::syntax::path::path_compare_str;
 use quote::quote;
-use syn::{parse_quote_spanned, spanned::Spanned, Attribute, Item};
+use syn::{parse_quote, parse_quote_spanned, spanned::Spanned, Attribute, Item};
 
 pub fn generate_cxx_mod_contents(qenums: &[ParsedQEnum]) -> Vec<Item> {
     qenums
@@ -16,7 +16,13 @@ pub fn generate_cxx_mod_contents(qenums: &[ParsedQEnum]) -> Vec<Item> {
             let namespace = &qenum.name.namespace();
             let item = &qenum.item;
             let vis = &item.vis;
-            let variants = &item.variants;
+            let mut variants = item.variants.clone();
+            // The roles of a model have explicit values, so that they match the C++ enum
+            if let Some(values) = qenum.variant_values() {
+                for (variant, value) in variants.iter_mut().zip(values) {
+                    variant.discriminant = Some((Default::default(), parse_quote! { #value }));
+                }
+            }
             let docs: Vec<&Attribute> = item
                 .attrs
                 .iter()
@@ -109,4 +115,33 @@ mod tests {
             },
         )
     }
+
+    #[test]
+    fn generates_model_roles() {
+        let qenums = vec![ParsedQEnum::parse(
+            parse_quote! {
+                #[model_roles]
+                enum Roles {
+                    Id,
+                    Value,
+                }
+            },
+            Some(format_ident!("MyObject")),
+            None,
+            &format_ident!("qobject"),
+        )
+        .unwrap()];
+
+        let generated = generate(&qenums);
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                #[repr(i32)]
+                enum Roles {
+                    Id = 256i32,
+                    Value = 257i32,
+                }
+            },
+        );
+    }
 }
@@ -9,17 +9,18 @@ use crate::{
         rust::{
             constructor, cxxqttype,
             fragment::{GeneratedRustFragment, RustFragmentPair},
-            inherit,
+            inherit, lazy,
             method::generate_rust_methods,
             property::generate_rust_properties,
             signals::generate_rust_signals,
-            threading,
+            signature, threading,
         },
     },
     naming::TypeNames,
 };
-use quote::quote;
-use syn::{Ident, Result};
+use convert_case::{Case, Casing};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Ident, Result};
 
 impl GeneratedRustFragment {
     // Might need to be refactored to use a StructuredQObject instead (confirm with Leon)
@@ -36,6 +37,7 @@ impl GeneratedRustFragment {
         generated.append(&mut generate_qobject_definitions(
             &qobject_names,
             qobject.base_class.clone(),
+            qobject.has_qobject_macro,
             type_names,
         )?);
 
@@ -77,6 +79,15 @@ impl GeneratedRustFragment {
             }
         }
 
+        // If this type is a lazy singleton then add the initialization methods and signal
+        if qobject
+            .qml_metadata
+            .as_ref()
+            .is_some_and(|qml_metadata| qml_metadata.lazy)
+        {
+            generated.append(&mut lazy::generate(&qobject_names, type_names)?);
+        }
+
         // If this type has threading enabled then add generation
         if structured_qobject.threading {
             generated.append(&mut threading::generate(
@@ -95,7 +106,33 @@ impl GeneratedRustFragment {
 
         generated.append(&mut cxxqttype::generate(&qobject_names, type_names)?);
 
-        Ok(generated)
+        // Export the signature of the bridge so that stale C++ generation fails to link
+        generated.append(&mut signature::generate(
+            &qobject_names,
+            &namespace_idents,
+            structured_qobject.signature_hash(),
+        )?);
+
+        // Re-export the QObject and its CxxQtThread from the module containing the bridge with the
+        // visibility of the declaration, so that a library can expose the QObject without exposing
+        // the bridge module
+        if qobject.reexport {
+            let vis = &qobject.declaration.vis;
+            let module_ident = qobject.name.require_module()?;
+            let qobject_ident = qobject.name.rust_unqualified();
+            generated.cxx_qt_mod_contents.push(parse_quote! {
+                #vis use self::#module_ident::#qobject_ident;
+            });
+
+            if structured_qobject.threading {
+                let cxx_qt_thread_ident = &qobject_names.cxx_qt_thread_class;
+                generated.cxx_qt_mod_contents.push(parse_quote! {
+                    #vis use self::#module_ident::#cxx_qt_thread_ident;
+                });
+            }
+        }
+
+        Ok(generated.with_cfgs(&qobject.cfgs))
     }
 }
 
@@ -103,6 +140,7 @@ impl GeneratedRustFragment {
 fn generate_qobject_definitions(
     qobject_idents: &QObjectNames,
     base: Option<Ident>,
+    has_qobject_macro: bool,
     type_names: &TypeNames,
 ) -> Result<GeneratedRustFragment> {
     let mut generated = GeneratedRustFragment::default();
@@ -125,22 +163,57 @@ fn generate_qobject_definitions(
 
     let cpp_struct_qualified = &qobject_idents.name.rust_qualified();
 
-    let base_upcast = if let Some(base) = base {
+    // Cast to and from the base class and, if it is not the base class, QObject
+    let mut upcasts = vec![];
+    if let Some(base) = base {
         let base_name = type_names.lookup(&base)?.rust_qualified();
-        vec![
-            quote! { impl cxx_qt::Upcast<#base_name> for #cpp_struct_qualified {} },
-            // Until we can actually implement the Upcast trait properly, we just need to silence
-            // the warning that the base class is otherwise unused.
-            // This can be done with an unnamed import and the right attributes
-            quote! {
-                #[allow(unused_imports)]
-                #[allow(dead_code)]
-                use #base_name as _;
-            },
-        ]
-    } else {
-        vec![]
-    };
+        let suffix = base.to_string().to_case(Case::Snake);
+        upcasts.push((base, base_name, suffix));
+    }
+    if has_qobject_macro {
+        upcasts.push((
+            format_ident!("CxxQtQObject"),
+            parse_quote! { cxx_qt::QObject },
+            "qobject".to_owned(),
+        ));
+    }
+    let (upcast_bridges, upcast_impls): (Vec<_>, Vec<_>) = upcasts
+        .into_iter()
+        .map(|(base_ident, base_qualified, suffix)| {
+            let upcast_name = qobject_idents.cxx_qt_ffi_method("upcastPtr");
+            let upcast_ident = format_ident!("{}_{suffix}", upcast_name.rust_unqualified());
+            let (upcast_name, upcast_attrs, upcast_qualified) =
+                upcast_name.with_rust_name(upcast_ident).into_cxx_parts();
+            let downcast_name = qobject_idents.cxx_qt_ffi_method("downcastPtr");
+            let downcast_ident = format_ident!("{}_{suffix}", downcast_name.rust_unqualified());
+            let (downcast_name, downcast_attrs, downcast_qualified) =
+                downcast_name.with_rust_name(downcast_ident).into_cxx_parts();
+            (
+                quote! {
+                    #[doc(hidden)]
+                    #(#upcast_attrs)*
+                    unsafe fn #upcast_name(thiz: *const #cpp_class_name_rust) -> *const #base_ident;
+
+                    #[doc(hidden)]
+                    #(#downcast_attrs)*
+                    unsafe fn #downcast_name(base: *const #base_ident) -> *const #cpp_class_name_rust;
+                },
+                // SAFETY: the C++ functions statically assert that the base is a base class and
+                // only downcast with qobject_cast or dynamic_cast, which return nullptr otherwise
+                quote! {
+                    unsafe impl cxx_qt::Upcast<#base_qualified> for #cpp_struct_qualified {
+                        unsafe fn upcast_ptr(this: *const Self) -> *const #base_qualified {
+                            #upcast_qualified(this)
+                        }
+
+                        unsafe fn from_base_ptr(base: *const #base_qualified) -> *const Self {
+                            #downcast_qualified(base)
+                        }
+                    }
+                },
+            )
+        })
+        .unzip();
 
     let fragment = RustFragmentPair {
         cxx_bridge: vec![
@@ -157,6 +230,13 @@ fn generate_qobject_definitions(
                     type #cpp_class_name_rust;
                 }
             },
+            quote! {
+                unsafe extern "C++" {
+                    include!("cxx-qt/upcast.h");
+
+                    #(#upcast_bridges)*
+                }
+            },
             quote! {
                 extern "Rust" {
                     // Needed for QObjects to have a namespace on their type or extern block
@@ -168,7 +248,7 @@ fn generate_qobject_definitions(
                 }
             },
         ],
-        implementation: base_upcast,
+        implementation: upcast_impls,
     };
 
     generated
@@ -213,6 +293,50 @@ mod tests {
         .is_ok());
     }
 
+    #[test]
+    fn test_generated_rust_qobject_blocks_reexport() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod my_bridge {
+                extern "RustQt" {
+                    #[qobject(reexport)]
+                    pub(crate) type MyObject = super::MyObjectRust;
+
+                    #[qobject]
+                    pub type OtherObject = super::OtherObjectRust;
+                }
+
+                impl cxx_qt::Threading for MyObject {}
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let rust = GeneratedRustFragment::from_qobject(&structures.qobjects[0], &parser.type_names)
+            .unwrap();
+        let len = rust.cxx_qt_mod_contents.len();
+        assert_tokens_eq(
+            &rust.cxx_qt_mod_contents[len - 2],
+            quote! {
+                pub(crate) use self::my_bridge::MyObject;
+            },
+        );
+        assert_tokens_eq(
+            &rust.cxx_qt_mod_contents[len - 1],
+            quote! {
+                pub(crate) use self::my_bridge::MyObjectCxxQtThread;
+            },
+        );
+
+        // Without the reexport argument the QObject is only available from the bridge module
+        let rust = GeneratedRustFragment::from_qobject(&structures.qobjects[1], &parser.type_names)
+            .unwrap();
+        assert!(!rust
+            .cxx_qt_mod_contents
+            .iter()
+            .any(|item| matches!(item, syn::Item::Use(_))));
+    }
+
     #[test]
     fn test_generated_rust_qobject_blocks_singleton() {
         let module = mock_qml_singleton();
@@ -224,7 +348,7 @@ mod tests {
             &parser.type_names,
         )
         .unwrap();
-        assert_eq!(rust.cxx_mod_contents.len(), 6);
+        assert_eq!(rust.cxx_mod_contents.len(), 8);
         assert_tokens_eq(
             &rust.cxx_mod_contents[0],
             quote! {
@@ -242,6 +366,24 @@ mod tests {
         );
         assert_tokens_eq(
             &rust.cxx_mod_contents[1],
+            quote! {
+                unsafe extern "C++" {
+                    include!("cxx-qt/upcast.h");
+
+                    #[doc(hidden)]
+                    #[cxx_name = "upcastPtr"]
+                    #[namespace = "rust::cxxqt1"]
+                    unsafe fn cxx_qt_ffi_my_object_upcast_ptr_qobject(thiz: *const MyObject) -> *const CxxQtQObject;
+
+                    #[doc(hidden)]
+                    #[cxx_name = "downcastPtr"]
+                    #[namespace = "rust::cxxqt1"]
+                    unsafe fn cxx_qt_ffi_my_object_downcast_ptr_qobject(base: *const CxxQtQObject) -> *const MyObject;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &rust.cxx_mod_contents[2],
             quote! {
                 extern "Rust" {
                     #[namespace = "cxx_qt"]
@@ -250,7 +392,7 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &rust.cxx_mod_contents[2],
+            &rust.cxx_mod_contents[3],
             quote! {
                 unsafe extern "C++" {
                     include!(<QtQml/QQmlEngine>);
@@ -258,7 +400,7 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &rust.cxx_mod_contents[3],
+            &rust.cxx_mod_contents[4],
             quote! {
                 extern "Rust" {
                     #[cxx_name = "createRs"]
@@ -268,7 +410,7 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &rust.cxx_mod_contents[4],
+            &rust.cxx_mod_contents[5],
             quote! {
                 unsafe extern "C++" {
                     #[doc(hidden)]
@@ -279,7 +421,7 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &rust.cxx_mod_contents[5],
+            &rust.cxx_mod_contents[6],
             quote! {
                 unsafe extern "C++" {
                     #[doc(hidden)]
@@ -15,8 +15,8 @@ use crate::{
     naming::{rust::syn_type_cxx_bridge_to_qualified, Name, TypeNames},
     parser::signals::ParsedSignal,
 };
-use quote::quote;
-use syn::{parse_quote, FnArg, Ident, Result, Type};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Attribute, FnArg, Ident, Result, Type};
 
 pub fn generate_rust_signal(
     signal: &ParsedSignal,
@@ -33,9 +33,15 @@ pub fn generate_rust_signal(
     let signal_name_cpp = idents.name.cxx_unqualified();
     let connect_ident_rust = idents.connect_name.rust_unqualified();
     let on_ident_rust = idents.on_name;
+    let on_with_context_ident_rust = idents.on_with_context_name;
 
     let free_connect_ident_cpp = idents_helper.connect_name.cxx_unqualified();
     let free_connect_ident_rust = idents_helper.connect_name.rust_unqualified();
+    let connect_with_context_ident_rust = idents.connect_with_context_name;
+    let free_connect_with_context_ident_cpp =
+        idents_helper.connect_with_context_name.cxx_unqualified();
+    let free_connect_with_context_ident_rust =
+        idents_helper.connect_with_context_name.rust_unqualified();
 
     let parameters_cxx: Vec<FnArg> = signal
         .parameters
@@ -65,7 +71,7 @@ pub fn generate_rust_signal(
         .iter()
         .map(|parameter| parameter.ident.clone())
         .collect();
-    let parameters_qualified_type: Vec<Type> = parameters_cxx
+    let mut parameters_qualified_type: Vec<Type> = parameters_cxx
         .iter()
         .cloned()
         .map(|parameter| match parameter {
@@ -107,14 +113,73 @@ pub fn generate_rust_signal(
         quote! {}
     };
 
-    let signal_ident_cpp = idents.name.rust_unqualified();
+    let mut signal_ident_cpp = idents.name.rust_unqualified().clone();
     let parameter_signatures =
         get_params_tokens(signal.mutable, &signal.parameters, rust_class_name);
 
     let return_type = &signal.method.sig.output;
 
+    let mut emit_wrapper = None;
+
+    // A #[flatten] signal is emitted and connected with its payload struct, but the C++ signal
+    // has the fields of the struct as its parameters. So the emit in the CXX bridge is hidden
+    // behind a method which takes the struct, and the connect closures take the struct.
+    let mut handler_arguments = quote! { #(#parameters_name),* };
+    if let Some(payload) = &signal.payload {
+        let signal_ident_rust = signal_ident_cpp;
+        signal_ident_cpp = format_ident!("cxx_qt_ffi_{signal_ident_rust}_flattened");
+
+        let payload_ident = &payload.ident;
+        let payload_ty = syn_type_cxx_bridge_to_qualified(&payload.ty, type_names)?;
+
+        if !signal.private {
+            emit_wrapper = Some(quote! {
+                impl #qualified_impl {
+                    #(#doc_comments)*
+                    pub #unsafe_call fn #signal_ident_rust(self: #self_type_qualified, #payload_ident: #payload_ty) {
+                        self.#signal_ident_cpp(#(#payload_ident.#parameters_name),*)
+                    }
+                }
+            });
+        }
+
+        handler_arguments = quote! { #payload_ty { #(#parameters_name),* } };
+        parameters_qualified_type = vec![payload_ty];
+    }
+
     // TODO: what happens with RustQt signals, can they be private yet?
-    if !signal.private {
+    if signal.private_signal && !signal.private {
+        // A private signal can only be emitted by the QObject itself in C++, so the emit goes
+        // through a free function which is a friend of the QObject. The Rust method which calls
+        // it is not public so that it can only be used next to the implementation of the QObject.
+        let free_emit_ident_cpp = idents_helper.emit_name.cxx_unqualified();
+        let free_emit_ident_rust = idents_helper.emit_name.rust_unqualified();
+        let namespace_str = idents_helper.namespace.to_string();
+
+        cxx_bridge.push(quote! {
+            #unsafe_block extern "C++" {
+                #[doc(hidden)]
+                #[namespace = #namespace_str]
+                #[cxx_name = #free_emit_ident_cpp]
+                #unsafe_call fn #free_emit_ident_rust(self_value: #self_type_cxx, #(#parameters_cxx),*);
+            }
+        });
+
+        emit_wrapper = Some(quote! {
+            impl #qualified_impl {
+                #(#doc_comments)*
+                #unsafe_call fn #signal_ident_cpp(self: #self_type_qualified, #(#parameters_qualified_arg),*) {
+                    #module_ident::#free_emit_ident_rust(self, #(#parameters_name),*)
+                }
+            }
+        });
+    } else if !signal.private {
+        let doc_comments: Vec<Attribute> = if signal.payload.is_some() {
+            vec![parse_quote! { #[doc(hidden)] }]
+        } else {
+            doc_comments.clone()
+        };
+
         cxx_bridge.push(quote! {
             #unsafe_block extern "C++" {
                 #[cxx_name = #cpp_ident]
@@ -142,6 +207,11 @@ pub fn generate_rust_signal(
             #[namespace = #namespace_str]
             #[cxx_name = #free_connect_ident_cpp]
             fn #free_connect_ident_rust(self_value: #self_type_cxx, signal_handler: #signal_handler_alias, conn_type: CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
+
+            #[doc(hidden)]
+            #[namespace = #namespace_str]
+            #[cxx_name = #free_connect_with_context_ident_cpp]
+            fn #free_connect_with_context_ident_rust(self_value: #self_type_cxx, context: &CxxQtQObject, signal_handler: #signal_handler_alias, conn_type: CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
         }
     });
 
@@ -156,7 +226,7 @@ pub fn generate_rust_signal(
         }
     });
 
-    let fragment = RustFragmentPair {
+    let mut fragment = RustFragmentPair {
         cxx_bridge,
         implementation: vec![
             quote! {
@@ -191,6 +261,54 @@ pub fn generate_rust_signal(
                     }
                 }
             },
+            quote! {
+                impl #qualified_impl {
+                    #[doc = "Connect the given function pointer to the signal "]
+                    #[doc = #signal_name_cpp]
+                    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+                    #[doc = "\n"]
+                    #[doc = "The connection is disconnected when the context object is destroyed."]
+                    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+                    #[doc = "\n"]
+                    #[doc = "# Safety"]
+                    #[doc = "\n"]
+                    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+                    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+                    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+                    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+                    pub unsafe fn #connect_with_context_ident_rust<F: FnMut(#self_type_qualified, #(#parameters_qualified_type),*) + Send + 'static>(self: #self_type_qualified, context: &impl cxx_qt::ConnectionContext, mut closure: F, conn_type: cxx_qt::ConnectionType) -> cxx_qt::QMetaObjectConnectionGuard
+                    {
+                        cxx_qt::QMetaObjectConnectionGuard::from(context.with_context(|context| #module_ident::#free_connect_with_context_ident_rust(
+                            self,
+                            context,
+                            cxx_qt::signalhandler::CxxQtSignalHandler::<#closure_struct>::new(Box::new(closure)),
+                            conn_type,
+                        )).unwrap_or_default())
+                    }
+                }
+            },
+            quote! {
+                impl #qualified_impl {
+                    #[doc = "Connect the given function pointer to the signal "]
+                    #[doc = #signal_name_cpp]
+                    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+                    #[doc = "\n"]
+                    #[doc = "Note that this method uses a AutoConnection connection type."]
+                    #[doc = "\n"]
+                    #[doc = "# Safety"]
+                    #[doc = "\n"]
+                    #[doc = "See the safety section of the connect method with a context object."]
+                    pub unsafe fn #on_with_context_ident_rust<F: FnMut(#self_type_qualified, #(#parameters_qualified_type),*) + Send + 'static>(self: #self_type_qualified, context: &impl cxx_qt::ConnectionContext, mut closure: F) -> cxx_qt::QMetaObjectConnectionGuard
+                    {
+                        cxx_qt::QMetaObjectConnectionGuard::from(context.with_context(|context| #module_ident::#free_connect_with_context_ident_rust(
+                            self,
+                            context,
+                            cxx_qt::signalhandler::CxxQtSignalHandler::<#closure_struct>::new(Box::new(closure)),
+                            cxx_qt::ConnectionType::AutoConnection,
+                        )).unwrap_or_default())
+                    }
+                }
+            },
             quote! {
                 #[doc(hidden)]
                 pub struct #closure_struct {}
@@ -210,7 +328,7 @@ pub fn generate_rust_signal(
                     self_value: #self_type_qualified,
                     #(#parameters_qualified_arg),*
                 ) {
-                    handler.closure()(self_value, #(#parameters_name),*);
+                    handler.closure()(self_value, #handler_arguments);
                 }
             },
             quote! {
@@ -221,16 +339,13 @@ pub fn generate_rust_signal(
             },
         ],
     };
+    fragment.implementation.extend(emit_wrapper);
 
-    let mut generated = GeneratedRustFragment::default();
-    generated
-        .cxx_mod_contents
-        .append(&mut fragment.cxx_bridge_as_items()?);
-    generated
-        .cxx_qt_mod_contents
-        .append(&mut fragment.implementation_as_items()?);
-
-    Ok(generated)
+    Ok(GeneratedRustFragment {
+        cxx_mod_contents: fragment.cxx_bridge_as_items()?,
+        cxx_qt_mod_contents: fragment.implementation_as_items()?,
+    }
+    .with_cfgs(&signal.rust_cfgs()))
 }
 
 pub fn generate_rust_signals(
@@ -264,7 +379,7 @@ mod tests {
 
     fn common_asserts(cxx_mod_contents: &Vec<Item>, cxx_qt_mod_contents: &Vec<Item>) {
         assert_eq!(cxx_mod_contents.len(), 2);
-        assert_eq!(cxx_qt_mod_contents.len(), 8);
+        assert_eq!(cxx_qt_mod_contents.len(), 10);
 
         assert_tokens_eq(
             &cxx_mod_contents[0],
@@ -278,6 +393,11 @@ mod tests {
                     #[namespace = "rust::cxxqtgen1"]
                     #[cxx_name = "MyObject_readyConnect"]
                     fn MyObject_connect_ready(self_value: Pin<&mut MyObject>, signal_handler: MyObjectCxxQtSignalHandlerready, conn_type: CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtgen1"]
+                    #[cxx_name = "MyObject_readyConnectWithContext"]
+                    fn MyObject_connect_ready_with_context(self_value: Pin<&mut MyObject>, context: &CxxQtQObject, signal_handler: MyObjectCxxQtSignalHandlerready, conn_type: CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
                 }
             },
         );
@@ -335,13 +455,67 @@ mod tests {
         );
         assert_tokens_eq(
             &cxx_qt_mod_contents[2],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc = "Connect the given function pointer to the signal "]
+                    #[doc = "ready"]
+                    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+                    #[doc = "\n"]
+                    #[doc = "The connection is disconnected when the context object is destroyed."]
+                    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+                    #[doc = "\n"]
+                    #[doc = "# Safety"]
+                    #[doc = "\n"]
+                    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+                    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+                    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+                    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+                    pub unsafe fn connect_ready_with_context<F: FnMut(core::pin::Pin<&mut qobject::MyObject>, ) + Send + 'static>(self: core::pin::Pin<&mut qobject::MyObject>, context: &impl cxx_qt::ConnectionContext, mut closure: F, conn_type: cxx_qt::ConnectionType) -> cxx_qt::QMetaObjectConnectionGuard
+                    {
+                        cxx_qt::QMetaObjectConnectionGuard::from(context.with_context(|context| qobject::MyObject_connect_ready_with_context(
+                            self,
+                            context,
+                            cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosureready>::new(Box::new(closure)),
+                            conn_type,
+                        )).unwrap_or_default())
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &cxx_qt_mod_contents[3],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc = "Connect the given function pointer to the signal "]
+                    #[doc = "ready"]
+                    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+                    #[doc = "\n"]
+                    #[doc = "Note that this method uses a AutoConnection connection type."]
+                    #[doc = "\n"]
+                    #[doc = "# Safety"]
+                    #[doc = "\n"]
+                    #[doc = "See the safety section of the connect method with a context object."]
+                    pub unsafe fn on_ready_with_context<F: FnMut(core::pin::Pin<&mut qobject::MyObject>, ) + Send + 'static>(self: core::pin::Pin<&mut qobject::MyObject>, context: &impl cxx_qt::ConnectionContext, mut closure: F) -> cxx_qt::QMetaObjectConnectionGuard
+                    {
+                        cxx_qt::QMetaObjectConnectionGuard::from(context.with_context(|context| qobject::MyObject_connect_ready_with_context(
+                            self,
+                            context,
+                            cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosureready>::new(Box::new(closure)),
+                            cxx_qt::ConnectionType::AutoConnection,
+                        )).unwrap_or_default())
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &cxx_qt_mod_contents[4],
             quote! {
                 #[doc(hidden)]
                 pub struct MyObjectCxxQtSignalClosureready {}
             },
         );
         assert_tokens_eq(
-            &cxx_qt_mod_contents[3],
+            &cxx_qt_mod_contents[5],
             quote! {
                 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosureready {
                     type Id = cxx::type_id!("::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerready");
@@ -350,13 +524,13 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &cxx_qt_mod_contents[4],
+            &cxx_qt_mod_contents[6],
             quote! {
                 use core::mem::drop as drop_MyObject_signal_handler_ready;
             },
         );
         assert_tokens_eq(
-            &cxx_qt_mod_contents[5],
+            &cxx_qt_mod_contents[7],
             quote! {
                 fn call_MyObject_signal_handler_ready(
                     handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureready>,
@@ -367,13 +541,13 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &cxx_qt_mod_contents[6],
+            &cxx_qt_mod_contents[8],
             quote! {
                 cxx_qt::static_assertions::assert_eq_align!(cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureready>, usize);
             },
         );
         assert_tokens_eq(
-            &cxx_qt_mod_contents[7],
+            &cxx_qt_mod_contents[9],
             quote! {
                 cxx_qt::static_assertions::assert_eq_size!(cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureready>, [usize; 2]);
             },
@@ -428,7 +602,7 @@ mod tests {
             generate_rust_signals(&vec![&qsignal], &qobject_names, &type_names).unwrap();
 
         assert_eq!(generated.cxx_mod_contents.len(), 3);
-        assert_eq!(generated.cxx_qt_mod_contents.len(), 8);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 10);
 
         assert_tokens_eq(
             &generated.cxx_mod_contents[0],
@@ -451,6 +625,11 @@ mod tests {
                     #[namespace = "rust::cxxqtgen1"]
                     #[cxx_name = "MyObject_dataChangedConnect"]
                     fn MyObject_connect_data_changed(self_value: Pin<&mut MyObject>, signal_handler: MyObjectCxxQtSignalHandlerdataChanged, conn_type: CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtgen1"]
+                    #[cxx_name = "MyObject_dataChangedConnectWithContext"]
+                    fn MyObject_connect_data_changed_with_context(self_value: Pin<&mut MyObject>, context: &CxxQtQObject, signal_handler: MyObjectCxxQtSignalHandlerdataChanged, conn_type: CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
                 }
             },
         );
@@ -507,14 +686,14 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[2],
+            &generated.cxx_qt_mod_contents[4],
             quote! {
                 #[doc(hidden)]
                 pub struct MyObjectCxxQtSignalClosuredataChanged {}
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[3],
+            &generated.cxx_qt_mod_contents[5],
             quote! {
                 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosuredataChanged {
                     type Id = cxx::type_id!("::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerdataChanged");
@@ -523,13 +702,13 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[4],
+            &generated.cxx_qt_mod_contents[6],
             quote! {
                 use core::mem::drop as drop_MyObject_signal_handler_dataChanged;
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[5],
+            &generated.cxx_qt_mod_contents[7],
             quote! {
                 fn call_MyObject_signal_handler_dataChanged(
                     handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuredataChanged>,
@@ -542,19 +721,161 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[6],
+            &generated.cxx_qt_mod_contents[8],
             quote! {
                 cxx_qt::static_assertions::assert_eq_align!(cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuredataChanged>, usize);
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[7],
+            &generated.cxx_qt_mod_contents[9],
             quote! {
                 cxx_qt::static_assertions::assert_eq_size!(cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuredataChanged>, [usize; 2]);
             },
         );
     }
 
+    #[test]
+    fn test_generate_rust_signal_flatten() {
+        let method: ForeignItemFn = parse_quote! {
+            /// Emitted when the object moves
+            #[flatten]
+            fn moved(self: Pin<&mut MyObject>, point: Point);
+        };
+        let mut qsignal = ParsedSignal::mock(&method);
+        let items: Vec<Item> = vec![parse_quote! {
+            struct Point {
+                x: f64,
+                y: f64,
+            }
+        }];
+        qsignal.flatten_payload(&items).unwrap();
+        let qobject_names = create_qobjectname();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("Point", Some(format_ident!("qobject")), None, None);
+        let generated =
+            generate_rust_signals(&vec![&qsignal], &qobject_names, &type_names).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 3);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 11);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[cxx_name = "moved"]
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_moved_flattened(self: Pin<&mut MyObject>, x: f64, y: f64);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[2],
+            quote! {
+                #[namespace = "rust::cxxqtgen1"]
+                extern "Rust" {
+                    #[doc(hidden)]
+                    fn drop_MyObject_signal_handler_moved(handler: MyObjectCxxQtSignalHandlermoved);
+
+                    #[doc(hidden)]
+                    fn call_MyObject_signal_handler_moved(handler: &mut MyObjectCxxQtSignalHandlermoved, self_value: Pin<&mut MyObject>, x: f64, y: f64);
+                }
+            },
+        );
+
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc = "Connect the given function pointer to the signal "]
+                    #[doc = "moved"]
+                    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+                    pub fn connect_moved<F: FnMut(core::pin::Pin<&mut qobject::MyObject>, qobject::Point) + 'static>(self: core::pin::Pin<&mut qobject::MyObject>, mut closure: F, conn_type: cxx_qt::ConnectionType) -> cxx_qt::QMetaObjectConnectionGuard
+                    {
+                        cxx_qt::QMetaObjectConnectionGuard::from(qobject::MyObject_connect_moved(
+                            self,
+                            cxx_qt::signalhandler::CxxQtSignalHandler::<MyObjectCxxQtSignalClosuremoved>::new(Box::new(closure)),
+                            conn_type,
+                        ))
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[5],
+            quote! {
+                impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosuremoved {
+                    type Id = cxx::type_id!("::rust::cxxqtgen1::MyObjectCxxQtSignalHandlermoved");
+                    type FnType = dyn FnMut(core::pin::Pin<&mut qobject::MyObject>, qobject::Point);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[7],
+            quote! {
+                fn call_MyObject_signal_handler_moved(
+                    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuremoved>,
+                    self_value: core::pin::Pin<&mut qobject::MyObject>,
+                    x: f64,
+                    y: f64
+                ) {
+                    handler.closure()(self_value, qobject::Point { x, y });
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[10],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc = r" Emitted when the object moves"]
+                    pub fn moved(self: core::pin::Pin<&mut qobject::MyObject>, point: qobject::Point) {
+                        self.cxx_qt_ffi_moved_flattened(point.x, point.y)
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_signal_private_signal() {
+        let method: ForeignItemFn = parse_quote! {
+            /// Emitted when ready
+            #[private_signal]
+            fn ready(self: Pin<&mut MyObject>, value: i32);
+        };
+        let qsignal = ParsedSignal::mock(&method);
+        let qobject_names = create_qobjectname();
+
+        let generated =
+            generate_rust_signals(&vec![&qsignal], &qobject_names, &TypeNames::mock()).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 3);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 11);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtgen1"]
+                    #[cxx_name = "MyObject_readyEmit"]
+                    fn MyObject_emit_ready(self_value: Pin<&mut MyObject>, value: i32);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[10],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc = r" Emitted when ready"]
+                    fn ready(self: core::pin::Pin<&mut qobject::MyObject>, value: i32) {
+                        qobject::MyObject_emit_ready(self, value)
+                    }
+                }
+            },
+        );
+    }
+
     #[test]
     fn test_generate_rust_signal_unsafe() {
         let method = parse_quote! {
@@ -569,7 +890,7 @@ mod tests {
             generate_rust_signals(&vec![&qsignal], &qobject_names, &type_names).unwrap();
 
         assert_eq!(generated.cxx_mod_contents.len(), 3);
-        assert_eq!(generated.cxx_qt_mod_contents.len(), 8);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 10);
 
         assert_tokens_eq(
             &generated.cxx_mod_contents[0],
@@ -592,6 +913,11 @@ mod tests {
                     #[namespace = "rust::cxxqtgen1"]
                     #[cxx_name = "MyObject_unsafeSignalConnect"]
                     fn MyObject_connect_unsafe_signal(self_value: Pin<&mut MyObject>, signal_handler: MyObjectCxxQtSignalHandlerunsafeSignal, conn_type: CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtgen1"]
+                    #[cxx_name = "MyObject_unsafeSignalConnectWithContext"]
+                    fn MyObject_connect_unsafe_signal_with_context(self_value: Pin<&mut MyObject>, context: &CxxQtQObject, signal_handler: MyObjectCxxQtSignalHandlerunsafeSignal, conn_type: CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
                 }
             },
         );
@@ -648,14 +974,14 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[2],
+            &generated.cxx_qt_mod_contents[4],
             quote! {
                 #[doc(hidden)]
                 pub struct MyObjectCxxQtSignalClosureunsafeSignal {}
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[3],
+            &generated.cxx_qt_mod_contents[5],
             quote! {
                 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosureunsafeSignal {
                     type Id = cxx::type_id!("::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerunsafeSignal");
@@ -664,13 +990,13 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[4],
+            &generated.cxx_qt_mod_contents[6],
             quote! {
                 use core::mem::drop as drop_MyObject_signal_handler_unsafeSignal;
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[5],
+            &generated.cxx_qt_mod_contents[7],
             quote! {
                 fn call_MyObject_signal_handler_unsafeSignal(
                     handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureunsafeSignal>,
@@ -682,13 +1008,13 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[6],
+            &generated.cxx_qt_mod_contents[8],
             quote! {
                 cxx_qt::static_assertions::assert_eq_align!(cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureunsafeSignal>, usize);
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[7],
+            &generated.cxx_qt_mod_contents[9],
             quote! {
                 cxx_qt::static_assertions::assert_eq_size!(cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosureunsafeSignal>, [usize; 2]);
             },
@@ -712,7 +1038,7 @@ mod tests {
             generate_rust_signals(&vec![&qsignal], &qobject_names, &TypeNames::mock()).unwrap();
 
         assert_eq!(generated.cxx_mod_contents.len(), 3);
-        assert_eq!(generated.cxx_qt_mod_contents.len(), 8);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 10);
 
         assert_tokens_eq(
             &generated.cxx_mod_contents[0],
@@ -735,6 +1061,11 @@ mod tests {
                     #[namespace = "rust::cxxqtgen1"]
                     #[cxx_name = "MyObject_baseNameConnect"]
                     fn MyObject_connect_existing_signal(self_value: Pin<&mut MyObject>, signal_handler: MyObjectCxxQtSignalHandlerbaseName, conn_type: CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
+
+                    #[doc(hidden)]
+                    #[namespace = "rust::cxxqtgen1"]
+                    #[cxx_name = "MyObject_baseNameConnectWithContext"]
+                    fn MyObject_connect_existing_signal_with_context(self_value: Pin<&mut MyObject>, context: &CxxQtQObject, signal_handler: MyObjectCxxQtSignalHandlerbaseName, conn_type: CxxQtConnectionType) -> CxxQtQMetaObjectConnection;
                 }
             },
         );
@@ -791,14 +1122,14 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[2],
+            &generated.cxx_qt_mod_contents[4],
             quote! {
                 #[doc(hidden)]
                 pub struct MyObjectCxxQtSignalClosurebaseName {}
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[3],
+            &generated.cxx_qt_mod_contents[5],
             quote! {
                 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosurebaseName {
                     type Id = cxx::type_id!("::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerbaseName");
@@ -807,13 +1138,13 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[4],
+            &generated.cxx_qt_mod_contents[6],
             quote! {
                 use core::mem::drop as drop_MyObject_signal_handler_baseName;
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[5],
+            &generated.cxx_qt_mod_contents[7],
             quote! {
                 fn call_MyObject_signal_handler_baseName(
                     handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurebaseName>,
@@ -824,13 +1155,13 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[6],
+            &generated.cxx_qt_mod_contents[8],
             quote! {
                 cxx_qt::static_assertions::assert_eq_align!(cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurebaseName>, usize);
             },
         );
         assert_tokens_eq(
-            &generated.cxx_qt_mod_contents[7],
+            &generated.cxx_qt_mod_contents[9],
             quote! {
                 cxx_qt::static_assertions::assert_eq_size!(cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurebaseName>, [usize; 2]);
             },
@@ -0,0 +1,99 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::{
+    naming::{namespace::NamespaceName, qobject::QObjectNames},
+    rust::fragment::{GeneratedRustFragment, RustFragmentPair},
+};
+use convert_case::{Case, Casing};
+use quote::{format_ident, quote};
+use syn::Result;
+
+pub fn generate(
+    qobject_names: &QObjectNames,
+    namespace_idents: &NamespaceName,
+    signature_hash: u64,
+) -> Result<GeneratedRustFragment> {
+    let mut blocks = GeneratedRustFragment::default();
+
+    let signature_ident = format_ident!(
+        "cxx_qt_ffi_{}_signature_{signature_hash:016x}",
+        qobject_names
+            .name
+            .rust_unqualified()
+            .to_string()
+            .to_case(Case::Snake)
+    );
+    // The hash is part of both the C++ name and the Rust name, which CXX uses for the exported
+    // symbol, so a C++ side generated from a different bridge signature fails to link
+    let signature_cxx_name = format!("cxxQtSignature{signature_hash:016x}");
+    let namespace_internals = &namespace_idents.internal;
+
+    let fragment = RustFragmentPair {
+        cxx_bridge: vec![quote! {
+            extern "Rust" {
+                #[cxx_name = #signature_cxx_name]
+                #[namespace = #namespace_internals]
+                #[doc(hidden)]
+                fn #signature_ident();
+            }
+        }],
+        implementation: vec![quote! {
+            #[doc(hidden)]
+            fn #signature_ident() {}
+        }],
+    };
+
+    blocks
+        .cxx_mod_contents
+        .append(&mut fragment.cxx_bridge_as_items()?);
+    blocks
+        .cxx_qt_mod_contents
+        .append(&mut fragment.implementation_as_items()?);
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::tests::assert_tokens_eq;
+    use quote::format_ident;
+
+    #[test]
+    fn test_generate_rust_signature() {
+        let qobject_names = create_qobjectname();
+        let namespace_idents = NamespaceName::from_namespace_and_ident(
+            "cxx_qt::my_object",
+            &format_ident!("MyObject"),
+        );
+
+        let generated = generate(&qobject_names, &namespace_idents, 0x0123_4567_89ab_cdef).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "cxxQtSignature0123456789abcdef"]
+                    #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_my_object_signature_0123456789abcdef();
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                #[doc(hidden)]
+                fn cxx_qt_ffi_my_object_signature_0123456789abcdef() {}
+            },
+        );
+    }
+}
@@ -42,6 +42,12 @@ pub fn generate(
         .into_cxx_parts();
     let (thread_fn_name, thread_fn_attrs, thread_fn_qualified) =
         qobject_names.cxx_qt_ffi_method("qtThread").into_cxx_parts();
+    let (thread_lock_name, thread_lock_attrs, thread_lock_qualified) = qobject_names
+        .cxx_qt_ffi_method("cxxQtThreadLock")
+        .into_cxx_parts();
+    let (thread_unlock_name, thread_unlock_attrs, thread_unlock_qualified) = qobject_names
+        .cxx_qt_ffi_method("cxxQtThreadUnlock")
+        .into_cxx_parts();
 
     let namespace_internals = &namespace_ident.internal;
     let cxx_qt_thread_ident_type_id_str =
@@ -69,14 +75,16 @@ pub fn generate(
 
                     // SAFETY:
                     // - Send + 'static: argument closure can be transferred to QObject thread.
-                    // - FnOnce: QMetaObject::invokeMethod() should call the function at most once.
+                    // - FnOnce: the posted event should call the function at most once.
                     #[doc(hidden)]
                     #(#thread_queue_attrs)*
                     fn #thread_queue_name(
                         cxx_qt_thread: &#cxx_qt_thread_ident,
                         func: fn(Pin<&mut #cpp_struct_ident>, Box<#cxx_qt_thread_queued_fn_ident>),
                         arg: Box<#cxx_qt_thread_queued_fn_ident>,
-                    ) -> Result<()>;
+                        priority: i32,
+                        capacity: usize,
+                    ) -> Result<bool>;
 
                     #[doc(hidden)]
                     #(#thread_clone_attrs)*
@@ -85,6 +93,14 @@ pub fn generate(
                     #[doc(hidden)]
                     #(#thread_drop_attrs)*
                     fn #thread_drop_name(cxx_qt_thread: &mut #cxx_qt_thread_ident);
+
+                    #[doc(hidden)]
+                    #(#thread_lock_attrs)*
+                    fn #thread_lock_name(cxx_qt_thread: &#cxx_qt_thread_ident) -> *const #cpp_struct_ident;
+
+                    #[doc(hidden)]
+                    #(#thread_unlock_attrs)*
+                    fn #thread_unlock_name(cxx_qt_thread: &#cxx_qt_thread_ident);
                 }
             },
             quote! {
@@ -106,7 +122,12 @@ pub fn generate(
                     }
 
                     #[doc(hidden)]
-                    fn queue<F>(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident, f: F) -> std::result::Result<(), cxx::Exception>
+                    fn queue<F>(
+                        cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident,
+                        f: F,
+                        priority: cxx_qt::EventPriority,
+                        capacity: usize,
+                    ) -> std::result::Result<bool, cxx::Exception>
                     where
                         F: FnOnce(core::pin::Pin<&mut #qualified_impl>),
                         F: Send + 'static,
@@ -123,7 +144,7 @@ pub fn generate(
                             (arg.inner)(obj)
                         }
                         let arg = #cxx_qt_thread_queued_fn_ident { inner: std::boxed::Box::new(f) };
-                        #thread_queue_qualified(cxx_qt_thread, func, std::boxed::Box::new(arg))
+                        #thread_queue_qualified(cxx_qt_thread, func, std::boxed::Box::new(arg), priority as i32, capacity)
                     }
 
                     #[doc(hidden)]
@@ -137,6 +158,18 @@ pub fn generate(
                     {
                         #thread_drop_qualified(cxx_qt_thread);
                     }
+
+                    #[doc(hidden)]
+                    fn threading_lock(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident) -> *const #qualified_impl
+                    {
+                        #thread_lock_qualified(cxx_qt_thread)
+                    }
+
+                    #[doc(hidden)]
+                    unsafe fn threading_unlock(cxx_qt_thread: &#module_ident::#cxx_qt_thread_ident)
+                    {
+                        #thread_unlock_qualified(cxx_qt_thread);
+                    }
                 }
             },
             quote! {
@@ -197,7 +230,7 @@ mod tests {
 
                     // SAFETY:
                     // - Send + 'static: argument closure can be transferred to QObject thread.
-                    // - FnOnce: QMetaObject::invokeMethod() should call the function at most once.
+                    // - FnOnce: the posted event should call the function at most once.
                     #[doc(hidden)]
                     #[cxx_name = "cxxQtThreadQueue"]
                     #[namespace = "rust::cxxqt1"]
@@ -205,7 +238,9 @@ mod tests {
                         cxx_qt_thread: &MyObjectCxxQtThread,
                         func: fn(Pin<&mut MyObject>, Box<MyObjectCxxQtThreadQueuedFn>),
                         arg: Box<MyObjectCxxQtThreadQueuedFn>,
-                    ) -> Result<()>;
+                        priority: i32,
+                        capacity: usize,
+                    ) -> Result<bool>;
 
                     #[doc(hidden)]
                     #[cxx_name = "cxxQtThreadClone"]
@@ -216,6 +251,16 @@ mod tests {
                     #[cxx_name = "cxxQtThreadDrop"]
                     #[namespace = "rust::cxxqt1"]
                     fn cxx_qt_ffi_my_object_cxx_qt_thread_drop(cxx_qt_thread: &mut MyObjectCxxQtThread);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtThreadLock"]
+                    #[namespace = "rust::cxxqt1"]
+                    fn cxx_qt_ffi_my_object_cxx_qt_thread_lock(cxx_qt_thread: &MyObjectCxxQtThread) -> *const MyObject;
+
+                    #[doc(hidden)]
+                    #[cxx_name = "cxxQtThreadUnlock"]
+                    #[namespace = "rust::cxxqt1"]
+                    fn cxx_qt_ffi_my_object_cxx_qt_thread_unlock(cxx_qt_thread: &MyObjectCxxQtThread);
                 }
             },
         );
@@ -243,7 +288,12 @@ mod tests {
                     }
 
                     #[doc(hidden)]
-                    fn queue<F>(cxx_qt_thread: &qobject::MyObjectCxxQtThread, f: F) -> std::result::Result<(), cxx::Exception>
+                    fn queue<F>(
+                        cxx_qt_thread: &qobject::MyObjectCxxQtThread,
+                        f: F,
+                        priority: cxx_qt::EventPriority,
+                        capacity: usize,
+                    ) -> std::result::Result<bool, cxx::Exception>
                     where
                         F: FnOnce(core::pin::Pin<&mut qobject::MyObject>),
                         F: Send + 'static,
@@ -260,7 +310,7 @@ mod tests {
                             (arg.inner)(obj)
                         }
                         let arg = MyObjectCxxQtThreadQueuedFn { inner: std::boxed::Box::new(f) };
-                        qobject::cxx_qt_ffi_my_object_cxx_qt_thread_queue(cxx_qt_thread, func, std::boxed::Box::new(arg))
+                        qobject::cxx_qt_ffi_my_object_cxx_qt_thread_queue(cxx_qt_thread, func, std::boxed::Box::new(arg), priority as i32, capacity)
                     }
 
                     #[doc(hidden)]
@@ -274,6 +324,18 @@ mod tests {
                     {
                         qobject::cxx_qt_ffi_my_object_cxx_qt_thread_drop(cxx_qt_thread);
                     }
+
+                    #[doc(hidden)]
+                    fn threading_lock(cxx_qt_thread: &qobject::MyObjectCxxQtThread) -> *const qobject::MyObject
+                    {
+                        qobject::cxx_qt_ffi_my_object_cxx_qt_thread_lock(cxx_qt_thread)
+                    }
+
+                    #[doc(hidden)]
+                    unsafe fn threading_unlock(cxx_qt_thread: &qobject::MyObjectCxxQtThread)
+                    {
+                        qobject::cxx_qt_ffi_my_object_cxx_qt_thread_unlock(cxx_qt_thread);
+                    }
                 }
             },
         );
@@ -170,6 +170,58 @@ mod tests {
         assert!(qobject.method_lookup(&format_ident!("test_fn")).is_ok());
     }
 
+    #[test]
+    fn test_signature_hash() {
+        fn signature_hash(module: ItemMod) -> u64 {
+            let parser = Parser::from(module).unwrap();
+            let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+            structures.qobjects.first().unwrap().signature_hash()
+        }
+
+        let original = signature_hash(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    fn invokable(self: &MyObject, value: i32);
+                }
+            }
+        });
+        // Formatting and documentation do not change the signature
+        let documented = signature_hash(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    /// The QObject
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+
+                    /// An invokable
+                    #[qinvokable]
+                    fn invokable(self: &MyObject, value: i32);
+                }
+            }
+        });
+        let changed = signature_hash(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    fn invokable(self: &MyObject, value: i64);
+                }
+            }
+        });
+
+        assert_eq!(original, documented);
+        assert_ne!(original, changed);
+    }
+
     #[test]
     fn test_structures() {
         let module = parse_quote! {
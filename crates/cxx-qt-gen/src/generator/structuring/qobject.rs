@@ -8,9 +8,11 @@ use crate::naming::Name;
 use crate::parser::constructor::Constructor;
 use crate::parser::inherit::ParsedInheritedMethod;
 use crate::parser::method::ParsedMethod;
+use crate::parser::property::FlagState;
 use crate::parser::signals::ParsedSignal;
 use crate::parser::{qenum::ParsedQEnum, qobject::ParsedQObject};
-use proc_macro2::Ident;
+use proc_macro2::{Delimiter, Ident, TokenTree};
+use quote::ToTokens;
 use syn::Result;
 
 /// The StructuredQObject contains the parsed QObject and all members.
@@ -33,6 +35,61 @@ fn lookup<T>(invokables: &[T], id: &Ident, name_getter: impl Fn(&T) -> &Name) ->
         .cloned()
 }
 
+/// A FNV-1a hasher over the parts of a bridge which affect the generated code
+///
+/// This is used instead of the std hashers as the hash must be stable between the build script
+/// and the macro expansion, even if they were built separately.
+struct SignatureHasher(u64);
+
+impl SignatureHasher {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write_str(&mut self, value: &str) {
+        // Terminate each value so that neighbouring values cannot be merged
+        for byte in value.bytes().chain(std::iter::once(0)) {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn write_bool(&mut self, value: bool) {
+        self.write_str(if value { "true" } else { "false" });
+    }
+
+    fn write_flag(&mut self, flag: Option<&FlagState>) {
+        match flag {
+            Some(FlagState::Auto) => self.write_str("auto"),
+            Some(FlagState::Custom(ident)) => self.write_str(&ident.to_string()),
+            None => self.write_str("none"),
+        }
+    }
+
+    /// Hash the tokens one at a time, as the string form of a whole TokenStream
+    /// differs between proc_macro and the proc_macro2 fallback used by the build script
+    fn write_tokens(&mut self, tokens: impl ToTokens) {
+        for token in tokens.into_token_stream() {
+            match token {
+                TokenTree::Group(group) => {
+                    let (open, close) = match group.delimiter() {
+                        Delimiter::Parenthesis => ("(", ")"),
+                        Delimiter::Brace => ("{", "}"),
+                        Delimiter::Bracket => ("[", "]"),
+                        Delimiter::None => ("", ""),
+                    };
+                    self.write_str(open);
+                    self.write_tokens(group.stream());
+                    self.write_str(close);
+                }
+                TokenTree::Ident(ident) => self.write_str(&ident.to_string()),
+                TokenTree::Punct(punct) => self.write_str(&punct.as_char().to_string()),
+                TokenTree::Literal(literal) => self.write_str(&literal.to_string()),
+            }
+        }
+    }
+}
+
 impl<'a> StructuredQObject<'a> {
     pub fn has_qobject_name(&self, ident: &Ident) -> bool {
         self.declaration.name.rust_unqualified() == ident
@@ -64,6 +121,93 @@ impl<'a> StructuredQObject<'a> {
             .ok_or_else(|| not_found_error("Signal", id))
     }
 
+    /// Returns a hash of the signature of the QObject and its members
+    ///
+    /// This is emitted into both the Rust and C++ generation, so that mixing stale generated
+    /// files with newer ones fails to link rather than misbehaving at runtime.
+    pub fn signature_hash(&self) -> u64 {
+        let mut hasher = SignatureHasher::new();
+        let qobject = self.declaration;
+
+        hasher.write_str(&qobject.name.cxx_qualified());
+        hasher.write_tokens(qobject.name.rust_unqualified());
+        hasher.write_tokens(&qobject.rust_type);
+        hasher.write_tokens(&qobject.base_class);
+        hasher.write_bool(qobject.has_qobject_macro);
+        hasher.write_bool(
+            qobject
+                .qml_metadata
+                .as_ref()
+                .is_some_and(|qml_metadata| qml_metadata.lazy),
+        );
+        hasher.write_bool(self.threading);
+
+        // Members behind a #[cfg] are skipped, as the build script only generates C++ for the
+        // enabled members whereas the macro sees all of them
+        for property in qobject
+            .properties
+            .iter()
+            .filter(|property| property.cfgs.is_empty())
+        {
+            hasher.write_str(&property.name.cxx_unqualified());
+            hasher.write_tokens(&property.ty);
+            hasher.write_flag(Some(&property.flags.read));
+            hasher.write_flag(property.flags.write.as_ref());
+            hasher.write_flag(property.flags.notify.as_ref());
+            hasher.write_tokens(&property.flags.reset);
+            hasher.write_bool(property.flags.is_final);
+            hasher.write_bool(property.flags.constant);
+            hasher.write_bool(property.flags.required);
+        }
+
+        for method in self.methods.iter().filter(|method| method.cfgs.is_empty()) {
+            hasher.write_str(&method.name.cxx_unqualified());
+            hasher.write_tokens(&method.method_fields.method.sig);
+            hasher.write_bool(method.is_qinvokable);
+            let mut specifiers = method
+                .specifiers
+                .iter()
+                .map(|specifier| specifier.as_str())
+                .collect::<Vec<_>>();
+            specifiers.sort_unstable();
+            for specifier in specifiers {
+                hasher.write_str(specifier);
+            }
+        }
+
+        for signal in self.signals.iter().filter(|signal| signal.cfgs.is_empty()) {
+            hasher.write_str(&signal.name.cxx_unqualified());
+            hasher.write_tokens(&signal.method_fields.method.sig);
+            hasher.write_bool(signal.inherit);
+            hasher.write_bool(signal.private);
+        }
+
+        for inherited in self
+            .inherited_methods
+            .iter()
+            .filter(|inherited| inherited.cfgs.is_empty())
+        {
+            hasher.write_str(&inherited.name.cxx_unqualified());
+            hasher.write_tokens(&inherited.method_fields.method.sig);
+        }
+
+        for constructor in &self.constructors {
+            for arguments in [
+                &constructor.arguments,
+                &constructor.new_arguments,
+                &constructor.base_arguments,
+                &constructor.initialize_arguments,
+            ] {
+                hasher.write_str("constructor");
+                for argument in arguments {
+                    hasher.write_tokens(argument);
+                }
+            }
+        }
+
+        hasher.0
+    }
+
     #[cfg(test)]
     pub fn mock(obj: &'a ParsedQObject) -> Self {
         Self::from_qobject(obj)
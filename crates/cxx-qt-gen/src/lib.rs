@@ -16,11 +16,12 @@ mod writer;
 
 pub use generator::{
     cpp::{fragment::CppFragment, GeneratedCppBlocks},
+    rep::{GeneratedRepBlocks, GeneratedRepClass},
     rust::GeneratedRustBlocks,
 };
 pub use parser::Parser;
-pub use syntax::{parse_qt_file, CxxQtFile, CxxQtItem};
-pub use writer::{cpp::write_cpp, rust::write_rust};
+pub use syntax::{parse_qt_file, CfgEvaluator, CfgResult, CxxQtFile, CxxQtItem};
+pub use writer::{cpp::write_cpp, rep::write_rep, rust::write_rust};
 
 pub use syn::{Error, Result};
 
@@ -222,6 +223,16 @@ mod tests {
         test_code_generation!("qenum");
     }
 
+    #[test]
+    fn generates_qml_singleton() {
+        test_code_generation!("qml_singleton");
+    }
+
+    #[test]
+    fn generates_qobject_alias() {
+        test_code_generation!("qobject_alias");
+    }
+
     #[test]
     #[should_panic]
     fn fail_token_assert() {
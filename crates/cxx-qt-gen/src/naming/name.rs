@@ -8,6 +8,8 @@ use convert_case::{Case, Casing};
 use quote::format_ident;
 use syn::{spanned::Spanned, Attribute, Error, Ident, Path, Result};
 
+/// Whether C++ names should be converted to camelCase when no `cxx_name` is given
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AutoCamel {
     Enabled,
     Disabled,
@@ -210,6 +212,12 @@ impl Name {
         self.cxx.clone().unwrap_or_else(|| self.rust.to_string())
     }
 
+    /// Whether a C++ name is set which is different from the Rust name,
+    /// either explicitly or by an automatic conversion to camelCase
+    pub fn has_cxx_name(&self) -> bool {
+        self.cxx.is_some()
+    }
+
     /// Get the unqualified name of the type in Rust.
     /// This is either;
     /// - The rust_name attribute value, if one is provided
@@ -5,18 +5,25 @@
 
 use super::qnamespace::ParsedQNamespace;
 use super::trait_impl::TraitImpl;
-use crate::naming::cpp::err_unsupported_item;
+use crate::naming::{cpp::err_unsupported_item, AutoCamel};
 use crate::{
     parser::{
         externcxxqt::ParsedExternCxxQt, inherit::ParsedInheritedMethod, method::ParsedMethod,
         qenum::ParsedQEnum, qobject::ParsedQObject, require_attributes, signals::ParsedSignal,
     },
     syntax::{
-        attribute::attribute_get_path, expr::expr_to_string, foreignmod::ForeignTypeIdentAlias,
-        path::path_compare_str, safety::Safety,
+        attribute::attribute_get_path,
+        cfg::{try_eval_attributes, CfgEvaluator},
+        expr::expr_to_string,
+        foreignmod::ForeignTypeIdentAlias,
+        path::path_compare_str,
+        safety::Safety,
     },
 };
-use syn::{ForeignItem, Ident, Item, ItemEnum, ItemForeignMod, ItemImpl, ItemMacro, Meta, Result};
+use syn::{
+    spanned::Spanned, Attribute, Error, ForeignItem, Ident, Item, ItemEnum, ItemForeignMod,
+    ItemImpl, ItemMacro, Meta, Result,
+};
 
 pub struct ParsedCxxQtData {
     /// Map of the QObjects defined in the module that will be used for code generation
@@ -42,6 +49,8 @@ pub struct ParsedCxxQtData {
     pub trait_impls: Vec<TraitImpl>,
     /// The ident of the module, used for mappings
     pub module_ident: Ident,
+    /// Whether the C++ names of invokables, signals, and properties are converted to camelCase
+    pub auto_camel: AutoCamel,
 }
 
 impl ParsedCxxQtData {
@@ -58,6 +67,7 @@ impl ParsedCxxQtData {
             extern_cxxqt_blocks: Vec::<ParsedExternCxxQt>::default(),
             module_ident,
             namespace,
+            auto_camel: AutoCamel::Enabled,
         }
     }
 
@@ -146,7 +156,13 @@ impl ParsedCxxQtData {
                 ForeignItem::Fn(foreign_fn) => {
                     // Test if the function is a signal
                     if attribute_get_path(&foreign_fn.attrs, &["qsignal"]).is_some() {
-                        let parsed_signal_method = ParsedSignal::parse(foreign_fn, safe_call)?;
+                        let mut parsed_signal_method = ParsedSignal::parse(foreign_fn, safe_call)?;
+                        // Inherited signals refer to an existing signal of the base class
+                        if !parsed_signal_method.inherit {
+                            parsed_signal_method
+                                .method_fields
+                                .apply_auto_camel(self.auto_camel)?;
+                        }
                         self.signals.push(parsed_signal_method);
 
                         // Test if the function is an inheritance method
@@ -159,7 +175,10 @@ impl ParsedCxxQtData {
                         self.inherited_methods.push(parsed_inherited_method);
                         // Remaining methods are either C++ methods or invokables
                     } else {
-                        let parsed_method = ParsedMethod::parse(foreign_fn, safe_call)?;
+                        let mut parsed_method = ParsedMethod::parse(foreign_fn, safe_call)?;
+                        parsed_method
+                            .method_fields
+                            .apply_auto_camel(self.auto_camel)?;
                         self.methods.push(parsed_method);
                     }
                 }
@@ -171,6 +190,7 @@ impl ParsedCxxQtData {
                         foreign_alias,
                         namespace.as_deref(),
                         &self.module_ident,
+                        self.auto_camel,
                     )?;
 
                     // Note that we assume a compiler error will occur later
@@ -197,6 +217,87 @@ impl ParsedCxxQtData {
         }
     }
 
+    /// Mark the signals which share their C++ name with another signal on the same QObject,
+    /// so that the generated helpers can refer to the correct overload of the signal
+    pub fn mark_overloaded_signals(&mut self) -> Result<()> {
+        let mut signals: Vec<&mut ParsedSignal> = self
+            .signals
+            .iter_mut()
+            .chain(
+                self.extern_cxxqt_blocks
+                    .iter_mut()
+                    .flat_map(|block| block.signals.iter_mut()),
+            )
+            .collect();
+        let keys: Vec<(Ident, String)> = signals
+            .iter()
+            .map(|signal| (signal.qobject_ident.clone(), signal.name.cxx_unqualified()))
+            .collect();
+
+        for (signal, key) in signals.iter_mut().zip(&keys) {
+            signal.overloaded = keys.iter().filter(|other| *other == key).count() > 1;
+
+            // The QPrivateSignal parameter is not accessible to the helpers, so the overload
+            // of a private signal cannot be selected with QOverload
+            if signal.overloaded && signal.private_signal {
+                return Err(Error::new(
+                    signal.method.span(),
+                    "#[private_signal] cannot be used on overloaded signals",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Remove the QObjects and members whose `#[cfg(...)]` attributes are disabled,
+    /// along with any members of a QObject which has been removed
+    pub fn evaluate_cfgs(&mut self, evaluator: &dyn CfgEvaluator) -> Result<()> {
+        let mut disabled = vec![];
+        retain_enabled(&mut self.qobjects, |qobject| {
+            let enabled = try_eval_attributes(evaluator, &qobject.cfgs)?;
+            if !enabled {
+                disabled.push(qobject.name.rust_unqualified().clone());
+            }
+            Ok(enabled)
+        })?;
+
+        for qobject in self.qobjects.iter_mut() {
+            retain_enabled(&mut qobject.properties, |property| {
+                try_eval_attributes(evaluator, &property.cfgs)
+            })?;
+        }
+
+        let is_enabled = |qobject_ident: &Ident, cfgs: &[Attribute]| -> Result<bool> {
+            Ok(!disabled.contains(qobject_ident) && try_eval_attributes(evaluator, cfgs)?)
+        };
+        retain_enabled(&mut self.methods, |method| {
+            is_enabled(&method.qobject_ident, &method.cfgs)
+        })?;
+        retain_enabled(&mut self.signals, |signal| {
+            is_enabled(&signal.qobject_ident, &signal.cfgs)
+        })?;
+        retain_enabled(&mut self.inherited_methods, |method| {
+            is_enabled(&method.qobject_ident, &method.cfgs)
+        })?;
+        self.qenums.retain(|qenum| {
+            !qenum
+                .qobject
+                .as_ref()
+                .is_some_and(|qobject| disabled.contains(qobject))
+        });
+        self.trait_impls
+            .retain(|trait_impl| !disabled.contains(&trait_impl.qobject));
+
+        for block in self.extern_cxxqt_blocks.iter_mut() {
+            retain_enabled(&mut block.signals, |signal| {
+                try_eval_attributes(evaluator, &signal.cfgs)
+            })?;
+        }
+
+        Ok(())
+    }
+
     #[cfg(test)]
     fn find_object(&self, id: &Ident) -> Option<&ParsedQObject> {
         self.qobjects
@@ -205,11 +306,27 @@ impl ParsedCxxQtData {
     }
 }
 
+/// Keep only the items for which the fallible predicate returns true
+fn retain_enabled<T>(
+    items: &mut Vec<T>,
+    mut predicate: impl FnMut(&T) -> Result<bool>,
+) -> Result<()> {
+    let mut enabled = Vec::with_capacity(items.len());
+    for item in items.drain(..) {
+        if predicate(&item)? {
+            enabled.push(item);
+        }
+    }
+    *items = enabled;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use crate::generator::structuring::Structures;
+    use crate::syntax::cfg::CfgResult;
     use crate::{naming::Name, parser::qobject::tests::create_parsed_qobject};
     use quote::format_ident;
     use syn::parse_quote;
@@ -485,6 +602,65 @@ mod tests {
         assert!(signals[1].inherit);
     }
 
+    #[test]
+    fn test_parse_qsignals_overloaded() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qsignal]
+                fn ready(self: Pin<&mut MyObject>);
+
+                #[cxx_name = "valueChanged"]
+                #[qsignal]
+                fn value_changed_int(self: Pin<&mut MyObject>, value: i32);
+
+                #[cxx_name = "valueChanged"]
+                #[qsignal]
+                fn value_changed_string(self: Pin<&mut MyObject>, value: &QString);
+            }
+        };
+        cxxqtdata.parse_cxx_qt_item(block).unwrap();
+        let block: Item = parse_quote! {
+            unsafe extern "C++Qt" {
+                #[qobject]
+                type QComboBox;
+
+                #[qsignal]
+                #[cxx_name = "valueChanged"]
+                fn value_changed(self: Pin<&mut QComboBox>, value: i32);
+            }
+        };
+        cxxqtdata.parse_cxx_qt_item(block).unwrap();
+        cxxqtdata.mark_overloaded_signals().unwrap();
+
+        let signals = &cxxqtdata.signals;
+        assert_eq!(signals.len(), 3);
+        assert!(!signals[0].overloaded);
+        assert!(signals[1].overloaded);
+        assert!(signals[2].overloaded);
+        // Signals on other QObjects are not overloads
+        assert!(!cxxqtdata.extern_cxxqt_blocks[0].signals[0].overloaded);
+    }
+
+    #[test]
+    fn test_parse_qsignals_overloaded_private_signal() {
+        let mut cxxqtdata = create_parsed_cxx_qt_data();
+        let block: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[cxx_name = "valueChanged"]
+                #[qsignal]
+                fn value_changed_int(self: Pin<&mut MyObject>, value: i32);
+
+                #[cxx_name = "valueChanged"]
+                #[qsignal]
+                #[private_signal]
+                fn value_changed_string(self: Pin<&mut MyObject>, value: &QString);
+            }
+        };
+        cxxqtdata.parse_cxx_qt_item(block).unwrap();
+        assert!(cxxqtdata.mark_overloaded_signals().is_err());
+    }
+
     #[test]
     fn test_parse_qsignals_unsafe() {
         let mut cxxqtdata = create_parsed_cxx_qt_data();
@@ -581,6 +757,87 @@ mod tests {
             .is_some());
     }
 
+    struct FeatureEvaluator;
+
+    impl CfgEvaluator for FeatureEvaluator {
+        fn eval(&self, name: &str, value: Option<&str>) -> CfgResult {
+            (name == "feature" && value == Some("enabled")).into()
+        }
+    }
+
+    #[test]
+    fn test_evaluate_cfgs() {
+        let mut parsed_cxxqtdata = ParsedCxxQtData::new(format_ident!("ffi"), None);
+        let items: [Item; 3] = [
+            parse_quote! {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, always)]
+                    #[cfg_attr(feature = "enabled", qproperty(i32, enabled))]
+                    #[cfg_attr(feature = "disabled", qproperty(i32, disabled))]
+                    type MyObject = super::T;
+
+                    #[cfg(feature = "disabled")]
+                    #[qobject]
+                    type MyOtherObject = super::MyOtherT;
+
+                    #[cfg(feature = "enabled")]
+                    fn enabled(self: &MyObject);
+                    #[cfg(feature = "disabled")]
+                    fn disabled(self: &MyObject);
+                    fn other(self: &MyOtherObject);
+
+                    #[qsignal]
+                    #[cfg(not(feature = "enabled"))]
+                    fn disabled_signal(self: Pin<&mut MyObject>);
+                    #[qsignal]
+                    fn other_signal(self: Pin<&mut MyOtherObject>);
+                }
+            },
+            parse_quote! {
+                impl cxx_qt::Threading for MyOtherObject {}
+            },
+            parse_quote! {
+                impl cxx_qt::Threading for MyObject {}
+            },
+        ];
+        for item in items {
+            parsed_cxxqtdata.parse_cxx_qt_item(item).unwrap();
+        }
+
+        parsed_cxxqtdata.evaluate_cfgs(&FeatureEvaluator).unwrap();
+
+        assert_eq!(parsed_cxxqtdata.qobjects.len(), 1);
+        let properties = &parsed_cxxqtdata.qobjects[0].properties;
+        assert_eq!(properties.len(), 2);
+        assert_eq!(properties[0].name.rust_unqualified(), "always");
+        assert_eq!(properties[1].name.rust_unqualified(), "enabled");
+
+        assert_eq!(parsed_cxxqtdata.methods.len(), 1);
+        assert_eq!(
+            parsed_cxxqtdata.methods[0].name.rust_unqualified(),
+            "enabled"
+        );
+        assert!(parsed_cxxqtdata.signals.is_empty());
+        assert_eq!(parsed_cxxqtdata.trait_impls.len(), 1);
+        assert_eq!(parsed_cxxqtdata.trait_impls[0].qobject, "MyObject");
+    }
+
+    #[test]
+    fn test_evaluate_cfgs_invalid() {
+        let mut parsed_cxxqtdata = ParsedCxxQtData::new(format_ident!("ffi"), None);
+        let extern_rust_qt: Item = parse_quote! {
+            unsafe extern "RustQt" {
+                #[qobject]
+                #[cfg(feature = 1)]
+                type MyObject = super::T;
+            }
+        };
+        parsed_cxxqtdata.parse_cxx_qt_item(extern_rust_qt).unwrap();
+
+        assert!(parsed_cxxqtdata.evaluate_cfgs(&FeatureEvaluator).is_err());
+    }
+
     #[test]
     fn test_qobject_namespaces() {
         let mut parsed_cxxqtdata = ParsedCxxQtData::new(format_ident!("ffi"), None);
@@ -4,16 +4,24 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::{
+    generator::naming::property::QPropertyExternNames,
     parser::{externqobject::ParsedExternQObject, require_attributes, signals::ParsedSignal},
     syntax::{attribute::attribute_get_path, expr::expr_to_string, safety::Safety},
 };
-use syn::{spanned::Spanned, Error, ForeignItem, Ident, ItemForeignMod, Result, Token};
+use syn::{
+    ext::IdentExt, parse_quote, spanned::Spanned, Attribute, Error, ForeignItem, ForeignItemFn,
+    Ident, ItemForeignMod, ItemType, Result, Token,
+};
 
 /// Representation of an extern "C++Qt" block
 #[derive(Default)]
 pub struct ParsedExternCxxQt {
     /// The namespace of the type in C++.
     pub namespace: Option<String>,
+    /// The namespace to place the generated signal helpers in, within `rust::cxxqtgen1`
+    ///
+    /// This defaults to the name of the bridge module
+    pub helper_namespace: String,
     /// Whether this block has an unsafe token
     pub unsafety: Option<Token![unsafe]>,
     /// Items which can be passed into the extern "C++Qt" block
@@ -30,7 +38,7 @@ impl ParsedExternCxxQt {
         module_ident: &Ident,
         parent_namespace: Option<&str>,
     ) -> Result<Self> {
-        let attrs = require_attributes(&foreign_mod.attrs, &["namespace"])?;
+        let attrs = require_attributes(&foreign_mod.attrs, &["namespace", "helper_namespace"])?;
 
         let namespace = attrs
             .get("namespace")
@@ -39,8 +47,16 @@ impl ParsedExternCxxQt {
             })
             .transpose()?;
 
+        let helper_namespace = attrs
+            .get("helper_namespace")
+            .map(|attr| Self::parse_helper_namespace(attr))
+            .transpose()?
+            // Bridges which declare the same signal then only clash if their modules have the same name
+            .unwrap_or_else(|| module_ident.unraw().to_string());
+
         let mut extern_cxx_block = ParsedExternCxxQt {
             namespace: namespace.clone(),
+            helper_namespace: helper_namespace.clone(),
             unsafety: foreign_mod.unsafety,
             ..Default::default()
         };
@@ -58,9 +74,21 @@ impl ParsedExternCxxQt {
                     // Test if the function is a signal
                     if attribute_get_path(&foreign_fn.attrs, &["qsignal"]).is_some() {
                         let mut signal = ParsedSignal::parse(foreign_fn, safe_call)?;
+                        if signal.payload.is_some() {
+                            return Err(Error::new(
+                                signal.method.span(),
+                                "#[flatten] cannot be used on signals in extern \"C++Qt\" blocks",
+                            ));
+                        }
                         // extern "C++Qt" signals are always inherit = true
                         // as they always exist on an existing QObject
                         signal.inherit = true;
+                        // A QPrivateSignal cannot be emitted from outside the QObject,
+                        // so only generate the connect methods
+                        if signal.private_signal {
+                            signal.private = true;
+                        }
+                        signal.helper_namespace = Some(helper_namespace.clone());
                         extern_cxx_block.signals.push(signal);
                     } else {
                         extern_cxx_block
@@ -73,8 +101,7 @@ impl ParsedExternCxxQt {
                     if attribute_get_path(&foreign_ty.attrs, &["qobject"]).is_some() {
                         let extern_ty =
                             ParsedExternQObject::parse(foreign_ty, module_ident, parent_namespace)?;
-                        // Pass through types separately for generation
-                        extern_cxx_block.qobjects.push(extern_ty);
+                        extern_cxx_block.push_qobject(extern_ty)?;
                     } else {
                         return Err(Error::new(
                             foreign_ty.span(),
@@ -82,6 +109,22 @@ impl ParsedExternCxxQt {
                         ));
                     }
                 }
+                ForeignItem::Verbatim(tokens) => match syn::parse2::<ItemType>(tokens.clone()) {
+                    // A QObject generated by another bridge or crate, type A = path::A;
+                    Ok(alias) if attribute_get_path(&alias.attrs, &["qobject"]).is_some() => {
+                        let extern_ty = ParsedExternQObject::parse_alias(
+                            alias,
+                            module_ident,
+                            parent_namespace,
+                        )?;
+                        extern_cxx_block.push_qobject(extern_ty)?;
+                    }
+                    _others => {
+                        extern_cxx_block
+                            .passthrough_items
+                            .push(ForeignItem::Verbatim(tokens));
+                    }
+                },
                 others => {
                     extern_cxx_block.passthrough_items.push(others);
                 }
@@ -90,11 +133,59 @@ impl ParsedExternCxxQt {
 
         Ok(extern_cxx_block)
     }
+
+    /// Parse a `#[helper_namespace = "..."]` attribute, which has to be a C++ namespace
+    fn parse_helper_namespace(attr: &Attribute) -> Result<String> {
+        let value = &attr.meta.require_name_value()?.value;
+        let namespace = expr_to_string(value)?;
+        let is_identifier = |segment: &str| {
+            segment
+                .chars()
+                .next()
+                .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+                && segment
+                    .chars()
+                    .all(|char| char.is_ascii_alphanumeric() || char == '_')
+        };
+        if !namespace.split("::").all(is_identifier) {
+            return Err(Error::new_spanned(
+                value,
+                "helper_namespace must be a C++ identifier or a namespace like a::b!",
+            ));
+        }
+        Ok(namespace)
+    }
+
+    fn push_qobject(&mut self, extern_ty: ParsedExternQObject) -> Result<()> {
+        // The notify signals of the properties can be connected to like any other signal
+        for property in &extern_ty.properties {
+            if let Some(notify) = QPropertyExternNames::from(property).notify {
+                let qobject_ident = &extern_ty.declaration.ident;
+                let notify_cpp = notify.cxx_unqualified();
+                let notify_rust = notify.rust_unqualified();
+                let method: ForeignItemFn = parse_quote! {
+                    #[cxx_name = #notify_cpp]
+                    fn #notify_rust(self: Pin<&mut #qobject_ident>);
+                };
+                let mut signal = ParsedSignal::parse(method, Safety::Safe)?;
+                signal.inherit = true;
+                // The notify signal may have parameters which are not declared,
+                // so it can only be connected to from Rust
+                signal.private = true;
+                signal.helper_namespace = Some(self.helper_namespace.clone());
+                self.signals.push(signal);
+            }
+        }
+        // Pass through types separately for generation
+        self.qobjects.push(extern_ty);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tests::assert_parse_errors;
     use quote::format_ident;
 
     use syn::parse_quote;
@@ -126,6 +217,217 @@ mod tests {
         assert!(extern_cxx_qt.unsafety.is_some());
     }
 
+    #[test]
+    fn test_extern_cxxqt_private_signal() {
+        let extern_cxx_qt = ParsedExternCxxQt::parse(
+            parse_quote! {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    type QTimer;
+
+                    #[qsignal]
+                    #[private_signal]
+                    fn timeout(self: Pin<&mut QTimer>);
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(extern_cxx_qt.signals.len(), 1);
+        assert!(extern_cxx_qt.signals[0].inherit);
+        assert!(extern_cxx_qt.signals[0].private);
+    }
+
+    #[test]
+    fn test_extern_cxxqt_property() {
+        let extern_cxx_qt = ParsedExternCxxQt::parse(
+            parse_quote! {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    #[qproperty(i32, interval)]
+                    #[qproperty(bool, active, READ = isActive)]
+                    type QTimer;
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(extern_cxx_qt.qobjects.len(), 1);
+        assert_eq!(extern_cxx_qt.qobjects[0].properties.len(), 2);
+        // Only the property with a notify signal has a signal
+        assert_eq!(extern_cxx_qt.signals.len(), 1);
+        let signal = &extern_cxx_qt.signals[0];
+        assert_eq!(signal.name.cxx_unqualified(), "intervalChanged");
+        assert_eq!(signal.name.rust_unqualified(), "interval_changed");
+        assert!(signal.inherit);
+        assert!(signal.private);
+    }
+
+    #[test]
+    fn test_extern_cxxqt_property_invalid() {
+        let result = ParsedExternCxxQt::parse(
+            parse_quote! {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    #[qproperty(i32, interval, READ, RESET = reset_interval)]
+                    type QTimer;
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_extern_cxxqt_helper_namespace() {
+        let extern_cxx_qt = ParsedExternCxxQt::parse(
+            parse_quote! {
+                #[helper_namespace = "my_crate"]
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    type QTimer;
+
+                    #[qsignal]
+                    fn timeout(self: Pin<&mut QTimer>);
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        )
+        .unwrap();
+
+        assert!(extern_cxx_qt.namespace.is_none());
+        assert_eq!(extern_cxx_qt.helper_namespace, "my_crate");
+        assert_eq!(extern_cxx_qt.signals.len(), 1);
+        assert_eq!(
+            extern_cxx_qt.signals[0].helper_namespace.as_deref(),
+            Some("my_crate")
+        );
+    }
+
+    #[test]
+    fn test_extern_cxxqt_helper_namespace_default() {
+        let extern_cxx_qt = ParsedExternCxxQt::parse(
+            parse_quote! {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    type QTimer;
+
+                    #[qsignal]
+                    fn timeout(self: Pin<&mut QTimer>);
+                }
+            },
+            &format_ident!("r#type"),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(extern_cxx_qt.helper_namespace, "type");
+        assert_eq!(
+            extern_cxx_qt.signals[0].helper_namespace.as_deref(),
+            Some("type")
+        );
+    }
+
+    #[test]
+    fn test_extern_cxxqt_helper_namespace_nested() {
+        let extern_cxx_qt = ParsedExternCxxQt::parse(
+            parse_quote! {
+                #[helper_namespace = "my_crate::_timer2"]
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    type QTimer;
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(extern_cxx_qt.helper_namespace, "my_crate::_timer2");
+    }
+
+    #[test]
+    fn test_extern_cxxqt_helper_namespace_invalid() {
+        assert_parse_errors! {
+            |item| ParsedExternCxxQt::parse(item, &format_ident!("qobject"), None) =>
+
+            {
+                #[helper_namespace = "my-crate"]
+                unsafe extern "C++Qt" {}
+            }
+            {
+                #[helper_namespace = "2d"]
+                unsafe extern "C++Qt" {}
+            }
+            {
+                #[helper_namespace = "my_crate::"]
+                unsafe extern "C++Qt" {}
+            }
+            {
+                #[helper_namespace = ""]
+                unsafe extern "C++Qt" {}
+            }
+            {
+                #[helper_namespace = "::my_crate"]
+                unsafe extern "C++Qt" {}
+            }
+        }
+    }
+
+    #[test]
+    fn test_extern_cxxqt_type_alias() {
+        let extern_cxx_qt = ParsedExternCxxQt::parse(
+            parse_quote! {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    #[namespace = "other"]
+                    type OtherObject = crate::other::qobject::OtherObject;
+
+                    #[qsignal]
+                    fn ready(self: Pin<&mut OtherObject>);
+
+                    type QString = cxx_qt_lib::QString;
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(extern_cxx_qt.qobjects.len(), 1);
+        let qobject = &extern_cxx_qt.qobjects[0];
+        assert_eq!(qobject.name.cxx_unqualified(), "OtherObject");
+        assert_eq!(qobject.name.namespace(), Some("other"));
+        assert_eq!(
+            qobject.alias,
+            Some(parse_quote! { crate::other::qobject::OtherObject })
+        );
+        assert_eq!(extern_cxx_qt.signals.len(), 1);
+        // Aliases of non QObject types are passed through
+        assert_eq!(extern_cxx_qt.passthrough_items.len(), 1);
+    }
+
+    #[test]
+    fn test_extern_cxxqt_type_alias_invalid() {
+        let extern_cxx_qt = ParsedExternCxxQt::parse(
+            parse_quote! {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    type OtherObject = &'static str;
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+        );
+        assert!(extern_cxx_qt.is_err());
+    }
+
     #[test]
     fn test_extern_cxxqt_type_missing_qobject() {
         let extern_cxx_qt = ParsedExternCxxQt::parse(
@@ -2,8 +2,14 @@
 // SPDX-FileContributor: Ben Ford <ben.ford@kdab.com>
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
-use crate::naming::Name;
-use syn::{ForeignItemType, Ident, Result};
+use crate::{
+    naming::{AutoCamel, Name},
+    parser::property::ParsedQProperty,
+    syntax::path::path_compare_str,
+};
+use syn::{
+    parse_quote, spanned::Spanned, Error, ForeignItemType, Ident, ItemType, Path, Result, Type,
+};
 
 /// A representation of a QObject to be generated in an extern C++ block
 pub struct ParsedExternQObject {
@@ -11,6 +17,10 @@ pub struct ParsedExternQObject {
     pub name: Name,
     /// Original declaration
     pub declaration: ForeignItemType,
+    /// The existing Q_PROPERTYs of the QObject which are accessed from Rust
+    pub properties: Vec<ParsedQProperty>,
+    /// The Rust path of the QObject when it is generated by another bridge, `type A = path::A`
+    pub alias: Option<Path>,
 }
 
 impl ParsedExternQObject {
@@ -26,7 +36,65 @@ impl ParsedExternQObject {
                 parent_namespace,
                 Some(module_ident),
             )?,
+            properties: Self::parse_property_attributes(&ty)?,
             declaration: ty,
+            alias: None,
         })
     }
+
+    /// Parse a QObject from another bridge or crate, which is declared as `type A = path::A;`
+    pub fn parse_alias(
+        alias: ItemType,
+        module_ident: &Ident,
+        parent_namespace: Option<&str>,
+    ) -> Result<ParsedExternQObject> {
+        if !alias.generics.params.is_empty() {
+            return Err(Error::new(
+                alias.generics.span(),
+                "QObject type aliases in extern \"C++Qt\" blocks cannot have generics!",
+            ));
+        }
+
+        let path = match *alias.ty {
+            Type::Path(ty_path) if ty_path.qself.is_none() => ty_path.path,
+            others => {
+                return Err(Error::new(
+                    others.span(),
+                    "QObject type aliases in extern \"C++Qt\" blocks must refer to a path, type A = path::A!",
+                ))
+            }
+        };
+
+        let attrs = &alias.attrs;
+        let vis = &alias.vis;
+        let ident = &alias.ident;
+        let mut qobject = Self::parse(
+            parse_quote! {
+                #(#attrs)*
+                #vis type #ident;
+            },
+            module_ident,
+            parent_namespace,
+        )?;
+        qobject.alias = Some(path);
+        Ok(qobject)
+    }
+
+    fn parse_property_attributes(ty: &ForeignItemType) -> Result<Vec<ParsedQProperty>> {
+        ty.attrs
+            .iter()
+            .filter(|attr| path_compare_str(attr.meta.path(), &["qproperty"]))
+            .map(|attr| {
+                let property = ParsedQProperty::parse(attr, AutoCamel::Enabled)?;
+                // The property already exists in C++, so only the accessors can be generated
+                if property.flags.reset.is_some() || property.flags.alias.is_some() {
+                    return Err(Error::new(
+                        attr.span(),
+                        "RESET and ALIAS are not supported on properties in extern \"C++Qt\" blocks!",
+                    ));
+                }
+                Ok(property)
+            })
+            .collect()
+    }
 }
@@ -3,9 +3,14 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use crate::{
-    naming::Name,
-    parser::{check_safety, parameter::ParsedFunctionParameter, require_attributes},
-    syntax::{foreignmod, safety::Safety, types},
+    naming::{AutoCamel, Name},
+    parser::{
+        check_safety, extract_docs, parameter::ParsedFunctionParameter, qtversion::QtVersion,
+        require_attributes,
+    },
+    syntax::{
+        attribute::attribute_get_path, cfg::cfg_attributes, foreignmod, safety::Safety, types,
+    },
 };
 use core::ops::Deref;
 use std::collections::{BTreeMap, HashSet};
@@ -20,7 +25,7 @@ pub enum ParsedQInvokableSpecifiers {
 }
 
 impl ParsedQInvokableSpecifiers {
-    fn as_str(&self) -> &str {
+    pub(crate) fn as_str(&self) -> &str {
         match self {
             ParsedQInvokableSpecifiers::Final => "cxx_final",
             ParsedQInvokableSpecifiers::Override => "cxx_override",
@@ -51,12 +56,15 @@ pub struct ParsedMethod {
     pub specifiers: HashSet<ParsedQInvokableSpecifiers>,
     /// Whether the method is qinvokable
     pub is_qinvokable: bool,
-    // No docs field since the docs should be on the method implementation outside the bridge
-    // This means any docs on the bridge declaration would be ignored
+    /// All the docs (each line) of the method
+    ///
+    /// These are only used for the generated C++, as the Rust docs should be on the method
+    /// implementation outside the bridge
+    pub docs: Vec<Attribute>,
 }
 
 impl ParsedMethod {
-    const ALLOWED_ATTRS: [&'static str; 7] = [
+    const ALLOWED_ATTRS: [&'static str; 9] = [
         "cxx_name",
         "rust_name",
         "qinvokable",
@@ -64,6 +72,8 @@ impl ParsedMethod {
         "cxx_override",
         "cxx_virtual",
         "doc",
+        "cfg",
+        "qt",
     ];
 
     #[cfg(test)]
@@ -103,6 +113,7 @@ impl ParsedMethod {
 
     pub fn parse(method: ForeignItemFn, safety: Safety) -> Result<Self> {
         check_safety(&method, &safety)?;
+        let docs = extract_docs(&method.attrs);
         let fields = MethodFields::parse(method)?;
         let attrs = require_attributes(&fields.method.attrs, &Self::ALLOWED_ATTRS)?;
 
@@ -114,6 +125,7 @@ impl ParsedMethod {
             method_fields: fields,
             specifiers,
             is_qinvokable,
+            docs,
         })
     }
 }
@@ -136,6 +148,10 @@ pub struct MethodFields {
     pub parameters: Vec<ParsedFunctionParameter>,
     pub safe: bool,
     pub name: Name,
+    /// The `#[cfg(...)]` attributes of the method, which are applied to all of its generated code
+    pub cfgs: Vec<Attribute>,
+    /// The minimum Qt version of the method from `#[qt(since = "...")]`
+    pub qt_since: Option<QtVersion>,
 }
 
 impl MethodFields {
@@ -147,6 +163,10 @@ impl MethodFields {
         let parameters = ParsedFunctionParameter::parse_all_ignoring_receiver(&method.sig)?;
         let safe = method.sig.unsafety.is_none();
         let name = Name::from_rust_ident_and_attrs(&method.sig.ident, &method.attrs, None, None)?;
+        let cfgs = cfg_attributes(&method.attrs);
+        let qt_since = attribute_get_path(&method.attrs, &["qt"])
+            .map(QtVersion::parse)
+            .transpose()?;
 
         Ok(MethodFields {
             method,
@@ -155,6 +175,31 @@ impl MethodFields {
             parameters,
             safe,
             name,
+            cfgs,
+            qt_since,
         })
     }
+
+    /// Use the Rust name in C++ unless a `cxx_name` is given, if camelCase conversion is disabled
+    pub fn apply_auto_camel(&mut self, auto_camel: AutoCamel) -> Result<()> {
+        if auto_camel == AutoCamel::Disabled {
+            self.name =
+                Name::from_ident_and_attrs(&self.method.sig.ident, &self.method.attrs, None, None)?;
+        }
+        Ok(())
+    }
+
+    /// The `#[cfg(...)]` attributes for the generated Rust, including any minimum Qt version
+    pub fn rust_cfgs(&self) -> Vec<Attribute> {
+        self.cfgs
+            .iter()
+            .cloned()
+            .chain(self.qt_since.as_ref().map(QtVersion::cfg))
+            .collect()
+    }
+
+    /// The preprocessor condition for the generated C++, if there is a minimum Qt version
+    pub fn cpp_condition(&self) -> Option<String> {
+        self.qt_since.as_ref().map(QtVersion::cpp_condition)
+    }
 }
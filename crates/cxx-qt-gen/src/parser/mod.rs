@@ -14,12 +14,13 @@ pub mod property;
 pub mod qenum;
 pub mod qnamespace;
 pub mod qobject;
+pub mod qtversion;
 pub mod signals;
 pub mod trait_impl;
 
 use crate::{
-    naming::TypeNames,
-    syntax::{expr::expr_to_string, path::path_compare_str, safety::Safety},
+    naming::{AutoCamel, TypeNames},
+    syntax::{cfg::CfgEvaluator, expr::expr_to_string, path::path_compare_str, safety::Safety},
 };
 use cxxqtdata::ParsedCxxQtData;
 use std::collections::BTreeMap;
@@ -123,9 +124,10 @@ pub struct Parser {
 }
 
 impl Parser {
-    fn parse_mod_attributes(module: &mut ItemMod) -> Result<Option<String>> {
+    fn parse_mod_attributes(module: &mut ItemMod) -> Result<(Option<String>, AutoCamel)> {
         let attrs = require_attributes(&module.attrs, &["doc", "cxx_qt::bridge"])?;
         let mut namespace = None;
+        let mut auto_camel = AutoCamel::Enabled;
 
         // Check for the cxx_qt::bridge attribute
         if let Some(attr) = attrs.get("cxx_qt::bridge") {
@@ -139,6 +141,17 @@ impl Parser {
                             // Parse any namespace in the cxx_qt::bridge macro
                             if name_value.path.is_ident("namespace") {
                                 namespace = Some(expr_to_string(&name_value.value)?);
+                                // Parse the naming policy of C++ names
+                            } else if name_value.path.is_ident("naming") {
+                                auto_camel =
+                                    match expr_to_string(&name_value.value)?.as_str() {
+                                        "camelCase" => AutoCamel::Enabled,
+                                        "preserve" => AutoCamel::Disabled,
+                                        _ => return Err(Error::new(
+                                            name_value.value.span(),
+                                            "naming must be either \"camelCase\" or \"preserve\"",
+                                        )),
+                                    };
                                 // Parse any custom file stem
                             } else if name_value.path.is_ident("cxx_file_stem") {
                                 return Err(Error::new(
@@ -158,16 +171,18 @@ impl Parser {
             ));
         }
 
-        Ok(namespace)
+        Ok((namespace, auto_camel))
     }
 
     fn parse_module_contents(
         mut module: ItemMod,
         namespace: Option<String>,
+        auto_camel: AutoCamel,
     ) -> Result<(ParsedCxxQtData, ItemMod)> {
         let mut others = vec![];
 
         let mut cxx_qt_data = ParsedCxxQtData::new(module.ident.clone(), namespace);
+        cxx_qt_data.auto_camel = auto_camel;
 
         // Check that there are items in the module
         if let Some((_, items)) = module.content {
@@ -209,17 +224,21 @@ impl Parser {
 
     /// Constructs a Parser object from a given [syn::ItemMod] block
     pub fn from(mut module: ItemMod) -> Result<Self> {
-        let namespace = Self::parse_mod_attributes(&mut module)?;
-        let (mut cxx_qt_data, module) = Self::parse_module_contents(module, namespace)?;
-        let type_names = Self::naming_phase(
-            &mut cxx_qt_data,
-            module
-                .content
-                .as_ref()
-                .map(|brace_and_items| &brace_and_items.1)
-                .unwrap_or(&vec![]),
-            &module.ident,
-        )?;
+        let (namespace, auto_camel) = Self::parse_mod_attributes(&mut module)?;
+        let (mut cxx_qt_data, module) = Self::parse_module_contents(module, namespace, auto_camel)?;
+        let cxx_items = module
+            .content
+            .as_ref()
+            .map(|brace_and_items| brace_and_items.1.as_slice())
+            .unwrap_or_default();
+
+        // Signals with a #[flatten] payload need the fields of the shared struct in the bridge
+        for signal in cxx_qt_data.signals.iter_mut() {
+            signal.flatten_payload(cxx_items)?;
+        }
+        cxx_qt_data.mark_overloaded_signals()?;
+
+        let type_names = Self::naming_phase(&mut cxx_qt_data, cxx_items, &module.ident)?;
 
         // Return the successful Parser object
         Ok(Self {
@@ -228,6 +247,13 @@ impl Parser {
             cxx_qt_data,
         })
     }
+
+    /// Remove any QObjects, properties, methods, and signals whose `#[cfg(...)]` attributes are disabled
+    ///
+    /// This is used when generating C++, as the generated Rust keeps the attributes for the compiler to evaluate.
+    pub fn evaluate_cfgs(&mut self, evaluator: &dyn CfgEvaluator) -> Result<()> {
+        self.cxx_qt_data.evaluate_cfgs(evaluator)
+    }
 }
 
 #[cfg(test)]
@@ -280,6 +306,61 @@ mod tests {
             }
         };
         assert!(Parser::from(module).is_ok()); // Meta::NameValue args which aren't `namespace` or `cxx_file_stem` are ignored
+
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(naming = "PascalCase")]
+            mod ffi {}
+        };
+        assert!(Parser::from(module).is_err());
+    }
+
+    #[test]
+    fn test_parser_naming_preserve() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(naming = "preserve")]
+            mod ffi {
+                unsafe extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, my_property)]
+                    #[qproperty(i32, renamed_property, cxx_name = "renamedProperty")]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    fn my_invokable(self: &MyObject);
+
+                    #[qinvokable]
+                    #[cxx_name = "renamedInvokable"]
+                    fn renamed_invokable(self: &MyObject);
+
+                    #[qsignal]
+                    fn my_signal(self: Pin<&mut MyObject>);
+
+                    #[qsignal]
+                    #[inherit]
+                    fn data_changed(self: Pin<&mut MyObject>);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let cxx_qt_data = &parser.cxx_qt_data;
+        assert_eq!(cxx_qt_data.auto_camel, AutoCamel::Disabled);
+
+        let properties = &cxx_qt_data.qobjects[0].properties;
+        assert_eq!(properties[0].name.cxx_unqualified(), "my_property");
+        assert_eq!(properties[1].name.cxx_unqualified(), "renamedProperty");
+
+        assert_eq!(
+            cxx_qt_data.methods[0].name.cxx_unqualified(),
+            "my_invokable"
+        );
+        assert_eq!(
+            cxx_qt_data.methods[1].name.cxx_unqualified(),
+            "renamedInvokable"
+        );
+
+        assert_eq!(cxx_qt_data.signals[0].name.cxx_unqualified(), "my_signal");
+        // Inherited signals still refer to the camelCase signal of the base class
+        assert_eq!(cxx_qt_data.signals[1].name.cxx_unqualified(), "dataChanged");
     }
 
     #[test]
@@ -3,14 +3,14 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::generator::naming::property::property_name_from_rust_name;
 use crate::naming::{AutoCamel, Name};
 use crate::syntax::expr::expr_to_string;
 use syn::{
     parse::{Error, ParseStream},
+    parse_quote,
     punctuated::Punctuated,
     spanned::Spanned,
-    Attribute, Expr, Ident, Meta, MetaNameValue, Result, Token, Type,
+    Attribute, Expr, ExprLit, Ident, Lit, Member, Meta, MetaNameValue, Result, Token, Type,
 };
 
 #[cfg(test)]
@@ -35,6 +35,42 @@ pub struct QPropertyFlags {
     pub(crate) is_final: bool,
     pub(crate) constant: bool,
     pub(crate) required: bool,
+    pub(crate) alias: Option<QPropertyAlias>,
+}
+
+/// Struct for storing the target of a QProperty alias
+///
+/// This is the `child.property` passed in ```#[qproperty(T, ident, ALIAS = child.property)]```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QPropertyAlias {
+    /// The field of the Rust struct which holds the child QObject
+    pub field: Ident,
+    /// The Rust name of the property on the child QObject
+    pub property: Ident,
+}
+
+impl QPropertyAlias {
+    fn parse(meta: &Meta) -> Result<Self> {
+        if let Meta::NameValue(MetaNameValue {
+            value: Expr::Field(expr_field),
+            ..
+        }) = meta
+        {
+            if let (Expr::Path(base), Member::Named(property)) =
+                (expr_field.base.as_ref(), &expr_field.member)
+            {
+                return Ok(Self {
+                    field: base.path.require_ident()?.clone(),
+                    property: property.clone(),
+                });
+            }
+        }
+
+        Err(Error::new(
+            meta.span(),
+            "ALIAS needs a value passed like `ALIAS = child.property`!",
+        ))
+    }
 }
 
 impl Default for QPropertyFlags {
@@ -48,6 +84,7 @@ impl Default for QPropertyFlags {
             is_final: false,
             constant: false,
             required: false,
+            alias: None,
         }
     }
 }
@@ -60,6 +97,10 @@ pub struct ParsedQProperty {
     pub ty: Type,
     /// Property flag collection
     pub flags: QPropertyFlags,
+    /// The `#[cfg(...)]` predicates from a `#[cfg_attr(..., qproperty(...))]`
+    pub cfgs: Vec<Attribute>,
+    /// The docs of the property, from `doc = "..."` flags
+    pub docs: Vec<Attribute>,
 }
 
 fn parse_meta_name_value(name_value: &MetaNameValue) -> Result<(Ident, Ident)> {
@@ -101,8 +142,25 @@ fn parse_meta(meta: Meta) -> Result<(Ident, Option<Ident>)> {
     }
 }
 
+fn parse_doc(meta: &Meta) -> Result<Attribute> {
+    if let Meta::NameValue(MetaNameValue {
+        value: value @ Expr::Lit(ExprLit {
+            lit: Lit::Str(_), ..
+        }),
+        ..
+    }) = meta
+    {
+        return Ok(parse_quote! { #[doc = #value] });
+    }
+
+    Err(Error::new(
+        meta.span(),
+        "doc needs a string passed like `doc = \"My property\"`!",
+    ))
+}
+
 impl ParsedQProperty {
-    pub fn parse(attr: &Attribute) -> Result<Self> {
+    pub fn parse(attr: &Attribute, auto_camel: AutoCamel) -> Result<Self> {
         attr.parse_args_with(|input: ParseStream| -> Result<Self> {
             let ty = input.parse()?;
             let _comma = input.parse::<Token![,]>()?;
@@ -111,9 +169,11 @@ impl ParsedQProperty {
             if input.is_empty() {
                 // No flags passed so desugar: #[qproperty(T, ident)] -> #[qproperty(T, ident, read, write, notify)]
                 Ok(Self {
-                    name: property_name_from_rust_name(ident),
+                    name: Name::new(ident).with_options(None, None, auto_camel),
                     ty,
                     flags: QPropertyFlags::default(),
+                    cfgs: vec![],
+                    docs: vec![],
                 })
             } else {
                 let _comma = input.parse::<Token![,]>()?; // Start of final identifiers
@@ -135,6 +195,8 @@ impl ParsedQProperty {
                 let mut reset = None;
                 let mut cxx_name = None;
                 let mut rust_name = None;
+                let mut alias = None;
+                let mut docs = vec![];
 
                 let map_auto_or_custom = |variable: &mut Option<FlagState>, value: &Option<Ident>| {
                     *variable = Some(value.as_ref().map_or(FlagState::Auto, |ident| FlagState::Custom(ident.clone())));
@@ -168,7 +230,7 @@ impl ParsedQProperty {
                                 "RESET" => reset = require_value("RESET flag", "RESET = my_reset_fn")?,
                                 _ => return Err(Error::new(
                                     ident.span(),
-                                    "Invalid flag passed!, must be one of\n  READ, WRITE, NOTIFY, RESET, CONSTANT, REQUIRED, FINAL or cxx_name / rust_name",
+                                    "Invalid flag passed!, must be one of\n  READ, WRITE, NOTIFY, RESET, CONSTANT, REQUIRED, FINAL, ALIAS, doc or cxx_name / rust_name",
                                 ))
                             }
                         }
@@ -178,6 +240,18 @@ impl ParsedQProperty {
                 };
 
                 for flag in flags {
+                    // ALIAS takes a field expression rather than an ident, so is parsed separately
+                    if flag.path().is_ident("ALIAS") {
+                        alias = Some(QPropertyAlias::parse(&flag)?);
+                        continue;
+                    }
+
+                    // Docs are a string literal, which is passed through as a doc attribute
+                    if flag.path().is_ident("doc") {
+                        docs.push(parse_doc(&flag)?);
+                        continue;
+                    }
+
                     let (field, maybe_value) = parse_meta(flag)?;
                     update_fields(&field, maybe_value)?;
                 }
@@ -190,7 +264,18 @@ impl ParsedQProperty {
                     ))
                 }
 
-                let name = Name::new(ident).with_options(cxx_name.map(|ident| ident.to_string()), rust_name, AutoCamel::Enabled);
+                // Aliases forward to the child's getter and setter, so custom ones make no sense
+                if alias.is_some()
+                    && (matches!(read, Some(FlagState::Custom(_)))
+                        || matches!(write, Some(FlagState::Custom(_))))
+                {
+                    return Err(Error::new(
+                        punctuated_flags.span(),
+                        "QProperties with an ALIAS cannot have a custom getter or setter!",
+                    ));
+                }
+
+                let name = Name::new(ident).with_options(cxx_name.map(|ident| ident.to_string()), rust_name, auto_camel);
 
                 // This check is needed otherwise this fn would error unless READ, WRITE, etc... was passed with cxx_name
                 if read_required {
@@ -206,7 +291,10 @@ impl ParsedQProperty {
                                 is_final,
                                 constant,
                                 required,
+                                alias,
                             },
+                            cfgs: vec![],
+                            docs,
                         })
                     } else {
                         Err(Error::new(
@@ -218,7 +306,13 @@ impl ParsedQProperty {
                     Ok(Self {
                         name,
                         ty,
-                        flags: QPropertyFlags::default(), // This block is hit if no flags, or only cxx / rust name were passed
+                        // This block is hit if no flags, or only cxx / rust name / alias / doc were passed
+                        flags: QPropertyFlags {
+                            alias,
+                            ..Default::default()
+                        },
+                        cfgs: vec![],
+                        docs,
                     })
                 }
             }
@@ -227,7 +321,7 @@ impl ParsedQProperty {
 }
 #[cfg(test)]
 pub fn mock_property(mut input: ItemStruct) -> ParsedQProperty {
-    ParsedQProperty::parse(&input.attrs.remove(0)).unwrap()
+    ParsedQProperty::parse(&input.attrs.remove(0), AutoCamel::Enabled).unwrap()
 }
 
 #[cfg(test)]
@@ -249,10 +343,22 @@ mod tests {
         assert_eq!(property.name.rust_unqualified(), "my_name");
     }
 
+    #[test]
+    fn test_parse_docs() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, doc = "The name", doc = "of the object")]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(property.docs.len(), 2);
+        assert_eq!(property.docs[0], parse_quote! { #[doc = "The name"] });
+        assert!(property.flags.write.is_some());
+    }
+
     #[test]
     fn test_parse_invalid() {
         assert_parse_errors! {
-            |attr| ParsedQProperty::parse(&attr) =>
+            |attr| ParsedQProperty::parse(&attr, AutoCamel::Enabled) =>
 
             // Non-constant property with constant flag
             { #[qproperty(T, name, READ, WRITE, NOTIFY, CONSTANT)] }
@@ -280,6 +386,14 @@ mod tests {
             { #[qproperty(T, name, cxx_name = "")] }
             // cxx_name had no value provided
             { #[qproperty(T, name, cxx_name)] }
+            // ALIAS had no value provided
+            { #[qproperty(T, name, ALIAS)] }
+            // ALIAS value is not a field expression
+            { #[qproperty(T, name, ALIAS = child)] }
+            // doc should be a string
+            { #[qproperty(T, name, doc = name)] }
+            // ALIAS with a custom getter
+            { #[qproperty(T, name, READ = my_getter, ALIAS = child.name)] }
         }
     }
 
@@ -361,4 +475,36 @@ mod tests {
             Some(FlagState::Custom(format_ident!("my_notifier")))
         );
     }
+
+    #[test]
+    fn test_parse_alias() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, ALIAS = child.child_name)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(property.name.rust_unqualified(), "name");
+        assert_eq!(property.flags.read, FlagState::Auto);
+        assert_eq!(property.flags.write, Some(FlagState::Auto));
+        assert_eq!(property.flags.notify, Some(FlagState::Auto));
+        assert_eq!(
+            property.flags.alias,
+            Some(QPropertyAlias {
+                field: format_ident!("child"),
+                property: format_ident!("child_name"),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_alias_read_only() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, READ, CONSTANT, ALIAS = child.name)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert!(property.flags.constant);
+        assert!(property.flags.write.is_none());
+        assert!(property.flags.alias.is_some());
+    }
 }
@@ -3,7 +3,11 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{naming::Name, parser::require_attributes, syntax::path::path_compare_str};
+use crate::{
+    naming::Name,
+    parser::require_attributes,
+    syntax::{attribute::attribute_get_path, path::path_compare_str},
+};
 use quote::ToTokens;
 use syn::{Ident, ItemEnum, Result, Variant};
 
@@ -14,12 +18,25 @@ pub struct ParsedQEnum {
     pub variants: Vec<Ident>,
     /// The QObject to which this QEnum belongs.
     pub qobject: Option<Ident>,
+    /// Whether the variants of this QEnum are the roles of the model of the QObject
+    pub model_roles: bool,
     /// The original enum item
     pub item: ItemEnum,
 }
 
 impl ParsedQEnum {
-    const ALLOWED_ATTRS: [&'static str; 5] = ["doc", "cxx_name", "rust_name", "namespace", "qenum"];
+    const ALLOWED_ATTRS: [&'static str; 6] = [
+        "doc",
+        "cxx_name",
+        "rust_name",
+        "namespace",
+        "qenum",
+        "model_roles",
+    ];
+
+    /// The value of Qt::UserRole, which is the first role that can be used for application-specific purposes
+    pub const USER_ROLE: i32 = 0x0100;
+
     fn parse_variant(variant: &Variant) -> Result<Ident> {
         fn err(spanned: &impl ToTokens, message: &str) -> Result<Ident> {
             Err(syn::Error::new_spanned(spanned, message))
@@ -72,6 +89,14 @@ impl ParsedQEnum {
             ));
         }
 
+        let model_roles = attribute_get_path(&qenum.attrs, &["model_roles"]).is_some();
+        if model_roles && qobject.is_none() {
+            return Err(syn::Error::new_spanned(
+                qenum.ident,
+                "A QEnum with #[model_roles] must be associated to a QObject!",
+            ));
+        }
+
         let variants = qenum
             .variants
             .iter()
@@ -81,10 +106,19 @@ impl ParsedQEnum {
         Ok(Self {
             name,
             qobject,
+            model_roles,
             variants,
             item: qenum,
         })
     }
+
+    /// The values of the variants, if they are not the default values
+    ///
+    /// The roles of a model start at Qt::UserRole, so that they do not clash with the roles defined by Qt.
+    pub fn variant_values(&self) -> Option<impl Iterator<Item = i32> + '_> {
+        self.model_roles
+            .then(|| (Self::USER_ROLE..).take(self.variants.len()))
+    }
 }
 
 #[cfg(test)]
@@ -195,6 +229,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_model_roles() {
+        let qenum: ItemEnum = parse_quote! {
+            #[model_roles]
+            enum Roles {
+                Id,
+                Value,
+            }
+        };
+        let parsed =
+            ParsedQEnum::parse(qenum, Some(format_ident!("MyObject")), None, &mock_module())
+                .unwrap();
+        assert!(parsed.model_roles);
+        assert_eq!(
+            parsed.variant_values().unwrap().collect::<Vec<_>>(),
+            [0x0100, 0x0101]
+        );
+    }
+
+    #[test]
+    fn parse_model_roles_missing_qobject() {
+        let qenum: ItemEnum = parse_quote! {
+            #[model_roles]
+            #[namespace = "my_namespace"]
+            enum Roles {
+                Id,
+            }
+        };
+        assert!(ParsedQEnum::parse(qenum, None, None, &mock_module()).is_err());
+    }
+
     #[test]
     fn parse_missing_namespace() {
         let qenum: ItemEnum = parse_quote! {
@@ -4,14 +4,19 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::{
-    naming::Name,
+    naming::{AutoCamel, Name},
     parser::{property::ParsedQProperty, require_attributes},
-    syntax::{expr::expr_to_string, foreignmod::ForeignTypeIdentAlias, path::path_compare_str},
+    syntax::{
+        cfg::cfg_attributes, expr::expr_to_string, foreignmod::ForeignTypeIdentAlias,
+        path::path_compare_str,
+    },
 };
 #[cfg(test)]
 use quote::format_ident;
+#[cfg(test)]
+use syn::Visibility;
 
-use syn::{Attribute, Error, Expr, Ident, Meta, Result};
+use syn::{parse::ParseStream, parse_quote, Attribute, Error, Expr, Ident, Meta, Result, Token};
 
 /// Metadata for registering QML element
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -19,6 +24,8 @@ pub struct QmlElementMetadata {
     pub name: String,
     pub uncreatable: bool,
     pub singleton: bool,
+    /// Whether the singleton is initialized on first use with `#[qml_singleton(lazy)]`
+    pub lazy: bool,
 }
 /// A representation of a QObject within a CXX-Qt [syn::ItemMod]
 ///
@@ -37,14 +44,20 @@ pub struct ParsedQObject {
     pub properties: Vec<ParsedQProperty>,
     /// List of specifiers to register with in QML
     pub qml_metadata: Option<QmlElementMetadata>,
+    /// The name of the D-Bus interface which the QObject is exported as
+    pub dbus_interface: Option<String>,
     /// Whether this type has a #[qobject] / Q_OBJECT macro
     pub has_qobject_macro: bool,
+    /// Whether the QObject is re-exported from the module containing the bridge with `#[qobject(reexport)]`
+    pub reexport: bool,
+    /// The `#[cfg(...)]` attributes of the QObject, which are applied to all of its generated code
+    pub cfgs: Vec<Attribute>,
     /// The original declaration entered by the user, i.e. a type alias with a list of attributes
     pub declaration: ForeignTypeIdentAlias,
 }
 
 impl ParsedQObject {
-    const ALLOWED_ATTRS: [&'static str; 10] = [
+    const ALLOWED_ATTRS: [&'static str; 13] = [
         "cxx_name",
         "rust_name",
         "namespace",
@@ -55,6 +68,9 @@ impl ParsedQObject {
         "qml_uncreatable",
         "qml_singleton",
         "qproperty",
+        "dbus_interface",
+        "cfg",
+        "cfg_attr",
     ];
     #[cfg(test)]
     pub fn mock() -> Self {
@@ -64,9 +80,13 @@ impl ParsedQObject {
             rust_type: format_ident!("MyObjectRust"),
             properties: vec![],
             qml_metadata: None,
+            dbus_interface: None,
             has_qobject_macro: false,
+            reexport: false,
+            cfgs: vec![],
             declaration: ForeignTypeIdentAlias {
                 attrs: vec![],
+                vis: Visibility::Inherited,
                 ident_left: format_ident!("MyObject"),
                 ident_right: format_ident!("MyObjectRust"),
             },
@@ -78,9 +98,15 @@ impl ParsedQObject {
         declaration: ForeignTypeIdentAlias,
         namespace: Option<&str>,
         module: &Ident,
+        auto_camel: AutoCamel,
     ) -> Result<Self> {
         let attributes = require_attributes(&declaration.attrs, &Self::ALLOWED_ATTRS)?;
         let has_qobject_macro = attributes.contains_key("qobject");
+        let reexport = attributes
+            .get("qobject")
+            .map(|attr| Self::parse_qobject_reexport(attr))
+            .transpose()?
+            .unwrap_or_default();
 
         let base_class = attributes
             .get("base")
@@ -115,10 +141,16 @@ impl ParsedQObject {
         // Find any QML metadata
         let qml_metadata = Self::parse_qml_metadata(&name, &declaration.attrs)?;
 
+        let dbus_interface = attributes
+            .get("dbus_interface")
+            .map(|attr| expr_to_string(&attr.meta.require_name_value()?.value))
+            .transpose()?;
+
         // Parse any properties in the type
         // and remove the #[qproperty] attribute
-        let properties = Self::parse_property_attributes(&declaration.attrs)?;
+        let properties = Self::parse_property_attributes(&declaration.attrs, auto_camel)?;
         let inner = declaration.ident_right.clone();
+        let cfgs = cfg_attributes(&declaration.attrs);
 
         Ok(Self {
             base_class,
@@ -127,10 +159,35 @@ impl ParsedQObject {
             rust_type: inner,
             properties,
             qml_metadata,
+            dbus_interface,
             has_qobject_macro,
+            reexport,
+            cfgs,
         })
     }
 
+    /// Parse a `#[qobject]` or `#[qobject(reexport)]` attribute, returning whether it is re-exported
+    fn parse_qobject_reexport(attr: &Attribute) -> Result<bool> {
+        match &attr.meta {
+            Meta::Path(_) => Ok(false),
+            Meta::List(list) => {
+                let ident: Ident = list.parse_args()?;
+                if ident == "reexport" {
+                    Ok(true)
+                } else {
+                    Err(Error::new_spanned(
+                        ident,
+                        "Invalid argument to #[qobject], expected reexport",
+                    ))
+                }
+            }
+            Meta::NameValue(_) => Err(Error::new_spanned(
+                attr,
+                "#[qobject] expects no value, use #[qobject] or #[qobject(reexport)]",
+            )),
+        }
+    }
+
     fn parse_qml_metadata(name: &Name, attrs: &[Attribute]) -> Result<Option<QmlElementMetadata>> {
         let attributes = require_attributes(attrs, &Self::ALLOWED_ATTRS)?;
         if let Some(attr) = attributes.get("qml_element") {
@@ -142,25 +199,87 @@ impl ParsedQObject {
             };
             let uncreatable = attributes.contains_key("qml_uncreatable");
             let singleton = attributes.contains_key("qml_singleton");
+            let lazy = attributes
+                .get("qml_singleton")
+                .map(|attr| Self::parse_qml_singleton_lazy(attr))
+                .transpose()?
+                .unwrap_or_default();
             return Ok(Some(QmlElementMetadata {
                 name,
                 uncreatable,
                 singleton,
+                lazy,
             }));
         }
         Ok(None)
     }
 
-    fn parse_property_attributes(attrs: &[Attribute]) -> Result<Vec<ParsedQProperty>> {
+    /// Parse a `#[qml_singleton]` or `#[qml_singleton(lazy)]` attribute, returning whether it is lazy
+    fn parse_qml_singleton_lazy(attr: &Attribute) -> Result<bool> {
+        match &attr.meta {
+            Meta::Path(_) => Ok(false),
+            Meta::List(list) => {
+                let ident: Ident = list.parse_args()?;
+                if ident == "lazy" {
+                    Ok(true)
+                } else {
+                    Err(Error::new_spanned(
+                        ident,
+                        "Invalid argument to #[qml_singleton], expected lazy",
+                    ))
+                }
+            }
+            Meta::NameValue(_) => Err(Error::new_spanned(
+                attr,
+                "#[qml_singleton] expects no value, use #[qml_singleton] or #[qml_singleton(lazy)]",
+            )),
+        }
+    }
+
+    fn parse_property_attributes(
+        attrs: &[Attribute],
+        auto_camel: AutoCamel,
+    ) -> Result<Vec<ParsedQProperty>> {
         // Once extract_if is stable, this would allow comparing all the elements using
         // path_compare_str and building ParsedQProperty from the extracted elements.
         // https://doc.rust-lang.org/nightly/std/vec/struct.Vec.html#method.extract_if
         attrs
             .iter()
-            .filter(|attr| path_compare_str(attr.meta.path(), &["qproperty"]))
-            .map(ParsedQProperty::parse)
+            .filter_map(|attr| {
+                if path_compare_str(attr.meta.path(), &["qproperty"]) {
+                    Some(ParsedQProperty::parse(attr, auto_camel))
+                } else if path_compare_str(attr.meta.path(), &["cfg_attr"]) {
+                    Some(Self::parse_cfg_attr_property(attr, auto_camel))
+                } else {
+                    None
+                }
+            })
             .collect::<Result<Vec<_>>>()
     }
+
+    /// Parse a `#[cfg_attr(predicate, qproperty(...))]` into a property which has a `#[cfg(predicate)]`
+    fn parse_cfg_attr_property(attr: &Attribute, auto_camel: AutoCamel) -> Result<ParsedQProperty> {
+        let (predicate, property) = attr.parse_args_with(|input: ParseStream| {
+            let predicate: Meta = input.parse()?;
+            input.parse::<Token![,]>()?;
+            let property: Meta = input.parse()?;
+            input.parse::<Option<Token![,]>>()?;
+            Ok((predicate, property))
+        })?;
+
+        if !path_compare_str(property.path(), &["qproperty"]) {
+            return Err(Error::new_spanned(
+                property,
+                "cfg_attr on a QObject can only be used with a single qproperty!",
+            ));
+        }
+
+        let property_attr: Attribute = parse_quote! { #[#property] };
+        Ok(ParsedQProperty {
+            cfgs: vec![parse_quote! { #[cfg(#predicate)] }],
+            ..ParsedQProperty::parse(&property_attr, auto_camel)?
+        })
+    }
 }
 
 #[cfg(test)]
@@ -178,7 +297,7 @@ pub mod tests {
                 let input = parse_quote! {
                     $($input)*
                 };
-                ParsedQObject::parse(input, None, &format_ident!("qobject")).unwrap()
+                ParsedQObject::parse(input, None, &format_ident!("qobject"), AutoCamel::Enabled).unwrap()
             }
        }
     }
@@ -201,6 +320,18 @@ pub mod tests {
         assert!(!qobject.has_qobject_macro);
     }
 
+    #[test]
+    fn test_qobject_reexport() {
+        assert!(!create_parsed_qobject().reexport);
+
+        let qobject = parse_qobject! {
+            #[qobject(reexport)]
+            pub type MyObject = super::MyObjectRust;
+        };
+        assert!(qobject.has_qobject_macro);
+        assert!(qobject.reexport);
+    }
+
     #[test]
     fn test_from_struct_no_base_class() {
         let qobject = create_parsed_qobject();
@@ -264,6 +395,7 @@ pub mod tests {
                 name: str_name.to_string(),
                 uncreatable: false,
                 singleton: false,
+                lazy: false,
             })
         );
     }
@@ -296,7 +428,9 @@ pub mod tests {
             #[cxx_name = "RenamedObject"]
             type MyObject = super::MyObjectRust;
         };
-        let qobject = ParsedQObject::parse(item, None, &format_ident!("qobject")).unwrap();
+        let qobject =
+            ParsedQObject::parse(item, None, &format_ident!("qobject"), AutoCamel::Enabled)
+                .unwrap();
         assert_qml_name(qobject, "RenamedObject");
     }
 
@@ -314,6 +448,26 @@ pub mod tests {
                 name: "MyObject".to_string(),
                 uncreatable: false,
                 singleton: true,
+                lazy: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_qml_metadata_singleton_lazy() {
+        let qobject = parse_qobject! {
+            #[qobject]
+            #[qml_element]
+            #[qml_singleton(lazy)]
+            type MyObject = super::MyObjectRust;
+        };
+        assert_eq!(
+            qobject.qml_metadata,
+            Some(QmlElementMetadata {
+                name: "MyObject".to_string(),
+                uncreatable: false,
+                singleton: true,
+                lazy: true,
             })
         );
     }
@@ -332,21 +486,93 @@ pub mod tests {
                 name: "MyObject".to_string(),
                 uncreatable: true,
                 singleton: false,
+                lazy: false,
             })
         );
     }
 
+    #[test]
+    fn test_dbus_interface() {
+        let qobject = parse_qobject! {
+            #[qobject]
+            #[dbus_interface = "org.kde.cxx_qt.MyObject"]
+            type MyObject = super::MyObjectRust;
+        };
+        assert_eq!(
+            qobject.dbus_interface.as_deref(),
+            Some("org.kde.cxx_qt.MyObject")
+        );
+        assert!(create_parsed_qobject().dbus_interface.is_none());
+    }
+
+    #[test]
+    fn test_cfgs() {
+        let qobject = parse_qobject! {
+            #[qobject]
+            #[cfg(feature = "objects")]
+            #[qproperty(i32, always)]
+            #[cfg_attr(feature = "extra", qproperty(i32, extra, READ))]
+            type MyObject = super::MyObjectRust;
+        };
+        assert_eq!(qobject.cfgs.len(), 1);
+
+        let properties = qobject.properties;
+        assert_eq!(properties.len(), 2);
+        assert!(properties[0].cfgs.is_empty());
+        assert_eq!(properties[1].name.rust_unqualified(), "extra");
+        assert_eq!(properties[1].cfgs.len(), 1);
+        assert_eq!(
+            properties[1].cfgs[0],
+            parse_quote! { #[cfg(feature = "extra")] }
+        );
+    }
+
     #[test]
     fn test_parse_errors() {
         assert_parse_errors! {
-            |input |ParsedQObject::parse(input, None, &format_ident!("qobject")) =>
+            |input |ParsedQObject::parse(input, None, &format_ident!("qobject"), AutoCamel::Enabled) =>
 
+            {
+                #[qobject]
+                #[cfg_attr(feature = "extra", qml_element)]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[cfg_attr(feature = "extra")]
+                type MyObject = super::T;
+            }
             {
                 #[qobject]
                 #[base = ""]
                 type MyObject = super::T;
             }
             { type MyObject = super::T; }
+            {
+                #[qobject]
+                #[qml_element]
+                #[qml_singleton(eager)]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[qml_element]
+                #[qml_singleton = "lazy"]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject]
+                #[dbus_interface]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject(export)]
+                type MyObject = super::T;
+            }
+            {
+                #[qobject = "reexport"]
+                type MyObject = super::T;
+            }
         }
     }
 }
@@ -0,0 +1,169 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use quote::format_ident;
+use syn::{parse_quote, Attribute, Error, LitStr, Result};
+
+/// The newest major version of Qt which has `cxxqt_qt_version_at_least_*` cfgs declared by cxx-qt-build
+const MAX_QT_MAJOR_VERSION: u32 = 7;
+/// The newest minor version of Qt which has `cxxqt_qt_version_at_least_*_*` cfgs declared by cxx-qt-build
+const MAX_QT_MINOR_VERSION: u32 = 99;
+
+/// The minimum Qt version of an item, from a `#[qt(since = "6.5")]` attribute
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QtVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl QtVersion {
+    /// Parse a `#[qt(since = "major.minor")]` attribute
+    pub fn parse(attr: &Attribute) -> Result<Self> {
+        let mut since = None;
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("since") {
+                let value: LitStr = meta.value()?.parse()?;
+                since = Some(Self::parse_version(&value)?);
+                Ok(())
+            } else {
+                Err(meta.error("Unsupported qt attribute, expected `since = \"major.minor\"`"))
+            }
+        })?;
+
+        since.ok_or_else(|| {
+            Error::new_spanned(
+                attr,
+                "#[qt] expects a version like `#[qt(since = \"6.5\")]`",
+            )
+        })
+    }
+
+    fn parse_version(value: &LitStr) -> Result<Self> {
+        let version = value.value();
+        let parts = version
+            .split('.')
+            .map(|part| part.parse::<u32>())
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|_| Error::new(value.span(), "Qt versions must be numeric like \"6.5\""))?;
+
+        let (major, minor) = match parts.as_slice() {
+            [major] => (*major, 0),
+            [major, minor] => (*major, *minor),
+            // Only major and minor versions are supported, as cfgs are not defined for patch versions
+            _ => (0, 0),
+        };
+        if !(5..=MAX_QT_MAJOR_VERSION).contains(&major) {
+            return Err(Error::new(
+                value.span(),
+                format!(
+                    "Qt versions must be \"major.minor\" with a major version from 5 to {MAX_QT_MAJOR_VERSION}"
+                ),
+            ));
+        }
+        if minor > MAX_QT_MINOR_VERSION {
+            return Err(Error::new(
+                value.span(),
+                format!(
+                    "Qt versions must be \"major.minor\" with a minor version from 0 to {MAX_QT_MINOR_VERSION}"
+                ),
+            ));
+        }
+
+        Ok(Self { major, minor })
+    }
+
+    /// The `#[cfg(...)]` of the generated Rust, using the cfgs which cxx-qt-build defines for the Qt version
+    pub fn cfg(&self) -> Attribute {
+        let major = format_ident!("cxxqt_qt_version_at_least_{}", self.major);
+        if self.minor == 0 {
+            return parse_quote! { #[cfg(#major)] };
+        }
+
+        // The minor version cfgs are only defined for the major version of Qt that is found
+        let minor = format_ident!("cxxqt_qt_version_at_least_{}_{}", self.major, self.minor);
+        if self.major < MAX_QT_MAJOR_VERSION {
+            let next_major = format_ident!("cxxqt_qt_version_at_least_{}", self.major + 1);
+            parse_quote! { #[cfg(any(#next_major, #minor))] }
+        } else {
+            parse_quote! { #[cfg(#minor)] }
+        }
+    }
+
+    /// The preprocessor condition of the generated C++
+    pub fn cpp_condition(&self) -> String {
+        format!(
+            "QT_VERSION >= QT_VERSION_CHECK({major}, {minor}, 0)",
+            major = self.major,
+            minor = self.minor
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_tokens_eq;
+    use quote::quote;
+
+    #[test]
+    fn test_parse() {
+        let version = QtVersion::parse(&parse_quote! { #[qt(since = "6.5")] }).unwrap();
+        assert_eq!(version, QtVersion { major: 6, minor: 5 });
+        assert_tokens_eq(
+            &version.cfg(),
+            quote! { #[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_5))] },
+        );
+        assert_eq!(
+            version.cpp_condition(),
+            "QT_VERSION >= QT_VERSION_CHECK(6, 5, 0)"
+        );
+
+        let version = QtVersion::parse(&parse_quote! { #[qt(since = "6")] }).unwrap();
+        assert_eq!(version, QtVersion { major: 6, minor: 0 });
+        assert_tokens_eq(
+            &version.cfg(),
+            quote! { #[cfg(cxxqt_qt_version_at_least_6)] },
+        );
+
+        let version = QtVersion::parse(&parse_quote! { #[qt(since = "7.1")] }).unwrap();
+        assert_tokens_eq(
+            &version.cfg(),
+            quote! { #[cfg(cxxqt_qt_version_at_least_7_1)] },
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for attr in [
+            parse_quote! { #[qt] },
+            parse_quote! { #[qt()] },
+            parse_quote! { #[qt(until = "6.5")] },
+            parse_quote! { #[qt(since = 6.5)] },
+            parse_quote! { #[qt(since = "six")] },
+            parse_quote! { #[qt(since = "6.5.1")] },
+            parse_quote! { #[qt(since = "4.8")] },
+            parse_quote! { #[qt(since = "6.100")] },
+        ] {
+            assert!(QtVersion::parse(&attr).is_err());
+        }
+    }
+
+    #[test]
+    fn test_parse_minor_version_range() {
+        let version = QtVersion::parse(&parse_quote! { #[qt(since = "6.99")] }).unwrap();
+        assert_eq!(
+            version,
+            QtVersion {
+                major: 6,
+                minor: 99
+            }
+        );
+
+        // There are no cfgs declared for minor versions above 99
+        let error = QtVersion::parse(&parse_quote! { #[qt(since = "6.100")] }).unwrap_err();
+        assert!(error.to_string().contains("minor version from 0 to 99"));
+    }
+}
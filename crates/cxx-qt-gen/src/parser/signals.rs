@@ -3,11 +3,16 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use crate::{
-    parser::{check_safety, extract_docs, method::MethodFields, require_attributes},
+    parser::{
+        check_safety, extract_docs, method::MethodFields, parameter::ParsedFunctionParameter,
+        require_attributes,
+    },
     syntax::{path::path_compare_str, safety::Safety},
 };
 use core::ops::Deref;
-use syn::{spanned::Spanned, Attribute, Error, ForeignItemFn, Result, Visibility};
+use syn::{
+    spanned::Spanned, Attribute, Error, Fields, ForeignItemFn, Item, Result, Type, Visibility,
+};
 #[derive(Clone)]
 /// Describes an individual Signal
 pub struct ParsedSignal {
@@ -19,10 +24,30 @@ pub struct ParsedSignal {
     pub private: bool,
     /// All the doc attributes (each line) of the signal
     pub docs: Vec<Attribute>,
+    /// The struct parameter of a `#[flatten]` signal, whose fields become the parameters of the signal
+    pub payload: Option<ParsedFunctionParameter>,
+    /// Whether the signal is a Qt private signal, which has a `QPrivateSignal` parameter in C++
+    /// so that it can only be emitted by the QObject itself
+    pub private_signal: bool,
+    /// Whether another signal on the same QObject has the same C++ name
+    pub overloaded: bool,
+    /// An extra namespace for the generated helpers of the signal, so that helpers for the same
+    /// signal from different bridges do not collide
+    pub helper_namespace: Option<String>,
 }
 
 impl ParsedSignal {
-    const ALLOWED_ATTRS: [&'static str; 5] = ["cxx_name", "rust_name", "inherit", "doc", "qsignal"];
+    const ALLOWED_ATTRS: [&'static str; 9] = [
+        "cxx_name",
+        "rust_name",
+        "inherit",
+        "doc",
+        "qsignal",
+        "flatten",
+        "private_signal",
+        "cfg",
+        "qt",
+    ];
 
     #[cfg(test)]
     /// Test fn for creating a mocked signal from a method body
@@ -46,6 +71,43 @@ impl ParsedSignal {
 
         let inherit = attrs.contains_key("inherit");
 
+        let payload = if attrs.contains_key("flatten") {
+            if inherit {
+                return Err(Error::new(
+                    fields.method.span(),
+                    "#[flatten] cannot be used on signals which are inherited",
+                ));
+            }
+
+            if fields.parameters.len() != 1 {
+                return Err(Error::new(
+                    fields.method.sig.inputs.span(),
+                    "#[flatten] signals must have a single struct parameter",
+                ));
+            }
+
+            fields.parameters.first().cloned()
+        } else {
+            None
+        };
+
+        let private_signal = attrs.contains_key("private_signal");
+        if private_signal {
+            if inherit {
+                return Err(Error::new(
+                    fields.method.span(),
+                    "#[private_signal] cannot be used on signals which are inherited",
+                ));
+            }
+
+            if payload.is_some() {
+                return Err(Error::new(
+                    fields.method.span(),
+                    "#[private_signal] cannot be combined with #[flatten]",
+                ));
+            }
+        }
+
         let private = if let Visibility::Restricted(vis_restricted) = &fields.method.vis {
             path_compare_str(&vis_restricted.path, &["self"])
         } else {
@@ -57,8 +119,53 @@ impl ParsedSignal {
             inherit,
             private,
             docs,
+            payload,
+            private_signal,
+            overloaded: false,
+            helper_namespace: None,
         })
     }
+
+    /// Replace the parameters of a `#[flatten]` signal with the fields of its payload struct,
+    /// which must be a shared struct declared in the bridge
+    pub fn flatten_payload(&mut self, items: &[Item]) -> Result<()> {
+        if let Some(payload) = &self.payload {
+            let fields = if let Type::Path(type_path) = &payload.ty {
+                items.iter().find_map(|item| match item {
+                    Item::Struct(item_struct) if type_path.path.is_ident(&item_struct.ident) => {
+                        if let Fields::Named(fields) = &item_struct.fields {
+                            Some(fields)
+                        } else {
+                            None
+                        }
+                    }
+                    _ => None,
+                })
+            } else {
+                None
+            };
+
+            if let Some(fields) = fields {
+                self.method_fields.parameters = fields
+                    .named
+                    .iter()
+                    .filter_map(|field| {
+                        field.ident.as_ref().map(|ident| ParsedFunctionParameter {
+                            ident: ident.clone(),
+                            ty: field.ty.clone(),
+                        })
+                    })
+                    .collect();
+            } else {
+                return Err(Error::new(
+                    payload.ty.span(),
+                    "#[flatten] signals must have a parameter which is a shared struct with named fields declared in the bridge",
+                ));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl Deref for ParsedSignal {
@@ -95,6 +202,32 @@ mod tests {
             { fn ready(x: f64); }
             // Self needs to be receiver like self: &T instead of &self
             { fn ready(&self); }
+            // Flatten needs a single parameter
+            {
+                #[flatten]
+                fn ready(self: Pin<&mut MyObject>);
+            }
+            {
+                #[flatten]
+                fn ready(self: Pin<&mut MyObject>, x: f64, y: f64);
+            }
+            // Flatten can't be used with inherit
+            {
+                #[flatten]
+                #[inherit]
+                fn ready(self: Pin<&mut MyObject>, point: Point);
+            }
+            // Private signals can't be inherited or flattened
+            {
+                #[private_signal]
+                #[inherit]
+                fn ready(self: Pin<&mut MyObject>);
+            }
+            {
+                #[private_signal]
+                #[flatten]
+                fn ready(self: Pin<&mut MyObject>, point: Point);
+            }
         }
     }
 
@@ -193,6 +326,24 @@ mod tests {
         assert!(signal.private);
     }
 
+    #[test]
+    fn test_parse_signal_private_signal() {
+        let method: ForeignItemFn = parse_quote! {
+            #[private_signal]
+            fn ready(self: Pin<&mut MyObject>);
+        };
+        let signal = ParsedSignal::parse(method.clone(), Safety::Safe).unwrap();
+        assert_eq!(signal.method, method);
+        assert_eq!(signal.qobject_ident, format_ident!("MyObject"));
+        assert!(signal.mutable);
+        assert_eq!(signal.parameters, vec![]);
+        assert_eq!(signal.name, Name::new(format_ident!("ready")));
+        assert!(signal.safe);
+        assert!(!signal.inherit);
+        assert!(!signal.private);
+        assert!(signal.private_signal);
+    }
+
     #[test]
     fn test_parse_signal_unsafe() {
         let method: ForeignItemFn = parse_quote! {
@@ -217,4 +368,47 @@ mod tests {
         // Can't be safe on the block and the method
         assert!(ParsedSignal::parse(method, Safety::Unsafe).is_err());
     }
+
+    #[test]
+    fn test_parse_signal_flatten() {
+        let method: ForeignItemFn = parse_quote! {
+            #[flatten]
+            fn moved(self: Pin<&mut MyObject>, point: Point);
+        };
+        let mut signal = ParsedSignal::parse(method.clone(), Safety::Safe).unwrap();
+        assert_eq!(signal.method, method);
+        assert_eq!(signal.parameters.len(), 1);
+        let payload = signal.payload.clone().unwrap();
+        assert_eq!(payload.ident, format_ident!("point"));
+        assert_eq!(payload.ty, parse_quote! { Point });
+
+        let items: Vec<Item> = vec![parse_quote! {
+            struct Point {
+                x: f64,
+                y: f64,
+            }
+        }];
+        signal.flatten_payload(&items).unwrap();
+        assert_eq!(signal.parameters.len(), 2);
+        assert_eq!(signal.parameters[0].ident, format_ident!("x"));
+        assert_eq!(signal.parameters[0].ty, f64_type());
+        assert_eq!(signal.parameters[1].ident, format_ident!("y"));
+        assert_eq!(signal.parameters[1].ty, f64_type());
+    }
+
+    #[test]
+    fn test_parse_signal_flatten_missing_struct() {
+        let method: ForeignItemFn = parse_quote! {
+            #[flatten]
+            fn moved(self: Pin<&mut MyObject>, point: Point);
+        };
+        let mut signal = ParsedSignal::parse(method, Safety::Safe).unwrap();
+        assert!(signal.flatten_payload(&[]).is_err());
+
+        // Tuple structs have no field names to use as parameters
+        let items: Vec<Item> = vec![parse_quote! {
+            struct Point(f64, f64);
+        }];
+        assert!(signal.flatten_payload(&items).is_err());
+    }
 }
@@ -0,0 +1,151 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::syntax::path::path_compare_str;
+use syn::{
+    punctuated::Punctuated, spanned::Spanned, Attribute, Error, Expr, ExprLit, Lit, Meta,
+    MetaNameValue, Result, Token,
+};
+
+/// The `#[cfg(...)]` attributes of the items in a bridge are evaluated with the same
+/// [CfgEvaluator] as CXX uses, so that a build system can use one evaluator for both.
+///
+/// The Rust generation instead keeps the attributes, so that they are evaluated by the compiler.
+pub use cxx_gen::{CfgEvaluator, CfgResult};
+
+/// Returns the `#[cfg(...)]` attributes of an item
+pub fn cfg_attributes(attrs: &[Attribute]) -> Vec<Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| path_compare_str(attr.meta.path(), &["cfg"]))
+        .cloned()
+        .collect()
+}
+
+/// Evaluate the given `#[cfg(...)]` attributes, returning whether all of them are enabled
+pub fn try_eval_attributes(evaluator: &dyn CfgEvaluator, attrs: &[Attribute]) -> Result<bool> {
+    for attr in attrs {
+        if !try_eval_meta(evaluator, &attr.meta.require_list()?.parse_args()?)? {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+fn try_eval_meta(evaluator: &dyn CfgEvaluator, meta: &Meta) -> Result<bool> {
+    let result = match meta {
+        Meta::Path(path) => evaluator.eval(&path.require_ident()?.to_string(), None),
+        Meta::NameValue(MetaNameValue {
+            path,
+            value:
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(value),
+                    ..
+                }),
+            ..
+        }) => evaluator.eval(&path.require_ident()?.to_string(), Some(&value.value())),
+        Meta::NameValue(name_value) => {
+            return Err(Error::new(
+                name_value.value.span(),
+                "Expected a string literal as the cfg value!",
+            ))
+        }
+        Meta::List(list) => {
+            let nested = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+            let mut values = nested
+                .iter()
+                .map(|meta| try_eval_meta(evaluator, meta))
+                .collect::<Result<Vec<bool>>>()?
+                .into_iter();
+
+            return if list.path.is_ident("all") {
+                Ok(values.all(|value| value))
+            } else if list.path.is_ident("any") {
+                Ok(values.any(|value| value))
+            } else if list.path.is_ident("not") && nested.len() == 1 {
+                Ok(!values.all(|value| value))
+            } else {
+                Err(Error::new(
+                    list.span(),
+                    "Unsupported cfg predicate, expected all(), any(), or not() with a single predicate!",
+                ))
+            };
+        }
+    };
+
+    match result {
+        CfgResult::True => Ok(true),
+        CfgResult::False => Ok(false),
+        CfgResult::Undetermined { msg } => Err(Error::new(meta.span(), msg)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::{parse_quote, ItemStruct};
+
+    struct UnixEvaluator;
+
+    impl CfgEvaluator for UnixEvaluator {
+        fn eval(&self, name: &str, value: Option<&str>) -> CfgResult {
+            match (name, value) {
+                ("unix", None) => CfgResult::True,
+                ("feature", Some(feature)) => CfgResult::from(feature == "enabled"),
+                ("test", None) => CfgResult::Undetermined {
+                    msg: "cfg(test) is not supported".to_owned(),
+                },
+                _ => CfgResult::False,
+            }
+        }
+    }
+
+    fn eval(item: ItemStruct) -> Result<bool> {
+        try_eval_attributes(&UnixEvaluator, &cfg_attributes(&item.attrs))
+    }
+
+    #[test]
+    fn test_cfg_attributes() {
+        let item: ItemStruct = parse_quote! {
+            #[doc = "Docs"]
+            #[cfg(unix)]
+            #[derive(Debug)]
+            #[cfg(windows)]
+            struct A;
+        };
+        assert_eq!(cfg_attributes(&item.attrs).len(), 2);
+    }
+
+    #[test]
+    fn test_eval() {
+        assert!(eval(parse_quote! { struct A; }).unwrap());
+        assert!(eval(parse_quote! { #[cfg(unix)] struct A; }).unwrap());
+        assert!(!eval(parse_quote! { #[cfg(windows)] struct A; }).unwrap());
+        assert!(eval(parse_quote! { #[cfg(feature = "enabled")] struct A; }).unwrap());
+        assert!(!eval(parse_quote! { #[cfg(feature = "disabled")] struct A; }).unwrap());
+        assert!(!eval(parse_quote! { #[cfg(unix)] #[cfg(windows)] struct A; }).unwrap());
+    }
+
+    #[test]
+    fn test_eval_predicates() {
+        assert!(eval(parse_quote! { #[cfg(all(unix, feature = "enabled"))] struct A; }).unwrap());
+        assert!(!eval(parse_quote! { #[cfg(all(unix, windows))] struct A; }).unwrap());
+        assert!(eval(parse_quote! { #[cfg(any(unix, windows))] struct A; }).unwrap());
+        assert!(!eval(parse_quote! { #[cfg(any())] struct A; }).unwrap());
+        assert!(eval(parse_quote! { #[cfg(not(windows))] struct A; }).unwrap());
+        assert!(!eval(parse_quote! { #[cfg(not(any(unix, windows)))] struct A; }).unwrap());
+    }
+
+    #[test]
+    fn test_eval_invalid() {
+        assert!(eval(parse_quote! { #[cfg(test)] struct A; }).is_err());
+        assert!(eval(parse_quote! { #[cfg(feature = 1)] struct A; }).is_err());
+        assert!(eval(parse_quote! { #[cfg(not(unix, windows))] struct A; }).is_err());
+        assert!(eval(parse_quote! { #[cfg(unknown(unix))] struct A; }).is_err());
+        assert!(eval(parse_quote! { #[cfg] struct A; }).is_err());
+    }
+}
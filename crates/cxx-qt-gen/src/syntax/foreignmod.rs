@@ -86,6 +86,8 @@ fn verbatim_to_foreign_type(tokens: &TokenStream) -> Result<Option<ForeignItemTy
 pub struct ForeignTypeIdentAlias {
     /// Attributes on the alias
     pub attrs: Vec<Attribute>,
+    /// The visibility of the alias
+    pub vis: Visibility,
     /// The left side of the alias
     pub ident_left: Ident,
     /// The right side of the alias
@@ -96,8 +98,7 @@ impl Parse for ForeignTypeIdentAlias {
     fn parse(input: ParseStream) -> Result<Self> {
         let attrs = input.call(Attribute::parse_outer)?;
 
-        // Note that visibility is ignored for now
-        let _: Visibility = input.parse()?;
+        let vis: Visibility = input.parse()?;
 
         if input.peek(Token![type]) {
             let _type_token: Token![type] = input.parse()?;
@@ -143,6 +144,7 @@ impl Parse for ForeignTypeIdentAlias {
 
             Ok(Self {
                 attrs,
+                vis,
                 ident_left,
                 ident_right,
             })
@@ -290,12 +292,12 @@ mod tests {
 
     #[test]
     fn test_foreign_type_ident_visibility() {
-        // Ensure that visibility does not error, later it might be stored
         let alias: ForeignTypeIdentAlias = parse_quote! {
             #[attr]
             pub type A = super::B;
         };
         assert_eq!(alias.attrs.len(), 1);
+        assert!(matches!(alias.vis, Visibility::Public(_)));
         assert_eq!(alias.ident_left, "A");
         assert_eq!(alias.ident_right, "B");
     }
@@ -4,6 +4,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 pub mod attribute;
+pub mod cfg;
 pub mod expr;
 pub mod foreignmod;
 pub mod lifetimes;
@@ -13,5 +14,6 @@ mod qtitem;
 pub mod safety;
 pub mod types;
 
+pub use cfg::{CfgEvaluator, CfgResult};
 pub use qtfile::{parse_qt_file, CxxQtFile};
 pub use qtitem::CxxQtItem;
@@ -81,7 +81,7 @@ fn qobjects_header(generated: &GeneratedCppBlocks) -> Vec<String> {
         let class_definition = namespaced(
             qobject.name.namespace().unwrap_or_default(),
             &formatdoc! { r#"
-                class {ident} : {base_classes}
+                {docs}class {ident} : {base_classes}
                 {{
                   {qobject_macro}
                 public:
@@ -94,6 +94,7 @@ fn qobjects_header(generated: &GeneratedCppBlocks) -> Vec<String> {
                 }};
 
                 {qobject_assert}"#,
+            docs = qobject.docs,
             // Note that there is always a base class as we always have CxxQtType
             base_classes = qobject.blocks.base_classes.iter().map(|base| format!("public {}", base)).collect::<Vec<String>>().join(", "),
             metaobjects = qobject.blocks.metaobjects.join("\n  "),
@@ -145,13 +146,17 @@ pub fn write_cpp_header(generated: &GeneratedCppBlocks, include_path: &str) -> S
                         .extern_cxx_qt
                         .iter()
                         .flat_map(|block| &block.includes),
-                ),
+                )
+                .cloned(),
         );
-        include_set
-            .into_iter()
-            .cloned()
-            .collect::<Vec<String>>()
-            .join("\n")
+        include_set.extend(
+            generated
+                .extern_cxx_qt
+                .iter()
+                .flat_map(|block| &block.aliased_bridges)
+                .filter_map(|bridge| bridge.include(include_path)),
+        );
+        include_set.into_iter().collect::<Vec<String>>().join("\n")
     };
     let extern_cxx_qt = extract_extern_qt(generated, pair_as_header);
 
@@ -110,6 +110,7 @@ mod tests {
                         "cxx_qt_my_object".to_owned()
                     },
                     has_qobject_macro: true,
+                    docs: String::new(),
                     blocks: GeneratedCppQObjectBlocks {
                         base_classes: vec!["QStringListModel".to_owned()],
                         includes: {
@@ -232,6 +233,7 @@ mod tests {
                     rust_struct: Name::mock("FirstObjectRust"),
                     namespace_internals: "cxx_qt::cxx_qt_first_object".to_owned(),
                     has_qobject_macro: true,
+                    docs: String::new(),
                     blocks: GeneratedCppQObjectBlocks {
                         base_classes: vec!["QStringListModel".to_owned()],
                         includes: {
@@ -275,6 +277,7 @@ mod tests {
                     rust_struct: Name::mock("SecondObjectRust"),
                     namespace_internals: "cxx_qt::cxx_qt_second_object".to_owned(),
                     has_qobject_macro: true,
+                    docs: String::new(),
                     blocks: GeneratedCppQObjectBlocks {
                         base_classes: vec!["QStringListModel".to_owned()],
                         includes: {
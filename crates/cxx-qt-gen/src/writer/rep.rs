@@ -0,0 +1,68 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::rep::GeneratedRepBlocks;
+
+/// For a given GeneratedRepBlocks write the Qt Remote Objects `.rep` file
+pub fn write_rep(generated: &GeneratedRepBlocks) -> String {
+    generated
+        .classes
+        .iter()
+        .map(|class| {
+            let members = class
+                .properties
+                .iter()
+                .chain(&class.signals)
+                .chain(&class.slots)
+                .map(|member| format!("    {member}\n"))
+                .collect::<String>();
+            format!("class {name}\n{{\n{members}}};\n", name = class.name)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::rep::GeneratedRepClass;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+
+    #[test]
+    fn test_write_rep() {
+        let generated = GeneratedRepBlocks {
+            classes: vec![
+                GeneratedRepClass {
+                    name: "MyObject".to_owned(),
+                    properties: vec!["PROP(::std::int32_t number READWRITE);".to_owned()],
+                    signals: vec!["SIGNAL(ready());".to_owned()],
+                    slots: vec!["SLOT(void sayHi(QString const& string));".to_owned()],
+                },
+                GeneratedRepClass {
+                    name: "SecondObject".to_owned(),
+                    ..Default::default()
+                },
+            ],
+        };
+
+        assert_str_eq!(
+            write_rep(&generated),
+            indoc! {r#"
+            class MyObject
+            {
+                PROP(::std::int32_t number READWRITE);
+                SIGNAL(ready());
+                SLOT(void sayHi(QString const& string));
+            };
+
+            class SecondObject
+            {
+            };
+            "#}
+        );
+    }
+}
@@ -79,6 +79,12 @@ fn signal_boilerplate() -> TokenStream {
             #[rust_name = "CxxQtQMetaObjectConnection"]
             #[allow(dead_code)]
             type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+
+            #[doc(hidden)]
+            #[namespace = ""]
+            #[rust_name = "CxxQtQObject"]
+            #[allow(dead_code)]
+            type QObject = cxx_qt::QObject;
         }
     }
 }
@@ -16,8 +16,8 @@ mod ffi {
     }
 
     unsafe extern "RustQt" {
-        #[qobject]
-        type MyObject = super::MyObjectRust;
+        #[qobject(reexport)]
+        pub type MyObject = super::MyObjectRust;
 
         fn cpp_method(self: &MyObject);
 
@@ -0,0 +1,10 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qml_singleton(lazy)]
+        #[qproperty(i32, number)]
+        type MySingleton = super::MySingletonRust;
+    }
+}
@@ -0,0 +1,19 @@
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        #[qobject]
+        #[namespace = "other"]
+        type OtherObject = crate::other::qobject::OtherObject;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[base = OtherObject]
+        type MyObject = super::MyObjectRust;
+    }
+
+    unsafe extern "RustQt" {
+        #[qinvokable]
+        fn compare(self: &MyObject, other: &OtherObject) -> bool;
+    }
+}
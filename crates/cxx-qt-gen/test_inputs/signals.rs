@@ -20,9 +20,11 @@ mod ffi {
     }
 
     unsafe extern "RustQt" {
+        /// A QObject with signals
         #[qobject]
         type MyObject = super::MyObjectRust;
 
+        /// Emitted when the object is ready
         #[qsignal]
         fn ready(self: Pin<&mut MyObject>);
 
@@ -46,6 +48,7 @@ mod ffi {
             fourth: &'a QPoint,
         );
 
+        /// Emits the signals
         #[qinvokable]
         fn invokable(self: Pin<&mut MyObject>);
     }
@@ -6,6 +6,11 @@ class MyObject;
 
 #include "directory/file_ident.cxx.h"
 
+namespace cxx_qt_my_object {
+[[maybe_unused]] static const bool cxxQtSignatureCheck =
+  (cxxQtSignaturefdea059e6e36ec18(), true);
+} // namespace cxx_qt_my_object
+
 class MyObject
   : public QAbstractItemModel
   , public ::rust::cxxqt1::CxxQtType<MyObjectRust>
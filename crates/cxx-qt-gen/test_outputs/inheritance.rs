@@ -20,6 +20,11 @@ mod inheritance {
         #[rust_name = "CxxQtQMetaObjectConnection"]
         #[allow(dead_code)]
         type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        #[allow(dead_code)]
+        type QObject = cxx_qt::QObject;
     }
     unsafe extern "C++" {
         include!("directory/file_ident.cxxqt.h");
@@ -33,6 +38,33 @@ mod inheritance {
         #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/qobject/generated-qobject.html>"]
         type MyObject;
     }
+    unsafe extern "C++" {
+        include!("cxx-qt/upcast.h");
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_upcast_ptr_q_abstract_item_model(
+            thiz: *const MyObject,
+        ) -> *const QAbstractItemModel;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_downcast_ptr_q_abstract_item_model(
+            base: *const QAbstractItemModel,
+        ) -> *const MyObject;
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_upcast_ptr_qobject(
+            thiz: *const MyObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_downcast_ptr_qobject(
+            base: *const CxxQtQObject,
+        ) -> *const MyObject;
+    }
     extern "Rust" {
         type MyObjectRust;
     }
@@ -79,11 +111,29 @@ mod inheritance {
             outer: Pin<&mut MyObject>,
         ) -> Pin<&mut MyObjectRust>;
     }
+    extern "Rust" {
+        #[cxx_name = "cxxQtSignaturefdea059e6e36ec18"]
+        #[namespace = "cxx_qt_my_object"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_signature_fdea059e6e36ec18();
+    }
+}
+unsafe impl cxx_qt::Upcast<inheritance::QAbstractItemModel> for inheritance::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const inheritance::QAbstractItemModel {
+        inheritance::cxx_qt_ffi_my_object_upcast_ptr_q_abstract_item_model(this)
+    }
+    unsafe fn from_base_ptr(base: *const inheritance::QAbstractItemModel) -> *const Self {
+        inheritance::cxx_qt_ffi_my_object_downcast_ptr_q_abstract_item_model(base)
+    }
+}
+unsafe impl cxx_qt::Upcast<cxx_qt::QObject> for inheritance::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        inheritance::cxx_qt_ffi_my_object_upcast_ptr_qobject(this)
+    }
+    unsafe fn from_base_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        inheritance::cxx_qt_ffi_my_object_downcast_ptr_qobject(base)
+    }
 }
-impl cxx_qt::Upcast<inheritance::QAbstractItemModel> for inheritance::MyObject {}
-#[allow(unused_imports)]
-#[allow(dead_code)]
-use inheritance::QAbstractItemModel as _;
 #[doc(hidden)]
 pub fn create_rs_my_object_rust() -> std::boxed::Box<MyObjectRust> {
     std::boxed::Box::new(core::default::Default::default())
@@ -103,3 +153,5 @@ impl ::cxx_qt::CxxQtType for inheritance::MyObject {
         inheritance::cxx_qt_ffi_my_object_unsafe_rust_mut(self)
     }
 }
+#[doc(hidden)]
+fn cxx_qt_ffi_my_object_signature_fdea059e6e36ec18() {}
@@ -6,6 +6,14 @@ static_assert(alignof(MyObjectCxxQtThread) <= alignof(::std::size_t),
 static_assert(sizeof(MyObjectCxxQtThread) == sizeof(::std::size_t[2]),
               "unexpected size");
 
+void
+MyObject::customEvent(QEvent* event)
+{
+  if (!::rust::cxxqt1::cxxQtThreadEvent(event)) {
+    QObject::customEvent(event);
+  }
+}
+
 MyObject::MyObject(::std::int32_t arg0, QString const& arg1)
   : MyObject(
       ::cxx_qt::my_object::cxx_qt_my_object::routeArguments0(::std::move(arg0),
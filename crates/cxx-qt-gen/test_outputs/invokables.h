@@ -11,6 +11,11 @@ using MyObjectCxxQtThread = ::rust::cxxqt1::CxxQtThread<MyObject>;
 
 #include "directory/file_ident.cxx.h"
 
+namespace cxx_qt::my_object::cxx_qt_my_object {
+[[maybe_unused]] static const bool cxxQtSignatureCheck =
+  (cxxQtSignature1f97de778e454d5a(), true);
+} // namespace cxx_qt::my_object::cxx_qt_my_object
+
 namespace cxx_qt::my_object {
 class MyObject
   : public QObject
@@ -35,6 +40,7 @@ public:
   Q_INVOKABLE virtual void invokableVirtual() const noexcept;
   Q_INVOKABLE void invokableResultTuple() const;
   Q_INVOKABLE ::rust::String invokableResultType() const;
+  void customEvent(QEvent* event) override;
   explicit MyObject(::std::int32_t arg0, QString const& arg1);
   explicit MyObject();
 
@@ -25,6 +25,11 @@ mod ffi {
         #[rust_name = "CxxQtQMetaObjectConnection"]
         #[allow(dead_code)]
         type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        #[allow(dead_code)]
+        type QObject = cxx_qt::QObject;
     }
     unsafe extern "C++" {
         include!("directory/file_ident.cxxqt.h");
@@ -39,6 +44,21 @@ mod ffi {
         #[namespace = "cxx_qt::my_object"]
         type MyObject;
     }
+    unsafe extern "C++" {
+        include!("cxx-qt/upcast.h");
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_upcast_ptr_qobject(
+            thiz: *const MyObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_downcast_ptr_qobject(
+            base: *const CxxQtQObject,
+        ) -> *const MyObject;
+    }
     extern "Rust" {
         #[namespace = "cxx_qt::my_object"]
         type MyObjectRust;
@@ -124,7 +144,9 @@ mod ffi {
             cxx_qt_thread: &MyObjectCxxQtThread,
             func: fn(Pin<&mut MyObject>, Box<MyObjectCxxQtThreadQueuedFn>),
             arg: Box<MyObjectCxxQtThreadQueuedFn>,
-        ) -> Result<()>;
+            priority: i32,
+            capacity: usize,
+        ) -> Result<bool>;
         #[doc(hidden)]
         #[cxx_name = "cxxQtThreadClone"]
         #[namespace = "rust::cxxqt1"]
@@ -135,6 +157,16 @@ mod ffi {
         #[cxx_name = "cxxQtThreadDrop"]
         #[namespace = "rust::cxxqt1"]
         fn cxx_qt_ffi_my_object_cxx_qt_thread_drop(cxx_qt_thread: &mut MyObjectCxxQtThread);
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadLock"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_my_object_cxx_qt_thread_lock(
+            cxx_qt_thread: &MyObjectCxxQtThread,
+        ) -> *const MyObject;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadUnlock"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_my_object_cxx_qt_thread_unlock(cxx_qt_thread: &MyObjectCxxQtThread);
     }
     extern "Rust" {
         #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
@@ -243,6 +275,20 @@ mod ffi {
             outer: Pin<&mut MyObject>,
         ) -> Pin<&mut MyObjectRust>;
     }
+    extern "Rust" {
+        #[cxx_name = "cxxQtSignature1f97de778e454d5a"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_signature_1f97de778e454d5a();
+    }
+}
+unsafe impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_my_object_upcast_ptr_qobject(this)
+    }
+    unsafe fn from_base_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_my_object_downcast_ptr_qobject(base)
+    }
 }
 impl cxx_qt::Threading for ffi::MyObject {
     type BoxedQueuedFn = MyObjectCxxQtThreadQueuedFn;
@@ -254,7 +300,9 @@ impl cxx_qt::Threading for ffi::MyObject {
     fn queue<F>(
         cxx_qt_thread: &ffi::MyObjectCxxQtThread,
         f: F,
-    ) -> std::result::Result<(), cxx::Exception>
+        priority: cxx_qt::EventPriority,
+        capacity: usize,
+    ) -> std::result::Result<bool, cxx::Exception>
     where
         F: FnOnce(core::pin::Pin<&mut ffi::MyObject>),
         F: Send + 'static,
@@ -274,6 +322,8 @@ impl cxx_qt::Threading for ffi::MyObject {
             cxx_qt_thread,
             func,
             std::boxed::Box::new(arg),
+            priority as i32,
+            capacity,
         )
     }
     #[doc(hidden)]
@@ -284,6 +334,14 @@ impl cxx_qt::Threading for ffi::MyObject {
     fn threading_drop(cxx_qt_thread: &mut ffi::MyObjectCxxQtThread) {
         ffi::cxx_qt_ffi_my_object_cxx_qt_thread_drop(cxx_qt_thread);
     }
+    #[doc(hidden)]
+    fn threading_lock(cxx_qt_thread: &ffi::MyObjectCxxQtThread) -> *const ffi::MyObject {
+        ffi::cxx_qt_ffi_my_object_cxx_qt_thread_lock(cxx_qt_thread)
+    }
+    #[doc(hidden)]
+    unsafe fn threading_unlock(cxx_qt_thread: &ffi::MyObjectCxxQtThread) {
+        ffi::cxx_qt_ffi_my_object_cxx_qt_thread_unlock(cxx_qt_thread);
+    }
 }
 #[doc(hidden)]
 pub struct MyObjectCxxQtThreadQueuedFn {
@@ -374,3 +432,7 @@ impl ::cxx_qt::CxxQtType for ffi::MyObject {
         ffi::cxx_qt_ffi_my_object_unsafe_rust_mut(self)
     }
 }
+#[doc(hidden)]
+fn cxx_qt_ffi_my_object_signature_1f97de778e454d5a() {}
+pub use self::ffi::MyObject;
+pub use self::ffi::MyObjectCxxQtThread;
@@ -4,7 +4,7 @@
 // https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
 namespace rust::cxxqt1 {
 template<>
-SignalHandler<::cxx_qt::multi_object::rust::cxxqtgen1::
+SignalHandler<::cxx_qt::multi_object::rust::cxxqtgen1::ffi::
                 QPushButtonCxxQtSignalParamsclicked*>::~SignalHandler() noexcept
 {
   if (data[0] == nullptr && data[1] == nullptr) {
@@ -17,7 +17,7 @@ SignalHandler<::cxx_qt::multi_object::rust::cxxqtgen1::
 template<>
 template<>
 void
-SignalHandler<::cxx_qt::multi_object::rust::cxxqtgen1::
+SignalHandler<::cxx_qt::multi_object::rust::cxxqtgen1::ffi::
                 QPushButtonCxxQtSignalParamsclicked*>::
 operator()<cxx_qt::multi_object::QPushButton&, bool>(
   cxx_qt::multi_object::QPushButton& self,
@@ -26,22 +26,24 @@ operator()<cxx_qt::multi_object::QPushButton&, bool>(
   call_QPushButton_signal_handler_clicked(*this, self, ::std::move(checked));
 }
 
-static_assert(alignof(SignalHandler<::cxx_qt::multi_object::rust::cxxqtgen1::
-                                      QPushButtonCxxQtSignalParamsclicked*>) <=
-                alignof(::std::size_t),
-              "unexpected aligment");
-static_assert(sizeof(SignalHandler<::cxx_qt::multi_object::rust::cxxqtgen1::
-                                     QPushButtonCxxQtSignalParamsclicked*>) ==
-                sizeof(::std::size_t[2]),
-              "unexpected size");
+static_assert(
+  alignof(SignalHandler<::cxx_qt::multi_object::rust::cxxqtgen1::ffi::
+                          QPushButtonCxxQtSignalParamsclicked*>) <=
+    alignof(::std::size_t),
+  "unexpected aligment");
+static_assert(
+  sizeof(SignalHandler<::cxx_qt::multi_object::rust::cxxqtgen1::ffi::
+                         QPushButtonCxxQtSignalParamsclicked*>) ==
+    sizeof(::std::size_t[2]),
+  "unexpected size");
 } // namespace rust::cxxqt1
 
-namespace cxx_qt::multi_object::rust::cxxqtgen1 {
+namespace cxx_qt::multi_object::rust::cxxqtgen1::ffi {
 ::QMetaObject::Connection
 QPushButton_clickedConnect(
   cxx_qt::multi_object::QPushButton& self,
-  ::cxx_qt::multi_object::rust::cxxqtgen1::QPushButtonCxxQtSignalHandlerclicked
-    closure,
+  ::cxx_qt::multi_object::rust::cxxqtgen1::ffi::
+    QPushButtonCxxQtSignalHandlerclicked closure,
   ::Qt::ConnectionType type)
 {
   return ::QObject::connect(
@@ -54,15 +56,39 @@ QPushButton_clickedConnect(
     },
     type);
 }
-} // namespace cxx_qt::multi_object::rust::cxxqtgen1
+} // namespace cxx_qt::multi_object::rust::cxxqtgen1::ffi
+
+namespace cxx_qt::multi_object::rust::cxxqtgen1::ffi {
+::QMetaObject::Connection
+QPushButton_clickedConnectWithContext(
+  cxx_qt::multi_object::QPushButton& self,
+  const ::QObject& context,
+  ::cxx_qt::multi_object::rust::cxxqtgen1::ffi::
+    QPushButtonCxxQtSignalHandlerclicked closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::multi_object::QPushButton::clicked,
+    &context,
+    [sender = ::QPointer<cxx_qt::multi_object::QPushButton>(&self),
+     closure = ::std::move(closure)](bool checked) mutable {
+      if (sender) {
+        closure.template operator()<cxx_qt::multi_object::QPushButton&, bool>(
+          *sender, ::std::move(checked));
+      }
+    },
+    type);
+}
+} // namespace cxx_qt::multi_object::rust::cxxqtgen1::ffi
 
 // Define namespace otherwise we hit a GCC bug
 // https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
 namespace rust::cxxqt1 {
 template<>
 SignalHandler<
-  ::mynamespace::rust::cxxqtgen1::ExternObjectCxxQtSignalParamsdataReady*>::
-  ~SignalHandler() noexcept
+  ::mynamespace::rust::cxxqtgen1::ffi::
+    ExternObjectCxxQtSignalParamsdataReady*>::~SignalHandler() noexcept
 {
   if (data[0] == nullptr && data[1] == nullptr) {
     return;
@@ -74,30 +100,30 @@ SignalHandler<
 template<>
 template<>
 void
-SignalHandler<
-  ::mynamespace::rust::cxxqtgen1::ExternObjectCxxQtSignalParamsdataReady*>::
+SignalHandler<::mynamespace::rust::cxxqtgen1::ffi::
+                ExternObjectCxxQtSignalParamsdataReady*>::
 operator()<mynamespace::ExternObjectCpp&>(mynamespace::ExternObjectCpp& self)
 {
   call_ExternObject_signal_handler_dataReady(*this, self);
 }
 
 static_assert(
-  alignof(SignalHandler<::mynamespace::rust::cxxqtgen1::
+  alignof(SignalHandler<::mynamespace::rust::cxxqtgen1::ffi::
                           ExternObjectCxxQtSignalParamsdataReady*>) <=
     alignof(::std::size_t),
   "unexpected aligment");
 static_assert(
-  sizeof(SignalHandler<::mynamespace::rust::cxxqtgen1::
+  sizeof(SignalHandler<::mynamespace::rust::cxxqtgen1::ffi::
                          ExternObjectCxxQtSignalParamsdataReady*>) ==
     sizeof(::std::size_t[2]),
   "unexpected size");
 } // namespace rust::cxxqt1
 
-namespace mynamespace::rust::cxxqtgen1 {
+namespace mynamespace::rust::cxxqtgen1::ffi {
 ::QMetaObject::Connection
 ExternObjectCpp_dataReadyConnect(
   mynamespace::ExternObjectCpp& self,
-  ::mynamespace::rust::cxxqtgen1::ExternObjectCxxQtSignalHandlerdataReady
+  ::mynamespace::rust::cxxqtgen1::ffi::ExternObjectCxxQtSignalHandlerdataReady
     closure,
   ::Qt::ConnectionType type)
 {
@@ -110,15 +136,38 @@ ExternObjectCpp_dataReadyConnect(
     },
     type);
 }
-} // namespace mynamespace::rust::cxxqtgen1
+} // namespace mynamespace::rust::cxxqtgen1::ffi
+
+namespace mynamespace::rust::cxxqtgen1::ffi {
+::QMetaObject::Connection
+ExternObjectCpp_dataReadyConnectWithContext(
+  mynamespace::ExternObjectCpp& self,
+  const ::QObject& context,
+  ::mynamespace::rust::cxxqtgen1::ffi::ExternObjectCxxQtSignalHandlerdataReady
+    closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &mynamespace::ExternObjectCpp::dataReady,
+    &context,
+    [sender = ::QPointer<mynamespace::ExternObjectCpp>(&self),
+     closure = ::std::move(closure)]() mutable {
+      if (sender) {
+        closure.template operator()<mynamespace::ExternObjectCpp&>(*sender);
+      }
+    },
+    type);
+}
+} // namespace mynamespace::rust::cxxqtgen1::ffi
 
 // Define namespace otherwise we hit a GCC bug
 // https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
 namespace rust::cxxqt1 {
 template<>
 SignalHandler<
-  ::mynamespace::rust::cxxqtgen1::ExternObjectCxxQtSignalParamserrorOccurred*>::
-  ~SignalHandler() noexcept
+  ::mynamespace::rust::cxxqtgen1::ffi::
+    ExternObjectCxxQtSignalParamserrorOccurred*>::~SignalHandler() noexcept
 {
   if (data[0] == nullptr && data[1] == nullptr) {
     return;
@@ -130,31 +179,31 @@ SignalHandler<
 template<>
 template<>
 void
-SignalHandler<
-  ::mynamespace::rust::cxxqtgen1::ExternObjectCxxQtSignalParamserrorOccurred*>::
+SignalHandler<::mynamespace::rust::cxxqtgen1::ffi::
+                ExternObjectCxxQtSignalParamserrorOccurred*>::
 operator()<mynamespace::ExternObjectCpp&>(mynamespace::ExternObjectCpp& self)
 {
   call_ExternObject_signal_handler_errorOccurred(*this, self);
 }
 
 static_assert(
-  alignof(SignalHandler<::mynamespace::rust::cxxqtgen1::
+  alignof(SignalHandler<::mynamespace::rust::cxxqtgen1::ffi::
                           ExternObjectCxxQtSignalParamserrorOccurred*>) <=
     alignof(::std::size_t),
   "unexpected aligment");
 static_assert(
-  sizeof(SignalHandler<::mynamespace::rust::cxxqtgen1::
+  sizeof(SignalHandler<::mynamespace::rust::cxxqtgen1::ffi::
                          ExternObjectCxxQtSignalParamserrorOccurred*>) ==
     sizeof(::std::size_t[2]),
   "unexpected size");
 } // namespace rust::cxxqt1
 
-namespace mynamespace::rust::cxxqtgen1 {
+namespace mynamespace::rust::cxxqtgen1::ffi {
 ::QMetaObject::Connection
 ExternObjectCpp_errorOccurredConnect(
   mynamespace::ExternObjectCpp& self,
-  ::mynamespace::rust::cxxqtgen1::ExternObjectCxxQtSignalHandlererrorOccurred
-    closure,
+  ::mynamespace::rust::cxxqtgen1::ffi::
+    ExternObjectCxxQtSignalHandlererrorOccurred closure,
   ::Qt::ConnectionType type)
 {
   return ::QObject::connect(
@@ -166,7 +215,30 @@ ExternObjectCpp_errorOccurredConnect(
     },
     type);
 }
-} // namespace mynamespace::rust::cxxqtgen1
+} // namespace mynamespace::rust::cxxqtgen1::ffi
+
+namespace mynamespace::rust::cxxqtgen1::ffi {
+::QMetaObject::Connection
+ExternObjectCpp_errorOccurredConnectWithContext(
+  mynamespace::ExternObjectCpp& self,
+  const ::QObject& context,
+  ::mynamespace::rust::cxxqtgen1::ffi::
+    ExternObjectCxxQtSignalHandlererrorOccurred closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &mynamespace::ExternObjectCpp::errorOccurred,
+    &context,
+    [sender = ::QPointer<mynamespace::ExternObjectCpp>(&self),
+     closure = ::std::move(closure)]() mutable {
+      if (sender) {
+        closure.template operator()<mynamespace::ExternObjectCpp&>(*sender);
+      }
+    },
+    type);
+}
+} // namespace mynamespace::rust::cxxqtgen1::ffi
 
 // Define namespace otherwise we hit a GCC bug
 // https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
@@ -225,6 +297,29 @@ MyObject_propertyNameChangedConnect(
 }
 } // namespace cxx_qt::multi_object::rust::cxxqtgen1
 
+namespace cxx_qt::multi_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_propertyNameChangedConnectWithContext(
+  cxx_qt::multi_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::multi_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalHandlerpropertyNameChanged closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::multi_object::MyObject::propertyNameChanged,
+    &context,
+    [sender = ::QPointer<cxx_qt::multi_object::MyObject>(&self),
+     closure = ::std::move(closure)]() mutable {
+      if (sender) {
+        closure.template operator()<cxx_qt::multi_object::MyObject&>(*sender);
+      }
+    },
+    type);
+}
+} // namespace cxx_qt::multi_object::rust::cxxqtgen1
+
 // Define namespace otherwise we hit a GCC bug
 // https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
 namespace rust::cxxqt1 {
@@ -279,6 +374,29 @@ MyObject_readyConnect(
 }
 } // namespace cxx_qt::multi_object::rust::cxxqtgen1
 
+namespace cxx_qt::multi_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_readyConnectWithContext(
+  cxx_qt::multi_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::multi_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerready
+    closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::multi_object::MyObject::ready,
+    &context,
+    [sender = ::QPointer<cxx_qt::multi_object::MyObject>(&self),
+     closure = ::std::move(closure)]() mutable {
+      if (sender) {
+        closure.template operator()<cxx_qt::multi_object::MyObject&>(*sender);
+      }
+    },
+    type);
+}
+} // namespace cxx_qt::multi_object::rust::cxxqtgen1
+
 namespace cxx_qt::multi_object {
 MyObject::MyObject(QObject* parent)
   : QStringListModel(parent)
@@ -345,6 +463,29 @@ SecondObject_propertyNameChangedConnect(
 }
 } // namespace second_object::rust::cxxqtgen1
 
+namespace second_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+SecondObject_propertyNameChangedConnectWithContext(
+  second_object::SecondObject& self,
+  const ::QObject& context,
+  ::second_object::rust::cxxqtgen1::
+    SecondObjectCxxQtSignalHandlerpropertyNameChanged closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &second_object::SecondObject::propertyNameChanged,
+    &context,
+    [sender = ::QPointer<second_object::SecondObject>(&self),
+     closure = ::std::move(closure)]() mutable {
+      if (sender) {
+        closure.template operator()<second_object::SecondObject&>(*sender);
+      }
+    },
+    type);
+}
+} // namespace second_object::rust::cxxqtgen1
+
 // Define namespace otherwise we hit a GCC bug
 // https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
 namespace rust::cxxqt1 {
@@ -401,6 +542,28 @@ SecondObject_readyConnect(
 }
 } // namespace second_object::rust::cxxqtgen1
 
+namespace second_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+SecondObject_readyConnectWithContext(
+  second_object::SecondObject& self,
+  const ::QObject& context,
+  ::second_object::rust::cxxqtgen1::SecondObjectCxxQtSignalHandlerready closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &second_object::SecondObject::ready,
+    &context,
+    [sender = ::QPointer<second_object::SecondObject>(&self),
+     closure = ::std::move(closure)]() mutable {
+      if (sender) {
+        closure.template operator()<second_object::SecondObject&>(*sender);
+      }
+    },
+    type);
+}
+} // namespace second_object::rust::cxxqtgen1
+
 namespace second_object {
 SecondObject::SecondObject(QObject* parent)
   : QObject(parent)
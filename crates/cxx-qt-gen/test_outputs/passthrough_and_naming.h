@@ -1,5 +1,6 @@
 #pragma once
 
+#include <QtCore/QPointer>
 #include <cxx-qt/signalhandler.h>
 #include <cxx-qt/type.h>
 
@@ -40,50 +41,80 @@ class MyCxxName;
 
 } // namespace my_namespace
 
-namespace cxx_qt::multi_object::rust::cxxqtgen1 {
+namespace cxx_qt::multi_object::rust::cxxqtgen1::ffi {
 using QPushButtonCxxQtSignalHandlerclicked =
   ::rust::cxxqt1::SignalHandler<struct QPushButtonCxxQtSignalParamsclicked*>;
-} // namespace cxx_qt::multi_object::rust::cxxqtgen1
+} // namespace cxx_qt::multi_object::rust::cxxqtgen1::ffi
 
-namespace mynamespace::rust::cxxqtgen1 {
+namespace mynamespace::rust::cxxqtgen1::ffi {
 using ExternObjectCxxQtSignalHandlerdataReady =
   ::rust::cxxqt1::SignalHandler<struct ExternObjectCxxQtSignalParamsdataReady*>;
-} // namespace mynamespace::rust::cxxqtgen1
+} // namespace mynamespace::rust::cxxqtgen1::ffi
 
-namespace mynamespace::rust::cxxqtgen1 {
+namespace mynamespace::rust::cxxqtgen1::ffi {
 using ExternObjectCxxQtSignalHandlererrorOccurred =
   ::rust::cxxqt1::SignalHandler<
     struct ExternObjectCxxQtSignalParamserrorOccurred*>;
-} // namespace mynamespace::rust::cxxqtgen1
+} // namespace mynamespace::rust::cxxqtgen1::ffi
 
 #include "directory/file_ident.cxx.h"
 
-namespace cxx_qt::multi_object::rust::cxxqtgen1 {
+namespace cxx_qt::multi_object::rust::cxxqtgen1::ffi {
 ::QMetaObject::Connection
 QPushButton_clickedConnect(
   cxx_qt::multi_object::QPushButton& self,
-  ::cxx_qt::multi_object::rust::cxxqtgen1::QPushButtonCxxQtSignalHandlerclicked
-    closure,
+  ::cxx_qt::multi_object::rust::cxxqtgen1::ffi::
+    QPushButtonCxxQtSignalHandlerclicked closure,
   ::Qt::ConnectionType type);
-} // namespace cxx_qt::multi_object::rust::cxxqtgen1
+} // namespace cxx_qt::multi_object::rust::cxxqtgen1::ffi
+
+namespace cxx_qt::multi_object::rust::cxxqtgen1::ffi {
+::QMetaObject::Connection
+QPushButton_clickedConnectWithContext(
+  cxx_qt::multi_object::QPushButton& self,
+  const ::QObject& context,
+  ::cxx_qt::multi_object::rust::cxxqtgen1::ffi::
+    QPushButtonCxxQtSignalHandlerclicked closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::multi_object::rust::cxxqtgen1::ffi
 
-namespace mynamespace::rust::cxxqtgen1 {
+namespace mynamespace::rust::cxxqtgen1::ffi {
 ::QMetaObject::Connection
 ExternObjectCpp_dataReadyConnect(
   mynamespace::ExternObjectCpp& self,
-  ::mynamespace::rust::cxxqtgen1::ExternObjectCxxQtSignalHandlerdataReady
+  ::mynamespace::rust::cxxqtgen1::ffi::ExternObjectCxxQtSignalHandlerdataReady
     closure,
   ::Qt::ConnectionType type);
-} // namespace mynamespace::rust::cxxqtgen1
+} // namespace mynamespace::rust::cxxqtgen1::ffi
 
-namespace mynamespace::rust::cxxqtgen1 {
+namespace mynamespace::rust::cxxqtgen1::ffi {
 ::QMetaObject::Connection
-ExternObjectCpp_errorOccurredConnect(
+ExternObjectCpp_dataReadyConnectWithContext(
   mynamespace::ExternObjectCpp& self,
-  ::mynamespace::rust::cxxqtgen1::ExternObjectCxxQtSignalHandlererrorOccurred
+  const ::QObject& context,
+  ::mynamespace::rust::cxxqtgen1::ffi::ExternObjectCxxQtSignalHandlerdataReady
     closure,
   ::Qt::ConnectionType type);
-} // namespace mynamespace::rust::cxxqtgen1
+} // namespace mynamespace::rust::cxxqtgen1::ffi
+
+namespace mynamespace::rust::cxxqtgen1::ffi {
+::QMetaObject::Connection
+ExternObjectCpp_errorOccurredConnect(
+  mynamespace::ExternObjectCpp& self,
+  ::mynamespace::rust::cxxqtgen1::ffi::
+    ExternObjectCxxQtSignalHandlererrorOccurred closure,
+  ::Qt::ConnectionType type);
+} // namespace mynamespace::rust::cxxqtgen1::ffi
+
+namespace mynamespace::rust::cxxqtgen1::ffi {
+::QMetaObject::Connection
+ExternObjectCpp_errorOccurredConnectWithContext(
+  mynamespace::ExternObjectCpp& self,
+  const ::QObject& context,
+  ::mynamespace::rust::cxxqtgen1::ffi::
+    ExternObjectCxxQtSignalHandlererrorOccurred closure,
+  ::Qt::ConnectionType type);
+} // namespace mynamespace::rust::cxxqtgen1::ffi
 
 namespace cxx_qt::multi_object::rust::cxxqtgen1 {
 ::QMetaObject::Connection
@@ -94,6 +125,16 @@ MyObject_propertyNameChangedConnect(
   ::Qt::ConnectionType type);
 } // namespace cxx_qt::multi_object::rust::cxxqtgen1
 
+namespace cxx_qt::multi_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_propertyNameChangedConnectWithContext(
+  cxx_qt::multi_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::multi_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalHandlerpropertyNameChanged closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::multi_object::rust::cxxqtgen1
+
 namespace cxx_qt::multi_object::rust::cxxqtgen1 {
 ::QMetaObject::Connection
 MyObject_readyConnect(
@@ -103,6 +144,21 @@ MyObject_readyConnect(
   ::Qt::ConnectionType type);
 } // namespace cxx_qt::multi_object::rust::cxxqtgen1
 
+namespace cxx_qt::multi_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_readyConnectWithContext(
+  cxx_qt::multi_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::multi_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerready
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::multi_object::rust::cxxqtgen1
+
+namespace cxx_qt::multi_object::cxx_qt_my_object {
+[[maybe_unused]] static const bool cxxQtSignatureCheck =
+  (cxxQtSignature73741f6beedf6970(), true);
+} // namespace cxx_qt::multi_object::cxx_qt_my_object
+
 namespace cxx_qt::multi_object {
 class MyObject
   : public QStringListModel
@@ -118,6 +174,7 @@ public:
 public:
   ::std::int32_t const& getPropertyName() const noexcept;
   Q_SLOT void setPropertyName(::std::int32_t value) noexcept;
+  /// Notify for the Q_PROPERTY
   Q_SIGNAL void propertyNameChanged();
   Q_INVOKABLE void invokableName() noexcept;
   Q_SIGNAL void ready();
@@ -139,6 +196,16 @@ SecondObject_propertyNameChangedConnect(
   ::Qt::ConnectionType type);
 } // namespace second_object::rust::cxxqtgen1
 
+namespace second_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+SecondObject_propertyNameChangedConnectWithContext(
+  second_object::SecondObject& self,
+  const ::QObject& context,
+  ::second_object::rust::cxxqtgen1::
+    SecondObjectCxxQtSignalHandlerpropertyNameChanged closure,
+  ::Qt::ConnectionType type);
+} // namespace second_object::rust::cxxqtgen1
+
 namespace second_object::rust::cxxqtgen1 {
 ::QMetaObject::Connection
 SecondObject_readyConnect(
@@ -147,6 +214,20 @@ SecondObject_readyConnect(
   ::Qt::ConnectionType type);
 } // namespace second_object::rust::cxxqtgen1
 
+namespace second_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+SecondObject_readyConnectWithContext(
+  second_object::SecondObject& self,
+  const ::QObject& context,
+  ::second_object::rust::cxxqtgen1::SecondObjectCxxQtSignalHandlerready closure,
+  ::Qt::ConnectionType type);
+} // namespace second_object::rust::cxxqtgen1
+
+namespace second_object::cxx_qt_second_object {
+[[maybe_unused]] static const bool cxxQtSignatureCheck =
+  (cxxQtSignature120e680902b62aff(), true);
+} // namespace second_object::cxx_qt_second_object
+
 namespace second_object {
 class SecondObject
   : public QObject
@@ -162,6 +243,7 @@ public:
 public:
   ::std::int32_t const& getPropertyName() const noexcept;
   Q_SLOT void setPropertyName(::std::int32_t value) noexcept;
+  /// Notify for the Q_PROPERTY
   Q_SIGNAL void propertyNameChanged();
   Q_INVOKABLE void invokableName() noexcept;
   Q_SIGNAL void ready();
@@ -174,6 +256,11 @@ static_assert(::std::is_base_of<QObject, SecondObject>::value,
 
 Q_DECLARE_METATYPE(second_object::SecondObject*)
 
+namespace my_namespace::cxx_qt_my_rust_name {
+[[maybe_unused]] static const bool cxxQtSignatureCheck =
+  (cxxQtSignature9640134fc72f7fb4(), true);
+} // namespace my_namespace::cxx_qt_my_rust_name
+
 namespace my_namespace {
 class MyCxxName
   : public QObject
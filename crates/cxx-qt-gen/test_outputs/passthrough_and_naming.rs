@@ -67,6 +67,11 @@ pub mod ffi {
         #[rust_name = "CxxQtQMetaObjectConnection"]
         #[allow(dead_code)]
         type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        #[allow(dead_code)]
+        type QObject = cxx_qt::QObject;
     }
     unsafe extern "C++" {
         include!("directory/file_ident.cxxqt.h");
@@ -81,6 +86,33 @@ pub mod ffi {
         #[namespace = "cxx_qt::multi_object"]
         type MyObject;
     }
+    unsafe extern "C++" {
+        include!("cxx-qt/upcast.h");
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_upcast_ptr_q_string_list_model(
+            thiz: *const MyObject,
+        ) -> *const QStringListModel;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_downcast_ptr_q_string_list_model(
+            base: *const QStringListModel,
+        ) -> *const MyObject;
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_upcast_ptr_qobject(
+            thiz: *const MyObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_downcast_ptr_qobject(
+            base: *const CxxQtQObject,
+        ) -> *const MyObject;
+    }
     extern "Rust" {
         #[namespace = "cxx_qt::multi_object"]
         type MyObjectRust;
@@ -116,6 +148,15 @@ pub mod ffi {
             signal_handler: MyObjectCxxQtSignalHandlerpropertyNameChanged,
             conn_type: CxxQtConnectionType,
         ) -> CxxQtQMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::multi_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_propertyNameChangedConnectWithContext"]
+        fn MyObject_connect_property_name_changed_with_context(
+            self_value: Pin<&mut MyObject>,
+            context: &CxxQtQObject,
+            signal_handler: MyObjectCxxQtSignalHandlerpropertyNameChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
     }
     #[namespace = "cxx_qt::multi_object::rust::cxxqtgen1"]
     extern "Rust" {
@@ -153,6 +194,15 @@ pub mod ffi {
             signal_handler: MyObjectCxxQtSignalHandlerready,
             conn_type: CxxQtConnectionType,
         ) -> CxxQtQMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::multi_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_readyConnectWithContext"]
+        fn MyObject_connect_ready_with_context(
+            self_value: Pin<&mut MyObject>,
+            context: &CxxQtQObject,
+            signal_handler: MyObjectCxxQtSignalHandlerready,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
     }
     #[namespace = "cxx_qt::multi_object::rust::cxxqtgen1"]
     extern "Rust" {
@@ -183,6 +233,12 @@ pub mod ffi {
             outer: Pin<&mut MyObject>,
         ) -> Pin<&mut MyObjectRust>;
     }
+    extern "Rust" {
+        #[cxx_name = "cxxQtSignature73741f6beedf6970"]
+        #[namespace = "cxx_qt::multi_object::cxx_qt_my_object"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_signature_73741f6beedf6970();
+    }
     unsafe extern "C++" {
         #[doc = "The C++ type for the QObject "]
         #[doc = "SecondObjectRust"]
@@ -193,6 +249,21 @@ pub mod ffi {
         #[namespace = "second_object"]
         type SecondObject;
     }
+    unsafe extern "C++" {
+        include!("cxx-qt/upcast.h");
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_second_object_upcast_ptr_qobject(
+            thiz: *const SecondObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_second_object_downcast_ptr_qobject(
+            base: *const CxxQtQObject,
+        ) -> *const SecondObject;
+    }
     extern "Rust" {
         #[namespace = "second_object"]
         type SecondObjectRust;
@@ -228,6 +299,15 @@ pub mod ffi {
             signal_handler: SecondObjectCxxQtSignalHandlerpropertyNameChanged,
             conn_type: CxxQtConnectionType,
         ) -> CxxQtQMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = "second_object::rust::cxxqtgen1"]
+        #[cxx_name = "SecondObject_propertyNameChangedConnectWithContext"]
+        fn SecondObject_connect_property_name_changed_with_context(
+            self_value: Pin<&mut SecondObject>,
+            context: &CxxQtQObject,
+            signal_handler: SecondObjectCxxQtSignalHandlerpropertyNameChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
     }
     #[namespace = "second_object::rust::cxxqtgen1"]
     extern "Rust" {
@@ -265,6 +345,15 @@ pub mod ffi {
             signal_handler: SecondObjectCxxQtSignalHandlerready,
             conn_type: CxxQtConnectionType,
         ) -> CxxQtQMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = "second_object::rust::cxxqtgen1"]
+        #[cxx_name = "SecondObject_readyConnectWithContext"]
+        fn SecondObject_connect_ready_with_context(
+            self_value: Pin<&mut SecondObject>,
+            context: &CxxQtQObject,
+            signal_handler: SecondObjectCxxQtSignalHandlerready,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
     }
     #[namespace = "second_object::rust::cxxqtgen1"]
     extern "Rust" {
@@ -295,6 +384,12 @@ pub mod ffi {
             outer: Pin<&mut SecondObject>,
         ) -> Pin<&mut SecondObjectRust>;
     }
+    extern "Rust" {
+        #[cxx_name = "cxxQtSignature120e680902b62aff"]
+        #[namespace = "second_object::cxx_qt_second_object"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_second_object_signature_120e680902b62aff();
+    }
     unsafe extern "C++" {
         #[doc = "The C++ type for the QObject "]
         #[doc = "ThirdObjectRust"]
@@ -308,6 +403,21 @@ pub mod ffi {
         #[cxx_name = "MyCxxName"]
         type MyRustName;
     }
+    unsafe extern "C++" {
+        include!("cxx-qt/upcast.h");
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_cxx_name_upcast_ptr_qobject(
+            thiz: *const MyRustName,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_cxx_name_downcast_ptr_qobject(
+            base: *const CxxQtQObject,
+        ) -> *const MyRustName;
+    }
     extern "Rust" {
         #[namespace = "my_namespace"]
         type ThirdObjectRust;
@@ -331,6 +441,12 @@ pub mod ffi {
             outer: Pin<&mut MyRustName>,
         ) -> Pin<&mut ThirdObjectRust>;
     }
+    extern "Rust" {
+        #[cxx_name = "cxxQtSignature9640134fc72f7fb4"]
+        #[namespace = "my_namespace::cxx_qt_my_rust_name"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_rust_name_signature_9640134fc72f7fb4();
+    }
     #[namespace = ""]
     unsafe extern "C++" {
         #[namespace = "cxx_qt::multi_object"]
@@ -346,19 +462,28 @@ pub mod ffi {
     }
     unsafe extern "C++" {
         #[doc(hidden)]
-        #[namespace = "cxx_qt::multi_object::rust::cxxqtgen1"]
+        #[namespace = "cxx_qt::multi_object::rust::cxxqtgen1::ffi"]
         type QPushButtonCxxQtSignalHandlerclicked =
             cxx_qt::signalhandler::CxxQtSignalHandler<super::QPushButtonCxxQtSignalClosureclicked>;
         #[doc(hidden)]
-        #[namespace = "cxx_qt::multi_object::rust::cxxqtgen1"]
+        #[namespace = "cxx_qt::multi_object::rust::cxxqtgen1::ffi"]
         #[cxx_name = "QPushButton_clickedConnect"]
         fn QPushButton_connect_clicked(
             self_value: Pin<&mut QPushButton>,
             signal_handler: QPushButtonCxxQtSignalHandlerclicked,
             conn_type: CxxQtConnectionType,
         ) -> CxxQtQMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::multi_object::rust::cxxqtgen1::ffi"]
+        #[cxx_name = "QPushButton_clickedConnectWithContext"]
+        fn QPushButton_connect_clicked_with_context(
+            self_value: Pin<&mut QPushButton>,
+            context: &CxxQtQObject,
+            signal_handler: QPushButtonCxxQtSignalHandlerclicked,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
     }
-    #[namespace = "cxx_qt::multi_object::rust::cxxqtgen1"]
+    #[namespace = "cxx_qt::multi_object::rust::cxxqtgen1::ffi"]
     extern "Rust" {
         #[doc(hidden)]
         fn drop_QPushButton_signal_handler_clicked(handler: QPushButtonCxxQtSignalHandlerclicked);
@@ -376,20 +501,29 @@ pub mod ffi {
     }
     unsafe extern "C++" {
         #[doc(hidden)]
-        #[namespace = "mynamespace::rust::cxxqtgen1"]
+        #[namespace = "mynamespace::rust::cxxqtgen1::ffi"]
         type ExternObjectCxxQtSignalHandlerdataReady = cxx_qt::signalhandler::CxxQtSignalHandler<
             super::ExternObjectCxxQtSignalClosuredataReady,
         >;
         #[doc(hidden)]
-        #[namespace = "mynamespace::rust::cxxqtgen1"]
+        #[namespace = "mynamespace::rust::cxxqtgen1::ffi"]
         #[cxx_name = "ExternObjectCpp_dataReadyConnect"]
         fn ExternObject_connect_data_ready(
             self_value: Pin<&mut ExternObject>,
             signal_handler: ExternObjectCxxQtSignalHandlerdataReady,
             conn_type: CxxQtConnectionType,
         ) -> CxxQtQMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = "mynamespace::rust::cxxqtgen1::ffi"]
+        #[cxx_name = "ExternObjectCpp_dataReadyConnectWithContext"]
+        fn ExternObject_connect_data_ready_with_context(
+            self_value: Pin<&mut ExternObject>,
+            context: &CxxQtQObject,
+            signal_handler: ExternObjectCxxQtSignalHandlerdataReady,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
     }
-    #[namespace = "mynamespace::rust::cxxqtgen1"]
+    #[namespace = "mynamespace::rust::cxxqtgen1::ffi"]
     extern "Rust" {
         #[doc(hidden)]
         fn drop_ExternObject_signal_handler_dataReady(
@@ -408,21 +542,30 @@ pub mod ffi {
     }
     unsafe extern "C++" {
         #[doc(hidden)]
-        #[namespace = "mynamespace::rust::cxxqtgen1"]
+        #[namespace = "mynamespace::rust::cxxqtgen1::ffi"]
         type ExternObjectCxxQtSignalHandlererrorOccurred =
             cxx_qt::signalhandler::CxxQtSignalHandler<
                 super::ExternObjectCxxQtSignalClosureerrorOccurred,
             >;
         #[doc(hidden)]
-        #[namespace = "mynamespace::rust::cxxqtgen1"]
+        #[namespace = "mynamespace::rust::cxxqtgen1::ffi"]
         #[cxx_name = "ExternObjectCpp_errorOccurredConnect"]
         fn ExternObject_connect_error_occurred(
             self_value: Pin<&mut ExternObject>,
             signal_handler: ExternObjectCxxQtSignalHandlererrorOccurred,
             conn_type: CxxQtConnectionType,
         ) -> CxxQtQMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = "mynamespace::rust::cxxqtgen1::ffi"]
+        #[cxx_name = "ExternObjectCpp_errorOccurredConnectWithContext"]
+        fn ExternObject_connect_error_occurred_with_context(
+            self_value: Pin<&mut ExternObject>,
+            context: &CxxQtQObject,
+            signal_handler: ExternObjectCxxQtSignalHandlererrorOccurred,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
     }
-    #[namespace = "mynamespace::rust::cxxqtgen1"]
+    #[namespace = "mynamespace::rust::cxxqtgen1::ffi"]
     extern "Rust" {
         #[doc(hidden)]
         fn drop_ExternObject_signal_handler_errorOccurred(
@@ -435,10 +578,22 @@ pub mod ffi {
         );
     }
 }
-impl cxx_qt::Upcast<ffi::QStringListModel> for ffi::MyObject {}
-#[allow(unused_imports)]
-#[allow(dead_code)]
-use ffi::QStringListModel as _;
+unsafe impl cxx_qt::Upcast<ffi::QStringListModel> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ffi::QStringListModel {
+        ffi::cxx_qt_ffi_my_object_upcast_ptr_q_string_list_model(this)
+    }
+    unsafe fn from_base_ptr(base: *const ffi::QStringListModel) -> *const Self {
+        ffi::cxx_qt_ffi_my_object_downcast_ptr_q_string_list_model(base)
+    }
+}
+unsafe impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_my_object_upcast_ptr_qobject(this)
+    }
+    unsafe fn from_base_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_my_object_downcast_ptr_qobject(base)
+    }
+}
 impl ffi::MyObject {
     #[doc = "Getter for the Q_PROPERTY "]
     #[doc = "property_name"]
@@ -495,6 +650,77 @@ impl ffi::MyObject {
         ))
     }
 }
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "propertyNameChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "The connection is disconnected when the context object is destroyed."]
+    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+    pub unsafe fn connect_property_name_changed_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MyObject_connect_property_name_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MyObjectCxxQtSignalClosurepropertyNameChanged,
+                        >::new(Box::new(closure)),
+                        conn_type,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "propertyNameChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "See the safety section of the connect method with a context object."]
+    pub unsafe fn on_property_name_changed_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MyObject_connect_property_name_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MyObjectCxxQtSignalClosurepropertyNameChanged,
+                        >::new(Box::new(closure)),
+                        cxx_qt::ConnectionType::AutoConnection,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
 #[doc(hidden)]
 pub struct MyObjectCxxQtSignalClosurepropertyNameChanged {}
 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure
@@ -559,6 +785,51 @@ impl ffi::MyObject {
         ))
     }
 }
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "ready"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "The connection is disconnected when the context object is destroyed."]
+    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+    pub unsafe fn connect_ready_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (context . with_context (| context | ffi :: MyObject_connect_ready_with_context (self , context , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosureready > :: new (Box :: new (closure)) , conn_type ,)) . unwrap_or_default ())
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "ready"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "See the safety section of the connect method with a context object."]
+    pub unsafe fn on_ready_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (context . with_context (| context | ffi :: MyObject_connect_ready_with_context (self , context , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosureready > :: new (Box :: new (closure)) , cxx_qt :: ConnectionType :: AutoConnection ,)) . unwrap_or_default ())
+    }
+}
 #[doc(hidden)]
 pub struct MyObjectCxxQtSignalClosureready {}
 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosureready {
@@ -600,6 +871,16 @@ impl ::cxx_qt::CxxQtType for ffi::MyObject {
         ffi::cxx_qt_ffi_my_object_unsafe_rust_mut(self)
     }
 }
+#[doc(hidden)]
+fn cxx_qt_ffi_my_object_signature_73741f6beedf6970() {}
+unsafe impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::SecondObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_second_object_upcast_ptr_qobject(this)
+    }
+    unsafe fn from_base_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_second_object_downcast_ptr_qobject(base)
+    }
+}
 impl ffi::SecondObject {
     #[doc = "Getter for the Q_PROPERTY "]
     #[doc = "property_name"]
@@ -658,6 +939,77 @@ impl ffi::SecondObject {
         ))
     }
 }
+impl ffi::SecondObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "propertyNameChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "The connection is disconnected when the context object is destroyed."]
+    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+    pub unsafe fn connect_property_name_changed_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::SecondObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::SecondObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::SecondObject_connect_property_name_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            SecondObjectCxxQtSignalClosurepropertyNameChanged,
+                        >::new(Box::new(closure)),
+                        conn_type,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+impl ffi::SecondObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "propertyNameChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "See the safety section of the connect method with a context object."]
+    pub unsafe fn on_property_name_changed_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::SecondObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::SecondObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::SecondObject_connect_property_name_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            SecondObjectCxxQtSignalClosurepropertyNameChanged,
+                        >::new(Box::new(closure)),
+                        cxx_qt::ConnectionType::AutoConnection,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
 #[doc(hidden)]
 pub struct SecondObjectCxxQtSignalClosurepropertyNameChanged {}
 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure
@@ -722,6 +1074,77 @@ impl ffi::SecondObject {
         ))
     }
 }
+impl ffi::SecondObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "ready"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "The connection is disconnected when the context object is destroyed."]
+    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+    pub unsafe fn connect_ready_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::SecondObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::SecondObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::SecondObject_connect_ready_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            SecondObjectCxxQtSignalClosureready,
+                        >::new(Box::new(closure)),
+                        conn_type,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+impl ffi::SecondObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "ready"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "See the safety section of the connect method with a context object."]
+    pub unsafe fn on_ready_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::SecondObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::SecondObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::SecondObject_connect_ready_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            SecondObjectCxxQtSignalClosureready,
+                        >::new(Box::new(closure)),
+                        cxx_qt::ConnectionType::AutoConnection,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
 #[doc(hidden)]
 pub struct SecondObjectCxxQtSignalClosureready {}
 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for SecondObjectCxxQtSignalClosureready {
@@ -764,6 +1187,16 @@ impl ::cxx_qt::CxxQtType for ffi::SecondObject {
     }
 }
 #[doc(hidden)]
+fn cxx_qt_ffi_second_object_signature_120e680902b62aff() {}
+unsafe impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::MyRustName {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_my_cxx_name_upcast_ptr_qobject(this)
+    }
+    unsafe fn from_base_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_my_cxx_name_downcast_ptr_qobject(base)
+    }
+}
+#[doc(hidden)]
 pub fn create_rs_third_object_rust() -> std::boxed::Box<ThirdObjectRust> {
     std::boxed::Box::new(core::default::Default::default())
 }
@@ -782,6 +1215,8 @@ impl ::cxx_qt::CxxQtType for ffi::MyRustName {
         ffi::cxx_qt_ffi_my_cxx_name_unsafe_rust_mut(self)
     }
 }
+#[doc(hidden)]
+fn cxx_qt_ffi_my_rust_name_signature_9640134fc72f7fb4() {}
 impl ffi::QPushButton {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "clicked"]
@@ -819,11 +1254,82 @@ impl ffi::QPushButton {
         ))
     }
 }
+impl ffi::QPushButton {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "clicked"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "The connection is disconnected when the context object is destroyed."]
+    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+    pub unsafe fn connect_clicked_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::QPushButton>, bool) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::QPushButton>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::QPushButton_connect_clicked_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            QPushButtonCxxQtSignalClosureclicked,
+                        >::new(Box::new(closure)),
+                        conn_type,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+impl ffi::QPushButton {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "clicked"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "See the safety section of the connect method with a context object."]
+    pub unsafe fn on_clicked_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::QPushButton>, bool) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::QPushButton>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::QPushButton_connect_clicked_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            QPushButtonCxxQtSignalClosureclicked,
+                        >::new(Box::new(closure)),
+                        cxx_qt::ConnectionType::AutoConnection,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
 #[doc(hidden)]
 pub struct QPushButtonCxxQtSignalClosureclicked {}
 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for QPushButtonCxxQtSignalClosureclicked {
     type Id = cxx::type_id!(
-        "::cxx_qt::multi_object::rust::cxxqtgen1::QPushButtonCxxQtSignalHandlerclicked"
+        "::cxx_qt::multi_object::rust::cxxqtgen1::ffi::QPushButtonCxxQtSignalHandlerclicked"
     );
     type FnType = dyn FnMut(core::pin::Pin<&mut ffi::QPushButton>, bool);
 }
@@ -868,11 +1374,83 @@ impl ffi::ExternObject {
         cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: ExternObject_connect_data_ready (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < ExternObjectCxxQtSignalClosuredataReady > :: new (Box :: new (closure)) , cxx_qt :: ConnectionType :: AutoConnection ,))
     }
 }
+impl ffi::ExternObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "dataReady"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "The connection is disconnected when the context object is destroyed."]
+    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+    pub unsafe fn connect_data_ready_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::ExternObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::ExternObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::ExternObject_connect_data_ready_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            ExternObjectCxxQtSignalClosuredataReady,
+                        >::new(Box::new(closure)),
+                        conn_type,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+impl ffi::ExternObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "dataReady"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "See the safety section of the connect method with a context object."]
+    pub unsafe fn on_data_ready_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::ExternObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::ExternObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::ExternObject_connect_data_ready_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            ExternObjectCxxQtSignalClosuredataReady,
+                        >::new(Box::new(closure)),
+                        cxx_qt::ConnectionType::AutoConnection,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
 #[doc(hidden)]
 pub struct ExternObjectCxxQtSignalClosuredataReady {}
 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for ExternObjectCxxQtSignalClosuredataReady {
-    type Id =
-        cxx::type_id!("::mynamespace::rust::cxxqtgen1::ExternObjectCxxQtSignalHandlerdataReady");
+    type Id = cxx::type_id!(
+        "::mynamespace::rust::cxxqtgen1::ffi::ExternObjectCxxQtSignalHandlerdataReady"
+    );
     type FnType = dyn FnMut(core::pin::Pin<&mut ffi::ExternObject>);
 }
 use core::mem::drop as drop_ExternObject_signal_handler_dataReady;
@@ -933,13 +1511,84 @@ impl ffi::ExternObject {
         )
     }
 }
+impl ffi::ExternObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "errorOccurred"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "The connection is disconnected when the context object is destroyed."]
+    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+    pub unsafe fn connect_error_occurred_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::ExternObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::ExternObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::ExternObject_connect_error_occurred_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            ExternObjectCxxQtSignalClosureerrorOccurred,
+                        >::new(Box::new(closure)),
+                        conn_type,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+impl ffi::ExternObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "errorOccurred"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "See the safety section of the connect method with a context object."]
+    pub unsafe fn on_error_occurred_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::ExternObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::ExternObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::ExternObject_connect_error_occurred_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            ExternObjectCxxQtSignalClosureerrorOccurred,
+                        >::new(Box::new(closure)),
+                        cxx_qt::ConnectionType::AutoConnection,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
 #[doc(hidden)]
 pub struct ExternObjectCxxQtSignalClosureerrorOccurred {}
 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure
     for ExternObjectCxxQtSignalClosureerrorOccurred
 {
     type Id = cxx::type_id!(
-        "::mynamespace::rust::cxxqtgen1::ExternObjectCxxQtSignalHandlererrorOccurred"
+        "::mynamespace::rust::cxxqtgen1::ffi::ExternObjectCxxQtSignalHandlererrorOccurred"
     );
     type FnType = dyn FnMut(core::pin::Pin<&mut ffi::ExternObject>);
 }
@@ -56,6 +56,29 @@ MyObject_primitiveChangedConnect(
 }
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_primitiveChangedConnectWithContext(
+  cxx_qt::my_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalHandlerprimitiveChanged closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::my_object::MyObject::primitiveChanged,
+    &context,
+    [sender = ::QPointer<cxx_qt::my_object::MyObject>(&self),
+     closure = ::std::move(closure)]() mutable {
+      if (sender) {
+        closure.template operator()<cxx_qt::my_object::MyObject&>(*sender);
+      }
+    },
+    type);
+}
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 // Define namespace otherwise we hit a GCC bug
 // https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
 namespace rust::cxxqt1 {
@@ -112,6 +135,29 @@ MyObject_trivialChangedConnect(
 }
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_trivialChangedConnectWithContext(
+  cxx_qt::my_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlertrivialChanged
+    closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::my_object::MyObject::trivialChanged,
+    &context,
+    [sender = ::QPointer<cxx_qt::my_object::MyObject>(&self),
+     closure = ::std::move(closure)]() mutable {
+      if (sender) {
+        closure.template operator()<cxx_qt::my_object::MyObject&>(*sender);
+      }
+    },
+    type);
+}
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 // Define namespace otherwise we hit a GCC bug
 // https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
 namespace rust::cxxqt1 {
@@ -170,6 +216,29 @@ MyObject_customFunctionPropChangedConnect(
 }
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_customFunctionPropChangedConnectWithContext(
+  cxx_qt::my_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalHandlercustomFunctionPropChanged closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::my_object::MyObject::customFunctionPropChanged,
+    &context,
+    [sender = ::QPointer<cxx_qt::my_object::MyObject>(&self),
+     closure = ::std::move(closure)]() mutable {
+      if (sender) {
+        closure.template operator()<cxx_qt::my_object::MyObject&>(*sender);
+      }
+    },
+    type);
+}
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 // Define namespace otherwise we hit a GCC bug
 // https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
 namespace rust::cxxqt1 {
@@ -226,6 +295,29 @@ MyObject_renamedPropertyChangedConnect(
 }
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_renamedPropertyChangedConnectWithContext(
+  cxx_qt::my_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalHandlerrenamedPropertyChanged closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::my_object::MyObject::renamedPropertyChanged,
+    &context,
+    [sender = ::QPointer<cxx_qt::my_object::MyObject>(&self),
+     closure = ::std::move(closure)]() mutable {
+      if (sender) {
+        closure.template operator()<cxx_qt::my_object::MyObject&>(*sender);
+      }
+    },
+    type);
+}
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 // Define namespace otherwise we hit a GCC bug
 // https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
 namespace rust::cxxqt1 {
@@ -282,6 +374,29 @@ MyObject_named_prop_2ChangedConnect(
 }
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_named_prop_2ChangedConnectWithContext(
+  cxx_qt::my_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalHandlernamed_prop_2Changed closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::my_object::MyObject::named_prop_2Changed,
+    &context,
+    [sender = ::QPointer<cxx_qt::my_object::MyObject>(&self),
+     closure = ::std::move(closure)]() mutable {
+      if (sender) {
+        closure.template operator()<cxx_qt::my_object::MyObject&>(*sender);
+      }
+    },
+    type);
+}
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 // Define namespace otherwise we hit a GCC bug
 // https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
 namespace rust::cxxqt1 {
@@ -336,6 +451,29 @@ MyObject_myOnChangedConnect(
 }
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_myOnChangedConnectWithContext(
+  cxx_qt::my_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlermyOnChanged
+    closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::my_object::MyObject::myOnChanged,
+    &context,
+    [sender = ::QPointer<cxx_qt::my_object::MyObject>(&self),
+     closure = ::std::move(closure)]() mutable {
+      if (sender) {
+        closure.template operator()<cxx_qt::my_object::MyObject&>(*sender);
+      }
+    },
+    type);
+}
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 namespace cxx_qt::my_object {
 MyObject::MyObject(QObject* parent)
   : QObject(parent)
@@ -1,5 +1,6 @@
 #pragma once
 
+#include <QtCore/QPointer>
 #include <cxx-qt/signalhandler.h>
 #include <cxx-qt/type.h>
 
@@ -53,6 +54,16 @@ MyObject_primitiveChangedConnect(
   ::Qt::ConnectionType type);
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_primitiveChangedConnectWithContext(
+  cxx_qt::my_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalHandlerprimitiveChanged closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 namespace cxx_qt::my_object::rust::cxxqtgen1 {
 ::QMetaObject::Connection
 MyObject_trivialChangedConnect(
@@ -62,6 +73,16 @@ MyObject_trivialChangedConnect(
   ::Qt::ConnectionType type);
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_trivialChangedConnectWithContext(
+  cxx_qt::my_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlertrivialChanged
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 namespace cxx_qt::my_object::rust::cxxqtgen1 {
 ::QMetaObject::Connection
 MyObject_customFunctionPropChangedConnect(
@@ -71,6 +92,16 @@ MyObject_customFunctionPropChangedConnect(
   ::Qt::ConnectionType type);
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_customFunctionPropChangedConnectWithContext(
+  cxx_qt::my_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalHandlercustomFunctionPropChanged closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 namespace cxx_qt::my_object::rust::cxxqtgen1 {
 ::QMetaObject::Connection
 MyObject_renamedPropertyChangedConnect(
@@ -80,6 +111,16 @@ MyObject_renamedPropertyChangedConnect(
   ::Qt::ConnectionType type);
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_renamedPropertyChangedConnectWithContext(
+  cxx_qt::my_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalHandlerrenamedPropertyChanged closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 namespace cxx_qt::my_object::rust::cxxqtgen1 {
 ::QMetaObject::Connection
 MyObject_named_prop_2ChangedConnect(
@@ -89,6 +130,16 @@ MyObject_named_prop_2ChangedConnect(
   ::Qt::ConnectionType type);
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_named_prop_2ChangedConnectWithContext(
+  cxx_qt::my_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MyObjectCxxQtSignalHandlernamed_prop_2Changed closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 namespace cxx_qt::my_object::rust::cxxqtgen1 {
 ::QMetaObject::Connection
 MyObject_myOnChangedConnect(
@@ -98,6 +149,21 @@ MyObject_myOnChangedConnect(
   ::Qt::ConnectionType type);
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_myOnChangedConnectWithContext(
+  cxx_qt::my_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlermyOnChanged
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object::cxx_qt_my_object {
+[[maybe_unused]] static const bool cxxQtSignatureCheck =
+  (cxxQtSignature4747433c49611a45(), true);
+} // namespace cxx_qt::my_object::cxx_qt_my_object
+
 namespace cxx_qt::my_object {
 class MyObject
   : public QObject
@@ -147,10 +213,15 @@ public:
   Q_SLOT void setRequiredProp(::std::int32_t value) noexcept;
   ::std::int32_t const& getFinalProp() const noexcept;
   Q_SLOT void setFinalProp(::std::int32_t value) noexcept;
+  /// Notify for the Q_PROPERTY
   Q_SIGNAL void primitiveChanged();
+  /// Notify for the Q_PROPERTY
   Q_SIGNAL void trivialChanged();
+  /// Notify for the Q_PROPERTY
   Q_SIGNAL void customFunctionPropChanged();
+  /// Notify for the Q_PROPERTY
   Q_SIGNAL void renamedPropertyChanged();
+  /// Notify for the Q_PROPERTY
   Q_SIGNAL void named_prop_2Changed();
   ::std::int32_t myGetter() const noexcept;
   void MyCustomSetter(::std::int32_t value) noexcept;
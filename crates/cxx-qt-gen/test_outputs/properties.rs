@@ -18,6 +18,11 @@ mod ffi {
         #[rust_name = "CxxQtQMetaObjectConnection"]
         #[allow(dead_code)]
         type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        #[allow(dead_code)]
+        type QObject = cxx_qt::QObject;
     }
     unsafe extern "C++" {
         include!("directory/file_ident.cxxqt.h");
@@ -32,6 +37,21 @@ mod ffi {
         #[namespace = "cxx_qt::my_object"]
         type MyObject;
     }
+    unsafe extern "C++" {
+        include!("cxx-qt/upcast.h");
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_upcast_ptr_qobject(
+            thiz: *const MyObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_downcast_ptr_qobject(
+            base: *const CxxQtQObject,
+        ) -> *const MyObject;
+    }
     extern "Rust" {
         #[namespace = "cxx_qt::my_object"]
         type MyObjectRust;
@@ -146,6 +166,15 @@ mod ffi {
             signal_handler: MyObjectCxxQtSignalHandlerprimitiveChanged,
             conn_type: CxxQtConnectionType,
         ) -> CxxQtQMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_primitiveChangedConnectWithContext"]
+        fn MyObject_connect_primitive_changed_with_context(
+            self_value: Pin<&mut MyObject>,
+            context: &CxxQtQObject,
+            signal_handler: MyObjectCxxQtSignalHandlerprimitiveChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
     }
     #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
     extern "Rust" {
@@ -179,6 +208,15 @@ mod ffi {
             signal_handler: MyObjectCxxQtSignalHandlertrivialChanged,
             conn_type: CxxQtConnectionType,
         ) -> CxxQtQMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_trivialChangedConnectWithContext"]
+        fn MyObject_connect_trivial_changed_with_context(
+            self_value: Pin<&mut MyObject>,
+            context: &CxxQtQObject,
+            signal_handler: MyObjectCxxQtSignalHandlertrivialChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
     }
     #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
     extern "Rust" {
@@ -213,6 +251,15 @@ mod ffi {
             signal_handler: MyObjectCxxQtSignalHandlercustomFunctionPropChanged,
             conn_type: CxxQtConnectionType,
         ) -> CxxQtQMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_customFunctionPropChangedConnectWithContext"]
+        fn MyObject_connect_custom_function_prop_changed_with_context(
+            self_value: Pin<&mut MyObject>,
+            context: &CxxQtQObject,
+            signal_handler: MyObjectCxxQtSignalHandlercustomFunctionPropChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
     }
     #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
     extern "Rust" {
@@ -247,6 +294,15 @@ mod ffi {
             signal_handler: MyObjectCxxQtSignalHandlerrenamedPropertyChanged,
             conn_type: CxxQtConnectionType,
         ) -> CxxQtQMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_renamedPropertyChangedConnectWithContext"]
+        fn MyObject_connect_renamed_property_changed_with_context(
+            self_value: Pin<&mut MyObject>,
+            context: &CxxQtQObject,
+            signal_handler: MyObjectCxxQtSignalHandlerrenamedPropertyChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
     }
     #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
     extern "Rust" {
@@ -281,6 +337,15 @@ mod ffi {
             signal_handler: MyObjectCxxQtSignalHandlernamed_prop_2Changed,
             conn_type: CxxQtConnectionType,
         ) -> CxxQtQMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_named_prop_2ChangedConnectWithContext"]
+        fn MyObject_connect_renamed_property_2_changed_with_context(
+            self_value: Pin<&mut MyObject>,
+            context: &CxxQtQObject,
+            signal_handler: MyObjectCxxQtSignalHandlernamed_prop_2Changed,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
     }
     #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
     extern "Rust" {
@@ -330,6 +395,15 @@ mod ffi {
             signal_handler: MyObjectCxxQtSignalHandlermyOnChanged,
             conn_type: CxxQtConnectionType,
         ) -> CxxQtQMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_myOnChangedConnectWithContext"]
+        fn MyObject_connect_my_on_changed_with_context(
+            self_value: Pin<&mut MyObject>,
+            context: &CxxQtQObject,
+            signal_handler: MyObjectCxxQtSignalHandlermyOnChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
     }
     #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
     extern "Rust" {
@@ -360,6 +434,20 @@ mod ffi {
             outer: Pin<&mut MyObject>,
         ) -> Pin<&mut MyObjectRust>;
     }
+    extern "Rust" {
+        #[cxx_name = "cxxQtSignature4747433c49611a45"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_signature_4747433c49611a45();
+    }
+}
+unsafe impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_my_object_upcast_ptr_qobject(this)
+    }
+    unsafe fn from_base_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_my_object_downcast_ptr_qobject(base)
+    }
 }
 impl ffi::MyObject {
     #[doc = "Getter for the Q_PROPERTY "]
@@ -565,6 +653,77 @@ impl ffi::MyObject {
         )
     }
 }
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "primitiveChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "The connection is disconnected when the context object is destroyed."]
+    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+    pub unsafe fn connect_primitive_changed_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MyObject_connect_primitive_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MyObjectCxxQtSignalClosureprimitiveChanged,
+                        >::new(Box::new(closure)),
+                        conn_type,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "primitiveChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "See the safety section of the connect method with a context object."]
+    pub unsafe fn on_primitive_changed_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MyObject_connect_primitive_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MyObjectCxxQtSignalClosureprimitiveChanged,
+                        >::new(Box::new(closure)),
+                        cxx_qt::ConnectionType::AutoConnection,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
 #[doc(hidden)]
 pub struct MyObjectCxxQtSignalClosureprimitiveChanged {}
 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure
@@ -617,6 +776,77 @@ impl ffi::MyObject {
         cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: MyObject_connect_trivial_changed (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosuretrivialChanged > :: new (Box :: new (closure)) , cxx_qt :: ConnectionType :: AutoConnection ,))
     }
 }
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "trivialChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "The connection is disconnected when the context object is destroyed."]
+    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+    pub unsafe fn connect_trivial_changed_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MyObject_connect_trivial_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MyObjectCxxQtSignalClosuretrivialChanged,
+                        >::new(Box::new(closure)),
+                        conn_type,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "trivialChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "See the safety section of the connect method with a context object."]
+    pub unsafe fn on_trivial_changed_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MyObject_connect_trivial_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MyObjectCxxQtSignalClosuretrivialChanged,
+                        >::new(Box::new(closure)),
+                        cxx_qt::ConnectionType::AutoConnection,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
 #[doc(hidden)]
 pub struct MyObjectCxxQtSignalClosuretrivialChanged {}
 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosuretrivialChanged {
@@ -687,6 +917,77 @@ impl ffi::MyObject {
         )
     }
 }
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "customFunctionPropChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "The connection is disconnected when the context object is destroyed."]
+    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+    pub unsafe fn connect_custom_function_prop_changed_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MyObject_connect_custom_function_prop_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MyObjectCxxQtSignalClosurecustomFunctionPropChanged,
+                        >::new(Box::new(closure)),
+                        conn_type,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "customFunctionPropChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "See the safety section of the connect method with a context object."]
+    pub unsafe fn on_custom_function_prop_changed_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MyObject_connect_custom_function_prop_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MyObjectCxxQtSignalClosurecustomFunctionPropChanged,
+                        >::new(Box::new(closure)),
+                        cxx_qt::ConnectionType::AutoConnection,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
 #[doc(hidden)]
 pub struct MyObjectCxxQtSignalClosurecustomFunctionPropChanged {}
 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure
@@ -753,6 +1054,77 @@ impl ffi::MyObject {
         ))
     }
 }
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "renamedPropertyChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "The connection is disconnected when the context object is destroyed."]
+    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+    pub unsafe fn connect_renamed_property_changed_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MyObject_connect_renamed_property_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MyObjectCxxQtSignalClosurerenamedPropertyChanged,
+                        >::new(Box::new(closure)),
+                        conn_type,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "renamedPropertyChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "See the safety section of the connect method with a context object."]
+    pub unsafe fn on_renamed_property_changed_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MyObject_connect_renamed_property_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MyObjectCxxQtSignalClosurerenamedPropertyChanged,
+                        >::new(Box::new(closure)),
+                        cxx_qt::ConnectionType::AutoConnection,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
 #[doc(hidden)]
 pub struct MyObjectCxxQtSignalClosurerenamedPropertyChanged {}
 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure
@@ -819,6 +1191,77 @@ impl ffi::MyObject {
         ))
     }
 }
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "named_prop_2Changed"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "The connection is disconnected when the context object is destroyed."]
+    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+    pub unsafe fn connect_renamed_property_2_changed_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MyObject_connect_renamed_property_2_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MyObjectCxxQtSignalClosurenamed_prop_2Changed,
+                        >::new(Box::new(closure)),
+                        conn_type,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "named_prop_2Changed"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "See the safety section of the connect method with a context object."]
+    pub unsafe fn on_renamed_property_2_changed_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MyObject_connect_renamed_property_2_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MyObjectCxxQtSignalClosurenamed_prop_2Changed,
+                        >::new(Box::new(closure)),
+                        cxx_qt::ConnectionType::AutoConnection,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
 #[doc(hidden)]
 pub struct MyObjectCxxQtSignalClosurenamed_prop_2Changed {}
 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure
@@ -883,6 +1326,77 @@ impl ffi::MyObject {
         ))
     }
 }
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "myOnChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "The connection is disconnected when the context object is destroyed."]
+    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+    pub unsafe fn connect_my_on_changed_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MyObject_connect_my_on_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MyObjectCxxQtSignalClosuremyOnChanged,
+                        >::new(Box::new(closure)),
+                        conn_type,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "myOnChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "See the safety section of the connect method with a context object."]
+    pub unsafe fn on_my_on_changed_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MyObject_connect_my_on_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MyObjectCxxQtSignalClosuremyOnChanged,
+                        >::new(Box::new(closure)),
+                        cxx_qt::ConnectionType::AutoConnection,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
 #[doc(hidden)]
 pub struct MyObjectCxxQtSignalClosuremyOnChanged {}
 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosuremyOnChanged {
@@ -925,3 +1439,5 @@ impl ::cxx_qt::CxxQtType for ffi::MyObject {
         ffi::cxx_qt_ffi_my_object_unsafe_rust_mut(self)
     }
 }
+#[doc(hidden)]
+fn cxx_qt_ffi_my_object_signature_4747433c49611a45() {}
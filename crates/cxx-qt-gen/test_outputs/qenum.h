@@ -72,6 +72,11 @@ enum class MyRenamedEnum : ::std::int32_t
 
 #include "directory/file_ident.cxx.h"
 
+namespace cxx_qt::my_object::cxx_qt_my_object {
+[[maybe_unused]] static const bool cxxQtSignatureCheck =
+  (cxxQtSignature1d3b41698ba126e3(), true);
+} // namespace cxx_qt::my_object::cxx_qt_my_object
+
 namespace cxx_qt::my_object {
 class MyObject
   : public QObject
@@ -110,6 +115,11 @@ static_assert(::std::is_base_of<QObject, MyObject>::value,
 
 Q_DECLARE_METATYPE(cxx_qt::my_object::MyObject*)
 
+namespace cxx_qt::my_object::cxx_qt_my_renamed_object {
+[[maybe_unused]] static const bool cxxQtSignatureCheck =
+  (cxxQtSignature254a8bc77ac4ee47(), true);
+} // namespace cxx_qt::my_object::cxx_qt_my_renamed_object
+
 namespace cxx_qt::my_object {
 class CxxName
   : public QObject
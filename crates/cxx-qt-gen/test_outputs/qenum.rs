@@ -13,6 +13,11 @@ mod ffi {
         #[rust_name = "CxxQtQMetaObjectConnection"]
         #[allow(dead_code)]
         type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        #[allow(dead_code)]
+        type QObject = cxx_qt::QObject;
     }
     unsafe extern "C++" {
         include!("directory/file_ident.cxxqt.h");
@@ -79,6 +84,21 @@ mod ffi {
         #[namespace = "cxx_qt::my_object"]
         type MyObject;
     }
+    unsafe extern "C++" {
+        include!("cxx-qt/upcast.h");
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_upcast_ptr_qobject(
+            thiz: *const MyObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_downcast_ptr_qobject(
+            base: *const CxxQtQObject,
+        ) -> *const MyObject;
+    }
     extern "Rust" {
         #[namespace = "cxx_qt::my_object"]
         type MyObjectRust;
@@ -108,6 +128,12 @@ mod ffi {
             outer: Pin<&mut MyObject>,
         ) -> Pin<&mut MyObjectRust>;
     }
+    extern "Rust" {
+        #[cxx_name = "cxxQtSignature1d3b41698ba126e3"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_signature_1d3b41698ba126e3();
+    }
     unsafe extern "C++" {
         #[doc = "The C++ type for the QObject "]
         #[doc = "InternalObject"]
@@ -121,6 +147,21 @@ mod ffi {
         #[cxx_name = "CxxName"]
         type MyRenamedObject;
     }
+    unsafe extern "C++" {
+        include!("cxx-qt/upcast.h");
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_cxx_name_upcast_ptr_qobject(
+            thiz: *const MyRenamedObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_cxx_name_downcast_ptr_qobject(
+            base: *const CxxQtQObject,
+        ) -> *const MyRenamedObject;
+    }
     extern "Rust" {
         #[namespace = "cxx_qt::my_object"]
         type InternalObject;
@@ -144,6 +185,20 @@ mod ffi {
             outer: Pin<&mut MyRenamedObject>,
         ) -> Pin<&mut InternalObject>;
     }
+    extern "Rust" {
+        #[cxx_name = "cxxQtSignature254a8bc77ac4ee47"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_renamed_object"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_renamed_object_signature_254a8bc77ac4ee47();
+    }
+}
+unsafe impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_my_object_upcast_ptr_qobject(this)
+    }
+    unsafe fn from_base_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_my_object_downcast_ptr_qobject(base)
+    }
 }
 #[doc(hidden)]
 pub fn create_rs_my_object_rust() -> std::boxed::Box<MyObjectRust> {
@@ -165,6 +220,16 @@ impl ::cxx_qt::CxxQtType for ffi::MyObject {
     }
 }
 #[doc(hidden)]
+fn cxx_qt_ffi_my_object_signature_1d3b41698ba126e3() {}
+unsafe impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::MyRenamedObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_cxx_name_upcast_ptr_qobject(this)
+    }
+    unsafe fn from_base_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_cxx_name_downcast_ptr_qobject(base)
+    }
+}
+#[doc(hidden)]
 pub fn create_rs_internal_object() -> std::boxed::Box<InternalObject> {
     std::boxed::Box::new(core::default::Default::default())
 }
@@ -183,3 +248,5 @@ impl ::cxx_qt::CxxQtType for ffi::MyRenamedObject {
         ffi::cxx_qt_ffi_cxx_name_unsafe_rust_mut(self)
     }
 }
+#[doc(hidden)]
+fn cxx_qt_ffi_my_renamed_object_signature_254a8bc77ac4ee47() {}
@@ -0,0 +1,184 @@
+#include "directory/file_ident.cxxqt.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust::cxxqt1 {
+template<>
+SignalHandler<
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MySingletonCxxQtSignalParamsnumberChanged*>::~SignalHandler() noexcept
+{
+  if (data[0] == nullptr && data[1] == nullptr) {
+    return;
+  }
+
+  drop_MySingleton_signal_handler_numberChanged(::std::move(*this));
+}
+
+template<>
+template<>
+void
+SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                MySingletonCxxQtSignalParamsnumberChanged*>::
+operator()<cxx_qt::my_object::MySingleton&>(
+  cxx_qt::my_object::MySingleton& self)
+{
+  call_MySingleton_signal_handler_numberChanged(*this, self);
+}
+
+static_assert(
+  alignof(SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                          MySingletonCxxQtSignalParamsnumberChanged*>) <=
+    alignof(::std::size_t),
+  "unexpected aligment");
+static_assert(
+  sizeof(SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                         MySingletonCxxQtSignalParamsnumberChanged*>) ==
+    sizeof(::std::size_t[2]),
+  "unexpected size");
+} // namespace rust::cxxqt1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MySingleton_numberChangedConnect(
+  cxx_qt::my_object::MySingleton& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MySingletonCxxQtSignalHandlernumberChanged closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::my_object::MySingleton::numberChanged,
+    &self,
+    [&, closure = ::std::move(closure)]() mutable {
+      closure.template operator()<cxx_qt::my_object::MySingleton&>(self);
+    },
+    type);
+}
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MySingleton_numberChangedConnectWithContext(
+  cxx_qt::my_object::MySingleton& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MySingletonCxxQtSignalHandlernumberChanged closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::my_object::MySingleton::numberChanged,
+    &context,
+    [sender = ::QPointer<cxx_qt::my_object::MySingleton>(&self),
+     closure = ::std::move(closure)]() mutable {
+      if (sender) {
+        closure.template operator()<cxx_qt::my_object::MySingleton&>(*sender);
+      }
+    },
+    type);
+}
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust::cxxqt1 {
+template<>
+SignalHandler<
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MySingletonCxxQtSignalParamsinitialized*>::~SignalHandler() noexcept
+{
+  if (data[0] == nullptr && data[1] == nullptr) {
+    return;
+  }
+
+  drop_MySingleton_signal_handler_initialized(::std::move(*this));
+}
+
+template<>
+template<>
+void
+SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                MySingletonCxxQtSignalParamsinitialized*>::
+operator()<cxx_qt::my_object::MySingleton&>(
+  cxx_qt::my_object::MySingleton& self)
+{
+  call_MySingleton_signal_handler_initialized(*this, self);
+}
+
+static_assert(
+  alignof(SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                          MySingletonCxxQtSignalParamsinitialized*>) <=
+    alignof(::std::size_t),
+  "unexpected aligment");
+static_assert(
+  sizeof(SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+                         MySingletonCxxQtSignalParamsinitialized*>) ==
+    sizeof(::std::size_t[2]),
+  "unexpected size");
+} // namespace rust::cxxqt1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MySingleton_initializedConnect(
+  cxx_qt::my_object::MySingleton& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MySingletonCxxQtSignalHandlerinitialized closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::my_object::MySingleton::initialized,
+    &self,
+    [&, closure = ::std::move(closure)]() mutable {
+      closure.template operator()<cxx_qt::my_object::MySingleton&>(self);
+    },
+    type);
+}
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MySingleton_initializedConnectWithContext(
+  cxx_qt::my_object::MySingleton& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MySingletonCxxQtSignalHandlerinitialized closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::my_object::MySingleton::initialized,
+    &context,
+    [sender = ::QPointer<cxx_qt::my_object::MySingleton>(&self),
+     closure = ::std::move(closure)]() mutable {
+      if (sender) {
+        closure.template operator()<cxx_qt::my_object::MySingleton&>(*sender);
+      }
+    },
+    type);
+}
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object {
+void
+MySingleton::ensureInitialized() noexcept
+{
+  ::rust::cxxqt1::lazyEnsureInitialized(*this);
+}
+
+MySingleton::MySingleton(QObject* parent)
+  : QObject(parent)
+  , ::rust::cxxqt1::CxxQtType<MySingletonRust>(
+      ::cxx_qt::my_object::cxx_qt_my_singleton::createRs())
+{
+}
+
+void
+MySingleton::connectNotify(const QMetaMethod& signal)
+{
+  ::rust::cxxqt1::lazyEnsureInitializedQueued(*this);
+  QObject::connectNotify(signal);
+}
+
+} // namespace cxx_qt::my_object
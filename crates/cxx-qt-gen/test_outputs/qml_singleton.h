@@ -0,0 +1,106 @@
+#pragma once
+
+#include <QtCore/QMetaMethod>
+#include <QtCore/QPointer>
+#include <cxx-qt/lazy.h>
+#include <cxx-qt/signalhandler.h>
+#include <cxx-qt/type.h>
+
+namespace cxx_qt::my_object {
+class MySingleton;
+
+} // namespace cxx_qt::my_object
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+using MySingletonCxxQtSignalHandlernumberChanged =
+  ::rust::cxxqt1::SignalHandler<
+    struct MySingletonCxxQtSignalParamsnumberChanged*>;
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+using MySingletonCxxQtSignalHandlerinitialized =
+  ::rust::cxxqt1::SignalHandler<
+    struct MySingletonCxxQtSignalParamsinitialized*>;
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+#include "directory/file_ident.cxx.h"
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MySingleton_numberChangedConnect(
+  cxx_qt::my_object::MySingleton& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MySingletonCxxQtSignalHandlernumberChanged closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MySingleton_numberChangedConnectWithContext(
+  cxx_qt::my_object::MySingleton& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MySingletonCxxQtSignalHandlernumberChanged closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MySingleton_initializedConnect(
+  cxx_qt::my_object::MySingleton& self,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MySingletonCxxQtSignalHandlerinitialized closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MySingleton_initializedConnectWithContext(
+  cxx_qt::my_object::MySingleton& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::
+    MySingletonCxxQtSignalHandlerinitialized closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object::cxx_qt_my_singleton {
+[[maybe_unused]] static const bool cxxQtSignatureCheck =
+  (cxxQtSignaturefad3812a746db4dd(), true);
+} // namespace cxx_qt::my_object::cxx_qt_my_singleton
+
+namespace cxx_qt::my_object {
+class MySingleton
+  : public QObject
+  , public ::rust::cxxqt1::CxxQtType<MySingletonRust>
+  , public ::rust::cxxqt1::CxxQtLazyInitialization
+{
+  Q_OBJECT
+public:
+  Q_CLASSINFO("QML.Element", "MySingleton")
+  QML_SINGLETON
+  Q_PROPERTY(::std::int32_t number READ getNumber WRITE setNumber NOTIFY
+               numberChanged)
+
+  virtual ~MySingleton() = default;
+
+public:
+  ::std::int32_t const& getNumber() const noexcept;
+  Q_SLOT void setNumber(::std::int32_t value) noexcept;
+  /// Notify for the Q_PROPERTY
+  Q_SIGNAL void numberChanged();
+  Q_INVOKABLE void ensureInitialized() noexcept;
+  Q_INVOKABLE bool isInitialized() const noexcept;
+  void cxxQtLazyInitialize() noexcept;
+  /// This signal is emitted once the lazy singleton has been initialized
+  Q_SIGNAL void initialized();
+  explicit MySingleton(QObject* parent = nullptr);
+
+private:
+  void connectNotify(const QMetaMethod& signal) override;
+};
+
+static_assert(::std::is_base_of<QObject, MySingleton>::value,
+              "MySingleton must inherit from QObject");
+} // namespace cxx_qt::my_object
+
+Q_DECLARE_METATYPE(cxx_qt::my_object::MySingleton*)
@@ -0,0 +1,528 @@
+#[cxx::bridge(namespace = "cxx_qt::my_object")]
+mod ffi {
+    unsafe extern "C++" {
+        include ! (< QtCore / QObject >);
+        include!("cxx-qt/connection.h");
+        #[doc(hidden)]
+        #[namespace = "Qt"]
+        #[rust_name = "CxxQtConnectionType"]
+        #[allow(dead_code)]
+        type ConnectionType = cxx_qt::ConnectionType;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqt1"]
+        #[rust_name = "CxxQtQMetaObjectConnection"]
+        #[allow(dead_code)]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        #[allow(dead_code)]
+        type QObject = cxx_qt::QObject;
+    }
+    unsafe extern "C++" {
+        include!("directory/file_ident.cxxqt.h");
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "MySingletonRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/qobject/generated-qobject.html>"]
+        #[namespace = "cxx_qt::my_object"]
+        type MySingleton;
+    }
+    unsafe extern "C++" {
+        include!("cxx-qt/upcast.h");
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_singleton_upcast_ptr_qobject(
+            thiz: *const MySingleton,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_singleton_downcast_ptr_qobject(
+            base: *const CxxQtQObject,
+        ) -> *const MySingleton;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::my_object"]
+        type MySingletonRust;
+    }
+    extern "Rust" {
+        #[cxx_name = "getNumber"]
+        #[namespace = "cxx_qt::my_object"]
+        unsafe fn number<'a>(self: &'a MySingleton) -> &'a i32;
+    }
+    extern "Rust" {
+        #[cxx_name = "setNumber"]
+        #[namespace = "cxx_qt::my_object"]
+        fn set_number(self: Pin<&mut MySingleton>, value: i32);
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "numberChanged"]
+        #[doc = "Notify for the Q_PROPERTY"]
+        #[namespace = "cxx_qt::my_object"]
+        fn number_changed(self: Pin<&mut MySingleton>);
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        type MySingletonCxxQtSignalHandlernumberChanged = cxx_qt::signalhandler::CxxQtSignalHandler<
+            super::MySingletonCxxQtSignalClosurenumberChanged,
+        >;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MySingleton_numberChangedConnect"]
+        fn MySingleton_connect_number_changed(
+            self_value: Pin<&mut MySingleton>,
+            signal_handler: MySingletonCxxQtSignalHandlernumberChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MySingleton_numberChangedConnectWithContext"]
+        fn MySingleton_connect_number_changed_with_context(
+            self_value: Pin<&mut MySingleton>,
+            context: &CxxQtQObject,
+            signal_handler: MySingletonCxxQtSignalHandlernumberChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_MySingleton_signal_handler_numberChanged(
+            handler: MySingletonCxxQtSignalHandlernumberChanged,
+        );
+        #[doc(hidden)]
+        fn call_MySingleton_signal_handler_numberChanged(
+            handler: &mut MySingletonCxxQtSignalHandlernumberChanged,
+            self_value: Pin<&mut MySingleton>,
+        );
+    }
+    unsafe extern "C++" {
+        include ! (< QtQml / QQmlEngine >);
+    }
+    unsafe extern "C++" {
+        include!("cxx-qt/lazy.h");
+        #[doc(hidden)]
+        #[cxx_name = "lazyEnsureInitialized"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_my_singleton_lazy_ensure_initialized(qobject: Pin<&mut MySingleton>);
+        #[doc(hidden)]
+        #[cxx_name = "lazyInitializeFinish"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_my_singleton_lazy_initialize_finish(qobject: Pin<&mut MySingleton>);
+        #[doc(hidden)]
+        #[cxx_name = "lazyIsInitialized"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_my_singleton_lazy_is_initialized(qobject: &MySingleton) -> bool;
+    }
+    extern "Rust" {
+        #[cxx_name = "cxxQtLazyInitialize"]
+        #[namespace = "cxx_qt::my_object"]
+        #[doc(hidden)]
+        fn cxx_qt_lazy_initialize(self: Pin<&mut MySingleton>);
+    }
+    extern "Rust" {
+        #[cxx_name = "isInitialized"]
+        #[namespace = "cxx_qt::my_object"]
+        #[doc(hidden)]
+        fn is_initialized(self: &MySingleton) -> bool;
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "initialized"]
+        #[doc = "This signal is emitted once the lazy singleton has been initialized"]
+        #[namespace = "cxx_qt::my_object"]
+        fn initialized(self: Pin<&mut MySingleton>);
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        type MySingletonCxxQtSignalHandlerinitialized = cxx_qt::signalhandler::CxxQtSignalHandler<
+            super::MySingletonCxxQtSignalClosureinitialized,
+        >;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MySingleton_initializedConnect"]
+        fn MySingleton_connect_initialized(
+            self_value: Pin<&mut MySingleton>,
+            signal_handler: MySingletonCxxQtSignalHandlerinitialized,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MySingleton_initializedConnectWithContext"]
+        fn MySingleton_connect_initialized_with_context(
+            self_value: Pin<&mut MySingleton>,
+            context: &CxxQtQObject,
+            signal_handler: MySingletonCxxQtSignalHandlerinitialized,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_MySingleton_signal_handler_initialized(
+            handler: MySingletonCxxQtSignalHandlerinitialized,
+        );
+        #[doc(hidden)]
+        fn call_MySingleton_signal_handler_initialized(
+            handler: &mut MySingletonCxxQtSignalHandlerinitialized,
+            self_value: Pin<&mut MySingleton>,
+        );
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_singleton"]
+        fn create_rs_my_singleton_rust() -> Box<MySingletonRust>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_my_singleton_unsafe_rust(outer: &MySingleton) -> &MySingletonRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_my_singleton_unsafe_rust_mut(
+            outer: Pin<&mut MySingleton>,
+        ) -> Pin<&mut MySingletonRust>;
+    }
+    extern "Rust" {
+        #[cxx_name = "cxxQtSignaturefad3812a746db4dd"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_singleton"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_singleton_signature_fad3812a746db4dd();
+    }
+}
+unsafe impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::MySingleton {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_my_singleton_upcast_ptr_qobject(this)
+    }
+    unsafe fn from_base_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_my_singleton_downcast_ptr_qobject(base)
+    }
+}
+impl ffi::MySingleton {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "number"]
+    pub fn number(&self) -> &i32 {
+        &self.number
+    }
+}
+impl ffi::MySingleton {
+    #[doc = "Setter for the Q_PROPERTY "]
+    #[doc = "number"]
+    pub fn set_number(mut self: core::pin::Pin<&mut Self>, value: i32) {
+        use cxx_qt::CxxQtType;
+        if self.number == value {
+            return;
+        }
+        self.as_mut().rust_mut().number = value;
+        self.as_mut().number_changed();
+    }
+}
+impl ffi::MySingleton {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "numberChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    pub fn connect_number_changed<F: FnMut(core::pin::Pin<&mut ffi::MySingleton>) + 'static>(
+        self: core::pin::Pin<&mut ffi::MySingleton>,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            ffi::MySingleton_connect_number_changed(
+                self,
+                cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MySingletonCxxQtSignalClosurenumberChanged,
+                >::new(Box::new(closure)),
+                conn_type,
+            ),
+        )
+    }
+}
+impl ffi::MySingleton {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "numberChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    pub fn on_number_changed<F: FnMut(core::pin::Pin<&mut ffi::MySingleton>) + 'static>(
+        self: core::pin::Pin<&mut ffi::MySingleton>,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            ffi::MySingleton_connect_number_changed(
+                self,
+                cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MySingletonCxxQtSignalClosurenumberChanged,
+                >::new(Box::new(closure)),
+                cxx_qt::ConnectionType::AutoConnection,
+            ),
+        )
+    }
+}
+impl ffi::MySingleton {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "numberChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "The connection is disconnected when the context object is destroyed."]
+    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+    pub unsafe fn connect_number_changed_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MySingleton>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MySingleton>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MySingleton_connect_number_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MySingletonCxxQtSignalClosurenumberChanged,
+                        >::new(Box::new(closure)),
+                        conn_type,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+impl ffi::MySingleton {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "numberChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "See the safety section of the connect method with a context object."]
+    pub unsafe fn on_number_changed_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MySingleton>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MySingleton>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MySingleton_connect_number_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MySingletonCxxQtSignalClosurenumberChanged,
+                        >::new(Box::new(closure)),
+                        cxx_qt::ConnectionType::AutoConnection,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+#[doc(hidden)]
+pub struct MySingletonCxxQtSignalClosurenumberChanged {}
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure
+    for MySingletonCxxQtSignalClosurenumberChanged
+{
+    type Id = cxx::type_id!(
+        "::cxx_qt::my_object::rust::cxxqtgen1::MySingletonCxxQtSignalHandlernumberChanged"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::MySingleton>);
+}
+use core::mem::drop as drop_MySingleton_signal_handler_numberChanged;
+fn call_MySingleton_signal_handler_numberChanged(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        MySingletonCxxQtSignalClosurenumberChanged,
+    >,
+    self_value: core::pin::Pin<&mut ffi::MySingleton>,
+) {
+    handler.closure()(self_value);
+}
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MySingletonCxxQtSignalClosurenumberChanged>,
+    usize
+);
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MySingletonCxxQtSignalClosurenumberChanged>,
+    [usize; 2]
+);
+impl ffi::MySingleton {
+    #[doc = r" Initializes the QObject with [cxx_qt::LazyInitialize] if this is the first call,"]
+    #[doc = r" then emits the initialized signal."]
+    pub fn ensure_initialized(self: core::pin::Pin<&mut Self>) {
+        ffi::cxx_qt_ffi_my_singleton_lazy_ensure_initialized(self);
+    }
+    #[doc = r" Returns whether the QObject has been initialized with [cxx_qt::LazyInitialize]."]
+    pub fn is_initialized(&self) -> bool {
+        ffi::cxx_qt_ffi_my_singleton_lazy_is_initialized(self)
+    }
+    #[doc(hidden)]
+    pub fn cxx_qt_lazy_initialize(mut self: core::pin::Pin<&mut Self>) {
+        <Self as cxx_qt::LazyInitialize>::lazy_initialize(self.as_mut());
+        ffi::cxx_qt_ffi_my_singleton_lazy_initialize_finish(self.as_mut());
+        self.initialized();
+    }
+}
+impl ffi::MySingleton {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "initialized"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    pub fn connect_initialized<F: FnMut(core::pin::Pin<&mut ffi::MySingleton>) + 'static>(
+        self: core::pin::Pin<&mut ffi::MySingleton>,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: MySingleton_connect_initialized (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MySingletonCxxQtSignalClosureinitialized > :: new (Box :: new (closure)) , conn_type ,))
+    }
+}
+impl ffi::MySingleton {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "initialized"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    pub fn on_initialized<F: FnMut(core::pin::Pin<&mut ffi::MySingleton>) + 'static>(
+        self: core::pin::Pin<&mut ffi::MySingleton>,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: MySingleton_connect_initialized (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MySingletonCxxQtSignalClosureinitialized > :: new (Box :: new (closure)) , cxx_qt :: ConnectionType :: AutoConnection ,))
+    }
+}
+impl ffi::MySingleton {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "initialized"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "The connection is disconnected when the context object is destroyed."]
+    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+    pub unsafe fn connect_initialized_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MySingleton>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MySingleton>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MySingleton_connect_initialized_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MySingletonCxxQtSignalClosureinitialized,
+                        >::new(Box::new(closure)),
+                        conn_type,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+impl ffi::MySingleton {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "initialized"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "See the safety section of the connect method with a context object."]
+    pub unsafe fn on_initialized_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MySingleton>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MySingleton>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MySingleton_connect_initialized_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MySingletonCxxQtSignalClosureinitialized,
+                        >::new(Box::new(closure)),
+                        cxx_qt::ConnectionType::AutoConnection,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+#[doc(hidden)]
+pub struct MySingletonCxxQtSignalClosureinitialized {}
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MySingletonCxxQtSignalClosureinitialized {
+    type Id = cxx::type_id!(
+        "::cxx_qt::my_object::rust::cxxqtgen1::MySingletonCxxQtSignalHandlerinitialized"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::MySingleton>);
+}
+use core::mem::drop as drop_MySingleton_signal_handler_initialized;
+fn call_MySingleton_signal_handler_initialized(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        MySingletonCxxQtSignalClosureinitialized,
+    >,
+    self_value: core::pin::Pin<&mut ffi::MySingleton>,
+) {
+    handler.closure()(self_value);
+}
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MySingletonCxxQtSignalClosureinitialized>,
+    usize
+);
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MySingletonCxxQtSignalClosureinitialized>,
+    [usize; 2]
+);
+#[doc(hidden)]
+pub fn create_rs_my_singleton_rust() -> std::boxed::Box<MySingletonRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+impl ::core::ops::Deref for ffi::MySingleton {
+    type Target = MySingletonRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_my_singleton_unsafe_rust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::MySingleton {
+    type Rust = MySingletonRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_my_singleton_unsafe_rust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_my_singleton_unsafe_rust_mut(self)
+    }
+}
+#[doc(hidden)]
+fn cxx_qt_ffi_my_singleton_signature_fad3812a746db4dd() {}
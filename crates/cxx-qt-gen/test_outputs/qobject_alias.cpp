@@ -0,0 +1,7 @@
+#include "directory/file_ident.cxxqt.h"
+
+MyObject::MyObject(QObject* parent)
+  : other::OtherObject(parent)
+  , ::rust::cxxqt1::CxxQtType<MyObjectRust>(::cxx_qt_my_object::createRs())
+{
+}
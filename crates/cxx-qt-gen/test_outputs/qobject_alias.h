@@ -0,0 +1,35 @@
+#pragma once
+
+#include "directory/other.cxxqt.h"
+#include <cxx-qt/type.h>
+
+class MyObject;
+
+namespace other {
+class OtherObject;
+} // namespace other
+
+#include "directory/file_ident.cxx.h"
+
+namespace cxx_qt_my_object {
+[[maybe_unused]] static const bool cxxQtSignatureCheck =
+  (cxxQtSignaturea03807d9bb19cf43(), true);
+} // namespace cxx_qt_my_object
+
+class MyObject
+  : public other::OtherObject
+  , public ::rust::cxxqt1::CxxQtType<MyObjectRust>
+{
+  Q_OBJECT
+public:
+  virtual ~MyObject() = default;
+
+public:
+  Q_INVOKABLE bool compare(other::OtherObject const& other) const noexcept;
+  explicit MyObject(QObject* parent = nullptr);
+};
+
+static_assert(::std::is_base_of<QObject, MyObject>::value,
+              "MyObject must inherit from QObject");
+
+Q_DECLARE_METATYPE(MyObject*)
@@ -0,0 +1,135 @@
+#[cxx::bridge(namespace = "")]
+mod ffi {
+    unsafe extern "C++" {
+        include ! (< QtCore / QObject >);
+        include!("cxx-qt/connection.h");
+        #[doc(hidden)]
+        #[namespace = "Qt"]
+        #[rust_name = "CxxQtConnectionType"]
+        #[allow(dead_code)]
+        type ConnectionType = cxx_qt::ConnectionType;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqt1"]
+        #[rust_name = "CxxQtQMetaObjectConnection"]
+        #[allow(dead_code)]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        #[allow(dead_code)]
+        type QObject = cxx_qt::QObject;
+    }
+    unsafe extern "C++" {
+        include!("directory/file_ident.cxxqt.h");
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "MyObjectRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/qobject/generated-qobject.html>"]
+        type MyObject;
+    }
+    unsafe extern "C++" {
+        include!("cxx-qt/upcast.h");
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_upcast_ptr_other_object(
+            thiz: *const MyObject,
+        ) -> *const OtherObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_downcast_ptr_other_object(
+            base: *const OtherObject,
+        ) -> *const MyObject;
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_upcast_ptr_qobject(
+            thiz: *const MyObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_downcast_ptr_qobject(
+            base: *const CxxQtQObject,
+        ) -> *const MyObject;
+    }
+    extern "Rust" {
+        type MyObjectRust;
+    }
+    extern "Rust" {
+        #[cxx_name = "compare"]
+        #[doc(hidden)]
+        fn compare(self: &MyObject, other: &OtherObject) -> bool;
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt_my_object"]
+        fn create_rs_my_object_rust() -> Box<MyObjectRust>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_my_object_unsafe_rust(outer: &MyObject) -> &MyObjectRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_my_object_unsafe_rust_mut(
+            outer: Pin<&mut MyObject>,
+        ) -> Pin<&mut MyObjectRust>;
+    }
+    extern "Rust" {
+        #[cxx_name = "cxxQtSignaturea03807d9bb19cf43"]
+        #[namespace = "cxx_qt_my_object"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_signature_a03807d9bb19cf43();
+    }
+    unsafe extern "C++" {
+        #[namespace = "other"]
+        type OtherObject = crate::other::qobject::OtherObject;
+    }
+}
+unsafe impl cxx_qt::Upcast<ffi::OtherObject> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ffi::OtherObject {
+        ffi::cxx_qt_ffi_my_object_upcast_ptr_other_object(this)
+    }
+    unsafe fn from_base_ptr(base: *const ffi::OtherObject) -> *const Self {
+        ffi::cxx_qt_ffi_my_object_downcast_ptr_other_object(base)
+    }
+}
+unsafe impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_my_object_upcast_ptr_qobject(this)
+    }
+    unsafe fn from_base_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_my_object_downcast_ptr_qobject(base)
+    }
+}
+#[doc(hidden)]
+pub fn create_rs_my_object_rust() -> std::boxed::Box<MyObjectRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+impl ::core::ops::Deref for ffi::MyObject {
+    type Target = MyObjectRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_my_object_unsafe_rust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::MyObject {
+    type Rust = MyObjectRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_my_object_unsafe_rust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_my_object_unsafe_rust_mut(self)
+    }
+}
+#[doc(hidden)]
+fn cxx_qt_ffi_my_object_signature_a03807d9bb19cf43() {}
@@ -4,7 +4,7 @@
 // https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
 namespace rust::cxxqt1 {
 template<>
-SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
+SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::ffi::
                 QTimerCxxQtSignalParamstimeout*>::~SignalHandler() noexcept
 {
   if (data[0] == nullptr && data[1] == nullptr) {
@@ -17,32 +17,31 @@ SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::
 template<>
 template<>
 void
-SignalHandler<
-  ::cxx_qt::my_object::rust::cxxqtgen1::QTimerCxxQtSignalParamstimeout*>::
+SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::ffi::
+                QTimerCxxQtSignalParamstimeout*>::
 operator()<cxx_qt::my_object::QTimer&>(cxx_qt::my_object::QTimer& self)
 {
   call_QTimer_signal_handler_timeout(*this, self);
 }
 
 static_assert(
-  alignof(
-    SignalHandler<
-      ::cxx_qt::my_object::rust::cxxqtgen1::QTimerCxxQtSignalParamstimeout*>) <=
+  alignof(SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::ffi::
+                          QTimerCxxQtSignalParamstimeout*>) <=
     alignof(::std::size_t),
   "unexpected aligment");
 static_assert(
-  sizeof(
-    SignalHandler<
-      ::cxx_qt::my_object::rust::cxxqtgen1::QTimerCxxQtSignalParamstimeout*>) ==
+  sizeof(SignalHandler<::cxx_qt::my_object::rust::cxxqtgen1::ffi::
+                         QTimerCxxQtSignalParamstimeout*>) ==
     sizeof(::std::size_t[2]),
   "unexpected size");
 } // namespace rust::cxxqt1
 
-namespace cxx_qt::my_object::rust::cxxqtgen1 {
+namespace cxx_qt::my_object::rust::cxxqtgen1::ffi {
 ::QMetaObject::Connection
 QTimer_timeoutConnect(
   cxx_qt::my_object::QTimer& self,
-  ::cxx_qt::my_object::rust::cxxqtgen1::QTimerCxxQtSignalHandlertimeout closure,
+  ::cxx_qt::my_object::rust::cxxqtgen1::ffi::QTimerCxxQtSignalHandlertimeout
+    closure,
   ::Qt::ConnectionType type)
 {
   return ::QObject::connect(
@@ -54,7 +53,30 @@ QTimer_timeoutConnect(
     },
     type);
 }
-} // namespace cxx_qt::my_object::rust::cxxqtgen1
+} // namespace cxx_qt::my_object::rust::cxxqtgen1::ffi
+
+namespace cxx_qt::my_object::rust::cxxqtgen1::ffi {
+::QMetaObject::Connection
+QTimer_timeoutConnectWithContext(
+  cxx_qt::my_object::QTimer& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::ffi::QTimerCxxQtSignalHandlertimeout
+    closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::my_object::QTimer::timeout,
+    &context,
+    [sender = ::QPointer<cxx_qt::my_object::QTimer>(&self),
+     closure = ::std::move(closure)]() mutable {
+      if (sender) {
+        closure.template operator()<cxx_qt::my_object::QTimer&>(*sender);
+      }
+    },
+    type);
+}
+} // namespace cxx_qt::my_object::rust::cxxqtgen1::ffi
 
 // Define namespace otherwise we hit a GCC bug
 // https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
@@ -112,6 +134,28 @@ MyObject_readyConnect(
 }
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_readyConnectWithContext(
+  cxx_qt::my_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerready closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::my_object::MyObject::ready,
+    &context,
+    [sender = ::QPointer<cxx_qt::my_object::MyObject>(&self),
+     closure = ::std::move(closure)]() mutable {
+      if (sender) {
+        closure.template operator()<cxx_qt::my_object::MyObject&>(*sender);
+      }
+    },
+    type);
+}
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 // Define namespace otherwise we hit a GCC bug
 // https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
 namespace rust::cxxqt1 {
@@ -190,6 +234,40 @@ MyObject_dataChangedConnect(
 }
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_dataChangedConnectWithContext(
+  cxx_qt::my_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerdataChanged
+    closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::my_object::MyObject::dataChanged,
+    &context,
+    [sender = ::QPointer<cxx_qt::my_object::MyObject>(&self),
+     closure = ::std::move(closure)](::std::int32_t first,
+                                     ::std::unique_ptr<Opaque> second,
+                                     QPoint third,
+                                     QPoint const& fourth) mutable {
+      if (sender) {
+        closure.template operator()<cxx_qt::my_object::MyObject&,
+                                    ::std::int32_t,
+                                    ::std::unique_ptr<Opaque>,
+                                    QPoint,
+                                    QPoint const&>(*sender,
+                                                   ::std::move(first),
+                                                   ::std::move(second),
+                                                   ::std::move(third),
+                                                   ::std::move(fourth));
+      }
+    },
+    type);
+}
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 // Define namespace otherwise we hit a GCC bug
 // https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
 namespace rust::cxxqt1 {
@@ -267,6 +345,40 @@ MyObject_newDataConnect(
 }
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_newDataConnectWithContext(
+  cxx_qt::my_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlernewData
+    closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::my_object::MyObject::newData,
+    &context,
+    [sender = ::QPointer<cxx_qt::my_object::MyObject>(&self),
+     closure = ::std::move(closure)](::std::int32_t first,
+                                     ::std::unique_ptr<Opaque> second,
+                                     QPoint third,
+                                     QPoint const& fourth) mutable {
+      if (sender) {
+        closure.template operator()<cxx_qt::my_object::MyObject&,
+                                    ::std::int32_t,
+                                    ::std::unique_ptr<Opaque>,
+                                    QPoint,
+                                    QPoint const&>(*sender,
+                                                   ::std::move(first),
+                                                   ::std::move(second),
+                                                   ::std::move(third),
+                                                   ::std::move(fourth));
+      }
+    },
+    type);
+}
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 namespace cxx_qt::my_object {
 MyObject::MyObject(QObject* parent)
   : QObject(parent)
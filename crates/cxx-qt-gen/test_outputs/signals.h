@@ -1,5 +1,6 @@
 #pragma once
 
+#include <QtCore/QPointer>
 #include <cxx-qt/signalhandler.h>
 #include <cxx-qt/type.h>
 
@@ -23,20 +24,31 @@ using MyObjectCxxQtSignalHandlernewData =
   ::rust::cxxqt1::SignalHandler<struct MyObjectCxxQtSignalParamsnewData*>;
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
-namespace cxx_qt::my_object::rust::cxxqtgen1 {
+namespace cxx_qt::my_object::rust::cxxqtgen1::ffi {
 using QTimerCxxQtSignalHandlertimeout =
   ::rust::cxxqt1::SignalHandler<struct QTimerCxxQtSignalParamstimeout*>;
-} // namespace cxx_qt::my_object::rust::cxxqtgen1
+} // namespace cxx_qt::my_object::rust::cxxqtgen1::ffi
 
 #include "directory/file_ident.cxx.h"
 
-namespace cxx_qt::my_object::rust::cxxqtgen1 {
+namespace cxx_qt::my_object::rust::cxxqtgen1::ffi {
 ::QMetaObject::Connection
 QTimer_timeoutConnect(
   cxx_qt::my_object::QTimer& self,
-  ::cxx_qt::my_object::rust::cxxqtgen1::QTimerCxxQtSignalHandlertimeout closure,
+  ::cxx_qt::my_object::rust::cxxqtgen1::ffi::QTimerCxxQtSignalHandlertimeout
+    closure,
   ::Qt::ConnectionType type);
-} // namespace cxx_qt::my_object::rust::cxxqtgen1
+} // namespace cxx_qt::my_object::rust::cxxqtgen1::ffi
+
+namespace cxx_qt::my_object::rust::cxxqtgen1::ffi {
+::QMetaObject::Connection
+QTimer_timeoutConnectWithContext(
+  cxx_qt::my_object::QTimer& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::ffi::QTimerCxxQtSignalHandlertimeout
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1::ffi
 
 namespace cxx_qt::my_object::rust::cxxqtgen1 {
 ::QMetaObject::Connection
@@ -46,6 +58,15 @@ MyObject_readyConnect(
   ::Qt::ConnectionType type);
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_readyConnectWithContext(
+  cxx_qt::my_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerready closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 namespace cxx_qt::my_object::rust::cxxqtgen1 {
 ::QMetaObject::Connection
 MyObject_dataChangedConnect(
@@ -55,6 +76,16 @@ MyObject_dataChangedConnect(
   ::Qt::ConnectionType type);
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_dataChangedConnectWithContext(
+  cxx_qt::my_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerdataChanged
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
 namespace cxx_qt::my_object::rust::cxxqtgen1 {
 ::QMetaObject::Connection
 MyObject_newDataConnect(
@@ -64,7 +95,23 @@ MyObject_newDataConnect(
   ::Qt::ConnectionType type);
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
+namespace cxx_qt::my_object::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_newDataConnectWithContext(
+  cxx_qt::my_object::MyObject& self,
+  const ::QObject& context,
+  ::cxx_qt::my_object::rust::cxxqtgen1::MyObjectCxxQtSignalHandlernewData
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::my_object::rust::cxxqtgen1
+
+namespace cxx_qt::my_object::cxx_qt_my_object {
+[[maybe_unused]] static const bool cxxQtSignatureCheck =
+  (cxxQtSignature1e899a677c4d4e9d(), true);
+} // namespace cxx_qt::my_object::cxx_qt_my_object
+
 namespace cxx_qt::my_object {
+/// A QObject with signals
 class MyObject
   : public QObject
   , public ::rust::cxxqt1::CxxQtType<MyObjectRust>
@@ -74,7 +121,9 @@ public:
   virtual ~MyObject() = default;
 
 public:
+  /// Emits the signals
   Q_INVOKABLE void invokable() noexcept;
+  /// Emitted when the object is ready
   Q_SIGNAL void ready();
   Q_SIGNAL void dataChanged(::std::int32_t first,
                             ::std::unique_ptr<Opaque> second,
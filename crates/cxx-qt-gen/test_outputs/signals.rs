@@ -19,6 +19,11 @@ mod ffi {
         #[rust_name = "CxxQtQMetaObjectConnection"]
         #[allow(dead_code)]
         type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = ""]
+        #[rust_name = "CxxQtQObject"]
+        #[allow(dead_code)]
+        type QObject = cxx_qt::QObject;
     }
     unsafe extern "C++" {
         include!("directory/file_ident.cxxqt.h");
@@ -33,6 +38,21 @@ mod ffi {
         #[namespace = "cxx_qt::my_object"]
         type MyObject;
     }
+    unsafe extern "C++" {
+        include!("cxx-qt/upcast.h");
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_upcast_ptr_qobject(
+            thiz: *const MyObject,
+        ) -> *const CxxQtQObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_my_object_downcast_ptr_qobject(
+            base: *const CxxQtQObject,
+        ) -> *const MyObject;
+    }
     extern "Rust" {
         #[namespace = "cxx_qt::my_object"]
         type MyObjectRust;
@@ -45,6 +65,7 @@ mod ffi {
     }
     unsafe extern "C++" {
         #[cxx_name = "ready"]
+        #[doc = " Emitted when the object is ready"]
         #[namespace = "cxx_qt::my_object"]
         fn ready(self: Pin<&mut MyObject>);
     }
@@ -61,6 +82,15 @@ mod ffi {
             signal_handler: MyObjectCxxQtSignalHandlerready,
             conn_type: CxxQtConnectionType,
         ) -> CxxQtQMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_readyConnectWithContext"]
+        fn MyObject_connect_ready_with_context(
+            self_value: Pin<&mut MyObject>,
+            context: &CxxQtQObject,
+            signal_handler: MyObjectCxxQtSignalHandlerready,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
     }
     #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
     extern "Rust" {
@@ -96,6 +126,15 @@ mod ffi {
             signal_handler: MyObjectCxxQtSignalHandlerdataChanged,
             conn_type: CxxQtConnectionType,
         ) -> CxxQtQMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_dataChangedConnectWithContext"]
+        fn MyObject_connect_data_changed_with_context(
+            self_value: Pin<&mut MyObject>,
+            context: &CxxQtQObject,
+            signal_handler: MyObjectCxxQtSignalHandlerdataChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
     }
     #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
     extern "Rust" {
@@ -135,6 +174,15 @@ mod ffi {
             signal_handler: MyObjectCxxQtSignalHandlernewData,
             conn_type: CxxQtConnectionType,
         ) -> CxxQtQMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_newDataConnectWithContext"]
+        fn MyObject_connect_base_class_new_data_with_context(
+            self_value: Pin<&mut MyObject>,
+            context: &CxxQtQObject,
+            signal_handler: MyObjectCxxQtSignalHandlernewData,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
     }
     #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
     extern "Rust" {
@@ -169,6 +217,12 @@ mod ffi {
             outer: Pin<&mut MyObject>,
         ) -> Pin<&mut MyObjectRust>;
     }
+    extern "Rust" {
+        #[cxx_name = "cxxQtSignature1e899a677c4d4e9d"]
+        #[namespace = "cxx_qt::my_object::cxx_qt_my_object"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_my_object_signature_1e899a677c4d4e9d();
+    }
     unsafe extern "C++" {
         include ! (< QtCore / QTimer >);
         #[namespace = "cxx_qt::my_object"]
@@ -177,19 +231,28 @@ mod ffi {
     }
     unsafe extern "C++" {
         #[doc(hidden)]
-        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1::ffi"]
         type QTimerCxxQtSignalHandlertimeout =
             cxx_qt::signalhandler::CxxQtSignalHandler<super::QTimerCxxQtSignalClosuretimeout>;
         #[doc(hidden)]
-        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1::ffi"]
         #[cxx_name = "QTimer_timeoutConnect"]
         fn QTimer_connect_timeout(
             self_value: Pin<&mut QTimer>,
             signal_handler: QTimerCxxQtSignalHandlertimeout,
             conn_type: CxxQtConnectionType,
         ) -> CxxQtQMetaObjectConnection;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::my_object::rust::cxxqtgen1::ffi"]
+        #[cxx_name = "QTimer_timeoutConnectWithContext"]
+        fn QTimer_connect_timeout_with_context(
+            self_value: Pin<&mut QTimer>,
+            context: &CxxQtQObject,
+            signal_handler: QTimerCxxQtSignalHandlertimeout,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
     }
-    #[namespace = "cxx_qt::my_object::rust::cxxqtgen1"]
+    #[namespace = "cxx_qt::my_object::rust::cxxqtgen1::ffi"]
     extern "Rust" {
         #[doc(hidden)]
         fn drop_QTimer_signal_handler_timeout(handler: QTimerCxxQtSignalHandlertimeout);
@@ -200,6 +263,14 @@ mod ffi {
         );
     }
 }
+unsafe impl cxx_qt::Upcast<cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const cxx_qt::QObject {
+        ffi::cxx_qt_ffi_my_object_upcast_ptr_qobject(this)
+    }
+    unsafe fn from_base_ptr(base: *const cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_my_object_downcast_ptr_qobject(base)
+    }
+}
 impl ffi::MyObject {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "ready"]
@@ -237,6 +308,51 @@ impl ffi::MyObject {
         ))
     }
 }
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "ready"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "The connection is disconnected when the context object is destroyed."]
+    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+    pub unsafe fn connect_ready_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (context . with_context (| context | ffi :: MyObject_connect_ready_with_context (self , context , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosureready > :: new (Box :: new (closure)) , conn_type ,)) . unwrap_or_default ())
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "ready"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "See the safety section of the connect method with a context object."]
+    pub unsafe fn on_ready_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (context . with_context (| context | ffi :: MyObject_connect_ready_with_context (self , context , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosureready > :: new (Box :: new (closure)) , cxx_qt :: ConnectionType :: AutoConnection ,)) . unwrap_or_default ())
+    }
+}
 #[doc(hidden)]
 pub struct MyObjectCxxQtSignalClosureready {}
 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosureready {
@@ -312,6 +428,91 @@ impl ffi::MyObject {
         ))
     }
 }
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "dataChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "The connection is disconnected when the context object is destroyed."]
+    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+    pub unsafe fn connect_data_changed_with_context<
+        F: FnMut(
+                core::pin::Pin<&mut ffi::MyObject>,
+                i32,
+                cxx::UniquePtr<ffi::Opaque>,
+                ffi::QPoint,
+                &ffi::QPoint,
+            ) + Send
+            + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MyObject_connect_data_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MyObjectCxxQtSignalClosuredataChanged,
+                        >::new(Box::new(closure)),
+                        conn_type,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "dataChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "See the safety section of the connect method with a context object."]
+    pub unsafe fn on_data_changed_with_context<
+        F: FnMut(
+                core::pin::Pin<&mut ffi::MyObject>,
+                i32,
+                cxx::UniquePtr<ffi::Opaque>,
+                ffi::QPoint,
+                &ffi::QPoint,
+            ) + Send
+            + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MyObject_connect_data_changed_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MyObjectCxxQtSignalClosuredataChanged,
+                        >::new(Box::new(closure)),
+                        cxx_qt::ConnectionType::AutoConnection,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
 #[doc(hidden)]
 pub struct MyObjectCxxQtSignalClosuredataChanged {}
 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosuredataChanged {
@@ -398,6 +599,91 @@ impl ffi::MyObject {
         ))
     }
 }
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "newData"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "The connection is disconnected when the context object is destroyed."]
+    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+    pub unsafe fn connect_base_class_new_data_with_context<
+        F: FnMut(
+                core::pin::Pin<&mut ffi::MyObject>,
+                i32,
+                cxx::UniquePtr<ffi::Opaque>,
+                ffi::QPoint,
+                &'a ffi::QPoint,
+            ) + Send
+            + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MyObject_connect_base_class_new_data_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MyObjectCxxQtSignalClosurenewData,
+                        >::new(Box::new(closure)),
+                        conn_type,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "newData"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "See the safety section of the connect method with a context object."]
+    pub unsafe fn on_base_class_new_data_with_context<
+        F: FnMut(
+                core::pin::Pin<&mut ffi::MyObject>,
+                i32,
+                cxx::UniquePtr<ffi::Opaque>,
+                ffi::QPoint,
+                &'a ffi::QPoint,
+            ) + Send
+            + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            context
+                .with_context(|context| {
+                    ffi::MyObject_connect_base_class_new_data_with_context(
+                        self,
+                        context,
+                        cxx_qt::signalhandler::CxxQtSignalHandler::<
+                            MyObjectCxxQtSignalClosurenewData,
+                        >::new(Box::new(closure)),
+                        cxx_qt::ConnectionType::AutoConnection,
+                    )
+                })
+                .unwrap_or_default(),
+        )
+    }
+}
 #[doc(hidden)]
 pub struct MyObjectCxxQtSignalClosurenewData {}
 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosurenewData {
@@ -449,6 +735,8 @@ impl ::cxx_qt::CxxQtType for ffi::MyObject {
         ffi::cxx_qt_ffi_my_object_unsafe_rust_mut(self)
     }
 }
+#[doc(hidden)]
+fn cxx_qt_ffi_my_object_signature_1e899a677c4d4e9d() {}
 impl ffi::QTimer {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "timeout"]
@@ -486,11 +774,56 @@ impl ffi::QTimer {
         ))
     }
 }
+impl ffi::QTimer {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "timeout"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "The connection is disconnected when the context object is destroyed."]
+    #[doc = "The context can also be the CxxQtThread of a QObject, then no connection is made if the QObject has been destroyed."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "The function pointer is given the QObject which emitted the signal, when the context object lives in a different thread "]
+    #[doc = "the caller must ensure that the QObject is not accessed from the function pointer while it is used by its own thread, "]
+    #[doc = "and that the QObject is not destroyed while the function pointer is executing. "]
+    #[doc = "If the QObject is destroyed before a queued signal emission is delivered the function pointer is not executed."]
+    pub unsafe fn connect_timeout_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::QTimer>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::QTimer>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (context . with_context (| context | ffi :: QTimer_connect_timeout_with_context (self , context , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < QTimerCxxQtSignalClosuretimeout > :: new (Box :: new (closure)) , conn_type ,)) . unwrap_or_default ())
+    }
+}
+impl ffi::QTimer {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "timeout"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed in the thread of the context object."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[doc = "\n"]
+    #[doc = "# Safety"]
+    #[doc = "\n"]
+    #[doc = "See the safety section of the connect method with a context object."]
+    pub unsafe fn on_timeout_with_context<
+        F: FnMut(core::pin::Pin<&mut ffi::QTimer>) + Send + 'static,
+    >(
+        self: core::pin::Pin<&mut ffi::QTimer>,
+        context: &impl cxx_qt::ConnectionContext,
+        mut closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (context . with_context (| context | ffi :: QTimer_connect_timeout_with_context (self , context , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < QTimerCxxQtSignalClosuretimeout > :: new (Box :: new (closure)) , cxx_qt :: ConnectionType :: AutoConnection ,)) . unwrap_or_default ())
+    }
+}
 #[doc(hidden)]
 pub struct QTimerCxxQtSignalClosuretimeout {}
 impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for QTimerCxxQtSignalClosuretimeout {
     type Id =
-        cxx::type_id!("::cxx_qt::my_object::rust::cxxqtgen1::QTimerCxxQtSignalHandlertimeout");
+        cxx::type_id!("::cxx_qt::my_object::rust::cxxqtgen1::ffi::QTimerCxxQtSignalHandlertimeout");
     type FnType = dyn FnMut(core::pin::Pin<&mut ffi::QTimer>);
 }
 use core::mem::drop as drop_QTimer_signal_handler_timeout;
@@ -6,6 +6,58 @@
 use cxx_qt_build::CxxQtBuilder;
 use std::path::PathBuf;
 
+fn qt_bluetooth_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_QT_BLUETOOTH").is_ok()
+}
+
+fn qt_remoteobjects_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_QT_REMOTEOBJECTS").is_ok()
+}
+
+fn qt_quick_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_QT_QUICK").is_ok()
+}
+
+fn qt_multimedia_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_QT_MULTIMEDIA").is_ok()
+}
+
+fn qt_network_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_QT_NETWORK").is_ok()
+}
+
+fn qt_positioning_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_QT_POSITIONING").is_ok()
+}
+
+fn qt_serialport_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_QT_SERIALPORT").is_ok()
+}
+
+fn qt_websockets_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_QT_WEBSOCKETS").is_ok()
+}
+
+fn qt_dbus_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_QT_DBUS").is_ok()
+}
+
+fn qt_printsupport_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_QT_PRINTSUPPORT").is_ok()
+}
+
+fn qt_statemachine_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_QT_STATEMACHINE").is_ok()
+}
+
+fn qt_test_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_QT_TEST").is_ok()
+}
+
+fn qt_quicktest_enabled() -> bool {
+    std::env::var("CARGO_FEATURE_QT_QUICKTEST").is_ok()
+}
+
 fn header_dir() -> PathBuf {
     PathBuf::from(std::env::var("OUT_DIR").unwrap())
         .join("include")
@@ -37,6 +89,45 @@ fn write_headers() {
 
     write_headers_in("core");
     write_headers_in("gui");
+    if qt_bluetooth_enabled() {
+        write_headers_in("bluetooth");
+    }
+    if qt_remoteobjects_enabled() {
+        write_headers_in("remoteobjects");
+    }
+    if qt_quick_enabled() {
+        write_headers_in("quick");
+    }
+    if qt_multimedia_enabled() {
+        write_headers_in("multimedia");
+    }
+    if qt_network_enabled() {
+        write_headers_in("network");
+    }
+    if qt_positioning_enabled() {
+        write_headers_in("positioning");
+    }
+    if qt_serialport_enabled() {
+        write_headers_in("serialport");
+    }
+    if qt_websockets_enabled() {
+        write_headers_in("websockets");
+    }
+    if qt_dbus_enabled() {
+        write_headers_in("dbus");
+    }
+    if qt_printsupport_enabled() {
+        write_headers_in("printsupport");
+    }
+    if qt_statemachine_enabled() {
+        write_headers_in("statemachine");
+    }
+    if qt_test_enabled() {
+        write_headers_in("testlib");
+    }
+    if qt_quicktest_enabled() {
+        write_headers_in("quicktest");
+    }
 }
 
 fn main() {
@@ -52,24 +143,324 @@ fn main() {
         .qt_module("Gui")
         .qt_module("Widgets");
 
-    let rust_bridges = vec![
+    if qt_bluetooth_enabled() {
+        builder = builder.qt_module("Bluetooth");
+    }
+
+    if qt_remoteobjects_enabled() {
+        builder = builder.qt_module("RemoteObjects");
+    }
+
+    if qt_quick_enabled() {
+        builder = builder.qt_module("Qml").qt_module("Quick");
+    }
+
+    if qt_multimedia_enabled() {
+        // The multimedia bindings use the QMediaPlayer API of Qt 6
+        builder = builder.qt_module("Multimedia");
+    }
+
+    if qt_network_enabled() {
+        builder = builder.qt_module("Network");
+    }
+
+    if qt_positioning_enabled() {
+        builder = builder.qt_module("Positioning");
+    }
+
+    if qt_serialport_enabled() {
+        builder = builder.qt_module("SerialPort");
+    }
+
+    if qt_websockets_enabled() {
+        builder = builder.qt_module("Network").qt_module("WebSockets");
+    }
+
+    if qt_dbus_enabled() {
+        builder = builder
+            .qt_module("DBus")
+            .qobject_header("include/dbus/qdbussignalreceiver.h");
+    }
+
+    if qt_printsupport_enabled() {
+        builder = builder.qt_module("PrintSupport");
+    }
+
+    if qt_statemachine_enabled() {
+        // The state machine framework is a separate module since Qt 6
+        builder = builder.qt_module("StateMachine");
+    }
+
+    if qt_test_enabled() {
+        // Qml is needed to find the window of a QQmlApplicationEngine in QTest
+        builder = builder.qt_module("Qml").qt_module("Test");
+    }
+
+    if qt_quicktest_enabled() {
+        builder = builder.qt_module("QuickTest");
+    }
+
+    let mut rust_bridges = vec![
+        "core/qabstractanimation",
+        "core/qdeadlinetimer",
+        "core/qeasingcurve",
         "core/qelapsedtimer",
         "core/qcommandlineoption",
         "core/qcommandlineparser",
+        "core/qiodevice",
+        "core/qparallelanimationgroup",
+        "core/qpropertyanimation",
+        "core/qrandomgenerator",
+        "core/qstringlistmodel",
+        "core/qthread",
+        "gui/qaccessible",
         "gui/qapplication",
+        "gui/qcompleter",
+        "gui/qpagedpaintdevice",
+        "gui/qpdfwriter",
+        "gui/qsizepolicy",
+        "gui/qsyntaxhighlighter",
+        "gui/qtextcharformat",
+        "gui/qtextcursor",
+        "gui/qtextdocument",
+        "gui/qwindow",
     ];
 
+    if qt_bluetooth_enabled() {
+        rust_bridges.extend([
+            "bluetooth/qbluetoothdevicediscoveryagent",
+            "bluetooth/qbluetoothdeviceinfo",
+            "bluetooth/qbluetoothuuid",
+            "bluetooth/qlowenergycharacteristic",
+            "bluetooth/qlowenergycontroller",
+            "bluetooth/qlowenergyservice",
+        ]);
+    }
+
+    if qt_remoteobjects_enabled() {
+        rust_bridges.extend([
+            "remoteobjects/qremoteobjectdynamicreplica",
+            "remoteobjects/qremoteobjecthost",
+            "remoteobjects/qremoteobjectnode",
+        ]);
+    }
+
+    if qt_quick_enabled() {
+        rust_bridges.extend([
+            "quick/qquickframebufferobject",
+            "quick/qquickimageprovider",
+            "quick/qquickitem",
+            "quick/qquicknativetextureitem",
+            "quick/qquicktextdocument",
+            "quick/qquickwindow",
+        ]);
+    }
+
+    if qt_multimedia_enabled() {
+        rust_bridges.extend([
+            "multimedia/qaudio",
+            "multimedia/qaudiodevice",
+            "multimedia/qaudioformat",
+            "multimedia/qaudiooutput",
+            "multimedia/qaudiosink",
+            "multimedia/qaudiosource",
+            "multimedia/qmediadevices",
+            "multimedia/qmediaplayer",
+        ]);
+    }
+
+    if qt_network_enabled() {
+        rust_bridges.extend([
+            "network/qhostaddress",
+            "network/qhostinfo",
+            "network/qnetworkinformation",
+            "network/qssl",
+            "network/qsslcertificate",
+            "network/qsslconfiguration",
+            "network/qsslkey",
+        ]);
+    }
+
+    if qt_positioning_enabled() {
+        rust_bridges.extend([
+            "positioning/qgeocoordinate",
+            "positioning/qgeopositioninfo",
+            "positioning/qgeopositioninfosource",
+            "positioning/qgeorectangle",
+        ]);
+    }
+
+    if qt_serialport_enabled() {
+        rust_bridges.extend(["serialport/qserialport", "serialport/qserialportinfo"]);
+    }
+
+    if qt_websockets_enabled() {
+        rust_bridges.extend([
+            "websockets/qabstractsocket",
+            "websockets/qwebsocket",
+            "websockets/qwebsocketprotocol",
+            "websockets/qwebsocketserver",
+        ]);
+    }
+
+    if qt_dbus_enabled() {
+        rust_bridges.extend([
+            "dbus/qdbusconnection",
+            "dbus/qdbusinterface",
+            "dbus/qdbusmessage",
+        ]);
+    }
+
+    if qt_printsupport_enabled() {
+        rust_bridges.extend(["printsupport/qprinter"]);
+    }
+
+    if qt_statemachine_enabled() {
+        rust_bridges.extend([
+            "statemachine/qfinalstate",
+            "statemachine/qstate",
+            "statemachine/qstatemachine",
+        ]);
+    }
+
+    if qt_test_enabled() {
+        rust_bridges.extend([
+            "testlib/qabstractitemmodeltester",
+            "testlib/qsignalspy",
+            "testlib/qtest",
+            "testlib/qtestapplication",
+        ]);
+    }
+
+    if qt_quicktest_enabled() {
+        rust_bridges.extend(["quicktest/qquicktest"]);
+    }
+
     for rust_source in &rust_bridges {
         builder = builder.file(format!("src/{rust_source}.rs"));
     }
 
-    let cpp_files = vec![
+    let mut cpp_files = vec![
+        "core/qdeadlinetimer",
+        "core/qeasingcurve",
         "core/qelapsedtimer",
         "core/qcommandlineoption",
         "core/qcommandlineparser",
+        "core/qiodevice",
+        "core/qparallelanimationgroup",
+        "core/qpropertyanimation",
+        "core/qrandomgenerator",
+        "core/qstringlistmodel",
+        "core/qthread",
+        "gui/qaccessible",
         "gui/qapplication",
+        "gui/qcompleter",
+        "gui/qpdfwriter",
+        "gui/qsizepolicy",
+        "gui/qtextcharformat",
+        "gui/qtextcursor",
+        "gui/qtextdocument",
     ];
 
+    if qt_bluetooth_enabled() {
+        cpp_files.extend([
+            "bluetooth/qbluetoothdevicediscoveryagent",
+            "bluetooth/qbluetoothdeviceinfo",
+            "bluetooth/qbluetoothuuid",
+            "bluetooth/qlowenergycontroller",
+            "bluetooth/qlowenergyservice",
+        ]);
+    }
+
+    if qt_remoteobjects_enabled() {
+        cpp_files.extend([
+            "remoteobjects/qremoteobjecthost",
+            "remoteobjects/qremoteobjectnode",
+        ]);
+    }
+
+    if qt_quick_enabled() {
+        cpp_files.extend([
+            "quick/qquickframebufferobject",
+            "quick/qquickitem",
+            "quick/qquicknativetextureitem",
+            "quick/qquickwindow",
+        ]);
+    }
+
+    if qt_multimedia_enabled() {
+        cpp_files.extend([
+            "multimedia/qaudiodevice",
+            "multimedia/qaudioformat",
+            "multimedia/qaudiooutput",
+            "multimedia/qaudiosink",
+            "multimedia/qaudiosource",
+            "multimedia/qmediadevices",
+            "multimedia/qmediaplayer",
+        ]);
+    }
+
+    if qt_network_enabled() {
+        cpp_files.extend([
+            "network/qhostaddress",
+            "network/qhostinfo",
+            "network/qnetworkinformation",
+            "network/qsslcertificate",
+            "network/qsslconfiguration",
+            "network/qsslkey",
+        ]);
+    }
+
+    if qt_positioning_enabled() {
+        cpp_files.extend([
+            "positioning/qgeocoordinate",
+            "positioning/qgeopositioninfo",
+            "positioning/qgeopositioninfosource",
+            "positioning/qgeorectangle",
+        ]);
+    }
+
+    if qt_serialport_enabled() {
+        cpp_files.extend(["serialport/qserialport", "serialport/qserialportinfo"]);
+    }
+
+    if qt_websockets_enabled() {
+        cpp_files.extend(["websockets/qwebsocket", "websockets/qwebsocketserver"]);
+    }
+
+    if qt_dbus_enabled() {
+        cpp_files.extend([
+            "dbus/qdbusconnection",
+            "dbus/qdbusinterface",
+            "dbus/qdbusmessage",
+        ]);
+    }
+
+    if qt_printsupport_enabled() {
+        cpp_files.extend(["printsupport/qprinter"]);
+    }
+
+    if qt_statemachine_enabled() {
+        cpp_files.extend([
+            "statemachine/qfinalstate",
+            "statemachine/qstate",
+            "statemachine/qstatemachine",
+        ]);
+    }
+
+    if qt_test_enabled() {
+        cpp_files.extend([
+            "testlib/qabstractitemmodeltester",
+            "testlib/qsignalspy",
+            "testlib/qtest",
+            "testlib/qtestapplication",
+        ]);
+    }
+
+    if qt_quicktest_enabled() {
+        cpp_files.extend(["quicktest/qquicktest"]);
+    }
+
     builder = builder.cc_builder(move |cc| {
         for cpp_file in &cpp_files {
             cc.file(format!("src/{cpp_file}.cpp"));
@@ -0,0 +1,65 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+#include <vector>
+
+#include <QtBluetooth/QBluetoothDeviceDiscoveryAgent>
+
+#include <cxx-qt/connection.h>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QBluetoothDeviceDiscoveryAgentDiscoveryMethod =
+  QBluetoothDeviceDiscoveryAgent::DiscoveryMethod;
+using QBluetoothDeviceDiscoveryAgentError =
+  QBluetoothDeviceDiscoveryAgent::Error;
+
+::std::unique_ptr<QBluetoothDeviceDiscoveryAgent>
+qbluetoothdevicediscoveryagentNew();
+
+::std::unique_ptr<::std::vector<QBluetoothDeviceInfo>>
+qbluetoothdevicediscoveryagentDiscoveredDevices(
+  const QBluetoothDeviceDiscoveryAgent& agent);
+
+template<typename A>
+::QMetaObject::Connection
+qbluetoothdevicediscoveryagentOnErrorOccurred(
+  QBluetoothDeviceDiscoveryAgent& agent,
+  ::rust::Fn<void(A&,
+                  QBluetoothDeviceDiscoveryAgent&,
+                  QBluetoothDeviceDiscoveryAgentError)> func,
+  ::rust::Box<A> arg)
+{
+  // Qt requires the functor to be copyable so share the box
+  auto shared = ::std::make_shared<::rust::Box<A>>(::std::move(arg));
+  return QObject::connect(
+    &agent,
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+    &QBluetoothDeviceDiscoveryAgent::errorOccurred,
+#else
+    // In Qt 5 the signal is named error, which is overloaded with the getter
+    QOverload<QBluetoothDeviceDiscoveryAgentError>::of(
+      &QBluetoothDeviceDiscoveryAgent::error),
+#endif
+    &agent,
+    [&agent, func = ::std::move(func), shared](
+      QBluetoothDeviceDiscoveryAgentError error) {
+      func(**shared, agent, error);
+    });
+}
+
+void
+qbluetoothdevicediscoveryagentStartWithMethod(
+  QBluetoothDeviceDiscoveryAgent& agent,
+  QBluetoothDeviceDiscoveryAgentDiscoveryMethod method);
+
+}
+}
@@ -0,0 +1,31 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+#include <vector>
+
+#include <QtBluetooth/QBluetoothDeviceInfo>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QBluetoothDeviceInfoCoreConfiguration =
+  QBluetoothDeviceInfo::CoreConfiguration;
+
+QString
+qbluetoothdeviceinfoAddress(const QBluetoothDeviceInfo& info);
+
+QBluetoothDeviceInfoCoreConfiguration
+qbluetoothdeviceinfoCoreConfigurations(const QBluetoothDeviceInfo& info);
+
+::std::unique_ptr<::std::vector<QBluetoothUuid>>
+qbluetoothdeviceinfoServiceUuids(const QBluetoothDeviceInfo& info);
+
+}
+}
@@ -0,0 +1,32 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtBluetooth/QBluetoothUuid>
+#include <QtCore/QString>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QBluetoothUuid> : ::std::true_type
+{
+};
+
+namespace cxxqtlib1 {
+
+QBluetoothUuid
+qbluetoothuuidFromQString(const QString& uuid);
+
+QString
+qbluetoothuuidToQString(const QBluetoothUuid& uuid);
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,58 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+#include <vector>
+
+#include <QtBluetooth/QLowEnergyController>
+#include <QtBluetooth/QLowEnergyService>
+
+#include <cxx-qt/connection.h>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QLowEnergyControllerControllerState =
+  QLowEnergyController::ControllerState;
+using QLowEnergyControllerError = QLowEnergyController::Error;
+
+::std::unique_ptr<QLowEnergyController>
+qlowenergycontrollerCreateCentral(const QBluetoothDeviceInfo& remoteDevice);
+
+::std::unique_ptr<QLowEnergyService>
+qlowenergycontrollerCreateServiceObject(QLowEnergyController& controller,
+                                        const QBluetoothUuid& serviceUuid);
+
+template<typename A>
+::QMetaObject::Connection
+qlowenergycontrollerOnErrorOccurred(
+  QLowEnergyController& controller,
+  ::rust::Fn<void(A&, QLowEnergyController&, QLowEnergyControllerError)> func,
+  ::rust::Box<A> arg)
+{
+  // Qt requires the functor to be copyable so share the box
+  auto shared = ::std::make_shared<::rust::Box<A>>(::std::move(arg));
+  return QObject::connect(
+    &controller,
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+    &QLowEnergyController::errorOccurred,
+#else
+    // In Qt 5 the signal is named error, which is overloaded with the getter
+    QOverload<QLowEnergyControllerError>::of(&QLowEnergyController::error),
+#endif
+    &controller,
+    [&controller, func = ::std::move(func), shared](
+      QLowEnergyControllerError error) { func(**shared, controller, error); });
+}
+
+::std::unique_ptr<::std::vector<QBluetoothUuid>>
+qlowenergycontrollerServices(const QLowEnergyController& controller);
+
+}
+}
@@ -0,0 +1,98 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+#include <vector>
+
+#include <QtBluetooth/QLowEnergyService>
+#include <QtCore/QByteArray>
+
+#include <cxx-qt/connection.h>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QLowEnergyServiceServiceError = QLowEnergyService::ServiceError;
+using QLowEnergyServiceWriteMode = QLowEnergyService::WriteMode;
+
+::std::unique_ptr<QLowEnergyCharacteristic>
+qlowenergyserviceCharacteristic(const QLowEnergyService& service,
+                                const QBluetoothUuid& uuid);
+
+::std::unique_ptr<::std::vector<QLowEnergyCharacteristic>>
+qlowenergyserviceCharacteristics(const QLowEnergyService& service);
+
+void
+qlowenergyserviceDiscoverDetails(QLowEnergyService& service);
+
+template<typename A>
+::QMetaObject::Connection
+qlowenergyserviceOnErrorOccurred(
+  QLowEnergyService& service,
+  ::rust::Fn<void(A&, QLowEnergyService&, QLowEnergyServiceServiceError)> func,
+  ::rust::Box<A> arg)
+{
+  // Qt requires the functor to be copyable so share the box
+  auto shared = ::std::make_shared<::rust::Box<A>>(::std::move(arg));
+  return QObject::connect(
+    &service,
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+    &QLowEnergyService::errorOccurred,
+#else
+    // In Qt 5 the signal is named error, which is overloaded with the getter
+    QOverload<QLowEnergyServiceServiceError>::of(&QLowEnergyService::error),
+#endif
+    &service,
+    [&service, func = ::std::move(func), shared](
+      QLowEnergyServiceServiceError error) { func(**shared, service, error); });
+}
+
+// The names of the service states differ between Qt 5 and Qt 6, so the state
+// is converted to an enum from the bridge which is deduced here.
+template<typename A, typename S>
+::QMetaObject::Connection
+qlowenergyserviceOnStateChanged(
+  QLowEnergyService& service,
+  ::rust::Fn<void(A&, QLowEnergyService&, S)> func,
+  ::rust::Box<A> arg)
+{
+  // Qt requires the functor to be copyable so share the box
+  auto shared = ::std::make_shared<::rust::Box<A>>(::std::move(arg));
+  return QObject::connect(
+    &service,
+    &QLowEnergyService::stateChanged,
+    &service,
+    [&service, func = ::std::move(func), shared](
+      QLowEnergyService::ServiceState state) {
+      func(**shared, service, static_cast<S>(state));
+    });
+}
+
+bool
+qlowenergyserviceSetNotificationsEnabled(
+  QLowEnergyService& service,
+  const QLowEnergyCharacteristic& characteristic,
+  bool enabled);
+
+template<typename S>
+S
+qlowenergyserviceState(const QLowEnergyService& service)
+{
+  return static_cast<S>(service.state());
+}
+
+void
+qlowenergyserviceWriteCharacteristic(
+  QLowEnergyService& service,
+  const QLowEnergyCharacteristic& characteristic,
+  const QByteArray& value,
+  QLowEnergyServiceWriteMode mode);
+
+}
+}
@@ -0,0 +1,54 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QDeadlineTimer>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QDeadlineTimer
+qdeadlinetimerCurrent(Qt::TimerType timerType);
+
+::std::int64_t
+qdeadlinetimerDeadline(const QDeadlineTimer& deadlineTimer);
+
+::std::int64_t
+qdeadlinetimerDeadlineNSecs(const QDeadlineTimer& deadlineTimer);
+
+QDeadlineTimer
+qdeadlinetimerForever(Qt::TimerType timerType);
+
+QDeadlineTimer
+qdeadlinetimerInit(::std::int64_t msecs, Qt::TimerType timerType);
+
+::std::int64_t
+qdeadlinetimerRemainingTime(const QDeadlineTimer& deadlineTimer);
+
+::std::int64_t
+qdeadlinetimerRemainingTimeNSecs(const QDeadlineTimer& deadlineTimer);
+
+void
+qdeadlinetimerSetDeadline(QDeadlineTimer& deadlineTimer,
+                          ::std::int64_t msecs,
+                          Qt::TimerType timerType);
+
+void
+qdeadlinetimerSetPreciseRemainingTime(QDeadlineTimer& deadlineTimer,
+                                      ::std::int64_t secs,
+                                      ::std::int64_t nsecs,
+                                      Qt::TimerType timerType);
+
+void
+qdeadlinetimerSetRemainingTime(QDeadlineTimer& deadlineTimer,
+                               ::std::int64_t msecs,
+                               Qt::TimerType timerType);
+
+}
+}
@@ -12,8 +12,32 @@
 
 namespace rust {
 namespace cxxqtlib1 {
+::std::int64_t
+qelapsedtimerElapsed(const QElapsedTimer& elapsedTimer);
+
+bool
+qelapsedtimerHasExpired(const QElapsedTimer& elapsedTimer,
+                        ::std::int64_t timeout);
+
+bool
+qelapsedtimerIsMonotonic();
+
+::std::int64_t
+qelapsedtimerMsecsSinceReference(const QElapsedTimer& elapsedTimer);
+
+::std::int64_t
+qelapsedtimerMsecsTo(const QElapsedTimer& elapsedTimer,
+                     const QElapsedTimer& other);
+
+::std::int64_t
+qelapsedtimerNsecsElapsed(const QElapsedTimer& elapsedTimer);
+
 ::std::int64_t
 qelapsedtimerRestart(QElapsedTimer& elapsedTimer);
 
+::std::int64_t
+qelapsedtimerSecsTo(const QElapsedTimer& elapsedTimer,
+                    const QElapsedTimer& other);
+
 }
 }
@@ -0,0 +1,30 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QByteArray>
+#include <QtCore/QIODevice>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::int64_t
+qiodeviceBytesAvailable(const QIODevice& device);
+
+::std::int64_t
+qiodeviceRead(QIODevice& device, ::rust::Slice<::std::uint8_t> data);
+
+QByteArray
+qiodeviceReadAll(QIODevice& device);
+
+::std::int64_t
+qiodeviceWrite(QIODevice& device, ::rust::Slice<const ::std::uint8_t> data);
+
+}
+}
@@ -0,0 +1,37 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QParallelAnimationGroup>
+#include <QtCore/QPropertyAnimation>
+
+#include "cxx-qt-lib-extras/qabstractanimation.h"
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qparallelanimationgroupAddAnimation(
+  QParallelAnimationGroup& group,
+  ::std::unique_ptr<QPropertyAnimation> animation);
+
+void
+qparallelanimationgroupAddGroup(
+  QParallelAnimationGroup& group,
+  ::std::unique_ptr<QParallelAnimationGroup> animation);
+
+::std::unique_ptr<QParallelAnimationGroup>
+qparallelanimationgroupNew();
+
+void
+qparallelanimationgroupStart(QParallelAnimationGroup& group);
+
+}
+}
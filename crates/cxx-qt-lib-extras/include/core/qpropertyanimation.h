@@ -0,0 +1,36 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QObject>
+#include <QtCore/QPropertyAnimation>
+
+#include "cxx-qt-lib-extras/qabstractanimation.h"
+#include "cxx-qt-lib-extras/qeasingcurve.h"
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QEasingCurveType
+qpropertyanimationEasingCurveType(const QPropertyAnimation& animation);
+
+::std::unique_ptr<QPropertyAnimation>
+qpropertyanimationNew(QObject* target, const QByteArray& propertyName);
+
+void
+qpropertyanimationSetEasingCurveType(QPropertyAnimation& animation,
+                                     QEasingCurveType type);
+
+void
+qpropertyanimationStart(QPropertyAnimation& animation);
+
+}
+}
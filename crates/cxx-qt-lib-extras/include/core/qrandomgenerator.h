@@ -0,0 +1,66 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cinttypes>
+#include <memory>
+
+#include <QtCore/QRandomGenerator>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+double
+qrandomgeneratorBoundedF64(const QRandomGenerator& generator, double highest);
+
+::std::int32_t
+qrandomgeneratorBoundedI32(const QRandomGenerator& generator,
+                           ::std::int32_t lowest,
+                           ::std::int32_t highest);
+
+::std::uint32_t
+qrandomgeneratorBoundedU32(const QRandomGenerator& generator,
+                           ::std::uint32_t highest);
+
+::std::unique_ptr<QRandomGenerator>
+qrandomgeneratorClone(const QRandomGenerator& generator);
+
+void
+qrandomgeneratorDiscard(const QRandomGenerator& generator, ::std::uint64_t z);
+
+void
+qrandomgeneratorFillRange(const QRandomGenerator& generator,
+                          ::rust::Slice<::std::uint32_t> buffer);
+
+::std::uint32_t
+qrandomgeneratorGenerate(const QRandomGenerator& generator);
+
+::std::uint64_t
+qrandomgeneratorGenerate64(const QRandomGenerator& generator);
+
+double
+qrandomgeneratorGenerateDouble(const QRandomGenerator& generator);
+
+const QRandomGenerator&
+qrandomgeneratorGlobal();
+
+::std::unique_ptr<QRandomGenerator>
+qrandomgeneratorNew(::std::uint32_t seed);
+
+::std::unique_ptr<QRandomGenerator>
+qrandomgeneratorNewFromSlice(::rust::Slice<const ::std::uint32_t> seeds);
+
+void
+qrandomgeneratorSeed(const QRandomGenerator& generator, ::std::uint32_t seed);
+
+const QRandomGenerator&
+qrandomgeneratorSystem();
+
+}
+}
@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QObject>
+#include <QtCore/QStringList>
+#include <QtCore/QStringListModel>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QObject&
+qstringlistmodelAsQObject(QStringListModel& model);
+
+::std::unique_ptr<QStringListModel>
+qstringlistmodelNew();
+
+::std::unique_ptr<QStringListModel>
+qstringlistmodelNewWithStringList(const QStringList& strings);
+
+}
+}
@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QThread>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QThread>
+qthreadNew();
+
+void
+qthreadStart(QThread& thread);
+
+bool
+qthreadWait(QThread& thread);
+
+// This is a template so that it can be declared in a bridge for any QObject,
+// the worker is given as an out parameter so that the type can be deduced
+template<typename T>
+void
+qthreadNewWorker(QThread& thread, ::std::unique_ptr<T>& worker)
+{
+  worker = ::std::make_unique<T>();
+  worker->moveToThread(&thread);
+}
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,46 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QObject>
+#include <QtCore/QString>
+#include <QtDBus/QDBusConnection>
+#include <QtDBus/QDBusMessage>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QDBusConnectionRegisterOption = QDBusConnection::RegisterOption;
+
+::std::unique_ptr<QDBusMessage>
+qdbusconnectionCall(const QDBusConnection& connection,
+                    const QDBusMessage& message);
+
+QString
+qdbusconnectionLastErrorMessage(const QDBusConnection& connection);
+
+bool
+qdbusconnectionRegisterObject(QDBusConnection& connection,
+                              const QString& path,
+                              QObject* object,
+                              QDBusConnectionRegisterOption options);
+
+::std::unique_ptr<QDBusConnection>
+qdbusconnectionSessionBus();
+
+::std::unique_ptr<QDBusConnection>
+qdbusconnectionSystemBus();
+
+void
+qdbusconnectionUnregisterObject(QDBusConnection& connection,
+                                const QString& path);
+
+}
+}
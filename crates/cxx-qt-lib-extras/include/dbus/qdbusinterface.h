@@ -0,0 +1,68 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QList>
+#include <QtCore/QString>
+#include <QtCore/QVariant>
+#include <QtDBus/QDBusConnection>
+#include <QtDBus/QDBusInterface>
+#include <QtDBus/QDBusMessage>
+
+#include "cxx-qt-lib-extras/qdbussignalreceiver.h"
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QDBusMessage>
+qdbusinterfaceCall(QDBusInterface& interface,
+                   const QString& method,
+                   const QList<QVariant>& arguments);
+
+QString
+qdbusinterfaceLastErrorMessage(const QDBusInterface& interface);
+
+::std::unique_ptr<QDBusInterface>
+qdbusinterfaceNew(const QString& service,
+                  const QString& path,
+                  const QString& interface,
+                  const QDBusConnection& connection);
+
+template<typename A>
+bool
+qdbusinterfaceOnSignal(QDBusInterface& interface,
+                       const QString& name,
+                       ::rust::Fn<void(A&, const QDBusMessage&)> func,
+                       ::rust::Box<A> arg)
+{
+  // The handler must be copyable so share the box
+  auto shared = ::std::make_shared<::rust::Box<A>>(::std::move(arg));
+  // The receiver is a child of the interface, so the connection is removed
+  // when the interface is destroyed
+  auto* receiver = new QDBusSignalReceiver(
+    [func = ::std::move(func), shared](const QDBusMessage& message) {
+      func(**shared, message);
+    },
+    &interface);
+  const bool connected =
+    interface.connection().connect(interface.service(),
+                                   interface.path(),
+                                   interface.interface(),
+                                   name,
+                                   receiver,
+                                   SLOT(handleSignal(QDBusMessage)));
+  if (!connected) {
+    delete receiver;
+  }
+  return connected;
+}
+
+}
+}
@@ -0,0 +1,32 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QString>
+#include <QtDBus/QDBusMessage>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QDBusMessageMessageType = QDBusMessage::MessageType;
+
+::std::unique_ptr<QDBusMessage>
+qdbusmessageCreateMethodCall(const QString& service,
+                             const QString& path,
+                             const QString& interface,
+                             const QString& method);
+
+::std::unique_ptr<QDBusMessage>
+qdbusmessageCreateSignal(const QString& path,
+                         const QString& interface,
+                         const QString& name);
+
+}
+}
@@ -0,0 +1,39 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <functional>
+
+#include <QtCore/QObject>
+#include <QtDBus/QDBusMessage>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// QDBusConnection::connect requires a slot of a QObject to deliver signals to,
+// so this forwards the messages of a remote signal to a handler
+class QDBusSignalReceiver : public QObject
+{
+  Q_OBJECT
+
+public:
+  QDBusSignalReceiver(::std::function<void(const QDBusMessage&)> handler,
+                      QObject* parent)
+    : QObject(parent)
+    , m_handler(::std::move(handler))
+  {
+  }
+
+public Q_SLOTS:
+  void handleSignal(const QDBusMessage& message) { m_handler(message); }
+
+private:
+  ::std::function<void(const QDBusMessage&)> m_handler;
+};
+
+}
+}
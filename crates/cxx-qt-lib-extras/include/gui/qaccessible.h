@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QObject>
+#include <QtCore/QVariant>
+#include <QtGui/QAccessible>
+
+namespace rust {
+namespace cxxqtlib1 {
+using QAccessibleEventType = QAccessible::Event;
+using QAccessibleRole = QAccessible::Role;
+
+bool
+qaccessibleIsActive();
+
+void
+qaccessibleUpdateAccessibility(QObject& object, QAccessibleEventType event);
+
+void
+qaccessibleUpdateAccessibilityValue(QObject& object, const QVariant& value);
+
+}
+}
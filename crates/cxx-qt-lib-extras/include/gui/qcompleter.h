@@ -0,0 +1,41 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QObject>
+#include <QtCore/QStringList>
+#include <QtWidgets/QCompleter>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QCompleterCompletionMode = QCompleter::CompletionMode;
+using QCompleterModelSorting = QCompleter::ModelSorting;
+
+QObject*
+qcompleterCompletionModel(const QCompleter& completer);
+
+::std::unique_ptr<QCompleter>
+qcompleterNew();
+
+::std::unique_ptr<QCompleter>
+qcompleterNewWithStringList(const QStringList& list);
+
+// This is a template so that it can be declared in a bridge for any model,
+// as there is no upcasting from a Rust QObject to QAbstractItemModel yet
+template<typename T>
+void
+qcompleterSetModel(QCompleter& completer, T& model)
+{
+  completer.setModel(&model);
+}
+
+} // namespace cxxqtlib1
+} // namespace rust
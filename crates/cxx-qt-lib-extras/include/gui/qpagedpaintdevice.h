@@ -0,0 +1,21 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QPageLayout>
+#include <QtGui/QPageSize>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QPageLayoutOrientation = QPageLayout::Orientation;
+using QPageLayoutUnit = QPageLayout::Unit;
+using QPageSizeId = QPageSize::PageSizeId;
+
+}
+}
@@ -0,0 +1,30 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtGui/QPainter>
+#include <QtGui/QPdfWriter>
+
+#include "cxx-qt-lib-extras/qpagedpaintdevice.h"
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QPdfWriter>
+qpdfwriterNew(const QString& filename);
+
+::std::unique_ptr<QPainter>
+qpdfwriterNewPainter(QPdfWriter& writer);
+
+bool
+qpdfwriterSetPageSize(QPdfWriter& writer, QPageSizeId pageSizeId);
+
+}
+}
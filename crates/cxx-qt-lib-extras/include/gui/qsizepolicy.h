@@ -0,0 +1,26 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QVariant>
+#include <QtWidgets/QSizePolicy>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QSizePolicyControlType = QSizePolicy::ControlType;
+using QSizePolicyPolicy = QSizePolicy::Policy;
+
+namespace qvariant {
+
+bool
+qvariantCanConvertQSizePolicy(const QVariant& variant);
+
+} // namespace qvariant
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,74 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QSyntaxHighlighter>
+#include <QtGui/QTextCharFormat>
+#include <QtGui/QTextDocument>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// A syntax highlighter which exposes the protected methods used while
+// highlighting a block, so that they can be called from Rust
+class QSyntaxHighlighterRust : public QSyntaxHighlighter
+{
+public:
+  explicit QSyntaxHighlighterRust(QTextDocument* parent)
+    : QSyntaxHighlighter(parent)
+  {
+  }
+
+  using QSyntaxHighlighter::currentBlockState;
+  using QSyntaxHighlighter::format;
+  using QSyntaxHighlighter::previousBlockState;
+  using QSyntaxHighlighter::setCurrentBlockState;
+  using QSyntaxHighlighter::setFormat;
+};
+
+// A syntax highlighter which calls a Rust closure to highlight each block
+template<typename A>
+class QSyntaxHighlighterRustClosure : public QSyntaxHighlighterRust
+{
+public:
+  QSyntaxHighlighterRustClosure(
+    QTextDocument* parent,
+    ::rust::Fn<void(A&, QSyntaxHighlighterRust&, const QString&)> func,
+    ::rust::Box<A> arg)
+    : QSyntaxHighlighterRust(parent)
+    , m_func(::std::move(func))
+    , m_arg(::std::move(arg))
+  {
+  }
+
+protected:
+  void highlightBlock(const QString& text) override
+  {
+    m_func(*m_arg, *this, text);
+  }
+
+private:
+  ::rust::Fn<void(A&, QSyntaxHighlighterRust&, const QString&)> m_func;
+  ::rust::Box<A> m_arg;
+};
+
+template<typename A>
+QSyntaxHighlighterRust*
+qsyntaxhighlighterNew(
+  QTextDocument& document,
+  ::rust::Fn<void(A&, QSyntaxHighlighterRust&, const QString&)> func,
+  ::rust::Box<A> arg)
+{
+  // The document takes ownership of the highlighter
+  return new QSyntaxHighlighterRustClosure<A>(
+    &document, ::std::move(func), ::std::move(arg));
+}
+
+}
+}
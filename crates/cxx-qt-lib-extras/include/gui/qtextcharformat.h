@@ -0,0 +1,39 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QColor>
+#include <QtGui/QTextCharFormat>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QTextCharFormat> : ::std::true_type
+{
+};
+
+namespace cxxqtlib1 {
+using QTextCharFormatUnderlineStyle = QTextCharFormat::UnderlineStyle;
+
+QColor
+qtextcharformatBackgroundColor(const QTextCharFormat& format);
+
+QColor
+qtextcharformatForegroundColor(const QTextCharFormat& format);
+
+void
+qtextcharformatSetBackgroundColor(QTextCharFormat& format, const QColor& color);
+
+void
+qtextcharformatSetForegroundColor(QTextCharFormat& format, const QColor& color);
+
+} // namespace cxxqtlib1
+} // namespace rust
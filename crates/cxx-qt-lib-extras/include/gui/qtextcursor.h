@@ -0,0 +1,32 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QTextCursor>
+#include <QtGui/QTextDocument>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QTextCursor> : ::std::true_type
+{
+};
+
+namespace cxxqtlib1 {
+using QTextCursorMoveMode = QTextCursor::MoveMode;
+using QTextCursorMoveOperation = QTextCursor::MoveOperation;
+using QTextCursorSelectionType = QTextCursor::SelectionType;
+
+QTextCursor
+qtextcursorInitFromQTextDocument(QTextDocument& document);
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,39 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtGui/QTextCursor>
+#include <QtGui/QTextDocument>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QTextDocumentFindFlag = QTextDocument::FindFlag;
+
+QTextCursor
+qtextdocumentFind(const QTextDocument& document,
+                  const QString& subString,
+                  const QTextCursor& cursor,
+                  QTextDocumentFindFlag options);
+
+::std::unique_ptr<QTextDocument>
+qtextdocumentNew(const QString& text);
+
+void
+qtextdocumentSetMarkdown(QTextDocument& document, const QString& markdown);
+
+QString
+qtextdocumentToHtml(const QTextDocument& document);
+
+QString
+qtextdocumentToMarkdown(const QTextDocument& document);
+
+}
+}
@@ -0,0 +1,25 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtMultimedia/QAudioFormat>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QAudioFormatSampleFormat = QAudioFormat::SampleFormat;
+
+::std::int32_t
+qaudioformatBytesForDuration(const QAudioFormat& format,
+                             ::std::int64_t microseconds);
+
+::std::int64_t
+qaudioformatDurationForBytes(const QAudioFormat& format, ::std::int32_t bytes);
+
+}
+}
@@ -0,0 +1,43 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QIODevice>
+#include <QtMultimedia/QAudioDevice>
+#include <QtMultimedia/QAudioFormat>
+#include <QtMultimedia/QAudioSink>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QAudioSink>
+qaudiosinkNew(const QAudioDevice& device, const QAudioFormat& format);
+
+::rust::isize
+qaudiosinkBufferSize(const QAudioSink& sink);
+
+::rust::isize
+qaudiosinkBytesFree(const QAudioSink& sink);
+
+::std::int64_t
+qaudiosinkElapsedUSecs(const QAudioSink& sink);
+
+::std::int64_t
+qaudiosinkProcessedUSecs(const QAudioSink& sink);
+
+void
+qaudiosinkSetBufferSize(QAudioSink& sink, ::rust::isize bytes);
+
+QIODevice*
+qaudiosinkStart(QAudioSink& sink);
+
+}
+}
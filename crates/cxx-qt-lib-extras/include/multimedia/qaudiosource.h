@@ -0,0 +1,43 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QIODevice>
+#include <QtMultimedia/QAudioDevice>
+#include <QtMultimedia/QAudioFormat>
+#include <QtMultimedia/QAudioSource>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QAudioSource>
+qaudiosourceNew(const QAudioDevice& device, const QAudioFormat& format);
+
+::rust::isize
+qaudiosourceBufferSize(const QAudioSource& source);
+
+::rust::isize
+qaudiosourceBytesAvailable(const QAudioSource& source);
+
+::std::int64_t
+qaudiosourceElapsedUSecs(const QAudioSource& source);
+
+::std::int64_t
+qaudiosourceProcessedUSecs(const QAudioSource& source);
+
+void
+qaudiosourceSetBufferSize(QAudioSource& source, ::rust::isize bytes);
+
+QIODevice*
+qaudiosourceStart(QAudioSource& source);
+
+}
+}
@@ -0,0 +1,36 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+#include <vector>
+
+#include <QtMultimedia/QAudioDevice>
+#include <QtMultimedia/QMediaDevices>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QMediaDevices>
+qmediadevicesNew();
+
+::std::unique_ptr<::std::vector<QAudioDevice>>
+qmediadevicesAudioInputs();
+
+::std::unique_ptr<::std::vector<QAudioDevice>>
+qmediadevicesAudioOutputs();
+
+QAudioDevice
+qmediadevicesDefaultAudioInput();
+
+QAudioDevice
+qmediadevicesDefaultAudioOutput();
+
+}
+}
@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtMultimedia/QAudioOutput>
+#include <QtMultimedia/QMediaPlayer>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QMediaPlayerError = QMediaPlayer::Error;
+using QMediaPlayerMediaStatus = QMediaPlayer::MediaStatus;
+using QMediaPlayerPlaybackState = QMediaPlayer::PlaybackState;
+
+::std::unique_ptr<QMediaPlayer>
+qmediaplayerNew();
+
+::std::int64_t
+qmediaplayerDuration(const QMediaPlayer& player);
+
+::std::int64_t
+qmediaplayerPosition(const QMediaPlayer& player);
+
+void
+qmediaplayerSetAudioOutput(QMediaPlayer& player, QAudioOutput* output);
+
+void
+qmediaplayerSetPosition(QMediaPlayer& player, ::std::int64_t position);
+
+}
+}
@@ -0,0 +1,35 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtCore/QString>
+#include <QtNetwork/QAbstractSocket>
+#include <QtNetwork/QHostAddress>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QHostAddress> : ::std::true_type
+{
+};
+
+namespace cxxqtlib1 {
+using QAbstractSocketNetworkLayerProtocol =
+  QAbstractSocket::NetworkLayerProtocol;
+using QHostAddressSpecialAddress = QHostAddress::SpecialAddress;
+
+::std::uint32_t
+qhostaddressToIPv4Address(const QHostAddress& address, bool& ok);
+
+}
+} // namespace rust
@@ -0,0 +1,62 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <memory>
+#include <vector>
+
+#include <QtCore/QString>
+#include <QtNetwork/QHostAddress>
+#include <QtNetwork/QHostInfo>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QHostInfo> : ::std::true_type
+{
+};
+
+namespace cxxqtlib1 {
+using QHostInfoHostInfoError = QHostInfo::HostInfoError;
+
+void
+qhostinfoAbortHostLookup(::std::int32_t id);
+
+::std::unique_ptr<::std::vector<QHostAddress>>
+qhostinfoAddresses(const QHostInfo& info);
+
+QHostInfo
+qhostinfoFromName(const QString& name);
+
+QString
+qhostinfoLocalDomainName();
+
+QString
+qhostinfoLocalHostName();
+
+template<typename A>
+::std::int32_t
+qhostinfoLookupHost(const QString& name,
+                    ::rust::Fn<void(::rust::Box<A>, const QHostInfo&)> func,
+                    ::rust::Box<A> arg)
+{
+  // Qt requires the functor to be copyable, so share the Rust closure
+  // between the copies, it is only called once when the lookup has finished
+  auto shared = ::std::make_shared<::rust::Box<A>>(::std::move(arg));
+  return QHostInfo::lookupHost(
+    name, [func, shared](const QHostInfo& info) {
+      func(::std::move(*shared), info);
+    });
+}
+
+}
+} // namespace rust
@@ -0,0 +1,35 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QStringList>
+#include <QtNetwork/QNetworkInformation>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QNetworkInformationReachability = QNetworkInformation::Reachability;
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 3, 0))
+using QNetworkInformationTransportMedium =
+  QNetworkInformation::TransportMedium;
+#endif
+
+QStringList
+qnetworkinformationAvailableBackends();
+
+QNetworkInformation*
+qnetworkinformationInstance();
+
+bool
+qnetworkinformationLoadBackendByName(const QString& backend);
+
+bool
+qnetworkinformationLoadDefaultBackend();
+
+}
+}
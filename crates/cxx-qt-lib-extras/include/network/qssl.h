@@ -0,0 +1,20 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtNetwork/QSsl>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QSslEncodingFormat = QSsl::EncodingFormat;
+using QSslKeyAlgorithm = QSsl::KeyAlgorithm;
+using QSslKeyType = QSsl::KeyType;
+
+}
+}
@@ -0,0 +1,49 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+#include <vector>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QStringList>
+#include <QtNetwork/QSslCertificate>
+
+#include "cxx-qt-lib-extras/qssl.h"
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QSslCertificate> : ::std::true_type
+{
+};
+
+namespace cxxqtlib1 {
+using QSslCertificateSubjectInfo = QSslCertificate::SubjectInfo;
+
+QByteArray
+qsslcertificateDigestSha256(const QSslCertificate& certificate);
+
+::std::unique_ptr<::std::vector<QSslCertificate>>
+qsslcertificateFromData(const QByteArray& data, QSslEncodingFormat format);
+
+::std::unique_ptr<::std::vector<QSslCertificate>>
+qsslcertificateFromPath(const QString& path, QSslEncodingFormat format);
+
+QStringList
+qsslcertificateIssuerInfo(const QSslCertificate& certificate,
+                          QSslCertificateSubjectInfo subject);
+
+QStringList
+qsslcertificateSubjectInfo(const QSslCertificate& certificate,
+                           QSslCertificateSubjectInfo subject);
+
+}
+} // namespace rust
@@ -0,0 +1,52 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+#include <vector>
+
+#include <QtNetwork/QSslConfiguration>
+#include <QtNetwork/QSslSocket>
+
+#include "cxx-qt-lib-extras/qsslcertificate.h"
+#include "cxx-qt-lib-extras/qsslkey.h"
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QSslSocketPeerVerifyMode = QSslSocket::PeerVerifyMode;
+
+void
+qsslconfigurationAddCaCertificate(QSslConfiguration& configuration,
+                                  const QSslCertificate& certificate);
+
+::std::unique_ptr<::std::vector<QSslCertificate>>
+qsslconfigurationCaCertificates(const QSslConfiguration& configuration);
+
+::std::unique_ptr<QSslConfiguration>
+qsslconfigurationClone(const QSslConfiguration& configuration);
+
+::std::unique_ptr<QSslConfiguration>
+qsslconfigurationDefaultConfiguration();
+
+::std::unique_ptr<QSslConfiguration>
+qsslconfigurationNew();
+
+void
+qsslconfigurationSetCaCertificates(
+  QSslConfiguration& configuration,
+  ::rust::Slice<const QSslCertificate> certificates);
+
+void
+qsslconfigurationSetDefaultConfiguration(
+  const QSslConfiguration& configuration);
+
+::std::unique_ptr<::std::vector<QSslCertificate>>
+qsslconfigurationSystemCaCertificates();
+
+}
+}
@@ -0,0 +1,33 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtNetwork/QSslKey>
+
+#include "cxx-qt-lib-extras/qssl.h"
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QSslKey> : ::std::true_type
+{
+};
+
+namespace cxxqtlib1 {
+
+QSslKey
+qsslkeyInitFromData(const QByteArray& encoded,
+                    QSslKeyAlgorithm algorithm,
+                    QSslEncodingFormat format,
+                    QSslKeyType type,
+                    const QByteArray& passPhrase);
+
+}
+} // namespace rust
@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtPositioning/QGeoPositionInfo>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+// QGeoPositionInfo only has a pointer to its private data, so it can be moved
+// in memory
+template<>
+struct IsRelocatable<QGeoPositionInfo> : ::std::true_type
+{
+};
+
+namespace cxxqtlib1 {
+using QGeoPositionInfoAttribute = QGeoPositionInfo::Attribute;
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,55 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QStringList>
+#include <QtPositioning/QGeoPositionInfoSource>
+
+#include <cxx-qt/connection.h>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QGeoPositionInfoSourceError = QGeoPositionInfoSource::Error;
+
+QStringList
+qgeopositioninfosourceAvailableSources();
+
+::std::unique_ptr<QGeoPositionInfoSource>
+qgeopositioninfosourceCreateDefaultSource();
+
+::std::unique_ptr<QGeoPositionInfoSource>
+qgeopositioninfosourceCreateSource(const QString& sourceName);
+
+template<typename A>
+::QMetaObject::Connection
+qgeopositioninfosourceOnErrorOccurred(
+  QGeoPositionInfoSource& source,
+  ::rust::Fn<void(A&, QGeoPositionInfoSource&, QGeoPositionInfoSourceError)>
+    func,
+  ::rust::Box<A> arg)
+{
+  // Qt requires the functor to be copyable so share the box
+  auto shared = ::std::make_shared<::rust::Box<A>>(::std::move(arg));
+  return QObject::connect(
+    &source,
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+    &QGeoPositionInfoSource::errorOccurred,
+#else
+    // In Qt 5 the signal is named error, which is overloaded with the getter
+    QOverload<QGeoPositionInfoSourceError>::of(&QGeoPositionInfoSource::error),
+#endif
+    &source,
+    [&source, func = ::std::move(func), shared](
+      QGeoPositionInfoSourceError error) { func(**shared, source, error); });
+}
+
+}
+}
@@ -0,0 +1,33 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtGui/QPainter>
+#include <QtPrintSupport/QPrinter>
+
+#include "cxx-qt-lib-extras/qpagedpaintdevice.h"
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QPrinterColorMode = QPrinter::ColorMode;
+using QPrinterMode = QPrinter::PrinterMode;
+using QPrinterOutputFormat = QPrinter::OutputFormat;
+
+::std::unique_ptr<QPrinter>
+qprinterNew(QPrinterMode mode);
+
+::std::unique_ptr<QPainter>
+qprinterNewPainter(QPrinter& printer);
+
+bool
+qprinterSetPageSize(QPrinter& printer, QPageSizeId pageSizeId);
+
+}
+}
@@ -0,0 +1,79 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtQuick/QQuickFramebufferObject>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// A renderer which exposes the protected methods used while rendering,
+// so that they can be called from Rust
+class QQuickFramebufferObjectRenderer : public QQuickFramebufferObject::Renderer
+{
+public:
+  using QQuickFramebufferObject::Renderer::invalidateFramebufferObject;
+  using QQuickFramebufferObject::Renderer::update;
+};
+
+// A renderer which calls Rust functions to synchronize and render
+template<typename A>
+class QQuickFramebufferObjectRendererRust
+  : public QQuickFramebufferObjectRenderer
+{
+public:
+  QQuickFramebufferObjectRendererRust(
+    ::rust::Fn<void(A&, QQuickFramebufferObjectRenderer&)> render,
+    ::rust::Fn<void(A&,
+                    QQuickFramebufferObjectRenderer&,
+                    QQuickFramebufferObject&)> synchronize,
+    ::rust::Box<A> arg)
+    : m_render(::std::move(render))
+    , m_synchronize(::std::move(synchronize))
+    , m_arg(::std::move(arg))
+  {
+  }
+
+protected:
+  void render() override { m_render(*m_arg, *this); }
+
+  void synchronize(QQuickFramebufferObject* item) override
+  {
+    if (item != nullptr) {
+      m_synchronize(*m_arg, *this, *item);
+    }
+  }
+
+private:
+  ::rust::Fn<void(A&, QQuickFramebufferObjectRenderer&)> m_render;
+  ::rust::Fn<
+    void(A&, QQuickFramebufferObjectRenderer&, QQuickFramebufferObject&)>
+    m_synchronize;
+  ::rust::Box<A> m_arg;
+};
+
+const void*
+qquickframebufferobjectrendererGetProcAddress(::rust::Str name);
+
+template<typename A>
+QQuickFramebufferObjectRenderer*
+qquickframebufferobjectrendererNew(
+  ::rust::Fn<void(A&, QQuickFramebufferObjectRenderer&)> render,
+  ::rust::Fn<
+    void(A&, QQuickFramebufferObjectRenderer&, QQuickFramebufferObject&)>
+    synchronize,
+  ::rust::Box<A> arg)
+{
+  // The item takes ownership of the renderer returned from createRenderer
+  return new QQuickFramebufferObjectRendererRust<A>(
+    ::std::move(render), ::std::move(synchronize), ::std::move(arg));
+}
+
+}
+}
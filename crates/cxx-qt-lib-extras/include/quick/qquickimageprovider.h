@@ -0,0 +1,62 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QImage>
+#include <QtQml/QQmlEngine>
+#include <QtQuick/QQuickImageProvider>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// An image provider which calls a Rust closure to create the requested images
+template<typename A>
+class QQuickImageProviderRust : public QQuickImageProvider
+{
+public:
+  QQuickImageProviderRust(
+    ::rust::Fn<QImage(const A&, const QString&, const QSize&)> func,
+    ::rust::Box<A> arg)
+    : QQuickImageProvider(QQuickImageProvider::Image)
+    , m_func(::std::move(func))
+    , m_arg(::std::move(arg))
+  {
+  }
+
+  QImage requestImage(const QString& id,
+                      QSize* size,
+                      const QSize& requestedSize) override
+  {
+    QImage image = m_func(*m_arg, id, requestedSize);
+    if (size != nullptr) {
+      *size = image.size();
+    }
+    return image;
+  }
+
+private:
+  ::rust::Fn<QImage(const A&, const QString&, const QSize&)> m_func;
+  ::rust::Box<A> m_arg;
+};
+
+template<typename A>
+void
+qqmlengineAddImageProvider(
+  QQmlEngine& engine,
+  const QString& id,
+  ::rust::Fn<QImage(const A&, const QString&, const QSize&)> func,
+  ::rust::Box<A> arg)
+{
+  // The engine takes ownership of the provider
+  engine.addImageProvider(
+    id, new QQuickImageProviderRust<A>(::std::move(func), ::std::move(arg)));
+}
+
+}
+}
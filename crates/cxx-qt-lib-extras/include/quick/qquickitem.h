@@ -0,0 +1,24 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QString>
+#include <QtCore/QVariant>
+#include <QtQuick/QQuickItem>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QQuickItemFlag = QQuickItem::Flag;
+
+bool
+qquickitemSetAccessibleProperty(QQuickItem& item,
+                                const QString& name,
+                                const QVariant& value);
+}
+}
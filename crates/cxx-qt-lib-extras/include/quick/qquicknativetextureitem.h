@@ -0,0 +1,45 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtCore/QSize>
+#include <QtQuick/QQuickItem>
+#include <QtQuick/QSGNode>
+#include <QtQuick/QSGTexture>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+// An item which displays a texture that was rendered outside of Qt Quick,
+// by wrapping the native texture for the graphics API of the window
+class QQuickNativeTextureItem : public QQuickItem
+{
+public:
+  explicit QQuickNativeTextureItem(QQuickItem* parent = nullptr);
+
+  void clearNativeTexture();
+  QSize nativeTextureSize() const;
+  void setNativeTexture(::std::uint64_t handle,
+                        ::std::int32_t layout,
+                        const QSize& size);
+
+protected:
+  QSGNode* updatePaintNode(QSGNode* oldNode, UpdatePaintNodeData*) override;
+
+private:
+  QSGTexture* createTexture() const;
+
+  ::std::uint64_t m_handle = 0;
+  ::std::int32_t m_layout = 0;
+  QSize m_size;
+  bool m_textureChanged = false;
+};
+
+}
+}
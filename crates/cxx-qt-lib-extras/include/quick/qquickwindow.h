@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtQuick/QQuickWindow>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::int32_t
+qquickwindowGraphicsApi(const QQuickWindow& window);
+
+void*
+qquickwindowNativeResource(QQuickWindow& window, ::std::int32_t resource);
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+void
+qquickwindowSetGraphicsApi(::std::int32_t api);
+#endif
+
+}
+}
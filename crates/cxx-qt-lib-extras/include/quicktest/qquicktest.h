@@ -0,0 +1,21 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtCore/QString>
+#include <QtQuickTest/quicktest.h>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::int32_t
+qquicktestRun(const QString& name, const QString& sourceDir);
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,36 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtRemoteObjects/QRemoteObjectHost>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QRemoteObjectHost>
+qremoteobjecthostNew(const QUrl& address);
+
+QRemoteObjectNode&
+qremoteobjecthostAsQRemoteObjectNode(QRemoteObjectHost& host);
+
+bool
+qremoteobjecthostDisableRemoting(QRemoteObjectHost& host, QObject* object);
+
+bool
+qremoteobjecthostEnableRemoting(QRemoteObjectHost& host,
+                                QObject* object,
+                                const QString& name);
+
+bool
+qremoteobjecthostSetHostUrl(QRemoteObjectHost& host, const QUrl& address);
+
+}
+}
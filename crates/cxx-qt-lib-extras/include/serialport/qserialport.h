@@ -0,0 +1,47 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtSerialPort/QSerialPort>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QIODeviceOpenModeFlag = QIODevice::OpenModeFlag;
+using QSerialPortDataBits = QSerialPort::DataBits;
+using QSerialPortFlowControl = QSerialPort::FlowControl;
+using QSerialPortParity = QSerialPort::Parity;
+using QSerialPortSerialPortError = QSerialPort::SerialPortError;
+using QSerialPortStopBits = QSerialPort::StopBits;
+
+::std::unique_ptr<QSerialPort>
+qserialportNew();
+
+::std::int32_t
+qserialportBaudRate(const QSerialPort& port);
+
+::std::int64_t
+qserialportBytesAvailable(const QSerialPort& port);
+
+bool
+qserialportOpen(QSerialPort& port, QIODeviceOpenModeFlag mode);
+
+QByteArray
+qserialportReadAll(QSerialPort& port);
+
+bool
+qserialportSetBaudRate(QSerialPort& port, ::std::int32_t baudRate);
+
+::std::int64_t
+qserialportWrite(QSerialPort& port, const QByteArray& data);
+
+}
+}
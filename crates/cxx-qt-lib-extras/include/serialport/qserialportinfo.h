@@ -0,0 +1,26 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+#include <vector>
+
+#include <QtSerialPort/QSerialPortInfo>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<::std::vector<QSerialPortInfo>>
+qserialportinfoAvailablePorts();
+
+::std::unique_ptr<QSerialPortInfo>
+qserialportinfoNew(const QString& name);
+
+}
+}
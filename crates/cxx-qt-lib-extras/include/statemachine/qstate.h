@@ -0,0 +1,37 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QObject>
+#include <QtStateMachine/QFinalState>
+#include <QtStateMachine/QState>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qstateAddTransition(QState& state,
+                    const QObject* sender,
+                    ::rust::Str signal,
+                    QState* target);
+
+bool
+qstateAddTransitionToFinalState(QState& state,
+                                const QObject* sender,
+                                ::rust::Str signal,
+                                QFinalState* target);
+
+QState*
+qstateNew(QState& parent);
+
+void
+qstateSetInitialState(QState& state, QState* initialState);
+
+}
+}
@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QList>
+#include <QtCore/QString>
+#include <QtTest/QAbstractItemModelTester>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qabstractitemmodeltesterInstallMessageHandler();
+
+QList<QString>
+qabstractitemmodeltesterTakeFailures();
+
+// This is a template so that it can be declared in a bridge for any model,
+// as there is no upcasting from a Rust QObject to QAbstractItemModel yet
+template<typename T>
+::std::unique_ptr<QAbstractItemModelTester>
+qabstractitemmodeltesterNew(T& model)
+{
+  // Failures are reported as warnings, which are then collected by our message
+  // handler. Fatal would abort the test binary without any Rust panic message.
+  qabstractitemmodeltesterInstallMessageHandler();
+  return ::std::make_unique<QAbstractItemModelTester>(
+    &model, QAbstractItemModelTester::FailureReportingMode::Warning);
+}
+
+} // namespace cxxqtlib1
+} // namespace rust
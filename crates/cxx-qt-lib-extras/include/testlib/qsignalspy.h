@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QList>
+#include <QtCore/QObject>
+#include <QtCore/QVariant>
+#include <QtTest/QSignalSpy>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSignalSpy>
+qsignalspyNew(const QObject& object, ::rust::Str signal);
+
+::rust::isize
+qsignalspyCount(const QSignalSpy& spy);
+
+QByteArray
+qsignalspySignal(const QSignalSpy& spy);
+
+QList<QVariant>
+qsignalspyTakeFirst(QSignalSpy& spy);
+
+bool
+qsignalspyWait(QSignalSpy& spy, ::std::int32_t timeout);
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,78 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtCore/QPoint>
+#include <QtGui/QWindow>
+#include <QtQml/QQmlApplicationEngine>
+#include <QtTest/QTest>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qtestKeyClick(QWindow& window,
+              Qt::Key key,
+              Qt::KeyboardModifier modifier,
+              ::std::int32_t delay);
+
+void
+qtestKeyPress(QWindow& window,
+              Qt::Key key,
+              Qt::KeyboardModifier modifier,
+              ::std::int32_t delay);
+
+void
+qtestKeyRelease(QWindow& window,
+                Qt::Key key,
+                Qt::KeyboardModifier modifier,
+                ::std::int32_t delay);
+
+void
+qtestMouseClick(QWindow& window,
+                Qt::MouseButton button,
+                Qt::KeyboardModifier modifier,
+                const QPoint& pos,
+                ::std::int32_t delay);
+
+void
+qtestMouseDoubleClick(QWindow& window,
+                      Qt::MouseButton button,
+                      Qt::KeyboardModifier modifier,
+                      const QPoint& pos,
+                      ::std::int32_t delay);
+
+void
+qtestMouseMove(QWindow& window, const QPoint& pos, ::std::int32_t delay);
+
+void
+qtestMousePress(QWindow& window,
+                Qt::MouseButton button,
+                Qt::KeyboardModifier modifier,
+                const QPoint& pos,
+                ::std::int32_t delay);
+
+void
+qtestMouseRelease(QWindow& window,
+                  Qt::MouseButton button,
+                  Qt::KeyboardModifier modifier,
+                  const QPoint& pos,
+                  ::std::int32_t delay);
+
+QWindow*
+qtestRootWindow(QQmlApplicationEngine& engine);
+
+bool
+qtestWaitForWindowActive(QWindow& window, ::std::int32_t timeout);
+
+bool
+qtestWaitForWindowExposed(QWindow& window, ::std::int32_t timeout);
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,24 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtCore/QCoreApplication>
+#include <QtTest/QTest>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qtestapplicationProcessEvents();
+
+void
+qtestapplicationWait(::std::int32_t timeout);
+
+} // namespace cxxqtlib1
+} // namespace rust
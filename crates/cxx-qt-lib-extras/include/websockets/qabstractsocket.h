@@ -0,0 +1,19 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtNetwork/QAbstractSocket>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QAbstractSocketSocketError = QAbstractSocket::SocketError;
+using QAbstractSocketSocketState = QAbstractSocket::SocketState;
+
+}
+}
@@ -0,0 +1,63 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QString>
+#include <QtCore/QUrl>
+#include <QtWebSockets/QWebSocket>
+
+#include <cxx-qt/connection.h>
+
+#include "cxx-qt-lib-extras/qabstractsocket.h"
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QWebSocket>
+qwebsocketNew();
+
+template<typename A>
+::QMetaObject::Connection
+qwebsocketOnErrorOccurred(
+  QWebSocket& socket,
+  ::rust::Fn<void(A&, QWebSocket&, QAbstractSocketSocketError)> func,
+  ::rust::Box<A> arg)
+{
+  // Qt requires the functor to be copyable so share the box
+  auto shared = ::std::make_shared<::rust::Box<A>>(::std::move(arg));
+  return QObject::connect(
+    &socket,
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 5, 0))
+    &QWebSocket::errorOccurred,
+#else
+    // Before Qt 6.5 the signal is named error, which is overloaded with the
+    // getter
+    QOverload<QAbstractSocketSocketError>::of(&QWebSocket::error),
+#endif
+    &socket,
+    [&socket, func = ::std::move(func), shared](
+      QAbstractSocketSocketError error) { func(**shared, socket, error); });
+}
+
+void
+qwebsocketOpen(QWebSocket& socket, const QUrl& url);
+
+::std::int64_t
+qwebsocketSendBinaryMessage(QWebSocket& socket, const QByteArray& data);
+
+::std::int64_t
+qwebsocketSendTextMessage(QWebSocket& socket, const QString& message);
+
+::std::unique_ptr<QSslConfiguration>
+qwebsocketSslConfiguration(const QWebSocket& socket);
+
+}
+}
@@ -0,0 +1,36 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QString>
+#include <QtWebSockets/QWebSocket>
+#include <QtWebSockets/QWebSocketServer>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QWebSocketServerSslMode = QWebSocketServer::SslMode;
+
+::std::unique_ptr<QWebSocketServer>
+qwebsocketserverNew(const QString& serverName, QWebSocketServerSslMode mode);
+
+bool
+qwebsocketserverListen(QWebSocketServer& server,
+                       const QString& address,
+                       ::std::uint16_t port);
+
+::std::unique_ptr<QWebSocket>
+qwebsocketserverNextPendingConnection(QWebSocketServer& server);
+
+::std::unique_ptr<QSslConfiguration>
+qwebsocketserverSslConfiguration(const QWebSocketServer& server);
+
+}
+}
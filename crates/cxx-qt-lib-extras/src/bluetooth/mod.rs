@@ -0,0 +1,30 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qbluetoothdevicediscoveryagent;
+pub use qbluetoothdevicediscoveryagent::{
+    QBluetoothDeviceDiscoveryAgent, QBluetoothDeviceDiscoveryAgentDiscoveryMethod,
+    QBluetoothDeviceDiscoveryAgentError,
+};
+
+mod qbluetoothdeviceinfo;
+pub use qbluetoothdeviceinfo::{QBluetoothDeviceInfo, QBluetoothDeviceInfoCoreConfiguration};
+
+mod qbluetoothuuid;
+pub use qbluetoothuuid::QBluetoothUuid;
+
+mod qlowenergycharacteristic;
+pub use qlowenergycharacteristic::QLowEnergyCharacteristic;
+
+mod qlowenergycontroller;
+pub use qlowenergycontroller::{
+    QLowEnergyController, QLowEnergyControllerControllerState, QLowEnergyControllerError,
+};
+
+mod qlowenergyservice;
+pub use qlowenergyservice::{
+    QLowEnergyService, QLowEnergyServiceServiceError, QLowEnergyServiceServiceState,
+    QLowEnergyServiceWriteMode,
+};
@@ -0,0 +1,37 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qbluetoothdevicediscoveryagent.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QBluetoothDeviceDiscoveryAgent>
+qbluetoothdevicediscoveryagentNew()
+{
+  return ::std::make_unique<QBluetoothDeviceDiscoveryAgent>();
+}
+
+::std::unique_ptr<::std::vector<QBluetoothDeviceInfo>>
+qbluetoothdevicediscoveryagentDiscoveredDevices(
+  const QBluetoothDeviceDiscoveryAgent& agent)
+{
+  const auto devices = agent.discoveredDevices();
+  return ::std::make_unique<::std::vector<QBluetoothDeviceInfo>>(
+    devices.cbegin(), devices.cend());
+}
+
+void
+qbluetoothdevicediscoveryagentStartWithMethod(
+  QBluetoothDeviceDiscoveryAgent& agent,
+  QBluetoothDeviceDiscoveryAgentDiscoveryMethod method)
+{
+  agent.start(method);
+}
+
+}
+}
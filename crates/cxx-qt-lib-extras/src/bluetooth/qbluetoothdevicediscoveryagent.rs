@@ -0,0 +1,214 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// This enum describes the type of discovery method employed by the [QBluetoothDeviceDiscoveryAgent].
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QBluetoothDeviceDiscoveryAgentDiscoveryMethod {
+        /// The discovery is not possible. None of the available methods are supported.
+        NoMethod = 0x0,
+        /// The discovery process searches for Bluetooth Classic (BaseRate) devices.
+        ClassicMethod = 0x01,
+        /// The discovery process searches for Bluetooth Low Energy devices.
+        LowEnergyMethod = 0x02,
+    }
+
+    /// Indicates all possible error conditions found during Bluetooth device discovery.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QBluetoothDeviceDiscoveryAgentError {
+        /// No error has occurred.
+        NoError = 0,
+        /// Writing or reading from the device resulted in an error.
+        InputOutputError = 1,
+        /// The Bluetooth adaptor is powered off, power it on before doing discovery.
+        PoweredOffError = 2,
+        /// The passed local adapter address does not match the physical adapter address of any local Bluetooth device.
+        InvalidBluetoothAdapterError = 3,
+        /// Device discovery is not possible or implemented on the current platform.
+        UnsupportedPlatformError = 4,
+        /// One of the requested discovery methods is not supported by the current platform.
+        UnsupportedDiscoveryMethod = 5,
+        /// An unknown error has occurred.
+        UnknownError = 100,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qbluetoothdevicediscoveryagent.h");
+        /// Discovers the Bluetooth devices nearby, the discovery runs on the Qt event loop.
+        #[qobject]
+        type QBluetoothDeviceDiscoveryAgent;
+
+        /// This signal is emitted when the device discovery is aborted by a call to [QBluetoothDeviceDiscoveryAgent::stop].
+        #[qsignal]
+        fn canceled(self: Pin<&mut QBluetoothDeviceDiscoveryAgent>);
+
+        /// This signal is emitted when the Bluetooth device described by info is discovered.
+        #[qsignal]
+        #[cxx_name = "deviceDiscovered"]
+        fn device_discovered(
+            self: Pin<&mut QBluetoothDeviceDiscoveryAgent>,
+            info: &QBluetoothDeviceInfo,
+        );
+
+        /// This signal is emitted when the Bluetooth device discovery completes.
+        ///
+        /// The signal is not going to be emitted if the device discovery finishes with an error.
+        #[qsignal]
+        fn finished(self: Pin<&mut QBluetoothDeviceDiscoveryAgent>);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/connection.h");
+        #[namespace = "rust::cxxqt1"]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-lib-extras/qbluetoothdeviceinfo.h");
+        type QBluetoothDeviceInfo = crate::QBluetoothDeviceInfo;
+
+        /// Returns the last error.
+        fn error(self: &QBluetoothDeviceDiscoveryAgent) -> QBluetoothDeviceDiscoveryAgentError;
+
+        /// Returns a human-readable description of the last error.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QBluetoothDeviceDiscoveryAgent) -> QString;
+
+        /// Returns true if the agent is currently discovering Bluetooth devices, otherwise returns false.
+        #[rust_name = "is_active"]
+        fn isActive(self: &QBluetoothDeviceDiscoveryAgent) -> bool;
+
+        /// Returns a timeout in milliseconds that is applied to the Bluetooth Low Energy device search.
+        ///
+        /// A value of -1 implies that the platform does not support this property, 0 means that the search does not finish by itself.
+        #[rust_name = "low_energy_discovery_timeout"]
+        fn lowEnergyDiscoveryTimeout(self: &QBluetoothDeviceDiscoveryAgent) -> i32;
+
+        /// Sets the maximum search time for Bluetooth Low Energy device search to timeout in milliseconds.
+        ///
+        /// If the timeout is 0 the discovery runs until [QBluetoothDeviceDiscoveryAgent::stop] is called.
+        #[rust_name = "set_low_energy_discovery_timeout"]
+        fn setLowEnergyDiscoveryTimeout(
+            self: Pin<&mut QBluetoothDeviceDiscoveryAgent>,
+            timeout: i32,
+        );
+
+        /// Starts Bluetooth device discovery using all supported discovery methods.
+        fn start(self: Pin<&mut QBluetoothDeviceDiscoveryAgent>);
+
+        /// Stops Bluetooth device discovery. The canceled signal is emitted once the device discovery is stopped.
+        fn stop(self: Pin<&mut QBluetoothDeviceDiscoveryAgent>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QBluetoothDeviceDiscoveryAgentDiscoveryMethod;
+        type QBluetoothDeviceDiscoveryAgentError;
+
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothdevicediscoveryagent_new"]
+        fn qbluetoothdevicediscoveryagentNew() -> UniquePtr<QBluetoothDeviceDiscoveryAgent>;
+
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothdevicediscoveryagent_discovered_devices"]
+        fn qbluetoothdevicediscoveryagentDiscoveredDevices(
+            agent: &QBluetoothDeviceDiscoveryAgent,
+        ) -> UniquePtr<CxxVector<QBluetoothDeviceInfo>>;
+
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothdevicediscoveryagent_on_error_occurred"]
+        fn qbluetoothdevicediscoveryagentOnErrorOccurred(
+            agent: Pin<&mut QBluetoothDeviceDiscoveryAgent>,
+            func: fn(
+                &mut QBluetoothDeviceDiscoveryAgentErrorHandler,
+                Pin<&mut QBluetoothDeviceDiscoveryAgent>,
+                QBluetoothDeviceDiscoveryAgentError,
+            ),
+            arg: Box<QBluetoothDeviceDiscoveryAgentErrorHandler>,
+        ) -> QMetaObjectConnection;
+
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothdevicediscoveryagent_start_with_method"]
+        fn qbluetoothdevicediscoveryagentStartWithMethod(
+            agent: Pin<&mut QBluetoothDeviceDiscoveryAgent>,
+            method: QBluetoothDeviceDiscoveryAgentDiscoveryMethod,
+        );
+    }
+
+    extern "Rust" {
+        #[namespace = "rust::cxxqtlib1"]
+        type QBluetoothDeviceDiscoveryAgentErrorHandler;
+    }
+
+    // QBluetoothDeviceDiscoveryAgent is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QBluetoothDeviceDiscoveryAgent>::isRelocatable);
+    impl UniquePtr<QBluetoothDeviceDiscoveryAgent> {}
+}
+
+use crate::QBluetoothDeviceInfo;
+use core::pin::Pin;
+use cxx_qt::QMetaObjectConnection;
+
+pub use ffi::{
+    QBluetoothDeviceDiscoveryAgent, QBluetoothDeviceDiscoveryAgentDiscoveryMethod,
+    QBluetoothDeviceDiscoveryAgentError,
+};
+
+impl QBluetoothDeviceDiscoveryAgent {
+    /// Returns the list of all discovered Bluetooth devices.
+    pub fn discovered_devices(&self) -> cxx::UniquePtr<cxx::CxxVector<QBluetoothDeviceInfo>> {
+        ffi::qbluetoothdevicediscoveryagent_discovered_devices(self)
+    }
+
+    /// Create a new QBluetoothDeviceDiscoveryAgent for the local default Bluetooth adapter.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qbluetoothdevicediscoveryagent_new()
+    }
+
+    /// Connect a closure which is called when an error occurs during discovery.
+    ///
+    /// The returned [QMetaObjectConnection] can be used to disconnect the closure.
+    pub fn on_error_occurred<F>(self: Pin<&mut Self>, closure: F) -> QMetaObjectConnection
+    where
+        F: FnMut(Pin<&mut QBluetoothDeviceDiscoveryAgent>, QBluetoothDeviceDiscoveryAgentError)
+            + 'static,
+    {
+        fn func(
+            handler: &mut QBluetoothDeviceDiscoveryAgentErrorHandler,
+            agent: Pin<&mut QBluetoothDeviceDiscoveryAgent>,
+            error: QBluetoothDeviceDiscoveryAgentError,
+        ) {
+            (handler.inner)(agent, error)
+        }
+        let arg = QBluetoothDeviceDiscoveryAgentErrorHandler {
+            inner: Box::new(closure),
+        };
+        ffi::qbluetoothdevicediscoveryagent_on_error_occurred(self, func, Box::new(arg))
+    }
+
+    /// Starts Bluetooth device discovery using the given method,
+    /// for example [QBluetoothDeviceDiscoveryAgentDiscoveryMethod::LowEnergyMethod] to only search for Bluetooth Low Energy devices.
+    pub fn start_with_method(
+        self: Pin<&mut Self>,
+        method: QBluetoothDeviceDiscoveryAgentDiscoveryMethod,
+    ) {
+        ffi::qbluetoothdevicediscoveryagent_start_with_method(self, method)
+    }
+}
+
+#[doc(hidden)]
+pub struct QBluetoothDeviceDiscoveryAgentErrorHandler {
+    inner: DiscoveryErrorFn,
+}
+
+type DiscoveryErrorFn =
+    Box<dyn FnMut(Pin<&mut QBluetoothDeviceDiscoveryAgent>, QBluetoothDeviceDiscoveryAgentError)>;
@@ -0,0 +1,51 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qbluetoothdeviceinfo.h"
+
+#include <QtBluetooth/QBluetoothAddress>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qbluetoothdeviceinfoAddress(const QBluetoothDeviceInfo& info)
+{
+  return info.address().toString();
+}
+
+QBluetoothDeviceInfoCoreConfiguration
+qbluetoothdeviceinfoCoreConfigurations(const QBluetoothDeviceInfo& info)
+{
+  // QFlags cannot be passed to Rust, but the combination of both
+  // configurations is also a value of the enum
+  const auto configurations = info.coreConfigurations();
+  const bool lowEnergy =
+    configurations.testFlag(QBluetoothDeviceInfo::LowEnergyCoreConfiguration);
+  const bool baseRate =
+    configurations.testFlag(QBluetoothDeviceInfo::BaseRateCoreConfiguration);
+  if (lowEnergy && baseRate) {
+    return QBluetoothDeviceInfo::BaseRateAndLowEnergyCoreConfiguration;
+  } else if (lowEnergy) {
+    return QBluetoothDeviceInfo::LowEnergyCoreConfiguration;
+  } else if (baseRate) {
+    return QBluetoothDeviceInfo::BaseRateCoreConfiguration;
+  } else {
+    return QBluetoothDeviceInfo::UnknownCoreConfiguration;
+  }
+}
+
+::std::unique_ptr<::std::vector<QBluetoothUuid>>
+qbluetoothdeviceinfoServiceUuids(const QBluetoothDeviceInfo& info)
+{
+  const auto uuids = info.serviceUuids();
+  return ::std::make_unique<::std::vector<QBluetoothUuid>>(uuids.cbegin(),
+                                                           uuids.cend());
+}
+
+}
+}
@@ -0,0 +1,100 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the configuration of the device.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QBluetoothDeviceInfoCoreConfiguration {
+        /// The type of the Bluetooth device cannot be determined.
+        UnknownCoreConfiguration = 0,
+        /// The device is a Bluetooth Low Energy device.
+        LowEnergyCoreConfiguration = 1,
+        /// The device is a standard Bluetooth device.
+        BaseRateCoreConfiguration = 2,
+        /// The device is a standard Bluetooth device which also supports Bluetooth Low Energy.
+        BaseRateAndLowEnergyCoreConfiguration = 3,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qbluetoothdeviceinfo.h");
+        /// Stores information about a Bluetooth device, such as its name, address and the services it advertises.
+        type QBluetoothDeviceInfo;
+
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-lib-extras/qbluetoothuuid.h");
+        type QBluetoothUuid = crate::QBluetoothUuid;
+
+        /// Returns the unique identifier of the device, this is used instead of the address on macOS and iOS.
+        #[rust_name = "device_uuid"]
+        fn deviceUuid(self: &QBluetoothDeviceInfo) -> QBluetoothUuid;
+
+        /// Returns true if the device info is cached from a previous discovery.
+        #[rust_name = "is_cached"]
+        fn isCached(self: &QBluetoothDeviceInfo) -> bool;
+
+        /// Returns true if the device info is valid, otherwise false.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QBluetoothDeviceInfo) -> bool;
+
+        /// Returns the name assigned to the device.
+        fn name(self: &QBluetoothDeviceInfo) -> QString;
+
+        /// Returns the signal strength when the device was last scanned, in dBm.
+        fn rssi(self: &QBluetoothDeviceInfo) -> i16;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QBluetoothDeviceInfoCoreConfiguration;
+
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothdeviceinfo_address"]
+        fn qbluetoothdeviceinfoAddress(info: &QBluetoothDeviceInfo) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothdeviceinfo_core_configurations"]
+        fn qbluetoothdeviceinfoCoreConfigurations(
+            info: &QBluetoothDeviceInfo,
+        ) -> QBluetoothDeviceInfoCoreConfiguration;
+
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothdeviceinfo_service_uuids"]
+        fn qbluetoothdeviceinfoServiceUuids(
+            info: &QBluetoothDeviceInfo,
+        ) -> UniquePtr<CxxVector<QBluetoothUuid>>;
+    }
+
+    // Allow for a list of devices to be returned from the discovery agent
+    impl CxxVector<QBluetoothDeviceInfo> {}
+}
+
+use crate::QBluetoothUuid;
+use cxx_qt_lib::QString;
+
+pub use ffi::{QBluetoothDeviceInfo, QBluetoothDeviceInfoCoreConfiguration};
+
+impl QBluetoothDeviceInfo {
+    /// Returns the address of the device, such as `00:11:22:33:44:55`.
+    ///
+    /// On macOS and iOS the address is not available, use [QBluetoothDeviceInfo::device_uuid] instead.
+    pub fn address(&self) -> QString {
+        ffi::qbluetoothdeviceinfo_address(self)
+    }
+
+    /// Returns the configuration of the device, which describes whether it supports Bluetooth Low Energy.
+    pub fn core_configurations(&self) -> QBluetoothDeviceInfoCoreConfiguration {
+        ffi::qbluetoothdeviceinfo_core_configurations(self)
+    }
+
+    /// Returns the UUIDs of the services which the device advertises.
+    pub fn service_uuids(&self) -> cxx::UniquePtr<cxx::CxxVector<QBluetoothUuid>> {
+        ffi::qbluetoothdeviceinfo_service_uuids(self)
+    }
+}
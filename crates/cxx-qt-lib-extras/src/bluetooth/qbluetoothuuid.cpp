@@ -0,0 +1,43 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qbluetoothuuid.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+#include <cstdint>
+
+// QBluetoothUuid has no members of its own, QUuid has a uint, two ushorts
+// and eight uchars
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/plugin/quuid.h?h=v5.15.6-lts-lgpl
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/plugin/quuid.h?h=v6.2.4
+assert_alignment_and_size(QBluetoothUuid, {
+  ::std::uint32_t a0;
+  ::std::uint16_t a1;
+  ::std::uint16_t a2;
+  ::std::uint8_t a3[8];
+});
+
+static_assert(QTypeInfo<QUuid>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QBluetoothUuid
+qbluetoothuuidFromQString(const QString& uuid)
+{
+  return QBluetoothUuid(QUuid(uuid));
+}
+
+QString
+qbluetoothuuidToQString(const QBluetoothUuid& uuid)
+{
+  return uuid.toString(QUuid::WithoutBraces);
+}
+
+}
+}
@@ -0,0 +1,136 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use cxx_qt_lib::QString;
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qbluetoothuuid.h");
+        type QBluetoothUuid = super::QBluetoothUuid;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        /// Returns true if this is the null UUID {00000000-0000-0000-0000-000000000000}; otherwise returns false.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QBluetoothUuid) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothuuid_drop"]
+        fn drop(uuid: &mut QBluetoothUuid);
+
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothuuid_init_default"]
+        fn construct() -> QBluetoothUuid;
+
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothuuid_init_u16"]
+        fn construct(uuid: u16) -> QBluetoothUuid;
+
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothuuid_clone"]
+        fn construct(uuid: &QBluetoothUuid) -> QBluetoothUuid;
+
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothuuid_eq"]
+        fn operatorEq(a: &QBluetoothUuid, b: &QBluetoothUuid) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothuuid_from_qstring"]
+        fn qbluetoothuuidFromQString(uuid: &QString) -> QBluetoothUuid;
+
+        #[doc(hidden)]
+        #[rust_name = "qbluetoothuuid_to_qstring"]
+        fn qbluetoothuuidToQString(uuid: &QBluetoothUuid) -> QString;
+    }
+
+    // Allow for a list of UUIDs to be returned from devices and controllers
+    impl CxxVector<QBluetoothUuid> {}
+}
+
+/// The QBluetoothUuid class generates a UUID for each Bluetooth service, characteristic and descriptor.
+#[repr(C)]
+pub struct QBluetoothUuid {
+    _data1: MaybeUninit<u32>,
+    _data2: MaybeUninit<u16>,
+    _data3: MaybeUninit<u16>,
+    _data4: MaybeUninit<[u8; 8]>,
+}
+
+impl QBluetoothUuid {
+    /// Constructs a UUID from a 16 bit short form UUID, such as `0x180D` for the heart rate service.
+    pub fn from_u16(uuid: u16) -> Self {
+        ffi::qbluetoothuuid_init_u16(uuid)
+    }
+
+    /// Constructs a UUID from its string representation, such as `0000180d-0000-1000-8000-00805f9b34fb`.
+    ///
+    /// If the string is not a valid UUID, then the UUID is null.
+    pub fn from_qstring(uuid: &QString) -> Self {
+        ffi::qbluetoothuuid_from_qstring(uuid)
+    }
+
+    /// Returns the string representation of the UUID without braces, such as `0000180d-0000-1000-8000-00805f9b34fb`.
+    pub fn to_qstring(&self) -> QString {
+        ffi::qbluetoothuuid_to_qstring(self)
+    }
+}
+
+impl Clone for QBluetoothUuid {
+    /// Constructs a copy of other.
+    fn clone(&self) -> Self {
+        ffi::qbluetoothuuid_clone(self)
+    }
+}
+
+impl Default for QBluetoothUuid {
+    /// Constructs a null UUID.
+    fn default() -> Self {
+        ffi::qbluetoothuuid_init_default()
+    }
+}
+
+impl Drop for QBluetoothUuid {
+    /// Destroys the UUID.
+    fn drop(&mut self) {
+        ffi::qbluetoothuuid_drop(self)
+    }
+}
+
+impl PartialEq for QBluetoothUuid {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qbluetoothuuid_eq(self, other)
+    }
+}
+
+impl Eq for QBluetoothUuid {}
+
+impl fmt::Display for QBluetoothUuid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_qstring())
+    }
+}
+
+impl fmt::Debug for QBluetoothUuid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QBluetoothUuid {
+    type Id = type_id!("QBluetoothUuid");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,44 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qlowenergycharacteristic.h");
+        /// Stores information about a Bluetooth Low Energy service characteristic, such as its UUID and value.
+        type QLowEnergyCharacteristic;
+
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-lib-extras/qbluetoothuuid.h");
+        type QBluetoothUuid = crate::QBluetoothUuid;
+
+        /// Returns true if the characteristic is valid, otherwise false.
+        ///
+        /// An invalid characteristic is returned when the service does not have a characteristic with the requested UUID.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QLowEnergyCharacteristic) -> bool;
+
+        /// Returns the human-readable name of the characteristic, if it is a standard characteristic.
+        fn name(self: &QLowEnergyCharacteristic) -> QString;
+
+        /// Returns the UUID of the characteristic.
+        fn uuid(self: &QLowEnergyCharacteristic) -> QBluetoothUuid;
+
+        /// Returns the cached value of the characteristic.
+        ///
+        /// The value is updated when the characteristic is read, written or changed by a notification.
+        fn value(self: &QLowEnergyCharacteristic) -> QByteArray;
+    }
+
+    // Allow for characteristics to be returned from a service
+    impl UniquePtr<QLowEnergyCharacteristic> {}
+    impl CxxVector<QLowEnergyCharacteristic> {}
+}
+
+pub use ffi::QLowEnergyCharacteristic;
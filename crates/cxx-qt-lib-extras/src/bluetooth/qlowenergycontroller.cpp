@@ -0,0 +1,40 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qlowenergycontroller.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QLowEnergyController>
+qlowenergycontrollerCreateCentral(const QBluetoothDeviceInfo& remoteDevice)
+{
+  // The caller takes ownership of the controller
+  return ::std::unique_ptr<QLowEnergyController>(
+    QLowEnergyController::createCentral(remoteDevice));
+}
+
+::std::unique_ptr<QLowEnergyService>
+qlowenergycontrollerCreateServiceObject(QLowEnergyController& controller,
+                                        const QBluetoothUuid& serviceUuid)
+{
+  // The caller takes ownership of the service, which is null if the
+  // service is not known to the controller
+  return ::std::unique_ptr<QLowEnergyService>(
+    controller.createServiceObject(serviceUuid));
+}
+
+::std::unique_ptr<::std::vector<QBluetoothUuid>>
+qlowenergycontrollerServices(const QLowEnergyController& controller)
+{
+  const auto services = controller.services();
+  return ::std::make_unique<::std::vector<QBluetoothUuid>>(services.cbegin(),
+                                                           services.cend());
+}
+
+}
+}
@@ -0,0 +1,242 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// Indicates the state of the controller.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QLowEnergyControllerControllerState {
+        /// The controller is not connected to a remote device.
+        UnconnectedState = 0,
+        /// The controller is attempting to connect to a remote device.
+        ConnectingState,
+        /// The controller is connected to a remote device.
+        ConnectedState,
+        /// The controller is retrieving the list of services offered by the remote device.
+        DiscoveringState,
+        /// The controller has discovered all services offered by the remote device.
+        DiscoveredState,
+        /// The controller is about to be disconnected from the remote device.
+        ClosingState,
+        /// The controller is currently advertising data.
+        AdvertisingState,
+    }
+
+    /// Indicates all possible error conditions found during the controller's existence.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QLowEnergyControllerError {
+        /// No error has occurred.
+        NoError = 0,
+        /// An unknown error has occurred.
+        UnknownError,
+        /// The remote Bluetooth Low Energy device with the address passed to the constructor of this class cannot be found.
+        UnknownRemoteDeviceError,
+        /// The attempt to read from or write to the remote device failed.
+        NetworkError,
+        /// The local Bluetooth device with the address passed to the constructor of this class cannot be found
+        /// or there is no local Bluetooth device.
+        InvalidBluetoothAdapterError,
+        /// The attempt to connect to the remote device failed.
+        ConnectionError,
+        /// The attempt to start advertising failed.
+        AdvertisingError,
+        /// The remote device closed the connection.
+        RemoteHostClosedError,
+        /// The local Bluetooth device closed the connection due to insufficient authorization.
+        AuthorizationError,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qlowenergycontroller.h");
+        /// Provides access to the services of a Bluetooth Low Energy device, acting as a GATT client.
+        #[qobject]
+        type QLowEnergyController;
+
+        /// This signal is emitted when the controller successfully connects to the remote Low Energy device.
+        #[qsignal]
+        fn connected(self: Pin<&mut QLowEnergyController>);
+
+        /// This signal is emitted when the controller disconnects from the remote Low Energy device.
+        #[qsignal]
+        fn disconnected(self: Pin<&mut QLowEnergyController>);
+
+        /// This signal is emitted when the running service discovery finishes.
+        ///
+        /// The signal is not emitted if the discovery process finishes with an error.
+        #[qsignal]
+        #[cxx_name = "discoveryFinished"]
+        fn discovery_finished(self: Pin<&mut QLowEnergyController>);
+
+        /// This signal is emitted each time a new service is discovered.
+        #[qsignal]
+        #[cxx_name = "serviceDiscovered"]
+        fn service_discovered(self: Pin<&mut QLowEnergyController>, new_service: &QBluetoothUuid);
+
+        /// This signal is emitted when the controller's state changes.
+        #[qsignal]
+        #[cxx_name = "stateChanged"]
+        fn state_changed(
+            self: Pin<&mut QLowEnergyController>,
+            state: QLowEnergyControllerControllerState,
+        );
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/connection.h");
+        #[namespace = "rust::cxxqt1"]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-lib-extras/qbluetoothdeviceinfo.h");
+        type QBluetoothDeviceInfo = crate::QBluetoothDeviceInfo;
+        include!("cxx-qt-lib-extras/qbluetoothuuid.h");
+        type QBluetoothUuid = crate::QBluetoothUuid;
+        include!("cxx-qt-lib-extras/qlowenergyservice.h");
+        type QLowEnergyService = crate::QLowEnergyService;
+
+        /// Connects to the remote Bluetooth Low Energy device.
+        ///
+        /// The connected signal is emitted once the connection is established.
+        #[rust_name = "connect_to_device"]
+        fn connectToDevice(self: Pin<&mut QLowEnergyController>);
+
+        /// Disconnects the controller from the remote device.
+        #[rust_name = "disconnect_from_device"]
+        fn disconnectFromDevice(self: Pin<&mut QLowEnergyController>);
+
+        /// Initiates the service discovery process.
+        ///
+        /// The service_discovered signal is emitted for each service and discovery_finished once the process is done.
+        #[rust_name = "discover_services"]
+        fn discoverServices(self: Pin<&mut QLowEnergyController>);
+
+        /// Returns the last occurred error or [QLowEnergyControllerError::NoError].
+        fn error(self: &QLowEnergyController) -> QLowEnergyControllerError;
+
+        /// Returns a textual representation of the last occurred error.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QLowEnergyController) -> QString;
+
+        /// Returns the name of the remote Bluetooth Low Energy device, if the controller is in the central role.
+        #[rust_name = "remote_name"]
+        fn remoteName(self: &QLowEnergyController) -> QString;
+
+        /// Returns the current state of the controller.
+        fn state(self: &QLowEnergyController) -> QLowEnergyControllerControllerState;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QLowEnergyControllerControllerState;
+        type QLowEnergyControllerError;
+
+        #[doc(hidden)]
+        #[rust_name = "qlowenergycontroller_create_central"]
+        fn qlowenergycontrollerCreateCentral(
+            remote_device: &QBluetoothDeviceInfo,
+        ) -> UniquePtr<QLowEnergyController>;
+
+        #[doc(hidden)]
+        #[rust_name = "qlowenergycontroller_create_service_object"]
+        fn qlowenergycontrollerCreateServiceObject(
+            controller: Pin<&mut QLowEnergyController>,
+            service_uuid: &QBluetoothUuid,
+        ) -> UniquePtr<QLowEnergyService>;
+
+        #[doc(hidden)]
+        #[rust_name = "qlowenergycontroller_on_error_occurred"]
+        fn qlowenergycontrollerOnErrorOccurred(
+            controller: Pin<&mut QLowEnergyController>,
+            func: fn(
+                &mut QLowEnergyControllerErrorHandler,
+                Pin<&mut QLowEnergyController>,
+                QLowEnergyControllerError,
+            ),
+            arg: Box<QLowEnergyControllerErrorHandler>,
+        ) -> QMetaObjectConnection;
+
+        #[doc(hidden)]
+        #[rust_name = "qlowenergycontroller_services"]
+        fn qlowenergycontrollerServices(
+            controller: &QLowEnergyController,
+        ) -> UniquePtr<CxxVector<QBluetoothUuid>>;
+    }
+
+    extern "Rust" {
+        #[namespace = "rust::cxxqtlib1"]
+        type QLowEnergyControllerErrorHandler;
+    }
+
+    // QLowEnergyController is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QLowEnergyController>::isRelocatable);
+    impl UniquePtr<QLowEnergyController> {}
+}
+
+use crate::{QBluetoothDeviceInfo, QBluetoothUuid, QLowEnergyService};
+use core::pin::Pin;
+use cxx_qt::QMetaObjectConnection;
+
+pub use ffi::{
+    QLowEnergyController, QLowEnergyControllerControllerState, QLowEnergyControllerError,
+};
+
+impl QLowEnergyController {
+    /// Create a new QLowEnergyController in the central role, which acts as a GATT client for the given remote device.
+    ///
+    /// The remote device is usually found with a [QBluetoothDeviceDiscoveryAgent](crate::QBluetoothDeviceDiscoveryAgent).
+    pub fn create_central(remote_device: &QBluetoothDeviceInfo) -> cxx::UniquePtr<Self> {
+        ffi::qlowenergycontroller_create_central(remote_device)
+    }
+
+    /// Creates an instance of the service represented by the service UUID,
+    /// the details of the service are then discovered with [QLowEnergyService::discover_details].
+    ///
+    /// Returns a null pointer if the service is not known to the controller, as the services must be discovered first.
+    pub fn create_service_object(
+        self: Pin<&mut Self>,
+        service_uuid: &QBluetoothUuid,
+    ) -> cxx::UniquePtr<QLowEnergyService> {
+        ffi::qlowenergycontroller_create_service_object(self, service_uuid)
+    }
+
+    /// Connect a closure which is called when an error occurs in the controller.
+    ///
+    /// The returned [QMetaObjectConnection] can be used to disconnect the closure.
+    pub fn on_error_occurred<F>(self: Pin<&mut Self>, closure: F) -> QMetaObjectConnection
+    where
+        F: FnMut(Pin<&mut QLowEnergyController>, QLowEnergyControllerError) + 'static,
+    {
+        fn func(
+            handler: &mut QLowEnergyControllerErrorHandler,
+            controller: Pin<&mut QLowEnergyController>,
+            error: QLowEnergyControllerError,
+        ) {
+            (handler.inner)(controller, error)
+        }
+        let arg = QLowEnergyControllerErrorHandler {
+            inner: Box::new(closure),
+        };
+        ffi::qlowenergycontroller_on_error_occurred(self, func, Box::new(arg))
+    }
+
+    /// Returns the list of services offered by the remote device, which is populated by [QLowEnergyController::discover_services].
+    pub fn services(&self) -> cxx::UniquePtr<cxx::CxxVector<QBluetoothUuid>> {
+        ffi::qlowenergycontroller_services(self)
+    }
+}
+
+#[doc(hidden)]
+pub struct QLowEnergyControllerErrorHandler {
+    inner: ControllerErrorFn,
+}
+
+type ControllerErrorFn = Box<dyn FnMut(Pin<&mut QLowEnergyController>, QLowEnergyControllerError)>;
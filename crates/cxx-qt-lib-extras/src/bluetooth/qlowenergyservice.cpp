@@ -0,0 +1,69 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qlowenergyservice.h"
+
+#include <QtBluetooth/QLowEnergyDescriptor>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QLowEnergyCharacteristic>
+qlowenergyserviceCharacteristic(const QLowEnergyService& service,
+                                const QBluetoothUuid& uuid)
+{
+  return ::std::make_unique<QLowEnergyCharacteristic>(
+    service.characteristic(uuid));
+}
+
+::std::unique_ptr<::std::vector<QLowEnergyCharacteristic>>
+qlowenergyserviceCharacteristics(const QLowEnergyService& service)
+{
+  const auto characteristics = service.characteristics();
+  return ::std::make_unique<::std::vector<QLowEnergyCharacteristic>>(
+    characteristics.cbegin(), characteristics.cend());
+}
+
+void
+qlowenergyserviceDiscoverDetails(QLowEnergyService& service)
+{
+  // Qt 6 has an optional discovery mode argument
+  service.discoverDetails();
+}
+
+bool
+qlowenergyserviceSetNotificationsEnabled(
+  QLowEnergyService& service,
+  const QLowEnergyCharacteristic& characteristic,
+  bool enabled)
+{
+  // Notifications are enabled by writing to the client characteristic
+  // configuration descriptor of the characteristic
+  const auto descriptor = characteristic.descriptor(QBluetoothUuid(
+    QBluetoothUuid::DescriptorType::ClientCharacteristicConfiguration));
+  if (!descriptor.isValid()) {
+    return false;
+  }
+
+  service.writeDescriptor(descriptor,
+                          enabled ? QByteArray::fromHex("0100")
+                                  : QByteArray::fromHex("0000"));
+  return true;
+}
+
+void
+qlowenergyserviceWriteCharacteristic(
+  QLowEnergyService& service,
+  const QLowEnergyCharacteristic& characteristic,
+  const QByteArray& value,
+  QLowEnergyServiceWriteMode mode)
+{
+  service.writeCharacteristic(characteristic, value, mode);
+}
+
+}
+}
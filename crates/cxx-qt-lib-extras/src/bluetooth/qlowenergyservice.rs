@@ -0,0 +1,328 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// This enum describes the errors that may occur in the service.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QLowEnergyServiceServiceError {
+        /// No error has occurred.
+        NoError = 0,
+        /// An operation was attempted while the service was not ready.
+        OperationError,
+        /// An attempt to write a new value to a characteristic failed.
+        CharacteristicWriteError,
+        /// An attempt to write a new value to a descriptor failed.
+        DescriptorWriteError,
+        /// An unknown error occurred when interacting with the service.
+        UnknownError,
+        /// An attempt to read a characteristic value failed.
+        CharacteristicReadError,
+        /// An attempt to read a descriptor value failed.
+        DescriptorReadError,
+    }
+
+    /// This enum describes the state of the service object.
+    ///
+    /// This is defined by CXX-Qt as the names of the states differ between Qt 5 and Qt 6,
+    /// the names from Qt 6 are used here.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QLowEnergyServiceServiceState {
+        /// A service can become invalid when it loses the connection to the underlying device.
+        InvalidService = 0,
+        /// The service details are yet to be discovered with [QLowEnergyService::discover_details].
+        RemoteService,
+        /// The service details are being discovered.
+        RemoteServiceDiscovering,
+        /// The service details have been discovered.
+        RemoteServiceDiscovered,
+        /// The service is associated with a controller object in the peripheral role.
+        LocalService,
+    }
+
+    /// This enum describes the mode to be used when writing a characteristic value.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QLowEnergyServiceWriteMode {
+        /// The write is acknowledged by the remote device,
+        /// then the characteristic_written signal is emitted.
+        WriteWithResponse = 0,
+        /// The write is not acknowledged by the remote device.
+        WriteWithoutResponse,
+        /// The write is signed and not acknowledged by the remote device.
+        WriteSigned,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qlowenergyservice.h");
+        /// Represents an individual service on a Bluetooth Low Energy device,
+        /// it is created with [QLowEnergyController::create_service_object](crate::QLowEnergyController::create_service_object).
+        #[qobject]
+        type QLowEnergyService;
+
+        /// This signal is emitted when the value of characteristic is changed by an event on the peripheral,
+        /// such as a notification which was enabled with [QLowEnergyService::set_notifications_enabled].
+        #[qsignal]
+        #[cxx_name = "characteristicChanged"]
+        fn characteristic_changed(
+            self: Pin<&mut QLowEnergyService>,
+            characteristic: &QLowEnergyCharacteristic,
+            new_value: &QByteArray,
+        );
+
+        /// This signal is emitted when the read request for characteristic successfully returned its value.
+        #[qsignal]
+        #[cxx_name = "characteristicRead"]
+        fn characteristic_read(
+            self: Pin<&mut QLowEnergyService>,
+            characteristic: &QLowEnergyCharacteristic,
+            value: &QByteArray,
+        );
+
+        /// This signal is emitted when the value of characteristic is successfully changed to new_value,
+        /// if the write was made with [QLowEnergyServiceWriteMode::WriteWithResponse].
+        #[qsignal]
+        #[cxx_name = "characteristicWritten"]
+        fn characteristic_written(
+            self: Pin<&mut QLowEnergyService>,
+            characteristic: &QLowEnergyCharacteristic,
+            new_value: &QByteArray,
+        );
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/connection.h");
+        #[namespace = "rust::cxxqt1"]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-lib-extras/qbluetoothuuid.h");
+        type QBluetoothUuid = crate::QBluetoothUuid;
+        include!("cxx-qt-lib-extras/qlowenergycharacteristic.h");
+        type QLowEnergyCharacteristic = crate::QLowEnergyCharacteristic;
+
+        /// Returns the last occurred error or [QLowEnergyServiceServiceError::NoError].
+        fn error(self: &QLowEnergyService) -> QLowEnergyServiceServiceError;
+
+        /// Reads the value of characteristic, the characteristic_read signal is emitted once the value has been read.
+        #[rust_name = "read_characteristic"]
+        fn readCharacteristic(
+            self: Pin<&mut QLowEnergyService>,
+            characteristic: &QLowEnergyCharacteristic,
+        );
+
+        /// Returns the name of the service, if it is a standard service.
+        #[rust_name = "service_name"]
+        fn serviceName(self: &QLowEnergyService) -> QString;
+
+        /// Returns the UUID of the service.
+        #[rust_name = "service_uuid"]
+        fn serviceUuid(self: &QLowEnergyService) -> QBluetoothUuid;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QLowEnergyServiceServiceError;
+        type QLowEnergyServiceWriteMode;
+
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_characteristic"]
+        fn qlowenergyserviceCharacteristic(
+            service: &QLowEnergyService,
+            uuid: &QBluetoothUuid,
+        ) -> UniquePtr<QLowEnergyCharacteristic>;
+
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_characteristics"]
+        fn qlowenergyserviceCharacteristics(
+            service: &QLowEnergyService,
+        ) -> UniquePtr<CxxVector<QLowEnergyCharacteristic>>;
+
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_discover_details"]
+        fn qlowenergyserviceDiscoverDetails(service: Pin<&mut QLowEnergyService>);
+
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_on_error_occurred"]
+        fn qlowenergyserviceOnErrorOccurred(
+            service: Pin<&mut QLowEnergyService>,
+            func: fn(
+                &mut QLowEnergyServiceErrorHandler,
+                Pin<&mut QLowEnergyService>,
+                QLowEnergyServiceServiceError,
+            ),
+            arg: Box<QLowEnergyServiceErrorHandler>,
+        ) -> QMetaObjectConnection;
+
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_on_state_changed"]
+        fn qlowenergyserviceOnStateChanged(
+            service: Pin<&mut QLowEnergyService>,
+            func: fn(
+                &mut QLowEnergyServiceStateHandler,
+                Pin<&mut QLowEnergyService>,
+                QLowEnergyServiceServiceState,
+            ),
+            arg: Box<QLowEnergyServiceStateHandler>,
+        ) -> QMetaObjectConnection;
+
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_set_notifications_enabled"]
+        fn qlowenergyserviceSetNotificationsEnabled(
+            service: Pin<&mut QLowEnergyService>,
+            characteristic: &QLowEnergyCharacteristic,
+            enabled: bool,
+        ) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_state"]
+        fn qlowenergyserviceState(service: &QLowEnergyService) -> QLowEnergyServiceServiceState;
+
+        #[doc(hidden)]
+        #[rust_name = "qlowenergyservice_write_characteristic"]
+        fn qlowenergyserviceWriteCharacteristic(
+            service: Pin<&mut QLowEnergyService>,
+            characteristic: &QLowEnergyCharacteristic,
+            value: &QByteArray,
+            mode: QLowEnergyServiceWriteMode,
+        );
+    }
+
+    extern "Rust" {
+        #[namespace = "rust::cxxqtlib1"]
+        type QLowEnergyServiceErrorHandler;
+
+        #[namespace = "rust::cxxqtlib1"]
+        type QLowEnergyServiceStateHandler;
+    }
+
+    // QLowEnergyService is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QLowEnergyService>::isRelocatable);
+    impl UniquePtr<QLowEnergyService> {}
+}
+
+use crate::{QBluetoothUuid, QLowEnergyCharacteristic};
+use core::pin::Pin;
+use cxx_qt::QMetaObjectConnection;
+use cxx_qt_lib::QByteArray;
+
+pub use ffi::{
+    QLowEnergyService, QLowEnergyServiceServiceError, QLowEnergyServiceServiceState,
+    QLowEnergyServiceWriteMode,
+};
+
+impl QLowEnergyService {
+    /// Returns the characteristic with the given UUID,
+    /// or an invalid characteristic if the service does not have a matching characteristic.
+    pub fn characteristic(
+        &self,
+        uuid: &QBluetoothUuid,
+    ) -> cxx::UniquePtr<QLowEnergyCharacteristic> {
+        ffi::qlowenergyservice_characteristic(self, uuid)
+    }
+
+    /// Returns all characteristics of the service, which are available once the details have been discovered.
+    pub fn characteristics(&self) -> cxx::UniquePtr<cxx::CxxVector<QLowEnergyCharacteristic>> {
+        ffi::qlowenergyservice_characteristics(self)
+    }
+
+    /// Initiates the discovery of the characteristics and descriptors of the service.
+    ///
+    /// The state of the service changes to [QLowEnergyServiceServiceState::RemoteServiceDiscovered] once the discovery is done.
+    pub fn discover_details(self: Pin<&mut Self>) {
+        ffi::qlowenergyservice_discover_details(self)
+    }
+
+    /// Connect a closure which is called when an error occurs in the service.
+    ///
+    /// The returned [QMetaObjectConnection] can be used to disconnect the closure.
+    pub fn on_error_occurred<F>(self: Pin<&mut Self>, closure: F) -> QMetaObjectConnection
+    where
+        F: FnMut(Pin<&mut QLowEnergyService>, QLowEnergyServiceServiceError) + 'static,
+    {
+        fn func(
+            handler: &mut QLowEnergyServiceErrorHandler,
+            service: Pin<&mut QLowEnergyService>,
+            error: QLowEnergyServiceServiceError,
+        ) {
+            (handler.inner)(service, error)
+        }
+        let arg = QLowEnergyServiceErrorHandler {
+            inner: Box::new(closure),
+        };
+        ffi::qlowenergyservice_on_error_occurred(self, func, Box::new(arg))
+    }
+
+    /// Connect a closure which is called when the state of the service changes.
+    ///
+    /// The returned [QMetaObjectConnection] can be used to disconnect the closure.
+    pub fn on_state_changed<F>(self: Pin<&mut Self>, closure: F) -> QMetaObjectConnection
+    where
+        F: FnMut(Pin<&mut QLowEnergyService>, QLowEnergyServiceServiceState) + 'static,
+    {
+        fn func(
+            handler: &mut QLowEnergyServiceStateHandler,
+            service: Pin<&mut QLowEnergyService>,
+            state: QLowEnergyServiceServiceState,
+        ) {
+            (handler.inner)(service, state)
+        }
+        let arg = QLowEnergyServiceStateHandler {
+            inner: Box::new(closure),
+        };
+        ffi::qlowenergyservice_on_state_changed(self, func, Box::new(arg))
+    }
+
+    /// Enables or disables notifications for the characteristic, by writing to its client characteristic configuration descriptor.
+    ///
+    /// The characteristic_changed signal is then emitted when the peripheral notifies a new value.
+    /// Returns false if the characteristic does not support notifications.
+    pub fn set_notifications_enabled(
+        self: Pin<&mut Self>,
+        characteristic: &QLowEnergyCharacteristic,
+        enabled: bool,
+    ) -> bool {
+        ffi::qlowenergyservice_set_notifications_enabled(self, characteristic, enabled)
+    }
+
+    /// Returns the current state of the service.
+    pub fn state(&self) -> QLowEnergyServiceServiceState {
+        ffi::qlowenergyservice_state(self)
+    }
+
+    /// Writes the value to the characteristic using the given mode.
+    pub fn write_characteristic(
+        self: Pin<&mut Self>,
+        characteristic: &QLowEnergyCharacteristic,
+        value: &QByteArray,
+        mode: QLowEnergyServiceWriteMode,
+    ) {
+        ffi::qlowenergyservice_write_characteristic(self, characteristic, value, mode)
+    }
+}
+
+#[doc(hidden)]
+pub struct QLowEnergyServiceErrorHandler {
+    inner: ServiceErrorFn,
+}
+
+type ServiceErrorFn = Box<dyn FnMut(Pin<&mut QLowEnergyService>, QLowEnergyServiceServiceError)>;
+
+#[doc(hidden)]
+pub struct QLowEnergyServiceStateHandler {
+    inner: ServiceStateFn,
+}
+
+type ServiceStateFn = Box<dyn FnMut(Pin<&mut QLowEnergyService>, QLowEnergyServiceServiceState)>;
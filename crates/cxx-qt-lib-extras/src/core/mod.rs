@@ -3,6 +3,15 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod qabstractanimation;
+pub use qabstractanimation::{QAbstractAnimationDirection, QAbstractAnimationState};
+
+mod qdeadlinetimer;
+pub use qdeadlinetimer::QDeadlineTimer;
+
+mod qeasingcurve;
+pub use qeasingcurve::{QEasingCurve, QEasingCurveType};
+
 mod qelapsedtimer;
 pub use qelapsedtimer::QElapsedTimer;
 
@@ -11,3 +20,21 @@ pub use qcommandlineoption::QCommandLineOption;
 
 mod qcommandlineparser;
 pub use qcommandlineparser::QCommandLineParser;
+
+mod qiodevice;
+pub use qiodevice::QIODevice;
+
+mod qparallelanimationgroup;
+pub use qparallelanimationgroup::QParallelAnimationGroup;
+
+mod qpropertyanimation;
+pub use qpropertyanimation::QPropertyAnimation;
+
+mod qrandomgenerator;
+pub use qrandomgenerator::QRandomGenerator;
+
+mod qstringlistmodel;
+pub use qstringlistmodel::QStringListModel;
+
+mod qthread;
+pub use qthread::{QThread, QThreadWorker};
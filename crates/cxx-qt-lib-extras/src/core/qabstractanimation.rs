@@ -0,0 +1,40 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the direction of the animation when in Running state.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QAbstractAnimationDirection {
+        /// The current time of the animation increases with time (i.e., moves from 0 and towards the end / duration).
+        Forward = 0,
+        /// The current time of the animation decreases with time (i.e., moves from the end / duration and towards 0).
+        Backward = 1,
+    }
+
+    /// This enum describes the state of the animation.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QAbstractAnimationState {
+        /// The animation is not running.
+        Stopped = 0,
+        /// The animation is paused (i.e., temporarily suspended).
+        Paused = 1,
+        /// The animation is running.
+        Running = 2,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qabstractanimation.h");
+        type QAbstractAnimationDirection;
+        type QAbstractAnimationState;
+    }
+}
+
+pub use ffi::{QAbstractAnimationDirection, QAbstractAnimationState};
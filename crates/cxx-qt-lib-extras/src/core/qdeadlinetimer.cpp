@@ -0,0 +1,99 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qdeadlinetimer.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+#include <cstdint>
+
+// QDeadlineTimer has an "int64" member and two "unsigned" members
+// Rust represents these as a 64-bit integer and two 32-bit integers.
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/kernel/qdeadlinetimer.h?h=v5.15.6-lts-lgpl#n174
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/kernel/qdeadlinetimer.h?h=v6.2.4#n166
+assert_alignment_and_size(QDeadlineTimer, {
+  ::std::int64_t a0;
+  ::std::uint32_t a1;
+  ::std::uint32_t a2;
+});
+
+static_assert(::std::is_trivially_copyable<QDeadlineTimer>::value,
+              "QDeadlineTimer must be trivially copyable!");
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QDeadlineTimer
+qdeadlinetimerCurrent(Qt::TimerType timerType)
+{
+  return QDeadlineTimer::current(timerType);
+}
+
+::std::int64_t
+qdeadlinetimerDeadline(const QDeadlineTimer& deadlineTimer)
+{
+  return static_cast<::std::int64_t>(deadlineTimer.deadline());
+}
+
+::std::int64_t
+qdeadlinetimerDeadlineNSecs(const QDeadlineTimer& deadlineTimer)
+{
+  return static_cast<::std::int64_t>(deadlineTimer.deadlineNSecs());
+}
+
+QDeadlineTimer
+qdeadlinetimerForever(Qt::TimerType timerType)
+{
+  return QDeadlineTimer(QDeadlineTimer::Forever, timerType);
+}
+
+QDeadlineTimer
+qdeadlinetimerInit(::std::int64_t msecs, Qt::TimerType timerType)
+{
+  return QDeadlineTimer(static_cast<qint64>(msecs), timerType);
+}
+
+::std::int64_t
+qdeadlinetimerRemainingTime(const QDeadlineTimer& deadlineTimer)
+{
+  return static_cast<::std::int64_t>(deadlineTimer.remainingTime());
+}
+
+::std::int64_t
+qdeadlinetimerRemainingTimeNSecs(const QDeadlineTimer& deadlineTimer)
+{
+  return static_cast<::std::int64_t>(deadlineTimer.remainingTimeNSecs());
+}
+
+void
+qdeadlinetimerSetDeadline(QDeadlineTimer& deadlineTimer,
+                          ::std::int64_t msecs,
+                          Qt::TimerType timerType)
+{
+  deadlineTimer.setDeadline(static_cast<qint64>(msecs), timerType);
+}
+
+void
+qdeadlinetimerSetPreciseRemainingTime(QDeadlineTimer& deadlineTimer,
+                                      ::std::int64_t secs,
+                                      ::std::int64_t nsecs,
+                                      Qt::TimerType timerType)
+{
+  deadlineTimer.setPreciseRemainingTime(
+    static_cast<qint64>(secs), static_cast<qint64>(nsecs), timerType);
+}
+
+void
+qdeadlinetimerSetRemainingTime(QDeadlineTimer& deadlineTimer,
+                               ::std::int64_t msecs,
+                               Qt::TimerType timerType)
+{
+  deadlineTimer.setRemainingTime(static_cast<qint64>(msecs), timerType);
+}
+
+}
+}
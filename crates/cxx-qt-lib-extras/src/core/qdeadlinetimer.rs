@@ -0,0 +1,266 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use cxx_qt_lib::TimerType;
+use std::cmp::Ordering;
+use std::time::Duration;
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type TimerType = cxx_qt_lib::TimerType;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qdeadlinetimer.h");
+        type QDeadlineTimer = crate::QDeadlineTimer;
+
+        /// Returns true if this QDeadlineTimer object has expired, false if there remains time left.
+        /// For objects that have expired, remaining_time() will return zero and deadline() will return a time point in the past.
+        ///
+        /// QDeadlineTimer objects created with the forever constructor never expire.
+        #[rust_name = "has_expired"]
+        fn hasExpired(self: &QDeadlineTimer) -> bool;
+
+        /// Returns true if this QDeadlineTimer object never expires, false otherwise.
+        #[rust_name = "is_forever"]
+        fn isForever(self: &QDeadlineTimer) -> bool;
+
+        /// Changes the timer type for this object to timer_type.
+        #[rust_name = "set_timer_type"]
+        fn setTimerType(self: &mut QDeadlineTimer, timer_type: TimerType);
+
+        /// Returns the timer type that is active for this object.
+        #[rust_name = "timer_type"]
+        fn timerType(self: &QDeadlineTimer) -> TimerType;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qdeadlinetimer_current"]
+        fn qdeadlinetimerCurrent(timer_type: TimerType) -> QDeadlineTimer;
+        #[doc(hidden)]
+        #[rust_name = "qdeadlinetimer_deadline"]
+        fn qdeadlinetimerDeadline(d: &QDeadlineTimer) -> i64;
+        #[doc(hidden)]
+        #[rust_name = "qdeadlinetimer_deadline_nsecs"]
+        fn qdeadlinetimerDeadlineNSecs(d: &QDeadlineTimer) -> i64;
+        #[doc(hidden)]
+        #[rust_name = "qdeadlinetimer_forever"]
+        fn qdeadlinetimerForever(timer_type: TimerType) -> QDeadlineTimer;
+        #[doc(hidden)]
+        #[rust_name = "qdeadlinetimer_init"]
+        fn qdeadlinetimerInit(msecs: i64, timer_type: TimerType) -> QDeadlineTimer;
+        #[doc(hidden)]
+        #[rust_name = "qdeadlinetimer_remaining_time"]
+        fn qdeadlinetimerRemainingTime(d: &QDeadlineTimer) -> i64;
+        #[doc(hidden)]
+        #[rust_name = "qdeadlinetimer_remaining_time_nsecs"]
+        fn qdeadlinetimerRemainingTimeNSecs(d: &QDeadlineTimer) -> i64;
+        #[doc(hidden)]
+        #[rust_name = "qdeadlinetimer_set_deadline"]
+        fn qdeadlinetimerSetDeadline(d: &mut QDeadlineTimer, msecs: i64, timer_type: TimerType);
+        #[doc(hidden)]
+        #[rust_name = "qdeadlinetimer_set_precise_remaining_time"]
+        fn qdeadlinetimerSetPreciseRemainingTime(
+            d: &mut QDeadlineTimer,
+            secs: i64,
+            nsecs: i64,
+            timer_type: TimerType,
+        );
+        #[doc(hidden)]
+        #[rust_name = "qdeadlinetimer_set_remaining_time"]
+        fn qdeadlinetimerSetRemainingTime(
+            d: &mut QDeadlineTimer,
+            msecs: i64,
+            timer_type: TimerType,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qdeadlinetimer_init_default"]
+        fn construct() -> QDeadlineTimer;
+        #[doc(hidden)]
+        #[rust_name = "qdeadlinetimer_cmp"]
+        fn operatorCmp(a: &QDeadlineTimer, b: &QDeadlineTimer) -> i8;
+        #[doc(hidden)]
+        #[rust_name = "qdeadlinetimer_eq"]
+        fn operatorEq(a: &QDeadlineTimer, b: &QDeadlineTimer) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qdeadlinetimer_plus"]
+        fn operatorPlus(a: &QDeadlineTimer, b: &i64) -> QDeadlineTimer;
+        #[doc(hidden)]
+        #[rust_name = "qdeadlinetimer_minus"]
+        fn operatorMinus(a: &QDeadlineTimer, b: &i64) -> QDeadlineTimer;
+    }
+}
+
+/// The QDeadlineTimer struct marks a deadline in the future.
+///
+/// It uses the same monotonic clock as Qt, so it can be passed to Qt functions which
+/// take a deadline and have the same meaning as on the C++ side.
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct QDeadlineTimer {
+    t1: i64,
+    t2: u32,
+    timer_type: u32,
+}
+
+impl Default for QDeadlineTimer {
+    /// Constructs an expired QDeadlineTimer object with the coarse timer type.
+    fn default() -> Self {
+        ffi::qdeadlinetimer_init_default()
+    }
+}
+
+impl PartialEq for QDeadlineTimer {
+    /// Returns true if the deadlines of both timers are the same, the timer type is not compared.
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qdeadlinetimer_eq(self, other)
+    }
+}
+
+impl Eq for QDeadlineTimer {}
+
+impl PartialOrd for QDeadlineTimer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QDeadlineTimer {
+    /// Compares the deadlines of the timers, a timer which never expires is greater than any other.
+    fn cmp(&self, other: &Self) -> Ordering {
+        ffi::qdeadlinetimer_cmp(self, other).cmp(&0)
+    }
+}
+
+impl std::ops::Add<i64> for QDeadlineTimer {
+    type Output = Self;
+    /// Returns a QDeadlineTimer object whose deadline is msecs later than the deadline stored in self.
+    fn add(self, msecs: i64) -> Self {
+        ffi::qdeadlinetimer_plus(&self, &msecs)
+    }
+}
+
+impl std::ops::Sub<i64> for QDeadlineTimer {
+    type Output = Self;
+    /// Returns a QDeadlineTimer object whose deadline is msecs before the deadline stored in self.
+    fn sub(self, msecs: i64) -> Self {
+        ffi::qdeadlinetimer_minus(&self, &msecs)
+    }
+}
+
+impl From<Duration> for QDeadlineTimer {
+    /// Constructs a QDeadlineTimer object with the coarse timer type which expires after the given duration.
+    fn from(remaining: Duration) -> Self {
+        let mut deadline = Self::default();
+        ffi::qdeadlinetimer_set_precise_remaining_time(
+            &mut deadline,
+            i64::try_from(remaining.as_secs()).unwrap_or(i64::MAX),
+            i64::from(remaining.subsec_nanos()),
+            TimerType::CoarseTimer,
+        );
+        deadline
+    }
+}
+
+impl QDeadlineTimer {
+    /// Returns a QDeadlineTimer that is expired but is guaranteed to contain the current time.
+    pub fn current(timer_type: TimerType) -> Self {
+        ffi::qdeadlinetimer_current(timer_type)
+    }
+
+    /// Returns the absolute time point for the deadline stored in this object,
+    /// calculated in milliseconds relative to the reference clock, the same as [QElapsedTimer::msecs_since_reference](crate::QElapsedTimer::msecs_since_reference).
+    ///
+    /// If this QDeadlineTimer never expires, this function returns i64::MAX.
+    pub fn deadline(&self) -> i64 {
+        ffi::qdeadlinetimer_deadline(self)
+    }
+
+    /// Returns the absolute time point for the deadline stored in this object,
+    /// calculated in nanoseconds relative to the reference clock.
+    ///
+    /// If this QDeadlineTimer never expires, this function returns i64::MAX.
+    pub fn deadline_nsecs(&self) -> i64 {
+        ffi::qdeadlinetimer_deadline_nsecs(self)
+    }
+
+    /// Constructs a QDeadlineTimer object that never expires.
+    pub fn forever(timer_type: TimerType) -> Self {
+        ffi::qdeadlinetimer_forever(timer_type)
+    }
+
+    /// Constructs a QDeadlineTimer object with an expiry time of msecs from the moment of the creation of this object.
+    ///
+    /// If msecs is negative, the QDeadlineTimer will be set so it never expires.
+    pub fn new(msecs: i64, timer_type: TimerType) -> Self {
+        ffi::qdeadlinetimer_init(msecs, timer_type)
+    }
+
+    /// Returns the remaining time in this QDeadlineTimer object in milliseconds.
+    ///
+    /// If the timer has already expired, this function will return zero,
+    /// and if it never expires this function returns -1.
+    pub fn remaining_time(&self) -> i64 {
+        ffi::qdeadlinetimer_remaining_time(self)
+    }
+
+    /// Returns the remaining time in this QDeadlineTimer object in nanoseconds.
+    ///
+    /// If the timer has already expired, this function will return zero,
+    /// and if it never expires this function returns -1.
+    pub fn remaining_time_nsecs(&self) -> i64 {
+        ffi::qdeadlinetimer_remaining_time_nsecs(self)
+    }
+
+    /// Returns the remaining time in this QDeadlineTimer object,
+    /// or None if it never expires.
+    pub fn remaining_duration(&self) -> Option<Duration> {
+        if self.is_forever() {
+            None
+        } else {
+            Some(Duration::from_nanos(
+                self.remaining_time_nsecs().max(0) as u64
+            ))
+        }
+    }
+
+    /// Sets the deadline for this QDeadlineTimer object to be the msecs absolute time point,
+    /// counted in milliseconds since the reference clock, and the timer type to timer_type.
+    pub fn set_deadline(&mut self, msecs: i64, timer_type: TimerType) {
+        ffi::qdeadlinetimer_set_deadline(self, msecs, timer_type)
+    }
+
+    /// Sets the remaining time for this QDeadlineTimer object to secs seconds plus nsecs nanoseconds
+    /// from now, if secs has a negative value, this QDeadlineTimer will be set so it never expires.
+    pub fn set_precise_remaining_time(&mut self, secs: i64, nsecs: i64, timer_type: TimerType) {
+        ffi::qdeadlinetimer_set_precise_remaining_time(self, secs, nsecs, timer_type)
+    }
+
+    /// Sets the remaining time for this QDeadlineTimer object to msecs milliseconds from now,
+    /// if msecs has a value of -1, this QDeadlineTimer will be set so it never expires.
+    pub fn set_remaining_time(&mut self, msecs: i64, timer_type: TimerType) {
+        ffi::qdeadlinetimer_set_remaining_time(self, msecs, timer_type)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side ensure that QDeadlineTimer is trivial.
+unsafe impl ExternType for QDeadlineTimer {
+    type Id = type_id!("QDeadlineTimer");
+    type Kind = cxx::kind::Trivial;
+}
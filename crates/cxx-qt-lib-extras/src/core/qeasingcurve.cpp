@@ -0,0 +1,23 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qeasingcurve.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+#include <cstdint>
+
+// QEasingCurve has 1 pointer
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/tools/qeasingcurve.h?h=v5.15.6-lts-lgpl
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/tools/qeasingcurve.h?h=v6.2.4
+assert_alignment_and_size(QEasingCurve, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QEasingCurve>::value);
+static_assert(!::std::is_trivially_copy_constructible<QEasingCurve>::value);
+
+static_assert(!::std::is_trivially_destructible<QEasingCurve>::value);
+static_assert(QTypeInfo<QEasingCurve>::isRelocatable);
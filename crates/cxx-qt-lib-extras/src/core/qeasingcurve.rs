@@ -0,0 +1,255 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// The type of easing curve, which matches the easing types of QML animations.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QEasingCurveType {
+        /// Easing curve for a linear (t) function: velocity is constant.
+        Linear = 0,
+        /// Easing curve for a quadratic (t^2) function: accelerating from zero velocity.
+        InQuad = 1,
+        /// Easing curve for a quadratic (t^2) function: decelerating to zero velocity.
+        OutQuad = 2,
+        /// Easing curve for a quadratic (t^2) function: acceleration until halfway, then deceleration.
+        InOutQuad = 3,
+        /// Easing curve for a quadratic (t^2) function: deceleration until halfway, then acceleration.
+        OutInQuad = 4,
+        /// Easing curve for a cubic (t^3) function: accelerating from zero velocity.
+        InCubic = 5,
+        /// Easing curve for a cubic (t^3) function: decelerating to zero velocity.
+        OutCubic = 6,
+        /// Easing curve for a cubic (t^3) function: acceleration until halfway, then deceleration.
+        InOutCubic = 7,
+        /// Easing curve for a cubic (t^3) function: deceleration until halfway, then acceleration.
+        OutInCubic = 8,
+        /// Easing curve for a quartic (t^4) function: accelerating from zero velocity.
+        InQuart = 9,
+        /// Easing curve for a quartic (t^4) function: decelerating to zero velocity.
+        OutQuart = 10,
+        /// Easing curve for a quartic (t^4) function: acceleration until halfway, then deceleration.
+        InOutQuart = 11,
+        /// Easing curve for a quartic (t^4) function: deceleration until halfway, then acceleration.
+        OutInQuart = 12,
+        /// Easing curve for a quintic (t^5) function: accelerating from zero velocity.
+        InQuint = 13,
+        /// Easing curve for a quintic (t^5) function: decelerating to zero velocity.
+        OutQuint = 14,
+        /// Easing curve for a quintic (t^5) function: acceleration until halfway, then deceleration.
+        InOutQuint = 15,
+        /// Easing curve for a quintic (t^5) function: deceleration until halfway, then acceleration.
+        OutInQuint = 16,
+        /// Easing curve for a sinusoidal (sin(t)) function: accelerating from zero velocity.
+        InSine = 17,
+        /// Easing curve for a sinusoidal (sin(t)) function: decelerating to zero velocity.
+        OutSine = 18,
+        /// Easing curve for a sinusoidal (sin(t)) function: acceleration until halfway, then deceleration.
+        InOutSine = 19,
+        /// Easing curve for a sinusoidal (sin(t)) function: deceleration until halfway, then acceleration.
+        OutInSine = 20,
+        /// Easing curve for a exponential (2^t) function: accelerating from zero velocity.
+        InExpo = 21,
+        /// Easing curve for a exponential (2^t) function: decelerating to zero velocity.
+        OutExpo = 22,
+        /// Easing curve for a exponential (2^t) function: acceleration until halfway, then deceleration.
+        InOutExpo = 23,
+        /// Easing curve for a exponential (2^t) function: deceleration until halfway, then acceleration.
+        OutInExpo = 24,
+        /// Easing curve for a circular (sqrt(1-t^2)) function: accelerating from zero velocity.
+        InCirc = 25,
+        /// Easing curve for a circular (sqrt(1-t^2)) function: decelerating to zero velocity.
+        OutCirc = 26,
+        /// Easing curve for a circular (sqrt(1-t^2)) function: acceleration until halfway, then deceleration.
+        InOutCirc = 27,
+        /// Easing curve for a circular (sqrt(1-t^2)) function: deceleration until halfway, then acceleration.
+        OutInCirc = 28,
+        /// Easing curve for a elastic (exponentially decaying sine wave) function: accelerating from zero velocity.
+        InElastic = 29,
+        /// Easing curve for a elastic (exponentially decaying sine wave) function: decelerating to zero velocity.
+        OutElastic = 30,
+        /// Easing curve for a elastic (exponentially decaying sine wave) function: acceleration until halfway, then deceleration.
+        InOutElastic = 31,
+        /// Easing curve for a elastic (exponentially decaying sine wave) function: deceleration until halfway, then acceleration.
+        OutInElastic = 32,
+        /// Easing curve for a back (overshooting cubic) function: accelerating from zero velocity.
+        InBack = 33,
+        /// Easing curve for a back (overshooting cubic) function: decelerating to zero velocity.
+        OutBack = 34,
+        /// Easing curve for a back (overshooting cubic) function: acceleration until halfway, then deceleration.
+        InOutBack = 35,
+        /// Easing curve for a back (overshooting cubic) function: deceleration until halfway, then acceleration.
+        OutInBack = 36,
+        /// Easing curve for a bounce (exponentially decaying parabolic bounce) function: accelerating from zero velocity.
+        InBounce = 37,
+        /// Easing curve for a bounce (exponentially decaying parabolic bounce) function: decelerating to zero velocity.
+        OutBounce = 38,
+        /// Easing curve for a bounce (exponentially decaying parabolic bounce) function: acceleration until halfway, then deceleration.
+        InOutBounce = 39,
+        /// Easing curve for a bounce (exponentially decaying parabolic bounce) function: deceleration until halfway, then acceleration.
+        OutInBounce = 40,
+        /// Easing curve which starts slowly and accelerates, used internally by QML.
+        InCurve = 41,
+        /// Easing curve which starts quickly and decelerates, used internally by QML.
+        OutCurve = 42,
+        /// Easing curve for a sine wave, used internally by QML.
+        SineCurve = 43,
+        /// Easing curve for a cosine wave, used internally by QML.
+        CosineCurve = 44,
+        /// Allows defining a custom easing curve using a cubic bezier spline.
+        BezierSpline = 45,
+        /// Allows defining a custom easing curve using a TCB spline.
+        TCBSpline = 46,
+        /// This is returned if the user specified a custom curve type with a custom function.
+        Custom = 47,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qeasingcurve.h");
+        type QEasingCurve = super::QEasingCurve;
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = cxx_qt_lib::QPointF;
+
+        /// Adds a segment of a cubic bezier spline to define a custom easing curve,
+        /// which is only applicable if the type is [QEasingCurveType::BezierSpline].
+        ///
+        /// The spline starts at the end point of the previous segment, or at (0, 0) for the first segment,
+        /// and uses c1 and c2 as control points. The last end point must be (1, 1).
+        #[rust_name = "add_cubic_bezier_segment"]
+        fn addCubicBezierSegment(
+            self: &mut QEasingCurve,
+            c1: &QPointF,
+            c2: &QPointF,
+            end_point: &QPointF,
+        );
+
+        /// Adds a segment of a TCB bezier spline to define a custom easing curve,
+        /// which is only applicable if the type is [QEasingCurveType::TCBSpline].
+        ///
+        /// The spline has to start explicitly at (0, 0) and has to end at (1, 1),
+        /// t is the tension, c the continuity and b the bias of the spline at the point.
+        #[rust_name = "add_tcb_segment"]
+        fn addTCBSegment(self: &mut QEasingCurve, next_point: &QPointF, t: f64, c: f64, b: f64);
+
+        /// Returns the amplitude, which is only applicable to the bounce and elastic curves.
+        fn amplitude(self: &QEasingCurve) -> f64;
+
+        /// Returns the overshoot, which is only applicable to the back curves.
+        fn overshoot(self: &QEasingCurve) -> f64;
+
+        /// Returns the period, which is only applicable to the elastic curves.
+        fn period(self: &QEasingCurve) -> f64;
+
+        /// Sets the amplitude to amplitude, which is the strength of the bounce or elastic oscillation.
+        #[rust_name = "set_amplitude"]
+        fn setAmplitude(self: &mut QEasingCurve, amplitude: f64);
+
+        /// Sets the overshoot to overshoot, where 0 produces no overshoot and the default of 1.70158 produces 10% overshoot.
+        #[rust_name = "set_overshoot"]
+        fn setOvershoot(self: &mut QEasingCurve, overshoot: f64);
+
+        /// Sets the period to period, where a small period gives a high frequency of the elastic curve.
+        #[rust_name = "set_period"]
+        fn setPeriod(self: &mut QEasingCurve, period: f64);
+
+        /// Sets the type of the easing curve to curve_type.
+        #[cxx_name = "setType"]
+        fn set_curve_type(self: &mut QEasingCurve, curve_type: QEasingCurveType);
+
+        /// Returns the type of the easing curve.
+        #[cxx_name = "type"]
+        fn curve_type(self: &QEasingCurve) -> QEasingCurveType;
+
+        /// Return the effective progress for the easing curve at progress,
+        /// which is the same value as a QML animation with this easing curve would use.
+        ///
+        /// While progress must be between 0 and 1, the returned effective progress can be outside those bounds,
+        /// for example with the back and elastic curves.
+        #[rust_name = "value_for_progress"]
+        fn valueForProgress(self: &QEasingCurve, progress: f64) -> f64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+        type QEasingCurveType;
+
+        #[doc(hidden)]
+        #[rust_name = "qeasingcurve_drop"]
+        fn drop(curve: &mut QEasingCurve);
+
+        #[doc(hidden)]
+        #[rust_name = "qeasingcurve_init_default"]
+        fn construct() -> QEasingCurve;
+
+        #[doc(hidden)]
+        #[rust_name = "qeasingcurve_init_type"]
+        fn construct(curve_type: QEasingCurveType) -> QEasingCurve;
+
+        #[doc(hidden)]
+        #[rust_name = "qeasingcurve_clone"]
+        fn construct(curve: &QEasingCurve) -> QEasingCurve;
+
+        #[doc(hidden)]
+        #[rust_name = "qeasingcurve_eq"]
+        fn operatorEq(a: &QEasingCurve, b: &QEasingCurve) -> bool;
+    }
+}
+
+pub use ffi::QEasingCurveType;
+
+/// The QEasingCurve class provides easing curves for controlling animation,
+/// so that interpolation in Rust can match the easing of QML animations.
+#[repr(C)]
+pub struct QEasingCurve {
+    _d: MaybeUninit<usize>,
+}
+
+impl QEasingCurve {
+    /// Constructs an easing curve of the given type.
+    pub fn new(curve_type: QEasingCurveType) -> Self {
+        ffi::qeasingcurve_init_type(curve_type)
+    }
+}
+
+impl Clone for QEasingCurve {
+    /// Construct a copy of other.
+    fn clone(&self) -> Self {
+        ffi::qeasingcurve_clone(self)
+    }
+}
+
+impl Default for QEasingCurve {
+    /// Constructs a linear easing curve.
+    fn default() -> Self {
+        ffi::qeasingcurve_init_default()
+    }
+}
+
+impl Drop for QEasingCurve {
+    /// Destructor.
+    fn drop(&mut self) {
+        ffi::qeasingcurve_drop(self)
+    }
+}
+
+impl PartialEq for QEasingCurve {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qeasingcurve_eq(self, other)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QEasingCurve {
+    type Id = type_id!("QEasingCurve");
+    type Kind = cxx::kind::Trivial;
+}
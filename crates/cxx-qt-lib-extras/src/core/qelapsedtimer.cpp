@@ -26,11 +26,56 @@ static_assert(::std::is_trivially_copyable<QElapsedTimer>::value,
 namespace rust {
 namespace cxxqtlib1 {
 
+::std::int64_t
+qelapsedtimerElapsed(const QElapsedTimer& elapsedTimer)
+{
+  return static_cast<::std::int64_t>(elapsedTimer.elapsed());
+}
+
+bool
+qelapsedtimerHasExpired(const QElapsedTimer& elapsedTimer,
+                        ::std::int64_t timeout)
+{
+  return elapsedTimer.hasExpired(static_cast<qint64>(timeout));
+}
+
+bool
+qelapsedtimerIsMonotonic()
+{
+  return QElapsedTimer::isMonotonic();
+}
+
+::std::int64_t
+qelapsedtimerMsecsSinceReference(const QElapsedTimer& elapsedTimer)
+{
+  return static_cast<::std::int64_t>(elapsedTimer.msecsSinceReference());
+}
+
+::std::int64_t
+qelapsedtimerMsecsTo(const QElapsedTimer& elapsedTimer,
+                     const QElapsedTimer& other)
+{
+  return static_cast<::std::int64_t>(elapsedTimer.msecsTo(other));
+}
+
+::std::int64_t
+qelapsedtimerNsecsElapsed(const QElapsedTimer& elapsedTimer)
+{
+  return static_cast<::std::int64_t>(elapsedTimer.nsecsElapsed());
+}
+
 ::std::int64_t
 qelapsedtimerRestart(QElapsedTimer& elapsedTimer)
 {
   return static_cast<::std::int64_t>(elapsedTimer.restart());
 }
 
+::std::int64_t
+qelapsedtimerSecsTo(const QElapsedTimer& elapsedTimer,
+                    const QElapsedTimer& other)
+{
+  return static_cast<::std::int64_t>(elapsedTimer.secsTo(other));
+}
+
 }
 }
@@ -24,9 +24,30 @@ mod ffi {
 
     #[namespace = "rust::cxxqtlib1"]
     unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qelapsedtimer_elapsed"]
+        fn qelapsedtimerElapsed(e: &QElapsedTimer) -> i64;
+        #[doc(hidden)]
+        #[rust_name = "qelapsedtimer_has_expired"]
+        fn qelapsedtimerHasExpired(e: &QElapsedTimer, timeout: i64) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qelapsedtimer_is_monotonic"]
+        fn qelapsedtimerIsMonotonic() -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qelapsedtimer_msecs_since_reference"]
+        fn qelapsedtimerMsecsSinceReference(e: &QElapsedTimer) -> i64;
+        #[doc(hidden)]
+        #[rust_name = "qelapsedtimer_msecs_to"]
+        fn qelapsedtimerMsecsTo(e: &QElapsedTimer, other: &QElapsedTimer) -> i64;
+        #[doc(hidden)]
+        #[rust_name = "qelapsedtimer_nsecs_elapsed"]
+        fn qelapsedtimerNsecsElapsed(e: &QElapsedTimer) -> i64;
         #[doc(hidden)]
         #[rust_name = "qelapsedtimer_restart"]
         fn qelapsedtimerRestart(e: &mut QElapsedTimer) -> i64;
+        #[doc(hidden)]
+        #[rust_name = "qelapsedtimer_secs_to"]
+        fn qelapsedtimerSecsTo(e: &QElapsedTimer, other: &QElapsedTimer) -> i64;
     }
 
     #[namespace = "rust::cxxqtlib1"]
@@ -55,11 +76,54 @@ impl Default for QElapsedTimer {
 }
 
 impl QElapsedTimer {
+    /// Returns the number of milliseconds since this QElapsedTimer was last started.
+    ///
+    /// Calling this function on a QElapsedTimer that is invalid results in undefined behavior.
+    pub fn elapsed(&self) -> i64 {
+        ffi::qelapsedtimer_elapsed(self)
+    }
+
+    /// Returns true if elapsed() exceeds the given timeout, otherwise false.
+    ///
+    /// A negative timeout is interpreted as infinite, so false is returned in this case.
+    pub fn has_expired(&self, timeout: i64) -> bool {
+        ffi::qelapsedtimer_has_expired(self, timeout)
+    }
+
+    /// Returns true if this is a monotonic clock, false otherwise.
+    pub fn is_monotonic() -> bool {
+        ffi::qelapsedtimer_is_monotonic()
+    }
+
+    /// Returns the number of milliseconds between last time this QElapsedTimer object was started and its reference clock's start.
+    pub fn msecs_since_reference(&self) -> i64 {
+        ffi::qelapsedtimer_msecs_since_reference(self)
+    }
+
+    /// Returns the number of milliseconds between this QElapsedTimer and other.
+    /// If other was started before this object, the returned value will be negative.
+    pub fn msecs_to(&self, other: &Self) -> i64 {
+        ffi::qelapsedtimer_msecs_to(self, other)
+    }
+
+    /// Returns the number of nanoseconds since this QElapsedTimer was last started.
+    ///
+    /// On platforms that do not provide nanosecond resolution, the value returned will be the best estimate available.
+    pub fn nsecs_elapsed(&self) -> i64 {
+        ffi::qelapsedtimer_nsecs_elapsed(self)
+    }
+
     /// Restarts the timer and returns the number of milliseconds elapsed since the previous start.
     /// This function is equivalent to obtaining the elapsed time with elapsed() and then starting the timer again with start(),
     /// but it does so in one single operation, avoiding the need to obtain the clock value twice.
-    pub fn restart(mut self) -> i64 {
-        ffi::qelapsedtimer_restart(&mut self)
+    pub fn restart(&mut self) -> i64 {
+        ffi::qelapsedtimer_restart(self)
+    }
+
+    /// Returns the number of seconds between this QElapsedTimer and other.
+    /// If other was started before this object, the returned value will be negative.
+    pub fn secs_to(&self, other: &Self) -> i64 {
+        ffi::qelapsedtimer_secs_to(self, other)
     }
 }
 
@@ -0,0 +1,42 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qiodevice.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::int64_t
+qiodeviceBytesAvailable(const QIODevice& device)
+{
+  return static_cast<::std::int64_t>(device.bytesAvailable());
+}
+
+::std::int64_t
+qiodeviceRead(QIODevice& device, ::rust::Slice<::std::uint8_t> data)
+{
+  return static_cast<::std::int64_t>(
+    device.read(reinterpret_cast<char*>(data.data()),
+                static_cast<qint64>(data.size())));
+}
+
+QByteArray
+qiodeviceReadAll(QIODevice& device)
+{
+  return device.readAll();
+}
+
+::std::int64_t
+qiodeviceWrite(QIODevice& device, ::rust::Slice<const ::std::uint8_t> data)
+{
+  return static_cast<::std::int64_t>(
+    device.write(reinterpret_cast<const char*>(data.data()),
+                 static_cast<qint64>(data.size())));
+}
+
+}
+}
@@ -0,0 +1,103 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qiodevice.h");
+        /// The QIODevice class is the base interface class of all I/O devices in Qt.
+        ///
+        /// This is used for the buffers of audio devices, such as the one returned by
+        /// [QAudioSink::start](crate::QAudioSink::start).
+        #[qobject]
+        type QIODevice;
+
+        /// This signal is emitted every time a payload of data has been written to the device.
+        #[qsignal]
+        #[cxx_name = "bytesWritten"]
+        fn bytes_written(self: Pin<&mut QIODevice>, bytes: i64);
+
+        /// This signal is emitted once every time new data is available for reading from the device.
+        #[qsignal]
+        #[cxx_name = "readyRead"]
+        fn ready_read(self: Pin<&mut QIODevice>);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        /// Closes the device.
+        fn close(self: Pin<&mut QIODevice>);
+
+        /// Returns a human-readable description of the last device error that occurred.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QIODevice) -> QString;
+
+        /// Returns true if the device is open, otherwise returns false.
+        #[rust_name = "is_open"]
+        fn isOpen(self: &QIODevice) -> bool;
+
+        /// Returns true if data can be read from the device, otherwise returns false.
+        #[rust_name = "is_readable"]
+        fn isReadable(self: &QIODevice) -> bool;
+
+        /// Returns true if data can be written to the device, otherwise returns false.
+        #[rust_name = "is_writable"]
+        fn isWritable(self: &QIODevice) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qiodevice_bytes_available"]
+        fn qiodeviceBytesAvailable(device: &QIODevice) -> i64;
+
+        #[doc(hidden)]
+        #[rust_name = "qiodevice_read"]
+        fn qiodeviceRead(device: Pin<&mut QIODevice>, data: &mut [u8]) -> i64;
+
+        #[doc(hidden)]
+        #[rust_name = "qiodevice_read_all"]
+        fn qiodeviceReadAll(device: Pin<&mut QIODevice>) -> QByteArray;
+
+        #[doc(hidden)]
+        #[rust_name = "qiodevice_write"]
+        fn qiodeviceWrite(device: Pin<&mut QIODevice>, data: &[u8]) -> i64;
+    }
+}
+
+use core::pin::Pin;
+use cxx_qt_lib::QByteArray;
+
+pub use ffi::QIODevice;
+
+impl QIODevice {
+    /// Returns the number of bytes that are available for reading.
+    pub fn bytes_available(&self) -> i64 {
+        ffi::qiodevice_bytes_available(self)
+    }
+
+    /// Reads at most the length of data bytes from the device into data.
+    ///
+    /// Returns the number of bytes read, or -1 if an error occurred.
+    pub fn read(self: Pin<&mut Self>, data: &mut [u8]) -> i64 {
+        ffi::qiodevice_read(self, data)
+    }
+
+    /// Reads all remaining data from the device.
+    pub fn read_all(self: Pin<&mut Self>) -> QByteArray {
+        ffi::qiodevice_read_all(self)
+    }
+
+    /// Writes the data to the device.
+    ///
+    /// Returns the number of bytes that were actually written, or -1 if an error occurred.
+    pub fn write(self: Pin<&mut Self>, data: &[u8]) -> i64 {
+        ffi::qiodevice_write(self, data)
+    }
+}
@@ -0,0 +1,45 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qparallelanimationgroup.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qparallelanimationgroupAddAnimation(
+  QParallelAnimationGroup& group,
+  ::std::unique_ptr<QPropertyAnimation> animation)
+{
+  // The group takes ownership of the animation
+  group.addAnimation(animation.release());
+}
+
+void
+qparallelanimationgroupAddGroup(
+  QParallelAnimationGroup& group,
+  ::std::unique_ptr<QParallelAnimationGroup> animation)
+{
+  // The group takes ownership of the animation
+  group.addAnimation(animation.release());
+}
+
+::std::unique_ptr<QParallelAnimationGroup>
+qparallelanimationgroupNew()
+{
+  return ::std::make_unique<QParallelAnimationGroup>();
+}
+
+void
+qparallelanimationgroupStart(QParallelAnimationGroup& group)
+{
+  // The group is owned by Rust, so it must not delete itself when stopped
+  group.start(QAbstractAnimation::KeepWhenStopped);
+}
+
+}
+}
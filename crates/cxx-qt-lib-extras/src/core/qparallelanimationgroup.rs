@@ -0,0 +1,146 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qpropertyanimation.h");
+        type QPropertyAnimation = crate::QPropertyAnimation;
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qparallelanimationgroup.h");
+        /// The QParallelAnimationGroup class provides a parallel group of animations,
+        /// which are all started together and the group finishes when the longest animation has finished.
+        #[qobject]
+        type QParallelAnimationGroup;
+
+        /// This signal is emitted after the animation has stopped and has reached the end.
+        #[qsignal]
+        fn finished(self: Pin<&mut QParallelAnimationGroup>);
+
+        /// This signal is emitted whenever the state of the animation has changed from old_state to new_state.
+        #[qsignal]
+        #[rust_name = "state_changed"]
+        fn stateChanged(
+            self: Pin<&mut QParallelAnimationGroup>,
+            new_state: QAbstractAnimationState,
+            old_state: QAbstractAnimationState,
+        );
+    }
+
+    unsafe extern "C++" {
+        /// Returns the current time inside the current loop, in milliseconds.
+        #[rust_name = "current_time"]
+        fn currentTime(self: &QParallelAnimationGroup) -> i32;
+
+        /// Returns the direction of the animation when it is in Running state.
+        fn direction(self: &QParallelAnimationGroup) -> QAbstractAnimationDirection;
+
+        /// Returns the duration of the animation in milliseconds, or -1 if the duration is undefined.
+        fn duration(self: &QParallelAnimationGroup) -> i32;
+
+        /// Returns how many times the animation should loop before it stops, -1 means forever.
+        #[rust_name = "loop_count"]
+        fn loopCount(self: &QParallelAnimationGroup) -> i32;
+
+        /// Pauses the animation, which can be resumed with resume.
+        fn pause(self: Pin<&mut QParallelAnimationGroup>);
+
+        /// Resumes the animation after it was paused.
+        fn resume(self: Pin<&mut QParallelAnimationGroup>);
+
+        /// Sets the current time of the animation in milliseconds.
+        #[rust_name = "set_current_time"]
+        fn setCurrentTime(self: Pin<&mut QParallelAnimationGroup>, msecs: i32);
+
+        /// Sets the direction of the animation when it is in Running state.
+        #[rust_name = "set_direction"]
+        fn setDirection(
+            self: Pin<&mut QParallelAnimationGroup>,
+            direction: QAbstractAnimationDirection,
+        );
+
+        /// Sets how many times the animation should loop before it stops, -1 means forever.
+        #[rust_name = "set_loop_count"]
+        fn setLoopCount(self: Pin<&mut QParallelAnimationGroup>, loop_count: i32);
+
+        /// Returns the state of the animation.
+        fn state(self: &QParallelAnimationGroup) -> QAbstractAnimationState;
+
+        /// Stops the animation, the current time is not reset.
+        fn stop(self: Pin<&mut QParallelAnimationGroup>);
+
+        /// Returns the number of animations managed by this group.
+        #[rust_name = "animation_count"]
+        fn animationCount(self: &QParallelAnimationGroup) -> i32;
+
+        /// Removes and deletes all animations in this animation group.
+        fn clear(self: Pin<&mut QParallelAnimationGroup>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qabstractanimation.h");
+        type QAbstractAnimationDirection = crate::QAbstractAnimationDirection;
+        type QAbstractAnimationState = crate::QAbstractAnimationState;
+
+        #[doc(hidden)]
+        #[rust_name = "qparallelanimationgroup_add_animation"]
+        fn qparallelanimationgroupAddAnimation(
+            group: Pin<&mut QParallelAnimationGroup>,
+            animation: UniquePtr<QPropertyAnimation>,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qparallelanimationgroup_add_group"]
+        fn qparallelanimationgroupAddGroup(
+            group: Pin<&mut QParallelAnimationGroup>,
+            animation: UniquePtr<QParallelAnimationGroup>,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qparallelanimationgroup_new"]
+        fn qparallelanimationgroupNew() -> UniquePtr<QParallelAnimationGroup>;
+
+        #[doc(hidden)]
+        #[rust_name = "qparallelanimationgroup_start"]
+        fn qparallelanimationgroupStart(group: Pin<&mut QParallelAnimationGroup>);
+    }
+
+    // QParallelAnimationGroup is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QParallelAnimationGroup>::isRelocatable);
+    impl UniquePtr<QParallelAnimationGroup> {}
+}
+
+use crate::QPropertyAnimation;
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+pub use ffi::QParallelAnimationGroup;
+
+impl QParallelAnimationGroup {
+    /// Constructs an empty QParallelAnimationGroup.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qparallelanimationgroup_new()
+    }
+
+    /// Adds the animation to this group, which takes ownership of the animation.
+    pub fn add_animation(self: Pin<&mut Self>, animation: UniquePtr<QPropertyAnimation>) {
+        ffi::qparallelanimationgroup_add_animation(self, animation)
+    }
+
+    /// Adds a nested group of animations to this group, which takes ownership of the nested group.
+    pub fn add_group(self: Pin<&mut Self>, group: UniquePtr<QParallelAnimationGroup>) {
+        ffi::qparallelanimationgroup_add_group(self, group)
+    }
+
+    /// Starts all of the animations of the group, the finished signal is emitted when all of them have finished.
+    pub fn start(self: Pin<&mut Self>) {
+        ffi::qparallelanimationgroup_start(self)
+    }
+}
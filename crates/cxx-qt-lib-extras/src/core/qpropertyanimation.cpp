@@ -0,0 +1,40 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qpropertyanimation.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QEasingCurveType
+qpropertyanimationEasingCurveType(const QPropertyAnimation& animation)
+{
+  return animation.easingCurve().type();
+}
+
+::std::unique_ptr<QPropertyAnimation>
+qpropertyanimationNew(QObject* target, const QByteArray& propertyName)
+{
+  return ::std::make_unique<QPropertyAnimation>(target, propertyName);
+}
+
+void
+qpropertyanimationSetEasingCurveType(QPropertyAnimation& animation,
+                                     QEasingCurveType type)
+{
+  animation.setEasingCurve(QEasingCurve(type));
+}
+
+void
+qpropertyanimationStart(QPropertyAnimation& animation)
+{
+  // The animation is owned by Rust, so it must not delete itself when stopped
+  animation.start(QAbstractAnimation::KeepWhenStopped);
+}
+
+}
+}
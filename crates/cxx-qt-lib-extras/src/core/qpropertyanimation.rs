@@ -0,0 +1,202 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt_lib::QObject;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+        include!("cxx-qt-lib-extras/qeasingcurve.h");
+        type QEasingCurve = crate::QEasingCurve;
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qpropertyanimation.h");
+        /// The QPropertyAnimation class animates a property of a QObject,
+        /// such as the `x` or `opacity` property of an item which was created in QML.
+        #[qobject]
+        type QPropertyAnimation;
+
+        /// This signal is emitted after the animation has stopped and has reached the end.
+        #[qsignal]
+        fn finished(self: Pin<&mut QPropertyAnimation>);
+
+        /// This signal is emitted whenever the state of the animation has changed from old_state to new_state.
+        #[qsignal]
+        #[rust_name = "state_changed"]
+        fn stateChanged(
+            self: Pin<&mut QPropertyAnimation>,
+            new_state: QAbstractAnimationState,
+            old_state: QAbstractAnimationState,
+        );
+    }
+
+    unsafe extern "C++" {
+        /// Returns the current time inside the current loop, in milliseconds.
+        #[rust_name = "current_time"]
+        fn currentTime(self: &QPropertyAnimation) -> i32;
+
+        /// Returns the direction of the animation when it is in Running state.
+        fn direction(self: &QPropertyAnimation) -> QAbstractAnimationDirection;
+
+        /// Returns the duration of the animation in milliseconds, or -1 if the duration is undefined.
+        fn duration(self: &QPropertyAnimation) -> i32;
+
+        /// Returns how many times the animation should loop before it stops, -1 means forever.
+        #[rust_name = "loop_count"]
+        fn loopCount(self: &QPropertyAnimation) -> i32;
+
+        /// Pauses the animation, which can be resumed with resume.
+        fn pause(self: Pin<&mut QPropertyAnimation>);
+
+        /// Resumes the animation after it was paused.
+        fn resume(self: Pin<&mut QPropertyAnimation>);
+
+        /// Sets the current time of the animation in milliseconds.
+        #[rust_name = "set_current_time"]
+        fn setCurrentTime(self: Pin<&mut QPropertyAnimation>, msecs: i32);
+
+        /// Sets the direction of the animation when it is in Running state.
+        #[rust_name = "set_direction"]
+        fn setDirection(self: Pin<&mut QPropertyAnimation>, direction: QAbstractAnimationDirection);
+
+        /// Sets how many times the animation should loop before it stops, -1 means forever.
+        #[rust_name = "set_loop_count"]
+        fn setLoopCount(self: Pin<&mut QPropertyAnimation>, loop_count: i32);
+
+        /// Returns the state of the animation.
+        fn state(self: &QPropertyAnimation) -> QAbstractAnimationState;
+
+        /// Stops the animation, the current time is not reset.
+        fn stop(self: Pin<&mut QPropertyAnimation>);
+
+        /// Returns the easing curve of the animation.
+        #[rust_name = "easing_curve"]
+        fn easingCurve(self: &QPropertyAnimation) -> QEasingCurve;
+
+        /// Returns the end value of the animation.
+        #[rust_name = "end_value"]
+        fn endValue(self: &QPropertyAnimation) -> QVariant;
+
+        /// Returns the name of the property which is animated.
+        #[rust_name = "property_name"]
+        fn propertyName(self: &QPropertyAnimation) -> QByteArray;
+
+        /// Sets the easing curve of the animation, such as a custom bezier curve.
+        #[rust_name = "set_easing_curve"]
+        fn setEasingCurve(self: Pin<&mut QPropertyAnimation>, easing_curve: &QEasingCurve);
+
+        /// Sets the duration of the animation in milliseconds.
+        #[rust_name = "set_duration"]
+        fn setDuration(self: Pin<&mut QPropertyAnimation>, msecs: i32);
+
+        /// Sets the end value of the animation.
+        #[rust_name = "set_end_value"]
+        fn setEndValue(self: Pin<&mut QPropertyAnimation>, value: &QVariant);
+
+        /// Creates a key frame at the given step with the given value, the step is between 0 and 1.
+        #[rust_name = "set_key_value_at"]
+        fn setKeyValueAt(self: Pin<&mut QPropertyAnimation>, step: f64, value: &QVariant);
+
+        /// Sets the name of the property which is animated.
+        #[rust_name = "set_property_name"]
+        fn setPropertyName(self: Pin<&mut QPropertyAnimation>, property_name: &QByteArray);
+
+        /// Sets the start value of the animation,
+        /// if no start value is set the current value of the property is used when the animation starts.
+        #[rust_name = "set_start_value"]
+        fn setStartValue(self: Pin<&mut QPropertyAnimation>, value: &QVariant);
+
+        /// Returns the start value of the animation.
+        #[rust_name = "start_value"]
+        fn startValue(self: &QPropertyAnimation) -> QVariant;
+
+        /// Returns the target QObject of the animation.
+        #[rust_name = "target_object"]
+        fn targetObject(self: &QPropertyAnimation) -> *mut QObject;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qabstractanimation.h");
+        type QAbstractAnimationDirection = crate::QAbstractAnimationDirection;
+        type QAbstractAnimationState = crate::QAbstractAnimationState;
+        include!("cxx-qt-lib-extras/qeasingcurve.h");
+        type QEasingCurveType = crate::QEasingCurveType;
+
+        #[doc(hidden)]
+        #[rust_name = "qpropertyanimation_easing_curve_type"]
+        fn qpropertyanimationEasingCurveType(animation: &QPropertyAnimation) -> QEasingCurveType;
+
+        #[doc(hidden)]
+        #[rust_name = "qpropertyanimation_new"]
+        unsafe fn qpropertyanimationNew(
+            target: *mut QObject,
+            property_name: &QByteArray,
+        ) -> UniquePtr<QPropertyAnimation>;
+
+        #[doc(hidden)]
+        #[rust_name = "qpropertyanimation_set_easing_curve_type"]
+        fn qpropertyanimationSetEasingCurveType(
+            animation: Pin<&mut QPropertyAnimation>,
+            easing_curve_type: QEasingCurveType,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qpropertyanimation_start"]
+        fn qpropertyanimationStart(animation: Pin<&mut QPropertyAnimation>);
+    }
+
+    // QPropertyAnimation is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QPropertyAnimation>::isRelocatable);
+    impl UniquePtr<QPropertyAnimation> {}
+}
+
+use crate::QEasingCurveType;
+use core::pin::Pin;
+use cxx_qt_lib::{QByteArray, QObject};
+
+pub use ffi::QPropertyAnimation;
+
+impl QPropertyAnimation {
+    /// Constructs a QPropertyAnimation which animates the property with the given name of the target.
+    ///
+    /// ```ignore
+    /// let mut animation = unsafe { QPropertyAnimation::new(item, &QByteArray::from("opacity")) };
+    /// animation.pin_mut().set_duration(250);
+    /// animation.pin_mut().set_end_value(&QVariant::from(&0.0_f64));
+    /// animation.pin_mut().set_easing_curve_type(QEasingCurveType::OutCubic);
+    /// animation.pin_mut().start();
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// The target must be a valid pointer to a QObject which outlives the animation while it is running.
+    pub unsafe fn new(target: *mut QObject, property_name: &QByteArray) -> cxx::UniquePtr<Self> {
+        ffi::qpropertyanimation_new(target, property_name)
+    }
+
+    /// Returns the type of the easing curve of the animation.
+    pub fn easing_curve_type(&self) -> QEasingCurveType {
+        ffi::qpropertyanimation_easing_curve_type(self)
+    }
+
+    /// Sets the easing curve of the animation to a curve of the given type,
+    /// which is the same as the `easing.type` of a QML animation.
+    pub fn set_easing_curve_type(self: Pin<&mut Self>, easing_curve_type: QEasingCurveType) {
+        ffi::qpropertyanimation_set_easing_curve_type(self, easing_curve_type)
+    }
+
+    /// Starts the animation, the finished signal is emitted when it reaches the end.
+    pub fn start(self: Pin<&mut Self>) {
+        ffi::qpropertyanimation_start(self)
+    }
+}
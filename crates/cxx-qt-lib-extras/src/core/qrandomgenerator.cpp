@@ -0,0 +1,124 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qrandomgenerator.h"
+
+namespace {
+
+// Generating a number advances the state of the generator, however in Rust the
+// generator is used by shared reference so that global() can be shared.
+//
+// This is safe as QRandomGenerator is not Sync in Rust, so a seeded generator
+// is only ever used from one thread, and the global and system generators are
+// thread-safe in Qt.
+QRandomGenerator&
+mutableGenerator(const QRandomGenerator& generator)
+{
+  return const_cast<QRandomGenerator&>(generator);
+}
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+double
+qrandomgeneratorBoundedF64(const QRandomGenerator& generator, double highest)
+{
+  return mutableGenerator(generator).bounded(highest);
+}
+
+::std::int32_t
+qrandomgeneratorBoundedI32(const QRandomGenerator& generator,
+                           ::std::int32_t lowest,
+                           ::std::int32_t highest)
+{
+  return mutableGenerator(generator).bounded(lowest, highest);
+}
+
+::std::uint32_t
+qrandomgeneratorBoundedU32(const QRandomGenerator& generator,
+                           ::std::uint32_t highest)
+{
+  return mutableGenerator(generator).bounded(static_cast<quint32>(highest));
+}
+
+::std::unique_ptr<QRandomGenerator>
+qrandomgeneratorClone(const QRandomGenerator& generator)
+{
+  return ::std::make_unique<QRandomGenerator>(generator);
+}
+
+void
+qrandomgeneratorDiscard(const QRandomGenerator& generator, ::std::uint64_t z)
+{
+  mutableGenerator(generator).discard(static_cast<unsigned long long>(z));
+}
+
+void
+qrandomgeneratorFillRange(const QRandomGenerator& generator,
+                          ::rust::Slice<::std::uint32_t> buffer)
+{
+  static_assert(sizeof(::std::uint32_t) == sizeof(quint32));
+  mutableGenerator(generator).fillRange(
+    reinterpret_cast<quint32*>(buffer.data()),
+    static_cast<qsizetype>(buffer.size()));
+}
+
+::std::uint32_t
+qrandomgeneratorGenerate(const QRandomGenerator& generator)
+{
+  return mutableGenerator(generator).generate();
+}
+
+::std::uint64_t
+qrandomgeneratorGenerate64(const QRandomGenerator& generator)
+{
+  return static_cast<::std::uint64_t>(mutableGenerator(generator).generate64());
+}
+
+double
+qrandomgeneratorGenerateDouble(const QRandomGenerator& generator)
+{
+  return mutableGenerator(generator).generateDouble();
+}
+
+const QRandomGenerator&
+qrandomgeneratorGlobal()
+{
+  return *QRandomGenerator::global();
+}
+
+::std::unique_ptr<QRandomGenerator>
+qrandomgeneratorNew(::std::uint32_t seed)
+{
+  return ::std::make_unique<QRandomGenerator>(static_cast<quint32>(seed));
+}
+
+::std::unique_ptr<QRandomGenerator>
+qrandomgeneratorNewFromSlice(::rust::Slice<const ::std::uint32_t> seeds)
+{
+  static_assert(sizeof(::std::uint32_t) == sizeof(quint32));
+  return ::std::make_unique<QRandomGenerator>(
+    reinterpret_cast<const quint32*>(seeds.data()),
+    static_cast<qsizetype>(seeds.size()));
+}
+
+void
+qrandomgeneratorSeed(const QRandomGenerator& generator, ::std::uint32_t seed)
+{
+  mutableGenerator(generator).seed(static_cast<quint32>(seed));
+}
+
+const QRandomGenerator&
+qrandomgeneratorSystem()
+{
+  return *QRandomGenerator::system();
+}
+
+}
+}
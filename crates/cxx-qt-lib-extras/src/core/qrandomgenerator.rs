@@ -0,0 +1,171 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qrandomgenerator.h");
+        /// The QRandomGenerator class allows one to obtain random values from a high-quality Random Number Generator.
+        ///
+        /// A generator which is seeded with the same value produces the same sequence of numbers
+        /// as a QRandomGenerator on the C++ side seeded with that value.
+        ///
+        /// QRandomGenerator is not `Sync`, so a seeded generator can only be used from one thread at a time,
+        /// the [QRandomGenerator::global] and [QRandomGenerator::system] generators are thread-safe in Qt
+        /// and can be retrieved from any thread.
+        type QRandomGenerator;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qrandomgenerator_bounded_f64"]
+        fn qrandomgeneratorBoundedF64(generator: &QRandomGenerator, highest: f64) -> f64;
+
+        #[doc(hidden)]
+        #[rust_name = "qrandomgenerator_bounded_i32"]
+        fn qrandomgeneratorBoundedI32(
+            generator: &QRandomGenerator,
+            lowest: i32,
+            highest: i32,
+        ) -> i32;
+
+        #[doc(hidden)]
+        #[rust_name = "qrandomgenerator_bounded_u32"]
+        fn qrandomgeneratorBoundedU32(generator: &QRandomGenerator, highest: u32) -> u32;
+
+        #[doc(hidden)]
+        #[rust_name = "qrandomgenerator_clone"]
+        fn qrandomgeneratorClone(generator: &QRandomGenerator) -> UniquePtr<QRandomGenerator>;
+
+        #[doc(hidden)]
+        #[rust_name = "qrandomgenerator_discard"]
+        fn qrandomgeneratorDiscard(generator: &QRandomGenerator, z: u64);
+
+        #[doc(hidden)]
+        #[rust_name = "qrandomgenerator_fill_range"]
+        fn qrandomgeneratorFillRange(generator: &QRandomGenerator, buffer: &mut [u32]);
+
+        #[doc(hidden)]
+        #[rust_name = "qrandomgenerator_generate"]
+        fn qrandomgeneratorGenerate(generator: &QRandomGenerator) -> u32;
+
+        #[doc(hidden)]
+        #[rust_name = "qrandomgenerator_generate64"]
+        fn qrandomgeneratorGenerate64(generator: &QRandomGenerator) -> u64;
+
+        #[doc(hidden)]
+        #[rust_name = "qrandomgenerator_generate_double"]
+        fn qrandomgeneratorGenerateDouble(generator: &QRandomGenerator) -> f64;
+
+        #[doc(hidden)]
+        #[rust_name = "qrandomgenerator_global"]
+        fn qrandomgeneratorGlobal() -> &'static QRandomGenerator;
+
+        #[doc(hidden)]
+        #[rust_name = "qrandomgenerator_new"]
+        fn qrandomgeneratorNew(seed: u32) -> UniquePtr<QRandomGenerator>;
+
+        #[doc(hidden)]
+        #[rust_name = "qrandomgenerator_new_from_slice"]
+        fn qrandomgeneratorNewFromSlice(seeds: &[u32]) -> UniquePtr<QRandomGenerator>;
+
+        #[doc(hidden)]
+        #[rust_name = "qrandomgenerator_seed"]
+        fn qrandomgeneratorSeed(generator: &QRandomGenerator, seed: u32);
+
+        #[doc(hidden)]
+        #[rust_name = "qrandomgenerator_system"]
+        fn qrandomgeneratorSystem() -> &'static QRandomGenerator;
+    }
+
+    // QRandomGenerator contains a Mersenne Twister whose size depends on the
+    // standard library, so we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    impl UniquePtr<QRandomGenerator> {}
+}
+
+use cxx::UniquePtr;
+
+pub use ffi::QRandomGenerator;
+
+impl QRandomGenerator {
+    /// Generates one random double in the range between 0 (inclusive) and highest (exclusive).
+    pub fn bounded_f64(&self, highest: f64) -> f64 {
+        ffi::qrandomgenerator_bounded_f64(self, highest)
+    }
+
+    /// Generates one random 32-bit quantity in the range between lowest (inclusive) and highest (exclusive).
+    ///
+    /// The highest parameter must be greater than lowest.
+    pub fn bounded_i32(&self, lowest: i32, highest: i32) -> i32 {
+        ffi::qrandomgenerator_bounded_i32(self, lowest, highest)
+    }
+
+    /// Generates one random 32-bit quantity in the range between 0 (inclusive) and highest (exclusive).
+    pub fn bounded_u32(&self, highest: u32) -> u32 {
+        ffi::qrandomgenerator_bounded_u32(self, highest)
+    }
+
+    /// Returns a copy of this generator, which produces the same sequence of numbers from its current state.
+    pub fn clone_generator(&self) -> UniquePtr<Self> {
+        ffi::qrandomgenerator_clone(self)
+    }
+
+    /// Discards the next z entries from the sequence.
+    pub fn discard(&self, z: u64) {
+        ffi::qrandomgenerator_discard(self, z)
+    }
+
+    /// Generates random 32-bit quantities filling the buffer.
+    pub fn fill_range(&self, buffer: &mut [u32]) {
+        ffi::qrandomgenerator_fill_range(self, buffer)
+    }
+
+    /// Generates a 32-bit random quantity and returns it.
+    pub fn generate(&self) -> u32 {
+        ffi::qrandomgenerator_generate(self)
+    }
+
+    /// Generates a 64-bit random quantity and returns it.
+    pub fn generate64(&self) -> u64 {
+        ffi::qrandomgenerator_generate64(self)
+    }
+
+    /// Generates one random double in the canonical range [0, 1) (that is, inclusive of zero and exclusive of 1).
+    pub fn generate_double(&self) -> f64 {
+        ffi::qrandomgenerator_generate_double(self)
+    }
+
+    /// Returns a reference to a shared QRandomGenerator that was seeded securely by Qt.
+    ///
+    /// This is the same generator as `QRandomGenerator::global()` on the C++ side.
+    pub fn global() -> &'static Self {
+        ffi::qrandomgenerator_global()
+    }
+
+    /// Constructs a QRandomGenerator object that is seeded with the given seed.
+    pub fn new(seed: u32) -> UniquePtr<Self> {
+        ffi::qrandomgenerator_new(seed)
+    }
+
+    /// Constructs a QRandomGenerator object that is seeded with the values in the seeds slice.
+    pub fn from_seeds(seeds: &[u32]) -> UniquePtr<Self> {
+        ffi::qrandomgenerator_new_from_slice(seeds)
+    }
+
+    /// Reseeds this object using the value seed as the seed.
+    ///
+    /// Note that this function has no effect on the [QRandomGenerator::system] generator.
+    pub fn seed(&self, seed: u32) {
+        ffi::qrandomgenerator_seed(self, seed)
+    }
+
+    /// Returns a reference to a shared QRandomGenerator that always uses the facilities
+    /// provided by the operating system to generate random numbers.
+    pub fn system() -> &'static Self {
+        ffi::qrandomgenerator_system()
+    }
+}
@@ -0,0 +1,32 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qstringlistmodel.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QObject&
+qstringlistmodelAsQObject(QStringListModel& model)
+{
+  return static_cast<QObject&>(model);
+}
+
+::std::unique_ptr<QStringListModel>
+qstringlistmodelNew()
+{
+  return ::std::make_unique<QStringListModel>();
+}
+
+::std::unique_ptr<QStringListModel>
+qstringlistmodelNewWithStringList(const QStringList& strings)
+{
+  return ::std::make_unique<QStringListModel>(strings);
+}
+
+}
+}
@@ -0,0 +1,129 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = cxx_qt_lib::QModelIndex;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt_lib::QObject;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = cxx_qt_lib::QStringList;
+        include!("cxx-qt-lib/qvector.h");
+        type QVector_i32 = cxx_qt_lib::QVector<i32>;
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qstringlistmodel.h");
+        /// The QStringListModel class provides a model that supplies strings to views.
+        ///
+        /// This allows for a list of strings from Rust to be shown in a QML view without implementing
+        /// a custom model, the whole list is replaced with [QStringListModel::set_string_list].
+        /// The model can be given to QML through a `*mut QObject` property, see [QStringListModel::as_qobject].
+        #[qobject]
+        type QStringListModel;
+
+        /// This signal is emitted whenever the data in an existing item changes.
+        #[qsignal]
+        #[rust_name = "data_changed"]
+        fn dataChanged(
+            self: Pin<&mut QStringListModel>,
+            top_left: &QModelIndex,
+            bottom_right: &QModelIndex,
+            roles: &QVector_i32,
+        );
+
+        /// This signal is emitted when the model has been reset, such as after the string list has been set.
+        #[qsignal]
+        #[rust_name = "model_reset"]
+        fn modelReset(self: Pin<&mut QStringListModel>);
+
+        /// This signal is emitted after rows have been inserted into the model,
+        /// the new items are those between first and last inclusive.
+        #[qsignal]
+        #[rust_name = "rows_inserted"]
+        fn rowsInserted(
+            self: Pin<&mut QStringListModel>,
+            parent: &QModelIndex,
+            first: i32,
+            last: i32,
+        );
+
+        /// This signal is emitted after rows have been removed from the model,
+        /// the removed items are those between first and last inclusive.
+        #[qsignal]
+        #[rust_name = "rows_removed"]
+        fn rowsRemoved(
+            self: Pin<&mut QStringListModel>,
+            parent: &QModelIndex,
+            first: i32,
+            last: i32,
+        );
+    }
+
+    unsafe extern "C++" {
+        /// Returns the number of rows in the model, the parent should be an invalid index.
+        #[rust_name = "row_count"]
+        fn rowCount(self: &QStringListModel, parent: &QModelIndex) -> i32;
+
+        /// Sets the model's internal string list to strings, the model will notify any attached views that its underlying data has changed.
+        #[rust_name = "set_string_list"]
+        fn setStringList(self: Pin<&mut QStringListModel>, strings: &QStringList);
+
+        /// Returns the string list used by the model to store data.
+        #[rust_name = "string_list"]
+        fn stringList(self: &QStringListModel) -> QStringList;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qstringlistmodel_as_qobject"]
+        fn qstringlistmodelAsQObject(model: Pin<&mut QStringListModel>) -> Pin<&mut QObject>;
+
+        #[doc(hidden)]
+        #[rust_name = "qstringlistmodel_new"]
+        fn qstringlistmodelNew() -> UniquePtr<QStringListModel>;
+
+        #[doc(hidden)]
+        #[rust_name = "qstringlistmodel_new_with_string_list"]
+        fn qstringlistmodelNewWithStringList(strings: &QStringList) -> UniquePtr<QStringListModel>;
+    }
+
+    // QStringListModel is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QStringListModel>::isRelocatable);
+    impl UniquePtr<QStringListModel> {}
+}
+
+use core::pin::Pin;
+use cxx_qt_lib::{QObject, QStringList};
+
+pub use ffi::QStringListModel;
+
+impl QStringListModel {
+    /// Constructs a model with an empty string list.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qstringlistmodel_new()
+    }
+
+    /// Constructs a model containing the given strings.
+    pub fn new_with_string_list(strings: &QStringList) -> cxx::UniquePtr<Self> {
+        ffi::qstringlistmodel_new_with_string_list(strings)
+    }
+
+    /// Convert the existing [QStringListModel] to a [QObject],
+    /// a pointer to which can be returned from a `*mut QObject` property and used as the model of a QML view.
+    ///
+    /// ```ignore
+    /// let model = unsafe { model.pin_mut().as_qobject().get_unchecked_mut() as *mut QObject };
+    /// qobject.set_model(model);
+    /// ```
+    pub fn as_qobject(self: Pin<&mut Self>) -> Pin<&mut QObject> {
+        ffi::qstringlistmodel_as_qobject(self)
+    }
+}
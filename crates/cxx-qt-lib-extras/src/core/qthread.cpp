@@ -0,0 +1,32 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qthread.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QThread>
+qthreadNew()
+{
+  return ::std::make_unique<QThread>();
+}
+
+void
+qthreadStart(QThread& thread)
+{
+  thread.start();
+}
+
+bool
+qthreadWait(QThread& thread)
+{
+  return thread.wait();
+}
+
+} // namespace cxxqtlib1
+} // namespace rust
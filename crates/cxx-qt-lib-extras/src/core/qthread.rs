@@ -0,0 +1,174 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qthread.h");
+        /// The QThread class provides a platform-independent way to manage threads.
+        ///
+        /// A QThread runs its own Qt event loop, so QObjects which are moved to the thread
+        /// execute their queued closures and slots on that thread.
+        #[qobject]
+        type QThread;
+    }
+
+    unsafe extern "C++" {
+        /// Returns true if the thread is finished; otherwise returns false.
+        #[rust_name = "is_finished"]
+        fn isFinished(self: &QThread) -> bool;
+
+        /// Return true if the task running on this thread should be stopped.
+        /// An interruption can be requested by [QThread::request_interruption].
+        #[rust_name = "is_interruption_requested"]
+        fn isInterruptionRequested(self: &QThread) -> bool;
+
+        /// Returns true if the thread is running; otherwise returns false.
+        #[rust_name = "is_running"]
+        fn isRunning(self: &QThread) -> bool;
+
+        /// Tells the thread's event loop to exit with return code 0 (success).
+        fn quit(self: Pin<&mut QThread>);
+
+        /// Request the interruption of the thread.
+        /// That request is advisory and it is up to code running on the thread to decide if and how it should act upon such request.
+        #[rust_name = "request_interruption"]
+        fn requestInterruption(self: Pin<&mut QThread>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qthread_new"]
+        fn qthreadNew() -> UniquePtr<QThread>;
+
+        #[doc(hidden)]
+        #[rust_name = "qthread_start"]
+        fn qthreadStart(thread: Pin<&mut QThread>);
+
+        #[doc(hidden)]
+        #[rust_name = "qthread_wait"]
+        fn qthreadWait(thread: Pin<&mut QThread>) -> bool;
+    }
+
+    // QThread is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QThread>::isRelocatable);
+    impl UniquePtr<QThread> {}
+}
+
+use core::pin::Pin;
+use cxx::{memory::UniquePtrTarget, UniquePtr};
+use cxx_qt::{CxxQtThread, Threading};
+
+pub use ffi::QThread;
+
+impl QThread {
+    /// Constructs a new QThread to manage a new thread. The thread does not begin executing until [QThread::start] is called.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qthread_new()
+    }
+
+    /// Begins execution of the thread, which runs the Qt event loop of the thread.
+    pub fn start(self: Pin<&mut Self>) {
+        ffi::qthread_start(self)
+    }
+
+    /// Blocks until the thread has finished execution, returning true if it has finished.
+    pub fn wait(self: Pin<&mut Self>) -> bool {
+        ffi::qthread_wait(self)
+    }
+}
+
+/// A QObject which lives in its own [QThread], following the Qt "worker object" pattern.
+///
+/// The worker is created by the `qthreadNewWorker` template, which is declared in the bridge of the worker.
+/// The worker is moved to a new [QThread] before the thread is started.
+///
+/// ```ignore
+/// #[cxx_qt::bridge]
+/// mod ffi {
+///     unsafe extern "C++" {
+///         include!("cxx-qt-lib-extras/qthread.h");
+///         type QThread = cxx_qt_lib_extras::QThread;
+///     }
+///
+///     unsafe extern "RustQt" {
+///         #[qobject]
+///         type Worker = super::WorkerRust;
+///
+///         fn process(self: Pin<&mut Worker>);
+///     }
+///
+///     impl cxx_qt::Threading for Worker {}
+///
+///     #[namespace = "rust::cxxqtlib1"]
+///     unsafe extern "C++" {
+///         #[rust_name = "worker_new"]
+///         fn qthreadNewWorker(thread: Pin<&mut QThread>, worker: &mut UniquePtr<Worker>);
+///     }
+///
+///     impl UniquePtr<Worker> {}
+/// }
+///
+/// let worker = QThreadWorker::new(ffi::worker_new);
+/// // The closure and the invokable are executed on the thread of the worker
+/// worker.qt_thread().queue(|worker| worker.process()).unwrap();
+/// ```
+///
+/// The closures queued with [QThreadWorker::qt_thread] are executed on the event loop of the thread,
+/// so the worker does not need to be [Send] or [Sync].
+/// When the [QThreadWorker] is dropped the thread is stopped and then the worker is destroyed.
+pub struct QThreadWorker<T>
+where
+    T: Threading + UniquePtrTarget,
+{
+    // The worker is dropped before the thread, after the thread has been stopped
+    worker: UniquePtr<T>,
+    thread: UniquePtr<QThread>,
+}
+
+impl<T> QThreadWorker<T>
+where
+    T: Threading + UniquePtrTarget,
+{
+    /// Create a worker with the given `qthreadNewWorker` function from the bridge of the worker,
+    /// then start the thread which the worker lives in.
+    pub fn new(new_worker: impl FnOnce(Pin<&mut QThread>, &mut UniquePtr<T>)) -> Self {
+        let mut thread = QThread::new();
+        let mut worker = UniquePtr::null();
+        new_worker(thread.pin_mut(), &mut worker);
+        thread.pin_mut().start();
+
+        Self { worker, thread }
+    }
+
+    /// Returns a [CxxQtThread] for queueing closures onto the thread of the worker
+    pub fn qt_thread(&self) -> CxxQtThread<T> {
+        self.worker
+            .as_ref()
+            .expect("QThreadWorker should have a worker")
+            .qt_thread()
+    }
+
+    /// Returns the [QThread] which the worker lives in
+    pub fn thread(&self) -> &QThread {
+        &self.thread
+    }
+}
+
+impl<T> Drop for QThreadWorker<T>
+where
+    T: Threading + UniquePtrTarget,
+{
+    /// Stops the thread and waits for it to finish, so that the worker can be destroyed safely
+    fn drop(&mut self) {
+        if let Some(mut thread) = self.thread.as_mut() {
+            thread.as_mut().quit();
+            thread.wait();
+        }
+    }
+}
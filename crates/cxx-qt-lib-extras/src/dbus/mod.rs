@@ -0,0 +1,13 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qdbusconnection;
+pub use qdbusconnection::{QDBusConnection, QDBusConnectionRegisterOption};
+
+mod qdbusinterface;
+pub use qdbusinterface::QDBusInterface;
+
+mod qdbusmessage;
+pub use qdbusmessage::{QDBusMessage, QDBusMessageMessageType};
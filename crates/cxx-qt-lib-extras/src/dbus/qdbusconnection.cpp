@@ -0,0 +1,59 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qdbusconnection.h"
+
+#include <QtDBus/QDBusError>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QDBusMessage>
+qdbusconnectionCall(const QDBusConnection& connection,
+                    const QDBusMessage& message)
+{
+  return ::std::make_unique<QDBusMessage>(connection.call(message));
+}
+
+QString
+qdbusconnectionLastErrorMessage(const QDBusConnection& connection)
+{
+  return connection.lastError().message();
+}
+
+bool
+qdbusconnectionRegisterObject(QDBusConnection& connection,
+                              const QString& path,
+                              QObject* object,
+                              QDBusConnectionRegisterOption options)
+{
+  // The options are flags, so combinations of the enum values can be passed
+  return connection.registerObject(
+    path, object, QDBusConnection::RegisterOptions(options));
+}
+
+::std::unique_ptr<QDBusConnection>
+qdbusconnectionSessionBus()
+{
+  return ::std::make_unique<QDBusConnection>(QDBusConnection::sessionBus());
+}
+
+::std::unique_ptr<QDBusConnection>
+qdbusconnectionSystemBus()
+{
+  return ::std::make_unique<QDBusConnection>(QDBusConnection::systemBus());
+}
+
+void
+qdbusconnectionUnregisterObject(QDBusConnection& connection,
+                                const QString& path)
+{
+  connection.unregisterObject(path);
+}
+
+}
+}
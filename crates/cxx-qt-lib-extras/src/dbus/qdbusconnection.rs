@@ -0,0 +1,192 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// Specifies the options for registering objects with the connection.
+    ///
+    /// The values are flags, so they can be combined by creating the enum from the bitwise or of their `repr`.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QDBusConnectionRegisterOption {
+        /// Export the contents of adaptors found in this object.
+        ExportAdaptors = 0x01,
+        /// Export this object's scriptable slots.
+        ExportScriptableSlots = 0x10,
+        /// Export this object's scriptable signals.
+        ExportScriptableSignals = 0x20,
+        /// Export this object's scriptable properties.
+        ExportScriptableProperties = 0x40,
+        /// Export this object's scriptable invokables.
+        ExportScriptableInvokables = 0x80,
+        /// Shorthand form for all of the scriptable contents.
+        ExportScriptableContents = 0xf0,
+        /// Export this object's non-scriptable slots.
+        ExportNonScriptableSlots = 0x100,
+        /// Export this object's non-scriptable signals.
+        ExportNonScriptableSignals = 0x200,
+        /// Export this object's non-scriptable properties.
+        ExportNonScriptableProperties = 0x400,
+        /// Export this object's non-scriptable invokables.
+        ExportNonScriptableInvokables = 0x800,
+        /// Shorthand form for all of the non-scriptable contents.
+        ExportNonScriptableContents = 0xf00,
+        /// Export all of this object's slots.
+        ExportAllSlots = 0x110,
+        /// Export all of this object's signals.
+        ExportAllSignals = 0x220,
+        /// Export all of this object's properties.
+        ExportAllProperties = 0x440,
+        /// Export all of this object's invokables.
+        ExportAllInvokables = 0x880,
+        /// Export all of this object's contents, which is used for CXX-Qt QObjects.
+        ExportAllContents = 0xff0,
+        /// Export this object's child objects.
+        ExportChildObjects = 0x1000,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qdbusconnection.h");
+        /// The QDBusConnection class represents a connection to the D-Bus bus daemon.
+        ///
+        /// A connection to the session or system bus is created with [QDBusConnection::session_bus]
+        /// or [QDBusConnection::system_bus].
+        type QDBusConnection;
+
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt_lib::QObject;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-lib-extras/qdbusmessage.h");
+        type QDBusMessage = crate::QDBusMessage;
+
+        /// Returns the unique connection name for this connection, such as `:1.42`.
+        #[rust_name = "base_service"]
+        fn baseService(self: &QDBusConnection) -> QString;
+
+        /// Returns true if this QDBusConnection object is connected.
+        #[rust_name = "is_connected"]
+        fn isConnected(self: &QDBusConnection) -> bool;
+
+        /// Returns the connection name for this connection.
+        fn name(self: &QDBusConnection) -> QString;
+
+        /// Attempts to register the service name on the D-Bus server, such as `org.example.MyService`.
+        ///
+        /// Returns true if the name was registered, false otherwise.
+        #[rust_name = "register_service"]
+        fn registerService(self: Pin<&mut QDBusConnection>, service_name: &QString) -> bool;
+
+        /// Sends the message over this connection, without waiting for a reply.
+        ///
+        /// This is suitable for errors, signals, and return values as well as calls whose return values are not necessary.
+        /// Returns true if the message was queued successfully, false otherwise.
+        fn send(self: &QDBusConnection, message: &QDBusMessage) -> bool;
+
+        /// Unregisters the service name that was registered with [QDBusConnection::register_service].
+        ///
+        /// Returns true if the name was unregistered, false otherwise.
+        #[rust_name = "unregister_service"]
+        fn unregisterService(self: Pin<&mut QDBusConnection>, service_name: &QString) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QDBusConnectionRegisterOption;
+
+        #[doc(hidden)]
+        #[rust_name = "qdbusconnection_call"]
+        fn qdbusconnectionCall(
+            connection: &QDBusConnection,
+            message: &QDBusMessage,
+        ) -> UniquePtr<QDBusMessage>;
+
+        #[doc(hidden)]
+        #[rust_name = "qdbusconnection_last_error_message"]
+        fn qdbusconnectionLastErrorMessage(connection: &QDBusConnection) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "qdbusconnection_register_object"]
+        unsafe fn qdbusconnectionRegisterObject(
+            connection: Pin<&mut QDBusConnection>,
+            path: &QString,
+            object: *mut QObject,
+            options: QDBusConnectionRegisterOption,
+        ) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qdbusconnection_session_bus"]
+        fn qdbusconnectionSessionBus() -> UniquePtr<QDBusConnection>;
+
+        #[doc(hidden)]
+        #[rust_name = "qdbusconnection_system_bus"]
+        fn qdbusconnectionSystemBus() -> UniquePtr<QDBusConnection>;
+
+        #[doc(hidden)]
+        #[rust_name = "qdbusconnection_unregister_object"]
+        fn qdbusconnectionUnregisterObject(connection: Pin<&mut QDBusConnection>, path: &QString);
+    }
+
+    // Allow for connections to be returned from the static bus helpers
+    impl UniquePtr<QDBusConnection> {}
+}
+
+use crate::QDBusMessage;
+use core::pin::Pin;
+use cxx::UniquePtr;
+use cxx_qt_lib::{QObject, QString};
+
+pub use ffi::{QDBusConnection, QDBusConnectionRegisterOption};
+
+impl QDBusConnection {
+    /// Returns a QDBusConnection object opened with the session bus.
+    pub fn session_bus() -> UniquePtr<Self> {
+        ffi::qdbusconnection_session_bus()
+    }
+
+    /// Returns a QDBusConnection object opened with the system bus.
+    pub fn system_bus() -> UniquePtr<Self> {
+        ffi::qdbusconnection_system_bus()
+    }
+
+    /// Sends the message over this connection and blocks, waiting for a reply.
+    ///
+    /// The reply is an [QDBusMessageMessageType::ErrorMessage](crate::QDBusMessageMessageType::ErrorMessage)
+    /// if the call failed, for example because the service does not exist.
+    pub fn call(&self, message: &QDBusMessage) -> UniquePtr<QDBusMessage> {
+        ffi::qdbusconnection_call(self, message)
+    }
+
+    /// Returns the message of the last error that happened in this connection.
+    pub fn last_error_message(&self) -> QString {
+        ffi::qdbusconnection_last_error_message(self)
+    }
+
+    /// Registers the object at the given path, such as `/org/example/MyObject`, and returns true if the registration was successful.
+    ///
+    /// Use [QDBusConnectionRegisterOption::ExportAllContents] to export the invokables, signals, and properties
+    /// of a CXX-Qt QObject, the name of the D-Bus interface can be set with the `#[dbus_interface = "..."]` attribute
+    /// on the QObject.
+    ///
+    /// # Safety
+    ///
+    /// The object must be a valid pointer to a QObject which outlives the registration,
+    /// such as a CXX-Qt QObject cast to a [QObject] pointer.
+    pub unsafe fn register_object(
+        self: Pin<&mut Self>,
+        path: &QString,
+        object: *mut QObject,
+        options: QDBusConnectionRegisterOption,
+    ) -> bool {
+        ffi::qdbusconnection_register_object(self, path, object, options)
+    }
+
+    /// Unregisters the object that was registered with [QDBusConnection::register_object] at the given path.
+    pub fn unregister_object(self: Pin<&mut Self>, path: &QString) {
+        ffi::qdbusconnection_unregister_object(self, path)
+    }
+}
@@ -0,0 +1,41 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qdbusinterface.h"
+
+#include <QtDBus/QDBusError>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QDBusMessage>
+qdbusinterfaceCall(QDBusInterface& interface,
+                   const QString& method,
+                   const QList<QVariant>& arguments)
+{
+  return ::std::make_unique<QDBusMessage>(
+    interface.callWithArgumentList(QDBus::Block, method, arguments));
+}
+
+QString
+qdbusinterfaceLastErrorMessage(const QDBusInterface& interface)
+{
+  return interface.lastError().message();
+}
+
+::std::unique_ptr<QDBusInterface>
+qdbusinterfaceNew(const QString& service,
+                  const QString& path,
+                  const QString& interface,
+                  const QDBusConnection& connection)
+{
+  return ::std::make_unique<QDBusInterface>(
+    service, path, interface, connection);
+}
+
+}
+}
@@ -0,0 +1,147 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qdbusinterface.h");
+        /// The QDBusInterface class is a proxy for interfaces on remote objects,
+        /// which is used to call methods of a service on the bus.
+        #[qobject]
+        type QDBusInterface;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QVariant = cxx_qt_lib::QList<cxx_qt_lib::QVariant>;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-lib-extras/qdbusconnection.h");
+        type QDBusConnection = crate::QDBusConnection;
+        include!("cxx-qt-lib-extras/qdbusmessage.h");
+        type QDBusMessage = crate::QDBusMessage;
+
+        /// Returns the name of this interface, such as `org.freedesktop.Notifications`.
+        fn interface(self: &QDBusInterface) -> QString;
+
+        /// Returns true if this is a valid reference to a remote object.
+        ///
+        /// It returns false if there was an error during the creation of this interface,
+        /// for instance if the remote application does not exist.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QDBusInterface) -> bool;
+
+        /// Returns the object path that this interface is associated with.
+        fn path(self: &QDBusInterface) -> QString;
+
+        /// Returns the name of the service this interface is associated with.
+        fn service(self: &QDBusInterface) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qdbusinterface_call"]
+        fn qdbusinterfaceCall(
+            interface: Pin<&mut QDBusInterface>,
+            method: &QString,
+            arguments: &QList_QVariant,
+        ) -> UniquePtr<QDBusMessage>;
+
+        #[doc(hidden)]
+        #[rust_name = "qdbusinterface_last_error_message"]
+        fn qdbusinterfaceLastErrorMessage(interface: &QDBusInterface) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "qdbusinterface_new"]
+        fn qdbusinterfaceNew(
+            service: &QString,
+            path: &QString,
+            interface: &QString,
+            connection: &QDBusConnection,
+        ) -> UniquePtr<QDBusInterface>;
+
+        #[doc(hidden)]
+        #[rust_name = "qdbusinterface_on_signal"]
+        fn qdbusinterfaceOnSignal(
+            interface: Pin<&mut QDBusInterface>,
+            name: &QString,
+            func: fn(&mut QDBusSignalHandler, &QDBusMessage),
+            arg: Box<QDBusSignalHandler>,
+        ) -> bool;
+    }
+
+    extern "Rust" {
+        #[namespace = "rust::cxxqtlib1"]
+        type QDBusSignalHandler;
+    }
+
+    // QDBusInterface is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QDBusInterface>::isRelocatable);
+    impl UniquePtr<QDBusInterface> {}
+}
+
+use crate::{QDBusConnection, QDBusMessage};
+use core::pin::Pin;
+use cxx::UniquePtr;
+use cxx_qt_lib::{QList, QString, QVariant};
+
+pub use ffi::QDBusInterface;
+
+impl QDBusInterface {
+    /// Calls the method on this interface with the given arguments and blocks, waiting for the reply.
+    ///
+    /// The arguments of the method are returned by [QDBusMessage::arguments] of the reply,
+    /// or an error if the call failed.
+    pub fn call(
+        self: Pin<&mut Self>,
+        method: &QString,
+        arguments: &QList<QVariant>,
+    ) -> UniquePtr<QDBusMessage> {
+        ffi::qdbusinterface_call(self, method, arguments)
+    }
+
+    /// Returns the message of the error that was generated by the last operation, such as the creation of the interface.
+    pub fn last_error_message(&self) -> QString {
+        ffi::qdbusinterface_last_error_message(self)
+    }
+
+    /// Create a new QDBusInterface for the interface of the object at path on the given service,
+    /// for example `org.freedesktop.Notifications` at `/org/freedesktop/Notifications`.
+    pub fn new(
+        service: &QString,
+        path: &QString,
+        interface: &QString,
+        connection: &QDBusConnection,
+    ) -> UniquePtr<Self> {
+        ffi::qdbusinterface_new(service, path, interface, connection)
+    }
+
+    /// Connect a closure which is called with the message of each emission of the remote signal with the given name.
+    ///
+    /// The arguments of the signal are returned by [QDBusMessage::arguments] of the message.
+    /// The closure stays connected for the lifetime of the interface.
+    /// Returns true if the connection was successful, otherwise false.
+    pub fn on_signal<F>(self: Pin<&mut Self>, name: &QString, closure: F) -> bool
+    where
+        F: FnMut(&QDBusMessage) + 'static,
+    {
+        fn func(handler: &mut QDBusSignalHandler, message: &QDBusMessage) {
+            (handler.inner)(message)
+        }
+        let arg = QDBusSignalHandler {
+            inner: Box::new(closure),
+        };
+        ffi::qdbusinterface_on_signal(self, name, func, Box::new(arg))
+    }
+}
+
+#[doc(hidden)]
+pub struct QDBusSignalHandler {
+    inner: Box<dyn FnMut(&QDBusMessage)>,
+}
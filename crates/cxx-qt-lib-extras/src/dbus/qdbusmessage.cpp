@@ -0,0 +1,33 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qdbusmessage.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QDBusMessage>
+qdbusmessageCreateMethodCall(const QString& service,
+                             const QString& path,
+                             const QString& interface,
+                             const QString& method)
+{
+  return ::std::make_unique<QDBusMessage>(
+    QDBusMessage::createMethodCall(service, path, interface, method));
+}
+
+::std::unique_ptr<QDBusMessage>
+qdbusmessageCreateSignal(const QString& path,
+                         const QString& interface,
+                         const QString& name)
+{
+  return ::std::make_unique<QDBusMessage>(
+    QDBusMessage::createSignal(path, interface, name));
+}
+
+}
+}
@@ -0,0 +1,121 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// The possible message types.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QDBusMessageMessageType {
+        /// This is an invalid message.
+        InvalidMessage = 0,
+        /// This message is a method call.
+        MethodCallMessage = 1,
+        /// This message is a reply to a method call.
+        ReplyMessage = 2,
+        /// This message is an error reply to a method call.
+        ErrorMessage = 3,
+        /// This message is a signal emission.
+        SignalMessage = 4,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qdbusmessage.h");
+        /// The QDBusMessage class represents one message sent or received over the D-Bus bus.
+        ///
+        /// This can be a method call, the reply to a method call, an error or a signal emission.
+        type QDBusMessage;
+
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QVariant = cxx_qt_lib::QList<cxx_qt_lib::QVariant>;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        /// Returns the list of arguments that are going to be sent or were received from D-Bus.
+        fn arguments(self: &QDBusMessage) -> QList_QVariant;
+
+        /// Returns the human-readable message associated with the error that was received.
+        #[rust_name = "error_message"]
+        fn errorMessage(self: &QDBusMessage) -> QString;
+
+        /// Returns the name of the error that was received, such as `org.freedesktop.DBus.Error.ServiceUnknown`.
+        #[rust_name = "error_name"]
+        fn errorName(self: &QDBusMessage) -> QString;
+
+        /// Returns the interface of the method being called or of the signal being received.
+        fn interface(self: &QDBusMessage) -> QString;
+
+        /// Returns the name of the signal that was emitted or the name of the method that was called.
+        fn member(self: &QDBusMessage) -> QString;
+
+        /// Returns the path of the object that this message is being sent to or the path of the object that emitted the signal.
+        fn path(self: &QDBusMessage) -> QString;
+
+        /// Returns the name of the service or the bus address of the remote method call.
+        fn service(self: &QDBusMessage) -> QString;
+
+        /// Sets the arguments that are going to be sent over D-Bus to arguments.
+        #[rust_name = "set_arguments"]
+        fn setArguments(self: Pin<&mut QDBusMessage>, arguments: &QList_QVariant);
+
+        /// Returns the message type.
+        #[cxx_name = "type"]
+        #[rust_name = "message_type"]
+        fn messageType(self: &QDBusMessage) -> QDBusMessageMessageType;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QDBusMessageMessageType;
+
+        #[doc(hidden)]
+        #[rust_name = "qdbusmessage_create_method_call"]
+        fn qdbusmessageCreateMethodCall(
+            service: &QString,
+            path: &QString,
+            interface: &QString,
+            method: &QString,
+        ) -> UniquePtr<QDBusMessage>;
+
+        #[doc(hidden)]
+        #[rust_name = "qdbusmessage_create_signal"]
+        fn qdbusmessageCreateSignal(
+            path: &QString,
+            interface: &QString,
+            name: &QString,
+        ) -> UniquePtr<QDBusMessage>;
+    }
+
+    // Allow for messages to be returned from calls
+    impl UniquePtr<QDBusMessage> {}
+}
+
+use cxx::UniquePtr;
+use cxx_qt_lib::QString;
+
+pub use ffi::{QDBusMessage, QDBusMessageMessageType};
+
+impl QDBusMessage {
+    /// Constructs a new message to represent a method call on the given object path and interface of a service.
+    ///
+    /// The message is sent with [QDBusConnection::call](crate::QDBusConnection::call),
+    /// the arguments of the method are set with [QDBusMessage::set_arguments].
+    pub fn create_method_call(
+        service: &QString,
+        path: &QString,
+        interface: &QString,
+        method: &QString,
+    ) -> UniquePtr<Self> {
+        ffi::qdbusmessage_create_method_call(service, path, interface, method)
+    }
+
+    /// Constructs a new message to represent a signal emission with the given name from the object path and interface.
+    ///
+    /// The message is sent with [QDBusConnection::send](crate::QDBusConnection::send).
+    pub fn create_signal(path: &QString, interface: &QString, name: &QString) -> UniquePtr<Self> {
+        ffi::qdbusmessage_create_signal(path, interface, name)
+    }
+}
@@ -3,5 +3,37 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod qaccessible;
+pub use qaccessible::{QAccessible, QAccessibleEventType, QAccessibleRole};
+
 mod qapplication;
 pub use qapplication::QApplication;
+
+mod qcompleter;
+pub use qcompleter::{QCompleter, QCompleterCompletionMode, QCompleterModelSorting};
+
+mod qpagedpaintdevice;
+pub use qpagedpaintdevice::{QPageLayoutOrientation, QPageLayoutUnit, QPageSizeId};
+
+mod qpdfwriter;
+pub use qpdfwriter::QPdfWriter;
+
+mod qsizepolicy;
+pub use qsizepolicy::{QSizePolicy, QSizePolicyControlType, QSizePolicyPolicy};
+
+mod qsyntaxhighlighter;
+pub use qsyntaxhighlighter::QSyntaxHighlighter;
+
+mod qtextcharformat;
+pub use qtextcharformat::{QTextCharFormat, QTextCharFormatUnderlineStyle};
+
+mod qtextcursor;
+pub use qtextcursor::{
+    QTextCursor, QTextCursorMoveMode, QTextCursorMoveOperation, QTextCursorSelectionType,
+};
+
+mod qtextdocument;
+pub use qtextdocument::{QTextDocument, QTextDocumentFindFlag};
+
+mod qwindow;
+pub use qwindow::QWindow;
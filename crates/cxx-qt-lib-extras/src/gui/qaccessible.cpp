@@ -0,0 +1,43 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qaccessible.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qaccessibleIsActive()
+{
+  return QAccessible::isActive();
+}
+
+void
+qaccessibleUpdateAccessibility(QObject& object, QAccessibleEventType event)
+{
+  // Avoid creating the event, and the accessible interface of the object,
+  // when there is no assistive technology
+  if (!QAccessible::isActive()) {
+    return;
+  }
+
+  QAccessibleEvent accessibleEvent(&object, event);
+  QAccessible::updateAccessibility(&accessibleEvent);
+}
+
+void
+qaccessibleUpdateAccessibilityValue(QObject& object, const QVariant& value)
+{
+  if (!QAccessible::isActive()) {
+    return;
+  }
+
+  QAccessibleValueChangeEvent accessibleEvent(&object, value);
+  QAccessible::updateAccessibility(&accessibleEvent);
+}
+
+}
+}
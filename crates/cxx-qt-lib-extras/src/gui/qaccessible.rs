@@ -0,0 +1,158 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QObject, QVariant};
+
+#[cxx::bridge]
+mod ffi {
+    /// The events which notify assistive technologies, such as screen readers, about changes of an object.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QAccessibleEventType {
+        /// The object has been shown.
+        ObjectShow = 0x8002,
+        /// The object has been hidden.
+        ObjectHide = 0x8003,
+        /// The children of the object have been reordered.
+        ObjectReorder = 0x8004,
+        /// The object has gained keyboard focus.
+        Focus = 0x8005,
+        /// The selection of the object has changed.
+        Selection = 0x8006,
+        /// The state of the object has changed, such as whether it is checked.
+        StateChanged = 0x800A,
+        /// The position or size of the object has changed.
+        LocationChanged = 0x800B,
+        /// The accessible name of the object has changed.
+        NameChanged = 0x800C,
+        /// The accessible description of the object has changed.
+        DescriptionChanged = 0x800D,
+        /// The value of the object has changed, see [QAccessible::update_accessibility_value].
+        ValueChanged = 0x800E,
+        /// The parent of the object has changed.
+        ParentChanged = 0x800F,
+    }
+
+    /// The role of an object, which tells assistive technologies what kind of element the object is.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QAccessibleRole {
+        /// The object has no role, which usually indicates an invalid object.
+        NoRole = 0x00,
+        /// The object represents the client area of a window.
+        Client = 0x0A,
+        /// The object is a document window, such as in an MDI application.
+        Document = 0x0F,
+        /// The object represents a frame or a generic container.
+        Pane = 0x10,
+        /// The object represents a graphical image used to represent data.
+        Chart = 0x11,
+        /// The object represents a logical grouping of other objects.
+        Grouping = 0x14,
+        /// The object represents a table.
+        Table = 0x18,
+        /// The object represents a cell in a table.
+        Cell = 0x1D,
+        /// The object represents a link to something else.
+        Link = 0x1E,
+        /// The object represents a list box.
+        List = 0x21,
+        /// The object represents an item in a list.
+        ListItem = 0x22,
+        /// The object represents a hierarchical list of items.
+        Tree = 0x23,
+        /// The object represents an item in a tree.
+        TreeItem = 0x24,
+        /// The object represents an indicator, such as the pointer on a slider.
+        Indicator = 0x27,
+        /// The object represents a picture.
+        Graphic = 0x28,
+        /// The object represents text which is only for reading.
+        StaticText = 0x29,
+        /// The object represents text which can be edited.
+        EditableText = 0x2A,
+        /// The object represents a push button.
+        Button = 0x2B,
+        /// The object represents a check box.
+        CheckBox = 0x2C,
+        /// The object represents a radio button.
+        RadioButton = 0x2D,
+        /// The object represents a combo box.
+        ComboBox = 0x2E,
+        /// The object represents a progress bar.
+        ProgressBar = 0x30,
+        /// The object represents a dial or knob.
+        Dial = 0x31,
+        /// The object represents a slider.
+        Slider = 0x33,
+        /// The object represents a spin box.
+        SpinBox = 0x34,
+        /// The object represents a canvas which is drawn on, such as custom Rust rendered content.
+        Canvas = 0x35,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt_lib::QObject;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qaccessible.h");
+        type QAccessibleEventType;
+        type QAccessibleRole;
+
+        #[doc(hidden)]
+        #[rust_name = "qaccessible_is_active"]
+        fn qaccessibleIsActive() -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qaccessible_update_accessibility"]
+        fn qaccessibleUpdateAccessibility(object: Pin<&mut QObject>, event: QAccessibleEventType);
+
+        #[doc(hidden)]
+        #[rust_name = "qaccessible_update_accessibility_value"]
+        fn qaccessibleUpdateAccessibilityValue(object: Pin<&mut QObject>, value: &QVariant);
+    }
+}
+
+pub use ffi::{QAccessibleEventType, QAccessibleRole};
+
+/// Notifies assistive technologies, such as screen readers, about changes of objects.
+///
+/// The name, description, and role of a Rust QML item are set with
+/// [QQuickItem::set_accessible_name](crate::QQuickItem::set_accessible_name) and similar,
+/// and events are emitted when they change, or when custom Rust rendered content changes.
+///
+/// ```ignore
+/// let mut object = self.as_mut().upcast_pin();
+/// QAccessible::update_accessibility_value(object.as_mut(), &QVariant::from(&value));
+/// ```
+pub struct QAccessible;
+
+impl QAccessible {
+    /// Returns true if an assistive technology is active, so events can be skipped when this is false.
+    pub fn is_active() -> bool {
+        ffi::qaccessible_is_active()
+    }
+
+    /// Notifies assistive technologies that the object has changed with the given event.
+    ///
+    /// For changes of the value use [QAccessible::update_accessibility_value], which includes the new value.
+    pub fn update_accessibility(object: Pin<&mut QObject>, event: QAccessibleEventType) {
+        ffi::qaccessible_update_accessibility(object, event);
+    }
+
+    /// Notifies assistive technologies that the value of the object has changed to the given value,
+    /// such as the value of a slider or progress bar.
+    pub fn update_accessibility_value(object: Pin<&mut QObject>, value: &QVariant) {
+        ffi::qaccessible_update_accessibility_value(object, value);
+    }
+}
@@ -0,0 +1,34 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qcompleter.h"
+
+#include <QtCore/QAbstractItemModel>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QObject*
+qcompleterCompletionModel(const QCompleter& completer)
+{
+  return completer.completionModel();
+}
+
+::std::unique_ptr<QCompleter>
+qcompleterNew()
+{
+  return ::std::make_unique<QCompleter>();
+}
+
+::std::unique_ptr<QCompleter>
+qcompleterNewWithStringList(const QStringList& list)
+{
+  return ::std::make_unique<QCompleter>(list);
+}
+
+}
+}
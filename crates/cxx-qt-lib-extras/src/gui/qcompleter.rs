@@ -0,0 +1,214 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// This enum specifies how completions are provided to the user.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QCompleterCompletionMode {
+        /// Current completions are displayed in a popup window.
+        PopupCompletion,
+        /// All possible completions are displayed in a popup window with the most likely suggestion indicated as current.
+        UnfilteredPopupCompletion,
+        /// Completions appear inline (as selected text).
+        InlineCompletion,
+    }
+
+    /// This enum specifies how the items in the model are sorted.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QCompleterModelSorting {
+        /// The model is unsorted.
+        UnsortedModel,
+        /// The model is sorted case sensitively.
+        CaseSensitivelySortedModel,
+        /// The model is sorted case insensitively.
+        CaseInsensitivelySortedModel,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = cxx_qt_lib::QModelIndex;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt_lib::QObject;
+        include!("cxx-qt-lib/qrect.h");
+        type QRect = cxx_qt_lib::QRect;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = cxx_qt_lib::QStringList;
+    }
+
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type CaseSensitivity = cxx_qt_lib::CaseSensitivity;
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qcompleter.h");
+        /// The QCompleter class provides completions based on an item model.
+        ///
+        /// The completions can come from a list of strings, or from a model which is implemented in Rust.
+        /// As Rust QObjects cannot be upcast to QAbstractItemModel yet, such a model is set
+        /// by declaring the `qcompleterSetModel` template in the bridge of the model.
+        ///
+        /// ```ignore
+        /// #[cxx_qt::bridge]
+        /// mod ffi {
+        ///     unsafe extern "C++" {
+        ///         include!("cxx-qt-lib-extras/qcompleter.h");
+        ///         type QCompleter = cxx_qt_lib_extras::QCompleter;
+        ///     }
+        ///
+        ///     #[namespace = "rust::cxxqtlib1"]
+        ///     unsafe extern "C++" {
+        ///         #[rust_name = "set_completer_model"]
+        ///         fn qcompleterSetModel(completer: Pin<&mut QCompleter>, model: Pin<&mut CustomBaseClass>);
+        ///     }
+        /// }
+        /// ```
+        ///
+        /// The completer does not take ownership of the model, so the model must outlive the completer.
+        #[qobject]
+        type QCompleter;
+
+        /// This signal is sent when an item in the popup is activated by the user
+        /// or when the user accepts an inline completion, with the text of the item.
+        #[qsignal]
+        #[cxx_name = "activated"]
+        fn activated(self: Pin<&mut QCompleter>, text: &QString);
+
+        /// This signal is sent when an item in the popup is activated by the user
+        /// or when the user accepts an inline completion, with the index of the item in the model.
+        #[qsignal]
+        #[cxx_name = "activated"]
+        fn activated_index(self: Pin<&mut QCompleter>, index: &QModelIndex);
+
+        /// This signal is sent when an item in the popup is highlighted by the user, with the text of the item.
+        #[qsignal]
+        #[cxx_name = "highlighted"]
+        fn highlighted(self: Pin<&mut QCompleter>, text: &QString);
+
+        /// This signal is sent when an item in the popup is highlighted by the user,
+        /// with the index of the item in the model.
+        #[qsignal]
+        #[cxx_name = "highlighted"]
+        fn highlighted_index(self: Pin<&mut QCompleter>, index: &QModelIndex);
+    }
+
+    unsafe extern "C++" {
+        /// Returns the case sensitivity of the matching.
+        #[rust_name = "case_sensitivity"]
+        fn caseSensitivity(self: &QCompleter) -> CaseSensitivity;
+
+        /// For QCompleter objects used with a widget, displays the popup for the current completion prefix
+        /// with the given rectangle, or below the widget if the rectangle is null.
+        fn complete(self: Pin<&mut QCompleter>, rect: &QRect);
+
+        /// Returns the number of completions for the current prefix.
+        #[rust_name = "completion_count"]
+        fn completionCount(self: &QCompleter) -> i32;
+
+        /// Returns how the completions are provided to the user.
+        #[rust_name = "completion_mode"]
+        fn completionMode(self: &QCompleter) -> QCompleterCompletionMode;
+
+        /// Returns the completion prefix, which is used to filter the model.
+        #[rust_name = "completion_prefix"]
+        fn completionPrefix(self: &QCompleter) -> QString;
+
+        /// Returns the current completion string, which includes the completion prefix.
+        #[rust_name = "current_completion"]
+        fn currentCompletion(self: &QCompleter) -> QString;
+
+        /// Returns the current row in the completion model.
+        #[rust_name = "current_row"]
+        fn currentRow(self: &QCompleter) -> i32;
+
+        /// Returns the maximum allowed size on screen of the completer, measured in items.
+        #[rust_name = "max_visible_items"]
+        fn maxVisibleItems(self: &QCompleter) -> i32;
+
+        /// Returns the way the model is sorted.
+        #[rust_name = "model_sorting"]
+        fn modelSorting(self: &QCompleter) -> QCompleterModelSorting;
+
+        /// Sets the case sensitivity of the matching.
+        #[rust_name = "set_case_sensitivity"]
+        fn setCaseSensitivity(self: Pin<&mut QCompleter>, case_sensitivity: CaseSensitivity);
+
+        /// Sets how the completions are provided to the user.
+        #[rust_name = "set_completion_mode"]
+        fn setCompletionMode(self: Pin<&mut QCompleter>, mode: QCompleterCompletionMode);
+
+        /// Sets the completion prefix, the completion model is then updated to the matching completions.
+        #[rust_name = "set_completion_prefix"]
+        fn setCompletionPrefix(self: Pin<&mut QCompleter>, prefix: &QString);
+
+        /// Sets the current row in the completion model, returns true if successful.
+        #[rust_name = "set_current_row"]
+        fn setCurrentRow(self: Pin<&mut QCompleter>, row: i32) -> bool;
+
+        /// Sets the maximum allowed size on screen of the completer, measured in items.
+        #[rust_name = "set_max_visible_items"]
+        fn setMaxVisibleItems(self: Pin<&mut QCompleter>, max_items: i32);
+
+        /// Sets the way the model is sorted, a sorted model allows for faster completions.
+        #[rust_name = "set_model_sorting"]
+        fn setModelSorting(self: Pin<&mut QCompleter>, sorting: QCompleterModelSorting);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QCompleterCompletionMode;
+        type QCompleterModelSorting;
+
+        #[doc(hidden)]
+        #[rust_name = "qcompleter_completion_model"]
+        fn qcompleterCompletionModel(completer: &QCompleter) -> *mut QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "qcompleter_new"]
+        fn qcompleterNew() -> UniquePtr<QCompleter>;
+
+        #[doc(hidden)]
+        #[rust_name = "qcompleter_new_with_string_list"]
+        fn qcompleterNewWithStringList(list: &QStringList) -> UniquePtr<QCompleter>;
+    }
+
+    // QCompleter is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QCompleter>::isRelocatable);
+    impl UniquePtr<QCompleter> {}
+}
+
+use cxx_qt_lib::{QObject, QStringList};
+
+pub use ffi::{QCompleter, QCompleterCompletionMode, QCompleterModelSorting};
+
+impl QCompleter {
+    /// Constructs a completer without a model.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qcompleter_new()
+    }
+
+    /// Constructs a completer which completes from the given list of strings.
+    pub fn new_with_string_list(list: &QStringList) -> cxx::UniquePtr<Self> {
+        ffi::qcompleter_new_with_string_list(list)
+    }
+
+    /// Returns the completion model, which contains the completions for the current completion prefix.
+    ///
+    /// This is a QAbstractItemModel, so it can be exposed to QML as a `*mut QObject` property
+    /// and used as the model of the ListView of a completion popup.
+    pub fn completion_model(&self) -> *mut QObject {
+        ffi::qcompleter_completion_model(self)
+    }
+}
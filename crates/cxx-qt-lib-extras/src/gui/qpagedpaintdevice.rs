@@ -0,0 +1,76 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum type defines the page orientation of a paged paint device.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QPageLayoutOrientation {
+        /// The page is taller than it is wide.
+        Portrait = 0,
+        /// The page is wider than it is tall.
+        Landscape = 1,
+    }
+
+    /// This enum type is used to specify the measurement unit for page layout and margins.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QPageLayoutUnit {
+        /// Millimeters.
+        Millimeter = 0,
+        /// Points, 1/72th of an inch.
+        Point = 1,
+        /// Inches.
+        Inch = 2,
+        /// Picas, 1/72th of a foot.
+        Pica = 3,
+        /// Didots, 1/72th of a French inch.
+        Didot = 4,
+        /// Ciceros, 1/6th of a French inch.
+        Cicero = 5,
+    }
+
+    /// This enum type lists the common standard page sizes.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QPageSizeId {
+        /// 210 x 297 mm
+        A4 = 0,
+        /// 176 x 250 mm
+        B5 = 1,
+        /// 8.5 x 11 inches
+        Letter = 2,
+        /// 8.5 x 14 inches
+        Legal = 3,
+        /// 7.5 x 10 inches
+        Executive = 4,
+        /// 297 x 420 mm
+        A3 = 8,
+        /// 148 x 210 mm
+        A5 = 9,
+        /// 105 x 148 mm
+        A6 = 10,
+        /// 250 x 353 mm
+        B4 = 19,
+        /// 17 x 11 inches
+        Ledger = 28,
+        /// 11 x 17 inches
+        Tabloid = 29,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qpagedpaintdevice.h");
+        type QPageLayoutOrientation;
+        type QPageLayoutUnit;
+        type QPageSizeId;
+    }
+}
+
+pub use ffi::{QPageLayoutOrientation, QPageLayoutUnit, QPageSizeId};
@@ -0,0 +1,32 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qpdfwriter.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QPdfWriter>
+qpdfwriterNew(const QString& filename)
+{
+  return ::std::make_unique<QPdfWriter>(filename);
+}
+
+::std::unique_ptr<QPainter>
+qpdfwriterNewPainter(QPdfWriter& writer)
+{
+  return ::std::make_unique<QPainter>(&writer);
+}
+
+bool
+qpdfwriterSetPageSize(QPdfWriter& writer, QPageSizeId pageSizeId)
+{
+  return writer.setPageSize(QPageSize(pageSizeId));
+}
+
+}
+}
@@ -0,0 +1,138 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmarginsf.h");
+        type QMarginsF = cxx_qt_lib::QMarginsF;
+        include!("cxx-qt-lib/qpainter.h");
+        type QPainter = cxx_qt_lib::QPainter;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qpdfwriter.h");
+        /// The QPdfWriter class is a class to generate PDFs that can be used as a paint device.
+        ///
+        /// Use [QPdfWriter::paint] to draw on its pages with the same [QPainter] code which is used on screen.
+        #[qobject]
+        type QPdfWriter;
+
+        /// Returns the creator of the document.
+        fn creator(self: &QPdfWriter) -> QString;
+
+        /// Adds an empty page to the document and makes it the current page, returns true if successful.
+        #[rust_name = "new_page"]
+        fn newPage(self: Pin<&mut QPdfWriter>) -> bool;
+
+        /// Returns the resolution of the PDF in DPI.
+        fn resolution(self: &QPdfWriter) -> i32;
+
+        /// Sets the creator of the document to creator.
+        #[rust_name = "set_creator"]
+        fn setCreator(self: Pin<&mut QPdfWriter>, creator: &QString);
+
+        /// Sets the page margins of the document in the given units, returns true if the margins were set.
+        #[rust_name = "set_page_margins"]
+        fn setPageMargins(
+            self: Pin<&mut QPdfWriter>,
+            margins: &QMarginsF,
+            units: QPageLayoutUnit,
+        ) -> bool;
+
+        /// Sets the page orientation of the document, returns true if the orientation was set.
+        #[rust_name = "set_page_orientation"]
+        fn setPageOrientation(
+            self: Pin<&mut QPdfWriter>,
+            orientation: QPageLayoutOrientation,
+        ) -> bool;
+
+        /// Sets the PDF resolution in DPI, which is the unit of the coordinates of the [QPainter].
+        ///
+        /// This setting affects the coordinate system as returned by, for example QPainter::viewport().
+        #[rust_name = "set_resolution"]
+        fn setResolution(self: Pin<&mut QPdfWriter>, resolution: i32);
+
+        /// Sets the title of the document being created to title.
+        #[rust_name = "set_title"]
+        fn setTitle(self: Pin<&mut QPdfWriter>, title: &QString);
+
+        /// Returns the title of the document.
+        fn title(self: &QPdfWriter) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qpagedpaintdevice.h");
+        type QPageLayoutOrientation = crate::QPageLayoutOrientation;
+        type QPageLayoutUnit = crate::QPageLayoutUnit;
+        type QPageSizeId = crate::QPageSizeId;
+
+        #[doc(hidden)]
+        #[rust_name = "qpdfwriter_new"]
+        fn qpdfwriterNew(filename: &QString) -> UniquePtr<QPdfWriter>;
+
+        #[doc(hidden)]
+        #[rust_name = "qpdfwriter_new_painter"]
+        fn qpdfwriterNewPainter(writer: Pin<&mut QPdfWriter>) -> UniquePtr<QPainter>;
+
+        #[doc(hidden)]
+        #[rust_name = "qpdfwriter_set_page_size"]
+        fn qpdfwriterSetPageSize(writer: Pin<&mut QPdfWriter>, page_size_id: QPageSizeId) -> bool;
+    }
+
+    // QPdfWriter is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QPdfWriter>::isRelocatable);
+    impl UniquePtr<QPdfWriter> {}
+}
+
+use crate::QPageSizeId;
+use core::pin::Pin;
+use cxx_qt_lib::{QPainter, QString};
+
+pub use ffi::QPdfWriter;
+
+impl QPdfWriter {
+    /// Constructs a PDF writer that will write the PDF to filename.
+    pub fn new(filename: &QString) -> cxx::UniquePtr<Self> {
+        ffi::qpdfwriter_new(filename)
+    }
+
+    /// Paint on the pages of the document with a [QPainter], which is active while the closure is called.
+    ///
+    /// The closure is also given the writer, so that it can call [QPdfWriter::new_page] to continue on a new page.
+    /// The document is written when the closure returns.
+    /// Returns false without calling the closure if painting could not begin, for example if the file cannot be opened.
+    ///
+    /// ```ignore
+    /// let mut writer = QPdfWriter::new(&QString::from("report.pdf"));
+    /// writer.pin_mut().paint(|mut painter, writer| {
+    ///     painter.as_mut().draw_text(&QPoint::new(100, 100), &QString::from("Page 1"));
+    ///     writer.new_page();
+    ///     painter.as_mut().draw_text(&QPoint::new(100, 100), &QString::from("Page 2"));
+    /// });
+    /// ```
+    pub fn paint<F>(mut self: Pin<&mut Self>, f: F) -> bool
+    where
+        F: FnOnce(Pin<&mut QPainter>, Pin<&mut Self>),
+    {
+        let mut painter = ffi::qpdfwriter_new_painter(self.as_mut());
+        if !painter.is_active() {
+            return false;
+        }
+
+        f(painter.pin_mut(), self);
+        true
+    }
+
+    /// Sets the page size of the document to one of the standard page sizes, returns true if the page size was set.
+    pub fn set_page_size(self: Pin<&mut Self>, page_size_id: QPageSizeId) -> bool {
+        ffi::qpdfwriter_set_page_size(self, page_size_id)
+    }
+}
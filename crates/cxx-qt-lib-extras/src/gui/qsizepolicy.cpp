@@ -0,0 +1,35 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qsizepolicy.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+#include <cstdint>
+
+// QSizePolicy has a union of bitfields and a "quint32"
+// Rust represents this as a 32-bit integer.
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/widgets/kernel/qsizepolicy.h?h=v5.15.6-lts-lgpl#n205
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/widgets/kernel/qsizepolicy.h?h=v6.2.4#n176
+assert_alignment_and_size(QSizePolicy, { ::std::uint32_t a0; });
+
+static_assert(::std::is_trivially_copyable<QSizePolicy>::value,
+              "QSizePolicy must be trivially copyable!");
+
+namespace rust {
+namespace cxxqtlib1 {
+namespace qvariant {
+
+bool
+qvariantCanConvertQSizePolicy(const QVariant& variant)
+{
+  return variant.canConvert<QSizePolicy>();
+}
+
+}
+}
+}
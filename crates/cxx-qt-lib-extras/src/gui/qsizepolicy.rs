@@ -0,0 +1,257 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use cxx_qt_lib::{QVariant, QVariantValue};
+use std::fmt;
+
+#[cxx::bridge]
+mod ffi {
+    /// Describes the different types of widgets, which affects the spacing between them in layouts.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSizePolicyControlType {
+        /// The default type, when none is specified.
+        DefaultType = 0x00000001,
+        /// A QDialogButtonBox instance.
+        ButtonBox = 0x00000002,
+        /// A QCheckBox instance.
+        CheckBox = 0x00000004,
+        /// A QComboBox instance.
+        ComboBox = 0x00000008,
+        /// A QFrame instance.
+        Frame = 0x00000010,
+        /// A QGroupBox instance.
+        GroupBox = 0x00000020,
+        /// A QLabel instance.
+        Label = 0x00000040,
+        /// A QFrame instance with QFrame::HLine or QFrame::VLine.
+        Line = 0x00000080,
+        /// A QLineEdit instance.
+        LineEdit = 0x00000100,
+        /// A QPushButton instance.
+        PushButton = 0x00000200,
+        /// A QRadioButton instance.
+        RadioButton = 0x00000400,
+        /// A QAbstractSlider instance.
+        Slider = 0x00000800,
+        /// A QAbstractSpinBox instance.
+        SpinBox = 0x00001000,
+        /// A QTabWidget instance.
+        TabWidget = 0x00002000,
+        /// A QToolButton instance.
+        ToolButton = 0x00004000,
+    }
+
+    /// The ways in which a widget can be resized by a layout, relative to its size hint.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSizePolicyPolicy {
+        /// The size hint is the only acceptable alternative, so the widget can never grow or shrink.
+        Fixed = 0,
+        /// The size hint is minimal, and sufficient. The widget can be expanded,
+        /// but there is no advantage to it being larger.
+        Minimum = 1,
+        /// The size hint is a maximum. The widget can be shrunk any amount
+        /// without detriment if other widgets need the space.
+        Maximum = 4,
+        /// The size hint is best, but the widget can be shrunk and still be useful.
+        /// The widget can be expanded, but there is no advantage to it being larger.
+        Preferred = 5,
+        /// The size hint is minimal, and sufficient. The widget can make use of extra space,
+        /// so it should get as much space as possible.
+        MinimumExpanding = 3,
+        /// The size hint is a sensible size, but the widget can be shrunk and still be useful.
+        /// The widget can make use of extra space, so it should get as much space as possible.
+        Expanding = 7,
+        /// The size hint is ignored. The widget will get as much space as possible.
+        Ignored = 13,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qsizepolicy.h");
+        type QSizePolicy = super::QSizePolicy;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+
+        /// Returns the control type associated with the widget for which this size policy applies.
+        #[rust_name = "control_type"]
+        fn controlType(self: &QSizePolicy) -> QSizePolicyControlType;
+
+        /// Returns true if the widget's preferred height depends on its width; otherwise returns false.
+        #[rust_name = "has_height_for_width"]
+        fn hasHeightForWidth(self: &QSizePolicy) -> bool;
+
+        /// Returns true if the widget's preferred width depends on its height; otherwise returns false.
+        #[rust_name = "has_width_for_height"]
+        fn hasWidthForHeight(self: &QSizePolicy) -> bool;
+
+        /// Returns the horizontal component of the size policy.
+        #[rust_name = "horizontal_policy"]
+        fn horizontalPolicy(self: &QSizePolicy) -> QSizePolicyPolicy;
+
+        /// Returns the horizontal stretch factor of the size policy.
+        #[rust_name = "horizontal_stretch"]
+        fn horizontalStretch(self: &QSizePolicy) -> i32;
+
+        /// Returns whether the layout should retain the widget's size when it is hidden.
+        #[rust_name = "retain_size_when_hidden"]
+        fn retainSizeWhenHidden(self: &QSizePolicy) -> bool;
+
+        /// Sets the control type associated with the widget for which this size policy applies to type.
+        #[rust_name = "set_control_type"]
+        fn setControlType(self: &mut QSizePolicy, control_type: QSizePolicyControlType);
+
+        /// Sets the flag determining whether the widget's preferred height depends on its width, to dependent.
+        #[rust_name = "set_height_for_width"]
+        fn setHeightForWidth(self: &mut QSizePolicy, dependent: bool);
+
+        /// Sets the horizontal component to the given policy.
+        #[rust_name = "set_horizontal_policy"]
+        fn setHorizontalPolicy(self: &mut QSizePolicy, policy: QSizePolicyPolicy);
+
+        /// Sets the horizontal stretch factor of the size policy to the given stretch factor,
+        /// which must be in the range [0,255].
+        #[rust_name = "set_horizontal_stretch"]
+        fn setHorizontalStretch(self: &mut QSizePolicy, stretch_factor: i32);
+
+        /// Sets whether a layout should retain the widget's size when it is hidden.
+        #[rust_name = "set_retain_size_when_hidden"]
+        fn setRetainSizeWhenHidden(self: &mut QSizePolicy, retain_size: bool);
+
+        /// Sets the vertical component to the given policy.
+        #[rust_name = "set_vertical_policy"]
+        fn setVerticalPolicy(self: &mut QSizePolicy, policy: QSizePolicyPolicy);
+
+        /// Sets the vertical stretch factor of the size policy to the given stretch factor,
+        /// which must be in the range [0,255].
+        #[rust_name = "set_vertical_stretch"]
+        fn setVerticalStretch(self: &mut QSizePolicy, stretch_factor: i32);
+
+        /// Sets the flag determining whether the widget's preferred width depends on its height, to dependent.
+        #[rust_name = "set_width_for_height"]
+        fn setWidthForHeight(self: &mut QSizePolicy, dependent: bool);
+
+        /// Swaps the horizontal and vertical policies and stretches.
+        fn transpose(self: &mut QSizePolicy);
+
+        /// Returns a size policy object with the horizontal and vertical policies and stretches swapped.
+        fn transposed(self: &QSizePolicy) -> QSizePolicy;
+
+        /// Returns the vertical component of the size policy.
+        #[rust_name = "vertical_policy"]
+        fn verticalPolicy(self: &QSizePolicy) -> QSizePolicyPolicy;
+
+        /// Returns the vertical stretch factor of the size policy.
+        #[rust_name = "vertical_stretch"]
+        fn verticalStretch(self: &QSizePolicy) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+        type QSizePolicyControlType;
+        type QSizePolicyPolicy;
+
+        #[doc(hidden)]
+        #[rust_name = "qsizepolicy_init_default"]
+        fn construct() -> QSizePolicy;
+
+        #[doc(hidden)]
+        #[rust_name = "qsizepolicy_init"]
+        fn construct(
+            horizontal: QSizePolicyPolicy,
+            vertical: QSizePolicyPolicy,
+            control_type: QSizePolicyControlType,
+        ) -> QSizePolicy;
+
+        #[doc(hidden)]
+        #[rust_name = "qsizepolicy_to_qstring"]
+        fn toQString(value: &QSizePolicy) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvariant"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "can_convert_QSizePolicy"]
+        fn qvariantCanConvertQSizePolicy(variant: &QVariant) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "construct_QSizePolicy"]
+        fn qvariantConstruct(value: &QSizePolicy) -> QVariant;
+
+        #[doc(hidden)]
+        #[rust_name = "value_or_default_QSizePolicy"]
+        fn qvariantValueOrDefault(variant: &QVariant) -> QSizePolicy;
+    }
+}
+
+pub use ffi::{QSizePolicyControlType, QSizePolicyPolicy};
+
+/// The QSizePolicy struct is a layout attribute describing horizontal and vertical resizing policy.
+///
+/// The size policy of a widget is an expression of its willingness to be resized in various ways,
+/// and affects how the widget is treated by the layout engine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct QSizePolicy {
+    data: u32,
+}
+
+impl QSizePolicy {
+    /// Constructs a size policy with the given horizontal and vertical policies,
+    /// and the default control type.
+    pub fn new(horizontal: QSizePolicyPolicy, vertical: QSizePolicyPolicy) -> Self {
+        ffi::qsizepolicy_init(horizontal, vertical, QSizePolicyControlType::DefaultType)
+    }
+
+    /// Constructs a size policy with the given horizontal and vertical policies, and the given control type.
+    pub fn new_with_control_type(
+        horizontal: QSizePolicyPolicy,
+        vertical: QSizePolicyPolicy,
+        control_type: QSizePolicyControlType,
+    ) -> Self {
+        ffi::qsizepolicy_init(horizontal, vertical, control_type)
+    }
+}
+
+impl Default for QSizePolicy {
+    /// Constructs a size policy with the Fixed policy in both directions, and the default control type.
+    fn default() -> Self {
+        ffi::qsizepolicy_init_default()
+    }
+}
+
+impl fmt::Display for QSizePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qsizepolicy_to_qstring(self))
+    }
+}
+
+impl QVariantValue for QSizePolicy {
+    fn can_convert(variant: &QVariant) -> bool {
+        ffi::can_convert_QSizePolicy(variant)
+    }
+
+    fn construct(value: &Self) -> QVariant {
+        ffi::construct_QSizePolicy(value)
+    }
+
+    fn value_or_default(variant: &QVariant) -> Self {
+        ffi::value_or_default_QSizePolicy(variant)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side ensure that QSizePolicy is trivial.
+unsafe impl ExternType for QSizePolicy {
+    type Id = type_id!("QSizePolicy");
+    type Kind = cxx::kind::Trivial;
+}
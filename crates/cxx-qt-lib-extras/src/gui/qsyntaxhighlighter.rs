@@ -0,0 +1,127 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib-extras/qtextcharformat.h");
+        type QTextCharFormat = crate::QTextCharFormat;
+        include!("cxx-qt-lib-extras/qtextdocument.h");
+        type QTextDocument = crate::QTextDocument;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qsyntaxhighlighter.h");
+        /// The QSyntaxHighlighter class allows you to define syntax highlighting rules,
+        /// which highlight the blocks of a [QTextDocument](crate::QTextDocument) with a Rust closure.
+        ///
+        /// Use [QSyntaxHighlighter::new] to attach a highlighter to a document.
+        #[cxx_name = "QSyntaxHighlighterRust"]
+        type QSyntaxHighlighter;
+
+        /// Returns the state of the current text block, or -1 if no value is set.
+        #[rust_name = "current_block_state"]
+        fn currentBlockState(self: &QSyntaxHighlighter) -> i32;
+
+        /// Returns the document on which this syntax highlighter is installed.
+        fn document(self: &QSyntaxHighlighter) -> *mut QTextDocument;
+
+        /// Returns the format at position inside the syntax highlighter's current text block.
+        fn format(self: &QSyntaxHighlighter, position: i32) -> QTextCharFormat;
+
+        /// Returns the state of the text block that precedes the current one, or -1 if no value is set.
+        ///
+        /// This can be used to continue constructs which span multiple blocks, such as comments.
+        #[rust_name = "previous_block_state"]
+        fn previousBlockState(self: &QSyntaxHighlighter) -> i32;
+
+        /// Reapplies the highlighting to the whole document, for example after the rules of the highlighter changed.
+        fn rehighlight(self: Pin<&mut QSyntaxHighlighter>);
+
+        /// Sets the state of the current text block to new_state.
+        ///
+        /// When the state of a block changes, the following block is highlighted again.
+        #[rust_name = "set_current_block_state"]
+        fn setCurrentBlockState(self: Pin<&mut QSyntaxHighlighter>, new_state: i32);
+
+        /// Applies the format to the syntax highlighter's current text block,
+        /// for the given count of characters starting at the start position.
+        ///
+        /// The format is only used for display and is not stored in the document.
+        #[rust_name = "set_format"]
+        fn setFormat(
+            self: Pin<&mut QSyntaxHighlighter>,
+            start: i32,
+            count: i32,
+            format: &QTextCharFormat,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qsyntaxhighlighter_new"]
+        fn qsyntaxhighlighterNew(
+            document: Pin<&mut QTextDocument>,
+            func: fn(&mut QSyntaxHighlighterHandler, Pin<&mut QSyntaxHighlighter>, &QString),
+            arg: Box<QSyntaxHighlighterHandler>,
+        ) -> *mut QSyntaxHighlighter;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        type QSyntaxHighlighterHandler;
+    }
+}
+
+use crate::QTextDocument;
+use core::pin::Pin;
+use cxx_qt_lib::QString;
+
+pub use ffi::QSyntaxHighlighter;
+
+impl QSyntaxHighlighter {
+    /// Installs a syntax highlighter on the given document, which calls the closure to highlight each block of text.
+    ///
+    /// The closure is called with the text of the block whenever the block changes,
+    /// and uses [QSyntaxHighlighter::set_format] to format ranges of the block.
+    /// The highlighter is owned by the document, and is deleted with the document.
+    ///
+    /// To highlight the text of a QML TextEdit or TextArea, use the document of its
+    /// [QQuickTextDocument](crate::QQuickTextDocument).
+    ///
+    /// ```ignore
+    /// let mut keyword = QTextCharFormat::default();
+    /// keyword.set_font_weight(700);
+    /// QSyntaxHighlighter::new(document, move |highlighter, text| {
+    ///     for (start, count) in lex_keywords(&String::from(text)) {
+    ///         highlighter.as_mut().set_format(start, count, &keyword);
+    ///     }
+    /// });
+    /// ```
+    pub fn new<F>(document: Pin<&mut QTextDocument>, highlighter: F) -> *mut Self
+    where
+        F: FnMut(Pin<&mut QSyntaxHighlighter>, &QString) + 'static,
+    {
+        fn func(
+            handler: &mut QSyntaxHighlighterHandler,
+            highlighter: Pin<&mut QSyntaxHighlighter>,
+            text: &QString,
+        ) {
+            (handler.inner)(highlighter, text)
+        }
+        let arg = QSyntaxHighlighterHandler {
+            inner: Box::new(highlighter),
+        };
+        ffi::qsyntaxhighlighter_new(document, func, Box::new(arg))
+    }
+}
+
+#[doc(hidden)]
+pub struct QSyntaxHighlighterHandler {
+    inner: HighlightBlockFn,
+}
+
+type HighlightBlockFn = Box<dyn FnMut(Pin<&mut QSyntaxHighlighter>, &QString)>;
@@ -0,0 +1,236 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the different ways drawing underlined text.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QTextCharFormatUnderlineStyle {
+        /// Text is drawn without any underlining decoration.
+        NoUnderline,
+        /// A line is drawn using Qt::SolidLine.
+        SingleUnderline,
+        /// Dashes are drawn using Qt::DashLine.
+        DashUnderline,
+        /// Dots are drawn using Qt::DotLine.
+        DotLine,
+        /// Dashes and dots are drawn using Qt::DashDotLine.
+        DashDotLine,
+        /// Underlines are drawn using Qt::DashDotDotLine.
+        DashDotDotLine,
+        /// The text is underlined using a wave shaped line.
+        WaveUnderline,
+        /// The underline is drawn depending on the SpellCheckUnderlineStyle theme hint of QPlatformTheme.
+        /// By default this is mapped to WaveUnderline, on macOS it is mapped to DotLine.
+        SpellCheckUnderline,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qtextcharformat.h");
+        type QTextCharFormat = super::QTextCharFormat;
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = cxx_qt_lib::QColor;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        /// Clears the brush used to paint the background of the text of the format.
+        #[rust_name = "clear_background"]
+        fn clearBackground(self: &mut QTextCharFormat);
+
+        /// Clears the brush used to paint the text of the format.
+        #[rust_name = "clear_foreground"]
+        fn clearForeground(self: &mut QTextCharFormat);
+
+        /// Returns the text format's font family.
+        #[rust_name = "font_family"]
+        fn fontFamily(self: &QTextCharFormat) -> QString;
+
+        /// Returns true if the text format's font is italic; otherwise returns false.
+        #[rust_name = "font_italic"]
+        fn fontItalic(self: &QTextCharFormat) -> bool;
+
+        /// Returns the font size used to display text in this format.
+        #[rust_name = "font_point_size"]
+        fn fontPointSize(self: &QTextCharFormat) -> f64;
+
+        /// Returns true if the text format's font is struck out (has a horizontal line drawn through it); otherwise returns false.
+        #[rust_name = "font_strike_out"]
+        fn fontStrikeOut(self: &QTextCharFormat) -> bool;
+
+        /// Returns true if the text format's font is underlined; otherwise returns false.
+        #[rust_name = "font_underline"]
+        fn fontUnderline(self: &QTextCharFormat) -> bool;
+
+        /// Returns the text format's font weight.
+        #[rust_name = "font_weight"]
+        fn fontWeight(self: &QTextCharFormat) -> i32;
+
+        /// Returns true if this character format is valid; otherwise returns false.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QTextCharFormat) -> bool;
+
+        /// Sets the text format's font family.
+        #[rust_name = "set_font_family"]
+        fn setFontFamily(self: &mut QTextCharFormat, family: &QString);
+
+        /// If italic is true, sets the text format's font to be italic; otherwise the font will be non-italic.
+        #[rust_name = "set_font_italic"]
+        fn setFontItalic(self: &mut QTextCharFormat, italic: bool);
+
+        /// Sets the text format's font size.
+        #[rust_name = "set_font_point_size"]
+        fn setFontPointSize(self: &mut QTextCharFormat, size: f64);
+
+        /// If strikeOut is true, sets the text format's font with strike-out enabled (with a horizontal line through it);
+        /// otherwise it is displayed without strikeout.
+        #[rust_name = "set_font_strike_out"]
+        fn setFontStrikeOut(self: &mut QTextCharFormat, strike_out: bool);
+
+        /// If underline is true, sets the text format's font to be underlined; otherwise it is displayed non-underlined.
+        #[rust_name = "set_font_underline"]
+        fn setFontUnderline(self: &mut QTextCharFormat, underline: bool);
+
+        /// Sets the text format's font weight to weight.
+        #[rust_name = "set_font_weight"]
+        fn setFontWeight(self: &mut QTextCharFormat, weight: i32);
+
+        /// Sets the tool tip for a fragment of text to the given text.
+        #[rust_name = "set_tool_tip"]
+        fn setToolTip(self: &mut QTextCharFormat, text: &QString);
+
+        /// Sets the underline color used for the characters with this format to the color specified.
+        #[rust_name = "set_underline_color"]
+        fn setUnderlineColor(self: &mut QTextCharFormat, color: &QColor);
+
+        /// Sets the style of underlining the text to style.
+        #[rust_name = "set_underline_style"]
+        fn setUnderlineStyle(self: &mut QTextCharFormat, style: QTextCharFormatUnderlineStyle);
+
+        /// Returns the tool tip that is displayed for a fragment of text.
+        #[rust_name = "tool_tip"]
+        fn toolTip(self: &QTextCharFormat) -> QString;
+
+        /// Returns the color used to underline the characters with this format.
+        #[rust_name = "underline_color"]
+        fn underlineColor(self: &QTextCharFormat) -> QColor;
+
+        /// Returns the style of underlining the text.
+        #[rust_name = "underline_style"]
+        fn underlineStyle(self: &QTextCharFormat) -> QTextCharFormatUnderlineStyle;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+        type QTextCharFormatUnderlineStyle;
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcharformat_drop"]
+        fn drop(format: &mut QTextCharFormat);
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcharformat_init_default"]
+        fn construct() -> QTextCharFormat;
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcharformat_clone"]
+        fn construct(format: &QTextCharFormat) -> QTextCharFormat;
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcharformat_eq"]
+        fn operatorEq(a: &QTextCharFormat, b: &QTextCharFormat) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcharformat_background_color"]
+        fn qtextcharformatBackgroundColor(format: &QTextCharFormat) -> QColor;
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcharformat_foreground_color"]
+        fn qtextcharformatForegroundColor(format: &QTextCharFormat) -> QColor;
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcharformat_set_background_color"]
+        fn qtextcharformatSetBackgroundColor(format: &mut QTextCharFormat, color: &QColor);
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcharformat_set_foreground_color"]
+        fn qtextcharformatSetForegroundColor(format: &mut QTextCharFormat, color: &QColor);
+    }
+}
+
+use cxx_qt_lib::QColor;
+
+pub use ffi::QTextCharFormatUnderlineStyle;
+
+/// The QTextCharFormat class provides formatting information for characters in a QTextDocument.
+#[repr(C)]
+pub struct QTextCharFormat {
+    _d: MaybeUninit<usize>,
+    _format_type: MaybeUninit<i32>,
+}
+
+impl Clone for QTextCharFormat {
+    /// Constructs a copy of other.
+    fn clone(&self) -> Self {
+        ffi::qtextcharformat_clone(self)
+    }
+}
+
+impl Default for QTextCharFormat {
+    /// Constructs a new character format object.
+    fn default() -> Self {
+        ffi::qtextcharformat_init_default()
+    }
+}
+
+impl Drop for QTextCharFormat {
+    /// Destroys the character format.
+    fn drop(&mut self) {
+        ffi::qtextcharformat_drop(self)
+    }
+}
+
+impl PartialEq for QTextCharFormat {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qtextcharformat_eq(self, other)
+    }
+}
+
+impl Eq for QTextCharFormat {}
+
+impl QTextCharFormat {
+    /// Returns the color of the brush used to paint the background of the text of the format.
+    pub fn background_color(&self) -> QColor {
+        ffi::qtextcharformat_background_color(self)
+    }
+
+    /// Sets the background brush to a solid brush of the specified color, for example to highlight search results.
+    pub fn set_background_color(&mut self, color: &QColor) {
+        ffi::qtextcharformat_set_background_color(self, color)
+    }
+
+    /// Returns the color of the brush used to paint the text of the format.
+    pub fn foreground_color(&self) -> QColor {
+        ffi::qtextcharformat_foreground_color(self)
+    }
+
+    /// Sets the foreground brush to a solid brush of the specified color.
+    pub fn set_foreground_color(&mut self, color: &QColor) {
+        ffi::qtextcharformat_set_foreground_color(self, color)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QTextCharFormat {
+    type Id = type_id!("QTextCharFormat");
+    type Kind = cxx::kind::Trivial;
+}
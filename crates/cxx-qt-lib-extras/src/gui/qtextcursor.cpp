@@ -0,0 +1,35 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qtextcursor.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+#include <cstdint>
+
+// QTextCursor has 1 pointer
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/text/qtextcursor.h?h=v5.15.6-lts-lgpl
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/text/qtextcursor.h?h=v6.2.4
+assert_alignment_and_size(QTextCursor, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QTextCursor>::value);
+static_assert(!::std::is_trivially_copy_constructible<QTextCursor>::value);
+
+static_assert(!::std::is_trivially_destructible<QTextCursor>::value);
+static_assert(QTypeInfo<QTextCursor>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QTextCursor
+qtextcursorInitFromQTextDocument(QTextDocument& document)
+{
+  return QTextCursor(&document);
+}
+
+}
+}
@@ -0,0 +1,289 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes whether the anchor moves with the cursor.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QTextCursorMoveMode {
+        /// Moves the anchor to the same position as the cursor itself.
+        MoveAnchor,
+        /// Keeps the anchor where it is.
+        KeepAnchor,
+    }
+
+    /// This enum describes the operations that can be performed when moving the cursor.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QTextCursorMoveOperation {
+        /// Keep the cursor where it is
+        NoMove,
+        /// Move to the start of the document.
+        Start,
+        /// Move up one line.
+        Up,
+        /// Move to the start of the current line.
+        StartOfLine,
+        /// Move to the start of the current block.
+        StartOfBlock,
+        /// Move to the start of the current word.
+        StartOfWord,
+        /// Move to the start of the previous block.
+        PreviousBlock,
+        /// Move to the previous character.
+        PreviousCharacter,
+        /// Move to the beginning of the previous word.
+        PreviousWord,
+        /// Move left one character.
+        Left,
+        /// Move left one word.
+        WordLeft,
+        /// Move to the end of the document.
+        End,
+        /// Move down one line.
+        Down,
+        /// Move to the end of the current line.
+        EndOfLine,
+        /// Move to the end of the current word.
+        EndOfWord,
+        /// Move to the end of the current block.
+        EndOfBlock,
+        /// Move to the beginning of the next block.
+        NextBlock,
+        /// Move to the next character.
+        NextCharacter,
+        /// Move to the next word.
+        NextWord,
+        /// Move right one character.
+        Right,
+        /// Move right one word.
+        WordRight,
+        /// Move to the beginning of the next table cell inside the current table.
+        NextCell,
+        /// Move to the beginning of the previous table cell inside the current table.
+        PreviousCell,
+        /// Move to the first new cell of the next row in the current table.
+        NextRow,
+        /// Move to the last cell of the previous row in the current table.
+        PreviousRow,
+    }
+
+    /// This enum describes the types of selection that can be applied with the select() function.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QTextCursorSelectionType {
+        /// Selects the word under the cursor.
+        WordUnderCursor,
+        /// Selects the line of text under the cursor.
+        LineUnderCursor,
+        /// Selects the block of text under the cursor.
+        BlockUnderCursor,
+        /// Selects the entire document.
+        Document,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qtextcursor.h");
+        type QTextCursor = super::QTextCursor;
+        include!("cxx-qt-lib-extras/qtextcharformat.h");
+        type QTextCharFormat = crate::QTextCharFormat;
+        include!("cxx-qt-lib-extras/qtextdocument.h");
+        type QTextDocument = crate::QTextDocument;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        /// Returns the anchor position; this is the same as position() unless there is a selection
+        /// in which case position() marks one end of the selection and anchor() marks the other end.
+        fn anchor(self: &QTextCursor) -> i32;
+
+        /// Returns true if the cursor is at the end of the document; otherwise returns false.
+        #[rust_name = "at_end"]
+        fn atEnd(self: &QTextCursor) -> bool;
+
+        /// Returns true if the cursor is at the start of the document; otherwise returns false.
+        #[rust_name = "at_start"]
+        fn atStart(self: &QTextCursor) -> bool;
+
+        /// Indicates the start of a block of editing operations on the document
+        /// that should appear as a single operation from an undo/redo point of view.
+        #[rust_name = "begin_edit_block"]
+        fn beginEditBlock(self: &mut QTextCursor);
+
+        /// Returns the format of the character immediately before the cursor position().
+        #[rust_name = "char_format"]
+        fn charFormat(self: &QTextCursor) -> QTextCharFormat;
+
+        /// Clears the current selection by setting the anchor to the cursor position.
+        #[rust_name = "clear_selection"]
+        fn clearSelection(self: &mut QTextCursor);
+
+        /// Returns the document this cursor is associated with.
+        fn document(self: &QTextCursor) -> *mut QTextDocument;
+
+        /// Indicates the end of a block of editing operations on the document
+        /// that should appear as a single operation from an undo/redo point of view.
+        #[rust_name = "end_edit_block"]
+        fn endEditBlock(self: &mut QTextCursor);
+
+        /// Returns true if the cursor contains a selection; otherwise returns false.
+        #[rust_name = "has_selection"]
+        fn hasSelection(self: &QTextCursor) -> bool;
+
+        /// Inserts the text html at the current position, which is interpreted as HTML-formatted text.
+        #[rust_name = "insert_html"]
+        fn insertHtml(self: &mut QTextCursor, html: &QString);
+
+        /// Inserts text at the current position, using the current character format.
+        #[rust_name = "insert_text"]
+        fn insertText(self: &mut QTextCursor, text: &QString);
+
+        /// Inserts text at the current position with the given format.
+        #[cxx_name = "insertText"]
+        #[rust_name = "insert_text_with_format"]
+        fn insertText(self: &mut QTextCursor, text: &QString, format: &QTextCharFormat);
+
+        /// Returns true if the cursor is null; otherwise returns false.
+        /// A null cursor is created by the default constructor.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QTextCursor) -> bool;
+
+        /// Merges the cursor's current character format with the properties described by format modifier.
+        /// If the cursor has a selection, this function applies all the properties set in modifier to all the character formats
+        /// that are part of the selection.
+        #[rust_name = "merge_char_format"]
+        fn mergeCharFormat(self: &mut QTextCursor, modifier: &QTextCharFormat);
+
+        /// Moves the cursor by performing the given operation n times, using the specified mode,
+        /// and returns true if all operations were completed successfully; otherwise returns false.
+        #[rust_name = "move_position"]
+        fn movePosition(
+            self: &mut QTextCursor,
+            operation: QTextCursorMoveOperation,
+            mode: QTextCursorMoveMode,
+            n: i32,
+        ) -> bool;
+
+        /// Returns the absolute position of the cursor within the document.
+        fn position(self: &QTextCursor) -> i32;
+
+        /// If there is a selection, its content is deleted; otherwise does nothing.
+        #[rust_name = "remove_selected_text"]
+        fn removeSelectedText(self: &mut QTextCursor);
+
+        /// Selects text in the document according to the given selection.
+        fn select(self: &mut QTextCursor, selection: QTextCursorSelectionType);
+
+        /// Returns the current selection's text (which may be empty).
+        #[rust_name = "selected_text"]
+        fn selectedText(self: &QTextCursor) -> QString;
+
+        /// Returns the end of the selection or position() if the cursor doesn't have a selection.
+        #[rust_name = "selection_end"]
+        fn selectionEnd(self: &QTextCursor) -> i32;
+
+        /// Returns the start of the selection or position() if the cursor doesn't have a selection.
+        #[rust_name = "selection_start"]
+        fn selectionStart(self: &QTextCursor) -> i32;
+
+        /// Sets the cursor's current character format to the given format.
+        /// If the cursor has a selection, the given format is applied to the current selection.
+        #[rust_name = "set_char_format"]
+        fn setCharFormat(self: &mut QTextCursor, format: &QTextCharFormat);
+
+        /// Moves the cursor to the absolute position in the document specified by pos using a MoveMode specified by mode.
+        #[rust_name = "set_position"]
+        fn setPosition(self: &mut QTextCursor, pos: i32, mode: QTextCursorMoveMode);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+        type QTextCursorMoveMode;
+        type QTextCursorMoveOperation;
+        type QTextCursorSelectionType;
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcursor_drop"]
+        fn drop(cursor: &mut QTextCursor);
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcursor_init_default"]
+        fn construct() -> QTextCursor;
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcursor_clone"]
+        fn construct(cursor: &QTextCursor) -> QTextCursor;
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcursor_eq"]
+        fn operatorEq(a: &QTextCursor, b: &QTextCursor) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qtextcursor_init_from_qtextdocument"]
+        fn qtextcursorInitFromQTextDocument(document: Pin<&mut QTextDocument>) -> QTextCursor;
+    }
+}
+
+use crate::QTextDocument;
+use core::pin::Pin;
+
+pub use ffi::{QTextCursorMoveMode, QTextCursorMoveOperation, QTextCursorSelectionType};
+
+/// The QTextCursor class offers an API to access and modify QTextDocuments.
+#[repr(C)]
+pub struct QTextCursor {
+    _d: MaybeUninit<usize>,
+}
+
+impl Clone for QTextCursor {
+    /// Constructs a new cursor that is a copy of cursor.
+    fn clone(&self) -> Self {
+        ffi::qtextcursor_clone(self)
+    }
+}
+
+impl Default for QTextCursor {
+    /// Constructs a null cursor.
+    fn default() -> Self {
+        ffi::qtextcursor_init_default()
+    }
+}
+
+impl Drop for QTextCursor {
+    /// Destroys the QTextCursor.
+    fn drop(&mut self) {
+        ffi::qtextcursor_drop(self)
+    }
+}
+
+impl From<Pin<&mut QTextDocument>> for QTextCursor {
+    /// Constructs a cursor pointing to the beginning of the document.
+    fn from(document: Pin<&mut QTextDocument>) -> Self {
+        ffi::qtextcursor_init_from_qtextdocument(document)
+    }
+}
+
+impl PartialEq for QTextCursor {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qtextcursor_eq(self, other)
+    }
+}
+
+impl Eq for QTextCursor {}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QTextCursor {
+    type Id = type_id!("QTextCursor");
+    type Kind = cxx::kind::Trivial;
+}
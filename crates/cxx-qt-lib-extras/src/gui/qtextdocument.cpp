@@ -0,0 +1,49 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qtextdocument.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QTextCursor
+qtextdocumentFind(const QTextDocument& document,
+                  const QString& subString,
+                  const QTextCursor& cursor,
+                  QTextDocumentFindFlag options)
+{
+  // The options are flags, so combinations of the enum values can be passed
+  return document.find(
+    subString, cursor, QTextDocument::FindFlags(static_cast<int>(options)));
+}
+
+::std::unique_ptr<QTextDocument>
+qtextdocumentNew(const QString& text)
+{
+  return ::std::make_unique<QTextDocument>(text);
+}
+
+void
+qtextdocumentSetMarkdown(QTextDocument& document, const QString& markdown)
+{
+  document.setMarkdown(markdown);
+}
+
+QString
+qtextdocumentToHtml(const QTextDocument& document)
+{
+  return document.toHtml();
+}
+
+QString
+qtextdocumentToMarkdown(const QTextDocument& document)
+{
+  return document.toMarkdown();
+}
+
+}
+}
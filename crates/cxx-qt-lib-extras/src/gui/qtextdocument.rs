@@ -0,0 +1,186 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// This enum describes the options available to the find function of QTextDocument.
+    ///
+    /// The options are flags, so the values can be combined.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QTextDocumentFindFlag {
+        /// Search backwards instead of forwards.
+        FindBackward = 0x00001,
+        /// By default find works case insensitive.
+        /// Specifying this option changes the behaviour to a case sensitive find operation.
+        FindCaseSensitively = 0x00002,
+        /// Makes find match only complete words.
+        FindWholeWords = 0x00004,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qtextdocument.h");
+        /// The QTextDocument class holds formatted text, which can be edited with a [QTextCursor](crate::QTextCursor).
+        #[qobject]
+        type QTextDocument;
+
+        /// This signal is emitted whenever the document's content changes; for example,
+        /// when text is inserted or deleted, or when formatting is applied.
+        ///
+        /// Information is provided about the position of the character in the document where the change occurred,
+        /// the number of characters removed, and the number of characters added.
+        #[qsignal]
+        #[rust_name = "contents_change"]
+        fn contentsChange(
+            self: Pin<&mut QTextDocument>,
+            position: i32,
+            chars_removed: i32,
+            chars_added: i32,
+        );
+
+        /// This signal is emitted whenever the document's content changes; for example,
+        /// when text is inserted or deleted, or when formatting is applied.
+        #[qsignal]
+        #[rust_name = "contents_changed"]
+        fn contentsChanged(self: Pin<&mut QTextDocument>);
+
+        /// This signal is emitted whenever the content of the document changes in a way that affects the modification state.
+        #[qsignal]
+        #[rust_name = "modification_changed"]
+        fn modificationChanged(self: Pin<&mut QTextDocument>, changed: bool);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib-extras/qtextcursor.h");
+        type QTextCursor = crate::QTextCursor;
+
+        /// Returns the number of text blocks in the document.
+        #[rust_name = "block_count"]
+        fn blockCount(self: &QTextDocument) -> i32;
+
+        /// Returns the number of characters of this document.
+        #[rust_name = "character_count"]
+        fn characterCount(self: &QTextDocument) -> i32;
+
+        /// Clears the document.
+        fn clear(self: Pin<&mut QTextDocument>);
+
+        /// Returns true if the document is empty; otherwise returns false.
+        #[rust_name = "is_empty"]
+        fn isEmpty(self: &QTextDocument) -> bool;
+
+        /// Returns true if the document has been modified since it was last marked as unmodified.
+        #[rust_name = "is_modified"]
+        fn isModified(self: &QTextDocument) -> bool;
+
+        /// Replaces the entire contents of the document with the given HTML-formatted text in the html string.
+        /// The undo/redo history is reset when this function is called.
+        #[rust_name = "set_html"]
+        fn setHtml(self: Pin<&mut QTextDocument>, html: &QString);
+
+        /// Sets the document's modification state to the given value.
+        #[rust_name = "set_modified"]
+        fn setModified(self: Pin<&mut QTextDocument>, modified: bool);
+
+        /// Replaces the entire contents of the document with the given plain text.
+        /// The undo/redo history is reset when this function is called.
+        #[rust_name = "set_plain_text"]
+        fn setPlainText(self: Pin<&mut QTextDocument>, text: &QString);
+
+        /// Returns the plain text contained in the document.
+        /// If you want formatting information use a [QTextCursor](crate::QTextCursor) instead.
+        #[rust_name = "to_plain_text"]
+        fn toPlainText(self: &QTextDocument) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QTextDocumentFindFlag;
+
+        #[doc(hidden)]
+        #[rust_name = "qtextdocument_find"]
+        fn qtextdocumentFind(
+            document: &QTextDocument,
+            sub_string: &QString,
+            cursor: &QTextCursor,
+            options: QTextDocumentFindFlag,
+        ) -> QTextCursor;
+
+        #[doc(hidden)]
+        #[rust_name = "qtextdocument_new"]
+        fn qtextdocumentNew(text: &QString) -> UniquePtr<QTextDocument>;
+
+        #[doc(hidden)]
+        #[rust_name = "qtextdocument_set_markdown"]
+        fn qtextdocumentSetMarkdown(document: Pin<&mut QTextDocument>, markdown: &QString);
+
+        #[doc(hidden)]
+        #[rust_name = "qtextdocument_to_html"]
+        fn qtextdocumentToHtml(document: &QTextDocument) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "qtextdocument_to_markdown"]
+        fn qtextdocumentToMarkdown(document: &QTextDocument) -> QString;
+    }
+
+    // QTextDocument is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QTextDocument>::isRelocatable);
+    impl UniquePtr<QTextDocument> {}
+}
+
+use crate::QTextCursor;
+use core::pin::Pin;
+use cxx_qt_lib::QString;
+
+pub use ffi::{QTextDocument, QTextDocumentFindFlag};
+
+impl QTextDocument {
+    /// Create a new QTextDocument containing the given plain text
+    pub fn new(text: &QString) -> cxx::UniquePtr<Self> {
+        ffi::qtextdocument_new(text)
+    }
+
+    /// Finds the next occurrence of the string, sub_string, in the document, starting at the position of the cursor.
+    ///
+    /// If the cursor has a selection the search begins after the selection.
+    /// Returns a cursor with the match selected, or a null cursor if the string was not found,
+    /// so that the match can be replaced with [QTextCursor::insert_text].
+    pub fn find(&self, sub_string: &QString, cursor: &QTextCursor) -> QTextCursor {
+        ffi::qtextdocument_find(self, sub_string, cursor, QTextDocumentFindFlag { repr: 0 })
+    }
+
+    /// Finds the next occurrence of the string, sub_string, in the document with the given options,
+    /// see [QTextDocument::find].
+    pub fn find_with_options(
+        &self,
+        sub_string: &QString,
+        cursor: &QTextCursor,
+        options: QTextDocumentFindFlag,
+    ) -> QTextCursor {
+        ffi::qtextdocument_find(self, sub_string, cursor, options)
+    }
+
+    /// Replaces the entire contents of the document with the given Markdown-formatted text,
+    /// using the GitHub dialect. The undo/redo history is reset when this function is called.
+    pub fn set_markdown(self: Pin<&mut Self>, markdown: &QString) {
+        ffi::qtextdocument_set_markdown(self, markdown)
+    }
+
+    /// Returns a string containing an HTML representation of the document.
+    pub fn to_html(&self) -> QString {
+        ffi::qtextdocument_to_html(self)
+    }
+
+    /// Returns a string containing a Markdown representation of the document using the GitHub dialect,
+    /// or an empty string if writing fails for any reason.
+    pub fn to_markdown(&self) -> QString {
+        ffi::qtextdocument_to_markdown(self)
+    }
+}
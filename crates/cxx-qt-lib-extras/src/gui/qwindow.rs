@@ -0,0 +1,49 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qwindow.h");
+        /// The QWindow class represents a window in the underlying windowing system.
+        ///
+        /// A QML `Window` or `ApplicationWindow` is a QQuickWindow, which is a QWindow.
+        #[qobject]
+        type QWindow;
+    }
+
+    unsafe extern "C++" {
+        /// Returns the height of the window.
+        fn height(self: &QWindow) -> i32;
+
+        /// Hides the window.
+        fn hide(self: Pin<&mut QWindow>);
+
+        /// Returns if this window is exposed in the windowing system.
+        #[rust_name = "is_exposed"]
+        fn isExposed(self: &QWindow) -> bool;
+
+        /// Returns true if the window is visible; otherwise returns false.
+        #[rust_name = "is_visible"]
+        fn isVisible(self: &QWindow) -> bool;
+
+        /// Requests the window to be activated, i.e. receive keyboard focus.
+        #[rust_name = "request_activate"]
+        fn requestActivate(self: Pin<&mut QWindow>);
+
+        /// Shows the window.
+        fn show(self: Pin<&mut QWindow>);
+
+        /// Returns the width of the window.
+        fn width(self: &QWindow) -> i32;
+    }
+
+    // QWindow is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QWindow>::isRelocatable);
+}
+
+pub use ffi::QWindow;
@@ -11,3 +11,77 @@ pub use crate::core::*;
 
 mod gui;
 pub use crate::gui::*;
+
+#[cfg(feature = "qt_bluetooth")]
+mod bluetooth;
+#[cfg(feature = "qt_bluetooth")]
+pub use crate::bluetooth::*;
+
+#[cfg(feature = "qt_remoteobjects")]
+mod remoteobjects;
+#[cfg(feature = "qt_remoteobjects")]
+pub use crate::remoteobjects::*;
+
+#[cfg(feature = "qt_quick")]
+mod quick;
+#[cfg(feature = "qt_quick")]
+pub use crate::quick::*;
+
+#[cfg(all(feature = "qt_multimedia", cxxqt_qt_version_major = "5"))]
+compile_error!("the qt_multimedia feature of cxx-qt-lib-extras requires Qt 6");
+#[cfg(feature = "qt_multimedia")]
+mod multimedia;
+#[cfg(feature = "qt_multimedia")]
+pub use crate::multimedia::*;
+
+#[cfg(all(
+    feature = "qt_network",
+    not(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_2))
+))]
+compile_error!("the qt_network feature of cxx-qt-lib-extras requires Qt 6.2 or later");
+#[cfg(feature = "qt_network")]
+mod network;
+#[cfg(feature = "qt_network")]
+pub use crate::network::*;
+
+#[cfg(feature = "qt_positioning")]
+mod positioning;
+#[cfg(feature = "qt_positioning")]
+pub use crate::positioning::*;
+
+#[cfg(feature = "qt_serialport")]
+mod serialport;
+#[cfg(feature = "qt_serialport")]
+pub use crate::serialport::*;
+
+#[cfg(feature = "qt_websockets")]
+mod websockets;
+#[cfg(feature = "qt_websockets")]
+pub use crate::websockets::*;
+
+#[cfg(feature = "qt_dbus")]
+mod dbus;
+#[cfg(feature = "qt_dbus")]
+pub use crate::dbus::*;
+
+#[cfg(feature = "qt_printsupport")]
+mod printsupport;
+#[cfg(feature = "qt_printsupport")]
+pub use crate::printsupport::*;
+
+#[cfg(all(feature = "qt_statemachine", cxxqt_qt_version_major = "5"))]
+compile_error!("the qt_statemachine feature of cxx-qt-lib-extras requires Qt 6");
+#[cfg(feature = "qt_statemachine")]
+mod statemachine;
+#[cfg(feature = "qt_statemachine")]
+pub use crate::statemachine::*;
+
+#[cfg(feature = "qt_test")]
+mod testlib;
+#[cfg(feature = "qt_test")]
+pub use crate::testlib::*;
+
+#[cfg(feature = "qt_quicktest")]
+mod quicktest;
+#[cfg(feature = "qt_quicktest")]
+pub use crate::quicktest::*;
@@ -0,0 +1,30 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qaudio;
+pub use qaudio::{QAudioError, QAudioState};
+
+mod qaudiodevice;
+pub use qaudiodevice::{QAudioDevice, QAudioDeviceMode};
+
+mod qaudioformat;
+pub use qaudioformat::{QAudioFormat, QAudioFormatSampleFormat};
+
+mod qaudiooutput;
+pub use qaudiooutput::QAudioOutput;
+
+mod qaudiosink;
+pub use qaudiosink::QAudioSink;
+
+mod qaudiosource;
+pub use qaudiosource::QAudioSource;
+
+mod qmediadevices;
+pub use qmediadevices::QMediaDevices;
+
+mod qmediaplayer;
+pub use qmediaplayer::{
+    QMediaPlayer, QMediaPlayerError, QMediaPlayerMediaStatus, QMediaPlayerPlaybackState,
+};
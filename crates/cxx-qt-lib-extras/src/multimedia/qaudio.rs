@@ -0,0 +1,50 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// The error states that an audio sink or source can be in.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QAudioError {
+        /// No errors have occurred.
+        NoError = 0,
+        /// An error occurred opening the audio device.
+        OpenError = 1,
+        /// An error occurred during read/write of audio device.
+        IOError = 2,
+        /// Audio data is not being fed to the audio device at a fast enough rate.
+        UnderrunError = 3,
+        /// A non-recoverable error has occurred, the audio device is not usable at this time.
+        FatalError = 4,
+    }
+
+    /// The states that an audio sink or source can be in.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QAudioState {
+        /// Audio data is being processed.
+        ActiveState = 0,
+        /// The audio stream is in a suspended state. Entered after suspend() is called
+        /// or when another stream takes control of the audio device.
+        SuspendedState = 1,
+        /// The audio device is closed, and is not processing any audio data.
+        StoppedState = 2,
+        /// The QIODevice passed in has no data and audio system's buffer is empty,
+        /// this state is set after start() is called and while no audio data is available to be processed.
+        IdleState = 3,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qaudio.h");
+        type QAudioError;
+        type QAudioState;
+    }
+}
+
+pub use ffi::{QAudioError, QAudioState};
@@ -0,0 +1,21 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qaudiodevice.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+#include <cstdint>
+
+// QAudioDevice has 1 pointer
+// https://code.qt.io/cgit/qt/qtmultimedia.git/tree/src/multimedia/audio/qaudiodevice.h?h=v6.2.4
+assert_alignment_and_size(QAudioDevice, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QAudioDevice>::value);
+static_assert(!::std::is_trivially_copy_constructible<QAudioDevice>::value);
+
+static_assert(!::std::is_trivially_destructible<QAudioDevice>::value);
+static_assert(QTypeInfo<QAudioDevice>::isRelocatable);
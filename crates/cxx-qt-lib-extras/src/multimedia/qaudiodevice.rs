@@ -0,0 +1,149 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// Describes the mode of a QAudioDevice.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QAudioDeviceMode {
+        /// A null device.
+        Null,
+        /// An input device.
+        Input,
+        /// An output device.
+        Output,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qaudiodevice.h");
+        type QAudioDevice = super::QAudioDevice;
+        include!("cxx-qt-lib-extras/qaudioformat.h");
+        type QAudioFormat = crate::QAudioFormat;
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        /// Returns a human readable name of the audio device.
+        fn description(self: &QAudioDevice) -> QString;
+
+        /// Returns an identifier for the audio device.
+        fn id(self: &QAudioDevice) -> QByteArray;
+
+        /// Returns true if this is the default audio device.
+        #[rust_name = "is_default"]
+        fn isDefault(self: &QAudioDevice) -> bool;
+
+        /// Returns true if the device supports the given format.
+        #[rust_name = "is_format_supported"]
+        fn isFormatSupported(self: &QAudioDevice, format: &QAudioFormat) -> bool;
+
+        /// Returns whether this QAudioDevice object holds a valid device definition.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QAudioDevice) -> bool;
+
+        /// Returns the maximum number of supported channel counts.
+        #[rust_name = "maximum_channel_count"]
+        fn maximumChannelCount(self: &QAudioDevice) -> i32;
+
+        /// Returns the maximum supported sample rate in Hertz.
+        #[rust_name = "maximum_sample_rate"]
+        fn maximumSampleRate(self: &QAudioDevice) -> i32;
+
+        /// Returns the minimum number of supported channel counts.
+        #[rust_name = "minimum_channel_count"]
+        fn minimumChannelCount(self: &QAudioDevice) -> i32;
+
+        /// Returns the minimum supported sample rate in Hertz.
+        #[rust_name = "minimum_sample_rate"]
+        fn minimumSampleRate(self: &QAudioDevice) -> i32;
+
+        /// Returns whether this device is an input or output device.
+        fn mode(self: &QAudioDevice) -> QAudioDeviceMode;
+
+        /// Returns the default audio format settings for this device.
+        ///
+        /// These settings are provided by the platform and are likely to be supported by the device.
+        #[rust_name = "preferred_format"]
+        fn preferredFormat(self: &QAudioDevice) -> QAudioFormat;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+        type QAudioDeviceMode;
+
+        #[doc(hidden)]
+        #[rust_name = "qaudiodevice_drop"]
+        fn drop(device: &mut QAudioDevice);
+
+        #[doc(hidden)]
+        #[rust_name = "qaudiodevice_init_default"]
+        fn construct() -> QAudioDevice;
+
+        #[doc(hidden)]
+        #[rust_name = "qaudiodevice_clone"]
+        fn construct(device: &QAudioDevice) -> QAudioDevice;
+
+        #[doc(hidden)]
+        #[rust_name = "qaudiodevice_eq"]
+        fn operatorEq(a: &QAudioDevice, b: &QAudioDevice) -> bool;
+    }
+
+    // Allow for a list of devices to be returned from QMediaDevices
+    impl CxxVector<QAudioDevice> {}
+}
+
+pub use ffi::QAudioDeviceMode;
+
+/// The QAudioDevice class provides an information about audio devices and their functionality.
+///
+/// The available devices are listed with [QMediaDevices](crate::QMediaDevices).
+#[repr(C)]
+pub struct QAudioDevice {
+    _d: MaybeUninit<usize>,
+}
+
+impl Clone for QAudioDevice {
+    /// Constructs a copy of other.
+    fn clone(&self) -> Self {
+        ffi::qaudiodevice_clone(self)
+    }
+}
+
+impl Default for QAudioDevice {
+    /// Constructs a null QAudioDevice object.
+    fn default() -> Self {
+        ffi::qaudiodevice_init_default()
+    }
+}
+
+impl Drop for QAudioDevice {
+    /// Destroy this audio device info.
+    fn drop(&mut self) {
+        ffi::qaudiodevice_drop(self)
+    }
+}
+
+impl PartialEq for QAudioDevice {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qaudiodevice_eq(self, other)
+    }
+}
+
+impl Eq for QAudioDevice {}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QAudioDevice {
+    type Id = type_id!("QAudioDevice");
+    type Kind = cxx::kind::Trivial;
+}
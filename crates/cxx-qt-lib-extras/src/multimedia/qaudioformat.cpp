@@ -0,0 +1,47 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qaudioformat.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+#include <cstdint>
+
+// QAudioFormat has a sample format, channel count, channel config,
+// sample rate and a reserved field
+// https://code.qt.io/cgit/qt/qtmultimedia.git/tree/src/multimedia/audio/qaudioformat.h?h=v6.2.4
+assert_alignment_and_size(QAudioFormat, {
+  ::std::uint16_t a0;
+  ::std::int16_t a1;
+  ::std::uint32_t a2;
+  ::std::int32_t a3;
+  ::std::uint64_t a4;
+});
+
+static_assert(::std::is_trivially_copyable<QAudioFormat>::value,
+              "QAudioFormat must be trivially copyable!");
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::int32_t
+qaudioformatBytesForDuration(const QAudioFormat& format,
+                             ::std::int64_t microseconds)
+{
+  return static_cast<::std::int32_t>(
+    format.bytesForDuration(static_cast<qint64>(microseconds)));
+}
+
+::std::int64_t
+qaudioformatDurationForBytes(const QAudioFormat& format, ::std::int32_t bytes)
+{
+  return static_cast<::std::int64_t>(
+    format.durationForBytes(static_cast<qint32>(bytes)));
+}
+
+}
+}
@@ -0,0 +1,153 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use std::{fmt, mem::MaybeUninit};
+
+#[cxx::bridge]
+mod ffi {
+    /// Holds the format of a single audio sample.
+    #[repr(u16)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QAudioFormatSampleFormat {
+        /// Not set.
+        Unknown,
+        /// Samples are 8 bit unsigned integers.
+        UInt8,
+        /// Samples are 16 bit signed integers.
+        Int16,
+        /// Samples are 32 bit signed integers.
+        Int32,
+        /// Samples are floats.
+        Float,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qaudioformat.h");
+        type QAudioFormat = super::QAudioFormat;
+
+        /// Returns the number of bytes required to represent one frame (a sample in each channel) in this format.
+        #[rust_name = "bytes_per_frame"]
+        fn bytesPerFrame(self: &QAudioFormat) -> i32;
+
+        /// Returns the number of bytes required to represent one sample in this format.
+        #[rust_name = "bytes_per_sample"]
+        fn bytesPerSample(self: &QAudioFormat) -> i32;
+
+        /// Returns the current channel count value.
+        #[rust_name = "channel_count"]
+        fn channelCount(self: &QAudioFormat) -> i32;
+
+        /// Returns true if all of the parameters are valid.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QAudioFormat) -> bool;
+
+        /// Returns the current sample format.
+        #[rust_name = "sample_format"]
+        fn sampleFormat(self: &QAudioFormat) -> QAudioFormatSampleFormat;
+
+        /// Returns the current sample rate in Hertz.
+        #[rust_name = "sample_rate"]
+        fn sampleRate(self: &QAudioFormat) -> i32;
+
+        /// Sets the channel count to channels.
+        #[rust_name = "set_channel_count"]
+        fn setChannelCount(self: &mut QAudioFormat, channels: i32);
+
+        /// Sets the sample format to format.
+        #[rust_name = "set_sample_format"]
+        fn setSampleFormat(self: &mut QAudioFormat, format: QAudioFormatSampleFormat);
+
+        /// Sets the sample rate to sample_rate in Hertz.
+        #[rust_name = "set_sample_rate"]
+        fn setSampleRate(self: &mut QAudioFormat, sample_rate: i32);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+        type QAudioFormatSampleFormat;
+
+        #[doc(hidden)]
+        #[rust_name = "qaudioformat_init_default"]
+        fn construct() -> QAudioFormat;
+
+        #[doc(hidden)]
+        #[rust_name = "qaudioformat_eq"]
+        fn operatorEq(a: &QAudioFormat, b: &QAudioFormat) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qaudioformat_bytes_for_duration"]
+        fn qaudioformatBytesForDuration(format: &QAudioFormat, microseconds: i64) -> i32;
+
+        #[doc(hidden)]
+        #[rust_name = "qaudioformat_duration_for_bytes"]
+        fn qaudioformatDurationForBytes(format: &QAudioFormat, bytes: i32) -> i64;
+    }
+}
+
+pub use ffi::QAudioFormatSampleFormat;
+
+/// The QAudioFormat class stores audio stream parameters,
+/// such as the sample rate, the number of channels and the format of the samples.
+///
+/// A format which is supported by a device can be found with
+/// [QAudioDevice::preferred_format](crate::QAudioDevice::preferred_format)
+/// and checked with [QAudioDevice::is_format_supported](crate::QAudioDevice::is_format_supported).
+#[derive(Clone)]
+#[repr(C)]
+pub struct QAudioFormat {
+    _sample_format: MaybeUninit<u16>,
+    _channel_count: MaybeUninit<i16>,
+    _channel_config: MaybeUninit<u32>,
+    _sample_rate: MaybeUninit<i32>,
+    _reserved: MaybeUninit<u64>,
+}
+
+impl Default for QAudioFormat {
+    /// Construct a new audio format, where all of the parameters are unset.
+    fn default() -> Self {
+        ffi::qaudioformat_init_default()
+    }
+}
+
+impl PartialEq for QAudioFormat {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qaudioformat_eq(self, other)
+    }
+}
+
+impl Eq for QAudioFormat {}
+
+impl fmt::Debug for QAudioFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QAudioFormat")
+            .field("sample_rate", &self.sample_rate())
+            .field("channel_count", &self.channel_count())
+            .field("sample_format", &self.sample_format())
+            .finish()
+    }
+}
+
+impl QAudioFormat {
+    /// Returns the number of bytes required for this audio format for the duration in microseconds.
+    pub fn bytes_for_duration(&self, microseconds: i64) -> i32 {
+        ffi::qaudioformat_bytes_for_duration(self, microseconds)
+    }
+
+    /// Returns the number of microseconds represented by bytes in this format.
+    pub fn duration_for_bytes(&self, bytes: i32) -> i64 {
+        ffi::qaudioformat_duration_for_bytes(self, bytes)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side ensure that QAudioFormat is trivial.
+unsafe impl ExternType for QAudioFormat {
+    type Id = type_id!("QAudioFormat");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,78 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qaudiooutput.h");
+        /// Represents an output channel for audio, which is used by a [QMediaPlayer](crate::QMediaPlayer).
+        #[qobject]
+        type QAudioOutput;
+
+        /// This signal is emitted when the device of the audio output changes.
+        #[qsignal]
+        #[cxx_name = "deviceChanged"]
+        fn device_changed(self: Pin<&mut QAudioOutput>);
+
+        /// This signal is emitted when the muted state of the audio output changes.
+        #[qsignal]
+        #[cxx_name = "mutedChanged"]
+        fn muted_changed(self: Pin<&mut QAudioOutput>, muted: bool);
+
+        /// This signal is emitted when the volume of the audio output changes.
+        #[qsignal]
+        #[cxx_name = "volumeChanged"]
+        fn volume_changed(self: Pin<&mut QAudioOutput>, volume: f32);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qaudiodevice.h");
+        type QAudioDevice = crate::QAudioDevice;
+
+        /// Returns the audio device which the output is sent to.
+        fn device(self: &QAudioOutput) -> QAudioDevice;
+
+        /// Returns true if the audio output is muted.
+        #[rust_name = "is_muted"]
+        fn isMuted(self: &QAudioOutput) -> bool;
+
+        /// Sets the audio device which the output is sent to.
+        #[rust_name = "set_device"]
+        fn setDevice(self: Pin<&mut QAudioOutput>, device: &QAudioDevice);
+
+        /// Mutes or unmutes the audio output.
+        #[rust_name = "set_muted"]
+        fn setMuted(self: Pin<&mut QAudioOutput>, muted: bool);
+
+        /// Sets the volume of the audio output, which ranges from 0.0 (silent) to 1.0 (loudest).
+        #[rust_name = "set_volume"]
+        fn setVolume(self: Pin<&mut QAudioOutput>, volume: f32);
+
+        /// Returns the volume of the audio output, which ranges from 0.0 (silent) to 1.0 (loudest).
+        fn volume(self: &QAudioOutput) -> f32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qaudiooutput_new"]
+        fn qaudiooutputNew() -> UniquePtr<QAudioOutput>;
+    }
+
+    // QAudioOutput is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QAudioOutput>::isRelocatable);
+    impl UniquePtr<QAudioOutput> {}
+}
+
+pub use ffi::QAudioOutput;
+
+impl QAudioOutput {
+    /// Create a new QAudioOutput which uses the default audio output device of the system.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qaudiooutput_new()
+    }
+}
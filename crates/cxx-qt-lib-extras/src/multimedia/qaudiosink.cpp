@@ -0,0 +1,57 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qaudiosink.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QAudioSink>
+qaudiosinkNew(const QAudioDevice& device, const QAudioFormat& format)
+{
+  return ::std::make_unique<QAudioSink>(device, format);
+}
+
+::rust::isize
+qaudiosinkBufferSize(const QAudioSink& sink)
+{
+  return static_cast<::rust::isize>(sink.bufferSize());
+}
+
+::rust::isize
+qaudiosinkBytesFree(const QAudioSink& sink)
+{
+  return static_cast<::rust::isize>(sink.bytesFree());
+}
+
+::std::int64_t
+qaudiosinkElapsedUSecs(const QAudioSink& sink)
+{
+  return static_cast<::std::int64_t>(sink.elapsedUSecs());
+}
+
+::std::int64_t
+qaudiosinkProcessedUSecs(const QAudioSink& sink)
+{
+  return static_cast<::std::int64_t>(sink.processedUSecs());
+}
+
+void
+qaudiosinkSetBufferSize(QAudioSink& sink, ::rust::isize bytes)
+{
+  sink.setBufferSize(static_cast<qsizetype>(bytes));
+}
+
+QIODevice*
+qaudiosinkStart(QAudioSink& sink)
+{
+  // The device is owned by the sink and is valid until it is stopped
+  return sink.start();
+}
+
+}
+}
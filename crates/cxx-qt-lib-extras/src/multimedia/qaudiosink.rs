@@ -0,0 +1,151 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qaudiosink.h");
+        /// The QAudioSink class provides an interface for sending raw audio data to an audio output device.
+        ///
+        /// Audio is written to the [QIODevice](crate::QIODevice) which is returned by [QAudioSink::start].
+        #[qobject]
+        type QAudioSink;
+
+        /// This signal is emitted when the state of the audio sink changes.
+        #[qsignal]
+        #[cxx_name = "stateChanged"]
+        fn state_changed(self: Pin<&mut QAudioSink>, state: QAudioState);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qaudio.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QAudioError = crate::QAudioError;
+        #[namespace = "rust::cxxqtlib1"]
+        type QAudioState = crate::QAudioState;
+
+        include!("cxx-qt-lib-extras/qaudiodevice.h");
+        type QAudioDevice = crate::QAudioDevice;
+        include!("cxx-qt-lib-extras/qaudioformat.h");
+        type QAudioFormat = crate::QAudioFormat;
+        include!("cxx-qt-lib-extras/qiodevice.h");
+        type QIODevice = crate::QIODevice;
+
+        /// Returns the error state.
+        fn error(self: &QAudioSink) -> QAudioError;
+
+        /// Returns the format which is used by the audio sink.
+        fn format(self: &QAudioSink) -> QAudioFormat;
+
+        /// Drops all audio data in the buffers, resets buffers to zero.
+        fn reset(self: Pin<&mut QAudioSink>);
+
+        /// Resumes processing audio data after a suspend().
+        fn resume(self: Pin<&mut QAudioSink>);
+
+        /// Sets the output volume, which ranges from 0.0 (silence) to 1.0 (full volume).
+        #[rust_name = "set_volume"]
+        fn setVolume(self: Pin<&mut QAudioSink>, volume: f64);
+
+        /// Returns the state of audio processing.
+        fn state(self: &QAudioSink) -> QAudioState;
+
+        /// Stops the audio output, detaching from the system resource.
+        fn stop(self: Pin<&mut QAudioSink>);
+
+        /// Stops processing audio data, preserving buffered audio data.
+        fn suspend(self: Pin<&mut QAudioSink>);
+
+        /// Returns the volume between 0.0 and 1.0.
+        fn volume(self: &QAudioSink) -> f64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qaudiosink_new"]
+        fn qaudiosinkNew(device: &QAudioDevice, format: &QAudioFormat) -> UniquePtr<QAudioSink>;
+
+        #[doc(hidden)]
+        #[rust_name = "qaudiosink_buffer_size"]
+        fn qaudiosinkBufferSize(sink: &QAudioSink) -> isize;
+
+        #[doc(hidden)]
+        #[rust_name = "qaudiosink_bytes_free"]
+        fn qaudiosinkBytesFree(sink: &QAudioSink) -> isize;
+
+        #[doc(hidden)]
+        #[rust_name = "qaudiosink_elapsed_usecs"]
+        fn qaudiosinkElapsedUSecs(sink: &QAudioSink) -> i64;
+
+        #[doc(hidden)]
+        #[rust_name = "qaudiosink_processed_usecs"]
+        fn qaudiosinkProcessedUSecs(sink: &QAudioSink) -> i64;
+
+        #[doc(hidden)]
+        #[rust_name = "qaudiosink_set_buffer_size"]
+        fn qaudiosinkSetBufferSize(sink: Pin<&mut QAudioSink>, bytes: isize);
+
+        #[doc(hidden)]
+        #[rust_name = "qaudiosink_start"]
+        fn qaudiosinkStart(sink: Pin<&mut QAudioSink>) -> *mut QIODevice;
+    }
+
+    // QAudioSink is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QAudioSink>::isRelocatable);
+    impl UniquePtr<QAudioSink> {}
+}
+
+use crate::{QAudioDevice, QAudioFormat, QIODevice};
+use core::pin::Pin;
+
+pub use ffi::QAudioSink;
+
+impl QAudioSink {
+    /// Returns the audio buffer size in bytes.
+    pub fn buffer_size(&self) -> isize {
+        ffi::qaudiosink_buffer_size(self)
+    }
+
+    /// Returns the number of free bytes available in the audio buffer,
+    /// which is the amount of data that can be written without blocking.
+    pub fn bytes_free(&self) -> isize {
+        ffi::qaudiosink_bytes_free(self)
+    }
+
+    /// Returns the microseconds since start() was called, including time in the idle and suspended states.
+    pub fn elapsed_usecs(&self) -> i64 {
+        ffi::qaudiosink_elapsed_usecs(self)
+    }
+
+    /// Create a new QAudioSink which sends audio data in the given format to the device.
+    ///
+    /// The format should be supported by the device, see
+    /// [QAudioDevice::is_format_supported](crate::QAudioDevice::is_format_supported).
+    pub fn new(device: &QAudioDevice, format: &QAudioFormat) -> cxx::UniquePtr<Self> {
+        ffi::qaudiosink_new(device, format)
+    }
+
+    /// Returns the amount of audio data processed since start() was called, in microseconds.
+    pub fn processed_usecs(&self) -> i64 {
+        ffi::qaudiosink_processed_usecs(self)
+    }
+
+    /// Sets the audio buffer size in bytes, this must be called before [QAudioSink::start].
+    pub fn set_buffer_size(self: Pin<&mut Self>, bytes: isize) {
+        ffi::qaudiosink_set_buffer_size(self, bytes)
+    }
+
+    /// Starts transferring audio data from the returned device to the audio output,
+    /// audio data is then written to the device with [QIODevice::write](crate::QIODevice::write).
+    ///
+    /// The device is owned by the audio sink and is only valid until the sink is stopped, started again or destroyed.
+    /// A null pointer is returned if the audio output could not be started, see [QAudioSink::error].
+    pub fn start(self: Pin<&mut Self>) -> *mut QIODevice {
+        ffi::qaudiosink_start(self)
+    }
+}
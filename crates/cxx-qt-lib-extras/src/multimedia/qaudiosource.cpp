@@ -0,0 +1,57 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qaudiosource.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QAudioSource>
+qaudiosourceNew(const QAudioDevice& device, const QAudioFormat& format)
+{
+  return ::std::make_unique<QAudioSource>(device, format);
+}
+
+::rust::isize
+qaudiosourceBufferSize(const QAudioSource& source)
+{
+  return static_cast<::rust::isize>(source.bufferSize());
+}
+
+::rust::isize
+qaudiosourceBytesAvailable(const QAudioSource& source)
+{
+  return static_cast<::rust::isize>(source.bytesAvailable());
+}
+
+::std::int64_t
+qaudiosourceElapsedUSecs(const QAudioSource& source)
+{
+  return static_cast<::std::int64_t>(source.elapsedUSecs());
+}
+
+::std::int64_t
+qaudiosourceProcessedUSecs(const QAudioSource& source)
+{
+  return static_cast<::std::int64_t>(source.processedUSecs());
+}
+
+void
+qaudiosourceSetBufferSize(QAudioSource& source, ::rust::isize bytes)
+{
+  source.setBufferSize(static_cast<qsizetype>(bytes));
+}
+
+QIODevice*
+qaudiosourceStart(QAudioSource& source)
+{
+  // The device is owned by the source and is valid until it is stopped
+  return source.start();
+}
+
+}
+}
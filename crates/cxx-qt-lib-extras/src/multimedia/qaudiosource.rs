@@ -0,0 +1,152 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qaudiosource.h");
+        /// The QAudioSource class provides an interface for receiving raw audio data from an audio input device.
+        ///
+        /// Audio is read from the [QIODevice](crate::QIODevice) which is returned by [QAudioSource::start].
+        #[qobject]
+        type QAudioSource;
+
+        /// This signal is emitted when the state of the audio source changes.
+        #[qsignal]
+        #[cxx_name = "stateChanged"]
+        fn state_changed(self: Pin<&mut QAudioSource>, state: QAudioState);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qaudio.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QAudioError = crate::QAudioError;
+        #[namespace = "rust::cxxqtlib1"]
+        type QAudioState = crate::QAudioState;
+
+        include!("cxx-qt-lib-extras/qaudiodevice.h");
+        type QAudioDevice = crate::QAudioDevice;
+        include!("cxx-qt-lib-extras/qaudioformat.h");
+        type QAudioFormat = crate::QAudioFormat;
+        include!("cxx-qt-lib-extras/qiodevice.h");
+        type QIODevice = crate::QIODevice;
+
+        /// Returns the error state.
+        fn error(self: &QAudioSource) -> QAudioError;
+
+        /// Returns the format which is used by the audio source.
+        fn format(self: &QAudioSource) -> QAudioFormat;
+
+        /// Drops all audio data in the buffers, resets buffers to zero.
+        fn reset(self: Pin<&mut QAudioSource>);
+
+        /// Resumes processing audio data after a suspend().
+        fn resume(self: Pin<&mut QAudioSource>);
+
+        /// Sets the input volume, which ranges from 0.0 (silence) to 1.0 (full volume).
+        #[rust_name = "set_volume"]
+        fn setVolume(self: Pin<&mut QAudioSource>, volume: f64);
+
+        /// Returns the state of audio processing.
+        fn state(self: &QAudioSource) -> QAudioState;
+
+        /// Stops the audio input, detaching from the system resource.
+        fn stop(self: Pin<&mut QAudioSource>);
+
+        /// Stops processing audio data, preserving buffered audio data.
+        fn suspend(self: Pin<&mut QAudioSource>);
+
+        /// Returns the volume between 0.0 and 1.0.
+        fn volume(self: &QAudioSource) -> f64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qaudiosource_new"]
+        fn qaudiosourceNew(device: &QAudioDevice, format: &QAudioFormat)
+            -> UniquePtr<QAudioSource>;
+
+        #[doc(hidden)]
+        #[rust_name = "qaudiosource_buffer_size"]
+        fn qaudiosourceBufferSize(source: &QAudioSource) -> isize;
+
+        #[doc(hidden)]
+        #[rust_name = "qaudiosource_bytes_available"]
+        fn qaudiosourceBytesAvailable(source: &QAudioSource) -> isize;
+
+        #[doc(hidden)]
+        #[rust_name = "qaudiosource_elapsed_usecs"]
+        fn qaudiosourceElapsedUSecs(source: &QAudioSource) -> i64;
+
+        #[doc(hidden)]
+        #[rust_name = "qaudiosource_processed_usecs"]
+        fn qaudiosourceProcessedUSecs(source: &QAudioSource) -> i64;
+
+        #[doc(hidden)]
+        #[rust_name = "qaudiosource_set_buffer_size"]
+        fn qaudiosourceSetBufferSize(source: Pin<&mut QAudioSource>, bytes: isize);
+
+        #[doc(hidden)]
+        #[rust_name = "qaudiosource_start"]
+        fn qaudiosourceStart(source: Pin<&mut QAudioSource>) -> *mut QIODevice;
+    }
+
+    // QAudioSource is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QAudioSource>::isRelocatable);
+    impl UniquePtr<QAudioSource> {}
+}
+
+use crate::{QAudioDevice, QAudioFormat, QIODevice};
+use core::pin::Pin;
+
+pub use ffi::QAudioSource;
+
+impl QAudioSource {
+    /// Returns the audio buffer size in bytes.
+    pub fn buffer_size(&self) -> isize {
+        ffi::qaudiosource_buffer_size(self)
+    }
+
+    /// Returns the amount of audio data available to read in bytes.
+    pub fn bytes_available(&self) -> isize {
+        ffi::qaudiosource_bytes_available(self)
+    }
+
+    /// Returns the microseconds since start() was called, including time in the idle and suspended states.
+    pub fn elapsed_usecs(&self) -> i64 {
+        ffi::qaudiosource_elapsed_usecs(self)
+    }
+
+    /// Create a new QAudioSource which receives audio data in the given format from the device.
+    ///
+    /// The format should be supported by the device, see
+    /// [QAudioDevice::is_format_supported](crate::QAudioDevice::is_format_supported).
+    pub fn new(device: &QAudioDevice, format: &QAudioFormat) -> cxx::UniquePtr<Self> {
+        ffi::qaudiosource_new(device, format)
+    }
+
+    /// Returns the amount of audio data processed since start() was called, in microseconds.
+    pub fn processed_usecs(&self) -> i64 {
+        ffi::qaudiosource_processed_usecs(self)
+    }
+
+    /// Sets the audio buffer size in bytes, this must be called before [QAudioSource::start].
+    pub fn set_buffer_size(self: Pin<&mut Self>, bytes: isize) {
+        ffi::qaudiosource_set_buffer_size(self, bytes)
+    }
+
+    /// Starts transferring audio data from the audio input to the returned device,
+    /// the ready_read signal of the device is emitted when audio data can be read with
+    /// [QIODevice::read](crate::QIODevice::read) or [QIODevice::read_all](crate::QIODevice::read_all).
+    ///
+    /// The device is owned by the audio source and is only valid until the source is stopped, started again or destroyed.
+    /// A null pointer is returned if the audio input could not be started, see [QAudioSource::error].
+    pub fn start(self: Pin<&mut Self>) -> *mut QIODevice {
+        ffi::qaudiosource_start(self)
+    }
+}
@@ -0,0 +1,55 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qmediadevices.h"
+
+namespace {
+
+::std::unique_ptr<::std::vector<QAudioDevice>>
+toVector(const QList<QAudioDevice>& devices)
+{
+  return ::std::make_unique<::std::vector<QAudioDevice>>(devices.cbegin(),
+                                                         devices.cend());
+}
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QMediaDevices>
+qmediadevicesNew()
+{
+  return ::std::make_unique<QMediaDevices>();
+}
+
+::std::unique_ptr<::std::vector<QAudioDevice>>
+qmediadevicesAudioInputs()
+{
+  return toVector(QMediaDevices::audioInputs());
+}
+
+::std::unique_ptr<::std::vector<QAudioDevice>>
+qmediadevicesAudioOutputs()
+{
+  return toVector(QMediaDevices::audioOutputs());
+}
+
+QAudioDevice
+qmediadevicesDefaultAudioInput()
+{
+  return QMediaDevices::defaultAudioInput();
+}
+
+QAudioDevice
+qmediadevicesDefaultAudioOutput()
+{
+  return QMediaDevices::defaultAudioOutput();
+}
+
+}
+}
@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qmediadevices.h");
+        /// The QMediaDevices class provides information about the available multimedia input and output devices.
+        ///
+        /// The lists of devices can be queried without an instance,
+        /// an instance is only needed to be notified when the available devices change.
+        #[qobject]
+        type QMediaDevices;
+
+        /// This signal is emitted when the available audio input devices have changed.
+        #[qsignal]
+        #[cxx_name = "audioInputsChanged"]
+        fn audio_inputs_changed(self: Pin<&mut QMediaDevices>);
+
+        /// This signal is emitted when the available audio output devices have changed.
+        #[qsignal]
+        #[cxx_name = "audioOutputsChanged"]
+        fn audio_outputs_changed(self: Pin<&mut QMediaDevices>);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qaudiodevice.h");
+        type QAudioDevice = crate::QAudioDevice;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmediadevices_new"]
+        fn qmediadevicesNew() -> UniquePtr<QMediaDevices>;
+
+        #[doc(hidden)]
+        #[rust_name = "qmediadevices_audio_inputs"]
+        fn qmediadevicesAudioInputs() -> UniquePtr<CxxVector<QAudioDevice>>;
+
+        #[doc(hidden)]
+        #[rust_name = "qmediadevices_audio_outputs"]
+        fn qmediadevicesAudioOutputs() -> UniquePtr<CxxVector<QAudioDevice>>;
+
+        #[doc(hidden)]
+        #[rust_name = "qmediadevices_default_audio_input"]
+        fn qmediadevicesDefaultAudioInput() -> QAudioDevice;
+
+        #[doc(hidden)]
+        #[rust_name = "qmediadevices_default_audio_output"]
+        fn qmediadevicesDefaultAudioOutput() -> QAudioDevice;
+    }
+
+    // QMediaDevices is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QMediaDevices>::isRelocatable);
+    impl UniquePtr<QMediaDevices> {}
+}
+
+use crate::QAudioDevice;
+use cxx::{CxxVector, UniquePtr};
+
+pub use ffi::QMediaDevices;
+
+impl QMediaDevices {
+    /// Returns a list of the audio input devices available on the system.
+    pub fn audio_inputs() -> UniquePtr<CxxVector<QAudioDevice>> {
+        ffi::qmediadevices_audio_inputs()
+    }
+
+    /// Returns a list of the audio output devices available on the system.
+    pub fn audio_outputs() -> UniquePtr<CxxVector<QAudioDevice>> {
+        ffi::qmediadevices_audio_outputs()
+    }
+
+    /// Returns the default audio input device, which may be null if there is no input device.
+    pub fn default_audio_input() -> QAudioDevice {
+        ffi::qmediadevices_default_audio_input()
+    }
+
+    /// Returns the default audio output device, which may be null if there is no output device.
+    pub fn default_audio_output() -> QAudioDevice {
+        ffi::qmediadevices_default_audio_output()
+    }
+
+    /// Create a new QMediaDevices, which is used to connect to the signals for changes of the available devices.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qmediadevices_new()
+    }
+}
@@ -0,0 +1,45 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qmediaplayer.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QMediaPlayer>
+qmediaplayerNew()
+{
+  return ::std::make_unique<QMediaPlayer>();
+}
+
+::std::int64_t
+qmediaplayerDuration(const QMediaPlayer& player)
+{
+  return static_cast<::std::int64_t>(player.duration());
+}
+
+::std::int64_t
+qmediaplayerPosition(const QMediaPlayer& player)
+{
+  return static_cast<::std::int64_t>(player.position());
+}
+
+void
+qmediaplayerSetAudioOutput(QMediaPlayer& player, QAudioOutput* output)
+{
+  // The player does not take ownership of the output
+  player.setAudioOutput(output);
+}
+
+void
+qmediaplayerSetPosition(QMediaPlayer& player, ::std::int64_t position)
+{
+  player.setPosition(static_cast<qint64>(position));
+}
+
+}
+}
@@ -0,0 +1,252 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// Defines the error state of a QMediaPlayer.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QMediaPlayerError {
+        /// No error has occurred.
+        NoError = 0,
+        /// A media resource couldn't be resolved.
+        ResourceError = 1,
+        /// The format of a media resource isn't (fully) supported.
+        /// Playback may still be possible, but without an audio or video component.
+        FormatError = 2,
+        /// A network error occurred.
+        NetworkError = 3,
+        /// There are not the appropriate permissions to play a media resource.
+        AccessDeniedError = 4,
+    }
+
+    /// Defines the status of a QMediaPlayer's current media.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QMediaPlayerMediaStatus {
+        /// There is no current media. The player is in the StoppedState.
+        NoMedia = 0,
+        /// The current media is being loaded. The player may be in any state.
+        LoadingMedia = 1,
+        /// The current media has been loaded. The player is in the StoppedState.
+        LoadedMedia = 2,
+        /// Playback of the current media has stalled due to insufficient buffering or some other temporary interruption.
+        /// The player is in the PlayingState or PausedState.
+        StalledMedia = 3,
+        /// The player is buffering data but has enough data buffered for playback to continue for the immediate future.
+        /// The player is in the PlayingState or PausedState.
+        BufferingMedia = 4,
+        /// The player has fully buffered the current media. The player is in the PlayingState or PausedState.
+        BufferedMedia = 5,
+        /// Playback has reached the end of the current media. The player is in the StoppedState.
+        EndOfMedia = 6,
+        /// The current media cannot be played. The player is in the StoppedState.
+        InvalidMedia = 7,
+    }
+
+    /// Defines the current state of a media player.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QMediaPlayerPlaybackState {
+        /// The media player is not playing content, playback will begin from the start of the current track.
+        StoppedState = 0,
+        /// The media player is currently playing content.
+        PlayingState = 1,
+        /// The media player has paused playback, playback of the current track will resume from the position the player was paused at.
+        PausedState = 2,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qmediaplayer.h");
+        /// The QMediaPlayer class allows the playing of a media file.
+        ///
+        /// The audio is only played once an output has been set with [QMediaPlayer::set_audio_output].
+        #[qobject]
+        type QMediaPlayer;
+
+        /// This signal is emitted when the duration of the media changes, in milliseconds.
+        #[qsignal]
+        #[cxx_name = "durationChanged"]
+        fn duration_changed(self: Pin<&mut QMediaPlayer>, duration: i64);
+
+        /// This signal is emitted when an error occurs, with a description of the error.
+        #[qsignal]
+        #[cxx_name = "errorOccurred"]
+        fn error_occurred(
+            self: Pin<&mut QMediaPlayer>,
+            error: QMediaPlayerError,
+            error_string: &QString,
+        );
+
+        /// This signal is emitted when the status of the current media changes.
+        #[qsignal]
+        #[cxx_name = "mediaStatusChanged"]
+        fn media_status_changed(self: Pin<&mut QMediaPlayer>, status: QMediaPlayerMediaStatus);
+
+        /// This signal is emitted when the state of the player changes.
+        #[qsignal]
+        #[cxx_name = "playbackStateChanged"]
+        fn playback_state_changed(
+            self: Pin<&mut QMediaPlayer>,
+            new_state: QMediaPlayerPlaybackState,
+        );
+
+        /// This signal is emitted when the position of the playback changes, in milliseconds.
+        #[qsignal]
+        #[cxx_name = "positionChanged"]
+        fn position_changed(self: Pin<&mut QMediaPlayer>, position: i64);
+
+        /// This signal is emitted when the source of the player changes.
+        #[qsignal]
+        #[cxx_name = "sourceChanged"]
+        fn source_changed(self: Pin<&mut QMediaPlayer>, media: &QUrl);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = cxx_qt_lib::QUrl;
+
+        include!("cxx-qt-lib-extras/qaudiooutput.h");
+        type QAudioOutput = crate::QAudioOutput;
+
+        /// Returns the audio output which the player plays to, or a null pointer if there is none.
+        #[rust_name = "audio_output"]
+        fn audioOutput(self: &QMediaPlayer) -> *mut QAudioOutput;
+
+        /// Returns the current error state.
+        fn error(self: &QMediaPlayer) -> QMediaPlayerError;
+
+        /// Returns a string describing the current error condition.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QMediaPlayer) -> QString;
+
+        /// Returns true if the media is seekable, so that the position can be changed with [QMediaPlayer::set_position].
+        #[rust_name = "is_seekable"]
+        fn isSeekable(self: &QMediaPlayer) -> bool;
+
+        /// Returns the number of times the media is played, which is -1 when it is played infinitely.
+        fn loops(self: &QMediaPlayer) -> i32;
+
+        /// Returns the status of the current media.
+        #[rust_name = "media_status"]
+        fn mediaStatus(self: &QMediaPlayer) -> QMediaPlayerMediaStatus;
+
+        /// Pause playing the current source.
+        fn pause(self: Pin<&mut QMediaPlayer>);
+
+        /// Start or resume playing the current source.
+        fn play(self: Pin<&mut QMediaPlayer>);
+
+        /// Returns the current state of the player.
+        #[rust_name = "playback_state"]
+        fn playbackState(self: &QMediaPlayer) -> QMediaPlayerPlaybackState;
+
+        /// Returns the playback rate of the current media, where 1.0 is the normal speed.
+        #[rust_name = "playback_rate"]
+        fn playbackRate(self: &QMediaPlayer) -> f64;
+
+        /// Sets the number of times the media is played, -1 plays the media infinitely.
+        #[rust_name = "set_loops"]
+        fn setLoops(self: Pin<&mut QMediaPlayer>, loops: i32);
+
+        /// Sets the playback rate of the current media, where 1.0 is the normal speed.
+        #[rust_name = "set_playback_rate"]
+        fn setPlaybackRate(self: Pin<&mut QMediaPlayer>, rate: f64);
+
+        /// Sets the current source, such as a local file or a remote URL.
+        ///
+        /// Setting the source only starts loading the media, playback is started with [QMediaPlayer::play].
+        #[rust_name = "set_source"]
+        fn setSource(self: Pin<&mut QMediaPlayer>, source: &QUrl);
+
+        /// Returns the current source of the player.
+        fn source(self: &QMediaPlayer) -> QUrl;
+
+        /// Stop playing, and reset the play position to the beginning.
+        fn stop(self: Pin<&mut QMediaPlayer>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QMediaPlayerError;
+        type QMediaPlayerMediaStatus;
+        type QMediaPlayerPlaybackState;
+
+        #[doc(hidden)]
+        #[rust_name = "qmediaplayer_new"]
+        fn qmediaplayerNew() -> UniquePtr<QMediaPlayer>;
+
+        #[doc(hidden)]
+        #[rust_name = "qmediaplayer_duration"]
+        fn qmediaplayerDuration(player: &QMediaPlayer) -> i64;
+
+        #[doc(hidden)]
+        #[rust_name = "qmediaplayer_position"]
+        fn qmediaplayerPosition(player: &QMediaPlayer) -> i64;
+
+        #[doc(hidden)]
+        #[rust_name = "qmediaplayer_set_audio_output"]
+        unsafe fn qmediaplayerSetAudioOutput(
+            player: Pin<&mut QMediaPlayer>,
+            output: *mut QAudioOutput,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qmediaplayer_set_position"]
+        fn qmediaplayerSetPosition(player: Pin<&mut QMediaPlayer>, position: i64);
+    }
+
+    // QMediaPlayer is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QMediaPlayer>::isRelocatable);
+    impl UniquePtr<QMediaPlayer> {}
+}
+
+use crate::QAudioOutput;
+use core::pin::Pin;
+
+pub use ffi::{
+    QMediaPlayer, QMediaPlayerError, QMediaPlayerMediaStatus, QMediaPlayerPlaybackState,
+};
+
+impl QMediaPlayer {
+    /// Returns the duration of the current media in milliseconds, or 0 if there is no media or the duration is not known.
+    pub fn duration(&self) -> i64 {
+        ffi::qmediaplayer_duration(self)
+    }
+
+    /// Create a new QMediaPlayer, the media to play is then set with [QMediaPlayer::set_source].
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qmediaplayer_new()
+    }
+
+    /// Returns the playback position of the current media in milliseconds.
+    pub fn position(&self) -> i64 {
+        ffi::qmediaplayer_position(self)
+    }
+
+    /// Sets the audio output which the player plays to, or a null pointer to disable the audio.
+    ///
+    /// # Safety
+    ///
+    /// The player does not take ownership of the audio output,
+    /// so the output must be valid for as long as it is used by the player.
+    pub unsafe fn set_audio_output(self: Pin<&mut Self>, output: *mut QAudioOutput) {
+        ffi::qmediaplayer_set_audio_output(self, output)
+    }
+
+    /// Seeks to the given playback position of the current media in milliseconds.
+    ///
+    /// This has no effect if the media is not seekable, see [QMediaPlayer::is_seekable].
+    pub fn set_position(self: Pin<&mut Self>, position: i64) {
+        ffi::qmediaplayer_set_position(self, position)
+    }
+}
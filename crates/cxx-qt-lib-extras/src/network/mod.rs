@@ -0,0 +1,30 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qhostaddress;
+pub use qhostaddress::{
+    QAbstractSocketNetworkLayerProtocol, QHostAddress, QHostAddressSpecialAddress,
+};
+
+mod qhostinfo;
+pub use qhostinfo::{QHostInfo, QHostInfoHostInfoError};
+
+mod qnetworkinformation;
+pub use qnetworkinformation::{QNetworkInformation, QNetworkInformationReachability};
+
+#[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_3))]
+pub use qnetworkinformation::QNetworkInformationTransportMedium;
+
+mod qssl;
+pub use qssl::{QSslEncodingFormat, QSslKeyAlgorithm, QSslKeyType};
+
+mod qsslcertificate;
+pub use qsslcertificate::{QSslCertificate, QSslCertificateSubjectInfo};
+
+mod qsslconfiguration;
+pub use qsslconfiguration::{QSslConfiguration, QSslSocketPeerVerifyMode};
+
+mod qsslkey;
+pub use qsslkey::QSslKey;
@@ -0,0 +1,34 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qhostaddress.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+#include <cstdint>
+
+// QHostAddress has 1 pointer
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/network/kernel/qhostaddress.h?h=v6.2.4
+assert_alignment_and_size(QHostAddress, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QHostAddress>::value);
+static_assert(!::std::is_trivially_copy_constructible<QHostAddress>::value);
+
+static_assert(!::std::is_trivially_destructible<QHostAddress>::value);
+static_assert(QTypeInfo<QHostAddress>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::uint32_t
+qhostaddressToIPv4Address(const QHostAddress& address, bool& ok)
+{
+  return address.toIPv4Address(&ok);
+}
+
+}
+}
@@ -0,0 +1,287 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::net::{AddrParseError, IpAddr, Ipv4Addr, Ipv6Addr};
+
+#[cxx::bridge]
+mod ffi {
+    /// Describes the network layer protocol values used in Qt.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QAbstractSocketNetworkLayerProtocol {
+        /// IPv4
+        IPv4Protocol = 0,
+        /// IPv6
+        IPv6Protocol = 1,
+        /// Either IPv4 or IPv6
+        AnyIPProtocol = 2,
+        /// Other than IPv4 and IPv6
+        UnknownNetworkLayerProtocol = -1,
+    }
+
+    /// Describes the special addresses which can be constructed with [QHostAddress::from].
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QHostAddressSpecialAddress {
+        /// The null address object. Equivalent to QHostAddress::default().
+        Null = 0,
+        /// The IPv4 broadcast address. Equivalent to QHostAddress::from("255.255.255.255").
+        Broadcast = 1,
+        /// The IPv4 localhost address. Equivalent to QHostAddress::from("127.0.0.1").
+        LocalHost = 2,
+        /// The IPv6 localhost address. Equivalent to QHostAddress::from("::1").
+        LocalHostIPv6 = 3,
+        /// The dual stack any-address. A socket bound with this address will listen on both IPv4 and IPv6 interfaces.
+        Any = 4,
+        /// The IPv6 any-address. Equivalent to QHostAddress::from("::").
+        AnyIPv6 = 5,
+        /// The IPv4 any-address. Equivalent to QHostAddress::from("0.0.0.0").
+        AnyIPv4 = 6,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qhostaddress.h");
+        type QHostAddress = super::QHostAddress;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        /// Sets the host address to null and sets the protocol to unknown.
+        fn clear(self: &mut QHostAddress);
+
+        /// Returns true if the address is the IPv4 broadcast address, false otherwise.
+        #[rust_name = "is_broadcast"]
+        fn isBroadcast(self: &QHostAddress) -> bool;
+
+        /// Returns true if the address is an IPv4 or IPv6 global address, false otherwise.
+        #[rust_name = "is_global"]
+        fn isGlobal(self: &QHostAddress) -> bool;
+
+        /// Returns true if this IP is in the subnet described by the network prefix subnet and netmask netmask.
+        #[rust_name = "is_in_subnet"]
+        fn isInSubnet(self: &QHostAddress, subnet: &QHostAddress, netmask: i32) -> bool;
+
+        /// Returns true if the address is an IPv4 or IPv6 link-local address, false otherwise.
+        #[rust_name = "is_link_local"]
+        fn isLinkLocal(self: &QHostAddress) -> bool;
+
+        /// Returns true if the address is the IPv6 loopback address, or any of the IPv4 loopback addresses.
+        #[rust_name = "is_loopback"]
+        fn isLoopback(self: &QHostAddress) -> bool;
+
+        /// Returns true if the address is an IPv4 or IPv6 multicast address, false otherwise.
+        #[rust_name = "is_multicast"]
+        fn isMulticast(self: &QHostAddress) -> bool;
+
+        /// Returns true if this host address is not valid for any host or interface.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QHostAddress) -> bool;
+
+        /// Returns true if the address is an IPv6 site-local address, false otherwise.
+        #[rust_name = "is_site_local"]
+        fn isSiteLocal(self: &QHostAddress) -> bool;
+
+        /// Returns true if the address is an IPv6 unique local unicast address, false otherwise.
+        #[rust_name = "is_unique_local_unicast"]
+        fn isUniqueLocalUnicast(self: &QHostAddress) -> bool;
+
+        /// Returns the network layer protocol of the host address.
+        fn protocol(self: &QHostAddress) -> QAbstractSocketNetworkLayerProtocol;
+
+        /// Returns the scope ID of an IPv6 address, or an empty string if the address does not contain a scope ID.
+        #[rust_name = "scope_id"]
+        fn scopeId(self: &QHostAddress) -> QString;
+
+        /// Sets the IPv4 or IPv6 address specified by the string representation specified by address (e.g. "127.0.0.1").
+        /// Returns true and sets the address if the address was successfully parsed.
+        #[rust_name = "set_address"]
+        fn setAddress(self: &mut QHostAddress, address: &QString) -> bool;
+
+        /// Sets the IPv6 scope ID of the address to id.
+        ///
+        /// If the address protocol is not IPv6, this function does nothing.
+        #[rust_name = "set_scope_id"]
+        fn setScopeId(self: &mut QHostAddress, id: &QString);
+
+        /// Returns the address as a string.
+        #[rust_name = "to_qstring"]
+        fn toString(self: &QHostAddress) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QAbstractSocketNetworkLayerProtocol;
+        type QHostAddressSpecialAddress;
+
+        #[doc(hidden)]
+        #[rust_name = "qhostaddress_to_ipv4_address"]
+        fn qhostaddressToIPv4Address(address: &QHostAddress, ok: &mut bool) -> u32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qhostaddress_drop"]
+        fn drop(address: &mut QHostAddress);
+
+        #[doc(hidden)]
+        #[rust_name = "qhostaddress_init_default"]
+        fn construct() -> QHostAddress;
+        #[doc(hidden)]
+        #[rust_name = "qhostaddress_init_from_ipv4"]
+        fn construct(ip4_addr: u32) -> QHostAddress;
+        #[doc(hidden)]
+        #[rust_name = "qhostaddress_init_from_qstring"]
+        fn construct(address: &QString) -> QHostAddress;
+        #[doc(hidden)]
+        #[rust_name = "qhostaddress_init_from_special_address"]
+        fn construct(address: QHostAddressSpecialAddress) -> QHostAddress;
+        #[doc(hidden)]
+        #[rust_name = "qhostaddress_clone"]
+        fn construct(address: &QHostAddress) -> QHostAddress;
+
+        #[doc(hidden)]
+        #[rust_name = "qhostaddress_eq"]
+        fn operatorEq(a: &QHostAddress, b: &QHostAddress) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qhostaddress_to_debug_qstring"]
+        fn toQString(address: &QHostAddress) -> QString;
+    }
+}
+
+use cxx_qt_lib::QString;
+
+pub use ffi::{QAbstractSocketNetworkLayerProtocol, QHostAddressSpecialAddress};
+
+/// The QHostAddress class provides an IP address.
+///
+/// It can be converted to and from the [std::net] address types, so addresses
+/// which are passed to or from Qt, for example in signals, can be used with the Rust standard library.
+#[repr(C)]
+pub struct QHostAddress {
+    _d: MaybeUninit<usize>,
+}
+
+impl QHostAddress {
+    /// Returns the IPv4 address as a number, or None if the protocol is not IPv4
+    /// and the address cannot be converted to IPv4.
+    ///
+    /// For example, if the address is 127.0.0.1, the returned value is 2130706433 (i.e. 0x7f000001).
+    pub fn to_ipv4_address(&self) -> Option<u32> {
+        let mut ok = false;
+        let address = ffi::qhostaddress_to_ipv4_address(self, &mut ok);
+        if ok {
+            Some(address)
+        } else {
+            None
+        }
+    }
+}
+
+impl Clone for QHostAddress {
+    /// Constructs a copy of the given address.
+    fn clone(&self) -> Self {
+        ffi::qhostaddress_clone(self)
+    }
+}
+
+impl Default for QHostAddress {
+    /// Constructs a null host address object, i.e. an address which is not valid for any host or interface.
+    fn default() -> Self {
+        ffi::qhostaddress_init_default()
+    }
+}
+
+impl Drop for QHostAddress {
+    /// Destroys the host address object.
+    fn drop(&mut self) {
+        ffi::qhostaddress_drop(self)
+    }
+}
+
+impl PartialEq for QHostAddress {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qhostaddress_eq(self, other)
+    }
+}
+
+impl Eq for QHostAddress {}
+
+impl fmt::Display for QHostAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_qstring())
+    }
+}
+
+impl fmt::Debug for QHostAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qhostaddress_to_debug_qstring(self))
+    }
+}
+
+impl From<&QString> for QHostAddress {
+    /// Constructs an IPv4 or IPv6 address based on the string address (e.g., "127.0.0.1").
+    ///
+    /// If the address could not be parsed, the address is null, see [QHostAddress::is_null].
+    fn from(address: &QString) -> Self {
+        ffi::qhostaddress_init_from_qstring(address)
+    }
+}
+
+impl From<QHostAddressSpecialAddress> for QHostAddress {
+    /// Constructs a QHostAddress object for the special address.
+    fn from(address: QHostAddressSpecialAddress) -> Self {
+        ffi::qhostaddress_init_from_special_address(address)
+    }
+}
+
+impl From<&Ipv4Addr> for QHostAddress {
+    /// Constructs a host address object with the IPv4 address.
+    fn from(address: &Ipv4Addr) -> Self {
+        ffi::qhostaddress_init_from_ipv4(u32::from(*address))
+    }
+}
+
+impl From<&Ipv6Addr> for QHostAddress {
+    /// Constructs a host address object with the IPv6 address.
+    fn from(address: &Ipv6Addr) -> Self {
+        QHostAddress::from(&QString::from(&address.to_string()))
+    }
+}
+
+impl From<&IpAddr> for QHostAddress {
+    /// Constructs a host address object with the IPv4 or IPv6 address.
+    fn from(address: &IpAddr) -> Self {
+        match address {
+            IpAddr::V4(address) => QHostAddress::from(address),
+            IpAddr::V6(address) => QHostAddress::from(address),
+        }
+    }
+}
+
+impl TryFrom<&QHostAddress> for IpAddr {
+    type Error = AddrParseError;
+
+    /// Converts the address into an [IpAddr], this fails if the address is null
+    /// or if it is an IPv6 address with a scope ID.
+    fn try_from(value: &QHostAddress) -> Result<Self, Self::Error> {
+        value.to_string().parse::<IpAddr>()
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QHostAddress {
+    type Id = type_id!("QHostAddress");
+    type Kind = cxx::kind::Trivial;
+}
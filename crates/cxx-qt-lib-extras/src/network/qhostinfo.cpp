@@ -0,0 +1,60 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qhostinfo.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+#include <cstdint>
+
+// QHostInfo has 1 pointer
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/network/kernel/qhostinfo.h?h=v6.2.4
+assert_alignment_and_size(QHostInfo, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QHostInfo>::value);
+static_assert(!::std::is_trivially_copy_constructible<QHostInfo>::value);
+
+static_assert(!::std::is_trivially_destructible<QHostInfo>::value);
+static_assert(QTypeInfo<QHostInfo>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qhostinfoAbortHostLookup(::std::int32_t id)
+{
+  QHostInfo::abortHostLookup(id);
+}
+
+::std::unique_ptr<::std::vector<QHostAddress>>
+qhostinfoAddresses(const QHostInfo& info)
+{
+  const auto addresses = info.addresses();
+  return ::std::make_unique<::std::vector<QHostAddress>>(addresses.cbegin(),
+                                                         addresses.cend());
+}
+
+QHostInfo
+qhostinfoFromName(const QString& name)
+{
+  return QHostInfo::fromName(name);
+}
+
+QString
+qhostinfoLocalDomainName()
+{
+  return QHostInfo::localDomainName();
+}
+
+QString
+qhostinfoLocalHostName()
+{
+  return QHostInfo::localHostName();
+}
+
+}
+}
@@ -0,0 +1,199 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, CxxVector, ExternType, UniquePtr};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// Describes the errors that may occur when trying to resolve a host name.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QHostInfoHostInfoError {
+        /// The lookup was successful.
+        NoError = 0,
+        /// No IP addresses were found for the host.
+        HostNotFound = 1,
+        /// An unknown error occurred.
+        UnknownError = 2,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qhostinfo.h");
+        type QHostInfo = super::QHostInfo;
+        include!("cxx-qt-lib-extras/qhostaddress.h");
+        type QHostAddress = crate::QHostAddress;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        /// Returns the type of error that occurred if the host name lookup failed; otherwise returns NoError.
+        fn error(self: &QHostInfo) -> QHostInfoHostInfoError;
+
+        /// If the lookup failed, this function returns a human readable description of the error.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QHostInfo) -> QString;
+
+        /// Returns the name of the host whose IP addresses were looked up.
+        #[rust_name = "host_name"]
+        fn hostName(self: &QHostInfo) -> QString;
+
+        /// Returns the ID of this lookup.
+        #[rust_name = "lookup_id"]
+        fn lookupId(self: &QHostInfo) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QHostInfoHostInfoError;
+
+        #[doc(hidden)]
+        #[rust_name = "qhostinfo_abort_host_lookup"]
+        fn qhostinfoAbortHostLookup(id: i32);
+        #[doc(hidden)]
+        #[rust_name = "qhostinfo_addresses"]
+        fn qhostinfoAddresses(info: &QHostInfo) -> UniquePtr<CxxVector<QHostAddress>>;
+        #[doc(hidden)]
+        #[rust_name = "qhostinfo_from_name"]
+        fn qhostinfoFromName(name: &QString) -> QHostInfo;
+        #[doc(hidden)]
+        #[rust_name = "qhostinfo_local_domain_name"]
+        fn qhostinfoLocalDomainName() -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qhostinfo_local_host_name"]
+        fn qhostinfoLocalHostName() -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qhostinfo_lookup_host"]
+        fn qhostinfoLookupHost(
+            name: &QString,
+            func: fn(Box<QHostInfoLookupHandler>, &QHostInfo),
+            arg: Box<QHostInfoLookupHandler>,
+        ) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qhostinfo_drop"]
+        fn drop(info: &mut QHostInfo);
+
+        #[doc(hidden)]
+        #[rust_name = "qhostinfo_init_default"]
+        fn construct() -> QHostInfo;
+        #[doc(hidden)]
+        #[rust_name = "qhostinfo_clone"]
+        fn construct(info: &QHostInfo) -> QHostInfo;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        type QHostInfoLookupHandler;
+    }
+
+    // Allow for lists of addresses to be returned from the lookup
+    impl CxxVector<QHostAddress> {}
+}
+
+use crate::QHostAddress;
+use cxx_qt_lib::QString;
+
+pub use ffi::QHostInfoHostInfoError;
+
+/// The QHostInfo class provides static functions for host name lookups.
+///
+/// Lookups are either asynchronous with [QHostInfo::lookup_host], which calls back into Rust
+/// with the result, or blocking with [QHostInfo::from_name].
+#[repr(C)]
+pub struct QHostInfo {
+    _d: MaybeUninit<usize>,
+}
+
+impl QHostInfo {
+    /// Aborts the host lookup with the ID id, as returned by [QHostInfo::lookup_host].
+    pub fn abort_host_lookup(id: i32) {
+        ffi::qhostinfo_abort_host_lookup(id)
+    }
+
+    /// Returns the list of IP addresses associated with the host name, which may be empty.
+    pub fn addresses(&self) -> UniquePtr<CxxVector<QHostAddress>> {
+        ffi::qhostinfo_addresses(self)
+    }
+
+    /// Looks up the IP address(es) for the given host name.
+    /// The function blocks during the lookup which means that execution of the program is suspended until the results of the lookup are ready.
+    ///
+    /// If you pass a literal IP address to name instead of a host name,
+    /// QHostInfo will search for the domain name for the IP (i.e., QHostInfo will perform a reverse lookup).
+    pub fn from_name(name: &QString) -> Self {
+        ffi::qhostinfo_from_name(name)
+    }
+
+    /// Returns the DNS domain of this machine.
+    pub fn local_domain_name() -> QString {
+        ffi::qhostinfo_local_domain_name()
+    }
+
+    /// Returns this machine's host name, if one is configured.
+    pub fn local_host_name() -> QString {
+        ffi::qhostinfo_local_host_name()
+    }
+
+    /// Looks up the IP address(es) associated with host name name, and returns an ID for the lookup.
+    ///
+    /// When the result of the lookup is ready, the callback is called with a QHostInfo object containing the result.
+    /// The callback is called on the thread which started the lookup, which must have a running Qt event loop.
+    /// If the lookup is aborted with [QHostInfo::abort_host_lookup], the callback is never called.
+    pub fn lookup_host<F>(name: &QString, callback: F) -> i32
+    where
+        F: FnOnce(&QHostInfo) + 'static,
+    {
+        #[allow(clippy::boxed_local)]
+        fn func(handler: Box<QHostInfoLookupHandler>, info: &QHostInfo) {
+            (handler.inner)(info)
+        }
+        let arg = QHostInfoLookupHandler {
+            inner: Box::new(callback),
+        };
+        ffi::qhostinfo_lookup_host(name, func, Box::new(arg))
+    }
+}
+
+impl Clone for QHostInfo {
+    /// Constructs a copy of other.
+    fn clone(&self) -> Self {
+        ffi::qhostinfo_clone(self)
+    }
+}
+
+impl Default for QHostInfo {
+    /// Constructs an empty host info object.
+    fn default() -> Self {
+        ffi::qhostinfo_init_default()
+    }
+}
+
+impl Drop for QHostInfo {
+    /// Destroys the host info object.
+    fn drop(&mut self) {
+        ffi::qhostinfo_drop(self)
+    }
+}
+
+#[doc(hidden)]
+pub struct QHostInfoLookupHandler {
+    inner: LookupHostFn,
+}
+
+type LookupHostFn = Box<dyn FnOnce(&QHostInfo)>;
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QHostInfo {
+    type Id = type_id!("QHostInfo");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,47 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qnetworkinformation.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QStringList
+qnetworkinformationAvailableBackends()
+{
+  return QNetworkInformation::availableBackends();
+}
+
+QNetworkInformation*
+qnetworkinformationInstance()
+{
+  return QNetworkInformation::instance();
+}
+
+bool
+qnetworkinformationLoadBackendByName(const QString& backend)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 4, 0))
+  return QNetworkInformation::loadBackendByName(backend);
+#else
+  return QNetworkInformation::load(backend);
+#endif
+}
+
+bool
+qnetworkinformationLoadDefaultBackend()
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 3, 0))
+  return QNetworkInformation::loadDefaultBackend();
+#else
+  // Before Qt 6.3 a backend is loaded by the features it supports
+  return QNetworkInformation::load(QNetworkInformation::Feature::Reachability);
+#endif
+}
+
+}
+}
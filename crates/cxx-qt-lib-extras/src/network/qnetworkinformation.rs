@@ -0,0 +1,152 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// Describes the level of reachability of the network.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QNetworkInformationReachability {
+        /// The reachability is unknown, or the backend does not support reachability.
+        Unknown = 0,
+        /// The system is not connected to a network.
+        Disconnected = 1,
+        /// The system is connected to a network, but only local or link-local resources are reachable.
+        Local = 2,
+        /// The system is connected to a network, but only site resources such as an intranet are reachable.
+        Site = 3,
+        /// The system is connected to a network and is able to access the Internet.
+        Online = 4,
+    }
+
+    /// Lists the transport media that are detected for the active network connection.
+    #[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_3))]
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QNetworkInformationTransportMedium {
+        /// The transport medium is unknown, or the backend does not support transport media.
+        Unknown = 0,
+        /// The system is using an ethernet connection.
+        Ethernet = 1,
+        /// The system is using a cellular connection.
+        Cellular = 2,
+        /// The system is using a WiFi connection.
+        WiFi = 3,
+        /// The system is using a bluetooth connection.
+        Bluetooth = 4,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qnetworkinformation.h");
+        /// The QNetworkInformation class exposes various network information through native backends.
+        ///
+        /// A backend must be loaded with [QNetworkInformation::load_default_backend]
+        /// before the instance is available from [QNetworkInformation::instance].
+        #[qobject]
+        type QNetworkInformation;
+
+        /// This signal is emitted when the device is found to be behind or no longer behind a captive portal.
+        #[qsignal]
+        #[cxx_name = "isBehindCaptivePortalChanged"]
+        fn is_behind_captive_portal_changed(self: Pin<&mut QNetworkInformation>, state: bool);
+
+        /// This signal is emitted when the reachability of the network changes.
+        #[qsignal]
+        #[cxx_name = "reachabilityChanged"]
+        fn reachability_changed(
+            self: Pin<&mut QNetworkInformation>,
+            new_reachability: QNetworkInformationReachability,
+        );
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = cxx_qt_lib::QStringList;
+
+        /// Returns the name of the currently loaded backend.
+        #[rust_name = "backend_name"]
+        fn backendName(self: &QNetworkInformation) -> QString;
+
+        /// Returns true if the device is behind a captive portal, such as the login page of a hotel network.
+        #[rust_name = "is_behind_captive_portal"]
+        fn isBehindCaptivePortal(self: &QNetworkInformation) -> bool;
+
+        /// Returns true if the current connection is (potentially) expensive, such as a cellular connection.
+        #[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_3))]
+        #[rust_name = "is_metered"]
+        fn isMetered(self: &QNetworkInformation) -> bool;
+
+        /// Returns the current reachability of the network.
+        fn reachability(self: &QNetworkInformation) -> QNetworkInformationReachability;
+
+        /// Returns the transport medium of the primary network interface.
+        #[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_3))]
+        #[rust_name = "transport_medium"]
+        fn transportMedium(self: &QNetworkInformation) -> QNetworkInformationTransportMedium;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QNetworkInformationReachability;
+        #[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_3))]
+        type QNetworkInformationTransportMedium;
+
+        #[doc(hidden)]
+        #[rust_name = "qnetworkinformation_available_backends"]
+        fn qnetworkinformationAvailableBackends() -> QStringList;
+
+        #[doc(hidden)]
+        #[rust_name = "qnetworkinformation_instance"]
+        fn qnetworkinformationInstance() -> *mut QNetworkInformation;
+
+        #[doc(hidden)]
+        #[rust_name = "qnetworkinformation_load_backend_by_name"]
+        fn qnetworkinformationLoadBackendByName(backend: &QString) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qnetworkinformation_load_default_backend"]
+        fn qnetworkinformationLoadDefaultBackend() -> bool;
+    }
+}
+
+use cxx_qt_lib::{QString, QStringList};
+
+pub use ffi::{QNetworkInformation, QNetworkInformationReachability};
+
+#[cfg(any(cxxqt_qt_version_at_least_7, cxxqt_qt_version_at_least_6_3))]
+pub use ffi::QNetworkInformationTransportMedium;
+
+impl QNetworkInformation {
+    /// Returns a list of the names of all currently available backends.
+    pub fn available_backends() -> QStringList {
+        ffi::qnetworkinformation_available_backends()
+    }
+
+    /// Returns a pointer to the instance of the QNetworkInformation, if a backend has been loaded,
+    /// otherwise returns a null pointer.
+    ///
+    /// The instance is owned by Qt and lives until the application exits.
+    pub fn instance() -> *mut Self {
+        ffi::qnetworkinformation_instance()
+    }
+
+    /// Attempts to load the backend with the given name, from [QNetworkInformation::available_backends].
+    ///
+    /// Returns true if it loaded the backend or if it was already loaded, otherwise returns false.
+    pub fn load_backend_by_name(backend: &QString) -> bool {
+        ffi::qnetworkinformation_load_backend_by_name(backend)
+    }
+
+    /// Attempts to load the platform-default backend.
+    ///
+    /// Returns true if it loaded a backend or if one was already loaded, otherwise returns false.
+    pub fn load_default_backend() -> bool {
+        ffi::qnetworkinformation_load_default_backend()
+    }
+}
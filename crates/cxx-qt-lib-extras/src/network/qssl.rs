@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// Describes supported encoding formats for certificates and keys.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSslEncodingFormat {
+        /// The PEM format.
+        Pem = 0,
+        /// The DER format.
+        Der = 1,
+    }
+
+    /// Describes the different key algorithms supported by QSslKey.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSslKeyAlgorithm {
+        /// A key that should be treated as a 'black box' by QSslKey.
+        Opaque = 0,
+        /// The RSA algorithm.
+        Rsa = 1,
+        /// The DSA algorithm.
+        Dsa = 2,
+        /// The Elliptic Curve algorithm.
+        Ec = 3,
+        /// The Diffie-Hellman algorithm.
+        Dh = 4,
+    }
+
+    /// Describes the two types of keys QSslKey supports.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSslKeyType {
+        /// A private key.
+        PrivateKey = 0,
+        /// A public key.
+        PublicKey = 1,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qssl.h");
+        type QSslEncodingFormat;
+        type QSslKeyAlgorithm;
+        type QSslKeyType;
+    }
+}
+
+pub use ffi::{QSslEncodingFormat, QSslKeyAlgorithm, QSslKeyType};
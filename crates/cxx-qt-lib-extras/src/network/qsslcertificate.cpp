@@ -0,0 +1,73 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qsslcertificate.h"
+
+#include <QtCore/QCryptographicHash>
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+#include <cstdint>
+
+// QSslCertificate has 1 pointer
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/network/ssl/qsslcertificate.h?h=v6.2.4
+assert_alignment_and_size(QSslCertificate, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QSslCertificate>::value);
+static_assert(!::std::is_trivially_copy_constructible<QSslCertificate>::value);
+
+static_assert(!::std::is_trivially_destructible<QSslCertificate>::value);
+static_assert(QTypeInfo<QSslCertificate>::isRelocatable);
+
+namespace {
+
+::std::unique_ptr<::std::vector<QSslCertificate>>
+toVector(const QList<QSslCertificate>& certificates)
+{
+  return ::std::make_unique<::std::vector<QSslCertificate>>(
+    certificates.cbegin(), certificates.cend());
+}
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QByteArray
+qsslcertificateDigestSha256(const QSslCertificate& certificate)
+{
+  return certificate.digest(QCryptographicHash::Sha256);
+}
+
+::std::unique_ptr<::std::vector<QSslCertificate>>
+qsslcertificateFromData(const QByteArray& data, QSslEncodingFormat format)
+{
+  return toVector(QSslCertificate::fromData(data, format));
+}
+
+::std::unique_ptr<::std::vector<QSslCertificate>>
+qsslcertificateFromPath(const QString& path, QSslEncodingFormat format)
+{
+  return toVector(QSslCertificate::fromPath(path, format));
+}
+
+QStringList
+qsslcertificateIssuerInfo(const QSslCertificate& certificate,
+                          QSslCertificateSubjectInfo subject)
+{
+  return certificate.issuerInfo(subject);
+}
+
+QStringList
+qsslcertificateSubjectInfo(const QSslCertificate& certificate,
+                           QSslCertificateSubjectInfo subject)
+{
+  return certificate.subjectInfo(subject);
+}
+
+}
+}
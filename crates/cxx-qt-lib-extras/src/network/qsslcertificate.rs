@@ -0,0 +1,221 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, CxxVector, ExternType, UniquePtr};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// Describes keys that you can pass to [QSslCertificate::issuer_info] or
+    /// [QSslCertificate::subject_info] to get information about the certificate issuer or subject.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSslCertificateSubjectInfo {
+        /// "O" The name of the organization.
+        Organization = 0,
+        /// "CN" The common name; most often this is used to store the host name.
+        CommonName = 1,
+        /// "L" The locality.
+        LocalityName = 2,
+        /// "OU" The organizational unit name.
+        OrganizationalUnitName = 3,
+        /// "C" The country.
+        CountryName = 4,
+        /// "ST" The state or province.
+        StateOrProvinceName = 5,
+        /// The distinguished name qualifier.
+        DistinguishedNameQualifier = 6,
+        /// The certificate's serial number.
+        SerialNumber = 7,
+        /// The email address associated with the certificate.
+        EmailAddress = 8,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qsslcertificate.h");
+        type QSslCertificate = super::QSslCertificate;
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qdatetime.h");
+        type QDateTime = cxx_qt_lib::QDateTime;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = cxx_qt_lib::QStringList;
+
+        include!("cxx-qt-lib-extras/qssl.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QSslEncodingFormat = crate::QSslEncodingFormat;
+
+        /// Returns the date-time that the certificate becomes valid.
+        #[rust_name = "effective_date"]
+        fn effectiveDate(self: &QSslCertificate) -> QDateTime;
+
+        /// Returns the date-time that the certificate expires.
+        #[rust_name = "expiry_date"]
+        fn expiryDate(self: &QSslCertificate) -> QDateTime;
+
+        /// Returns true if this is a null certificate (i.e., a certificate with no contents), otherwise returns false.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QSslCertificate) -> bool;
+
+        /// Returns true if this certificate is self signed, otherwise returns false.
+        #[rust_name = "is_self_signed"]
+        fn isSelfSigned(self: &QSslCertificate) -> bool;
+
+        /// Returns the certificate's serial number string in hexadecimal format.
+        #[rust_name = "serial_number"]
+        fn serialNumber(self: &QSslCertificate) -> QByteArray;
+
+        /// Returns this certificate converted to a DER (binary) encoded representation.
+        #[rust_name = "to_der"]
+        fn toDer(self: &QSslCertificate) -> QByteArray;
+
+        /// Returns this certificate converted to a PEM (Base64) encoded representation.
+        #[rust_name = "to_pem"]
+        fn toPem(self: &QSslCertificate) -> QByteArray;
+
+        /// Returns this certificate converted to a human-readable text representation.
+        #[rust_name = "to_text"]
+        fn toText(self: &QSslCertificate) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+        type QSslCertificateSubjectInfo;
+
+        #[doc(hidden)]
+        #[rust_name = "qsslcertificate_drop"]
+        fn drop(certificate: &mut QSslCertificate);
+
+        #[doc(hidden)]
+        #[rust_name = "qsslcertificate_init_default"]
+        fn construct() -> QSslCertificate;
+
+        #[doc(hidden)]
+        #[rust_name = "qsslcertificate_clone"]
+        fn construct(certificate: &QSslCertificate) -> QSslCertificate;
+
+        #[doc(hidden)]
+        #[rust_name = "qsslcertificate_eq"]
+        fn operatorEq(a: &QSslCertificate, b: &QSslCertificate) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qsslcertificate_digest_sha256"]
+        fn qsslcertificateDigestSha256(certificate: &QSslCertificate) -> QByteArray;
+
+        #[doc(hidden)]
+        #[rust_name = "qsslcertificate_from_data"]
+        fn qsslcertificateFromData(
+            data: &QByteArray,
+            format: QSslEncodingFormat,
+        ) -> UniquePtr<CxxVector<QSslCertificate>>;
+
+        #[doc(hidden)]
+        #[rust_name = "qsslcertificate_from_path"]
+        fn qsslcertificateFromPath(
+            path: &QString,
+            format: QSslEncodingFormat,
+        ) -> UniquePtr<CxxVector<QSslCertificate>>;
+
+        #[doc(hidden)]
+        #[rust_name = "qsslcertificate_issuer_info"]
+        fn qsslcertificateIssuerInfo(
+            certificate: &QSslCertificate,
+            subject: QSslCertificateSubjectInfo,
+        ) -> QStringList;
+
+        #[doc(hidden)]
+        #[rust_name = "qsslcertificate_subject_info"]
+        fn qsslcertificateSubjectInfo(
+            certificate: &QSslCertificate,
+            subject: QSslCertificateSubjectInfo,
+        ) -> QStringList;
+    }
+
+    // Allow for lists of certificates to be used by QSslConfiguration
+    impl CxxVector<QSslCertificate> {}
+}
+
+use crate::QSslEncodingFormat;
+use cxx_qt_lib::{QByteArray, QString, QStringList};
+
+pub use ffi::QSslCertificateSubjectInfo;
+
+/// The QSslCertificate class provides a convenient API for an X509 certificate.
+///
+/// Certificates are loaded with [QSslCertificate::from_data] or [QSslCertificate::from_path].
+#[repr(C)]
+pub struct QSslCertificate {
+    _d: MaybeUninit<usize>,
+}
+
+impl Clone for QSslCertificate {
+    /// Constructs an identical copy of other.
+    fn clone(&self) -> Self {
+        ffi::qsslcertificate_clone(self)
+    }
+}
+
+impl Default for QSslCertificate {
+    /// Constructs a null certificate.
+    fn default() -> Self {
+        ffi::qsslcertificate_init_default()
+    }
+}
+
+impl Drop for QSslCertificate {
+    /// Destroys the QSslCertificate.
+    fn drop(&mut self) {
+        ffi::qsslcertificate_drop(self)
+    }
+}
+
+impl PartialEq for QSslCertificate {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qsslcertificate_eq(self, other)
+    }
+}
+
+impl Eq for QSslCertificate {}
+
+impl QSslCertificate {
+    /// Returns the SHA-256 digest of the certificate, which can be used to pin a certificate.
+    pub fn digest_sha256(&self) -> QByteArray {
+        ffi::qsslcertificate_digest_sha256(self)
+    }
+
+    /// Searches for and parses all certificates in data that are encoded in the specified format
+    /// and returns them in a list of certificates.
+    pub fn from_data(data: &QByteArray, format: QSslEncodingFormat) -> UniquePtr<CxxVector<Self>> {
+        ffi::qsslcertificate_from_data(data, format)
+    }
+
+    /// Searches for and parses all certificates in the file at path that are encoded in the specified format
+    /// and returns them in a list of certificates.
+    pub fn from_path(path: &QString, format: QSslEncodingFormat) -> UniquePtr<CxxVector<Self>> {
+        ffi::qsslcertificate_from_path(path, format)
+    }
+
+    /// Returns the issuer information for the subject from the certificate, or an empty list if there is no information.
+    pub fn issuer_info(&self, subject: QSslCertificateSubjectInfo) -> QStringList {
+        ffi::qsslcertificate_issuer_info(self, subject)
+    }
+
+    /// Returns the information for the subject, or an empty list if there is no information.
+    pub fn subject_info(&self, subject: QSslCertificateSubjectInfo) -> QStringList {
+        ffi::qsslcertificate_subject_info(self, subject)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QSslCertificate {
+    type Id = type_id!("QSslCertificate");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,72 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qsslconfiguration.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qsslconfigurationAddCaCertificate(QSslConfiguration& configuration,
+                                  const QSslCertificate& certificate)
+{
+  configuration.addCaCertificate(certificate);
+}
+
+::std::unique_ptr<::std::vector<QSslCertificate>>
+qsslconfigurationCaCertificates(const QSslConfiguration& configuration)
+{
+  const auto certificates = configuration.caCertificates();
+  return ::std::make_unique<::std::vector<QSslCertificate>>(
+    certificates.cbegin(), certificates.cend());
+}
+
+::std::unique_ptr<QSslConfiguration>
+qsslconfigurationClone(const QSslConfiguration& configuration)
+{
+  return ::std::make_unique<QSslConfiguration>(configuration);
+}
+
+::std::unique_ptr<QSslConfiguration>
+qsslconfigurationDefaultConfiguration()
+{
+  return ::std::make_unique<QSslConfiguration>(
+    QSslConfiguration::defaultConfiguration());
+}
+
+::std::unique_ptr<QSslConfiguration>
+qsslconfigurationNew()
+{
+  return ::std::make_unique<QSslConfiguration>();
+}
+
+void
+qsslconfigurationSetCaCertificates(
+  QSslConfiguration& configuration,
+  ::rust::Slice<const QSslCertificate> certificates)
+{
+  configuration.setCaCertificates(
+    QList<QSslCertificate>(certificates.begin(), certificates.end()));
+}
+
+void
+qsslconfigurationSetDefaultConfiguration(
+  const QSslConfiguration& configuration)
+{
+  QSslConfiguration::setDefaultConfiguration(configuration);
+}
+
+::std::unique_ptr<::std::vector<QSslCertificate>>
+qsslconfigurationSystemCaCertificates()
+{
+  const auto certificates = QSslConfiguration::systemCaCertificates();
+  return ::std::make_unique<::std::vector<QSslCertificate>>(
+    certificates.cbegin(), certificates.cend());
+}
+
+}
+}
@@ -0,0 +1,170 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{CxxVector, UniquePtr};
+
+#[cxx::bridge]
+mod ffi {
+    /// Describes the peer verification modes for QSslSocket.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSslSocketPeerVerifyMode {
+        /// The connection is encrypted but the peer is not asked for a certificate.
+        VerifyNone = 0,
+        /// A certificate is requested from the peer, but it is not required to be valid.
+        QueryPeer = 1,
+        /// The peer is required to present a valid certificate, otherwise the handshake fails.
+        VerifyPeer = 2,
+        /// Uses QueryPeer for server-side sockets and VerifyPeer for client-side sockets.
+        AutoVerifyPeer = 3,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qsslconfiguration.h");
+        /// The QSslConfiguration class holds the configuration and state of an SSL connection,
+        /// such as the CA certificates which are trusted and the local certificate and private key.
+        type QSslConfiguration;
+
+        include!("cxx-qt-lib-extras/qsslcertificate.h");
+        type QSslCertificate = crate::QSslCertificate;
+        include!("cxx-qt-lib-extras/qsslkey.h");
+        type QSslKey = crate::QSslKey;
+
+        /// Returns true if this is a null QSslConfiguration object.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QSslConfiguration) -> bool;
+
+        /// Returns the certificate to be presented to the peer during the SSL handshake process.
+        #[rust_name = "local_certificate"]
+        fn localCertificate(self: &QSslConfiguration) -> QSslCertificate;
+
+        /// Returns the peer's digital certificate, or a null certificate if the connection is not established.
+        #[rust_name = "peer_certificate"]
+        fn peerCertificate(self: &QSslConfiguration) -> QSslCertificate;
+
+        /// Returns the verify mode, which decides whether the socket should request a certificate from the peer.
+        #[rust_name = "peer_verify_mode"]
+        fn peerVerifyMode(self: &QSslConfiguration) -> QSslSocketPeerVerifyMode;
+
+        /// Returns the SSL key assigned to this connection or a null key if none has been assigned yet.
+        #[rust_name = "private_key"]
+        fn privateKey(self: &QSslConfiguration) -> QSslKey;
+
+        /// Sets the certificate to be presented to the peer during SSL handshake to be certificate.
+        #[rust_name = "set_local_certificate"]
+        fn setLocalCertificate(self: Pin<&mut QSslConfiguration>, certificate: &QSslCertificate);
+
+        /// Sets the verify mode to mode.
+        #[rust_name = "set_peer_verify_mode"]
+        fn setPeerVerifyMode(self: Pin<&mut QSslConfiguration>, mode: QSslSocketPeerVerifyMode);
+
+        /// Sets the connection's private key to key.
+        #[rust_name = "set_private_key"]
+        fn setPrivateKey(self: Pin<&mut QSslConfiguration>, key: &QSslKey);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QSslSocketPeerVerifyMode;
+
+        #[doc(hidden)]
+        #[rust_name = "qsslconfiguration_add_ca_certificate"]
+        fn qsslconfigurationAddCaCertificate(
+            configuration: Pin<&mut QSslConfiguration>,
+            certificate: &QSslCertificate,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qsslconfiguration_ca_certificates"]
+        fn qsslconfigurationCaCertificates(
+            configuration: &QSslConfiguration,
+        ) -> UniquePtr<CxxVector<QSslCertificate>>;
+
+        #[doc(hidden)]
+        #[rust_name = "qsslconfiguration_clone"]
+        fn qsslconfigurationClone(
+            configuration: &QSslConfiguration,
+        ) -> UniquePtr<QSslConfiguration>;
+
+        #[doc(hidden)]
+        #[rust_name = "qsslconfiguration_default_configuration"]
+        fn qsslconfigurationDefaultConfiguration() -> UniquePtr<QSslConfiguration>;
+
+        #[doc(hidden)]
+        #[rust_name = "qsslconfiguration_new"]
+        fn qsslconfigurationNew() -> UniquePtr<QSslConfiguration>;
+
+        #[doc(hidden)]
+        #[rust_name = "qsslconfiguration_set_ca_certificates"]
+        fn qsslconfigurationSetCaCertificates(
+            configuration: Pin<&mut QSslConfiguration>,
+            certificates: &[QSslCertificate],
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qsslconfiguration_set_default_configuration"]
+        fn qsslconfigurationSetDefaultConfiguration(configuration: &QSslConfiguration);
+
+        #[doc(hidden)]
+        #[rust_name = "qsslconfiguration_system_ca_certificates"]
+        fn qsslconfigurationSystemCaCertificates() -> UniquePtr<CxxVector<QSslCertificate>>;
+    }
+
+    // Allow for configurations to be passed to sockets
+    impl UniquePtr<QSslConfiguration> {}
+}
+
+use crate::QSslCertificate;
+use core::pin::Pin;
+
+pub use ffi::{QSslConfiguration, QSslSocketPeerVerifyMode};
+
+impl QSslConfiguration {
+    /// Constructs an empty SSL configuration.
+    ///
+    /// The configuration has no CA certificates set, use [QSslConfiguration::default_configuration]
+    /// for a configuration which trusts the system CA certificates.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qsslconfiguration_new()
+    }
+
+    /// Returns the default SSL configuration to be used in new SSL connections.
+    pub fn default_configuration() -> UniquePtr<Self> {
+        ffi::qsslconfiguration_default_configuration()
+    }
+
+    /// Sets the default SSL configuration to be used in new SSL connections to be configuration.
+    pub fn set_default_configuration(configuration: &Self) {
+        ffi::qsslconfiguration_set_default_configuration(configuration)
+    }
+
+    /// Returns the system default CA certificate database.
+    pub fn system_ca_certificates() -> UniquePtr<CxxVector<QSslCertificate>> {
+        ffi::qsslconfiguration_system_ca_certificates()
+    }
+
+    /// Adds certificate to the configuration's CA certificate database.
+    pub fn add_ca_certificate(self: Pin<&mut Self>, certificate: &QSslCertificate) {
+        ffi::qsslconfiguration_add_ca_certificate(self, certificate)
+    }
+
+    /// Returns this connection's CA certificate database, which is used to verify the peer's certificate.
+    pub fn ca_certificates(&self) -> UniquePtr<CxxVector<QSslCertificate>> {
+        ffi::qsslconfiguration_ca_certificates(self)
+    }
+
+    /// Sets this socket's CA certificate database to be certificates, replacing any existing certificates.
+    ///
+    /// Setting only the certificate of a trusted server pins the connection to that certificate.
+    pub fn set_ca_certificates(self: Pin<&mut Self>, certificates: &[QSslCertificate]) {
+        ffi::qsslconfiguration_set_ca_certificates(self, certificates)
+    }
+
+    /// Constructs a copy of the configuration.
+    pub fn to_owned(&self) -> UniquePtr<Self> {
+        ffi::qsslconfiguration_clone(self)
+    }
+}
@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qsslkey.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+#include <cstdint>
+
+// QSslKey has 1 pointer
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/network/ssl/qsslkey.h?h=v6.2.4
+assert_alignment_and_size(QSslKey, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QSslKey>::value);
+static_assert(!::std::is_trivially_copy_constructible<QSslKey>::value);
+
+static_assert(!::std::is_trivially_destructible<QSslKey>::value);
+static_assert(QTypeInfo<QSslKey>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QSslKey
+qsslkeyInitFromData(const QByteArray& encoded,
+                    QSslKeyAlgorithm algorithm,
+                    QSslEncodingFormat format,
+                    QSslKeyType type,
+                    const QByteArray& passPhrase)
+{
+  return QSslKey(encoded, algorithm, format, type, passPhrase);
+}
+
+}
+}
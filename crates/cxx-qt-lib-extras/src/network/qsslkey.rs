@@ -0,0 +1,142 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qsslkey.h");
+        type QSslKey = super::QSslKey;
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+
+        include!("cxx-qt-lib-extras/qssl.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QSslEncodingFormat = crate::QSslEncodingFormat;
+        #[namespace = "rust::cxxqtlib1"]
+        type QSslKeyAlgorithm = crate::QSslKeyAlgorithm;
+        #[namespace = "rust::cxxqtlib1"]
+        type QSslKeyType = crate::QSslKeyType;
+
+        /// Returns the key algorithm.
+        fn algorithm(self: &QSslKey) -> QSslKeyAlgorithm;
+
+        /// Returns true if this is a null key, otherwise false.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QSslKey) -> bool;
+
+        /// Returns the length of the key in bits, or -1 if the key is null.
+        fn length(self: &QSslKey) -> i32;
+
+        /// Returns the key in DER encoding, the pass phrase is used to encrypt private keys.
+        #[rust_name = "to_der"]
+        fn toDer(self: &QSslKey, pass_phrase: &QByteArray) -> QByteArray;
+
+        /// Returns the key in PEM encoding, the pass phrase is used to encrypt private keys.
+        #[rust_name = "to_pem"]
+        fn toPem(self: &QSslKey, pass_phrase: &QByteArray) -> QByteArray;
+
+        /// Returns the type of the key, such as a private key or a public key.
+        #[cxx_name = "type"]
+        #[rust_name = "key_type"]
+        fn keyType(self: &QSslKey) -> QSslKeyType;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qsslkey_drop"]
+        fn drop(key: &mut QSslKey);
+
+        #[doc(hidden)]
+        #[rust_name = "qsslkey_init_default"]
+        fn construct() -> QSslKey;
+
+        #[doc(hidden)]
+        #[rust_name = "qsslkey_clone"]
+        fn construct(key: &QSslKey) -> QSslKey;
+
+        #[doc(hidden)]
+        #[rust_name = "qsslkey_eq"]
+        fn operatorEq(a: &QSslKey, b: &QSslKey) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qsslkey_init_from_data"]
+        fn qsslkeyInitFromData(
+            encoded: &QByteArray,
+            algorithm: QSslKeyAlgorithm,
+            format: QSslEncodingFormat,
+            key_type: QSslKeyType,
+            pass_phrase: &QByteArray,
+        ) -> QSslKey;
+    }
+}
+
+use crate::{QSslEncodingFormat, QSslKeyAlgorithm, QSslKeyType};
+use cxx_qt_lib::QByteArray;
+
+/// The QSslKey class provides an interface for private and public keys,
+/// such as the private key of a client certificate.
+#[repr(C)]
+pub struct QSslKey {
+    _d: MaybeUninit<usize>,
+}
+
+impl Clone for QSslKey {
+    /// Constructs an identical copy of other.
+    fn clone(&self) -> Self {
+        ffi::qsslkey_clone(self)
+    }
+}
+
+impl Default for QSslKey {
+    /// Constructs a null key.
+    fn default() -> Self {
+        ffi::qsslkey_init_default()
+    }
+}
+
+impl Drop for QSslKey {
+    /// Destroys the QSslKey object.
+    fn drop(&mut self) {
+        ffi::qsslkey_drop(self)
+    }
+}
+
+impl PartialEq for QSslKey {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qsslkey_eq(self, other)
+    }
+}
+
+impl Eq for QSslKey {}
+
+impl QSslKey {
+    /// Constructs a QSslKey by decoding the encoded data using the given algorithm, format and type.
+    ///
+    /// The pass phrase is used to decrypt an encrypted private key, otherwise it may be empty.
+    /// A null key is returned if the data could not be decoded, see [QSslKey::is_null].
+    pub fn from_data(
+        encoded: &QByteArray,
+        algorithm: QSslKeyAlgorithm,
+        format: QSslEncodingFormat,
+        key_type: QSslKeyType,
+        pass_phrase: &QByteArray,
+    ) -> Self {
+        ffi::qsslkey_init_from_data(encoded, algorithm, format, key_type, pass_phrase)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QSslKey {
+    type Id = type_id!("QSslKey");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,16 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qgeocoordinate;
+pub use qgeocoordinate::{QGeoCoordinate, QGeoCoordinateCoordinateType};
+
+mod qgeopositioninfo;
+pub use qgeopositioninfo::{QGeoPositionInfo, QGeoPositionInfoAttribute};
+
+mod qgeopositioninfosource;
+pub use qgeopositioninfosource::{QGeoPositionInfoSource, QGeoPositionInfoSourceError};
+
+mod qgeorectangle;
+pub use qgeorectangle::QGeoRectangle;
@@ -0,0 +1,37 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qgeocoordinate.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+#include <cstdint>
+
+// QGeoCoordinate has 1 pointer
+// https://code.qt.io/cgit/qt/qtlocation.git/tree/src/positioning/qgeocoordinate.h?h=5.15
+//
+// https://code.qt.io/cgit/qt/qtpositioning.git/tree/src/positioning/qgeocoordinate.h?h=v6.2.4
+assert_alignment_and_size(QGeoCoordinate, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QGeoCoordinate>::value);
+static_assert(!::std::is_trivially_copy_constructible<QGeoCoordinate>::value);
+
+static_assert(!::std::is_trivially_destructible<QGeoCoordinate>::value);
+static_assert(QTypeInfo<QGeoCoordinate>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+namespace qvariant {
+
+bool
+qvariantCanConvertQGeoCoordinate(const QVariant& variant)
+{
+  return variant.canConvert<QGeoCoordinate>();
+}
+
+}
+}
+}
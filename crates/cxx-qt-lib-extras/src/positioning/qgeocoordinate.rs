@@ -0,0 +1,220 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use cxx_qt_lib::{QVariant, QVariantValue};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// Defines the types of a coordinate.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QGeoCoordinateCoordinateType {
+        /// An invalid coordinate. A coordinate is invalid if its latitude or longitude values are invalid.
+        InvalidCoordinate,
+        /// A coordinate with valid latitude and longitude values.
+        Coordinate2D,
+        /// A coordinate with valid latitude and longitude values, and also an altitude value.
+        Coordinate3D,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qgeocoordinate.h");
+        type QGeoCoordinate = super::QGeoCoordinate;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+
+        /// Returns the altitude (meters above sea level).
+        ///
+        /// If the altitude has not been set, this function returns NaN.
+        fn altitude(self: &QGeoCoordinate) -> f64;
+
+        /// Returns a QGeoCoordinate which is the result of the coordinate being translated by distance meters
+        /// in the direction of azimuth degrees, and up by distance_up meters.
+        ///
+        /// Returns an invalid QGeoCoordinate if this coordinate is invalid.
+        #[rust_name = "at_distance_and_azimuth"]
+        fn atDistanceAndAzimuth(
+            self: &QGeoCoordinate,
+            distance: f64,
+            azimuth: f64,
+            distance_up: f64,
+        ) -> QGeoCoordinate;
+
+        /// Returns the azimuth (or bearing) in degrees from this coordinate to the coordinate specified by other.
+        ///
+        /// Returns 0 if the type of this coordinate or the type of other is QGeoCoordinateCoordinateType::InvalidCoordinate.
+        #[rust_name = "azimuth_to"]
+        fn azimuthTo(self: &QGeoCoordinate, other: &QGeoCoordinate) -> f64;
+
+        /// Returns the distance (in meters) from this coordinate to the coordinate specified by other.
+        /// Altitude is not used in the calculation.
+        ///
+        /// Returns 0 if the type of this coordinate or the type of other is QGeoCoordinateCoordinateType::InvalidCoordinate.
+        #[rust_name = "distance_to"]
+        fn distanceTo(self: &QGeoCoordinate, other: &QGeoCoordinate) -> f64;
+
+        /// Returns true if the latitude is between -90 to 90 inclusive, and the longitude is between -180 and 180 inclusive;
+        /// otherwise returns false.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QGeoCoordinate) -> bool;
+
+        /// Returns the latitude, in decimal degrees.
+        ///
+        /// If the latitude has not been set, this function returns NaN.
+        fn latitude(self: &QGeoCoordinate) -> f64;
+
+        /// Returns the longitude, in decimal degrees.
+        ///
+        /// If the longitude has not been set, this function returns NaN.
+        fn longitude(self: &QGeoCoordinate) -> f64;
+
+        /// Sets the altitude (meters above sea level) to altitude.
+        #[rust_name = "set_altitude"]
+        fn setAltitude(self: &mut QGeoCoordinate, altitude: f64);
+
+        /// Sets the latitude (in decimal degrees) to latitude. The value should be in the WGS84 datum.
+        ///
+        /// To be valid, the latitude must be between -90 to 90 inclusive.
+        #[rust_name = "set_latitude"]
+        fn setLatitude(self: &mut QGeoCoordinate, latitude: f64);
+
+        /// Sets the longitude (in decimal degrees) to longitude. The value should be in the WGS84 datum.
+        ///
+        /// To be valid, the longitude must be between -180 to 180 inclusive.
+        #[rust_name = "set_longitude"]
+        fn setLongitude(self: &mut QGeoCoordinate, longitude: f64);
+
+        /// Returns the type of this coordinate.
+        #[cxx_name = "type"]
+        fn coordinate_type(self: &QGeoCoordinate) -> QGeoCoordinateCoordinateType;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+        type QGeoCoordinateCoordinateType;
+
+        #[doc(hidden)]
+        #[rust_name = "qgeocoordinate_drop"]
+        fn drop(coordinate: &mut QGeoCoordinate);
+
+        #[doc(hidden)]
+        #[rust_name = "qgeocoordinate_init_default"]
+        fn construct() -> QGeoCoordinate;
+
+        #[doc(hidden)]
+        #[rust_name = "qgeocoordinate_init_2d"]
+        fn construct(latitude: f64, longitude: f64) -> QGeoCoordinate;
+
+        #[doc(hidden)]
+        #[rust_name = "qgeocoordinate_init_3d"]
+        fn construct(latitude: f64, longitude: f64, altitude: f64) -> QGeoCoordinate;
+
+        #[doc(hidden)]
+        #[rust_name = "qgeocoordinate_clone"]
+        fn construct(coordinate: &QGeoCoordinate) -> QGeoCoordinate;
+
+        #[doc(hidden)]
+        #[rust_name = "qgeocoordinate_eq"]
+        fn operatorEq(a: &QGeoCoordinate, b: &QGeoCoordinate) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvariant"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "can_convert_QGeoCoordinate"]
+        fn qvariantCanConvertQGeoCoordinate(variant: &QVariant) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "construct_QGeoCoordinate"]
+        fn qvariantConstruct(value: &QGeoCoordinate) -> QVariant;
+
+        #[doc(hidden)]
+        #[rust_name = "value_or_default_QGeoCoordinate"]
+        fn qvariantValueOrDefault(variant: &QVariant) -> QGeoCoordinate;
+    }
+}
+
+pub use ffi::QGeoCoordinateCoordinateType;
+
+/// The QGeoCoordinate class defines a geographical position on the surface of the Earth.
+///
+/// A QGeoCoordinate stored in a [QVariant] can be returned from the data of a model,
+/// so that delegates of a QML `MapItemView` can bind their `coordinate` to the role.
+#[repr(C)]
+pub struct QGeoCoordinate {
+    _d: MaybeUninit<usize>,
+}
+
+impl QGeoCoordinate {
+    /// Constructs a coordinate with the given latitude and longitude.
+    ///
+    /// If the latitude is not between -90 to 90 inclusive, or the longitude is not between -180 to 180 inclusive,
+    /// none of the values are set and the type will be QGeoCoordinateCoordinateType::InvalidCoordinate.
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        ffi::qgeocoordinate_init_2d(latitude, longitude)
+    }
+
+    /// Constructs a coordinate with the given latitude, longitude and altitude.
+    ///
+    /// If the latitude is not between -90 to 90 inclusive, or the longitude is not between -180 to 180 inclusive,
+    /// none of the values are set and the type will be QGeoCoordinateCoordinateType::InvalidCoordinate.
+    pub fn new_with_altitude(latitude: f64, longitude: f64, altitude: f64) -> Self {
+        ffi::qgeocoordinate_init_3d(latitude, longitude, altitude)
+    }
+}
+
+impl Clone for QGeoCoordinate {
+    /// Constructs a coordinate from the contents of other.
+    fn clone(&self) -> Self {
+        ffi::qgeocoordinate_clone(self)
+    }
+}
+
+impl Default for QGeoCoordinate {
+    /// Constructs a coordinate. The coordinate will be of type QGeoCoordinateCoordinateType::InvalidCoordinate
+    /// until either set_latitude() or set_longitude() is called.
+    fn default() -> Self {
+        ffi::qgeocoordinate_init_default()
+    }
+}
+
+impl Drop for QGeoCoordinate {
+    /// Destroys the coordinate object.
+    fn drop(&mut self) {
+        ffi::qgeocoordinate_drop(self)
+    }
+}
+
+impl PartialEq for QGeoCoordinate {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qgeocoordinate_eq(self, other)
+    }
+}
+
+impl QVariantValue for QGeoCoordinate {
+    fn can_convert(variant: &QVariant) -> bool {
+        ffi::can_convert_QGeoCoordinate(variant)
+    }
+
+    fn construct(value: &Self) -> QVariant {
+        ffi::construct_QGeoCoordinate(value)
+    }
+
+    fn value_or_default(variant: &QVariant) -> Self {
+        ffi::value_or_default_QGeoCoordinate(variant)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QGeoCoordinate {
+    type Id = type_id!("QGeoCoordinate");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,23 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qgeopositioninfo.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+#include <cstdint>
+
+// QGeoPositionInfo has 1 pointer
+// https://code.qt.io/cgit/qt/qtlocation.git/tree/src/positioning/qgeopositioninfo.h?h=5.15
+//
+// https://code.qt.io/cgit/qt/qtpositioning.git/tree/src/positioning/qgeopositioninfo.h?h=v6.2.4
+assert_alignment_and_size(QGeoPositionInfo, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QGeoPositionInfo>::value);
+static_assert(
+  !::std::is_trivially_copy_constructible<QGeoPositionInfo>::value);
+
+static_assert(!::std::is_trivially_destructible<QGeoPositionInfo>::value);
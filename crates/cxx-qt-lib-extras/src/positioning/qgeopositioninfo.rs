@@ -0,0 +1,160 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// Defines the attributes for positional information.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QGeoPositionInfoAttribute {
+        /// The bearing measured in degrees clockwise from true north to the direction of travel.
+        Direction,
+        /// The ground speed, in meters/sec.
+        GroundSpeed,
+        /// The vertical speed, in meters/sec.
+        VerticalSpeed,
+        /// The angle between the horizontal component of the magnetic field and true north, in degrees.
+        MagneticVariation,
+        /// The accuracy of the provided latitude-longitude value, in meters.
+        HorizontalAccuracy,
+        /// The accuracy of the provided altitude value, in meters.
+        VerticalAccuracy,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qgeopositioninfo.h");
+        type QGeoPositionInfo = super::QGeoPositionInfo;
+        include!("cxx-qt-lib-extras/qgeocoordinate.h");
+        type QGeoCoordinate = crate::QGeoCoordinate;
+        include!("cxx-qt-lib/qdatetime.h");
+        type QDateTime = cxx_qt_lib::QDateTime;
+
+        /// Returns the value of the specified attribute as a f64 value.
+        ///
+        /// Returns NaN if the value has not been set.
+        fn attribute(self: &QGeoPositionInfo, attribute: QGeoPositionInfoAttribute) -> f64;
+
+        /// Returns the coordinate for this position.
+        ///
+        /// Returns an invalid coordinate if no coordinate has been set.
+        fn coordinate(self: &QGeoPositionInfo) -> QGeoCoordinate;
+
+        /// Returns true if the specified attribute is present for this position info.
+        #[rust_name = "has_attribute"]
+        fn hasAttribute(self: &QGeoPositionInfo, attribute: QGeoPositionInfoAttribute) -> bool;
+
+        /// Returns true if the timestamp and coordinate values are both valid.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QGeoPositionInfo) -> bool;
+
+        /// Removes the specified attribute and its value.
+        #[rust_name = "remove_attribute"]
+        fn removeAttribute(self: &mut QGeoPositionInfo, attribute: QGeoPositionInfoAttribute);
+
+        /// Sets the value for attribute to value.
+        #[rust_name = "set_attribute"]
+        fn setAttribute(
+            self: &mut QGeoPositionInfo,
+            attribute: QGeoPositionInfoAttribute,
+            value: f64,
+        );
+
+        /// Sets the coordinate for this position to coordinate.
+        #[rust_name = "set_coordinate"]
+        fn setCoordinate(self: &mut QGeoPositionInfo, coordinate: &QGeoCoordinate);
+
+        /// Sets the date and time at which this position was reported to timestamp.
+        #[rust_name = "set_timestamp"]
+        fn setTimestamp(self: &mut QGeoPositionInfo, timestamp: &QDateTime);
+
+        /// Returns the date and time at which this position was reported, in UTC time.
+        ///
+        /// Returns an invalid QDateTime if no date/time value has been set.
+        fn timestamp(self: &QGeoPositionInfo) -> QDateTime;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+        type QGeoPositionInfoAttribute;
+
+        #[doc(hidden)]
+        #[rust_name = "qgeopositioninfo_drop"]
+        fn drop(info: &mut QGeoPositionInfo);
+
+        #[doc(hidden)]
+        #[rust_name = "qgeopositioninfo_init_default"]
+        fn construct() -> QGeoPositionInfo;
+
+        #[doc(hidden)]
+        #[rust_name = "qgeopositioninfo_init"]
+        fn construct(coordinate: &QGeoCoordinate, timestamp: &QDateTime) -> QGeoPositionInfo;
+
+        #[doc(hidden)]
+        #[rust_name = "qgeopositioninfo_clone"]
+        fn construct(info: &QGeoPositionInfo) -> QGeoPositionInfo;
+
+        #[doc(hidden)]
+        #[rust_name = "qgeopositioninfo_eq"]
+        fn operatorEq(a: &QGeoPositionInfo, b: &QGeoPositionInfo) -> bool;
+    }
+}
+
+use crate::QGeoCoordinate;
+use cxx_qt_lib::QDateTime;
+
+pub use ffi::QGeoPositionInfoAttribute;
+
+/// The QGeoPositionInfo class contains information gathered on a global position, direction and velocity at a particular point in time.
+#[repr(C)]
+pub struct QGeoPositionInfo {
+    _d: MaybeUninit<usize>,
+}
+
+impl QGeoPositionInfo {
+    /// Creates a QGeoPositionInfo for the given coordinate and timestamp.
+    pub fn new(coordinate: &QGeoCoordinate, timestamp: &QDateTime) -> Self {
+        ffi::qgeopositioninfo_init(coordinate, timestamp)
+    }
+}
+
+impl Clone for QGeoPositionInfo {
+    /// Creates a QGeoPositionInfo with the values of other.
+    fn clone(&self) -> Self {
+        ffi::qgeopositioninfo_clone(self)
+    }
+}
+
+impl Default for QGeoPositionInfo {
+    /// Creates an invalid QGeoPositionInfo object.
+    fn default() -> Self {
+        ffi::qgeopositioninfo_init_default()
+    }
+}
+
+impl Drop for QGeoPositionInfo {
+    /// Destroys a QGeoPositionInfo object.
+    fn drop(&mut self) {
+        ffi::qgeopositioninfo_drop(self)
+    }
+}
+
+impl PartialEq for QGeoPositionInfo {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qgeopositioninfo_eq(self, other)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QGeoPositionInfo {
+    type Id = type_id!("QGeoPositionInfo");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,36 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qgeopositioninfosource.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QStringList
+qgeopositioninfosourceAvailableSources()
+{
+  return QGeoPositionInfoSource::availableSources();
+}
+
+::std::unique_ptr<QGeoPositionInfoSource>
+qgeopositioninfosourceCreateDefaultSource()
+{
+  // The caller takes ownership of the source, which is null if there is no
+  // source available on the platform
+  return ::std::unique_ptr<QGeoPositionInfoSource>(
+    QGeoPositionInfoSource::createDefaultSource(nullptr));
+}
+
+::std::unique_ptr<QGeoPositionInfoSource>
+qgeopositioninfosourceCreateSource(const QString& sourceName)
+{
+  return ::std::unique_ptr<QGeoPositionInfoSource>(
+    QGeoPositionInfoSource::createSource(sourceName, nullptr));
+}
+
+}
+}
@@ -0,0 +1,197 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// The Error enumeration represents the errors which can occur.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QGeoPositionInfoSourceError {
+        /// The connection setup to the remote positioning backend failed because the application
+        /// lacked the required privileges.
+        AccessError = 0,
+        /// The remote positioning backend closed the connection, which happens for example in case
+        /// the user is switching location services to off.
+        ClosedError = 1,
+        /// An unidentified error occurred.
+        UnknownSourceError = 2,
+        /// No error has occurred.
+        NoError = 3,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qgeopositioninfosource.h");
+        /// An abstract base class for the distribution of positional updates, such as from a GPS receiver.
+        ///
+        /// A source for the platform is created with [QGeoPositionInfoSource::create_default_source].
+        #[qobject]
+        type QGeoPositionInfoSource;
+
+        /// This signal is emitted when an update becomes available.
+        ///
+        /// The updates are started with [QGeoPositionInfoSource::start_updates] or [QGeoPositionInfoSource::request_update].
+        #[qsignal]
+        #[cxx_name = "positionUpdated"]
+        fn position_updated(self: Pin<&mut QGeoPositionInfoSource>, update: &QGeoPositionInfo);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/connection.h");
+        #[namespace = "rust::cxxqt1"]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = cxx_qt_lib::QStringList;
+
+        include!("cxx-qt-lib-extras/qgeopositioninfo.h");
+        type QGeoPositionInfo = crate::QGeoPositionInfo;
+
+        /// Returns the type of error that last occurred.
+        fn error(self: &QGeoPositionInfoSource) -> QGeoPositionInfoSourceError;
+
+        /// Returns an update containing the last known position, or an invalid update if none is available.
+        ///
+        /// If from_satellite_positioning_methods_only is true, this returns the last known position
+        /// received from a satellite positioning method.
+        #[rust_name = "last_known_position"]
+        fn lastKnownPosition(
+            self: &QGeoPositionInfoSource,
+            from_satellite_positioning_methods_only: bool,
+        ) -> QGeoPositionInfo;
+
+        /// Returns the minimum time in milliseconds needed to retrieve a position update.
+        #[rust_name = "minimum_update_interval"]
+        fn minimumUpdateInterval(self: &QGeoPositionInfoSource) -> i32;
+
+        /// Attempts to get the current position and emit position_updated with this information.
+        ///
+        /// If the current position cannot be found within the given timeout (in milliseconds)
+        /// or if timeout is less than the value returned by minimum_update_interval(), an error is emitted.
+        /// If the timeout is zero, the timeout defaults to a reasonable timeout period as appropriate for the source.
+        #[rust_name = "request_update"]
+        fn requestUpdate(self: Pin<&mut QGeoPositionInfoSource>, timeout: i32);
+
+        /// Sets the interval in milliseconds at which position updates are requested.
+        ///
+        /// If the interval is less than the minimum_update_interval(), the minimum interval is used instead.
+        #[rust_name = "set_update_interval"]
+        fn setUpdateInterval(self: Pin<&mut QGeoPositionInfoSource>, msec: i32);
+
+        /// Returns the unique name of the position source implementation in use.
+        #[rust_name = "source_name"]
+        fn sourceName(self: &QGeoPositionInfoSource) -> QString;
+
+        /// Starts emitting updates at regular intervals as specified by set_update_interval().
+        #[rust_name = "start_updates"]
+        fn startUpdates(self: Pin<&mut QGeoPositionInfoSource>);
+
+        /// Stops emitting updates at regular intervals.
+        #[rust_name = "stop_updates"]
+        fn stopUpdates(self: Pin<&mut QGeoPositionInfoSource>);
+
+        /// Returns the interval in milliseconds at which position updates are requested.
+        #[rust_name = "update_interval"]
+        fn updateInterval(self: &QGeoPositionInfoSource) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QGeoPositionInfoSourceError;
+
+        #[doc(hidden)]
+        #[rust_name = "qgeopositioninfosource_available_sources"]
+        fn qgeopositioninfosourceAvailableSources() -> QStringList;
+
+        #[doc(hidden)]
+        #[rust_name = "qgeopositioninfosource_create_default_source"]
+        fn qgeopositioninfosourceCreateDefaultSource() -> UniquePtr<QGeoPositionInfoSource>;
+
+        #[doc(hidden)]
+        #[rust_name = "qgeopositioninfosource_create_source"]
+        fn qgeopositioninfosourceCreateSource(
+            source_name: &QString,
+        ) -> UniquePtr<QGeoPositionInfoSource>;
+
+        #[doc(hidden)]
+        #[rust_name = "qgeopositioninfosource_on_error_occurred"]
+        fn qgeopositioninfosourceOnErrorOccurred(
+            source: Pin<&mut QGeoPositionInfoSource>,
+            func: fn(
+                &mut QGeoPositionInfoSourceErrorHandler,
+                Pin<&mut QGeoPositionInfoSource>,
+                QGeoPositionInfoSourceError,
+            ),
+            arg: Box<QGeoPositionInfoSourceErrorHandler>,
+        ) -> QMetaObjectConnection;
+    }
+
+    extern "Rust" {
+        #[namespace = "rust::cxxqtlib1"]
+        type QGeoPositionInfoSourceErrorHandler;
+    }
+
+    // QGeoPositionInfoSource is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QGeoPositionInfoSource>::isRelocatable);
+    impl UniquePtr<QGeoPositionInfoSource> {}
+}
+
+use core::pin::Pin;
+use cxx_qt::QMetaObjectConnection;
+use cxx_qt_lib::{QString, QStringList};
+
+pub use ffi::{QGeoPositionInfoSource, QGeoPositionInfoSourceError};
+
+impl QGeoPositionInfoSource {
+    /// Returns a list of available source plugins, including the default system backend if available.
+    pub fn available_sources() -> QStringList {
+        ffi::qgeopositioninfosource_available_sources()
+    }
+
+    /// Creates and returns a position source with the default plugin of the platform.
+    ///
+    /// Returns a null pointer if the platform does not have any source plugin available.
+    pub fn create_default_source() -> cxx::UniquePtr<Self> {
+        ffi::qgeopositioninfosource_create_default_source()
+    }
+
+    /// Creates and returns a position source with the given name, from [QGeoPositionInfoSource::available_sources].
+    ///
+    /// Returns a null pointer if the source could not be found.
+    pub fn create_source(source_name: &QString) -> cxx::UniquePtr<Self> {
+        ffi::qgeopositioninfosource_create_source(source_name)
+    }
+
+    /// Connect a closure which is called when an error occurs in the source,
+    /// such as when the user has not granted access to the location.
+    ///
+    /// The returned [QMetaObjectConnection] can be used to disconnect the closure.
+    pub fn on_error_occurred<F>(self: Pin<&mut Self>, closure: F) -> QMetaObjectConnection
+    where
+        F: FnMut(Pin<&mut QGeoPositionInfoSource>, QGeoPositionInfoSourceError) + 'static,
+    {
+        fn func(
+            handler: &mut QGeoPositionInfoSourceErrorHandler,
+            source: Pin<&mut QGeoPositionInfoSource>,
+            error: QGeoPositionInfoSourceError,
+        ) {
+            (handler.inner)(source, error)
+        }
+        let arg = QGeoPositionInfoSourceErrorHandler {
+            inner: Box::new(closure),
+        };
+        ffi::qgeopositioninfosource_on_error_occurred(self, func, Box::new(arg))
+    }
+}
+
+#[doc(hidden)]
+pub struct QGeoPositionInfoSourceErrorHandler {
+    inner: SourceErrorFn,
+}
+
+type SourceErrorFn = Box<dyn FnMut(Pin<&mut QGeoPositionInfoSource>, QGeoPositionInfoSourceError)>;
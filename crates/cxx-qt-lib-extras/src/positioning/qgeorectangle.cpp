@@ -0,0 +1,36 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qgeorectangle.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+#include <cstdint>
+
+// QGeoRectangle has no members of its own, QGeoShape has 1 pointer
+// https://code.qt.io/cgit/qt/qtlocation.git/tree/src/positioning/qgeoshape.h?h=5.15
+//
+// https://code.qt.io/cgit/qt/qtpositioning.git/tree/src/positioning/qgeoshape.h?h=v6.2.4
+assert_alignment_and_size(QGeoRectangle, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QGeoRectangle>::value);
+static_assert(!::std::is_trivially_copy_constructible<QGeoRectangle>::value);
+
+static_assert(!::std::is_trivially_destructible<QGeoRectangle>::value);
+
+namespace rust {
+namespace cxxqtlib1 {
+namespace qvariant {
+
+bool
+qvariantCanConvertQGeoRectangle(const QVariant& variant)
+{
+  return variant.canConvert<QGeoRectangle>();
+}
+
+}
+}
+}
@@ -0,0 +1,266 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use cxx_qt_lib::{QVariant, QVariantValue};
+use std::mem::MaybeUninit;
+
+use crate::QGeoCoordinate;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qgeorectangle.h");
+        type QGeoRectangle = super::QGeoRectangle;
+        include!("cxx-qt-lib-extras/qgeocoordinate.h");
+        type QGeoCoordinate = crate::QGeoCoordinate;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+
+        /// Returns the bottom left coordinate of this geo rectangle.
+        #[rust_name = "bottom_left"]
+        fn bottomLeft(self: &QGeoRectangle) -> QGeoCoordinate;
+
+        /// Returns the bottom right coordinate of this geo rectangle.
+        #[rust_name = "bottom_right"]
+        fn bottomRight(self: &QGeoRectangle) -> QGeoCoordinate;
+
+        /// Returns the center of this geo rectangle.
+        fn center(self: &QGeoRectangle) -> QGeoCoordinate;
+
+        /// Returns whether the coordinate coordinate is contained within this geo rectangle.
+        fn contains(self: &QGeoRectangle, coordinate: &QGeoCoordinate) -> bool;
+
+        /// Returns whether the geo rectangle rectangle is contained within this geo rectangle.
+        #[cxx_name = "contains"]
+        #[rust_name = "contains_rectangle"]
+        fn contains(self: &QGeoRectangle, rectangle: &QGeoRectangle) -> bool;
+
+        /// Extends the geo rectangle to also cover the coordinate coordinate.
+        #[rust_name = "extend_rectangle"]
+        fn extendRectangle(self: &mut QGeoRectangle, coordinate: &QGeoCoordinate);
+
+        /// Returns the height of this geo rectangle in degrees.
+        ///
+        /// The return value is undefined if this geo rectangle is invalid.
+        fn height(self: &QGeoRectangle) -> f64;
+
+        /// Returns whether the geo rectangle rectangle intersects this geo rectangle.
+        ///
+        /// If the top or bottom edges of both geo rectangles are at one of the poles
+        /// the geo rectangles are considered to be intersecting, since the longitude is irrelevant when the edges are at the pole.
+        fn intersects(self: &QGeoRectangle, rectangle: &QGeoRectangle) -> bool;
+
+        /// Returns whether this geo shape is empty.
+        ///
+        /// An empty geo shape is a region which has a geometrical area of 0.
+        #[rust_name = "is_empty"]
+        fn isEmpty(self: &QGeoRectangle) -> bool;
+
+        /// Returns whether this geo shape is valid.
+        ///
+        /// A geo shape is considered to be invalid if some of the data that is required to unambiguously describe the geo shape
+        /// has not been set or has been set to an unsuitable value.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QGeoRectangle) -> bool;
+
+        /// Sets the bottom left coordinate of this geo rectangle to bottom_left.
+        #[rust_name = "set_bottom_left"]
+        fn setBottomLeft(self: &mut QGeoRectangle, bottom_left: &QGeoCoordinate);
+
+        /// Sets the bottom right coordinate of this geo rectangle to bottom_right.
+        #[rust_name = "set_bottom_right"]
+        fn setBottomRight(self: &mut QGeoRectangle, bottom_right: &QGeoCoordinate);
+
+        /// Sets the center of this geo rectangle to center.
+        ///
+        /// If this causes the geo rectangle to cross one of the poles the height of the geo rectangle will be truncated
+        /// such that the geo rectangle only extends up to the pole.
+        /// The center of the geo rectangle will be unchanged, and the height will be adjusted such that the center point
+        /// is at the center of the truncated geo rectangle.
+        #[rust_name = "set_center"]
+        fn setCenter(self: &mut QGeoRectangle, center: &QGeoCoordinate);
+
+        /// Sets the height of this geo rectangle in degrees to degrees_height.
+        ///
+        /// If degrees_height is less than 0.0 or if this geo rectangle is invalid this function does nothing.
+        /// To set up the values of an invalid geo rectangle based on the center, width and height you should use set_center() first
+        /// in order to make the geo rectangle valid.
+        #[rust_name = "set_height"]
+        fn setHeight(self: &mut QGeoRectangle, degrees_height: f64);
+
+        /// Sets the top left coordinate of this geo rectangle to top_left.
+        #[rust_name = "set_top_left"]
+        fn setTopLeft(self: &mut QGeoRectangle, top_left: &QGeoCoordinate);
+
+        /// Sets the top right coordinate of this geo rectangle to top_right.
+        #[rust_name = "set_top_right"]
+        fn setTopRight(self: &mut QGeoRectangle, top_right: &QGeoCoordinate);
+
+        /// Sets the width of this geo rectangle in degrees to degrees_width.
+        ///
+        /// If degrees_width is less than 0.0 or if this geo rectangle is invalid this function does nothing.
+        /// To set up the values of an invalid geo rectangle based on the center, width and height you should use set_center() first
+        /// in order to make the geo rectangle valid.
+        #[rust_name = "set_width"]
+        fn setWidth(self: &mut QGeoRectangle, degrees_width: f64);
+
+        /// Returns the top left coordinate of this geo rectangle.
+        #[rust_name = "top_left"]
+        fn topLeft(self: &QGeoRectangle) -> QGeoCoordinate;
+
+        /// Returns the top right coordinate of this geo rectangle.
+        #[rust_name = "top_right"]
+        fn topRight(self: &QGeoRectangle) -> QGeoCoordinate;
+
+        /// Translates this geo rectangle by degrees_latitude northwards and degrees_longitude eastwards.
+        ///
+        /// Negative values of degrees_latitude and degrees_longitude correspond to southward and westward translation respectively.
+        fn translate(self: &mut QGeoRectangle, degrees_latitude: f64, degrees_longitude: f64);
+
+        /// Returns a copy of this geo rectangle translated by degrees_latitude northwards and degrees_longitude eastwards.
+        ///
+        /// Negative values of degrees_latitude and degrees_longitude correspond to southward and westward translation respectively.
+        fn translated(
+            self: &QGeoRectangle,
+            degrees_latitude: f64,
+            degrees_longitude: f64,
+        ) -> QGeoRectangle;
+
+        /// Returns the smallest geo rectangle which contains both this geo rectangle and rectangle.
+        ///
+        /// If the centers of the two geo rectangles are separated by exactly 180.0 degrees
+        /// then the width is set to 360.0 degrees with the west longitude set to -180.0 degrees.
+        /// This is done to ensure that the result is independent of the order of the operands.
+        fn united(self: &QGeoRectangle, rectangle: &QGeoRectangle) -> QGeoRectangle;
+
+        /// Returns the width of this geo rectangle in degrees.
+        ///
+        /// The return value is undefined if this geo rectangle is invalid.
+        fn width(self: &QGeoRectangle) -> f64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qgeorectangle_drop"]
+        fn drop(rectangle: &mut QGeoRectangle);
+
+        #[doc(hidden)]
+        #[rust_name = "qgeorectangle_init_default"]
+        fn construct() -> QGeoRectangle;
+
+        #[doc(hidden)]
+        #[rust_name = "qgeorectangle_init_center"]
+        fn construct(
+            center: &QGeoCoordinate,
+            degrees_width: f64,
+            degrees_height: f64,
+        ) -> QGeoRectangle;
+
+        #[doc(hidden)]
+        #[rust_name = "qgeorectangle_init_corners"]
+        fn construct(top_left: &QGeoCoordinate, bottom_right: &QGeoCoordinate) -> QGeoRectangle;
+
+        #[doc(hidden)]
+        #[rust_name = "qgeorectangle_clone"]
+        fn construct(rectangle: &QGeoRectangle) -> QGeoRectangle;
+
+        #[doc(hidden)]
+        #[rust_name = "qgeorectangle_eq"]
+        fn operatorEq(a: &QGeoRectangle, b: &QGeoRectangle) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvariant"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "can_convert_QGeoRectangle"]
+        fn qvariantCanConvertQGeoRectangle(variant: &QVariant) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "construct_QGeoRectangle"]
+        fn qvariantConstruct(value: &QGeoRectangle) -> QVariant;
+
+        #[doc(hidden)]
+        #[rust_name = "value_or_default_QGeoRectangle"]
+        fn qvariantValueOrDefault(variant: &QVariant) -> QGeoRectangle;
+    }
+}
+
+/// The QGeoRectangle class defines a rectangular geographic area.
+///
+/// A QGeoRectangle stored in a [QVariant] can be returned from the data of a model,
+/// so that delegates of a QML `MapItemView` can bind a `MapRectangle` to the role.
+#[repr(C)]
+pub struct QGeoRectangle {
+    _d: MaybeUninit<usize>,
+}
+
+impl QGeoRectangle {
+    /// Constructs a new geo rectangle centered at center with a width in longitude of degrees_width
+    /// and a height in latitude of degrees_height.
+    ///
+    /// If degrees_height would take the geo rectangle beyond one of the poles, the height of the geo rectangle
+    /// will be truncated such that the geo rectangle only extends up to the pole.
+    pub fn from_center(center: &QGeoCoordinate, degrees_width: f64, degrees_height: f64) -> Self {
+        ffi::qgeorectangle_init_center(center, degrees_width, degrees_height)
+    }
+
+    /// Constructs a new geo rectangle with a top left coordinate top_left and a bottom right coordinate bottom_right.
+    pub fn from_corners(top_left: &QGeoCoordinate, bottom_right: &QGeoCoordinate) -> Self {
+        ffi::qgeorectangle_init_corners(top_left, bottom_right)
+    }
+}
+
+impl Clone for QGeoRectangle {
+    /// Constructs a geo rectangle from the contents of other.
+    fn clone(&self) -> Self {
+        ffi::qgeorectangle_clone(self)
+    }
+}
+
+impl Default for QGeoRectangle {
+    /// Constructs a new, invalid geo rectangle.
+    fn default() -> Self {
+        ffi::qgeorectangle_init_default()
+    }
+}
+
+impl Drop for QGeoRectangle {
+    /// Destroys the geo rectangle.
+    fn drop(&mut self) {
+        ffi::qgeorectangle_drop(self)
+    }
+}
+
+impl PartialEq for QGeoRectangle {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qgeorectangle_eq(self, other)
+    }
+}
+
+impl QVariantValue for QGeoRectangle {
+    fn can_convert(variant: &QVariant) -> bool {
+        ffi::can_convert_QGeoRectangle(variant)
+    }
+
+    fn construct(value: &Self) -> QVariant {
+        ffi::construct_QGeoRectangle(value)
+    }
+
+    fn value_or_default(variant: &QVariant) -> Self {
+        ffi::value_or_default_QGeoRectangle(variant)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QGeoRectangle {
+    type Id = type_id!("QGeoRectangle");
+    type Kind = cxx::kind::Trivial;
+}
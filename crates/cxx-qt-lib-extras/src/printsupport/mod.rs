@@ -0,0 +1,7 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qprinter;
+pub use qprinter::{QPrinter, QPrinterColorMode, QPrinterMode, QPrinterOutputFormat};
@@ -0,0 +1,32 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qprinter.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QPrinter>
+qprinterNew(QPrinterMode mode)
+{
+  return ::std::make_unique<QPrinter>(mode);
+}
+
+::std::unique_ptr<QPainter>
+qprinterNewPainter(QPrinter& printer)
+{
+  return ::std::make_unique<QPainter>(&printer);
+}
+
+bool
+qprinterSetPageSize(QPrinter& printer, QPageSizeId pageSizeId)
+{
+  return printer.setPageSize(QPageSize(pageSizeId));
+}
+
+}
+}
@@ -0,0 +1,206 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::UniquePtr;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum type is used to indicate whether QPrinter should print in color or not.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QPrinterColorMode {
+        /// Print in grayscale on a color printer.
+        GrayScale = 0,
+        /// Print in color if available, otherwise in grayscale.
+        Color = 1,
+    }
+
+    /// This enum describes the mode the printer should work in,
+    /// which determines the initial resolution of the printer.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QPrinterMode {
+        /// The resolution is set to match the resolution of the screen.
+        ScreenResolution = 0,
+        /// This is a deprecated value which behaves like HighResolution.
+        PrinterResolution = 1,
+        /// The resolution is set to match the resolution of the printer.
+        HighResolution = 2,
+    }
+
+    /// This enum is used by QPrinter to describe the format QPrinter should use for its output.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QPrinterOutputFormat {
+        /// QPrinter will print output using a method defined by the platform it is running on.
+        NativeFormat = 0,
+        /// QPrinter will generate its output as a searchable PDF file.
+        PdfFormat = 1,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmarginsf.h");
+        type QMarginsF = cxx_qt_lib::QMarginsF;
+        include!("cxx-qt-lib/qpainter.h");
+        type QPainter = cxx_qt_lib::QPainter;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-lib-extras/qprinter.h");
+        /// The QPrinter class is a paint device that paints on a printer.
+        ///
+        /// Use [QPrinter::paint] to draw on its pages with the same [QPainter] code which is used on screen.
+        type QPrinter;
+
+        /// Aborts the current print run, returns true if the print run was successfully aborted.
+        fn abort(self: Pin<&mut QPrinter>) -> bool;
+
+        /// Returns the current color mode.
+        #[rust_name = "color_mode"]
+        fn colorMode(self: &QPrinter) -> QPrinterColorMode;
+
+        /// Returns the number of copies that will be printed.
+        #[rust_name = "copy_count"]
+        fn copyCount(self: &QPrinter) -> i32;
+
+        /// Returns the document name.
+        #[rust_name = "doc_name"]
+        fn docName(self: &QPrinter) -> QString;
+
+        /// Returns true if the printer is currently valid, for example if the printer which was set exists.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QPrinter) -> bool;
+
+        /// Tells the printer to eject the current page and to continue printing on a new page,
+        /// returns true if this was successful.
+        #[rust_name = "new_page"]
+        fn newPage(self: Pin<&mut QPrinter>) -> bool;
+
+        /// Returns the name of the output file, which is empty if the output is sent to a printer.
+        #[rust_name = "output_file_name"]
+        fn outputFileName(self: &QPrinter) -> QString;
+
+        /// Returns the output format for this printer.
+        #[rust_name = "output_format"]
+        fn outputFormat(self: &QPrinter) -> QPrinterOutputFormat;
+
+        /// Returns the printer name, which is empty if the default printer is used.
+        #[rust_name = "printer_name"]
+        fn printerName(self: &QPrinter) -> QString;
+
+        /// Returns the current assumed resolution of the printer, as set by [QPrinter::set_resolution] or by the printer driver.
+        fn resolution(self: &QPrinter) -> i32;
+
+        /// Sets the printer's color mode to newColorMode, which can be either Color or GrayScale.
+        #[rust_name = "set_color_mode"]
+        fn setColorMode(self: Pin<&mut QPrinter>, color_mode: QPrinterColorMode);
+
+        /// Sets the number of copies to be printed to count.
+        #[rust_name = "set_copy_count"]
+        fn setCopyCount(self: Pin<&mut QPrinter>, count: i32);
+
+        /// Sets the document name to name, which may be shown in the print queue.
+        #[rust_name = "set_doc_name"]
+        fn setDocName(self: Pin<&mut QPrinter>, name: &QString);
+
+        /// Sets the name of the output file, setting the file name to an empty string disables printing to a file.
+        ///
+        /// Setting a file name ending in `.pdf` also sets the output format to [QPrinterOutputFormat::PdfFormat].
+        #[rust_name = "set_output_file_name"]
+        fn setOutputFileName(self: Pin<&mut QPrinter>, file_name: &QString);
+
+        /// Sets the output format for this printer to format.
+        #[rust_name = "set_output_format"]
+        fn setOutputFormat(self: Pin<&mut QPrinter>, format: QPrinterOutputFormat);
+
+        /// Sets the page margins in the given units, returns true if the margins were set.
+        #[rust_name = "set_page_margins"]
+        fn setPageMargins(
+            self: Pin<&mut QPrinter>,
+            margins: &QMarginsF,
+            units: QPageLayoutUnit,
+        ) -> bool;
+
+        /// Sets the page orientation, returns true if the orientation was set.
+        #[rust_name = "set_page_orientation"]
+        fn setPageOrientation(
+            self: Pin<&mut QPrinter>,
+            orientation: QPageLayoutOrientation,
+        ) -> bool;
+
+        /// Sets the printer name to name, an empty name selects the default printer.
+        #[rust_name = "set_printer_name"]
+        fn setPrinterName(self: Pin<&mut QPrinter>, name: &QString);
+
+        /// Requests that the printer prints at resolution DPI, which is the unit of the coordinates of the [QPainter].
+        #[rust_name = "set_resolution"]
+        fn setResolution(self: Pin<&mut QPrinter>, dpi: i32);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QPrinterColorMode;
+        type QPrinterMode;
+        type QPrinterOutputFormat;
+
+        include!("cxx-qt-lib-extras/qpagedpaintdevice.h");
+        type QPageLayoutOrientation = crate::QPageLayoutOrientation;
+        type QPageLayoutUnit = crate::QPageLayoutUnit;
+        type QPageSizeId = crate::QPageSizeId;
+
+        #[doc(hidden)]
+        #[rust_name = "qprinter_new"]
+        fn qprinterNew(mode: QPrinterMode) -> UniquePtr<QPrinter>;
+
+        #[doc(hidden)]
+        #[rust_name = "qprinter_new_painter"]
+        fn qprinterNewPainter(printer: Pin<&mut QPrinter>) -> UniquePtr<QPainter>;
+
+        #[doc(hidden)]
+        #[rust_name = "qprinter_set_page_size"]
+        fn qprinterSetPageSize(printer: Pin<&mut QPrinter>, page_size_id: QPageSizeId) -> bool;
+    }
+
+    impl UniquePtr<QPrinter> {}
+}
+
+use crate::QPageSizeId;
+use core::pin::Pin;
+use cxx_qt_lib::QPainter;
+
+pub use ffi::{QPrinter, QPrinterColorMode, QPrinterMode, QPrinterOutputFormat};
+
+impl QPrinter {
+    /// Creates a new printer object with the given mode, which prints to the default printer.
+    pub fn new(mode: QPrinterMode) -> UniquePtr<Self> {
+        ffi::qprinter_new(mode)
+    }
+
+    /// Paint on the pages of the print job with a [QPainter], which is active while the closure is called.
+    ///
+    /// The closure is also given the printer, so that it can call [QPrinter::new_page] to continue on a new page.
+    /// The print job is sent when the closure returns.
+    /// Returns false without calling the closure if painting could not begin, for example if the printer is not valid.
+    pub fn paint<F>(mut self: Pin<&mut Self>, f: F) -> bool
+    where
+        F: FnOnce(Pin<&mut QPainter>, Pin<&mut Self>),
+    {
+        let mut painter = ffi::qprinter_new_painter(self.as_mut());
+        if !painter.is_active() {
+            return false;
+        }
+
+        f(painter.pin_mut(), self);
+        true
+    }
+
+    /// Sets the page size to one of the standard page sizes, returns true if the page size was set.
+    pub fn set_page_size(self: Pin<&mut Self>, page_size_id: QPageSizeId) -> bool {
+        ffi::qprinter_set_page_size(self, page_size_id)
+    }
+}
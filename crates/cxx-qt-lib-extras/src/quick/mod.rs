@@ -0,0 +1,26 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qquickframebufferobject;
+pub use qquickframebufferobject::{
+    FramebufferObjectRenderer, QQuickFramebufferObject, QQuickFramebufferObjectRenderer,
+};
+
+mod qquickimageprovider;
+pub use qquickimageprovider::QQmlEngineImageProviderExt;
+
+mod qquickitem;
+pub use qquickitem::{QQuickItem, QQuickItemFlag};
+
+mod qquicknativetextureitem;
+pub use qquicknativetextureitem::QQuickNativeTextureItem;
+
+mod qquicktextdocument;
+pub use qquicktextdocument::QQuickTextDocument;
+
+mod qquickwindow;
+pub use qquickwindow::{
+    QQuickCloseEvent, QQuickWindow, QSGRendererInterfaceGraphicsApi, QSGRendererInterfaceResource,
+};
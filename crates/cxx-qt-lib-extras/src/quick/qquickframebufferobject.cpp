@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qquickframebufferobject.h"
+
+#include <QtCore/QByteArray>
+#include <QtGui/QOpenGLContext>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+const void*
+qquickframebufferobjectrendererGetProcAddress(::rust::Str name)
+{
+  QOpenGLContext* context = QOpenGLContext::currentContext();
+  if (context == nullptr) {
+    return nullptr;
+  }
+
+  const QByteArray procName(name.data(), static_cast<int>(name.size()));
+  return reinterpret_cast<const void*>(context->getProcAddress(procName));
+}
+
+}
+}
@@ -0,0 +1,202 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+        type c_void = cxx_qt_lib::c_void;
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qquickframebufferobject.h");
+        /// The QQuickFramebufferObject class is a convenience class for integrating OpenGL rendering
+        /// using a framebuffer object with Qt Quick.
+        ///
+        /// A QObject with this as its `#[base]` overrides `createRenderer` to return a
+        /// [QQuickFramebufferObjectRenderer] which renders with OpenGL from Rust,
+        /// for example with the [glow](https://crates.io/crates/glow) crate.
+        ///
+        /// ```ignore
+        /// #[cxx_qt::bridge]
+        /// mod qobject {
+        ///     unsafe extern "C++" {
+        ///         include!("cxx-qt-lib-extras/qquickframebufferobject.h");
+        ///         type QQuickFramebufferObject = cxx_qt_lib_extras::QQuickFramebufferObject;
+        ///     }
+        ///
+        ///     #[namespace = "rust::cxxqtlib1"]
+        ///     unsafe extern "C++" {
+        ///         type QQuickFramebufferObjectRenderer = cxx_qt_lib_extras::QQuickFramebufferObjectRenderer;
+        ///     }
+        ///
+        ///     unsafe extern "RustQt" {
+        ///         #[qml_element]
+        ///         #[qobject]
+        ///         #[base = QQuickFramebufferObject]
+        ///         type Triangle = super::TriangleRust;
+        ///
+        ///         #[cxx_override]
+        ///         #[rust_name = "create_renderer"]
+        ///         fn createRenderer(self: &Triangle) -> *mut QQuickFramebufferObjectRenderer;
+        ///     }
+        /// }
+        ///
+        /// impl qobject::Triangle {
+        ///     fn create_renderer(&self) -> *mut QQuickFramebufferObjectRenderer {
+        ///         QQuickFramebufferObjectRenderer::new(TriangleRenderer::default())
+        ///     }
+        /// }
+        /// ```
+        ///
+        /// Note that Qt Quick must use OpenGL as its graphics API, with Qt 6 this can be chosen
+        /// by setting the `QSG_RHI_BACKEND` environment variable to `opengl`.
+        #[qobject]
+        type QQuickFramebufferObject;
+
+        /// This signal is emitted when the framebuffer object is mirrored vertically or no longer mirrored.
+        #[qsignal]
+        #[rust_name = "mirror_vertically_changed"]
+        fn mirrorVerticallyChanged(self: Pin<&mut QQuickFramebufferObject>, mirror: bool);
+
+        /// This signal is emitted when the size of the framebuffer object follows the size of the item or no longer follows it.
+        #[qsignal]
+        #[rust_name = "texture_follows_item_size_changed"]
+        fn textureFollowsItemSizeChanged(self: Pin<&mut QQuickFramebufferObject>, follows: bool);
+    }
+
+    unsafe extern "C++" {
+        /// Returns true if the framebuffer object is mirrored vertically when it is displayed.
+        #[rust_name = "mirror_vertically"]
+        fn mirrorVertically(self: &QQuickFramebufferObject) -> bool;
+
+        /// Sets whether the framebuffer object is mirrored vertically when it is displayed,
+        /// this is needed when the rendering does not use the bottom-left origin of OpenGL.
+        #[rust_name = "set_mirror_vertically"]
+        fn setMirrorVertically(self: Pin<&mut QQuickFramebufferObject>, enable: bool);
+
+        /// Sets whether the size of the framebuffer object follows the size of the item.
+        #[rust_name = "set_texture_follows_item_size"]
+        fn setTextureFollowsItemSize(self: Pin<&mut QQuickFramebufferObject>, follows: bool);
+
+        /// Returns true if the size of the framebuffer object follows the size of the item.
+        #[rust_name = "texture_follows_item_size"]
+        fn textureFollowsItemSize(self: &QQuickFramebufferObject) -> bool;
+
+        /// Schedules the item to be rendered again, which synchronizes and renders the renderer.
+        fn update(self: Pin<&mut QQuickFramebufferObject>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        /// The renderer of a [QQuickFramebufferObject], which renders into the framebuffer object
+        /// on the rendering thread of Qt Quick.
+        ///
+        /// The renderer is created with [QQuickFramebufferObjectRenderer::new] from a type which implements
+        /// [FramebufferObjectRenderer], and is owned and deleted by the item.
+        type QQuickFramebufferObjectRenderer;
+
+        /// Invalidates the framebuffer object, so that it is created again before the next render.
+        #[rust_name = "invalidate_framebuffer_object"]
+        fn invalidateFramebufferObject(self: Pin<&mut QQuickFramebufferObjectRenderer>);
+
+        /// Schedules the item to be rendered again,
+        /// this can be called from [FramebufferObjectRenderer::render] to render continuously.
+        fn update(self: Pin<&mut QQuickFramebufferObjectRenderer>);
+
+        #[doc(hidden)]
+        #[rust_name = "qquickframebufferobjectrenderer_get_proc_address"]
+        fn qquickframebufferobjectrendererGetProcAddress(name: &str) -> *const c_void;
+
+        #[doc(hidden)]
+        #[rust_name = "qquickframebufferobjectrenderer_new"]
+        fn qquickframebufferobjectrendererNew(
+            render: fn(
+                &mut QQuickFramebufferObjectRendererHandler,
+                Pin<&mut QQuickFramebufferObjectRenderer>,
+            ),
+            synchronize: fn(
+                &mut QQuickFramebufferObjectRendererHandler,
+                Pin<&mut QQuickFramebufferObjectRenderer>,
+                Pin<&mut QQuickFramebufferObject>,
+            ),
+            arg: Box<QQuickFramebufferObjectRendererHandler>,
+        ) -> *mut QQuickFramebufferObjectRenderer;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        type QQuickFramebufferObjectRendererHandler;
+    }
+}
+
+use core::pin::Pin;
+
+pub use ffi::{QQuickFramebufferObject, QQuickFramebufferObjectRenderer};
+
+/// The rendering of a [QQuickFramebufferObject] which is implemented in Rust.
+///
+/// Qt Quick may render on a separate thread, so the renderer must not access the item
+/// other than in [FramebufferObjectRenderer::synchronize], while the GUI thread is blocked.
+pub trait FramebufferObjectRenderer: 'static {
+    /// Renders into the framebuffer object, which is bound and has a current OpenGL context.
+    ///
+    /// OpenGL functions can be loaded with [QQuickFramebufferObjectRenderer::get_proc_address].
+    fn render(&mut self, renderer: Pin<&mut QQuickFramebufferObjectRenderer>);
+
+    /// Copies the state of the item which is needed for rendering into the renderer,
+    /// this is called before [FramebufferObjectRenderer::render] while the GUI thread is blocked.
+    ///
+    /// The item can be cast to the Rust QObject with `cxx_qt::Downcast::downcast_pin`.
+    fn synchronize(
+        &mut self,
+        _renderer: Pin<&mut QQuickFramebufferObjectRenderer>,
+        _item: Pin<&mut QQuickFramebufferObject>,
+    ) {
+    }
+}
+
+impl QQuickFramebufferObjectRenderer {
+    /// Creates a renderer which calls the given [FramebufferObjectRenderer],
+    /// this is returned from the `createRenderer` override of a [QQuickFramebufferObject].
+    ///
+    /// The item takes ownership of the renderer and deletes it on the rendering thread.
+    pub fn new<R>(renderer: R) -> *mut Self
+    where
+        R: FramebufferObjectRenderer,
+    {
+        fn render(
+            handler: &mut QQuickFramebufferObjectRendererHandler,
+            renderer: Pin<&mut QQuickFramebufferObjectRenderer>,
+        ) {
+            handler.inner.render(renderer)
+        }
+        fn synchronize(
+            handler: &mut QQuickFramebufferObjectRendererHandler,
+            renderer: Pin<&mut QQuickFramebufferObjectRenderer>,
+            item: Pin<&mut QQuickFramebufferObject>,
+        ) {
+            handler.inner.synchronize(renderer, item)
+        }
+        let arg = QQuickFramebufferObjectRendererHandler {
+            inner: Box::new(renderer),
+        };
+        ffi::qquickframebufferobjectrenderer_new(render, synchronize, Box::new(arg))
+    }
+
+    /// Returns the address of the OpenGL function with the given name in the current OpenGL context,
+    /// or a null pointer if there is no current context or the function is not available.
+    ///
+    /// This is used to load OpenGL functions while rendering, for example with `glow::Context::from_loader_function`.
+    pub fn get_proc_address(name: &str) -> *const core::ffi::c_void {
+        ffi::qquickframebufferobjectrenderer_get_proc_address(name).cast()
+    }
+}
+
+#[doc(hidden)]
+pub struct QQuickFramebufferObjectRendererHandler {
+    inner: Box<dyn FramebufferObjectRenderer>,
+}
@@ -0,0 +1,77 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qimage.h");
+        type QImage = cxx_qt_lib::QImage;
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = cxx_qt_lib::QQmlEngine;
+        include!("cxx-qt-lib/qsize.h");
+        type QSize = cxx_qt_lib::QSize;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qquickimageprovider.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_add_image_provider"]
+        fn qqmlengineAddImageProvider(
+            engine: Pin<&mut QQmlEngine>,
+            id: &QString,
+            func: fn(&QQuickImageProviderHandler, &QString, &QSize) -> QImage,
+            arg: Box<QQuickImageProviderHandler>,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        type QQuickImageProviderHandler;
+    }
+}
+
+use core::pin::Pin;
+use cxx_qt_lib::{QImage, QQmlEngine, QSize, QString};
+
+/// Extension trait for registering Qt Quick image providers with a [QQmlEngine].
+pub trait QQmlEngineImageProviderExt {
+    /// Add an image provider with the given `id` to the engine,
+    /// which is used for images with an `image://id/` URL in QML.
+    ///
+    /// The provider is called with the rest of the URL and the requested size of the image,
+    /// which is invalid when the image has no `sourceSize`.
+    /// Any image provider which was previously added with the same `id` is replaced.
+    ///
+    /// Note that the provider may be called from the thread which loads images asynchronously.
+    fn add_image_provider<F>(self: Pin<&mut Self>, id: &QString, provider: F)
+    where
+        F: Fn(&QString, &QSize) -> QImage + Send + Sync + 'static;
+}
+
+impl QQmlEngineImageProviderExt for QQmlEngine {
+    fn add_image_provider<F>(self: Pin<&mut Self>, id: &QString, provider: F)
+    where
+        F: Fn(&QString, &QSize) -> QImage + Send + Sync + 'static,
+    {
+        fn func(handler: &QQuickImageProviderHandler, id: &QString, size: &QSize) -> QImage {
+            (handler.inner)(id, size)
+        }
+        let arg = QQuickImageProviderHandler {
+            inner: Box::new(provider),
+        };
+        ffi::qqmlengine_add_image_provider(self, id, func, Box::new(arg));
+    }
+}
+
+#[doc(hidden)]
+pub struct QQuickImageProviderHandler {
+    inner: ImageProviderFn,
+}
+
+type ImageProviderFn = Box<dyn Fn(&QString, &QSize) -> QImage + Send + Sync>;
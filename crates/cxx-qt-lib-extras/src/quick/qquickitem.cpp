@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qquickitem.h"
+
+#include <QtQml/QQmlContext>
+#include <QtQml/QQmlProperty>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qquickitemSetAccessibleProperty(QQuickItem& item,
+                                const QString& name,
+                                const QVariant& value)
+{
+  // The Accessible attached type is found through the imports of the context
+  // of the item, which exists when the item was created by a QML file
+  QQmlProperty property(
+    &item, QStringLiteral("Accessible.") + name, qmlContext(&item));
+  return property.write(value);
+}
+
+}
+}
@@ -0,0 +1,332 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// Flags which control the behaviour of a [QQuickItem].
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QQuickItemFlag {
+        /// Indicates this item should visually clip its children
+        /// so that they are rendered only within the boundaries of this item.
+        ItemClipsChildrenToShape = 0x01,
+        /// Indicates the item supports text input methods.
+        ItemAcceptsInputMethod = 0x02,
+        /// Indicates the item is a focus scope.
+        ItemIsFocusScope = 0x04,
+        /// Indicates the item has visual content and should be rendered by the scene graph,
+        /// which is needed for [QQuickItem::update] to cause `updatePaintNode` to be called.
+        ItemHasContents = 0x08,
+        /// Indicates the item accepts drag and drop events.
+        ItemAcceptsDrops = 0x10,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = cxx_qt_lib::QPointF;
+        include!("cxx-qt-lib/qrectf.h");
+        type QRectF = cxx_qt_lib::QRectF;
+        include!("cxx-qt-lib/qsizef.h");
+        type QSizeF = cxx_qt_lib::QSizeF;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+        include!("cxx-qt-lib-extras/qquickwindow.h");
+        type QQuickWindow = crate::QQuickWindow;
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qquickitem.h");
+        /// The QQuickItem class provides the most basic of all visual items in Qt Quick.
+        ///
+        /// A QObject with `#[base = QQuickItem]`, or a base which inherits QQuickItem,
+        /// can manage its own geometry from Rust by upcasting to a QQuickItem.
+        ///
+        /// ```ignore
+        /// let mut item = self.upcast_pin();
+        /// item.as_mut().set_size(&QSizeF::new(200.0, 100.0));
+        /// item.as_mut().update();
+        /// ```
+        ///
+        /// To relayout its content when it is resized, for example by a QML layout,
+        /// the item can override `geometryChange`, which is called `geometryChanged` with Qt 5,
+        /// and call the implementation of the base class so that the geometry signals are emitted.
+        ///
+        /// ```ignore
+        /// unsafe extern "RustQt" {
+        ///     #[qobject]
+        ///     #[base = QQuickItem]
+        ///     type Chart = super::ChartRust;
+        ///
+        ///     #[cxx_override]
+        ///     #[rust_name = "geometry_change"]
+        ///     fn geometryChange(self: Pin<&mut Chart>, new_geometry: &QRectF, old_geometry: &QRectF);
+        ///
+        ///     #[inherit]
+        ///     #[cxx_name = "geometryChange"]
+        ///     fn geometry_change_super(self: Pin<&mut Chart>, new_geometry: &QRectF, old_geometry: &QRectF);
+        /// }
+        ///
+        /// impl qobject::Chart {
+        ///     fn geometry_change(mut self: Pin<&mut Self>, new_geometry: &QRectF, old_geometry: &QRectF) {
+        ///         self.as_mut().geometry_change_super(new_geometry, old_geometry);
+        ///         self.as_mut().relayout(new_geometry.size());
+        ///     }
+        /// }
+        /// ```
+        #[qobject]
+        type QQuickItem;
+
+        /// This signal is emitted when the height of the item changes.
+        #[qsignal]
+        #[rust_name = "height_changed"]
+        fn heightChanged(self: Pin<&mut QQuickItem>);
+
+        /// This signal is emitted when the implicit height of the item changes.
+        #[qsignal]
+        #[rust_name = "implicit_height_changed"]
+        fn implicitHeightChanged(self: Pin<&mut QQuickItem>);
+
+        /// This signal is emitted when the implicit width of the item changes.
+        #[qsignal]
+        #[rust_name = "implicit_width_changed"]
+        fn implicitWidthChanged(self: Pin<&mut QQuickItem>);
+
+        /// This signal is emitted when the item becomes visible or hidden.
+        #[qsignal]
+        #[rust_name = "visible_changed"]
+        fn visibleChanged(self: Pin<&mut QQuickItem>);
+
+        /// This signal is emitted when the width of the item changes.
+        #[qsignal]
+        #[rust_name = "width_changed"]
+        fn widthChanged(self: Pin<&mut QQuickItem>);
+
+        /// This signal is emitted when the x position of the item changes.
+        #[qsignal]
+        #[rust_name = "x_changed"]
+        fn xChanged(self: Pin<&mut QQuickItem>);
+
+        /// This signal is emitted when the y position of the item changes.
+        #[qsignal]
+        #[rust_name = "y_changed"]
+        fn yChanged(self: Pin<&mut QQuickItem>);
+    }
+
+    unsafe extern "C++" {
+        /// Returns the extents of the item in its own coordinate system,
+        /// a rectangle from 0, 0 to the width and height of the item.
+        #[rust_name = "bounding_rect"]
+        fn boundingRect(self: &QQuickItem) -> QRectF;
+
+        /// Returns the height of the item.
+        fn height(self: &QQuickItem) -> f64;
+
+        /// Returns the implicit height of the item, which is its natural height when no height is set.
+        #[rust_name = "implicit_height"]
+        fn implicitHeight(self: &QQuickItem) -> f64;
+
+        /// Returns the implicit width of the item, which is its natural width when no width is set.
+        #[rust_name = "implicit_width"]
+        fn implicitWidth(self: &QQuickItem) -> f64;
+
+        /// Returns true if the item is visible.
+        #[rust_name = "is_visible"]
+        fn isVisible(self: &QQuickItem) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "map_to_item_ptr"]
+        unsafe fn mapToItem(self: &QQuickItem, item: *const QQuickItem, point: &QPointF)
+            -> QPointF;
+
+        /// Maps the given point in the coordinate system of the item to the equivalent point
+        /// within the coordinate system of the scene.
+        #[rust_name = "map_to_scene"]
+        fn mapToScene(self: &QQuickItem, point: &QPointF) -> QPointF;
+
+        #[doc(hidden)]
+        #[rust_name = "map_from_item_ptr"]
+        unsafe fn mapFromItem(
+            self: &QQuickItem,
+            item: *const QQuickItem,
+            point: &QPointF,
+        ) -> QPointF;
+
+        /// Maps the given point in the coordinate system of the scene
+        /// to the equivalent point within the coordinate system of the item.
+        #[rust_name = "map_from_scene"]
+        fn mapFromScene(self: &QQuickItem, point: &QPointF) -> QPointF;
+
+        /// Returns the visual parent of the item, or a null pointer if the item has no visual parent.
+        #[rust_name = "parent_item"]
+        fn parentItem(self: &QQuickItem) -> *mut QQuickItem;
+
+        /// Schedules a polish event for the item, so that `updatePolish` is called before the next frame.
+        fn polish(self: Pin<&mut QQuickItem>);
+
+        /// Returns the position of the item relative to its parent.
+        fn position(self: &QQuickItem) -> QPointF;
+
+        /// Enables the flag for the item if enabled is true, otherwise disables it.
+        #[rust_name = "set_flag"]
+        fn setFlag(self: Pin<&mut QQuickItem>, flag: QQuickItemFlag, enabled: bool);
+
+        /// Sets the height of the item.
+        #[rust_name = "set_height"]
+        fn setHeight(self: Pin<&mut QQuickItem>, height: f64);
+
+        #[doc(hidden)]
+        #[rust_name = "set_parent_item_ptr"]
+        unsafe fn setParentItem(self: Pin<&mut QQuickItem>, parent: *mut QQuickItem);
+
+        /// Sets the implicit height of the item, which is used by QML layouts as its preferred height.
+        #[rust_name = "set_implicit_height"]
+        fn setImplicitHeight(self: Pin<&mut QQuickItem>, height: f64);
+
+        /// Sets the implicit width of the item, which is used by QML layouts as its preferred width.
+        #[rust_name = "set_implicit_width"]
+        fn setImplicitWidth(self: Pin<&mut QQuickItem>, width: f64);
+
+        /// Sets the position of the item relative to its parent.
+        #[rust_name = "set_position"]
+        fn setPosition(self: Pin<&mut QQuickItem>, position: &QPointF);
+
+        /// Sets the size of the item.
+        #[rust_name = "set_size"]
+        fn setSize(self: Pin<&mut QQuickItem>, size: &QSizeF);
+
+        /// Sets whether the item is visible.
+        #[rust_name = "set_visible"]
+        fn setVisible(self: Pin<&mut QQuickItem>, visible: bool);
+
+        /// Sets the width of the item.
+        #[rust_name = "set_width"]
+        fn setWidth(self: Pin<&mut QQuickItem>, width: f64);
+
+        /// Sets the x position of the item relative to its parent.
+        #[rust_name = "set_x"]
+        fn setX(self: Pin<&mut QQuickItem>, x: f64);
+
+        /// Sets the y position of the item relative to its parent.
+        #[rust_name = "set_y"]
+        fn setY(self: Pin<&mut QQuickItem>, y: f64);
+
+        /// Returns the size of the item.
+        fn size(self: &QQuickItem) -> QSizeF;
+
+        /// Schedules the item to be rendered again, so that `updatePaintNode` is called.
+        ///
+        /// This only has an effect if the item has the [QQuickItemFlag::ItemHasContents] flag.
+        fn update(self: Pin<&mut QQuickItem>);
+
+        /// Returns the width of the item.
+        fn width(self: &QQuickItem) -> f64;
+
+        /// Returns the window in which the item is rendered, or a null pointer if it is not in a window.
+        fn window(self: &QQuickItem) -> *mut QQuickWindow;
+
+        /// Returns the x position of the item relative to its parent.
+        fn x(self: &QQuickItem) -> f64;
+
+        /// Returns the y position of the item relative to its parent.
+        fn y(self: &QQuickItem) -> f64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QQuickItemFlag;
+
+        #[doc(hidden)]
+        #[rust_name = "qquickitem_set_accessible_property"]
+        fn qquickitemSetAccessibleProperty(
+            item: Pin<&mut QQuickItem>,
+            name: &QString,
+            value: &QVariant,
+        ) -> bool;
+    }
+
+    // QQuickItem is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QQuickItem>::isRelocatable);
+}
+
+use core::pin::Pin;
+use cxx_qt_lib::{QPointF, QSizeF, QString, QVariant};
+
+use crate::QAccessibleRole;
+
+pub use ffi::{QQuickItem, QQuickItemFlag};
+
+impl QQuickItem {
+    /// Maps the given point in the coordinate system of the item to the equivalent point
+    /// within the coordinate system of the other item, or of the scene if there is no other item.
+    pub fn map_to_item(&self, item: Option<&QQuickItem>, point: &QPointF) -> QPointF {
+        let item = item.map_or(core::ptr::null(), |item| item as *const QQuickItem);
+        unsafe { self.map_to_item_ptr(item, point) }
+    }
+
+    /// Maps the given point in the coordinate system of the other item, or of the scene if there is no other item,
+    /// to the equivalent point within the coordinate system of the item.
+    pub fn map_from_item(&self, item: Option<&QQuickItem>, point: &QPointF) -> QPointF {
+        let item = item.map_or(core::ptr::null(), |item| item as *const QQuickItem);
+        unsafe { self.map_from_item_ptr(item, point) }
+    }
+
+    /// Sets the description of the item for assistive technologies, such as screen readers,
+    /// which is the `Accessible.description` attached property in QML.
+    ///
+    /// Returns false if the property could not be set, the item must have been created by QML
+    /// in a file which imports QtQuick.
+    pub fn set_accessible_description(self: Pin<&mut Self>, description: &QString) -> bool {
+        ffi::qquickitem_set_accessible_property(
+            self,
+            &QString::from("description"),
+            &QVariant::from(description),
+        )
+    }
+
+    /// Sets the name of the item for assistive technologies, such as screen readers,
+    /// which is the `Accessible.name` attached property in QML.
+    ///
+    /// Returns false if the property could not be set, the item must have been created by QML
+    /// in a file which imports QtQuick.
+    pub fn set_accessible_name(self: Pin<&mut Self>, name: &QString) -> bool {
+        ffi::qquickitem_set_accessible_property(self, &QString::from("name"), &QVariant::from(name))
+    }
+
+    /// Sets the role of the item for assistive technologies, such as screen readers,
+    /// which is the `Accessible.role` attached property in QML.
+    ///
+    /// Items with a role such as [QAccessibleRole::Slider] or [QAccessibleRole::ProgressBar]
+    /// expose their `value`, `from`, `to`, and `stepSize` properties as the accessible value.
+    ///
+    /// Returns false if the property could not be set, the item must have been created by QML
+    /// in a file which imports QtQuick.
+    pub fn set_accessible_role(self: Pin<&mut Self>, role: QAccessibleRole) -> bool {
+        ffi::qquickitem_set_accessible_property(
+            self,
+            &QString::from("role"),
+            &QVariant::from(&role.repr),
+        )
+    }
+
+    /// Sets the implicit width and height of the item, which are used by QML layouts as its preferred size.
+    pub fn set_implicit_size(mut self: Pin<&mut Self>, size: &QSizeF) {
+        self.as_mut().set_implicit_width(size.width());
+        self.set_implicit_height(size.height());
+    }
+
+    /// Sets the visual parent of the item, which may be null to remove it from the scene.
+    ///
+    /// # Safety
+    ///
+    /// The parent must be a valid pointer to a [QQuickItem] or null.
+    pub unsafe fn set_parent_item(self: Pin<&mut Self>, parent: *mut QQuickItem) {
+        self.set_parent_item_ptr(parent);
+    }
+}
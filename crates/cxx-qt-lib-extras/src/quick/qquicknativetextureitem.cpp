@@ -0,0 +1,140 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qquicknativetextureitem.h"
+
+#include <cstring>
+
+#include <QtQuick/QQuickWindow>
+#include <QtQuick/QSGRendererInterface>
+#include <QtQuick/QSGSimpleTextureNode>
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+#include <QtQuick/qsgtexture_platform.h>
+#endif
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QQuickNativeTextureItem::QQuickNativeTextureItem(QQuickItem* parent)
+  : QQuickItem(parent)
+{
+  setFlag(QQuickItem::ItemHasContents);
+}
+
+void
+QQuickNativeTextureItem::clearNativeTexture()
+{
+  setNativeTexture(0, 0, QSize());
+}
+
+QSize
+QQuickNativeTextureItem::nativeTextureSize() const
+{
+  return m_size;
+}
+
+void
+QQuickNativeTextureItem::setNativeTexture(::std::uint64_t handle,
+                                          ::std::int32_t layout,
+                                          const QSize& size)
+{
+  // The texture is only read in updatePaintNode, which is called on the
+  // render thread while the GUI thread is blocked, so no locking is needed
+  m_textureChanged = m_textureChanged || m_handle != handle ||
+                     m_layout != layout || m_size != size;
+  m_handle = handle;
+  m_layout = layout;
+  m_size = size;
+  update();
+}
+
+QSGNode*
+QQuickNativeTextureItem::updatePaintNode(QSGNode* oldNode,
+                                         UpdatePaintNodeData*)
+{
+  auto* node = static_cast<QSGSimpleTextureNode*>(oldNode);
+  if (m_handle == 0 || m_size.isEmpty() || window() == nullptr) {
+    delete node;
+    return nullptr;
+  }
+
+  if (node == nullptr) {
+    node = new QSGSimpleTextureNode();
+    node->setOwnsTexture(true);
+    m_textureChanged = true;
+  }
+
+  if (m_textureChanged) {
+    QSGTexture* texture = createTexture();
+    if (texture == nullptr) {
+      delete node;
+      return nullptr;
+    }
+
+    // The node only deletes the texture it has when it is destroyed
+    QSGTexture* previous = node->texture();
+    node->setTexture(texture);
+    delete previous;
+    m_textureChanged = false;
+  }
+
+  node->setRect(boundingRect());
+  // The contents of the texture may have changed even if the texture has not
+  node->markDirty(QSGNode::DirtyMaterial);
+  return node;
+}
+
+QSGTexture*
+QQuickNativeTextureItem::createTexture() const
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  QQuickWindow* quickWindow = window();
+  switch (quickWindow->rendererInterface()->graphicsApi()) {
+#if QT_CONFIG(opengl)
+    case QSGRendererInterface::OpenGL:
+      return QNativeInterface::QSGOpenGLTexture::fromNative(
+        static_cast<GLuint>(m_handle), quickWindow, m_size);
+#endif
+#if QT_CONFIG(vulkan) && __has_include(<vulkan/vulkan.h>)
+    case QSGRendererInterface::Vulkan: {
+      // VkImage is a pointer or a 64-bit integer depending on the platform
+      VkImage image;
+      static_assert(sizeof(image) <= sizeof(m_handle));
+      ::std::memcpy(&image, &m_handle, sizeof(image));
+      return QNativeInterface::QSGVulkanTexture::fromNative(
+        image, static_cast<VkImageLayout>(m_layout), quickWindow, m_size);
+    }
+#endif
+#if defined(Q_OS_WIN)
+    case QSGRendererInterface::Direct3D11:
+      return QNativeInterface::QSGD3D11Texture::fromNative(
+        reinterpret_cast<void*>(static_cast<quintptr>(m_handle)),
+        quickWindow,
+        m_size);
+#endif
+#if defined(Q_OS_DARWIN)
+    case QSGRendererInterface::Metal:
+      return QNativeInterface::QSGMetalTexture::fromNative(
+        reinterpret_cast<MTLTexture*>(static_cast<quintptr>(m_handle)),
+        quickWindow,
+        m_size);
+#endif
+    default:
+      return nullptr;
+  }
+#else
+  // Qt 5 only supports wrapping OpenGL textures
+  if (window()->rendererInterface()->graphicsApi() !=
+      QSGRendererInterface::OpenGL) {
+    return nullptr;
+  }
+  return window()->createTextureFromId(static_cast<uint>(m_handle), m_size);
+#endif
+}
+
+}
+}
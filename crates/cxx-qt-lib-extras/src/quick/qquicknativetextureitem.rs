@@ -0,0 +1,119 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsize.h");
+        type QSize = cxx_qt_lib::QSize;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qquicknativetextureitem.h");
+        /// A QQuickItem which displays a texture that was rendered outside of Qt Quick,
+        /// for example by [wgpu](https://crates.io/crates/wgpu) in Rust.
+        ///
+        /// A QObject with this as its `#[base]` is a QML item which sets the native handle of its texture,
+        /// which is wrapped as a QSGTexture for the graphics API that the window of the item uses.
+        ///
+        /// ```ignore
+        /// #[cxx_qt::bridge]
+        /// mod qobject {
+        ///     #[namespace = "rust::cxxqtlib1"]
+        ///     unsafe extern "C++" {
+        ///         include!("cxx-qt-lib-extras/qquicknativetextureitem.h");
+        ///         type QQuickNativeTextureItem = cxx_qt_lib_extras::QQuickNativeTextureItem;
+        ///     }
+        ///
+        ///     unsafe extern "RustQt" {
+        ///         #[qml_element]
+        ///         #[qobject]
+        ///         #[base = QQuickNativeTextureItem]
+        ///         type Viewport = super::ViewportRust;
+        ///     }
+        /// }
+        ///
+        /// fn frame_rendered(viewport: Pin<&mut qobject::Viewport>, image: u64, size: &QSize) {
+        ///     viewport.upcast_pin().set_vulkan_image(image, VK_IMAGE_LAYOUT_SHADER_READ_ONLY_OPTIMAL, size);
+        /// }
+        /// ```
+        ///
+        /// The texture is only read while Qt Quick synchronizes the item with the scene graph,
+        /// when the GUI thread is blocked, so it is safe to set the texture from the GUI thread at any time.
+        /// The texture must be created on the same graphics device as Qt Quick,
+        /// rendering into it must have completed before it is set,
+        /// and it must stay valid until it is replaced or cleared and the next frame has been rendered.
+        #[qobject]
+        type QQuickNativeTextureItem;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        /// Stops displaying the texture, so that the item is empty.
+        #[rust_name = "clear_native_texture"]
+        fn clearNativeTexture(self: Pin<&mut QQuickNativeTextureItem>);
+
+        /// Returns the size of the texture, which is invalid when no texture is set.
+        #[rust_name = "native_texture_size"]
+        fn nativeTextureSize(self: &QQuickNativeTextureItem) -> QSize;
+
+        #[doc(hidden)]
+        #[rust_name = "set_native_texture"]
+        fn setNativeTexture(
+            self: Pin<&mut QQuickNativeTextureItem>,
+            handle: u64,
+            layout: i32,
+            size: &QSize,
+        );
+    }
+}
+
+use core::pin::Pin;
+use cxx_qt_lib::QSize;
+
+pub use ffi::QQuickNativeTextureItem;
+
+impl QQuickNativeTextureItem {
+    /// Displays the Direct3D 11 texture, which is an `ID3D11Texture2D` pointer,
+    /// when the window uses Direct3D 11.
+    ///
+    /// # Safety
+    ///
+    /// The texture must be a valid `ID3D11Texture2D` created on the device of the window.
+    pub unsafe fn set_d3d11_texture(
+        self: Pin<&mut Self>,
+        texture: *mut core::ffi::c_void,
+        size: &QSize,
+    ) {
+        self.set_native_texture(texture as usize as u64, 0, size);
+    }
+
+    /// Displays the Metal texture, which is an `id<MTLTexture>` pointer, when the window uses Metal.
+    ///
+    /// # Safety
+    ///
+    /// The texture must be a valid `MTLTexture` created on the device of the window.
+    pub unsafe fn set_metal_texture(
+        self: Pin<&mut Self>,
+        texture: *mut core::ffi::c_void,
+        size: &QSize,
+    ) {
+        self.set_native_texture(texture as usize as u64, 0, size);
+    }
+
+    /// Displays the OpenGL texture with the given name, when the window uses OpenGL.
+    pub fn set_opengl_texture(self: Pin<&mut Self>, texture: u32, size: &QSize) {
+        self.set_native_texture(u64::from(texture), 0, size);
+    }
+
+    /// Displays the Vulkan image, which has the given `VkImageLayout`, when the window uses Vulkan.
+    ///
+    /// The image must be usable as a sampled image, such as with the
+    /// `VK_IMAGE_LAYOUT_SHADER_READ_ONLY_OPTIMAL` layout.
+    pub fn set_vulkan_image(self: Pin<&mut Self>, image: u64, layout: i32, size: &QSize) {
+        self.set_native_texture(image, layout, size);
+    }
+}
@@ -0,0 +1,62 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qquicktextdocument.h");
+        /// The QQuickTextDocument class provides access to the [QTextDocument](crate::QTextDocument)
+        /// of a QML TextEdit or TextArea, which is the `textDocument` property of the item.
+        ///
+        /// This allows for Rust code to format or inspect the text of the item with a [QTextCursor](crate::QTextCursor).
+        ///
+        /// A Rust QObject can receive the `textDocument` of an item with an invokable which takes a pointer,
+        /// as QML passes the QQuickTextDocument by pointer.
+        ///
+        /// ```ignore
+        /// #[qinvokable]
+        /// unsafe fn attach(self: Pin<&mut MyObject>, document: *mut QQuickTextDocument);
+        /// ```
+        ///
+        /// ```qml
+        /// TextArea {
+        ///     id: textArea
+        ///     Component.onCompleted: myObject.attach(textArea.textDocument)
+        /// }
+        /// ```
+        #[qobject]
+        type QQuickTextDocument;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qtextdocument.h");
+        type QTextDocument = crate::QTextDocument;
+
+        /// Returns a pointer to the QTextDocument object.
+        #[rust_name = "text_document"]
+        fn textDocument(self: &QQuickTextDocument) -> *mut QTextDocument;
+    }
+
+    // QQuickTextDocument is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QQuickTextDocument>::isRelocatable);
+}
+
+use crate::QTextDocument;
+use core::pin::Pin;
+
+pub use ffi::QQuickTextDocument;
+
+impl QQuickTextDocument {
+    /// Returns the [QTextDocument] of the item, which can be edited or have a
+    /// [QSyntaxHighlighter](crate::QSyntaxHighlighter) installed.
+    pub fn text_document_mut(self: Pin<&mut Self>) -> Pin<&mut QTextDocument> {
+        let document = self.text_document();
+        // Safety: the document of the item is never null and is valid for the lifetime of the QQuickTextDocument,
+        // which is owned by the item
+        unsafe { Pin::new_unchecked(&mut *document) }
+    }
+}
@@ -0,0 +1,95 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qquickwindow.h"
+
+#include <QtQuick/QSGRendererInterface>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::int32_t
+qquickwindowGraphicsApi(const QQuickWindow& window)
+{
+  // The Rust enum uses the values of Qt 6, so map the values of Qt 5 to them
+  enum class GraphicsApi : ::std::int32_t
+  {
+    Unknown = 0,
+    Software = 1,
+    OpenVG = 2,
+    OpenGL = 3,
+    Direct3D11 = 4,
+    Vulkan = 5,
+    Metal = 6,
+    Null = 7,
+    Direct3D12 = 8,
+  };
+
+  const QSGRendererInterface* interface = window.rendererInterface();
+  if (interface == nullptr) {
+    return static_cast<::std::int32_t>(GraphicsApi::Unknown);
+  }
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return static_cast<::std::int32_t>(interface->graphicsApi());
+#else
+  GraphicsApi api = GraphicsApi::Unknown;
+  switch (interface->graphicsApi()) {
+    case QSGRendererInterface::Software:
+      api = GraphicsApi::Software;
+      break;
+    case QSGRendererInterface::OpenVG:
+      api = GraphicsApi::OpenVG;
+      break;
+    case QSGRendererInterface::OpenGL:
+    case QSGRendererInterface::OpenGLRhi:
+      api = GraphicsApi::OpenGL;
+      break;
+    case QSGRendererInterface::Direct3D11Rhi:
+      api = GraphicsApi::Direct3D11;
+      break;
+    case QSGRendererInterface::VulkanRhi:
+      api = GraphicsApi::Vulkan;
+      break;
+    case QSGRendererInterface::MetalRhi:
+      api = GraphicsApi::Metal;
+      break;
+    case QSGRendererInterface::NullRhi:
+      api = GraphicsApi::Null;
+      break;
+    case QSGRendererInterface::Direct3D12:
+      api = GraphicsApi::Direct3D12;
+      break;
+    default:
+      break;
+  }
+  return static_cast<::std::int32_t>(api);
+#endif
+}
+
+void*
+qquickwindowNativeResource(QQuickWindow& window, ::std::int32_t resource)
+{
+  QSGRendererInterface* interface = window.rendererInterface();
+  if (interface == nullptr) {
+    return nullptr;
+  }
+
+  return interface->getResource(
+    &window, static_cast<QSGRendererInterface::Resource>(resource));
+}
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+void
+qquickwindowSetGraphicsApi(::std::int32_t api)
+{
+  QQuickWindow::setGraphicsApi(
+    static_cast<QSGRendererInterface::GraphicsApi>(api));
+}
+#endif
+
+}
+}
@@ -0,0 +1,288 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// The graphics API which is used by the scene graph of a [QQuickWindow].
+    ///
+    /// The values are those of QSGRendererInterface::GraphicsApi in Qt 6,
+    /// the RHI based values of Qt 5 are mapped to them.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSGRendererInterfaceGraphicsApi {
+        /// The graphics API is unknown, such as before the scene graph has been initialized.
+        Unknown = 0,
+        /// The scene graph uses the software renderer.
+        Software = 1,
+        /// The scene graph uses OpenVG.
+        OpenVG = 2,
+        /// The scene graph uses OpenGL ES 2.0 or higher, or OpenGL 2.1 or higher.
+        OpenGL = 3,
+        /// The scene graph uses Direct3D 11.
+        Direct3D11 = 4,
+        /// The scene graph uses Vulkan.
+        Vulkan = 5,
+        /// The scene graph uses Metal.
+        Metal = 6,
+        /// The scene graph uses the null backend, which does not render anything.
+        Null = 7,
+        /// The scene graph uses Direct3D 12.
+        Direct3D12 = 8,
+    }
+
+    /// A native resource of the scene graph of a [QQuickWindow],
+    /// see [QQuickWindow::native_resource].
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSGRendererInterfaceResource {
+        /// The graphics device, such as a `VkDevice*`, an `ID3D11Device*`, or an `id<MTLDevice>`.
+        DeviceResource = 0,
+        /// The graphics command queue, such as a `VkQueue*` or an `id<MTLCommandQueue>`.
+        CommandQueueResource = 1,
+        /// The command list or buffer which is used by the scene graph, such as a `VkCommandBuffer*`.
+        CommandListResource = 2,
+        /// The QPainter which is used by the software renderer.
+        PainterResource = 3,
+        /// The QRhi which is used by the scene graph.
+        RhiResource = 4,
+        /// The physical device, such as a `VkPhysicalDevice*`.
+        PhysicalDeviceResource = 5,
+        /// The QOpenGLContext which is used by the scene graph.
+        OpenGLContextResource = 6,
+        /// The device context, such as an `ID3D11DeviceContext*`.
+        DeviceContextResource = 7,
+        /// The current render command encoder, such as an `id<MTLRenderCommandEncoder>`.
+        CommandEncoderResource = 8,
+        /// The QVulkanInstance which is used by the scene graph.
+        VulkanInstanceResource = 9,
+        /// The render pass of the current render target, such as a `VkRenderPass*`.
+        RenderPassResource = 10,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qquickwindow.h");
+        /// The QQuickWindow class provides the window for displaying a graphical QML scene.
+        ///
+        /// A QML `Window` or `ApplicationWindow` is a QQuickWindow. Its rendering signals can be used to
+        /// draw with the graphics API of the scene graph underneath or on top of the QML scene,
+        /// see [QQuickWindow::on_before_rendering_in_render_thread].
+        #[qobject]
+        type QQuickWindow;
+
+        /// This signal is emitted after the scene has been rendered, before the buffers are swapped.
+        ///
+        /// This signal is emitted from the rendering thread.
+        #[qsignal]
+        #[rust_name = "after_rendering"]
+        fn afterRendering(self: Pin<&mut QQuickWindow>);
+
+        /// This signal is emitted after the scene graph is prepared but before the scene is rendered.
+        ///
+        /// This signal is emitted from the rendering thread.
+        #[qsignal]
+        #[rust_name = "before_rendering"]
+        fn beforeRendering(self: Pin<&mut QQuickWindow>);
+
+        /// This signal is emitted when the window receives an event to close,
+        /// for example when the user presses the close button of the window.
+        ///
+        /// The close event is owned by Qt and is only valid during the signal emission.
+        #[qsignal]
+        unsafe fn closing(self: Pin<&mut QQuickWindow>, close: *mut QQuickCloseEvent);
+
+        /// This signal is emitted when the scene graph has been initialized,
+        /// so its graphics resources such as the device are available.
+        ///
+        /// This signal is emitted from the rendering thread.
+        #[qsignal]
+        #[rust_name = "scene_graph_initialized"]
+        fn sceneGraphInitialized(self: Pin<&mut QQuickWindow>);
+
+        /// This signal is emitted when the scene graph has been invalidated,
+        /// graphics resources which were created for the scene graph should be released.
+        ///
+        /// This signal is emitted from the rendering thread.
+        #[qsignal]
+        #[rust_name = "scene_graph_invalidated"]
+        fn sceneGraphInvalidated(self: Pin<&mut QQuickWindow>);
+    }
+
+    unsafe extern "C++" {
+        /// Notification that a window is about to be closed, as passed to [QQuickWindow::on_closing].
+        ///
+        /// The event can only be accepted or rejected from QML, in Rust it identifies the close request.
+        type QQuickCloseEvent;
+
+        /// Call this function when the application is about to issue graphics commands
+        /// to the command buffer of the scene graph, from a slot connected to a rendering signal.
+        ///
+        /// This must be followed by [QQuickWindow::end_external_commands].
+        #[cfg(cxxqt_qt_version_major = "6")]
+        #[rust_name = "begin_external_commands"]
+        fn beginExternalCommands(self: Pin<&mut QQuickWindow>);
+
+        /// Call this function when the application has finished issuing graphics commands
+        /// which were started with [QQuickWindow::begin_external_commands].
+        #[cfg(cxxqt_qt_version_major = "6")]
+        #[rust_name = "end_external_commands"]
+        fn endExternalCommands(self: Pin<&mut QQuickWindow>);
+
+        /// Resets the OpenGL state which is used by the scene graph to the defaults,
+        /// call this after rendering with OpenGL from a slot connected to a rendering signal.
+        #[cfg(cxxqt_qt_version_major = "5")]
+        #[rust_name = "reset_opengl_state"]
+        fn resetOpenGLState(self: Pin<&mut QQuickWindow>);
+
+        /// Schedules the window to render another frame.
+        fn update(self: Pin<&mut QQuickWindow>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+        type c_void = cxx_qt_lib::c_void;
+
+        #[doc(hidden)]
+        #[rust_name = "qquickwindow_graphics_api"]
+        fn qquickwindowGraphicsApi(window: &QQuickWindow) -> i32;
+
+        #[doc(hidden)]
+        #[rust_name = "qquickwindow_native_resource"]
+        fn qquickwindowNativeResource(window: Pin<&mut QQuickWindow>, resource: i32)
+            -> *mut c_void;
+
+        #[doc(hidden)]
+        #[cfg(cxxqt_qt_version_major = "6")]
+        #[rust_name = "qquickwindow_set_graphics_api"]
+        fn qquickwindowSetGraphicsApi(api: i32);
+    }
+
+    // QQuickWindow is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QQuickWindow>::isRelocatable);
+}
+
+use core::pin::Pin;
+use cxx_qt::{ConnectionType, QMetaObjectConnectionGuard};
+
+pub use ffi::{
+    QQuickCloseEvent, QQuickWindow, QSGRendererInterfaceGraphicsApi, QSGRendererInterfaceResource,
+};
+
+impl QQuickWindow {
+    /// Returns the graphics API which is used by the scene graph of the window,
+    /// which is [QSGRendererInterfaceGraphicsApi::Unknown] until the scene graph has been initialized.
+    pub fn graphics_api(&self) -> QSGRendererInterfaceGraphicsApi {
+        QSGRendererInterfaceGraphicsApi {
+            repr: ffi::qquickwindow_graphics_api(self),
+        }
+    }
+
+    /// Returns a pointer to the native resource of the scene graph of the window,
+    /// or a null pointer if the resource is not available with the graphics API of the window.
+    ///
+    /// This allows for Rust graphics code to use the same device and queue as Qt Quick,
+    /// for example so that textures can be shared with a [crate::QQuickNativeTextureItem].
+    ///
+    /// Note that most resources are a pointer to the native handle, such as a `VkDevice*` with Vulkan,
+    /// rather than the handle itself, and the resources are only valid on the rendering thread,
+    /// for example in [QQuickWindow::on_scene_graph_initialized_in_render_thread].
+    pub fn native_resource(
+        self: Pin<&mut Self>,
+        resource: QSGRendererInterfaceResource,
+    ) -> *mut core::ffi::c_void {
+        ffi::qquickwindow_native_resource(self, resource.repr).cast()
+    }
+
+    /// Requests the graphics API which is used by the scene graph of all windows which are created afterwards,
+    /// this must be called before the first QQuickWindow is created.
+    ///
+    /// This allows for the scene graph to use the same graphics API as Rust graphics code,
+    /// for example Vulkan with [wgpu](https://crates.io/crates/wgpu).
+    #[cfg(cxxqt_qt_version_major = "6")]
+    pub fn set_graphics_api(api: QSGRendererInterfaceGraphicsApi) {
+        ffi::qquickwindow_set_graphics_api(api.repr);
+    }
+
+    /// Connects the closure to [QQuickWindow::after_rendering] with a direct connection,
+    /// so that it is called on the rendering thread after the QML scene has been rendered.
+    ///
+    /// This allows for drawing on top of the QML scene with the graphics API of the scene graph,
+    /// the commands must be surrounded by [QQuickWindow::begin_external_commands]
+    /// and [QQuickWindow::end_external_commands] with Qt 6.
+    ///
+    /// The closure must not access the window or the items in the scene other than
+    /// for the functions which are intended to be called from the rendering thread,
+    /// as the GUI thread may be running at the same time.
+    pub fn on_after_rendering_in_render_thread<F>(
+        self: Pin<&mut Self>,
+        closure: F,
+    ) -> QMetaObjectConnectionGuard
+    where
+        F: FnMut(Pin<&mut QQuickWindow>) + Send + 'static,
+    {
+        self.connect_after_rendering(closure, ConnectionType::DirectConnection)
+    }
+
+    /// Connects the closure to [QQuickWindow::before_rendering] with a direct connection,
+    /// so that it is called on the rendering thread before the QML scene is rendered.
+    ///
+    /// This allows for drawing underneath the QML scene with the graphics API of the scene graph,
+    /// the commands must be surrounded by [QQuickWindow::begin_external_commands]
+    /// and [QQuickWindow::end_external_commands] with Qt 6.
+    /// The window should not clear its color buffer, for example by setting its color to transparent.
+    ///
+    /// ```ignore
+    /// let guard = window.as_mut().on_before_rendering_in_render_thread(move |mut window| {
+    ///     window.as_mut().begin_external_commands();
+    ///     renderer.render();
+    ///     window.as_mut().end_external_commands();
+    /// });
+    /// ```
+    ///
+    /// The closure must not access the window or the items in the scene other than
+    /// for the functions which are intended to be called from the rendering thread,
+    /// as the GUI thread may be running at the same time.
+    pub fn on_before_rendering_in_render_thread<F>(
+        self: Pin<&mut Self>,
+        closure: F,
+    ) -> QMetaObjectConnectionGuard
+    where
+        F: FnMut(Pin<&mut QQuickWindow>) + Send + 'static,
+    {
+        self.connect_before_rendering(closure, ConnectionType::DirectConnection)
+    }
+
+    /// Connects the closure to [QQuickWindow::scene_graph_initialized] with a direct connection,
+    /// so that it is called on the rendering thread once the graphics resources of the scene graph exist.
+    ///
+    /// This is where graphics resources which are used by the rendering closures should be created.
+    pub fn on_scene_graph_initialized_in_render_thread<F>(
+        self: Pin<&mut Self>,
+        closure: F,
+    ) -> QMetaObjectConnectionGuard
+    where
+        F: FnMut(Pin<&mut QQuickWindow>) + Send + 'static,
+    {
+        self.connect_scene_graph_initialized(closure, ConnectionType::DirectConnection)
+    }
+
+    /// Connects the closure to [QQuickWindow::scene_graph_invalidated] with a direct connection,
+    /// so that it is called on the rendering thread before the graphics resources of the scene graph are released.
+    ///
+    /// This is where graphics resources which are used by the rendering closures should be released.
+    pub fn on_scene_graph_invalidated_in_render_thread<F>(
+        self: Pin<&mut Self>,
+        closure: F,
+    ) -> QMetaObjectConnectionGuard
+    where
+        F: FnMut(Pin<&mut QQuickWindow>) + Send + 'static,
+    {
+        self.connect_scene_graph_invalidated(closure, ConnectionType::DirectConnection)
+    }
+}
@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qquicktest.h"
+
+#include <QtCore/QByteArray>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::int32_t
+qquicktestRun(const QString& name, const QString& sourceDir)
+{
+  // Quick Test parses its options from the arguments, so only pass a program
+  // name and let the test runner options come from the environment.
+  QByteArray program = name.toUtf8();
+  const QByteArray source = sourceDir.toUtf8();
+  char* argv[] = { program.data(), nullptr };
+
+  return static_cast<::std::int32_t>(
+    quick_test_main(1, argv, program.constData(), source.constData()));
+}
+
+}
+}
@@ -0,0 +1,73 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::path::Path;
+
+use cxx_qt_lib::QString;
+
+use crate::QTestApplication;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qquicktest.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qquicktest_run"]
+        fn qquicktestRun(name: &QString, source_dir: &QString) -> i32;
+    }
+}
+
+/// Support for running Qt Quick Test `TestCase` QML files from `cargo test`.
+///
+/// The QML modules of the crate are linked into its test binaries, so the tests can import them
+/// in the same way as the application does.
+///
+/// ```ignore
+/// #[test]
+/// fn qml_tests() {
+///     QQuickTest::assert_passes(
+///         "my_crate",
+///         concat!(env!("CARGO_MANIFEST_DIR"), "/tests/qml"),
+///     );
+/// }
+/// ```
+///
+/// The tests are run on the thread of [QTestApplication::run_gui], so `QT_QPA_PLATFORM` defaults to `offscreen`.
+/// Options for the test runner, such as `QML2_IMPORT_PATH` or `QT_LOGGING_RULES`, can be given as environment variables.
+pub struct QQuickTest;
+
+impl QQuickTest {
+    /// Runs every `tst_*.qml` file in the source directory, or the file if a QML file is given,
+    /// and returns the number of failed tests.
+    ///
+    /// The name is used to identify the test run in the output of Qt Quick Test.
+    pub fn run(name: &str, source_dir: impl AsRef<Path>) -> i32 {
+        let name = name.to_owned();
+        let source_dir = source_dir.as_ref().to_string_lossy().into_owned();
+        QTestApplication::run_gui(move || {
+            ffi::qquicktest_run(&QString::from(&name), &QString::from(&source_dir))
+        })
+    }
+
+    /// Runs the QML tests in the source directory with [QQuickTest::run],
+    /// panicking if any of them failed so that the Rust test fails.
+    pub fn assert_passes(name: &str, source_dir: impl AsRef<Path>) {
+        let source_dir = source_dir.as_ref();
+        let failures = Self::run(name, source_dir);
+        assert_eq!(
+            failures,
+            0,
+            "{failures} QML test(s) failed in {}",
+            source_dir.display()
+        );
+    }
+}
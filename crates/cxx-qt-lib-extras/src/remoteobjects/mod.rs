@@ -0,0 +1,15 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// Only dynamic replicas are supported in Rust, static replicas for C++ can be generated by repc
+// from the .rep files written by CxxQtBuilder::remote_objects_rep in cxx-qt-build.
+mod qremoteobjectdynamicreplica;
+pub use qremoteobjectdynamicreplica::QRemoteObjectDynamicReplica;
+
+mod qremoteobjecthost;
+pub use qremoteobjecthost::QRemoteObjectHost;
+
+mod qremoteobjectnode;
+pub use qremoteobjectnode::QRemoteObjectNode;
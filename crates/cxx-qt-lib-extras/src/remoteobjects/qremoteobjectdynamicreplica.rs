@@ -0,0 +1,38 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qremoteobjectdynamicreplica.h");
+        /// A replica of a remote source, whose properties, signals and invokables are discovered at runtime.
+        #[qobject]
+        type QRemoteObjectDynamicReplica;
+
+        /// This signal is emitted once the replica is initialized with the data from the source.
+        #[qsignal]
+        fn initialized(self: Pin<&mut QRemoteObjectDynamicReplica>);
+    }
+
+    unsafe extern "C++" {
+        /// Returns true if the replica has a connection to the source and has been initialized, false otherwise.
+        #[rust_name = "is_replica_valid"]
+        fn isReplicaValid(self: &QRemoteObjectDynamicReplica) -> bool;
+
+        /// Blocks until the replica is initialized or timeout (in milliseconds) expires.
+        ///
+        /// Returns true if the replica was initialized, false otherwise.
+        #[rust_name = "wait_for_source"]
+        fn waitForSource(self: Pin<&mut QRemoteObjectDynamicReplica>, timeout: i32) -> bool;
+    }
+
+    // QRemoteObjectDynamicReplica is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QRemoteObjectDynamicReplica>::isRelocatable);
+    impl UniquePtr<QRemoteObjectDynamicReplica> {}
+}
+
+pub use ffi::QRemoteObjectDynamicReplica;
@@ -0,0 +1,46 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qremoteobjecthost.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QRemoteObjectHost>
+qremoteobjecthostNew(const QUrl& address)
+{
+  return ::std::make_unique<QRemoteObjectHost>(address);
+}
+
+QRemoteObjectNode&
+qremoteobjecthostAsQRemoteObjectNode(QRemoteObjectHost& host)
+{
+  return static_cast<QRemoteObjectNode&>(host);
+}
+
+bool
+qremoteobjecthostDisableRemoting(QRemoteObjectHost& host, QObject* object)
+{
+  return host.disableRemoting(object);
+}
+
+bool
+qremoteobjecthostEnableRemoting(QRemoteObjectHost& host,
+                                QObject* object,
+                                const QString& name)
+{
+  return host.enableRemoting(object, name);
+}
+
+bool
+qremoteobjecthostSetHostUrl(QRemoteObjectHost& host, const QUrl& address)
+{
+  return host.setHostUrl(address);
+}
+
+}
+}
@@ -0,0 +1,125 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qremoteobjecthost.h");
+        /// A host node on a Qt Remote Objects network, which exposes sources to other nodes.
+        #[qobject]
+        type QRemoteObjectHost;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt_lib::QObject;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = cxx_qt_lib::QUrl;
+
+        include!("cxx-qt-lib-extras/qremoteobjectnode.h");
+        type QRemoteObjectNode = crate::QRemoteObjectNode;
+
+        /// Returns the host address of this node, which other nodes can use with [QRemoteObjectNode::connect_to_node].
+        #[rust_name = "host_url"]
+        fn hostUrl(self: &QRemoteObjectHost) -> QUrl;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qremoteobjecthost_new"]
+        fn qremoteobjecthostNew(address: &QUrl) -> UniquePtr<QRemoteObjectHost>;
+
+        #[doc(hidden)]
+        #[rust_name = "qremoteobjecthost_as_qremoteobjectnode"]
+        fn qremoteobjecthostAsQRemoteObjectNode(
+            ptr: Pin<&mut QRemoteObjectHost>,
+        ) -> Pin<&mut QRemoteObjectNode>;
+
+        #[doc(hidden)]
+        #[rust_name = "qremoteobjecthost_disable_remoting"]
+        unsafe fn qremoteobjecthostDisableRemoting(
+            host: Pin<&mut QRemoteObjectHost>,
+            object: *mut QObject,
+        ) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qremoteobjecthost_enable_remoting"]
+        unsafe fn qremoteobjecthostEnableRemoting(
+            host: Pin<&mut QRemoteObjectHost>,
+            object: *mut QObject,
+            name: &QString,
+        ) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qremoteobjecthost_set_host_url"]
+        fn qremoteobjecthostSetHostUrl(host: Pin<&mut QRemoteObjectHost>, address: &QUrl) -> bool;
+    }
+
+    // QRemoteObjectHost is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QRemoteObjectHost>::isRelocatable);
+    impl UniquePtr<QRemoteObjectHost> {}
+}
+
+use crate::QRemoteObjectNode;
+use core::pin::Pin;
+use cxx_qt_lib::{QObject, QString, QUrl};
+
+pub use ffi::QRemoteObjectHost;
+
+impl QRemoteObjectHost {
+    /// Convert the existing [QRemoteObjectHost] to a [QRemoteObjectNode]
+    pub fn as_qremoteobjectnode<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut QRemoteObjectNode> {
+        ffi::qremoteobjecthost_as_qremoteobjectnode(self)
+    }
+
+    /// Disables remote access for the given object, which was previously enabled with [QRemoteObjectHost::enable_remoting].
+    ///
+    /// Returns false if the object was not being remoted, true otherwise.
+    ///
+    /// # Safety
+    ///
+    /// The object must be a valid pointer to a QObject.
+    pub unsafe fn disable_remoting(self: Pin<&mut Self>, object: *mut QObject) -> bool {
+        ffi::qremoteobjecthost_disable_remoting(self, object)
+    }
+
+    /// Enables remote access to the given object, using its properties, signals and invokables
+    /// from its meta object. Other nodes can then acquire it with [QRemoteObjectNode::acquire_dynamic]
+    /// using the given name, or as a static replica generated from the `.rep` file written by
+    /// `CxxQtBuilder::remote_objects_rep` in cxx-qt-build.
+    ///
+    /// If the name is empty then the objectName of the QObject is used instead.
+    ///
+    /// Returns false if the object could not be enabled for remoting, true otherwise.
+    ///
+    /// # Safety
+    ///
+    /// The object must be a valid pointer to a QObject which outlives the remoting,
+    /// such as a CXX-Qt QObject cast to a [QObject] pointer.
+    pub unsafe fn enable_remoting(
+        self: Pin<&mut Self>,
+        object: *mut QObject,
+        name: &QString,
+    ) -> bool {
+        ffi::qremoteobjecthost_enable_remoting(self, object, name)
+    }
+
+    /// Create a new QRemoteObjectHost which is listening at the given address, for example `local:replica`
+    pub fn new(address: &QUrl) -> cxx::UniquePtr<Self> {
+        ffi::qremoteobjecthost_new(address)
+    }
+
+    /// Sets the host address of this node.
+    ///
+    /// Returns true if the address was set, false otherwise.
+    pub fn set_host_url(self: Pin<&mut Self>, address: &QUrl) -> bool {
+        ffi::qremoteobjecthost_set_host_url(self, address)
+    }
+}
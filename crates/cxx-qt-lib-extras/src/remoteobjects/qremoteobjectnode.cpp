@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qremoteobjectnode.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QRemoteObjectNode>
+qremoteobjectnodeNew()
+{
+  return ::std::make_unique<QRemoteObjectNode>();
+}
+
+::std::unique_ptr<QRemoteObjectDynamicReplica>
+qremoteobjectnodeAcquireDynamic(QRemoteObjectNode& node, const QString& name)
+{
+  // The caller takes ownership of the replica
+  return ::std::unique_ptr<QRemoteObjectDynamicReplica>(
+    node.acquireDynamic(name));
+}
+
+}
+}
@@ -0,0 +1,101 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qremoteobjectnode.h");
+        /// A node on a Qt Remote Objects network, which is used to acquire replicas of remote sources.
+        #[qobject]
+        type QRemoteObjectNode;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = cxx_qt_lib::QUrl;
+
+        include!("cxx-qt-lib-extras/qremoteobjectdynamicreplica.h");
+        type QRemoteObjectDynamicReplica = crate::QRemoteObjectDynamicReplica;
+
+        /// Connects a client node to the host node at address.
+        ///
+        /// Returns true if successful, false otherwise.
+        #[rust_name = "connect_to_node"]
+        fn connectToNode(self: Pin<&mut QRemoteObjectNode>, address: &QUrl) -> bool;
+
+        /// Returns the interval in milliseconds at which heartbeats are sent to the source.
+        #[rust_name = "heartbeat_interval"]
+        fn heartbeatInterval(self: &QRemoteObjectNode) -> i32;
+
+        /// Returns the address of the registry of this node, or an empty url if the node does not use a registry.
+        #[rust_name = "registry_url"]
+        fn registryUrl(self: &QRemoteObjectNode) -> QUrl;
+
+        /// Sets the interval in milliseconds at which heartbeats are sent to the source.
+        ///
+        /// A value of 0 disables heartbeats.
+        #[rust_name = "set_heartbeat_interval"]
+        fn setHeartbeatInterval(self: Pin<&mut QRemoteObjectNode>, interval: i32);
+
+        /// Sets the registry of this node to registry_address.
+        ///
+        /// Returns true if the registry was found, false otherwise.
+        #[rust_name = "set_registry_url"]
+        fn setRegistryUrl(self: Pin<&mut QRemoteObjectNode>, registry_address: &QUrl) -> bool;
+
+        /// Blocks until this node's registry is initialized or timeout (in milliseconds) expires.
+        ///
+        /// Returns true if the registry was initialized, false otherwise.
+        #[rust_name = "wait_for_registry"]
+        fn waitForRegistry(self: Pin<&mut QRemoteObjectNode>, timeout: i32) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qremoteobjectnode_new"]
+        fn qremoteobjectnodeNew() -> UniquePtr<QRemoteObjectNode>;
+
+        #[doc(hidden)]
+        #[rust_name = "qremoteobjectnode_acquire_dynamic"]
+        fn qremoteobjectnodeAcquireDynamic(
+            node: Pin<&mut QRemoteObjectNode>,
+            name: &QString,
+        ) -> UniquePtr<QRemoteObjectDynamicReplica>;
+    }
+
+    // QRemoteObjectNode is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QRemoteObjectNode>::isRelocatable);
+    impl UniquePtr<QRemoteObjectNode> {}
+}
+
+use crate::QRemoteObjectDynamicReplica;
+use core::pin::Pin;
+use cxx_qt_lib::QString;
+
+pub use ffi::QRemoteObjectNode;
+
+impl QRemoteObjectNode {
+    /// Returns a replica of the source with the given name, whose properties, signals and
+    /// invokables are discovered at runtime from the source.
+    ///
+    /// This allows for any QObject which has been enabled for remoting, such as a CXX-Qt QObject,
+    /// to be used without a `.rep` file.
+    pub fn acquire_dynamic(
+        self: Pin<&mut Self>,
+        name: &QString,
+    ) -> cxx::UniquePtr<QRemoteObjectDynamicReplica> {
+        ffi::qremoteobjectnode_acquire_dynamic(self, name)
+    }
+
+    /// Create a new QRemoteObjectNode which is not connected to any host node
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qremoteobjectnode_new()
+    }
+}
@@ -0,0 +1,13 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qserialport;
+pub use qserialport::{
+    QIODeviceOpenModeFlag, QSerialPort, QSerialPortDataBits, QSerialPortFlowControl,
+    QSerialPortParity, QSerialPortSerialPortError, QSerialPortStopBits,
+};
+
+mod qserialportinfo;
+pub use qserialportinfo::QSerialPortInfo;
@@ -0,0 +1,56 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qserialport.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSerialPort>
+qserialportNew()
+{
+  return ::std::make_unique<QSerialPort>();
+}
+
+::std::int32_t
+qserialportBaudRate(const QSerialPort& port)
+{
+  return static_cast<::std::int32_t>(port.baudRate());
+}
+
+::std::int64_t
+qserialportBytesAvailable(const QSerialPort& port)
+{
+  return static_cast<::std::int64_t>(port.bytesAvailable());
+}
+
+bool
+qserialportOpen(QSerialPort& port, QIODeviceOpenModeFlag mode)
+{
+  return port.open(mode);
+}
+
+QByteArray
+qserialportReadAll(QSerialPort& port)
+{
+  return port.readAll();
+}
+
+bool
+qserialportSetBaudRate(QSerialPort& port, ::std::int32_t baudRate)
+{
+  return port.setBaudRate(static_cast<qint32>(baudRate));
+}
+
+::std::int64_t
+qserialportWrite(QSerialPort& port, const QByteArray& data)
+{
+  return static_cast<::std::int64_t>(port.write(data));
+}
+
+}
+}
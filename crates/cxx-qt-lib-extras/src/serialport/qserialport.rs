@@ -0,0 +1,347 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// This enum is used with [QSerialPort::open] to describe the mode in which the serial port is opened.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QIODeviceOpenModeFlag {
+        /// The device is not open.
+        NotOpen = 0x0000,
+        /// The device is open for reading.
+        ReadOnly = 0x0001,
+        /// The device is open for writing.
+        WriteOnly = 0x0002,
+        /// The device is open for reading and writing.
+        ReadWrite = 0x0003,
+    }
+
+    /// This enum describes the number of data bits used.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSerialPortDataBits {
+        /// The number of data bits in each character is 5.
+        Data5 = 5,
+        /// The number of data bits in each character is 6.
+        Data6 = 6,
+        /// The number of data bits in each character is 7.
+        Data7 = 7,
+        /// The number of data bits in each character is 8.
+        Data8 = 8,
+    }
+
+    /// This enum describes the flow control used.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSerialPortFlowControl {
+        /// No flow control.
+        NoFlowControl = 0,
+        /// Hardware flow control (RTS/CTS).
+        HardwareControl = 1,
+        /// Software flow control (XON/XOFF).
+        SoftwareControl = 2,
+    }
+
+    /// This enum describes the parity scheme used.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSerialPortParity {
+        /// No parity bit is sent.
+        NoParity = 0,
+        /// The number of 1 bits in each character, including the parity bit, is always even.
+        EvenParity = 2,
+        /// The number of 1 bits in each character, including the parity bit, is always odd.
+        OddParity = 3,
+        /// Space parity. The parity bit is sent in the space signal condition.
+        SpaceParity = 4,
+        /// Mark parity. The parity bit is always set to the mark signal condition (logical 1).
+        MarkParity = 5,
+    }
+
+    /// This enum describes the errors that may be contained by [QSerialPort::error].
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSerialPortSerialPortError {
+        /// No error occurred.
+        NoError = 0,
+        /// An error occurred while attempting to open a non-existing device.
+        DeviceNotFoundError = 1,
+        /// An error occurred while attempting to open an already opened device by another process
+        /// or a user not having enough permission and credentials to open.
+        PermissionError = 2,
+        /// An error occurred while attempting to open an already opened device in this object.
+        OpenError = 3,
+        /// An I/O error occurred while writing the data.
+        WriteError = 7,
+        /// An I/O error occurred while reading the data.
+        ReadError = 8,
+        /// An I/O error occurred when a resource becomes unavailable, e.g. when the device is unexpectedly removed from the system.
+        ResourceError = 9,
+        /// The requested device operation is not supported or prohibited by the running operating system.
+        UnsupportedOperationError = 10,
+        /// An unidentified error occurred.
+        UnknownError = 11,
+        /// A timeout error occurred.
+        TimeoutError = 12,
+        /// This error occurs when an operation is executed that can only be successfully performed if the device is open.
+        NotOpenError = 13,
+    }
+
+    /// This enum describes the number of stop bits used.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSerialPortStopBits {
+        /// 1 stop bit.
+        OneStop = 1,
+        /// 1.5 stop bits. This is only for the Windows platform.
+        OneAndHalfStop = 3,
+        /// 2 stop bits.
+        TwoStop = 2,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qserialport.h");
+        /// Provides functions to access serial ports from the Qt event loop.
+        #[qobject]
+        type QSerialPort;
+
+        /// This signal is emitted every time a payload of data has been written to the serial port.
+        #[qsignal]
+        #[cxx_name = "bytesWritten"]
+        fn bytes_written(self: Pin<&mut QSerialPort>, bytes: i64);
+
+        /// This signal is emitted when an error occurs in the serial port.
+        #[qsignal]
+        #[cxx_name = "errorOccurred"]
+        fn error_occurred(self: Pin<&mut QSerialPort>, error: QSerialPortSerialPortError);
+
+        /// This signal is emitted once every time new data is available for reading from the serial port.
+        #[qsignal]
+        #[cxx_name = "readyRead"]
+        fn ready_read(self: Pin<&mut QSerialPort>);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-lib-extras/qserialportinfo.h");
+        type QSerialPortInfo = crate::QSerialPortInfo;
+
+        /// Sets the error status of the serial port to [QSerialPortSerialPortError::NoError].
+        #[rust_name = "clear_error"]
+        fn clearError(self: Pin<&mut QSerialPort>);
+
+        /// Closes the serial port.
+        fn close(self: Pin<&mut QSerialPort>);
+
+        /// Returns the data bits in a frame.
+        #[rust_name = "data_bits"]
+        fn dataBits(self: &QSerialPort) -> QSerialPortDataBits;
+
+        /// Returns the error status of the serial port.
+        fn error(self: &QSerialPort) -> QSerialPortSerialPortError;
+
+        /// Returns a human-readable description of the last error that occurred.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QSerialPort) -> QString;
+
+        /// Returns the desired flow control mode.
+        #[rust_name = "flow_control"]
+        fn flowControl(self: &QSerialPort) -> QSerialPortFlowControl;
+
+        /// Writes as much as possible from the internal write buffer to the underlying serial port without blocking.
+        ///
+        /// Returns true if any data was written, false otherwise.
+        fn flush(self: Pin<&mut QSerialPort>) -> bool;
+
+        /// Returns the state of the line signal DTR (data terminal ready).
+        #[rust_name = "is_data_terminal_ready"]
+        fn isDataTerminalReady(self: Pin<&mut QSerialPort>) -> bool;
+
+        /// Returns true if the serial port is open, false otherwise.
+        #[rust_name = "is_open"]
+        fn isOpen(self: &QSerialPort) -> bool;
+
+        /// Returns the state of the line signal RTS (request to send).
+        #[rust_name = "is_request_to_send"]
+        fn isRequestToSend(self: Pin<&mut QSerialPort>) -> bool;
+
+        /// Returns the parity checking mode.
+        fn parity(self: &QSerialPort) -> QSerialPortParity;
+
+        /// Returns the name set by [QSerialPort::set_port] or [QSerialPort::set_port_name].
+        #[rust_name = "port_name"]
+        fn portName(self: &QSerialPort) -> QString;
+
+        /// Sets the data bits in a frame.
+        ///
+        /// Returns true if successful, false otherwise.
+        #[rust_name = "set_data_bits"]
+        fn setDataBits(self: Pin<&mut QSerialPort>, data_bits: QSerialPortDataBits) -> bool;
+
+        /// Sets the state of the line signal DTR (data terminal ready).
+        ///
+        /// Returns true if successful, false otherwise.
+        #[rust_name = "set_data_terminal_ready"]
+        fn setDataTerminalReady(self: Pin<&mut QSerialPort>, set: bool) -> bool;
+
+        /// Sets the desired flow control mode.
+        ///
+        /// Returns true if successful, false otherwise.
+        #[rust_name = "set_flow_control"]
+        fn setFlowControl(
+            self: Pin<&mut QSerialPort>,
+            flow_control: QSerialPortFlowControl,
+        ) -> bool;
+
+        /// Sets the parity checking mode.
+        ///
+        /// Returns true if successful, false otherwise.
+        #[rust_name = "set_parity"]
+        fn setParity(self: Pin<&mut QSerialPort>, parity: QSerialPortParity) -> bool;
+
+        /// Sets the port stored in the serial port info instance.
+        #[rust_name = "set_port"]
+        fn setPort(self: Pin<&mut QSerialPort>, info: &QSerialPortInfo);
+
+        /// Sets the name of the serial port, which can be a short name such as `ttyUSB0` or a system location such as `/dev/ttyUSB0`.
+        #[rust_name = "set_port_name"]
+        fn setPortName(self: Pin<&mut QSerialPort>, name: &QString);
+
+        /// Sets the state of the line signal RTS (request to send).
+        ///
+        /// Returns true if successful, false otherwise.
+        #[rust_name = "set_request_to_send"]
+        fn setRequestToSend(self: Pin<&mut QSerialPort>, set: bool) -> bool;
+
+        /// Sets the number of stop bits in a frame.
+        ///
+        /// Returns true if successful, false otherwise.
+        #[rust_name = "set_stop_bits"]
+        fn setStopBits(self: Pin<&mut QSerialPort>, stop_bits: QSerialPortStopBits) -> bool;
+
+        /// Returns the number of stop bits in a frame.
+        #[rust_name = "stop_bits"]
+        fn stopBits(self: &QSerialPort) -> QSerialPortStopBits;
+
+        /// Blocks until all pending data has been written to the serial port or msecs milliseconds have passed.
+        ///
+        /// Returns true if data was written, false otherwise.
+        #[rust_name = "wait_for_bytes_written"]
+        fn waitForBytesWritten(self: Pin<&mut QSerialPort>, msecs: i32) -> bool;
+
+        /// Blocks until new data is available for reading and the ready_read signal has been emitted,
+        /// or until msecs milliseconds have passed.
+        ///
+        /// Returns true if new data is available for reading, false otherwise.
+        #[rust_name = "wait_for_ready_read"]
+        fn waitForReadyRead(self: Pin<&mut QSerialPort>, msecs: i32) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QIODeviceOpenModeFlag;
+        type QSerialPortDataBits;
+        type QSerialPortFlowControl;
+        type QSerialPortParity;
+        type QSerialPortSerialPortError;
+        type QSerialPortStopBits;
+
+        #[doc(hidden)]
+        #[rust_name = "qserialport_new"]
+        fn qserialportNew() -> UniquePtr<QSerialPort>;
+
+        #[doc(hidden)]
+        #[rust_name = "qserialport_baud_rate"]
+        fn qserialportBaudRate(port: &QSerialPort) -> i32;
+
+        #[doc(hidden)]
+        #[rust_name = "qserialport_bytes_available"]
+        fn qserialportBytesAvailable(port: &QSerialPort) -> i64;
+
+        #[doc(hidden)]
+        #[rust_name = "qserialport_open"]
+        fn qserialportOpen(port: Pin<&mut QSerialPort>, mode: QIODeviceOpenModeFlag) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qserialport_read_all"]
+        fn qserialportReadAll(port: Pin<&mut QSerialPort>) -> QByteArray;
+
+        #[doc(hidden)]
+        #[rust_name = "qserialport_set_baud_rate"]
+        fn qserialportSetBaudRate(port: Pin<&mut QSerialPort>, baud_rate: i32) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qserialport_write"]
+        fn qserialportWrite(port: Pin<&mut QSerialPort>, data: &QByteArray) -> i64;
+    }
+
+    // QSerialPort is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QSerialPort>::isRelocatable);
+    impl UniquePtr<QSerialPort> {}
+}
+
+use core::pin::Pin;
+use cxx_qt_lib::QByteArray;
+
+pub use ffi::{
+    QIODeviceOpenModeFlag, QSerialPort, QSerialPortDataBits, QSerialPortFlowControl,
+    QSerialPortParity, QSerialPortSerialPortError, QSerialPortStopBits,
+};
+
+impl QSerialPort {
+    /// Returns the data baud rate for all directions, for example 9600 or 115200.
+    pub fn baud_rate(&self) -> i32 {
+        ffi::qserialport_baud_rate(self)
+    }
+
+    /// Returns the number of bytes that are available for reading.
+    pub fn bytes_available(&self) -> i64 {
+        ffi::qserialport_bytes_available(self)
+    }
+
+    /// Create a new QSerialPort, the port to use is then set with [QSerialPort::set_port_name] or [QSerialPort::set_port].
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qserialport_new()
+    }
+
+    /// Opens the serial port using the given mode.
+    ///
+    /// Returns true if successful, otherwise returns false and sets an error code which can be obtained with [QSerialPort::error].
+    pub fn open(self: Pin<&mut Self>, mode: QIODeviceOpenModeFlag) -> bool {
+        ffi::qserialport_open(self, mode)
+    }
+
+    /// Reads all remaining data that is available from the serial port.
+    pub fn read_all(self: Pin<&mut Self>) -> QByteArray {
+        ffi::qserialport_read_all(self)
+    }
+
+    /// Sets the data baud rate for all directions, for example 9600 or 115200.
+    ///
+    /// Returns true if successful, false otherwise.
+    pub fn set_baud_rate(self: Pin<&mut Self>, baud_rate: i32) -> bool {
+        ffi::qserialport_set_baud_rate(self, baud_rate)
+    }
+
+    /// Writes the data to the serial port, the data is written asynchronously from the event loop.
+    ///
+    /// Returns the number of bytes that were written, or -1 if an error occurred.
+    pub fn write(self: Pin<&mut Self>, data: &QByteArray) -> i64 {
+        ffi::qserialport_write(self, data)
+    }
+}
@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qserialportinfo.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<::std::vector<QSerialPortInfo>>
+qserialportinfoAvailablePorts()
+{
+  const auto ports = QSerialPortInfo::availablePorts();
+  return ::std::make_unique<::std::vector<QSerialPortInfo>>(ports.cbegin(),
+                                                            ports.cend());
+}
+
+::std::unique_ptr<QSerialPortInfo>
+qserialportinfoNew(const QString& name)
+{
+  return ::std::make_unique<QSerialPortInfo>(name);
+}
+
+}
+}
@@ -0,0 +1,83 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qserialportinfo.h");
+        /// Provides information about an existing serial port, such as its name, description and USB identifiers.
+        type QSerialPortInfo;
+
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        /// Returns the description string of the serial port, if available; otherwise returns an empty string.
+        fn description(self: &QSerialPortInfo) -> QString;
+
+        /// Returns true if there is a valid 16-bit product number present; otherwise returns false.
+        #[rust_name = "has_product_identifier"]
+        fn hasProductIdentifier(self: &QSerialPortInfo) -> bool;
+
+        /// Returns true if there is a valid 16-bit vendor number present; otherwise returns false.
+        #[rust_name = "has_vendor_identifier"]
+        fn hasVendorIdentifier(self: &QSerialPortInfo) -> bool;
+
+        /// Returns whether this QSerialPortInfo object holds a serial port definition.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QSerialPortInfo) -> bool;
+
+        /// Returns the manufacturer string of the serial port, if available; otherwise returns an empty string.
+        fn manufacturer(self: &QSerialPortInfo) -> QString;
+
+        /// Returns the name of the serial port, such as `ttyUSB0` or `COM1`.
+        #[rust_name = "port_name"]
+        fn portName(self: &QSerialPortInfo) -> QString;
+
+        /// Returns the 16-bit product number for the serial port, if available; otherwise returns zero.
+        #[rust_name = "product_identifier"]
+        fn productIdentifier(self: &QSerialPortInfo) -> u16;
+
+        /// Returns the serial number string of the serial port, if available; otherwise returns an empty string.
+        #[rust_name = "serial_number"]
+        fn serialNumber(self: &QSerialPortInfo) -> QString;
+
+        /// Returns the system location of the serial port, such as `/dev/ttyUSB0`.
+        #[rust_name = "system_location"]
+        fn systemLocation(self: &QSerialPortInfo) -> QString;
+
+        /// Returns the 16-bit vendor number for the serial port, if available; otherwise returns zero.
+        #[rust_name = "vendor_identifier"]
+        fn vendorIdentifier(self: &QSerialPortInfo) -> u16;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qserialportinfo_available_ports"]
+        fn qserialportinfoAvailablePorts() -> UniquePtr<CxxVector<QSerialPortInfo>>;
+
+        #[doc(hidden)]
+        #[rust_name = "qserialportinfo_new"]
+        fn qserialportinfoNew(name: &QString) -> UniquePtr<QSerialPortInfo>;
+    }
+}
+
+use cxx_qt_lib::QString;
+
+pub use ffi::QSerialPortInfo;
+
+impl QSerialPortInfo {
+    /// Returns a list of the serial ports which are available on the system.
+    pub fn available_ports() -> cxx::UniquePtr<cxx::CxxVector<Self>> {
+        ffi::qserialportinfo_available_ports()
+    }
+
+    /// Constructs a QSerialPortInfo object from the serial port name, such as `ttyUSB0` or `/dev/ttyUSB0`.
+    ///
+    /// If the serial port is not found, then [QSerialPortInfo::is_null] returns true.
+    pub fn new(name: &QString) -> cxx::UniquePtr<Self> {
+        ffi::qserialportinfo_new(name)
+    }
+}
@@ -0,0 +1,13 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qfinalstate;
+pub use qfinalstate::QFinalState;
+
+mod qstate;
+pub use qstate::QState;
+
+mod qstatemachine;
+pub use qstatemachine::QStateMachine;
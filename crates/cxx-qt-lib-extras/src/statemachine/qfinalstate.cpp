@@ -0,0 +1,21 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qfinalstate.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QFinalState*
+qfinalstateNew(QState& parent)
+{
+  // The parent state takes ownership of the final state
+  return new QFinalState(&parent);
+}
+
+}
+}
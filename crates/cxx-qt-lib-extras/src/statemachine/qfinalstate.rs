@@ -0,0 +1,56 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qstate.h");
+        type QState = crate::QState;
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qfinalstate.h");
+        /// The QFinalState class provides a final state.
+        ///
+        /// When a final state of a [QState](crate::QState) is entered, the parent state emits its finished signal,
+        /// and when a top level final state is entered the [QStateMachine](crate::QStateMachine) finishes.
+        #[qobject]
+        type QFinalState;
+
+        /// This signal is emitted when the state has been entered.
+        #[qsignal]
+        fn entered(self: Pin<&mut QFinalState>);
+
+        /// This signal is emitted when the state has been exited.
+        #[qsignal]
+        fn exited(self: Pin<&mut QFinalState>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qfinalstate_new"]
+        fn qfinalstateNew(parent: Pin<&mut QState>) -> *mut QFinalState;
+    }
+
+    // QFinalState is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QFinalState>::isRelocatable);
+}
+
+use crate::QState;
+use core::pin::Pin;
+
+pub use ffi::QFinalState;
+
+impl QFinalState {
+    /// Constructs a new final state which is a child of the given parent state.
+    ///
+    /// The final state is owned by the parent state and is deleted with it.
+    pub fn new(parent: Pin<&mut QState>) -> *mut Self {
+        ffi::qfinalstate_new(parent)
+    }
+}
@@ -0,0 +1,64 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qstate.h"
+
+#include <QtCore/QByteArray>
+
+namespace {
+
+bool
+addSignalTransition(QState& state,
+                    const QObject* sender,
+                    ::rust::Str signal,
+                    QAbstractState* target)
+{
+  // Prefix the signature in the same way as the SIGNAL macro
+  const QByteArray signature = QByteArray::number(QSIGNAL_CODE) +
+                               QByteArray(signal.data(), signal.size());
+  return state.addTransition(sender, signature.constData(), target) !=
+         nullptr;
+}
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+bool
+qstateAddTransition(QState& state,
+                    const QObject* sender,
+                    ::rust::Str signal,
+                    QState* target)
+{
+  return addSignalTransition(state, sender, signal, target);
+}
+
+bool
+qstateAddTransitionToFinalState(QState& state,
+                                const QObject* sender,
+                                ::rust::Str signal,
+                                QFinalState* target)
+{
+  return addSignalTransition(state, sender, signal, target);
+}
+
+QState*
+qstateNew(QState& parent)
+{
+  // The parent state takes ownership of the state
+  return new QState(&parent);
+}
+
+void
+qstateSetInitialState(QState& state, QState* initialState)
+{
+  state.setInitialState(initialState);
+}
+
+}
+}
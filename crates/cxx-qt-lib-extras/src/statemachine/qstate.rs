@@ -0,0 +1,131 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt_lib::QObject;
+        include!("cxx-qt-lib-extras/qfinalstate.h");
+        type QFinalState = crate::QFinalState;
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qstate.h");
+        /// The QState class provides a general-purpose state for [QStateMachine](crate::QStateMachine).
+        ///
+        /// States can have child states, and transitions to other states when a signal is emitted,
+        /// so that the flow of a user interface can be modelled while the actions stay in Rust closures
+        /// connected to the entered and exited signals.
+        #[qobject]
+        type QState;
+
+        /// This signal is emitted when the state has been entered.
+        #[qsignal]
+        fn entered(self: Pin<&mut QState>);
+
+        /// This signal is emitted when the state has been exited.
+        #[qsignal]
+        fn exited(self: Pin<&mut QState>);
+
+        /// This signal is emitted when a final child state of this state is entered.
+        #[qsignal]
+        fn finished(self: Pin<&mut QState>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qstate_add_transition"]
+        unsafe fn qstateAddTransition(
+            state: Pin<&mut QState>,
+            sender: *const QObject,
+            signal: &str,
+            target: *mut QState,
+        ) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qstate_add_transition_to_final_state"]
+        unsafe fn qstateAddTransitionToFinalState(
+            state: Pin<&mut QState>,
+            sender: *const QObject,
+            signal: &str,
+            target: *mut QFinalState,
+        ) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qstate_new"]
+        fn qstateNew(parent: Pin<&mut QState>) -> *mut QState;
+
+        #[doc(hidden)]
+        #[rust_name = "qstate_set_initial_state"]
+        unsafe fn qstateSetInitialState(state: Pin<&mut QState>, initial_state: *mut QState);
+    }
+
+    // QState is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QState>::isRelocatable);
+}
+
+use crate::QFinalState;
+use core::pin::Pin;
+use cxx_qt_lib::QObject;
+
+pub use ffi::QState;
+
+impl QState {
+    /// Constructs a new state which is a child of the given parent state,
+    /// which can be the [QStateMachine](crate::QStateMachine) using [QStateMachine::as_qstate](crate::QStateMachine::as_qstate).
+    ///
+    /// The state is owned by the parent state and is deleted with it.
+    pub fn new(parent: Pin<&mut QState>) -> *mut Self {
+        ffi::qstate_new(parent)
+    }
+
+    /// Adds a transition from this state to the target state, which is triggered when the sender emits the signal.
+    ///
+    /// The signal is given as its C++ signature, such as `valueChanged(int)`,
+    /// for a `#[qsignal]` of a CXX-Qt QObject this is the camel case name of the signal.
+    /// Returns false if the signal does not exist on the sender.
+    ///
+    /// # Safety
+    ///
+    /// The sender must be a valid pointer to a QObject, and the target must be a valid pointer
+    /// to a state of the same state machine.
+    pub unsafe fn add_transition(
+        self: Pin<&mut Self>,
+        sender: *const QObject,
+        signal: &str,
+        target: *mut QState,
+    ) -> bool {
+        ffi::qstate_add_transition(self, sender, signal, target)
+    }
+
+    /// Adds a transition from this state to the target final state, which is triggered when the sender emits the signal,
+    /// see [QState::add_transition].
+    ///
+    /// # Safety
+    ///
+    /// The sender must be a valid pointer to a QObject, and the target must be a valid pointer
+    /// to a final state of the same state machine.
+    pub unsafe fn add_transition_to_final_state(
+        self: Pin<&mut Self>,
+        sender: *const QObject,
+        signal: &str,
+        target: *mut QFinalState,
+    ) -> bool {
+        ffi::qstate_add_transition_to_final_state(self, sender, signal, target)
+    }
+
+    /// Sets this state's initial state, which is entered when this state is entered.
+    ///
+    /// # Safety
+    ///
+    /// The initial state must be a valid pointer to a child state of this state.
+    pub unsafe fn set_initial_state(self: Pin<&mut Self>, initial_state: *mut QState) {
+        ffi::qstate_set_initial_state(self, initial_state)
+    }
+}
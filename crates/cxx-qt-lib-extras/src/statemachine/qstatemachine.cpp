@@ -0,0 +1,26 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qstatemachine.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QState&
+qstatemachineAsQState(QStateMachine& machine)
+{
+  return static_cast<QState&>(machine);
+}
+
+::std::unique_ptr<QStateMachine>
+qstatemachineNew()
+{
+  return ::std::make_unique<QStateMachine>();
+}
+
+}
+}
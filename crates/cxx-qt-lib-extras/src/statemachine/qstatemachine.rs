@@ -0,0 +1,102 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib-extras/qstate.h");
+        type QState = crate::QState;
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qstatemachine.h");
+        /// The QStateMachine class provides a hierarchical finite state machine.
+        ///
+        /// The states of the machine are created as children of [QStateMachine::as_qstate],
+        /// and the machine is started with [QStateMachine::start] once its initial state has been set.
+        ///
+        /// ```ignore
+        /// let mut machine = QStateMachine::new();
+        /// let idle = QState::new(machine.pin_mut().as_qstate());
+        /// let busy = QState::new(machine.pin_mut().as_qstate());
+        /// unsafe {
+        ///     machine.pin_mut().as_qstate().set_initial_state(idle);
+        ///     Pin::new_unchecked(&mut *idle).add_transition(button, "clicked()", busy);
+        ///     Pin::new_unchecked(&mut *busy)
+        ///         .on_entered(|_| println!("busy"))
+        ///         .release();
+        /// }
+        /// machine.pin_mut().start();
+        /// ```
+        #[qobject]
+        type QStateMachine;
+
+        /// This signal is emitted when the running property changes with running as the new value.
+        #[qsignal]
+        #[rust_name = "running_changed"]
+        fn runningChanged(self: Pin<&mut QStateMachine>, running: bool);
+
+        /// This signal is emitted when the state machine has entered its initial state.
+        #[qsignal]
+        fn started(self: Pin<&mut QStateMachine>);
+
+        /// This signal is emitted when the state machine has stopped.
+        #[qsignal]
+        fn stopped(self: Pin<&mut QStateMachine>);
+    }
+
+    unsafe extern "C++" {
+        /// Returns the error string of the last error that occurred.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QStateMachine) -> QString;
+
+        /// Returns whether this state machine is running.
+        #[rust_name = "is_running"]
+        fn isRunning(self: &QStateMachine) -> bool;
+
+        /// Starts this state machine, which enters its initial state.
+        fn start(self: Pin<&mut QStateMachine>);
+
+        /// Stops this state machine.
+        fn stop(self: Pin<&mut QStateMachine>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qstatemachine_as_qstate"]
+        fn qstatemachineAsQState(ptr: Pin<&mut QStateMachine>) -> Pin<&mut QState>;
+
+        #[doc(hidden)]
+        #[rust_name = "qstatemachine_new"]
+        fn qstatemachineNew() -> UniquePtr<QStateMachine>;
+    }
+
+    // QStateMachine is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QStateMachine>::isRelocatable);
+    impl UniquePtr<QStateMachine> {}
+}
+
+use crate::QState;
+use core::pin::Pin;
+
+pub use ffi::QStateMachine;
+
+impl QStateMachine {
+    /// Constructs a new state machine.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qstatemachine_new()
+    }
+
+    /// Convert the existing [QStateMachine] to its root [QState],
+    /// which is the parent of the top level states of the machine.
+    pub fn as_qstate(self: Pin<&mut Self>) -> Pin<&mut QState> {
+        ffi::qstatemachine_as_qstate(self)
+    }
+}
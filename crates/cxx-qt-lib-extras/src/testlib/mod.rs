@@ -0,0 +1,16 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qabstractitemmodeltester;
+pub use qabstractitemmodeltester::QAbstractItemModelTester;
+
+mod qsignalspy;
+pub use qsignalspy::{QSignalSpy, QSignalSpyArguments};
+
+mod qtest;
+pub use qtest::QTest;
+
+mod qtestapplication;
+pub use qtestapplication::QTestApplication;
@@ -0,0 +1,61 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qabstractitemmodeltester.h"
+
+#include <mutex>
+
+#include <QtCore/QMutex>
+#include <QtCore/QMutexLocker>
+
+namespace {
+
+QMutex failuresMutex;
+QList<QString> failures;
+QtMessageHandler previousMessageHandler = nullptr;
+
+void
+messageHandler(QtMsgType type,
+               const QMessageLogContext& context,
+               const QString& message)
+{
+  // QAbstractItemModelTester reports failures as warnings in this category
+  if (type == QtWarningMsg && context.category != nullptr &&
+      qstrcmp(context.category, "qt.modeltest") == 0) {
+    QMutexLocker locker(&failuresMutex);
+    failures.append(message);
+  }
+
+  if (previousMessageHandler != nullptr) {
+    previousMessageHandler(type, context, message);
+  }
+}
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qabstractitemmodeltesterInstallMessageHandler()
+{
+  static ::std::once_flag installed;
+  ::std::call_once(installed, []() {
+    previousMessageHandler = qInstallMessageHandler(messageHandler);
+  });
+}
+
+QList<QString>
+qabstractitemmodeltesterTakeFailures()
+{
+  QMutexLocker locker(&failuresMutex);
+  QList<QString> taken;
+  taken.swap(failures);
+  return taken;
+}
+
+}
+}
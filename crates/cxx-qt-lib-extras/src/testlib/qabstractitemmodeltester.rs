@@ -0,0 +1,81 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qabstractitemmodeltester.h");
+        /// The QAbstractItemModelTester class helps testing QAbstractItemModel subclasses.
+        ///
+        /// The tester checks the model each time it changes, for example that the row counts
+        /// and parents of indexes are consistent with the signals that the model emitted.
+        ///
+        /// As Rust QObjects cannot be upcast to QAbstractItemModel yet, a tester is created
+        /// by declaring the `qabstractitemmodeltesterNew` template in the bridge of the model.
+        ///
+        /// ```ignore
+        /// #[cxx_qt::bridge]
+        /// mod ffi {
+        ///     unsafe extern "C++" {
+        ///         include!("cxx-qt-lib-extras/qabstractitemmodeltester.h");
+        ///         type QAbstractItemModelTester = cxx_qt_lib_extras::QAbstractItemModelTester;
+        ///     }
+        ///
+        ///     #[namespace = "rust::cxxqtlib1"]
+        ///     unsafe extern "C++" {
+        ///         #[rust_name = "attach_model_tester"]
+        ///         fn qabstractitemmodeltesterNew(
+        ///             model: Pin<&mut CustomBaseClass>,
+        ///         ) -> UniquePtr<QAbstractItemModelTester>;
+        ///     }
+        /// }
+        /// ```
+        ///
+        /// The tester then stays attached to the model until the `UniquePtr` is dropped.
+        /// Failures are collected rather than aborting, call [QAbstractItemModelTester::assert_no_failures]
+        /// to turn them into a Rust panic. To only attach the tester in debug and test builds,
+        /// use `#[cfg(debug_assertions)]` on the code which creates it.
+        type QAbstractItemModelTester;
+
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QString = cxx_qt_lib::QList<cxx_qt_lib::QString>;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qabstractitemmodeltester_take_failures"]
+        fn qabstractitemmodeltesterTakeFailures() -> QList_QString;
+    }
+
+    impl UniquePtr<QAbstractItemModelTester> {}
+}
+
+pub use ffi::QAbstractItemModelTester;
+
+impl QAbstractItemModelTester {
+    /// Returns the failures reported by any QAbstractItemModelTester since the last call,
+    /// clearing the list of failures.
+    pub fn take_failures() -> Vec<String> {
+        ffi::qabstractitemmodeltester_take_failures()
+            .iter()
+            .map(|failure| failure.to_string())
+            .collect()
+    }
+
+    /// Panics if any QAbstractItemModelTester has reported a failure since the last call,
+    /// with a message containing all of the failures.
+    ///
+    /// This is intended to be called at the end of a test, or after each change to the model.
+    pub fn assert_no_failures() {
+        let failures = Self::take_failures();
+        if !failures.is_empty() {
+            panic!(
+                "QAbstractItemModelTester reported failures:\n{}",
+                failures.join("\n")
+            );
+        }
+    }
+}
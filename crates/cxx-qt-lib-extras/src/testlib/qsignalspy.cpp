@@ -0,0 +1,52 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qsignalspy.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSignalSpy>
+qsignalspyNew(const QObject& object, ::rust::Str signal)
+{
+  // QSignalSpy expects the signature in the form that the SIGNAL macro creates,
+  // which is the normalized signature prefixed with the signal code.
+  QByteArray signature = QMetaObject::normalizedSignature(
+    QByteArray(signal.data(), static_cast<int>(signal.size())).constData());
+  signature.prepend(QByteArray::number(QSIGNAL_CODE));
+  return ::std::make_unique<QSignalSpy>(&object, signature.constData());
+}
+
+::rust::isize
+qsignalspyCount(const QSignalSpy& spy)
+{
+  return static_cast<::rust::isize>(spy.count());
+}
+
+QByteArray
+qsignalspySignal(const QSignalSpy& spy)
+{
+  return spy.signal();
+}
+
+QList<QVariant>
+qsignalspyTakeFirst(QSignalSpy& spy)
+{
+  if (spy.isEmpty()) {
+    return QList<QVariant>();
+  }
+
+  return spy.takeFirst();
+}
+
+bool
+qsignalspyWait(QSignalSpy& spy, ::std::int32_t timeout)
+{
+  return spy.wait(static_cast<int>(timeout));
+}
+
+}
+}
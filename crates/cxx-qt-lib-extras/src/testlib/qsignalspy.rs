@@ -0,0 +1,127 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qsignalspy.h");
+        /// The QSignalSpy class enables introspection of signal emission.
+        ///
+        /// Each emission of the signal is recorded with its arguments,
+        /// so that tests of QObjects can assert on the signals that have been emitted.
+        type QSignalSpy;
+
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QVariant = cxx_qt_lib::QList<cxx_qt_lib::QVariant>;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt::QObject;
+
+        /// Returns true if the signal spy listens to a valid signal, otherwise false.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QSignalSpy) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qsignalspy_new"]
+        fn qsignalspyNew(object: &QObject, signal: &str) -> UniquePtr<QSignalSpy>;
+
+        #[doc(hidden)]
+        #[rust_name = "qsignalspy_count"]
+        fn qsignalspyCount(spy: &QSignalSpy) -> isize;
+
+        #[doc(hidden)]
+        #[rust_name = "qsignalspy_signal"]
+        fn qsignalspySignal(spy: &QSignalSpy) -> QByteArray;
+
+        #[doc(hidden)]
+        #[rust_name = "qsignalspy_take_first"]
+        fn qsignalspyTakeFirst(spy: Pin<&mut QSignalSpy>) -> QList_QVariant;
+
+        #[doc(hidden)]
+        #[rust_name = "qsignalspy_wait"]
+        fn qsignalspyWait(spy: Pin<&mut QSignalSpy>, timeout: i32) -> bool;
+    }
+}
+
+use core::pin::Pin;
+use cxx_qt::QObject;
+use cxx_qt_lib::{QByteArray, QList, QVariant, QVariantValue};
+
+pub use ffi::QSignalSpy;
+
+impl QSignalSpy {
+    /// Constructs a new QSignalSpy that listens for emissions of the signal from the QObject object.
+    ///
+    /// The signal is the C++ signature of the signal, for example `"valueChanged(int)"`.
+    /// If the object does not have the signal, the spy is not valid.
+    pub fn new(object: &QObject, signal: &str) -> cxx::UniquePtr<Self> {
+        ffi::qsignalspy_new(object, signal)
+    }
+
+    /// Returns the number of signal emissions which have been recorded.
+    pub fn count(&self) -> isize {
+        ffi::qsignalspy_count(self)
+    }
+
+    /// Returns the normalized signal the spy is currently listening to.
+    pub fn signal(&self) -> QByteArray {
+        ffi::qsignalspy_signal(self)
+    }
+
+    /// Removes the first recorded emission and returns its arguments converted to T,
+    /// or None if there are no recorded emissions.
+    ///
+    /// If the arguments cannot be converted to T then None is returned,
+    /// note that the emission is still removed from the spy.
+    pub fn take_first<T: QSignalSpyArguments>(self: Pin<&mut Self>) -> Option<T> {
+        if self.count() == 0 {
+            return None;
+        }
+
+        T::from_arguments(&ffi::qsignalspy_take_first(self))
+    }
+
+    /// Starts an event loop that runs until the given signal is received or timeout milliseconds have passed.
+    ///
+    /// Returns true if the signal was emitted at least once in timeout milliseconds, otherwise returns false.
+    pub fn wait(self: Pin<&mut Self>, timeout: i32) -> bool {
+        ffi::qsignalspy_wait(self, timeout)
+    }
+}
+
+/// Trait for the arguments of a recorded signal emission which can be taken from a [QSignalSpy].
+///
+/// This is implemented for tuples of types which implement [QVariantValue].
+pub trait QSignalSpyArguments: Sized {
+    /// Converts the arguments of a recorded signal emission,
+    /// returning None if the number or types of the arguments do not match.
+    fn from_arguments(arguments: &QList<QVariant>) -> Option<Self>;
+}
+
+macro_rules! impl_qsignalspy_arguments {
+    ( $count:literal; $( $index:tt => $type:ident ),* ) => {
+        impl<$( $type: QVariantValue ),*> QSignalSpyArguments for ( $( $type, )* ) {
+            fn from_arguments(arguments: &QList<QVariant>) -> Option<Self> {
+                if arguments.len() != $count {
+                    return None;
+                }
+
+                Some(( $( arguments.get($index)?.value::<$type>()?, )* ))
+            }
+        }
+    };
+}
+
+impl_qsignalspy_arguments!(0;);
+impl_qsignalspy_arguments!(1; 0 => A);
+impl_qsignalspy_arguments!(2; 0 => A, 1 => B);
+impl_qsignalspy_arguments!(3; 0 => A, 1 => B, 2 => C);
+impl_qsignalspy_arguments!(4; 0 => A, 1 => B, 2 => C, 3 => D);
+impl_qsignalspy_arguments!(5; 0 => A, 1 => B, 2 => C, 3 => D, 4 => E);
+impl_qsignalspy_arguments!(6; 0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F);
@@ -0,0 +1,137 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qtest.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qtestKeyClick(QWindow& window,
+              Qt::Key key,
+              Qt::KeyboardModifier modifier,
+              ::std::int32_t delay)
+{
+  // The modifier can be a combination of flags, so convert to QFlags
+  QTest::keyClick(&window,
+                  key,
+                  Qt::KeyboardModifiers(modifier),
+                  static_cast<int>(delay));
+}
+
+void
+qtestKeyPress(QWindow& window,
+              Qt::Key key,
+              Qt::KeyboardModifier modifier,
+              ::std::int32_t delay)
+{
+  QTest::keyPress(&window,
+                  key,
+                  Qt::KeyboardModifiers(modifier),
+                  static_cast<int>(delay));
+}
+
+void
+qtestKeyRelease(QWindow& window,
+                Qt::Key key,
+                Qt::KeyboardModifier modifier,
+                ::std::int32_t delay)
+{
+  QTest::keyRelease(&window,
+                    key,
+                    Qt::KeyboardModifiers(modifier),
+                    static_cast<int>(delay));
+}
+
+void
+qtestMouseClick(QWindow& window,
+                Qt::MouseButton button,
+                Qt::KeyboardModifier modifier,
+                const QPoint& pos,
+                ::std::int32_t delay)
+{
+  QTest::mouseClick(&window,
+                    button,
+                    Qt::KeyboardModifiers(modifier),
+                    pos,
+                    static_cast<int>(delay));
+}
+
+void
+qtestMouseDoubleClick(QWindow& window,
+                      Qt::MouseButton button,
+                      Qt::KeyboardModifier modifier,
+                      const QPoint& pos,
+                      ::std::int32_t delay)
+{
+  QTest::mouseDClick(&window,
+                     button,
+                     Qt::KeyboardModifiers(modifier),
+                     pos,
+                     static_cast<int>(delay));
+}
+
+void
+qtestMouseMove(QWindow& window, const QPoint& pos, ::std::int32_t delay)
+{
+  QTest::mouseMove(&window, pos, static_cast<int>(delay));
+}
+
+void
+qtestMousePress(QWindow& window,
+                Qt::MouseButton button,
+                Qt::KeyboardModifier modifier,
+                const QPoint& pos,
+                ::std::int32_t delay)
+{
+  QTest::mousePress(&window,
+                    button,
+                    Qt::KeyboardModifiers(modifier),
+                    pos,
+                    static_cast<int>(delay));
+}
+
+void
+qtestMouseRelease(QWindow& window,
+                  Qt::MouseButton button,
+                  Qt::KeyboardModifier modifier,
+                  const QPoint& pos,
+                  ::std::int32_t delay)
+{
+  QTest::mouseRelease(&window,
+                      button,
+                      Qt::KeyboardModifiers(modifier),
+                      pos,
+                      static_cast<int>(delay));
+}
+
+QWindow*
+qtestRootWindow(QQmlApplicationEngine& engine)
+{
+  const auto rootObjects = engine.rootObjects();
+  for (auto* rootObject : rootObjects) {
+    if (auto* window = qobject_cast<QWindow*>(rootObject)) {
+      return window;
+    }
+  }
+
+  return nullptr;
+}
+
+bool
+qtestWaitForWindowActive(QWindow& window, ::std::int32_t timeout)
+{
+  return QTest::qWaitForWindowActive(&window, static_cast<int>(timeout));
+}
+
+bool
+qtestWaitForWindowExposed(QWindow& window, ::std::int32_t timeout)
+{
+  return QTest::qWaitForWindowExposed(&window, static_cast<int>(timeout));
+}
+
+}
+}
@@ -0,0 +1,246 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{Key, KeyboardModifier, MouseButton, QPoint, QQmlApplicationEngine};
+
+use crate::QWindow;
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type Key = cxx_qt_lib::Key;
+        type KeyboardModifier = cxx_qt_lib::KeyboardModifier;
+        type MouseButton = cxx_qt_lib::MouseButton;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpoint.h");
+        type QPoint = cxx_qt_lib::QPoint;
+        include!("cxx-qt-lib/qqmlapplicationengine.h");
+        type QQmlApplicationEngine = cxx_qt_lib::QQmlApplicationEngine;
+        include!("cxx-qt-lib-extras/qwindow.h");
+        type QWindow = crate::QWindow;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qtest.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qtest_key_click"]
+        fn qtestKeyClick(
+            window: Pin<&mut QWindow>,
+            key: Key,
+            modifier: KeyboardModifier,
+            delay: i32,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qtest_key_press"]
+        fn qtestKeyPress(
+            window: Pin<&mut QWindow>,
+            key: Key,
+            modifier: KeyboardModifier,
+            delay: i32,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qtest_key_release"]
+        fn qtestKeyRelease(
+            window: Pin<&mut QWindow>,
+            key: Key,
+            modifier: KeyboardModifier,
+            delay: i32,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qtest_mouse_click"]
+        fn qtestMouseClick(
+            window: Pin<&mut QWindow>,
+            button: MouseButton,
+            modifier: KeyboardModifier,
+            pos: &QPoint,
+            delay: i32,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qtest_mouse_double_click"]
+        fn qtestMouseDoubleClick(
+            window: Pin<&mut QWindow>,
+            button: MouseButton,
+            modifier: KeyboardModifier,
+            pos: &QPoint,
+            delay: i32,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qtest_mouse_move"]
+        fn qtestMouseMove(window: Pin<&mut QWindow>, pos: &QPoint, delay: i32);
+
+        #[doc(hidden)]
+        #[rust_name = "qtest_mouse_press"]
+        fn qtestMousePress(
+            window: Pin<&mut QWindow>,
+            button: MouseButton,
+            modifier: KeyboardModifier,
+            pos: &QPoint,
+            delay: i32,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qtest_mouse_release"]
+        fn qtestMouseRelease(
+            window: Pin<&mut QWindow>,
+            button: MouseButton,
+            modifier: KeyboardModifier,
+            pos: &QPoint,
+            delay: i32,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qtest_root_window"]
+        fn qtestRootWindow(engine: Pin<&mut QQmlApplicationEngine>) -> *mut QWindow;
+
+        #[doc(hidden)]
+        #[rust_name = "qtest_wait_for_window_active"]
+        fn qtestWaitForWindowActive(window: Pin<&mut QWindow>, timeout: i32) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qtest_wait_for_window_exposed"]
+        fn qtestWaitForWindowExposed(window: Pin<&mut QWindow>, timeout: i32) -> bool;
+    }
+}
+
+/// Simulation of mouse and keyboard input on a [QWindow], for example the QQuickWindow of a QML `ApplicationWindow`.
+///
+/// These must be called from within [QTestApplication::run_gui](crate::QTestApplication::run_gui),
+/// so that there is a QGuiApplication to deliver the events.
+///
+/// ```ignore
+/// #[test]
+/// fn button_click_increments() {
+///     QTestApplication::run_gui(|| {
+///         let mut engine = QQmlApplicationEngine::new();
+///         engine.pin_mut().load(&QUrl::from("qrc:/qt/qml/com/kdab/cxx_qt/demo/qml/main.qml"));
+///
+///         let window = QTest::root_window(engine.pin_mut());
+///         let mut window = unsafe { Pin::new_unchecked(&mut *window) };
+///         assert!(QTest::wait_for_window_exposed(window.as_mut(), 5000));
+///
+///         QTest::mouse_click(
+///             window.as_mut(),
+///             MouseButton::LeftButton,
+///             KeyboardModifier::NoModifier,
+///             &QPoint::new(50, 50),
+///             -1,
+///         );
+///         assert!(QTestApplication::wait_for(|| counter_value() == 1, 1000));
+///     });
+/// }
+/// ```
+///
+/// A delay in milliseconds is waited before each event is sent, a negative delay uses the default of QTest.
+/// The modifiers can be combined using the `|` operator.
+pub struct QTest;
+
+impl QTest {
+    /// Simulates clicking a key with an optional modifier on a window.
+    pub fn key_click(window: Pin<&mut QWindow>, key: Key, modifier: KeyboardModifier, delay: i32) {
+        ffi::qtest_key_click(window, key, modifier, delay)
+    }
+
+    /// Simulates pressing a key with an optional modifier on a window.
+    pub fn key_press(window: Pin<&mut QWindow>, key: Key, modifier: KeyboardModifier, delay: i32) {
+        ffi::qtest_key_press(window, key, modifier, delay)
+    }
+
+    /// Simulates releasing a key with an optional modifier on a window.
+    pub fn key_release(
+        window: Pin<&mut QWindow>,
+        key: Key,
+        modifier: KeyboardModifier,
+        delay: i32,
+    ) {
+        ffi::qtest_key_release(window, key, modifier, delay)
+    }
+
+    /// Simulates clicking a mouse button with an optional modifier on a window.
+    /// The position of the click is defined by pos, in window local coordinates.
+    pub fn mouse_click(
+        window: Pin<&mut QWindow>,
+        button: MouseButton,
+        modifier: KeyboardModifier,
+        pos: &QPoint,
+        delay: i32,
+    ) {
+        ffi::qtest_mouse_click(window, button, modifier, pos, delay)
+    }
+
+    /// Simulates double clicking a mouse button with an optional modifier on a window.
+    /// The position of the click is defined by pos, in window local coordinates.
+    pub fn mouse_double_click(
+        window: Pin<&mut QWindow>,
+        button: MouseButton,
+        modifier: KeyboardModifier,
+        pos: &QPoint,
+        delay: i32,
+    ) {
+        ffi::qtest_mouse_double_click(window, button, modifier, pos, delay)
+    }
+
+    /// Moves the mouse pointer to a window.
+    /// The mouse is moved to pos, in window local coordinates.
+    pub fn mouse_move(window: Pin<&mut QWindow>, pos: &QPoint, delay: i32) {
+        ffi::qtest_mouse_move(window, pos, delay)
+    }
+
+    /// Simulates pressing a mouse button with an optional modifier on a window.
+    /// The position is defined by pos, in window local coordinates.
+    pub fn mouse_press(
+        window: Pin<&mut QWindow>,
+        button: MouseButton,
+        modifier: KeyboardModifier,
+        pos: &QPoint,
+        delay: i32,
+    ) {
+        ffi::qtest_mouse_press(window, button, modifier, pos, delay)
+    }
+
+    /// Simulates releasing a mouse button with an optional modifier on a window.
+    /// The position of the release is defined by pos, in window local coordinates.
+    pub fn mouse_release(
+        window: Pin<&mut QWindow>,
+        button: MouseButton,
+        modifier: KeyboardModifier,
+        pos: &QPoint,
+        delay: i32,
+    ) {
+        ffi::qtest_mouse_release(window, button, modifier, pos, delay)
+    }
+
+    /// Returns the first root object of the engine which is a window,
+    /// or a null pointer if the engine has not loaded a window.
+    pub fn root_window(engine: Pin<&mut QQmlApplicationEngine>) -> *mut QWindow {
+        ffi::qtest_root_window(engine)
+    }
+
+    /// Returns true if the window is active within timeout milliseconds.
+    /// Otherwise returns false.
+    pub fn wait_for_window_active(window: Pin<&mut QWindow>, timeout: i32) -> bool {
+        ffi::qtest_wait_for_window_active(window, timeout)
+    }
+
+    /// Returns true if the window is exposed within timeout milliseconds.
+    /// Otherwise returns false.
+    ///
+    /// This is useful after showing a window, so that input is only simulated once the window
+    /// has been mapped to the screen.
+    pub fn wait_for_window_exposed(window: Pin<&mut QWindow>, timeout: i32) -> bool {
+        ffi::qtest_wait_for_window_exposed(window, timeout)
+    }
+}
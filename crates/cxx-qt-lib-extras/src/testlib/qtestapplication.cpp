@@ -0,0 +1,28 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib-extras/qtestapplication.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qtestapplicationProcessEvents()
+{
+  QCoreApplication::processEvents(QEventLoop::AllEvents);
+  // Objects which called deleteLater are only deleted when control returns to
+  // the event loop, which never happens when there is no running event loop.
+  QCoreApplication::sendPostedEvents(nullptr, QEvent::DeferredDelete);
+}
+
+void
+qtestapplicationWait(::std::int32_t timeout)
+{
+  QTest::qWait(static_cast<int>(timeout));
+}
+
+}
+}
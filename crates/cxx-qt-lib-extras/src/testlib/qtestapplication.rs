@@ -0,0 +1,205 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Mutex, OnceLock},
+    time::{Duration, Instant},
+};
+
+use cxx_qt_lib::{QCoreApplication, QGuiApplication};
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qtestapplication.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qtestapplication_process_events"]
+        fn qtestapplicationProcessEvents();
+
+        #[doc(hidden)]
+        #[rust_name = "qtestapplication_wait"]
+        fn qtestapplicationWait(timeout: i32);
+    }
+}
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// The thread which owns the QCoreApplication or QGuiApplication
+struct ApplicationThread {
+    sender: Mutex<mpsc::Sender<Job>>,
+    gui: bool,
+}
+
+/// Returns the thread which owns the application, starting the thread if this is the first call
+/// in the test binary. The first call decides whether a QGuiApplication is created.
+fn application_thread(gui: bool) -> &'static ApplicationThread {
+    static THREAD: OnceLock<ApplicationThread> = OnceLock::new();
+
+    THREAD.get_or_init(|| {
+        // Allow for GUI tests to run without a display, unless a platform has been chosen
+        if gui && std::env::var_os("QT_QPA_PLATFORM").is_none() {
+            std::env::set_var("QT_QPA_PLATFORM", "offscreen");
+        }
+
+        let (sender, receiver) = mpsc::channel::<Job>();
+        std::thread::Builder::new()
+            .name("qtestapplication".to_owned())
+            .spawn(move || {
+                if gui {
+                    let _application = QGuiApplication::new();
+                    receiver.into_iter().for_each(|job| job());
+                } else {
+                    let _application = QCoreApplication::new();
+                    receiver.into_iter().for_each(|job| job());
+                }
+            })
+            .expect("Failed to spawn the QTestApplication thread");
+
+        ApplicationThread {
+            sender: Mutex::new(sender),
+            gui,
+        }
+    })
+}
+
+/// Support for testing QObjects from `cargo test` without a QML engine.
+///
+/// Qt requires that there is a single QCoreApplication and that QObjects are used from the thread they live in,
+/// whereas `cargo test` runs each test on its own thread. So the first call to [QTestApplication::run] in a test binary
+/// starts a thread which creates a QCoreApplication, then every closure given to [QTestApplication::run] is executed
+/// on that thread one at a time.
+///
+/// Tests which show windows or simulate input with [QTest](crate::QTest) use [QTestApplication::run_gui] instead,
+/// which creates a QGuiApplication.
+///
+/// ```ignore
+/// #[test]
+/// fn counter_increments() {
+///     QTestApplication::run(|| {
+///         let mut counter = ffi::counter_new();
+///         counter.pin_mut().increment();
+///         QTestApplication::process_events();
+///         assert_eq!(*counter.count(), 1);
+///     });
+/// }
+/// ```
+///
+/// Generated QObjects can be constructed directly by declaring the `make_unique` helper of cxx-qt-lib in the bridge.
+///
+/// ```ignore
+/// #[namespace = "rust::cxxqtlib1"]
+/// unsafe extern "C++" {
+///     include!("cxx-qt-lib/common.h");
+///
+///     #[rust_name = "counter_new"]
+///     fn make_unique() -> UniquePtr<Counter>;
+/// }
+/// ```
+pub struct QTestApplication;
+
+impl QTestApplication {
+    /// Runs the closure on the thread which owns the QCoreApplication and returns its result.
+    ///
+    /// Once the closure has returned any pending events are processed,
+    /// so that objects which called deleteLater are destroyed before the next test runs.
+    ///
+    /// If the closure panics then the panic is resumed on the calling thread, so the test fails as normal.
+    pub fn run<F, R>(f: F) -> R
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        Self::run_on(application_thread(false), f)
+    }
+
+    /// Runs the closure on the thread which owns a QGuiApplication and returns its result,
+    /// this is needed to show windows and simulate input with [QTest](crate::QTest).
+    ///
+    /// If the `QT_QPA_PLATFORM` environment variable is not set then the `offscreen` platform is used,
+    /// so that tests can run without a display.
+    ///
+    /// As there can only be one application, all tests in a test binary which need a QGuiApplication
+    /// should use this method. Otherwise this panics if a QCoreApplication was already created by [QTestApplication::run].
+    pub fn run_gui<F, R>(f: F) -> R
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let thread = application_thread(true);
+        assert!(
+            thread.gui,
+            "QTestApplication::run_gui cannot be used once QTestApplication::run has created a QCoreApplication"
+        );
+        Self::run_on(thread, f)
+    }
+
+    fn run_on<F, R>(thread: &ApplicationThread, f: F) -> R
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let (result_sender, result_receiver) = mpsc::channel();
+        let job: Job = Box::new(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(f));
+            ffi::qtestapplication_process_events();
+            // The receiver only goes away if the calling thread has already panicked
+            let _ = result_sender.send(result);
+        });
+
+        thread
+            .sender
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .send(job)
+            .expect("The QTestApplication thread has stopped");
+
+        match result_receiver
+            .recv()
+            .expect("The QTestApplication thread has stopped")
+        {
+            Ok(result) => result,
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    }
+
+    /// Processes all pending events for the calling thread, including deferred deletes.
+    ///
+    /// This must be called from within [QTestApplication::run], for example after queueing
+    /// a closure with a `CxxQtThread` or emitting a signal with a queued connection.
+    pub fn process_events() {
+        ffi::qtestapplication_process_events();
+    }
+
+    /// Waits for timeout milliseconds, while waiting events will be processed
+    /// and the test will stay responsive to user interface events or network communication.
+    ///
+    /// This must be called from within [QTestApplication::run].
+    pub fn wait(timeout: i32) {
+        ffi::qtestapplication_wait(timeout);
+    }
+
+    /// Waits until the condition returns true or timeout milliseconds have passed,
+    /// processing events while waiting.
+    ///
+    /// Returns the final result of the condition, so that the test can assert on it.
+    ///
+    /// This must be called from within [QTestApplication::run].
+    pub fn wait_for(mut condition: impl FnMut() -> bool, timeout: i32) -> bool {
+        let deadline = Instant::now() + Duration::from_millis(timeout.max(0) as u64);
+        while Instant::now() < deadline {
+            if condition() {
+                return true;
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            Self::wait(remaining.as_millis().min(10) as i32);
+        }
+
+        condition()
+    }
+}
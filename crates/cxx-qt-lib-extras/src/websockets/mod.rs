@@ -0,0 +1,16 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+mod qabstractsocket;
+pub use qabstractsocket::{QAbstractSocketSocketError, QAbstractSocketSocketState};
+
+mod qwebsocket;
+pub use qwebsocket::QWebSocket;
+
+mod qwebsocketprotocol;
+pub use qwebsocketprotocol::QWebSocketProtocolCloseCode;
+
+mod qwebsocketserver;
+pub use qwebsocketserver::{QWebSocketServer, QWebSocketServerSslMode};
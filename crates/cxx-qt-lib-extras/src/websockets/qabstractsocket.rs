@@ -0,0 +1,64 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the socket errors that can occur.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QAbstractSocketSocketError {
+        /// The connection was refused by the peer (or timed out).
+        ConnectionRefusedError = 0,
+        /// The remote host closed the connection.
+        RemoteHostClosedError = 1,
+        /// The host address was not found.
+        HostNotFoundError = 2,
+        /// The socket operation failed because the application lacked the required privileges.
+        SocketAccessError = 3,
+        /// The local system ran out of resources (e.g., too many sockets).
+        SocketResourceError = 4,
+        /// The socket operation timed out.
+        SocketTimeoutError = 5,
+        /// An error occurred with the network (e.g., the network cable was accidentally plugged out).
+        NetworkError = 7,
+        /// The address specified is already in use and was set to be exclusive.
+        AddressInUseError = 8,
+        /// The SSL/TLS handshake failed, so the connection was closed.
+        SslHandshakeFailedError = 13,
+        /// An unidentified error occurred.
+        UnknownSocketError = -1,
+    }
+
+    /// This enum describes the different states in which a socket can be.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QAbstractSocketSocketState {
+        /// The socket is not connected.
+        UnconnectedState = 0,
+        /// The socket is performing a host name lookup.
+        HostLookupState = 1,
+        /// The socket has started establishing a connection.
+        ConnectingState = 2,
+        /// A connection is established.
+        ConnectedState = 3,
+        /// The socket is bound to an address and port.
+        BoundState = 4,
+        /// For internal use only.
+        ListeningState = 5,
+        /// The socket is about to close (data may still be waiting to be written).
+        ClosingState = 6,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qabstractsocket.h");
+        type QAbstractSocketSocketError;
+        type QAbstractSocketSocketState;
+    }
+}
+
+pub use ffi::{QAbstractSocketSocketError, QAbstractSocketSocketState};
@@ -0,0 +1,44 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qwebsocket.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QWebSocket>
+qwebsocketNew()
+{
+  return ::std::make_unique<QWebSocket>();
+}
+
+void
+qwebsocketOpen(QWebSocket& socket, const QUrl& url)
+{
+  socket.open(url);
+}
+
+::std::int64_t
+qwebsocketSendBinaryMessage(QWebSocket& socket, const QByteArray& data)
+{
+  return static_cast<::std::int64_t>(socket.sendBinaryMessage(data));
+}
+
+::std::int64_t
+qwebsocketSendTextMessage(QWebSocket& socket, const QString& message)
+{
+  return static_cast<::std::int64_t>(socket.sendTextMessage(message));
+}
+
+::std::unique_ptr<QSslConfiguration>
+qwebsocketSslConfiguration(const QWebSocket& socket)
+{
+  return ::std::make_unique<QSslConfiguration>(socket.sslConfiguration());
+}
+
+}
+}
@@ -0,0 +1,225 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qwebsocket.h");
+        /// Implements a TCP socket that talks the WebSocket protocol, running on the Qt event loop.
+        #[qobject]
+        type QWebSocket;
+
+        /// This signal is emitted whenever a binary message is received.
+        #[qsignal]
+        #[cxx_name = "binaryMessageReceived"]
+        fn binary_message_received(self: Pin<&mut QWebSocket>, message: &QByteArray);
+
+        /// This signal is emitted when a connection is successfully established.
+        #[qsignal]
+        fn connected(self: Pin<&mut QWebSocket>);
+
+        /// This signal is emitted when the socket is disconnected.
+        #[qsignal]
+        fn disconnected(self: Pin<&mut QWebSocket>);
+
+        /// This signal is emitted whenever the state of the socket changes.
+        #[qsignal]
+        #[cxx_name = "stateChanged"]
+        fn state_changed(self: Pin<&mut QWebSocket>, state: QAbstractSocketSocketState);
+
+        /// This signal is emitted whenever a text message is received.
+        #[qsignal]
+        #[cxx_name = "textMessageReceived"]
+        fn text_message_received(self: Pin<&mut QWebSocket>, message: &QString);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/connection.h");
+        #[namespace = "rust::cxxqt1"]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = cxx_qt_lib::QUrl;
+
+        include!("cxx-qt-lib-extras/qabstractsocket.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QAbstractSocketSocketError = crate::QAbstractSocketSocketError;
+        #[namespace = "rust::cxxqtlib1"]
+        type QAbstractSocketSocketState = crate::QAbstractSocketSocketState;
+        include!("cxx-qt-lib-extras/qwebsocketprotocol.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QWebSocketProtocolCloseCode = crate::QWebSocketProtocolCloseCode;
+
+        /// Aborts the current socket and resets the socket.
+        ///
+        /// Unlike [QWebSocket::close], this function immediately closes the socket, discarding any pending data.
+        fn abort(self: Pin<&mut QWebSocket>);
+
+        /// Gracefully closes the socket with the given close code and reason.
+        fn close(
+            self: Pin<&mut QWebSocket>,
+            close_code: QWebSocketProtocolCloseCode,
+            reason: &QString,
+        );
+
+        /// Returns the code indicating why the socket was closed.
+        #[rust_name = "close_code"]
+        fn closeCode(self: &QWebSocket) -> QWebSocketProtocolCloseCode;
+
+        /// Returns the reason why the socket was closed.
+        #[rust_name = "close_reason"]
+        fn closeReason(self: &QWebSocket) -> QString;
+
+        /// Returns the type of error that last occurred.
+        fn error(self: &QWebSocket) -> QAbstractSocketSocketError;
+
+        /// Returns a human-readable description of the last error that occurred.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QWebSocket) -> QString;
+
+        /// Returns true if the socket is ready for reading and writing, otherwise returns false.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QWebSocket) -> bool;
+
+        /// Pings the server to indicate that the connection is still alive.
+        ///
+        /// Additional payload can be sent along the ping message, which is at most 125 bytes.
+        fn ping(self: Pin<&mut QWebSocket>, payload: &QByteArray);
+
+        /// Returns the url the socket is connected to or will connect to.
+        #[rust_name = "request_url"]
+        fn requestUrl(self: &QWebSocket) -> QUrl;
+
+        /// Returns the current state of the socket.
+        fn state(self: &QWebSocket) -> QAbstractSocketSocketState;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qwebsocket_new"]
+        fn qwebsocketNew() -> UniquePtr<QWebSocket>;
+
+        #[doc(hidden)]
+        #[rust_name = "qwebsocket_on_error_occurred"]
+        fn qwebsocketOnErrorOccurred(
+            socket: Pin<&mut QWebSocket>,
+            func: fn(&mut QWebSocketErrorHandler, Pin<&mut QWebSocket>, QAbstractSocketSocketError),
+            arg: Box<QWebSocketErrorHandler>,
+        ) -> QMetaObjectConnection;
+
+        #[doc(hidden)]
+        #[rust_name = "qwebsocket_open"]
+        fn qwebsocketOpen(socket: Pin<&mut QWebSocket>, url: &QUrl);
+
+        #[doc(hidden)]
+        #[rust_name = "qwebsocket_send_binary_message"]
+        fn qwebsocketSendBinaryMessage(socket: Pin<&mut QWebSocket>, data: &QByteArray) -> i64;
+
+        #[doc(hidden)]
+        #[rust_name = "qwebsocket_send_text_message"]
+        fn qwebsocketSendTextMessage(socket: Pin<&mut QWebSocket>, message: &QString) -> i64;
+    }
+
+    #[cfg(feature = "qt_network")]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qsslconfiguration.h");
+        type QSslConfiguration = crate::QSslConfiguration;
+
+        /// Sets the socket's SSL configuration to be the contents of configuration.
+        ///
+        /// This must be called before the connection is opened to take effect.
+        #[rust_name = "set_ssl_configuration"]
+        fn setSslConfiguration(self: Pin<&mut QWebSocket>, configuration: &QSslConfiguration);
+
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "qwebsocket_ssl_configuration"]
+        fn qwebsocketSslConfiguration(socket: &QWebSocket) -> UniquePtr<QSslConfiguration>;
+    }
+
+    extern "Rust" {
+        #[namespace = "rust::cxxqtlib1"]
+        type QWebSocketErrorHandler;
+    }
+
+    // QWebSocket is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QWebSocket>::isRelocatable);
+    impl UniquePtr<QWebSocket> {}
+}
+
+use crate::QAbstractSocketSocketError;
+use core::pin::Pin;
+use cxx_qt::QMetaObjectConnection;
+use cxx_qt_lib::{QByteArray, QString, QUrl};
+
+pub use ffi::QWebSocket;
+
+impl QWebSocket {
+    /// Create a new QWebSocket, which is then connected to a server with [QWebSocket::open].
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qwebsocket_new()
+    }
+
+    /// Connect a closure which is called when an error occurs in the socket.
+    ///
+    /// The returned [QMetaObjectConnection] can be used to disconnect the closure.
+    pub fn on_error_occurred<F>(self: Pin<&mut Self>, closure: F) -> QMetaObjectConnection
+    where
+        F: FnMut(Pin<&mut QWebSocket>, QAbstractSocketSocketError) + 'static,
+    {
+        fn func(
+            handler: &mut QWebSocketErrorHandler,
+            socket: Pin<&mut QWebSocket>,
+            error: QAbstractSocketSocketError,
+        ) {
+            (handler.inner)(socket, error)
+        }
+        let arg = QWebSocketErrorHandler {
+            inner: Box::new(closure),
+        };
+        ffi::qwebsocket_on_error_occurred(self, func, Box::new(arg))
+    }
+
+    /// Opens a WebSocket connection to the given url, such as `wss://example.com/socket`.
+    ///
+    /// The connected signal is emitted once the connection is established,
+    /// otherwise an error is reported with [QWebSocket::on_error_occurred].
+    pub fn open(self: Pin<&mut Self>, url: &QUrl) {
+        ffi::qwebsocket_open(self, url)
+    }
+
+    /// Sends the given binary data over the socket as a binary message.
+    ///
+    /// Returns the number of bytes sent.
+    pub fn send_binary_message(self: Pin<&mut Self>, data: &QByteArray) -> i64 {
+        ffi::qwebsocket_send_binary_message(self, data)
+    }
+
+    /// Sends the given message over the socket as a text message.
+    ///
+    /// Returns the number of bytes sent.
+    pub fn send_text_message(self: Pin<&mut Self>, message: &QString) -> i64 {
+        ffi::qwebsocket_send_text_message(self, message)
+    }
+
+    /// Returns the socket's SSL configuration state.
+    #[cfg(feature = "qt_network")]
+    pub fn ssl_configuration(&self) -> cxx::UniquePtr<crate::QSslConfiguration> {
+        ffi::qwebsocket_ssl_configuration(self)
+    }
+}
+
+#[doc(hidden)]
+pub struct QWebSocketErrorHandler {
+    inner: SocketErrorFn,
+}
+
+type SocketErrorFn = Box<dyn FnMut(Pin<&mut QWebSocket>, QAbstractSocketSocketError)>;
@@ -0,0 +1,48 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// The close codes supported by WebSockets V13.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QWebSocketProtocolCloseCode {
+        /// Normal closure.
+        CloseCodeNormal = 1000,
+        /// Going away.
+        CloseCodeGoingAway = 1001,
+        /// Protocol error.
+        CloseCodeProtocolError = 1002,
+        /// Unsupported data.
+        CloseCodeDatatypeNotSupported = 1003,
+        /// Reserved.
+        CloseCodeReserved1004 = 1004,
+        /// No status received.
+        CloseCodeMissingStatusCode = 1005,
+        /// Abnormal closure.
+        CloseCodeAbnormalDisconnection = 1006,
+        /// Invalid frame payload data.
+        CloseCodeWrongDatatype = 1007,
+        /// Policy violation.
+        CloseCodePolicyViolated = 1008,
+        /// Message too big.
+        CloseCodeTooMuchData = 1009,
+        /// Mandatory extension missing.
+        CloseCodeMissingExtension = 1010,
+        /// Internal server error.
+        CloseCodeBadOperation = 1011,
+        /// TLS handshake failed.
+        CloseCodeTlsHandshakeFailed = 1015,
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qwebsocketprotocol.h");
+        type QWebSocketProtocolCloseCode;
+    }
+}
+
+pub use ffi::QWebSocketProtocolCloseCode;
@@ -0,0 +1,48 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib-extras/qwebsocketserver.h"
+
+#include <QtNetwork/QHostAddress>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QWebSocketServer>
+qwebsocketserverNew(const QString& serverName, QWebSocketServerSslMode mode)
+{
+  return ::std::make_unique<QWebSocketServer>(serverName, mode);
+}
+
+bool
+qwebsocketserverListen(QWebSocketServer& server,
+                       const QString& address,
+                       ::std::uint16_t port)
+{
+  // An empty address listens on all network interfaces
+  const auto hostAddress = address.isEmpty()
+                             ? QHostAddress(QHostAddress::Any)
+                             : QHostAddress(address);
+  return server.listen(hostAddress, static_cast<quint16>(port));
+}
+
+::std::unique_ptr<QWebSocket>
+qwebsocketserverNextPendingConnection(QWebSocketServer& server)
+{
+  // The server does not take ownership of the returned socket, so the caller
+  // must delete it
+  return ::std::unique_ptr<QWebSocket>(server.nextPendingConnection());
+}
+
+::std::unique_ptr<QSslConfiguration>
+qwebsocketserverSslConfiguration(const QWebSocketServer& server)
+{
+  return ::std::make_unique<QSslConfiguration>(server.sslConfiguration());
+}
+
+}
+}
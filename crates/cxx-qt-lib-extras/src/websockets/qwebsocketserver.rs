@@ -0,0 +1,193 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// Indicates whether the server operates over wss (SecureMode) or ws (NonSecureMode).
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QWebSocketServerSslMode {
+        /// The server operates in secure mode (over wss).
+        SecureMode = 0,
+        /// The server operates in non-secure mode (over ws).
+        NonSecureMode = 1,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib-extras/qwebsocketserver.h");
+        /// Implements a WebSocket-based server, running on the Qt event loop.
+        #[qobject]
+        type QWebSocketServer;
+
+        /// This signal is emitted when accepting a new connection results in an error.
+        #[qsignal]
+        #[cxx_name = "acceptError"]
+        fn accept_error(self: Pin<&mut QWebSocketServer>, socket_error: QAbstractSocketSocketError);
+
+        /// This signal is emitted when the server closed its connection.
+        #[qsignal]
+        fn closed(self: Pin<&mut QWebSocketServer>);
+
+        /// This signal is emitted every time a new connection is available,
+        /// which is then accepted with [QWebSocketServer::next_pending_connection].
+        #[qsignal]
+        #[cxx_name = "newConnection"]
+        fn new_connection(self: Pin<&mut QWebSocketServer>);
+
+        /// This signal is emitted when an error occurs during the setup of a WebSocket connection.
+        #[qsignal]
+        #[cxx_name = "serverError"]
+        fn server_error(self: Pin<&mut QWebSocketServer>, close_code: QWebSocketProtocolCloseCode);
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = cxx_qt_lib::QUrl;
+
+        include!("cxx-qt-lib-extras/qabstractsocket.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QAbstractSocketSocketError = crate::QAbstractSocketSocketError;
+        include!("cxx-qt-lib-extras/qwebsocket.h");
+        type QWebSocket = crate::QWebSocket;
+        include!("cxx-qt-lib-extras/qwebsocketprotocol.h");
+        #[namespace = "rust::cxxqtlib1"]
+        type QWebSocketProtocolCloseCode = crate::QWebSocketProtocolCloseCode;
+
+        /// Closes the server, the server will no longer listen for incoming connections.
+        fn close(self: Pin<&mut QWebSocketServer>);
+
+        /// Returns an error code for the last error that occurred.
+        fn error(self: &QWebSocketServer) -> QWebSocketProtocolCloseCode;
+
+        /// Returns a human readable description of the last error that occurred.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QWebSocketServer) -> QString;
+
+        /// Returns true if the server has pending connections, otherwise returns false.
+        #[rust_name = "has_pending_connections"]
+        fn hasPendingConnections(self: &QWebSocketServer) -> bool;
+
+        /// Returns true if the server is currently listening for incoming connections, otherwise returns false.
+        #[rust_name = "is_listening"]
+        fn isListening(self: &QWebSocketServer) -> bool;
+
+        /// Returns the maximum number of pending accepted connections.
+        #[rust_name = "max_pending_connections"]
+        fn maxPendingConnections(self: &QWebSocketServer) -> i32;
+
+        /// Returns the server name that is used during the http handshake phase.
+        #[rust_name = "server_name"]
+        fn serverName(self: &QWebSocketServer) -> QString;
+
+        /// Returns the server's port if the server is listening for connections, otherwise returns 0.
+        #[rust_name = "server_port"]
+        fn serverPort(self: &QWebSocketServer) -> u16;
+
+        /// Returns a URL clients can use to connect to this server if the server is listening for connections,
+        /// otherwise an invalid URL is returned.
+        #[rust_name = "server_url"]
+        fn serverUrl(self: &QWebSocketServer) -> QUrl;
+
+        /// Sets the maximum number of pending accepted connections, the default is 30.
+        #[rust_name = "set_max_pending_connections"]
+        fn setMaxPendingConnections(self: Pin<&mut QWebSocketServer>, num_connections: i32);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QWebSocketServerSslMode;
+
+        #[doc(hidden)]
+        #[rust_name = "qwebsocketserver_new"]
+        fn qwebsocketserverNew(
+            server_name: &QString,
+            mode: QWebSocketServerSslMode,
+        ) -> UniquePtr<QWebSocketServer>;
+
+        #[doc(hidden)]
+        #[rust_name = "qwebsocketserver_listen"]
+        fn qwebsocketserverListen(
+            server: Pin<&mut QWebSocketServer>,
+            address: &QString,
+            port: u16,
+        ) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qwebsocketserver_next_pending_connection"]
+        fn qwebsocketserverNextPendingConnection(
+            server: Pin<&mut QWebSocketServer>,
+        ) -> UniquePtr<QWebSocket>;
+    }
+
+    #[cfg(feature = "qt_network")]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qsslconfiguration.h");
+        type QSslConfiguration = crate::QSslConfiguration;
+
+        /// Sets the SSL configuration for the server, such as the local certificate and private key.
+        ///
+        /// This has only an effect when the server is in secure mode.
+        #[rust_name = "set_ssl_configuration"]
+        fn setSslConfiguration(self: Pin<&mut QWebSocketServer>, configuration: &QSslConfiguration);
+
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqtlib1"]
+        #[rust_name = "qwebsocketserver_ssl_configuration"]
+        fn qwebsocketserverSslConfiguration(
+            server: &QWebSocketServer,
+        ) -> UniquePtr<QSslConfiguration>;
+    }
+
+    // QWebSocketServer is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QWebSocketServer>::isRelocatable);
+    impl UniquePtr<QWebSocketServer> {}
+}
+
+use crate::QWebSocket;
+use core::pin::Pin;
+use cxx_qt_lib::QString;
+
+pub use ffi::{QWebSocketServer, QWebSocketServerSslMode};
+
+impl QWebSocketServer {
+    /// Tells the server to listen for incoming connections on all network interfaces on the given port.
+    ///
+    /// If port is 0, a port is chosen automatically, which can be found with [QWebSocketServer::server_port].
+    /// Returns true on success, otherwise false.
+    pub fn listen(self: Pin<&mut Self>, port: u16) -> bool {
+        ffi::qwebsocketserver_listen(self, &QString::default(), port)
+    }
+
+    /// Tells the server to listen for incoming connections on the given address and port,
+    /// such as `127.0.0.1` to only accept connections from the local machine.
+    ///
+    /// Returns true on success, otherwise false.
+    pub fn listen_with_address(self: Pin<&mut Self>, address: &QString, port: u16) -> bool {
+        ffi::qwebsocketserver_listen(self, address, port)
+    }
+
+    /// Create a new QWebSocketServer with the given name, which is used in the http handshake phase.
+    pub fn new(server_name: &QString, mode: QWebSocketServerSslMode) -> cxx::UniquePtr<Self> {
+        ffi::qwebsocketserver_new(server_name, mode)
+    }
+
+    /// Returns the next pending connection as a connected QWebSocket, or a null pointer if there are no pending connections.
+    ///
+    /// The connection is owned by the caller, so the socket is closed when it is dropped.
+    pub fn next_pending_connection(self: Pin<&mut Self>) -> cxx::UniquePtr<QWebSocket> {
+        ffi::qwebsocketserver_next_pending_connection(self)
+    }
+
+    /// Returns the SSL configuration used by the server.
+    #[cfg(feature = "qt_network")]
+    pub fn ssl_configuration(&self) -> cxx::UniquePtr<crate::QSslConfiguration> {
+        ffi::qwebsocketserver_ssl_configuration(self)
+    }
+}
@@ -77,7 +77,10 @@ fn main() {
     };
 
     let mut rust_bridges = vec![
+        "core/qabstractlistmodel",
+        "core/qbitarray",
         "core/qbytearray",
+        "core/qcollator",
         "core/qcoreapplication",
         "core/qdate",
         "core/qhash/qhash_i32_qbytearray",
@@ -113,7 +116,10 @@ fn main() {
         "core/qmap/qmap_qstring_qvariant",
         "core/qmargins",
         "core/qmarginsf",
+        "core/qmetaobject",
         "core/qmodelindex",
+        "core/qobject",
+        "core/qoperatingsystemversion",
         "core/qpersistentmodelindex",
         "core/qpoint",
         "core/qpointf",
@@ -138,11 +144,16 @@ fn main() {
         "core/qset/qset_u64",
         "core/qsize",
         "core/qsizef",
+        "core/qsocketnotifier",
         "core/qstring",
+        "core/qstringconverter",
         "core/qstringlist",
         "core/qt",
         "core/qtime",
+        "core/qtimer",
+        "core/qtlogging",
         "core/qurl",
+        "core/qurlquery",
         "core/qvariant/mod",
         "core/qvariant/qvariant_bool",
         "core/qvariant/qvariant_f32",
@@ -153,6 +164,8 @@ fn main() {
         "core/qvariant/qvariant_i64",
         "core/qvariant/qvariant_qbytearray",
         "core/qvariant/qvariant_qdate",
+        "core/qvariant/qvariant_qmargins",
+        "core/qvariant/qvariant_qmarginsf",
         "core/qvariant/qvariant_qmodelindex",
         "core/qvariant/qvariant_qpersistentmodelindex",
         "core/qvariant/qvariant_qpoint",
@@ -195,6 +208,7 @@ fn main() {
         "core/qvector/qvector_u16",
         "core/qvector/qvector_u32",
         "core/qvector/qvector_u64",
+        "core/qversionnumber",
     ];
 
     if qt_gui_enabled() {
@@ -202,16 +216,26 @@ fn main() {
             "core/qlist/qlist_qcolor",
             "core/qvariant/qvariant_qcolor",
             "core/qvector/qvector_qcolor",
+            "gui/qbrush",
             "gui/qcolor",
             "gui/qfont",
+            "gui/qfontmetricsf",
+            "gui/qgradient",
             "gui/qguiapplication",
             "gui/qimage",
+            "gui/qlineargradient",
+            "gui/qmatrix4x4",
             "gui/qpainterpath",
             "gui/qpainter",
+            "gui/qpalette",
             "gui/qpen",
             "gui/qpolygon",
             "gui/qpolygonf",
+            "gui/qquaternion",
+            "gui/qradialgradient",
             "gui/qregion",
+            "gui/qtransform",
+            "gui/qvalidator",
             "gui/qvector2d",
             "gui/qvector3d",
             "gui/qvector4d",
@@ -219,7 +243,12 @@ fn main() {
     }
 
     if qt_qml_enabled() {
-        rust_bridges.extend(["qml/qqmlapplicationengine", "qml/qqmlengine"]);
+        rust_bridges.extend([
+            "qml/qqmlapplicationengine",
+            "qml/qqmlcomponent",
+            "qml/qqmlcontext",
+            "qml/qqmlengine",
+        ]);
     }
 
     if qt_quickcontrols_enabled() {
@@ -238,7 +267,10 @@ fn main() {
     }
 
     let mut cpp_files = vec![
+        "core/qabstractlistmodel",
+        "core/qbitarray",
         "core/qbytearray",
+        "core/qcollator",
         "core/qcoreapplication",
         "core/qdate",
         "core/qhash/qhash",
@@ -248,7 +280,10 @@ fn main() {
         "core/qmap/qmap",
         "core/qmargins",
         "core/qmarginsf",
+        "core/qmetaobject",
         "core/qmodelindex",
+        "core/qobject",
+        "core/qoperatingsystemversion",
         "core/qpersistentmodelindex",
         "core/qpoint",
         "core/qpointf",
@@ -257,26 +292,39 @@ fn main() {
         "core/qset/qset",
         "core/qsize",
         "core/qsizef",
+        "core/qsocketnotifier",
         "core/qstring",
+        "core/qstringconverter",
         "core/qstringlist",
         "core/qtime",
+        "core/qtimer",
+        "core/qtlogging",
         "core/qurl",
+        "core/qurlquery",
         "core/qvariant/qvariant",
         "core/qvector/qvector",
+        "core/qversionnumber",
     ];
 
     if qt_gui_enabled() {
         cpp_files.extend([
+            "gui/qbrush",
             "gui/qcolor",
             "gui/qfont",
+            "gui/qfontmetricsf",
+            "gui/qgradient",
             "gui/qguiapplication",
             "gui/qimage",
+            "gui/qmatrix4x4",
             "gui/qpainterpath",
             "gui/qpainter",
+            "gui/qpalette",
             "gui/qpen",
             "gui/qpolygon",
             "gui/qpolygonf",
+            "gui/qquaternion",
             "gui/qregion",
+            "gui/qtransform",
             "gui/qvector2d",
             "gui/qvector3d",
             "gui/qvector4d",
@@ -284,7 +332,11 @@ fn main() {
     }
 
     if qt_qml_enabled() {
-        cpp_files.extend(["qml/qqmlapplicationengine", "qml/qqmlengine"]);
+        cpp_files.extend([
+            "qml/qqmlapplicationengine",
+            "qml/qqmlcomponent",
+            "qml/qqmlengine",
+        ]);
     }
 
     if qt_quickcontrols_enabled() {
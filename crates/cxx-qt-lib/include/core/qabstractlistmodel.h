@@ -0,0 +1,53 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtCore/QAbstractListModel>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qabstractlistmodelBeginInsertRows(QAbstractListModel& model,
+                                  ::std::int32_t first,
+                                  ::std::int32_t last);
+
+bool
+qabstractlistmodelBeginMoveRows(QAbstractListModel& model,
+                                ::std::int32_t first,
+                                ::std::int32_t last,
+                                ::std::int32_t destination);
+
+void
+qabstractlistmodelBeginRemoveRows(QAbstractListModel& model,
+                                  ::std::int32_t first,
+                                  ::std::int32_t last);
+
+void
+qabstractlistmodelBeginResetModel(QAbstractListModel& model);
+
+void
+qabstractlistmodelEndInsertRows(QAbstractListModel& model);
+
+void
+qabstractlistmodelEndMoveRows(QAbstractListModel& model);
+
+void
+qabstractlistmodelEndRemoveRows(QAbstractListModel& model);
+
+void
+qabstractlistmodelEndResetModel(QAbstractListModel& model);
+
+void
+qabstractlistmodelRowsChanged(QAbstractListModel& model,
+                              ::std::int32_t first,
+                              ::std::int32_t last);
+
+}
+}
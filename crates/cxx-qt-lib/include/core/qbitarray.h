@@ -0,0 +1,52 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QBitArray>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QBitArray> : ::std::true_type
+{
+};
+
+namespace cxxqtlib1 {
+
+QBitArray
+qbitarrayAnd(const QBitArray& a, const QBitArray& b);
+::rust::isize
+qbitarrayCount(const QBitArray& bitArray, bool on);
+bool
+qbitarrayFill(QBitArray& bitArray, bool value, ::rust::isize size);
+QBitArray
+qbitarrayInit(::rust::isize size, bool value);
+::rust::isize
+qbitarrayLen(const QBitArray& bitArray);
+QBitArray
+qbitarrayNot(const QBitArray& bitArray);
+QBitArray
+qbitarrayOr(const QBitArray& a, const QBitArray& b);
+void
+qbitarrayResize(QBitArray& bitArray, ::rust::isize size);
+void
+qbitarraySetBit(QBitArray& bitArray, ::rust::isize i, bool value);
+bool
+qbitarrayTestBit(const QBitArray& bitArray, ::rust::isize i);
+bool
+qbitarrayToggleBit(QBitArray& bitArray, ::rust::isize i);
+void
+qbitarrayTruncate(QBitArray& bitArray, ::rust::isize pos);
+QBitArray
+qbitarrayXor(const QBitArray& a, const QBitArray& b);
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -0,0 +1,51 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QMetaMethod>
+#include <QtCore/QMetaObject>
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QMetaMethodMethodType = QMetaMethod::MethodType;
+
+QString
+qmetaobjectClassName(const QMetaObject& metaObject);
+
+QString
+qmetaobjectEnumeratorName(const QMetaObject& metaObject, ::std::int32_t index);
+
+QStringList
+qmetaobjectEnumeratorKeys(const QMetaObject& metaObject, ::std::int32_t index);
+
+QByteArray
+qmetaobjectMethodSignature(const QMetaObject& metaObject,
+                           ::std::int32_t index);
+
+QMetaMethodMethodType
+qmetaobjectMethodType(const QMetaObject& metaObject, ::std::int32_t index);
+
+QString
+qmetaobjectPropertyName(const QMetaObject& metaObject, ::std::int32_t index);
+
+QString
+qmetaobjectPropertyTypeName(const QMetaObject& metaObject,
+                            ::std::int32_t index);
+
+const QMetaObject*
+qmetaobjectSuperClass(const QMetaObject& metaObject);
+
+}
+}
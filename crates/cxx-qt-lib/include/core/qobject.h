@@ -0,0 +1,82 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QList>
+#include <QtCore/QMetaObject>
+#include <QtCore/QObject>
+#include <QtCore/QString>
+#include <QtCore/QVariant>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QObject*
+qobjectChildAt(const QObject& object, ::rust::isize index);
+
+::rust::isize
+qobjectChildrenLen(const QObject& object);
+
+void
+qobjectDeleteLater(QObject& object);
+
+QList<QByteArray>
+qobjectDynamicPropertyNames(const QObject& object);
+
+bool
+qobjectInvokeMethod(QObject& object,
+                    const QByteArray& name,
+                    Qt::ConnectionType connectionType,
+                    const QList<QVariant>& args,
+                    QVariant& returnValue);
+
+const QMetaObject&
+qobjectMetaObject(const QObject& object);
+
+QString
+qobjectObjectName(const QObject& object);
+
+template<typename A>
+::QMetaObject::Connection
+qobjectOnDestroyed(QObject& object,
+                   ::rust::Fn<void(A&, const QObject&)> func,
+                   ::rust::Box<A> arg)
+{
+  // Qt requires the functor to be copyable so share the box
+  auto shared = ::std::make_shared<::rust::Box<A>>(::std::move(arg));
+  return QObject::connect(
+    &object,
+    &QObject::destroyed,
+    [func = ::std::move(func), shared](QObject* destroyed) {
+      func(**shared, *destroyed);
+    });
+}
+
+QObject*
+qobjectParent(const QObject& object);
+
+QVariant
+qobjectProperty(const QObject& object, const QByteArray& name);
+
+void
+qobjectSetObjectName(QObject& object, const QString& name);
+
+void
+qobjectSetParent(QObject& object, QObject* parent);
+
+bool
+qobjectSetProperty(QObject& object,
+                   const QByteArray& name,
+                   const QVariant& value);
+
+}
+}
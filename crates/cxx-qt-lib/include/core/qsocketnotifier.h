@@ -0,0 +1,59 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QSocketNotifier>
+
+#include <cxx-qt/connection.h>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QSocketNotifierType = QSocketNotifier::Type;
+
+// The activated signal has a QPrivateSignal argument which cannot be named
+// outside of QSocketNotifier, so it is deduced instead. This also selects
+// the overload with the socket descriptor in Qt 5.15.
+template<typename PrivateSignal>
+constexpr auto
+qsocketnotifierActivatedSignal(
+  void (QSocketNotifier::*signal)(QSocketDescriptor,
+                                  QSocketNotifier::Type,
+                                  PrivateSignal))
+{
+  return signal;
+}
+
+::std::unique_ptr<QSocketNotifier>
+qsocketnotifierNew(::rust::isize socket, QSocketNotifierType type);
+
+template<typename A>
+::QMetaObject::Connection
+qsocketnotifierOnActivated(QSocketNotifier& notifier,
+                           ::rust::Fn<void(A&, QSocketNotifier&)> func,
+                           ::rust::Box<A> arg)
+{
+  // Qt requires the functor to be copyable so share the box
+  auto shared = ::std::make_shared<::rust::Box<A>>(::std::move(arg));
+  return QObject::connect(
+    &notifier,
+    qsocketnotifierActivatedSignal(&QSocketNotifier::activated),
+    &notifier,
+    [&notifier, func = ::std::move(func), shared]() {
+      func(**shared, notifier);
+    });
+}
+
+::rust::isize
+qsocketnotifierSocket(const QSocketNotifier& notifier);
+
+}
+}
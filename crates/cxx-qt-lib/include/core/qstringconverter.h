@@ -0,0 +1,50 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QtGlobal>
+
+// QStringDecoder and QStringEncoder are only available in Qt 6
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+
+#include <cinttypes>
+#include <memory>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QString>
+#include <QtCore/QStringDecoder>
+#include <QtCore/QStringEncoder>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+using QStringConverterEncoding = QStringConverter::Encoding;
+
+QString
+qstringdecoderDecode(QStringDecoder& decoder,
+                     ::rust::Slice<const ::std::uint8_t> data);
+::std::unique_ptr<QStringDecoder>
+qstringdecoderFromName(::rust::Str name);
+QString
+qstringdecoderName(const QStringDecoder& decoder);
+::std::unique_ptr<QStringDecoder>
+qstringdecoderNew(QStringConverterEncoding encoding);
+
+QByteArray
+qstringencoderEncode(QStringEncoder& encoder, const QString& string);
+::std::unique_ptr<QStringEncoder>
+qstringencoderFromName(::rust::Str name);
+QString
+qstringencoderName(const QStringEncoder& encoder);
+::std::unique_ptr<QStringEncoder>
+qstringencoderNew(QStringConverterEncoding encoding);
+
+} // namespace cxxqtlib1
+} // namespace rust
+
+#endif
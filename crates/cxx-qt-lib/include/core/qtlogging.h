@@ -0,0 +1,71 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+#include <functional>
+#include <memory>
+
+#include <QtCore/QMessageLogContext>
+#include <QtCore/QString>
+#include <QtCore/QtGlobal>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QtMessageHandlerFunction = ::std::function<
+  void(QtMsgType, const QMessageLogContext&, const QString&)>;
+
+void
+qtInstallMessageHandlerFunction(QtMessageHandlerFunction handler);
+
+template<typename A>
+void
+qtInstallMessageHandler(
+  ::rust::Fn<void(const A&, QtMsgType, const QMessageLogContext&, const QString&)>
+    func,
+  ::rust::Box<A> arg)
+{
+  // std::function needs to be copyable, so share the Box between the copies
+  auto sharedArg = ::std::make_shared<::rust::Box<A>>(::std::move(arg));
+  qtInstallMessageHandlerFunction(
+    [func, sharedArg](QtMsgType type,
+                      const QMessageLogContext& context,
+                      const QString& message) {
+      func(**sharedArg, type, context, message);
+    });
+}
+
+void
+qtResetMessageHandler();
+
+QString
+qmessagelogcontextCategory(const QMessageLogContext& context);
+
+QString
+qmessagelogcontextFile(const QMessageLogContext& context);
+
+QString
+qmessagelogcontextFunction(const QMessageLogContext& context);
+
+::std::int32_t
+qmessagelogcontextLine(const QMessageLogContext& context);
+
+bool
+qtLoggingCategoryIsEnabled(::rust::Str category, QtMsgType type);
+
+void
+qtLogMessage(::rust::Str category,
+             QtMsgType type,
+             ::rust::Str file,
+             ::std::int32_t line,
+             const QString& message);
+
+}
+}
@@ -11,6 +11,7 @@
 #include <QtCore/QString>
 #include <QtCore/QStringList>
 #include <QtCore/QUrl>
+#include <QtCore/QUrlQuery>
 
 #include "rust/cxx.h"
 
@@ -27,20 +28,30 @@ struct IsRelocatable<QUrl> : ::std::true_type
 
 namespace rust {
 namespace cxxqtlib1 {
+using QUrlComponentFormattingOption = QUrl::ComponentFormattingOption;
+using QUrlParsingMode = QUrl::ParsingMode;
 
 QUrl
 qurlInitFromString(::rust::Str string);
 ::rust::String
 qurlToRustString(const QUrl& url);
 
-// Bitwise enums don't work well with Rust and CXX, so lets just use the
-// defaults for now
+// Bitwise enums don't work well with Rust and CXX, so the helpers without
+// options use the defaults and the helpers with options convert the enum into
+// the flags
 QString
 qurlAuthority(const QUrl& url);
 QString
+qurlAuthorityWithOptions(const QUrl& url,
+                         QUrlComponentFormattingOption options);
+QString
 qurlFileName(const QUrl& url);
 QString
+qurlFileNameWithOptions(const QUrl& url, QUrlComponentFormattingOption options);
+QString
 qurlFragment(const QUrl& url);
+QString
+qurlFragmentWithOptions(const QUrl& url, QUrlComponentFormattingOption options);
 QUrl
 qurlFromEncoded(const QByteArray& input);
 QUrl
@@ -51,14 +62,22 @@ QUrl
 qurlFromUserInput(const QString& userInput, const QString& workingDirectory);
 QString
 qurlHost(const QUrl& url);
+QString
+qurlHostWithOptions(const QUrl& url, QUrlComponentFormattingOption options);
 QStringList
 qurlIdnWhitelist();
 QString
 qurlPath(const QUrl& url);
 QString
+qurlPathWithOptions(const QUrl& url, QUrlComponentFormattingOption options);
+QString
 qurlPassword(const QUrl& url);
 QString
+qurlPasswordWithOptions(const QUrl& url, QUrlComponentFormattingOption options);
+QString
 qurlQuery(const QUrl& url);
+QString
+qurlQueryWithOptions(const QUrl& url, QUrlComponentFormattingOption options);
 void
 qurlSetAuthority(QUrl& url, const QString& authority);
 void
@@ -86,15 +105,25 @@ qurlToDisplayString(const QUrl& url);
 QByteArray
 qurlToEncoded(const QUrl& url);
 QByteArray
+qurlToEncodedWithOptions(const QUrl& url,
+                         QUrlComponentFormattingOption options);
+QByteArray
 qurlToPercentEncoding(const QString& input,
                       const QByteArray& exclude,
                       const QByteArray& include);
 QString
 qurlToQString(const QUrl& url);
 QString
+qurlToQStringWithOptions(const QUrl& url,
+                         QUrlComponentFormattingOption options);
+QString
 qurlUserInfo(const QUrl& url);
 QString
+qurlUserInfoWithOptions(const QUrl& url, QUrlComponentFormattingOption options);
+QString
 qurlUserName(const QUrl& url);
+QString
+qurlUserNameWithOptions(const QUrl& url, QUrlComponentFormattingOption options);
 
 }
 }
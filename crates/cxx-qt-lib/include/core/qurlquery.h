@@ -0,0 +1,47 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QString>
+#include <QtCore/QStringList>
+#include <QtCore/QUrl>
+#include <QtCore/QUrlQuery>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QUrlQuery> : ::std::true_type
+{
+};
+
+namespace cxxqtlib1 {
+
+QStringList
+qurlqueryAllQueryItemValues(const QUrlQuery& query,
+                            const QString& key,
+                            QUrl::ComponentFormattingOption options);
+QString
+qurlqueryQuery(const QUrlQuery& query, QUrl::ComponentFormattingOption options);
+QStringList
+qurlqueryQueryItemKeys(const QUrlQuery& query,
+                       QUrl::ComponentFormattingOption options);
+QString
+qurlqueryQueryItemValue(const QUrlQuery& query,
+                        const QString& key,
+                        QUrl::ComponentFormattingOption options);
+QStringList
+qurlqueryQueryItemValues(const QUrlQuery& query,
+                         QUrl::ComponentFormattingOption options);
+QString
+qurlqueryToQString(const QUrlQuery& query);
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -14,6 +14,7 @@
 #include <QtCore/QByteArray>
 #include <QtCore/QDate>
 #include <QtCore/QDateTime>
+#include <QtCore/QMargins>
 #include <QtCore/QModelIndex>
 #include <QtCore/QPersistentModelIndex>
 #include <QtCore/QPoint>
@@ -80,6 +81,8 @@ CXX_QT_QVARIANT_CAN_CONVERT(QColor)
 #endif
 CXX_QT_QVARIANT_CAN_CONVERT(QDate)
 CXX_QT_QVARIANT_CAN_CONVERT(QDateTime)
+CXX_QT_QVARIANT_CAN_CONVERT(QMargins)
+CXX_QT_QVARIANT_CAN_CONVERT(QMarginsF)
 CXX_QT_QVARIANT_CAN_CONVERT(QModelIndex)
 CXX_QT_QVARIANT_CAN_CONVERT(QPersistentModelIndex)
 CXX_QT_QVARIANT_CAN_CONVERT(QPoint)
@@ -0,0 +1,42 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cinttypes>
+
+#include <QtCore/QVersionNumber>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QVersionNumber> : ::std::true_type
+{
+};
+
+namespace cxxqtlib1 {
+
+QVersionNumber
+qversionnumberCommonPrefix(const QVersionNumber& v1, const QVersionNumber& v2);
+QVersionNumber
+qversionnumberFromSlice(::rust::Slice<const ::std::int32_t> segments);
+QVersionNumber
+qversionnumberFromString(const QString& string);
+QVersionNumber
+qversionnumberNormalized(const QVersionNumber& version);
+::std::int32_t
+qversionnumberSegmentAt(const QVersionNumber& version, ::rust::isize index);
+::rust::isize
+qversionnumberSegmentCount(const QVersionNumber& version);
+::rust::Vec<::std::int32_t>
+qversionnumberSegments(const QVersionNumber& version);
+
+} // namespace cxxqtlib1
+} // namespace rust
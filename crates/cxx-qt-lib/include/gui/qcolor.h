@@ -68,6 +68,8 @@ QColor
 qcolorInitFromRgbF(float red, float green, float blue, float alpha);
 QColor
 qcolorInitFromRustString(::rust::Str string);
+bool
+qcolorIsValidColorName(const QString& name);
 
 // Qt 5 uses qreal and Qt 6 uses float, so cast all to floats
 float
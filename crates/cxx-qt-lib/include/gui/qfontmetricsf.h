@@ -0,0 +1,40 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QSizeF>
+#include <QtCore/QString>
+#include <QtGui/QFontMetricsF>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<QFontMetricsF> : ::std::true_type
+{
+};
+
+namespace cxxqtlib1 {
+
+QString
+qfontmetricsfElidedText(const QFontMetricsF& metrics,
+                        const QString& text,
+                        Qt::TextElideMode mode,
+                        qreal width);
+
+qreal
+qfontmetricsfHorizontalAdvance(const QFontMetricsF& metrics,
+                               const QString& text);
+
+QSizeF
+qfontmetricsfSize(const QFontMetricsF& metrics, const QString& text);
+
+} // namespace cxxqtlib1
+} // namespace rust
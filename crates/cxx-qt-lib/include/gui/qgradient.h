@@ -0,0 +1,57 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+
+#include <QtCore/QPointF>
+#include <QtGui/QColor>
+#include <QtGui/QGradient>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QGradientCoordinateMode = QGradient::CoordinateMode;
+using QGradientSpread = QGradient::Spread;
+using QGradientType = QGradient::Type;
+
+void
+qgradientClearStops(QGradient& gradient);
+::std::unique_ptr<QGradient>
+qgradientClone(const QGradient& gradient);
+QColor
+qgradientStopColor(const QGradient& gradient, ::rust::isize index);
+::rust::isize
+qgradientStopCount(const QGradient& gradient);
+double
+qgradientStopPosition(const QGradient& gradient, ::rust::isize index);
+
+const QGradient&
+qlineargradientAsQGradient(const QLinearGradient& gradient);
+QGradient&
+qlineargradientAsQGradientMut(QLinearGradient& gradient);
+::std::unique_ptr<QLinearGradient>
+qlineargradientClone(const QLinearGradient& gradient);
+::std::unique_ptr<QLinearGradient>
+qlineargradientNew(const QPointF& start, const QPointF& finalStop);
+
+const QGradient&
+qradialgradientAsQGradient(const QRadialGradient& gradient);
+QGradient&
+qradialgradientAsQGradientMut(QRadialGradient& gradient);
+::std::unique_ptr<QRadialGradient>
+qradialgradientClone(const QRadialGradient& gradient);
+::std::unique_ptr<QRadialGradient>
+qradialgradientNew(const QPointF& center,
+                   double centerRadius,
+                   const QPointF& focalPoint,
+                   double focalRadius);
+
+}
+}
@@ -10,6 +10,7 @@
 
 #include <QtGui/QFont>
 #include <QtGui/QGuiApplication>
+#include <QtGui/QPalette>
 
 #include "rust/cxx.h"
 
@@ -25,5 +26,11 @@ qguiapplicationSetFont(QGuiApplication& app, const QFont& font);
 QFont
 qguiapplicationFont(const QGuiApplication& app);
 
+QPalette
+qguiapplicationPalette(const QGuiApplication& app);
+
+void
+qguiapplicationSetPalette(QGuiApplication& app, const QPalette& palette);
+
 }
 }
@@ -0,0 +1,22 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QMatrix4x4>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QMatrix4x4
+qmatrix4x4FromRowMajor(::rust::Slice<const float> values);
+QMatrix4x4
+qmatrix4x4Inverted(const QMatrix4x4& matrix, bool& invertible);
+
+} // namespace cxxqtlib1
+} // namespace rust
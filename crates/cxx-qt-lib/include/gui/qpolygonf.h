@@ -19,4 +19,12 @@ struct IsRelocatable<QPolygonF> : ::std::true_type
 {
 };
 
+namespace cxxqtlib1 {
+
+::rust::Slice<const QPointF>
+qpolygonfAsSlice(const QPolygonF& polygon);
+QPolygonF
+qpolygonfFromSlice(::rust::Slice<const QPointF> points);
+
+} // namespace cxxqtlib1
 } // namespace rust
@@ -0,0 +1,27 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QQuaternion>
+#include <QtGui/QVector3D>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QQuaternion
+qquaternionFromAxisAndAngle(const QVector3D& axis, float angle);
+QQuaternion
+qquaternionFromEulerAngles(float pitch, float yaw, float roll);
+QQuaternion
+qquaternionNlerp(const QQuaternion& q1, const QQuaternion& q2, float t);
+QQuaternion
+qquaternionRotationTo(const QVector3D& from, const QVector3D& to);
+QQuaternion
+qquaternionSlerp(const QQuaternion& q1, const QQuaternion& q2, float t);
+
+}
+}
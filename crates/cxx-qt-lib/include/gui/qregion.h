@@ -19,4 +19,13 @@ struct IsRelocatable<QRegion> : ::std::true_type
 {
 };
 
+namespace cxxqtlib1 {
+using QRegionRegionType = QRegion::RegionType;
+
+::rust::Slice<const QRect>
+qregionAsSlice(const QRegion& region);
+QRegion
+qregionFromSlice(::rust::Slice<const QRect> rects);
+
+} // namespace cxxqtlib1
 } // namespace rust
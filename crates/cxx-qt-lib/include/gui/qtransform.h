@@ -0,0 +1,38 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QTransform>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+// QTransform still had copy & move constructors in Qt 5 but they were basically
+// trivial.
+template<>
+struct IsRelocatable<QTransform> : ::std::true_type
+{
+};
+
+namespace cxxqtlib1 {
+
+QTransform
+qtransformFromMatrix(::rust::Slice<const double> values);
+QTransform
+qtransformFromScale(double sx, double sy);
+QTransform
+qtransformFromTranslate(double dx, double dy);
+QTransform
+qtransformInverted(const QTransform& transform, bool& invertible);
+void
+qtransformRotate(QTransform& transform, double angle);
+
+} // namespace cxxqtlib1
+} // namespace rust
@@ -10,9 +10,12 @@
 
 #include <memory>
 
+#include <QtCore/QStringList>
 #include <QtQml/QQmlApplicationEngine>
 #include <QtQml/QQmlEngine>
 
+#include "rust/cxx.h"
+
 namespace rust {
 namespace cxxqtlib1 {
 
@@ -22,6 +25,20 @@ qqmlapplicationengineNew();
 QQmlEngine&
 qqmlapplicationengineAsQQmlEngine(QQmlApplicationEngine&);
 
+void
+qqmlapplicationengineReload(QQmlApplicationEngine& engine);
+
+void
+qqmlapplicationengineReloadOnChanges(QQmlApplicationEngine& engine,
+                                     const QStringList& directories);
+
+QObject*
+qqmlapplicationengineRootObjectAt(const QQmlApplicationEngine& engine,
+                                  ::rust::isize index);
+
+::rust::isize
+qqmlapplicationengineRootObjectsLen(const QQmlApplicationEngine& engine);
+
 }
 }
 
@@ -0,0 +1,53 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#ifdef CXX_QT_QML_FEATURE
+
+#include <cstdint>
+#include <memory>
+
+#include <QtQml/QQmlComponent>
+#include <QtQml/QQmlEngine>
+#include <QtQml/QQmlError>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+using QQmlComponentStatus = QQmlComponent::Status;
+
+::std::unique_ptr<QObject>
+qqmlcomponentCreate(QQmlComponent& component);
+
+::std::unique_ptr<QQmlComponent>
+qqmlcomponentNew(QQmlEngine& engine);
+
+template<typename A>
+void
+qqmlcomponentErrors(const QQmlComponent& component,
+                    ::rust::Fn<void(A&,
+                                    const QUrl& url,
+                                    ::std::int32_t line,
+                                    ::std::int32_t column,
+                                    const QString& description)> func,
+                    A& errors)
+{
+  for (const auto& error : component.errors()) {
+    func(errors,
+         error.url(),
+         static_cast<::std::int32_t>(error.line()),
+         static_cast<::std::int32_t>(error.column()),
+         error.description());
+  }
+}
+
+}
+}
+
+#endif
@@ -8,16 +8,135 @@
 
 #ifdef CXX_QT_QML_FEATURE
 
+#include <functional>
 #include <memory>
+#include <optional>
 
+#include <QtQml/QQmlAbstractUrlInterceptor>
+#include <QtQml/QQmlContext>
 #include <QtQml/QQmlEngine>
+#include <QtQml/QQmlError>
+
+#include <cxx-qt/connection.h>
+
+#include "rust/cxx.h"
 
 namespace rust {
 namespace cxxqtlib1 {
 
+using QQmlAbstractUrlInterceptorDataType = QQmlAbstractUrlInterceptor::DataType;
+
+// A URL interceptor which is set from Rust, this is a child of the engine
+// so that it is destroyed with the engine
+class QQmlEngineUrlInterceptorBase
+  : public QObject
+  , public QQmlAbstractUrlInterceptor
+{
+public:
+  explicit QQmlEngineUrlInterceptorBase(QQmlEngine& engine);
+};
+
+template<typename A>
+class QQmlEngineUrlInterceptor : public QQmlEngineUrlInterceptorBase
+{
+public:
+  QQmlEngineUrlInterceptor(
+    QQmlEngine& engine,
+    ::rust::Fn<QUrl(const A&, const QUrl&, QQmlAbstractUrlInterceptorDataType)>
+      func,
+    ::rust::Box<A> arg)
+    : QQmlEngineUrlInterceptorBase(engine)
+    , m_func(::std::move(func))
+    , m_arg(::std::move(arg))
+  {
+  }
+
+  QUrl intercept(const QUrl& path, DataType type) override
+  {
+    return m_func(*m_arg, path, type);
+  }
+
+private:
+  ::rust::Fn<QUrl(const A&, const QUrl&, QQmlAbstractUrlInterceptorDataType)>
+    m_func;
+  ::rust::Box<A> m_arg;
+};
+
 ::std::unique_ptr<QQmlEngine>
 qqmlengineNew();
 
+void
+qqmlengineAddShutdownCallback(QQmlEngine& engine,
+                              ::std::function<void()> callback);
+
+QQmlContext&
+qqmlengineRootContext(QQmlEngine& engine);
+
+void
+qqmlengineReplaceUrlInterceptor(QQmlEngine& engine,
+                                QQmlEngineUrlInterceptorBase* interceptor);
+
+template<typename A>
+void
+qqmlengineSetUrlInterceptor(
+  QQmlEngine& engine,
+  ::rust::Fn<QUrl(const A&, const QUrl&, QQmlAbstractUrlInterceptorDataType)>
+    func,
+  ::rust::Box<A> arg)
+{
+  qqmlengineReplaceUrlInterceptor(
+    engine,
+    new QQmlEngineUrlInterceptor<A>(engine, ::std::move(func), ::std::move(arg)));
+}
+
+void
+qqmlengineShutdown(QQmlEngine& engine);
+
+template<typename A>
+void
+qqmlengineOnShutdown(QQmlEngine& engine,
+                     ::rust::Fn<void(QQmlEngine&, ::rust::Box<A>)> func,
+                     ::rust::Box<A> arg)
+{
+  // std::function requires the callable to be copyable so share the box
+  auto shared = ::std::make_shared<::std::optional<::rust::Box<A>>>(
+    ::std::move(arg));
+  qqmlengineAddShutdownCallback(
+    engine, [&engine, func = ::std::move(func), shared]() {
+      if (shared->has_value()) {
+        func(engine, ::std::move(shared->value()));
+        shared->reset();
+      }
+    });
+}
+
+template<typename A>
+::QMetaObject::Connection
+qqmlengineOnWarnings(QQmlEngine& engine,
+                     ::rust::Fn<void(A&,
+                                     const QUrl& url,
+                                     ::std::int32_t line,
+                                     ::std::int32_t column,
+                                     const QString& description)> func,
+                     ::rust::Box<A> arg)
+{
+  // Qt requires the functor to be copyable so share the box
+  auto shared = ::std::make_shared<::rust::Box<A>>(::std::move(arg));
+  return QObject::connect(
+    &engine,
+    &QQmlEngine::warnings,
+    &engine,
+    [func = ::std::move(func), shared](const QList<QQmlError>& warnings) {
+      for (const auto& warning : warnings) {
+        func(**shared,
+             warning.url(),
+             static_cast<::std::int32_t>(warning.line()),
+             static_cast<::std::int32_t>(warning.column()),
+             warning.description());
+      }
+    });
+}
+
 }
 }
 
@@ -3,9 +3,22 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod qabstractlistmodel;
+pub use cxx_qt::QAbstractListModelRow;
+pub use qabstractlistmodel::{
+    QAbstractListModel, QAbstractListModelFetchRows, QAbstractListModelFetchState,
+    QAbstractListModelRow, QAbstractListModelRows,
+};
+
+mod qbitarray;
+pub use qbitarray::QBitArray;
+
 mod qbytearray;
 pub use qbytearray::QByteArray;
 
+mod qcollator;
+pub use qcollator::{QCollator, QCollatorSortKey};
+
 mod qcoreapplication;
 pub use qcoreapplication::QCoreApplication;
 
@@ -38,12 +51,21 @@ pub use qmargins::QMargins;
 mod qmarginsf;
 pub use qmarginsf::QMarginsF;
 
-// Reexport QMetaObjectConnection and guard from cxx-qt
-pub use cxx_qt::{QMetaObjectConnection, QMetaObjectConnectionGuard};
+mod qmetaobject;
+pub use qmetaobject::{QMetaMethodMethodType, QMetaObject};
+
+// Reexport QMetaObjectConnection, guard, and QObject from cxx-qt
+pub use cxx_qt::{QMetaObjectConnection, QMetaObjectConnectionGuard, QObject};
 
 mod qmodelindex;
 pub use qmodelindex::QModelIndex;
 
+mod qobject;
+pub use qobject::QObjectExt;
+
+mod qoperatingsystemversion;
+pub use qoperatingsystemversion::{QOperatingSystemVersion, QOperatingSystemVersionOSType};
+
 mod qpersistentmodelindex;
 pub use qpersistentmodelindex::QPersistentModelIndex;
 
@@ -59,25 +81,44 @@ pub use qset::{QSet, QSetElement};
 mod qsize;
 pub use qsize::QSize;
 
+mod qsocketnotifier;
+pub use qsocketnotifier::{QSocketNotifier, QSocketNotifierType};
+
 mod qsizef;
 pub use qsizef::QSizeF;
 
 mod qstring;
 pub use qstring::QString;
 
+#[cfg(cxxqt_qt_version_at_least_6)]
+mod qstringconverter;
+#[cfg(cxxqt_qt_version_at_least_6)]
+pub use qstringconverter::{QStringConverterEncoding, QStringDecoder, QStringEncoder};
+
 mod qstringlist;
 pub use qstringlist::QStringList;
 
 mod qt;
 pub use qt::{
-    AspectRatioMode, BGMode, CaseSensitivity, ClipOperation, ConnectionType, DateFormat, FillRule,
-    LayoutDirection, PenCapStyle, PenJoinStyle, PenStyle, SizeMode, SplitBehaviorFlags, TimeSpec,
-    TransformationMode,
+    ApplicationState, AspectRatioMode, BGMode, BrushStyle, CaseSensitivity, ClipOperation,
+    ConnectionType, DateFormat, FillRule, InputMethodHint, Key, KeyboardModifier, LayoutDirection,
+    MouseButton, PenCapStyle, PenJoinStyle, PenStyle, SizeMode, SplitBehaviorFlags, TextElideMode,
+    TimeSpec, TimerType, TransformationMode,
 };
 
 mod qtime;
 pub use qtime::QTime;
 
+mod qtimer;
+pub use qtimer::QTimer;
+
+mod qtlogging;
+#[cfg(feature = "log")]
+pub use qtlogging::install_log_message_handler;
+pub use qtlogging::{
+    install_message_handler, qt_log, reset_message_handler, QMessageLogContext, QtMsgType,
+};
+
 #[cfg(not(target_os = "emscripten"))]
 mod qtimezone;
 #[cfg(not(target_os = "emscripten"))]
@@ -90,7 +131,15 @@ mod qpointf;
 pub use qpointf::QPointF;
 
 mod qurl;
-pub use qurl::QUrl;
+pub use qurl::{QUrl, QUrlComponentFormattingOption, QUrlParsingMode};
+
+mod qurlquery;
+pub use qurlquery::QUrlQuery;
+
+mod qversionnumber;
+pub use qversionnumber::QVersionNumber;
+
+mod rowdiff;
 
 mod qvariant;
 pub use qvariant::{QVariant, QVariantValue};
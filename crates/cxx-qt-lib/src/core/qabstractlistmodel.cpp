@@ -0,0 +1,98 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qabstractlistmodel.h"
+
+namespace {
+
+// The methods which notify views of changes to the rows are protected,
+// so make them accessible to the helpers which are called from Rust
+class QAbstractListModelAccessor : public QAbstractListModel
+{
+public:
+  using QAbstractListModel::beginInsertRows;
+  using QAbstractListModel::beginMoveRows;
+  using QAbstractListModel::beginRemoveRows;
+  using QAbstractListModel::beginResetModel;
+  using QAbstractListModel::endInsertRows;
+  using QAbstractListModel::endMoveRows;
+  using QAbstractListModel::endRemoveRows;
+  using QAbstractListModel::endResetModel;
+};
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qabstractlistmodelBeginInsertRows(QAbstractListModel& model,
+                                  ::std::int32_t first,
+                                  ::std::int32_t last)
+{
+  (model.*(&QAbstractListModelAccessor::beginInsertRows))(
+    QModelIndex(), first, last);
+}
+
+bool
+qabstractlistmodelBeginMoveRows(QAbstractListModel& model,
+                                ::std::int32_t first,
+                                ::std::int32_t last,
+                                ::std::int32_t destination)
+{
+  return (model.*(&QAbstractListModelAccessor::beginMoveRows))(
+    QModelIndex(), first, last, QModelIndex(), destination);
+}
+
+void
+qabstractlistmodelBeginRemoveRows(QAbstractListModel& model,
+                                  ::std::int32_t first,
+                                  ::std::int32_t last)
+{
+  (model.*(&QAbstractListModelAccessor::beginRemoveRows))(
+    QModelIndex(), first, last);
+}
+
+void
+qabstractlistmodelBeginResetModel(QAbstractListModel& model)
+{
+  (model.*(&QAbstractListModelAccessor::beginResetModel))();
+}
+
+void
+qabstractlistmodelEndInsertRows(QAbstractListModel& model)
+{
+  (model.*(&QAbstractListModelAccessor::endInsertRows))();
+}
+
+void
+qabstractlistmodelEndMoveRows(QAbstractListModel& model)
+{
+  (model.*(&QAbstractListModelAccessor::endMoveRows))();
+}
+
+void
+qabstractlistmodelEndRemoveRows(QAbstractListModel& model)
+{
+  (model.*(&QAbstractListModelAccessor::endRemoveRows))();
+}
+
+void
+qabstractlistmodelEndResetModel(QAbstractListModel& model)
+{
+  (model.*(&QAbstractListModelAccessor::endResetModel))();
+}
+
+void
+qabstractlistmodelRowsChanged(QAbstractListModel& model,
+                              ::std::int32_t first,
+                              ::std::int32_t last)
+{
+  Q_EMIT model.dataChanged(model.index(first, 0), model.index(last, 0));
+}
+
+}
+}
@@ -0,0 +1,637 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use super::rowdiff::{diff_rows, RowOperation};
+use crate::{QHash, QHashPair_i32_QByteArray, QModelIndex, QVariant};
+use core::pin::Pin;
+use cxx_qt::Upcast;
+use std::hash::Hash;
+
+#[cxx_qt::bridge]
+mod ffi {
+    #[helper_namespace = "cxx_qt_lib"]
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qabstractlistmodel.h");
+        /// The QAbstractListModel class provides an abstract model that can be subclassed to create one-dimensional list models.
+        ///
+        /// This can be used as the `#[base]` of a QObject, which can then store its rows
+        /// in Rust by implementing [QAbstractListModelRows].
+        /// The signals of QAbstractItemModel can be connected to after upcasting the QObject to a QAbstractListModel.
+        #[qobject]
+        type QAbstractListModel;
+
+        /// This signal is emitted whenever the data in an existing item changes.
+        ///
+        /// If the items are of the same parent, the affected ones are those between top_left and bottom_right inclusive.
+        /// The optional roles argument can be used to specify which data roles have actually been modified,
+        /// an empty vector means that all roles should be considered modified.
+        #[qsignal]
+        #[cxx_name = "dataChanged"]
+        fn data_changed(
+            self: Pin<&mut QAbstractListModel>,
+            top_left: &QModelIndex,
+            bottom_right: &QModelIndex,
+            roles: &QVector_i32,
+        );
+
+        /// This signal is emitted just before the internal state of the model
+        /// (e.g. persistent model indexes) has been invalidated.
+        #[qsignal]
+        #[private_signal]
+        #[cxx_name = "modelAboutToBeReset"]
+        fn model_about_to_be_reset(self: Pin<&mut QAbstractListModel>);
+
+        /// This signal is emitted when the model is reset,
+        /// after the internal state of the model (e.g. persistent model indexes) has been invalidated.
+        #[qsignal]
+        #[private_signal]
+        #[cxx_name = "modelReset"]
+        fn model_reset(self: Pin<&mut QAbstractListModel>);
+
+        /// This signal is emitted just before rows are inserted into the model.
+        /// The new items will be positioned between first and last inclusive, under the given parent item.
+        #[qsignal]
+        #[private_signal]
+        #[cxx_name = "rowsAboutToBeInserted"]
+        fn rows_about_to_be_inserted(
+            self: Pin<&mut QAbstractListModel>,
+            parent: &QModelIndex,
+            first: i32,
+            last: i32,
+        );
+
+        /// This signal is emitted just before rows are removed from the model.
+        /// The items that will be removed are those between first and last inclusive, under the given parent item.
+        #[qsignal]
+        #[private_signal]
+        #[cxx_name = "rowsAboutToBeRemoved"]
+        fn rows_about_to_be_removed(
+            self: Pin<&mut QAbstractListModel>,
+            parent: &QModelIndex,
+            first: i32,
+            last: i32,
+        );
+
+        /// This signal is emitted after rows have been inserted into the model.
+        /// The new items are those between first and last inclusive, under the given parent item.
+        #[qsignal]
+        #[private_signal]
+        #[cxx_name = "rowsInserted"]
+        fn rows_inserted(
+            self: Pin<&mut QAbstractListModel>,
+            parent: &QModelIndex,
+            first: i32,
+            last: i32,
+        );
+
+        /// This signal is emitted after rows have been moved within the model.
+        /// The items between start and end inclusive, under the given parent item
+        /// have been moved to destination starting at the given row.
+        #[qsignal]
+        #[private_signal]
+        #[cxx_name = "rowsMoved"]
+        fn rows_moved(
+            self: Pin<&mut QAbstractListModel>,
+            parent: &QModelIndex,
+            start: i32,
+            end: i32,
+            destination: &QModelIndex,
+            row: i32,
+        );
+
+        /// This signal is emitted after rows have been removed from the model.
+        /// The removed items are those between first and last inclusive, under the given parent item.
+        #[qsignal]
+        #[private_signal]
+        #[cxx_name = "rowsRemoved"]
+        fn rows_removed(
+            self: Pin<&mut QAbstractListModel>,
+            parent: &QModelIndex,
+            first: i32,
+            last: i32,
+        );
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = crate::QModelIndex;
+        include!("cxx-qt-lib/qvector.h");
+        type QVector_i32 = crate::QVector<i32>;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qabstractlistmodel_begin_insert_rows"]
+        fn qabstractlistmodelBeginInsertRows(
+            model: Pin<&mut QAbstractListModel>,
+            first: i32,
+            last: i32,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qabstractlistmodel_begin_move_rows"]
+        fn qabstractlistmodelBeginMoveRows(
+            model: Pin<&mut QAbstractListModel>,
+            first: i32,
+            last: i32,
+            destination: i32,
+        ) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qabstractlistmodel_begin_remove_rows"]
+        fn qabstractlistmodelBeginRemoveRows(
+            model: Pin<&mut QAbstractListModel>,
+            first: i32,
+            last: i32,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qabstractlistmodel_begin_reset_model"]
+        fn qabstractlistmodelBeginResetModel(model: Pin<&mut QAbstractListModel>);
+
+        #[doc(hidden)]
+        #[rust_name = "qabstractlistmodel_end_insert_rows"]
+        fn qabstractlistmodelEndInsertRows(model: Pin<&mut QAbstractListModel>);
+
+        #[doc(hidden)]
+        #[rust_name = "qabstractlistmodel_end_move_rows"]
+        fn qabstractlistmodelEndMoveRows(model: Pin<&mut QAbstractListModel>);
+
+        #[doc(hidden)]
+        #[rust_name = "qabstractlistmodel_end_remove_rows"]
+        fn qabstractlistmodelEndRemoveRows(model: Pin<&mut QAbstractListModel>);
+
+        #[doc(hidden)]
+        #[rust_name = "qabstractlistmodel_end_reset_model"]
+        fn qabstractlistmodelEndResetModel(model: Pin<&mut QAbstractListModel>);
+
+        #[doc(hidden)]
+        #[rust_name = "qabstractlistmodel_rows_changed"]
+        fn qabstractlistmodelRowsChanged(
+            model: Pin<&mut QAbstractListModel>,
+            first: i32,
+            last: i32,
+        );
+    }
+}
+
+pub use ffi::QAbstractListModel;
+
+/// A row of a list model whose fields are exposed as roles of the model.
+///
+/// This is usually implemented with `#[derive(QAbstractListModelRow)]`,
+/// which makes each field of the struct a role starting at `Qt::UserRole`,
+/// in the order the fields are declared, with the name of the field in camel case as the role name.
+/// The type of each field must implement [QVariantValue](crate::QVariantValue).
+pub trait QAbstractListModelRow {
+    /// The role names of the fields of the row,
+    /// this can be returned from `QAbstractItemModel::roleNames`
+    fn role_names() -> QHash<QHashPair_i32_QByteArray>;
+
+    /// The value of the field for the given role, or an invalid QVariant if there is no such role
+    fn role_data(&self, role: i32) -> QVariant;
+
+    /// Set the field for the given role to the value,
+    /// returns false if there is no such role or the value cannot be converted to the type of the field
+    fn set_role_data(&mut self, role: i32, value: &QVariant) -> bool;
+}
+
+/// A list model which stores its rows in a `Vec` in Rust.
+///
+/// This is implemented for a QObject with `QAbstractListModel` as its `#[base]` by giving access to the rows,
+/// the methods of the model can then be implemented by calling the provided methods,
+/// and the rows can be changed with methods which notify any views of the changes.
+///
+/// ```ignore
+/// #[cxx_qt::bridge]
+/// mod qobject {
+///     unsafe extern "C++" {
+///         include!("cxx-qt-lib/qabstractlistmodel.h");
+///         type QAbstractListModel = cxx_qt_lib::QAbstractListModel;
+///         // Also include QHash_i32_QByteArray, QModelIndex, and QVariant
+///     }
+///
+///     unsafe extern "RustQt" {
+///         #[qobject]
+///         #[base = QAbstractListModel]
+///         type BookModel = super::BookModelRust;
+///
+///         #[cxx_override]
+///         #[rust_name = "row_count"]
+///         fn rowCount(self: &BookModel, parent: &QModelIndex) -> i32;
+///
+///         #[cxx_override]
+///         fn data(self: &BookModel, index: &QModelIndex, role: i32) -> QVariant;
+///
+///         #[cxx_override]
+///         #[rust_name = "role_names"]
+///         fn roleNames(self: &BookModel) -> QHash_i32_QByteArray;
+///     }
+/// }
+///
+/// #[derive(QAbstractListModelRow)]
+/// pub struct Book {
+///     title: QString,
+///     page_count: i32,
+/// }
+///
+/// #[derive(Default)]
+/// pub struct BookModelRust {
+///     books: Vec<Book>,
+/// }
+///
+/// impl QAbstractListModelRows for qobject::BookModel {
+///     type Row = Book;
+///
+///     fn rows(&self) -> &[Book] {
+///         &self.books
+///     }
+///
+///     fn rows_mut(self: Pin<&mut Self>) -> &mut Vec<Book> {
+///         &mut self.rust_mut().get_mut().books
+///     }
+/// }
+///
+/// impl qobject::BookModel {
+///     fn row_count(&self, _parent: &QModelIndex) -> i32 {
+///         self.rows_row_count()
+///     }
+///
+///     fn data(&self, index: &QModelIndex, role: i32) -> QVariant {
+///         self.rows_data(index, role)
+///     }
+///
+///     fn role_names(&self) -> QHash<QHashPair_i32_QByteArray> {
+///         self.rows_role_names()
+///     }
+/// }
+/// ```
+///
+/// The provided methods have a `rows_` prefix so that they do not clash with the overridden methods of the QObject.
+pub trait QAbstractListModelRows: Upcast<QAbstractListModel> {
+    /// The type of the rows of the model
+    type Row: QAbstractListModelRow;
+
+    /// The rows of the model
+    fn rows(&self) -> &[Self::Row];
+
+    /// The rows of the model, which can be changed without notifying views
+    ///
+    /// Changes made through this must notify views of the changes to the rows,
+    /// so prefer the methods of this trait which do so.
+    fn rows_mut(self: Pin<&mut Self>) -> &mut Vec<Self::Row>;
+
+    /// An implementation of `QAbstractItemModel::rowCount` which returns the number of rows
+    fn rows_row_count(&self) -> i32 {
+        row_from_index(self.rows().len())
+    }
+
+    /// An implementation of `QAbstractItemModel::data` which returns the data of the row for the given role
+    fn rows_data(&self, index: &QModelIndex, role: i32) -> QVariant {
+        usize::try_from(index.row())
+            .ok()
+            .and_then(|row| self.rows().get(row))
+            .map(|row| row.role_data(role))
+            .unwrap_or_default()
+    }
+
+    /// An implementation of `QAbstractItemModel::roleNames` which returns the role names of the rows
+    fn rows_role_names(&self) -> QHash<QHashPair_i32_QByteArray> {
+        Self::Row::role_names()
+    }
+
+    /// An implementation of `QAbstractItemModel::setData` which sets the data of the row for the given role,
+    /// notifying views that the row has changed
+    fn rows_set_data(
+        mut self: Pin<&mut Self>,
+        index: &QModelIndex,
+        value: &QVariant,
+        role: i32,
+    ) -> bool {
+        let changed = usize::try_from(index.row())
+            .ok()
+            .and_then(|row| self.as_mut().rows_mut().get_mut(row))
+            .map(|row| row.set_role_data(role, value))
+            .unwrap_or_default();
+        if changed {
+            ffi::qabstractlistmodel_rows_changed(self.upcast_pin(), index.row(), index.row());
+        }
+        changed
+    }
+
+    /// Inserts the row at the given position, notifying views that the row has been inserted
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is greater than the number of rows.
+    fn insert_row(mut self: Pin<&mut Self>, position: usize, row: Self::Row) {
+        let len = self.rows().len();
+        assert!(
+            position <= len,
+            "insertion position (is {position}) should be <= len (is {len})"
+        );
+
+        let first = row_from_index(position);
+        ffi::qabstractlistmodel_begin_insert_rows(self.as_mut().upcast_pin(), first, first);
+        self.as_mut().rows_mut().insert(position, row);
+        ffi::qabstractlistmodel_end_insert_rows(self.upcast_pin());
+    }
+
+    /// Appends the row after the last row, notifying views that the row has been inserted
+    fn push_row(self: Pin<&mut Self>, row: Self::Row) {
+        let len = self.rows().len();
+        self.insert_row(len, row);
+    }
+
+    /// Appends the rows after the last row, notifying views that the rows have been inserted at once
+    fn append_rows(mut self: Pin<&mut Self>, rows: Vec<Self::Row>) {
+        if rows.is_empty() {
+            return;
+        }
+
+        let len = self.rows().len();
+        ffi::qabstractlistmodel_begin_insert_rows(
+            self.as_mut().upcast_pin(),
+            row_from_index(len),
+            row_from_index(len + rows.len() - 1),
+        );
+        self.as_mut().rows_mut().extend(rows);
+        ffi::qabstractlistmodel_end_insert_rows(self.upcast_pin());
+    }
+
+    /// Removes and returns the row at the given position, notifying views that the row has been removed
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is out of bounds.
+    fn remove_row(mut self: Pin<&mut Self>, position: usize) -> Self::Row {
+        let len = self.rows().len();
+        assert!(
+            position < len,
+            "removal position (is {position}) should be < len (is {len})"
+        );
+
+        let first = row_from_index(position);
+        ffi::qabstractlistmodel_begin_remove_rows(self.as_mut().upcast_pin(), first, first);
+        let row = self.as_mut().rows_mut().remove(position);
+        ffi::qabstractlistmodel_end_remove_rows(self.upcast_pin());
+        row
+    }
+
+    /// Replaces the row at the given position and returns the previous row,
+    /// notifying views that the data of the row has changed
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is out of bounds.
+    fn update_row(mut self: Pin<&mut Self>, position: usize, row: Self::Row) -> Self::Row {
+        let previous = core::mem::replace(&mut self.as_mut().rows_mut()[position], row);
+        let row = row_from_index(position);
+        ffi::qabstractlistmodel_rows_changed(self.upcast_pin(), row, row);
+        previous
+    }
+
+    /// Replaces all of the rows, notifying views that the model has been reset
+    fn set_rows(mut self: Pin<&mut Self>, rows: Vec<Self::Row>) {
+        ffi::qabstractlistmodel_begin_reset_model(self.as_mut().upcast_pin());
+        *self.as_mut().rows_mut() = rows;
+        ffi::qabstractlistmodel_end_reset_model(self.upcast_pin());
+    }
+
+    /// Replaces all of the rows, notifying views of the rows which have been removed, moved, inserted, and changed,
+    /// rather than resetting the model, so that views keep their scroll position and selection.
+    ///
+    /// Rows which have the same key in the previous and new rows are the same row,
+    /// so the key should identify a row, such as the id of a record in a database.
+    /// The fewest rows are moved, and rows which are not equal to the new row are changed.
+    /// If the keys are not unique the model is reset as with [set_rows](QAbstractListModelRows::set_rows).
+    ///
+    /// ```ignore
+    /// let books = load_books();
+    /// model.as_mut().replace_rows(books, |book| book.id);
+    /// ```
+    fn replace_rows<K, F>(mut self: Pin<&mut Self>, rows: Vec<Self::Row>, key: F)
+    where
+        Self::Row: PartialEq,
+        K: Eq + Hash,
+        F: Fn(&Self::Row) -> K,
+    {
+        let Some(operations) = diff_rows(self.rows(), &rows, key) else {
+            self.set_rows(rows);
+            return;
+        };
+
+        let mut rows: Vec<Option<Self::Row>> = rows.into_iter().map(Some).collect();
+        for operation in operations {
+            match operation {
+                RowOperation::Remove { first, last } => {
+                    ffi::qabstractlistmodel_begin_remove_rows(
+                        self.as_mut().upcast_pin(),
+                        row_from_index(first),
+                        row_from_index(last),
+                    );
+                    self.as_mut().rows_mut().drain(first..=last);
+                    ffi::qabstractlistmodel_end_remove_rows(self.as_mut().upcast_pin());
+                }
+                RowOperation::Move { from, to } => {
+                    // The destination of a move is the row before which the row is placed,
+                    // which is after the row at to when moving down
+                    let destination = if to > from { to + 1 } else { to };
+                    let from_row = row_from_index(from);
+                    ffi::qabstractlistmodel_begin_move_rows(
+                        self.as_mut().upcast_pin(),
+                        from_row,
+                        from_row,
+                        row_from_index(destination),
+                    );
+                    let current = self.as_mut().rows_mut();
+                    let row = current.remove(from);
+                    current.insert(to, row);
+                    ffi::qabstractlistmodel_end_move_rows(self.as_mut().upcast_pin());
+                }
+                RowOperation::Insert { first, last } => {
+                    ffi::qabstractlistmodel_begin_insert_rows(
+                        self.as_mut().upcast_pin(),
+                        row_from_index(first),
+                        row_from_index(last),
+                    );
+                    let inserted = rows[first..=last].iter_mut().filter_map(Option::take);
+                    self.as_mut().rows_mut().splice(first..first, inserted);
+                    ffi::qabstractlistmodel_end_insert_rows(self.as_mut().upcast_pin());
+                }
+                RowOperation::Change { first, last } => {
+                    let current = self.as_mut().rows_mut();
+                    for (position, row) in (first..=last).zip(&mut rows[first..=last]) {
+                        if let Some(row) = row.take() {
+                            current[position] = row;
+                        }
+                    }
+                    ffi::qabstractlistmodel_rows_changed(
+                        self.as_mut().upcast_pin(),
+                        row_from_index(first),
+                        row_from_index(last),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// The number of rows which a [QAbstractListModelFetchRows] has fetched and whether all of them have been fetched,
+/// which is stored in the Rust struct of the model.
+///
+/// The rows are counted separately from the rows of the model,
+/// so that rows which are inserted or removed by the model do not change the offset of the next batch.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct QAbstractListModelFetchState {
+    fetched: usize,
+    all_fetched: bool,
+}
+
+impl QAbstractListModelFetchState {
+    /// The number of rows which have been fetched, which is the offset of the next batch
+    pub fn fetched(&self) -> usize {
+        self.fetched
+    }
+
+    /// Returns true until a batch with fewer rows than the batch size has been fetched
+    pub fn can_fetch_more(&self) -> bool {
+        !self.all_fetched
+    }
+
+    /// Record that a batch of rows has been fetched
+    fn batch_fetched(&mut self, len: usize, batch_size: usize) {
+        self.fetched += len;
+        if len < batch_size {
+            self.all_fetched = true;
+        }
+    }
+}
+
+/// A list model which fetches its rows in batches as a view scrolls,
+/// so that a large number of rows, such as the results of a database query, are loaded incrementally.
+///
+/// The model overrides `canFetchMore` and `fetchMore` and calls the provided methods,
+/// which fetch the next batch after the rows which have already been fetched.
+/// A batch with fewer rows than the batch size means that all of the rows have been fetched.
+///
+/// ```ignore
+/// #[cxx_qt::bridge]
+/// mod qobject {
+///     unsafe extern "RustQt" {
+///         #[qobject]
+///         #[base = QAbstractListModel]
+///         type BookModel = super::BookModelRust;
+///
+///         #[cxx_override]
+///         #[rust_name = "can_fetch_more"]
+///         fn canFetchMore(self: &BookModel, parent: &QModelIndex) -> bool;
+///
+///         #[cxx_override]
+///         #[rust_name = "fetch_more"]
+///         fn fetchMore(self: Pin<&mut BookModel>, parent: &QModelIndex);
+///     }
+/// }
+///
+/// impl QAbstractListModelFetchRows for qobject::BookModel {
+///     fn fetch_rows(&self, offset: usize, limit: usize) -> Vec<Book> {
+///         self.database.books(offset, limit)
+///     }
+///
+///     fn fetch_state(&self) -> &QAbstractListModelFetchState {
+///         &self.fetch_state
+///     }
+///
+///     fn fetch_state_mut(self: Pin<&mut Self>) -> &mut QAbstractListModelFetchState {
+///         &mut self.rust_mut().get_mut().fetch_state
+///     }
+/// }
+///
+/// impl qobject::BookModel {
+///     fn can_fetch_more(&self, _parent: &QModelIndex) -> bool {
+///         self.rows_can_fetch_more()
+///     }
+///
+///     fn fetch_more(self: Pin<&mut Self>, _parent: &QModelIndex) {
+///         self.rows_fetch_more();
+///     }
+/// }
+/// ```
+pub trait QAbstractListModelFetchRows: QAbstractListModelRows {
+    /// The number of rows which are fetched in each batch
+    const BATCH_SIZE: usize = 256;
+
+    /// Fetch at most limit rows starting at offset, returning fewer rows when the end has been reached
+    fn fetch_rows(&self, offset: usize, limit: usize) -> Vec<Self::Row>;
+
+    /// The number of rows which have been fetched
+    fn fetch_state(&self) -> &QAbstractListModelFetchState;
+
+    /// The number of rows which have been fetched, which is changed by the provided methods
+    fn fetch_state_mut(self: Pin<&mut Self>) -> &mut QAbstractListModelFetchState;
+
+    /// An implementation of `QAbstractItemModel::canFetchMore` which returns true until all of the rows have been fetched
+    fn rows_can_fetch_more(&self) -> bool {
+        self.fetch_state().can_fetch_more()
+    }
+
+    /// An implementation of `QAbstractItemModel::fetchMore` which appends the next batch of rows,
+    /// notifying views that the rows have been inserted
+    fn rows_fetch_more(mut self: Pin<&mut Self>) {
+        if !self.fetch_state().can_fetch_more() {
+            return;
+        }
+
+        let batch_size = Self::BATCH_SIZE.max(1);
+        let batch = self.fetch_rows(self.fetch_state().fetched(), batch_size);
+        self.as_mut()
+            .fetch_state_mut()
+            .batch_fetched(batch.len(), batch_size);
+        self.append_rows(batch);
+    }
+
+    /// Removes all of the rows, notifying views that the model has been reset,
+    /// so that the rows are fetched again from the start, for example when the query has changed
+    fn refetch_rows(mut self: Pin<&mut Self>) {
+        *self.as_mut().fetch_state_mut() = QAbstractListModelFetchState::default();
+        self.set_rows(vec![]);
+    }
+}
+
+/// Convert the index of a row in Rust to the row in the model, Qt models have at most `i32::MAX` rows
+fn row_from_index(index: usize) -> i32 {
+    i32::try_from(index).expect("list models have at most i32::MAX rows")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fetch_state_batches() {
+        let mut state = QAbstractListModelFetchState::default();
+        assert_eq!(state.fetched(), 0);
+        assert!(state.can_fetch_more());
+
+        state.batch_fetched(2, 2);
+        assert_eq!(state.fetched(), 2);
+        assert!(state.can_fetch_more());
+
+        // A batch which is not full is the end of the rows
+        state.batch_fetched(1, 2);
+        assert_eq!(state.fetched(), 3);
+        assert!(!state.can_fetch_more());
+    }
+
+    #[test]
+    fn fetch_state_empty_batch() {
+        let mut state = QAbstractListModelFetchState::default();
+        state.batch_fetched(2, 2);
+        state.batch_fetched(0, 2);
+        assert_eq!(state.fetched(), 2);
+        assert!(!state.can_fetch_more());
+    }
+}
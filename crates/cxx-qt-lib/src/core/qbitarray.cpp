@@ -0,0 +1,161 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qbitarray.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QBitArray has one QByteArray member, so the layout has changed between
+// Qt 5 and Qt 6 in the same way as QByteArray
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/tools/qbitarray.h?h=v5.15.6-lts-lgpl#n51
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/tools/qbitarray.h?h=v6.2.4#n52
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+assert_alignment_and_size(QBitArray, {
+  ::std::size_t a0;
+  ::std::size_t a1;
+  ::std::size_t a2;
+});
+#else
+assert_alignment_and_size(QBitArray, { ::std::size_t a0; });
+#endif
+
+static_assert(!::std::is_trivially_copy_assignable<QBitArray>::value);
+static_assert(!::std::is_trivially_copy_constructible<QBitArray>::value);
+
+static_assert(!::std::is_trivially_destructible<QBitArray>::value);
+
+static_assert(QTypeInfo<QBitArray>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QBitArray
+qbitarrayAnd(const QBitArray& a, const QBitArray& b)
+{
+  return a & b;
+}
+
+::rust::isize
+qbitarrayCount(const QBitArray& bitArray, bool on)
+{
+  // In Qt 5 the type was int now it is qsizetype, so we need to ensure the type
+  // is the same for CXX
+  return static_cast<::rust::isize>(bitArray.count(on));
+}
+
+bool
+qbitarrayFill(QBitArray& bitArray, bool value, ::rust::isize size)
+{
+  Q_ASSERT(size >= -1);
+  // Qt 5 has an int Qt 6 has a qsizetype
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return bitArray.fill(value, static_cast<qsizetype>(size));
+#else
+  return bitArray.fill(value, static_cast<int>(size));
+#endif
+}
+
+QBitArray
+qbitarrayInit(::rust::isize size, bool value)
+{
+  Q_ASSERT(size >= 0);
+  // Qt 5 has an int Qt 6 has a qsizetype
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return QBitArray(static_cast<qsizetype>(size), value);
+#else
+  return QBitArray(static_cast<int>(size), value);
+#endif
+}
+
+::rust::isize
+qbitarrayLen(const QBitArray& bitArray)
+{
+  // In Qt 5 the type was int now it is qsizetype, so we need to ensure the type
+  // is the same for CXX
+  return static_cast<::rust::isize>(bitArray.size());
+}
+
+QBitArray
+qbitarrayNot(const QBitArray& bitArray)
+{
+  return ~bitArray;
+}
+
+QBitArray
+qbitarrayOr(const QBitArray& a, const QBitArray& b)
+{
+  return a | b;
+}
+
+void
+qbitarrayResize(QBitArray& bitArray, ::rust::isize size)
+{
+  Q_ASSERT(size >= 0);
+  // Qt 5 has an int Qt 6 has a qsizetype
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  bitArray.resize(static_cast<qsizetype>(size));
+#else
+  bitArray.resize(static_cast<int>(size));
+#endif
+}
+
+void
+qbitarraySetBit(QBitArray& bitArray, ::rust::isize i, bool value)
+{
+  Q_ASSERT(i >= 0);
+  // Qt 5 has an int Qt 6 has a qsizetype
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  bitArray.setBit(static_cast<qsizetype>(i), value);
+#else
+  bitArray.setBit(static_cast<int>(i), value);
+#endif
+}
+
+bool
+qbitarrayTestBit(const QBitArray& bitArray, ::rust::isize i)
+{
+  Q_ASSERT(i >= 0);
+  // Qt 5 has an int Qt 6 has a qsizetype
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return bitArray.testBit(static_cast<qsizetype>(i));
+#else
+  return bitArray.testBit(static_cast<int>(i));
+#endif
+}
+
+bool
+qbitarrayToggleBit(QBitArray& bitArray, ::rust::isize i)
+{
+  Q_ASSERT(i >= 0);
+  // Qt 5 has an int Qt 6 has a qsizetype
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return bitArray.toggleBit(static_cast<qsizetype>(i));
+#else
+  return bitArray.toggleBit(static_cast<int>(i));
+#endif
+}
+
+void
+qbitarrayTruncate(QBitArray& bitArray, ::rust::isize pos)
+{
+  Q_ASSERT(pos >= 0);
+  // Qt 5 has an int Qt 6 has a qsizetype
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  bitArray.truncate(static_cast<qsizetype>(pos));
+#else
+  bitArray.truncate(static_cast<int>(pos));
+#endif
+}
+
+QBitArray
+qbitarrayXor(const QBitArray& a, const QBitArray& b)
+{
+  return a ^ b;
+}
+
+}
+}
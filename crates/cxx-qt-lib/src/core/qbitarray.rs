@@ -0,0 +1,325 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbitarray.h");
+        type QBitArray = super::QBitArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// Clears the contents of the bit array and makes it empty.
+        fn clear(self: &mut QBitArray);
+
+        /// Returns true if this bit array has size 0; otherwise returns false.
+        #[rust_name = "is_empty"]
+        fn isEmpty(self: &QBitArray) -> bool;
+
+        /// Returns true if this bit array is null; otherwise returns false.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QBitArray) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qbitarray_and"]
+        fn qbitarrayAnd(a: &QBitArray, b: &QBitArray) -> QBitArray;
+        #[doc(hidden)]
+        #[rust_name = "qbitarray_count"]
+        fn qbitarrayCount(bitarray: &QBitArray, on: bool) -> isize;
+        #[doc(hidden)]
+        #[rust_name = "qbitarray_fill"]
+        fn qbitarrayFill(bitarray: &mut QBitArray, value: bool, size: isize) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qbitarray_init"]
+        fn qbitarrayInit(size: isize, value: bool) -> QBitArray;
+        #[doc(hidden)]
+        #[rust_name = "qbitarray_len"]
+        fn qbitarrayLen(bitarray: &QBitArray) -> isize;
+        #[doc(hidden)]
+        #[rust_name = "qbitarray_not"]
+        fn qbitarrayNot(bitarray: &QBitArray) -> QBitArray;
+        #[doc(hidden)]
+        #[rust_name = "qbitarray_or"]
+        fn qbitarrayOr(a: &QBitArray, b: &QBitArray) -> QBitArray;
+        #[doc(hidden)]
+        #[rust_name = "qbitarray_resize"]
+        fn qbitarrayResize(bitarray: &mut QBitArray, size: isize);
+        #[doc(hidden)]
+        #[rust_name = "qbitarray_set_bit"]
+        fn qbitarraySetBit(bitarray: &mut QBitArray, i: isize, value: bool);
+        #[doc(hidden)]
+        #[rust_name = "qbitarray_test_bit"]
+        fn qbitarrayTestBit(bitarray: &QBitArray, i: isize) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qbitarray_toggle_bit"]
+        fn qbitarrayToggleBit(bitarray: &mut QBitArray, i: isize) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qbitarray_truncate"]
+        fn qbitarrayTruncate(bitarray: &mut QBitArray, pos: isize);
+        #[doc(hidden)]
+        #[rust_name = "qbitarray_xor"]
+        fn qbitarrayXor(a: &QBitArray, b: &QBitArray) -> QBitArray;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qbitarray_clone"]
+        fn construct(bitarray: &QBitArray) -> QBitArray;
+        #[doc(hidden)]
+        #[rust_name = "qbitarray_default"]
+        fn construct() -> QBitArray;
+        #[doc(hidden)]
+        #[rust_name = "qbitarray_drop"]
+        fn drop(bitarray: &mut QBitArray);
+        #[doc(hidden)]
+        #[rust_name = "qbitarray_eq"]
+        fn operatorEq(a: &QBitArray, b: &QBitArray) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qbitarray_to_qstring"]
+        fn toQString(value: &QBitArray) -> QString;
+    }
+}
+
+/// The QBitArray class provides an array of bits.
+#[repr(C)]
+pub struct QBitArray {
+    /// The layout has changed between Qt 5 and Qt 6
+    ///
+    /// Qt5 QBitArray has one QByteArray member, which has one pointer as a member
+    /// Qt6 QBitArray has one QByteArray member, which contains two pointers and a size_t
+    #[cfg(cxxqt_qt_version_major = "5")]
+    _space: MaybeUninit<usize>,
+    #[cfg(cxxqt_qt_version_major = "6")]
+    _space: MaybeUninit<[usize; 3]>,
+}
+
+impl Clone for QBitArray {
+    /// Constructs a copy of other.
+    fn clone(&self) -> Self {
+        ffi::qbitarray_clone(self)
+    }
+}
+
+impl Default for QBitArray {
+    /// Constructs an empty bit array.
+    fn default() -> Self {
+        ffi::qbitarray_default()
+    }
+}
+
+impl Drop for QBitArray {
+    /// Destroys the bit array.
+    fn drop(&mut self) {
+        ffi::qbitarray_drop(self)
+    }
+}
+
+impl PartialEq for QBitArray {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qbitarray_eq(self, other)
+    }
+}
+
+impl Eq for QBitArray {}
+
+impl fmt::Display for QBitArray {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qbitarray_to_qstring(self))
+    }
+}
+
+impl fmt::Debug for QBitArray {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl std::ops::BitAnd for QBitArray {
+    type Output = Self;
+    /// Returns a bit array that is the AND of the bit arrays,
+    /// the result has the length of the longest bit array with any missing bits taken to be 0.
+    fn bitand(self, other: Self) -> Self {
+        ffi::qbitarray_and(&self, &other)
+    }
+}
+
+impl std::ops::BitOr for QBitArray {
+    type Output = Self;
+    /// Returns a bit array that is the OR of the bit arrays,
+    /// the result has the length of the longest bit array with any missing bits taken to be 0.
+    fn bitor(self, other: Self) -> Self {
+        ffi::qbitarray_or(&self, &other)
+    }
+}
+
+impl std::ops::BitXor for QBitArray {
+    type Output = Self;
+    /// Returns a bit array that is the XOR of the bit arrays,
+    /// the result has the length of the longest bit array with any missing bits taken to be 0.
+    fn bitxor(self, other: Self) -> Self {
+        ffi::qbitarray_xor(&self, &other)
+    }
+}
+
+impl std::ops::Not for QBitArray {
+    type Output = Self;
+    /// Returns a bit array that contains the inverted bits of this bit array.
+    fn not(self) -> Self {
+        ffi::qbitarray_not(&self)
+    }
+}
+
+impl From<&[bool]> for QBitArray {
+    /// Constructs a bit array containing the given bits.
+    fn from(bits: &[bool]) -> Self {
+        let mut bitarray = Self::new(bits.len() as isize, false);
+        for (i, bit) in bits.iter().enumerate() {
+            if *bit {
+                ffi::qbitarray_set_bit(&mut bitarray, i as isize, true);
+            }
+        }
+        bitarray
+    }
+}
+
+impl From<&QBitArray> for Vec<bool> {
+    /// Converts a QBitArray to a vector containing its bits.
+    fn from(bitarray: &QBitArray) -> Self {
+        (0..bitarray.len())
+            .map(|i| ffi::qbitarray_test_bit(bitarray, i))
+            .collect()
+    }
+}
+
+#[cfg(feature = "bitvec")]
+impl<T: bitvec::store::BitStore, O: bitvec::order::BitOrder> From<&bitvec::vec::BitVec<T, O>>
+    for QBitArray
+{
+    /// Constructs a QBitArray containing the bits of the `bitvec::vec::BitVec`.
+    fn from(bits: &bitvec::vec::BitVec<T, O>) -> Self {
+        let mut bitarray = Self::new(bits.len() as isize, false);
+        for i in bits.iter_ones() {
+            ffi::qbitarray_set_bit(&mut bitarray, i as isize, true);
+        }
+        bitarray
+    }
+}
+
+#[cfg(feature = "bitvec")]
+impl<T: bitvec::store::BitStore, O: bitvec::order::BitOrder> From<&QBitArray>
+    for bitvec::vec::BitVec<T, O>
+{
+    /// Converts a QBitArray to a `bitvec::vec::BitVec` containing its bits.
+    fn from(bitarray: &QBitArray) -> Self {
+        (0..bitarray.len())
+            .map(|i| ffi::qbitarray_test_bit(bitarray, i))
+            .collect()
+    }
+}
+
+impl QBitArray {
+    /// Returns the number of bits that are set to on, if on is true,
+    /// or the number of bits that are cleared, if on is false.
+    pub fn count(&self, on: bool) -> isize {
+        ffi::qbitarray_count(self, on)
+    }
+
+    /// Sets every bit in the bit array to value,
+    /// if size is not -1 the bit array is resized to size beforehand.
+    ///
+    /// Returns true if successful; otherwise returns false.
+    pub fn fill(&mut self, value: bool, size: isize) -> bool {
+        ffi::qbitarray_fill(self, value, size)
+    }
+
+    /// Returns the value of the bit at index position i,
+    /// or None if i is not a valid index position in the bit array (i.e., 0 <= i < len()).
+    pub fn get(&self, i: isize) -> Option<bool> {
+        if i >= 0 && i < self.len() {
+            Some(ffi::qbitarray_test_bit(self, i))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of bits stored in the bit array.
+    pub fn len(&self) -> isize {
+        ffi::qbitarray_len(self)
+    }
+
+    /// Constructs a bit array containing size bits, which are each initialized to value.
+    pub fn new(size: isize, value: bool) -> Self {
+        ffi::qbitarray_init(size, value)
+    }
+
+    /// Resizes the bit array to size bits.
+    ///
+    /// If size is greater than the current size, the bit array is extended with bits which are cleared.
+    /// If size is less than the current size, bits are removed from the end.
+    pub fn resize(&mut self, size: isize) {
+        ffi::qbitarray_resize(self, size);
+    }
+
+    /// Sets the bit at index position i to value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if i is not a valid index position in the bit array (i.e., 0 <= i < len()).
+    pub fn set_bit(&mut self, i: isize, value: bool) {
+        assert!(i >= 0 && i < self.len(), "index out of bounds");
+        ffi::qbitarray_set_bit(self, i, value);
+    }
+
+    /// Inverts the value of the bit at index position i, returning the previous value of that bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if i is not a valid index position in the bit array (i.e., 0 <= i < len()).
+    pub fn toggle_bit(&mut self, i: isize) -> bool {
+        assert!(i >= 0 && i < self.len(), "index out of bounds");
+        ffi::qbitarray_toggle_bit(self, i)
+    }
+
+    /// Truncates the bit array at index position pos.
+    ///
+    /// If pos is beyond the end of the array, nothing happens.
+    pub fn truncate(&mut self, pos: isize) {
+        ffi::qbitarray_truncate(self, pos);
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QBitArray {
+    type Id = type_id!("QBitArray");
+    type Kind = cxx::kind::Trivial;
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "bitvec")]
+    use super::*;
+
+    #[cfg(feature = "bitvec")]
+    #[test]
+    fn test_bitvec() {
+        let bits = bitvec::bitvec![1, 0, 1, 1];
+        let qbitarray = QBitArray::from(&bits);
+        assert_eq!(Vec::from(&qbitarray), vec![true, false, true, true]);
+
+        let bitvec_bits = bitvec::vec::BitVec::<usize, bitvec::order::Lsb0>::from(&qbitarray);
+        assert_eq!(bits, bitvec_bits);
+    }
+}
@@ -0,0 +1,57 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qcollator.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+#include <QtCore/QLocale>
+
+// QCollator has one pointer to its private data as a member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/text/qcollator.h?h=v5.15.6-lts-lgpl#n126
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/text/qcollator.h?h=v6.2.4#n122
+assert_alignment_and_size(QCollator, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QCollator>::value);
+static_assert(!::std::is_trivially_copy_constructible<QCollator>::value);
+
+static_assert(!::std::is_trivially_destructible<QCollator>::value);
+
+static_assert(QTypeInfo<QCollator>::isRelocatable);
+
+// QCollatorSortKey has one QExplicitlySharedDataPointer member, which has one
+// pointer as a member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/text/qcollator.h?h=v5.15.6-lts-lgpl#n80
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/text/qcollator.h?h=v6.2.4#n76
+assert_alignment_and_size(QCollatorSortKey, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QCollatorSortKey>::value);
+static_assert(
+  !::std::is_trivially_copy_constructible<QCollatorSortKey>::value);
+
+static_assert(!::std::is_trivially_destructible<QCollatorSortKey>::value);
+
+static_assert(QTypeInfo<QCollatorSortKey>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QCollator
+qcollatorInitFromLocaleName(const QString& name)
+{
+  return QCollator(QLocale(name));
+}
+
+QString
+qcollatorLocaleName(const QCollator& collator)
+{
+  return collator.locale().name();
+}
+
+}
+}
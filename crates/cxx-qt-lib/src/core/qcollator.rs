@@ -0,0 +1,205 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::cmp::Ordering;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type CaseSensitivity = crate::CaseSensitivity;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcollator.h");
+        type QCollator = super::QCollator;
+        type QCollatorSortKey = super::QCollatorSortKey;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// Returns case sensitivity of the collator.
+        #[rust_name = "case_sensitivity"]
+        fn caseSensitivity(self: &QCollator) -> CaseSensitivity;
+
+        // We wrap this method to provide an enum so hide it from docs
+        #[doc(hidden)]
+        #[rust_name = "compare_i32"]
+        fn compare(self: &QCollator, s1: &QString, s2: &QString) -> i32;
+
+        /// Returns true if punctuation characters and symbols are ignored when determining sort order.
+        #[rust_name = "ignore_punctuation"]
+        fn ignorePunctuation(self: &QCollator) -> bool;
+
+        /// Returns true if numeric sorting is enabled, false otherwise.
+        #[rust_name = "numeric_mode"]
+        fn numericMode(self: &QCollator) -> bool;
+
+        /// Sets the case sensitivity of the collator to cs.
+        #[rust_name = "set_case_sensitivity"]
+        fn setCaseSensitivity(self: &mut QCollator, cs: CaseSensitivity);
+
+        /// If on is set to true, punctuation characters and symbols are ignored when determining sort order.
+        #[rust_name = "set_ignore_punctuation"]
+        fn setIgnorePunctuation(self: &mut QCollator, on: bool);
+
+        /// Enables numeric sorting mode when on is true,
+        /// so that strings containing numbers are sorted by the value of the numbers ("file2" before "file10").
+        #[rust_name = "set_numeric_mode"]
+        fn setNumericMode(self: &mut QCollator, on: bool);
+
+        /// Returns a sort key for the string str.
+        ///
+        /// The sort key can be used to speed up string collation, as comparing two sort keys
+        /// is faster than comparing the strings themselves.
+        #[rust_name = "sort_key"]
+        fn sortKey(self: &QCollator, str: &QString) -> QCollatorSortKey;
+
+        // We wrap this method to provide an enum so hide it from docs
+        #[doc(hidden)]
+        #[rust_name = "compare_i32"]
+        fn compare(self: &QCollatorSortKey, other: &QCollatorSortKey) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qcollator_init_from_locale_name"]
+        fn qcollatorInitFromLocaleName(name: &QString) -> QCollator;
+        #[doc(hidden)]
+        #[rust_name = "qcollator_locale_name"]
+        fn qcollatorLocaleName(collator: &QCollator) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qcollator_clone"]
+        fn construct(collator: &QCollator) -> QCollator;
+        #[doc(hidden)]
+        #[rust_name = "qcollator_default"]
+        fn construct() -> QCollator;
+        #[doc(hidden)]
+        #[rust_name = "qcollator_drop"]
+        fn drop(collator: &mut QCollator);
+
+        #[doc(hidden)]
+        #[rust_name = "qcollatorsortkey_clone"]
+        fn construct(key: &QCollatorSortKey) -> QCollatorSortKey;
+        #[doc(hidden)]
+        #[rust_name = "qcollatorsortkey_drop"]
+        fn drop(key: &mut QCollatorSortKey);
+    }
+}
+
+/// The QCollator class compares strings according to a localized collation algorithm.
+///
+/// This allows for sorting strings in Rust in the same order as they are sorted elsewhere in the Qt application.
+#[repr(C)]
+pub struct QCollator {
+    _d: MaybeUninit<usize>,
+}
+
+impl QCollator {
+    /// Compares s1 with s2 according to the collation of the locale.
+    pub fn compare(&self, s1: &ffi::QString, s2: &ffi::QString) -> Ordering {
+        self.compare_i32(s1, s2).cmp(&0)
+    }
+
+    /// Constructs a QCollator using the locale with the given name,
+    /// which has the format "language[_territory][.codeset][@modifier]" or "C".
+    pub fn from_locale_name(name: &ffi::QString) -> Self {
+        ffi::qcollator_init_from_locale_name(name)
+    }
+
+    /// Returns the name of the locale the collator is using.
+    pub fn locale_name(&self) -> ffi::QString {
+        ffi::qcollator_locale_name(self)
+    }
+}
+
+impl Clone for QCollator {
+    /// Creates a copy of other.
+    fn clone(&self) -> Self {
+        ffi::qcollator_clone(self)
+    }
+}
+
+impl Default for QCollator {
+    /// Constructs a QCollator using the default locale's collation locale.
+    fn default() -> Self {
+        ffi::qcollator_default()
+    }
+}
+
+impl Drop for QCollator {
+    /// Destroys the collator.
+    fn drop(&mut self) {
+        ffi::qcollator_drop(self)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QCollator {
+    type Id = type_id!("QCollator");
+    type Kind = cxx::kind::Trivial;
+}
+
+/// The QCollatorSortKey class can be used to speed up string collation.
+///
+/// Sort keys can only be created by [QCollator::sort_key],
+/// and sort keys created by different collators should not be compared.
+#[repr(C)]
+pub struct QCollatorSortKey {
+    _d: MaybeUninit<usize>,
+}
+
+impl Clone for QCollatorSortKey {
+    /// Constructs a copy of the other collator key.
+    fn clone(&self) -> Self {
+        ffi::qcollatorsortkey_clone(self)
+    }
+}
+
+impl Drop for QCollatorSortKey {
+    /// Destroys the collator key.
+    fn drop(&mut self) {
+        ffi::qcollatorsortkey_drop(self)
+    }
+}
+
+impl PartialEq for QCollatorSortKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for QCollatorSortKey {}
+
+impl PartialOrd for QCollatorSortKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QCollatorSortKey {
+    /// Compares this key to the other key, which must have been created by the same collator.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare_i32(other).cmp(&0)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QCollatorSortKey {
+    type Id = type_id!("QCollatorSortKey");
+    type Kind = cxx::kind::Trivial;
+}
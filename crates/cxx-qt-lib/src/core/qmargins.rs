@@ -182,6 +182,14 @@ impl std::ops::Div<f64> for QMargins {
     }
 }
 
+impl std::ops::Neg for QMargins {
+    type Output = Self;
+    /// Returns margins with all components negated.
+    fn neg(self) -> Self {
+        Self::new(-self.left, -self.top, -self.right, -self.bottom)
+    }
+}
+
 // Safety:
 //
 // Static checks on the C++ side ensure that QMargins is trivial.
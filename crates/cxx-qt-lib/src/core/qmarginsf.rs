@@ -164,6 +164,14 @@ impl std::ops::Div<f64> for QMarginsF {
     }
 }
 
+impl std::ops::Neg for QMarginsF {
+    type Output = Self;
+    /// Returns margins with all components negated.
+    fn neg(self) -> Self {
+        Self::new(-self.left, -self.top, -self.right, -self.bottom)
+    }
+}
+
 impl From<&ffi::QMargins> for QMarginsF {
     /// Constructs margins copied from the given margins.
     fn from(margins: &ffi::QMargins) -> Self {
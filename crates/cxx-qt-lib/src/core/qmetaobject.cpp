@@ -0,0 +1,70 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qmetaobject.h"
+
+#include <QtCore/QMetaEnum>
+#include <QtCore/QMetaProperty>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qmetaobjectClassName(const QMetaObject& metaObject)
+{
+  return QString::fromLatin1(metaObject.className());
+}
+
+QString
+qmetaobjectEnumeratorName(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return QString::fromLatin1(metaObject.enumerator(index).name());
+}
+
+QStringList
+qmetaobjectEnumeratorKeys(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  const QMetaEnum enumerator = metaObject.enumerator(index);
+  QStringList keys;
+  for (int i = 0; i < enumerator.keyCount(); ++i) {
+    keys.append(QString::fromLatin1(enumerator.key(i)));
+  }
+  return keys;
+}
+
+QByteArray
+qmetaobjectMethodSignature(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return metaObject.method(index).methodSignature();
+}
+
+QMetaMethodMethodType
+qmetaobjectMethodType(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return metaObject.method(index).methodType();
+}
+
+QString
+qmetaobjectPropertyName(const QMetaObject& metaObject, ::std::int32_t index)
+{
+  return QString::fromLatin1(metaObject.property(index).name());
+}
+
+QString
+qmetaobjectPropertyTypeName(const QMetaObject& metaObject,
+                            ::std::int32_t index)
+{
+  return QString::fromLatin1(metaObject.property(index).typeName());
+}
+
+const QMetaObject*
+qmetaobjectSuperClass(const QMetaObject& metaObject)
+{
+  return metaObject.superClass();
+}
+
+}
+}
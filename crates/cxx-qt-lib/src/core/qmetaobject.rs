@@ -0,0 +1,168 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QByteArray, QString, QStringList};
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the type of a method of a QMetaObject.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QMetaMethodMethodType {
+        /// The function is a plain member function.
+        Method,
+        /// The function is a signal.
+        Signal,
+        /// The function is a slot.
+        Slot,
+        /// The function is a constructor.
+        Constructor,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+
+        include!("cxx-qt-lib/qmetaobject.h");
+        /// The QMetaObject class contains meta-information about Qt objects.
+        ///
+        /// This is an opaque type which is only available by reference,
+        /// for example from [QObjectExt::meta_object](crate::QObjectExt::meta_object).
+        type QMetaObject;
+
+        /// Returns the number of enumerators in this class, including those of its base classes.
+        #[rust_name = "enumerator_count"]
+        fn enumeratorCount(self: &QMetaObject) -> i32;
+
+        /// Returns the index of the first enumerator of this class,
+        /// the enumerators before it are declared by its base classes.
+        #[rust_name = "enumerator_offset"]
+        fn enumeratorOffset(self: &QMetaObject) -> i32;
+
+        /// Returns the number of methods in this class, including those of its base classes.
+        /// This includes signals and slots as well as invokable member functions.
+        #[rust_name = "method_count"]
+        fn methodCount(self: &QMetaObject) -> i32;
+
+        /// Returns the index of the first method of this class,
+        /// the methods before it are declared by its base classes.
+        #[rust_name = "method_offset"]
+        fn methodOffset(self: &QMetaObject) -> i32;
+
+        /// Returns the number of properties in this class, including those of its base classes.
+        #[rust_name = "property_count"]
+        fn propertyCount(self: &QMetaObject) -> i32;
+
+        /// Returns the index of the first property of this class,
+        /// the properties before it are declared by its base classes.
+        #[rust_name = "property_offset"]
+        fn propertyOffset(self: &QMetaObject) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QMetaMethodMethodType;
+
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_class_name"]
+        fn qmetaobjectClassName(meta_object: &QMetaObject) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_enumerator_keys"]
+        fn qmetaobjectEnumeratorKeys(meta_object: &QMetaObject, index: i32) -> QStringList;
+
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_enumerator_name"]
+        fn qmetaobjectEnumeratorName(meta_object: &QMetaObject, index: i32) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_method_signature"]
+        fn qmetaobjectMethodSignature(meta_object: &QMetaObject, index: i32) -> QByteArray;
+
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_method_type"]
+        fn qmetaobjectMethodType(meta_object: &QMetaObject, index: i32) -> QMetaMethodMethodType;
+
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_property_name"]
+        fn qmetaobjectPropertyName(meta_object: &QMetaObject, index: i32) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_property_type_name"]
+        fn qmetaobjectPropertyTypeName(meta_object: &QMetaObject, index: i32) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "qmetaobject_super_class"]
+        fn qmetaobjectSuperClass(meta_object: &QMetaObject) -> *const QMetaObject;
+    }
+}
+
+pub use ffi::{QMetaMethodMethodType, QMetaObject};
+
+impl QMetaObject {
+    /// Returns the name of the class.
+    pub fn class_name(&self) -> QString {
+        ffi::qmetaobject_class_name(self)
+    }
+
+    /// Returns the keys of the enumerator with the given index,
+    /// or None if the index is out of range.
+    pub fn enumerator_keys(&self, index: i32) -> Option<QStringList> {
+        (0..self.enumerator_count())
+            .contains(&index)
+            .then(|| ffi::qmetaobject_enumerator_keys(self, index))
+    }
+
+    /// Returns the name of the enumerator with the given index,
+    /// or None if the index is out of range.
+    pub fn enumerator_name(&self, index: i32) -> Option<QString> {
+        (0..self.enumerator_count())
+            .contains(&index)
+            .then(|| ffi::qmetaobject_enumerator_name(self, index))
+    }
+
+    /// Returns the signature of the method with the given index, for example `setValue(int)`,
+    /// or None if the index is out of range.
+    pub fn method_signature(&self, index: i32) -> Option<QByteArray> {
+        (0..self.method_count())
+            .contains(&index)
+            .then(|| ffi::qmetaobject_method_signature(self, index))
+    }
+
+    /// Returns the type of the method with the given index, such as a signal or slot,
+    /// or None if the index is out of range.
+    pub fn method_type(&self, index: i32) -> Option<QMetaMethodMethodType> {
+        (0..self.method_count())
+            .contains(&index)
+            .then(|| ffi::qmetaobject_method_type(self, index))
+    }
+
+    /// Returns the name of the property with the given index,
+    /// or None if the index is out of range.
+    pub fn property_name(&self, index: i32) -> Option<QString> {
+        (0..self.property_count())
+            .contains(&index)
+            .then(|| ffi::qmetaobject_property_name(self, index))
+    }
+
+    /// Returns the name of the type of the property with the given index,
+    /// or None if the index is out of range.
+    pub fn property_type_name(&self, index: i32) -> Option<QString> {
+        (0..self.property_count())
+            .contains(&index)
+            .then(|| ffi::qmetaobject_property_type_name(self, index))
+    }
+
+    /// Returns the meta-object of the base class, or None if there is no such object.
+    pub fn super_class(&self) -> Option<&QMetaObject> {
+        // SAFETY: meta-objects are static so the pointer is either null or valid
+        unsafe { ffi::qmetaobject_super_class(self).as_ref() }
+    }
+}
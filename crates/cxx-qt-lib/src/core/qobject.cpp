@@ -0,0 +1,197 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qobject.h"
+
+#include <QtCore/QMetaMethod>
+#include <QtCore/QMetaObject>
+#include <QtCore/QThread>
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QObject*
+qobjectChildAt(const QObject& object, ::rust::isize index)
+{
+  Q_ASSERT(index >= 0);
+  // Qt 5 has an int Qt 6 has a qsizetype
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return object.children().at(static_cast<qsizetype>(index));
+#else
+  return object.children().at(static_cast<int>(index));
+#endif
+}
+
+::rust::isize
+qobjectChildrenLen(const QObject& object)
+{
+  return static_cast<::rust::isize>(object.children().size());
+}
+
+void
+qobjectDeleteLater(QObject& object)
+{
+  object.deleteLater();
+}
+
+QList<QByteArray>
+qobjectDynamicPropertyNames(const QObject& object)
+{
+  return object.dynamicPropertyNames();
+}
+
+bool
+qobjectInvokeMethod(QObject& object,
+                    const QByteArray& name,
+                    Qt::ConnectionType connectionType,
+                    const QList<QVariant>& args,
+                    QVariant& returnValue)
+{
+  // QMetaMethod::invoke supports at most ten arguments
+  constexpr int maxArguments = 10;
+  const int argumentCount = static_cast<int>(args.size());
+  if (argumentCount > maxArguments) {
+    return false;
+  }
+
+  // Resolve the connection type which will be used, as QMetaMethod::invoke does,
+  // so that a return value is only requested when the member is not queued
+  const bool sameThread = object.thread() == QThread::currentThread();
+  if (connectionType == Qt::AutoConnection) {
+    connectionType = sameThread ? Qt::DirectConnection : Qt::QueuedConnection;
+  } else if (connectionType == Qt::BlockingQueuedConnection && sameThread) {
+    // Blocking on the thread which has to invoke the member would deadlock
+    return false;
+  }
+
+  const QMetaObject* metaObject = object.metaObject();
+  // Search the most derived class first, as QMetaObject::invokeMethod does
+  for (int index = metaObject->methodCount() - 1; index >= 0; --index) {
+    const QMetaMethod method = metaObject->method(index);
+    if (method.name() != name || method.parameterCount() != argumentCount ||
+        method.returnType() == QMetaType::UnknownType) {
+      continue;
+    }
+
+    // Convert the arguments to the parameter types of the method
+    QList<QVariant> converted;
+    bool convertible = true;
+    for (int i = 0; i < argumentCount && convertible; ++i) {
+      const int parameterType = method.parameterType(i);
+      QVariant argument = args.at(i);
+      if (parameterType != QMetaType::QVariant) {
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+        convertible = argument.convert(QMetaType(parameterType));
+#else
+        convertible = argument.convert(parameterType);
+#endif
+      }
+      converted.append(argument);
+    }
+    if (!convertible) {
+      continue;
+    }
+
+    const QList<QByteArray> parameterTypes = method.parameterTypes();
+    QGenericArgument arguments[maxArguments];
+    for (int i = 0; i < argumentCount; ++i) {
+      const QVariant& argument = converted.at(i);
+      // A QVariant parameter is passed the variant itself rather than its value
+      const void* data = method.parameterType(i) == QMetaType::QVariant
+                           ? static_cast<const void*>(&argument)
+                           : argument.constData();
+      arguments[i] = QGenericArgument(parameterTypes.at(i).constData(), data);
+    }
+
+    // Return values are not available for queued connections, as the member is
+    // invoked after this function returns
+    QVariant result;
+    QGenericReturnArgument returnArgument;
+    const int returnType = method.returnType();
+    if (connectionType != Qt::QueuedConnection &&
+        returnType != QMetaType::Void) {
+      if (returnType == QMetaType::QVariant) {
+        returnArgument = QGenericReturnArgument(method.typeName(), &result);
+      } else {
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+        result = QVariant(QMetaType(returnType));
+#else
+        result = QVariant(returnType, nullptr);
+#endif
+        returnArgument =
+          QGenericReturnArgument(method.typeName(), result.data());
+      }
+    }
+
+    if (!method.invoke(&object,
+                       connectionType,
+                       returnArgument,
+                       arguments[0],
+                       arguments[1],
+                       arguments[2],
+                       arguments[3],
+                       arguments[4],
+                       arguments[5],
+                       arguments[6],
+                       arguments[7],
+                       arguments[8],
+                       arguments[9])) {
+      return false;
+    }
+
+    returnValue = result;
+    return true;
+  }
+
+  return false;
+}
+
+const QMetaObject&
+qobjectMetaObject(const QObject& object)
+{
+  return *object.metaObject();
+}
+
+QString
+qobjectObjectName(const QObject& object)
+{
+  return object.objectName();
+}
+
+QObject*
+qobjectParent(const QObject& object)
+{
+  return object.parent();
+}
+
+QVariant
+qobjectProperty(const QObject& object, const QByteArray& name)
+{
+  return object.property(name.constData());
+}
+
+void
+qobjectSetObjectName(QObject& object, const QString& name)
+{
+  object.setObjectName(name);
+}
+
+void
+qobjectSetParent(QObject& object, QObject* parent)
+{
+  object.setParent(parent);
+}
+
+bool
+qobjectSetProperty(QObject& object,
+                   const QByteArray& name,
+                   const QVariant& value)
+{
+  return object.setProperty(name.constData(), value);
+}
+
+}
+}
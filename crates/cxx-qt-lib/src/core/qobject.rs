@@ -0,0 +1,313 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QByteArray, QList, QMetaObject, QString, QVariant};
+use core::pin::Pin;
+use cxx_qt::{ConnectionType, QMetaObjectConnectionGuard, QObject, Upcast};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QByteArray = crate::QList<crate::QByteArray>;
+        type QList_QVariant = crate::QList<crate::QVariant>;
+        include!("cxx-qt-lib/qmetaobject.h");
+        type QMetaObject = crate::QMetaObject;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt::QObject;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+        include!("cxx-qt/connection.h");
+        #[namespace = "rust::cxxqt1"]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+    }
+
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        type ConnectionType = cxx_qt::ConnectionType;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qobject_child_at"]
+        fn qobjectChildAt(object: &QObject, index: isize) -> *mut QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_children_len"]
+        fn qobjectChildrenLen(object: &QObject) -> isize;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_delete_later"]
+        fn qobjectDeleteLater(object: Pin<&mut QObject>);
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_dynamic_property_names"]
+        fn qobjectDynamicPropertyNames(object: &QObject) -> QList_QByteArray;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_invoke_method"]
+        fn qobjectInvokeMethod(
+            object: Pin<&mut QObject>,
+            name: &QByteArray,
+            connection_type: ConnectionType,
+            args: &QList_QVariant,
+            return_value: &mut QVariant,
+        ) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_meta_object"]
+        fn qobjectMetaObject(object: &QObject) -> &QMetaObject;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_object_name"]
+        fn qobjectObjectName(object: &QObject) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_on_destroyed"]
+        fn qobjectOnDestroyed(
+            object: Pin<&mut QObject>,
+            func: fn(&mut QObjectDestroyedHandler, &QObject),
+            arg: Box<QObjectDestroyedHandler>,
+        ) -> QMetaObjectConnection;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_parent"]
+        fn qobjectParent(object: &QObject) -> *mut QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_property"]
+        fn qobjectProperty(object: &QObject, name: &QByteArray) -> QVariant;
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_set_object_name"]
+        fn qobjectSetObjectName(object: Pin<&mut QObject>, name: &QString);
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_set_parent"]
+        unsafe fn qobjectSetParent(object: Pin<&mut QObject>, parent: *mut QObject);
+
+        #[doc(hidden)]
+        #[rust_name = "qobject_set_property"]
+        fn qobjectSetProperty(
+            object: Pin<&mut QObject>,
+            name: &QByteArray,
+            value: &QVariant,
+        ) -> bool;
+    }
+
+    extern "Rust" {
+        #[namespace = "rust::cxxqtlib1"]
+        type QObjectDestroyedHandler;
+    }
+}
+
+/// Methods of [QObject] which are available on [QObject] itself
+/// and on any type which can be upcast to it, such as the QObjects generated by CXX-Qt.
+pub trait QObjectExt: Upcast<QObject> {
+    /// Returns a list of child objects.
+    fn children(&self) -> Vec<*mut QObject> {
+        let object = self.upcast();
+        (0..ffi::qobject_children_len(object))
+            .map(|index| ffi::qobject_child_at(object, index))
+            .collect()
+    }
+
+    /// Schedules this object for deletion.
+    ///
+    /// The object will be deleted when control returns to the event loop.
+    ///
+    /// # Safety
+    ///
+    /// The object must be owned by Qt, for example by its parent or by QML,
+    /// and not by Rust, for example in a `cxx::UniquePtr`, otherwise it is deleted twice.
+    /// The object must not be used once it has been deleted.
+    unsafe fn delete_later(self: Pin<&mut Self>) {
+        ffi::qobject_delete_later(self.upcast_pin());
+    }
+
+    /// Returns the names of all properties which were dynamically added to the object using
+    /// [set_property](QObjectExt::set_property).
+    fn dynamic_property_names(&self) -> QList<QByteArray> {
+        ffi::qobject_dynamic_property_names(self.upcast())
+    }
+
+    /// Returns the child of this object which can be cast to T and has the given name,
+    /// or None if there is no such object. An empty name matches all objects.
+    ///
+    /// As with `QObject::findChild` the search is recursive,
+    /// with the direct children being checked before their descendants.
+    fn find_child<T>(&self, name: &QString) -> Option<*mut T>
+    where
+        T: Upcast<QObject>,
+    {
+        let children = self.children();
+        children
+            .iter()
+            .find_map(|child| cast_child::<T>(*child, name))
+            .or_else(|| {
+                children.iter().find_map(|child| {
+                    // SAFETY: the children of this object are valid while it is borrowed
+                    unsafe { &**child }.find_child::<T>(name)
+                })
+            })
+    }
+
+    /// Returns all of the children of this object which can be cast to T and have the given name.
+    /// An empty name matches all objects.
+    ///
+    /// As with `QObject::findChildren` the search is recursive.
+    fn find_children<T>(&self, name: &QString) -> Vec<*mut T>
+    where
+        T: Upcast<QObject>,
+    {
+        let mut found = vec![];
+        for child in self.children() {
+            found.extend(cast_child::<T>(child, name));
+            // SAFETY: the children of this object are valid while it is borrowed
+            found.extend(unsafe { &*child }.find_children::<T>(name));
+        }
+        found
+    }
+
+    /// Invokes the member called name, which may be a signal, slot or invokable method,
+    /// on this object with the given arguments.
+    ///
+    /// The member is found by its name and number of parameters, and the arguments are converted
+    /// to the types of its parameters. The return value of the member is returned,
+    /// which is an invalid QVariant when the member does not return a value
+    /// or is queued, which is the case for a `QueuedConnection` or for an `AutoConnection`
+    /// when this object lives in another thread. A `BlockingQueuedConnection` waits for the
+    /// thread of this object to invoke the member and returns its value.
+    /// None is returned if no matching member was found or it could not be invoked,
+    /// such as with a `BlockingQueuedConnection` when this object lives in the current thread.
+    ///
+    /// At most ten arguments are supported, as with `QMetaObject::invokeMethod`.
+    fn invoke_method(
+        self: Pin<&mut Self>,
+        name: &QByteArray,
+        connection_type: ConnectionType,
+        args: &QList<QVariant>,
+    ) -> Option<QVariant> {
+        let mut return_value = QVariant::default();
+        if ffi::qobject_invoke_method(
+            self.upcast_pin(),
+            name,
+            connection_type,
+            args,
+            &mut return_value,
+        ) {
+            Some(return_value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the meta-object of this object,
+    /// which describes the class name, properties, methods and enumerators of its most derived class.
+    fn meta_object(&self) -> &QMetaObject {
+        ffi::qobject_meta_object(self.upcast())
+    }
+
+    /// Returns the name of this object.
+    fn object_name(&self) -> QString {
+        ffi::qobject_object_name(self.upcast())
+    }
+
+    /// Connect a closure to the destroyed signal of this object,
+    /// which is emitted immediately before the object is destroyed.
+    ///
+    /// The closure is given the object which is being destroyed, at this point
+    /// only the QObject part of it is still valid, so it must not be cast to a derived type.
+    /// The connection is disconnected when the returned guard is dropped.
+    fn on_destroyed<F>(self: Pin<&mut Self>, closure: F) -> QMetaObjectConnectionGuard
+    where
+        F: FnMut(&QObject) + 'static,
+    {
+        fn func(handler: &mut QObjectDestroyedHandler, object: &QObject) {
+            (handler.inner)(object)
+        }
+        let arg = QObjectDestroyedHandler {
+            inner: Box::new(closure),
+        };
+        QMetaObjectConnectionGuard::from(ffi::qobject_on_destroyed(
+            self.upcast_pin(),
+            func,
+            Box::new(arg),
+        ))
+    }
+
+    /// Returns a pointer to the parent object, or a null pointer if there is no parent.
+    fn parent(&self) -> *mut QObject {
+        ffi::qobject_parent(self.upcast())
+    }
+
+    /// Returns the value of the object's name property.
+    ///
+    /// If no such property exists, the returned variant is invalid.
+    /// Information about all available properties is provided through the meta-object
+    /// and [dynamic_property_names](QObjectExt::dynamic_property_names).
+    fn property(&self, name: &QByteArray) -> QVariant {
+        ffi::qobject_property(self.upcast(), name)
+    }
+
+    /// Sets the name of this object.
+    fn set_object_name(self: Pin<&mut Self>, name: &QString) {
+        ffi::qobject_set_object_name(self.upcast_pin(), name);
+    }
+
+    /// Makes the object a child of parent, the parent may be a null pointer.
+    ///
+    /// # Safety
+    ///
+    /// The parent must be a valid pointer or null. The parent takes ownership of this object
+    /// and deletes it when the parent is deleted, so this object must not be owned by Rust.
+    unsafe fn set_parent(self: Pin<&mut Self>, parent: *mut QObject) {
+        ffi::qobject_set_parent(self.upcast_pin(), parent);
+    }
+
+    /// Sets the value of the object's name property to value.
+    ///
+    /// If the property is defined in the class using Q_PROPERTY then true is returned on success
+    /// and false otherwise. If the property is not defined using Q_PROPERTY,
+    /// and therefore not listed in the meta-object, it is added as a dynamic property and false is returned.
+    /// Passing an invalid QVariant removes a dynamic property.
+    fn set_property(self: Pin<&mut Self>, name: &QByteArray, value: &QVariant) -> bool {
+        ffi::qobject_set_property(self.upcast_pin(), name, value)
+    }
+}
+
+impl<T> QObjectExt for T where T: Upcast<QObject> {}
+
+#[doc(hidden)]
+pub struct QObjectDestroyedHandler {
+    inner: DestroyedFn,
+}
+
+type DestroyedFn = Box<dyn FnMut(&QObject)>;
+
+/// Cast the child to T if it has the given name, an empty name matches all objects
+fn cast_child<T>(child: *mut QObject, name: &QString) -> Option<*mut T>
+where
+    T: Upcast<QObject>,
+{
+    // SAFETY: the children of an object are valid while it is borrowed
+    let child = unsafe { &*child };
+    if !name.is_empty() && child.object_name() != *name {
+        return None;
+    }
+
+    // SAFETY: the pointer is a valid QObject
+    let cast = unsafe { T::from_base_ptr(child) };
+    if cast.is_null() {
+        None
+    } else {
+        Some(cast.cast_mut())
+    }
+}
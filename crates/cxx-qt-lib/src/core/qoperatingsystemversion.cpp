@@ -0,0 +1,44 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qoperatingsystemversion.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+#include <cstdint>
+
+// QOperatingSystemVersion has an OSType member and "int" members - major,
+// minor, micro
+// Rust represents these as 32-bit integer types.
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/global/qoperatingsystemversion.h?h=v5.15.6-lts-lgpl#n133
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/global/qoperatingsystemversion.h?h=v6.2.4#n130
+assert_alignment_and_size(QOperatingSystemVersion, {
+  ::std::int32_t a0;
+  ::std::int32_t a1;
+  ::std::int32_t a2;
+  ::std::int32_t a3;
+});
+
+static_assert(::std::is_trivially_copyable<QOperatingSystemVersion>::value);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QOperatingSystemVersion
+qoperatingsystemversionCurrent()
+{
+  return QOperatingSystemVersion::current();
+}
+
+QOperatingSystemVersionOSType
+qoperatingsystemversionCurrentType()
+{
+  return QOperatingSystemVersion::currentType();
+}
+
+}
+}
@@ -0,0 +1,183 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use crate::QVersionNumber;
+use cxx::{type_id, ExternType};
+use std::cmp::Ordering;
+use std::fmt;
+
+#[cxx::bridge]
+mod ffi {
+    /// The operating system types which can be detected at runtime.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QOperatingSystemVersionOSType {
+        /// An unknown or unsupported operating system.
+        Unknown = 0,
+        /// The Microsoft Windows operating system.
+        Windows = 1,
+        /// The Apple macOS operating system.
+        MacOS = 2,
+        /// The Apple iOS operating system.
+        IOS = 3,
+        /// The Apple tvOS operating system.
+        TvOS = 4,
+        /// The Apple watchOS operating system.
+        WatchOS = 5,
+        /// The Google Android operating system.
+        Android = 6,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qoperatingsystemversion.h");
+        type QOperatingSystemVersion = super::QOperatingSystemVersion;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// Returns the major version number, that is, the first segment of the operating system's version number.
+        ///
+        /// -1 indicates an unknown or absent version number component.
+        #[rust_name = "major_version"]
+        fn majorVersion(self: &QOperatingSystemVersion) -> i32;
+
+        /// Returns the micro version number, that is, the third segment of the operating system's version number.
+        ///
+        /// -1 indicates an unknown or absent version number component.
+        #[rust_name = "micro_version"]
+        fn microVersion(self: &QOperatingSystemVersion) -> i32;
+
+        /// Returns the minor version number, that is, the second segment of the operating system's version number.
+        ///
+        /// -1 indicates an unknown or absent version number component.
+        #[rust_name = "minor_version"]
+        fn minorVersion(self: &QOperatingSystemVersion) -> i32;
+
+        /// Returns a string representation of the operating system type, such as "Windows" or "macOS".
+        fn name(self: &QOperatingSystemVersion) -> QString;
+
+        /// Returns the OS type identified by the QOperatingSystemVersion.
+        #[cxx_name = "type"]
+        fn os_type(self: &QOperatingSystemVersion) -> QOperatingSystemVersionOSType;
+
+        /// Returns the number of integers stored in the version number.
+        #[rust_name = "segment_count"]
+        fn segmentCount(self: &QOperatingSystemVersion) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QOperatingSystemVersionOSType;
+
+        #[doc(hidden)]
+        #[rust_name = "qoperatingsystemversion_current"]
+        fn qoperatingsystemversionCurrent() -> QOperatingSystemVersion;
+        #[doc(hidden)]
+        #[rust_name = "qoperatingsystemversion_current_type"]
+        fn qoperatingsystemversionCurrentType() -> QOperatingSystemVersionOSType;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qoperatingsystemversion_init"]
+        fn construct(
+            os_type: QOperatingSystemVersionOSType,
+            major: i32,
+            minor: i32,
+            micro: i32,
+        ) -> QOperatingSystemVersion;
+
+        #[doc(hidden)]
+        #[rust_name = "qoperatingsystemversion_to_qstring"]
+        fn toQString(value: &QOperatingSystemVersion) -> QString;
+    }
+}
+
+pub use ffi::QOperatingSystemVersionOSType;
+
+/// The QOperatingSystemVersion struct provides information about the operating system version.
+///
+/// Versions of the same operating system type can be compared, which allows for runtime checks
+/// that match the ones made on the C++ side, for example `QOperatingSystemVersion::current() >= version`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct QOperatingSystemVersion {
+    os_type: i32,
+    major: i32,
+    minor: i32,
+    micro: i32,
+}
+
+impl QOperatingSystemVersion {
+    /// Returns a QOperatingSystemVersion indicating the current OS and its version number.
+    pub fn current() -> Self {
+        ffi::qoperatingsystemversion_current()
+    }
+
+    /// Returns the current OS type without constructing a QOperatingSystemVersion instance.
+    pub fn current_type() -> QOperatingSystemVersionOSType {
+        ffi::qoperatingsystemversion_current_type()
+    }
+
+    /// Constructs a QOperatingSystemVersion consisting of the OS type os_type,
+    /// and major, minor, and micro version numbers.
+    ///
+    /// Use -1 for the minor or micro version numbers if they are not known.
+    pub fn new(os_type: QOperatingSystemVersionOSType, major: i32, minor: i32, micro: i32) -> Self {
+        ffi::qoperatingsystemversion_init(os_type, major, minor, micro)
+    }
+
+    /// Returns the operating system's version number as a QVersionNumber,
+    /// containing only the segments which are known.
+    pub fn version(&self) -> QVersionNumber {
+        let segments = [
+            self.major_version(),
+            self.minor_version(),
+            self.micro_version(),
+        ];
+        let count = (self.segment_count().max(0) as usize).min(segments.len());
+        QVersionNumber::from(&segments[..count])
+    }
+}
+
+impl PartialOrd for QOperatingSystemVersion {
+    /// Compares the version numbers of operating systems of the same type.
+    ///
+    /// As in Qt, versions of different operating system types are not comparable.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.os_type() != other.os_type() {
+            return None;
+        }
+
+        Some(
+            (
+                self.major_version(),
+                self.minor_version(),
+                self.micro_version(),
+            )
+                .cmp(&(
+                    other.major_version(),
+                    other.minor_version(),
+                    other.micro_version(),
+                )),
+        )
+    }
+}
+
+impl fmt::Display for QOperatingSystemVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qoperatingsystemversion_to_qstring(self))
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side ensure that QOperatingSystemVersion is trivial.
+unsafe impl ExternType for QOperatingSystemVersion {
+    type Id = type_id!("QOperatingSystemVersion");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,27 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qsocketnotifier.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QSocketNotifier>
+qsocketnotifierNew(::rust::isize socket, QSocketNotifierType type)
+{
+  return ::std::make_unique<QSocketNotifier>(static_cast<qintptr>(socket),
+                                             type);
+}
+
+::rust::isize
+qsocketnotifierSocket(const QSocketNotifier& notifier)
+{
+  return static_cast<::rust::isize>(notifier.socket());
+}
+
+}
+}
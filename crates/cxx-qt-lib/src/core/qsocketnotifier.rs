@@ -0,0 +1,136 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// This enum describes the various types of events that a [QSocketNotifier] can recognize.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QSocketNotifierType {
+        /// There is data to be read.
+        Read,
+        /// Data can be written.
+        Write,
+        /// An exception has occurred.
+        Exception,
+    }
+
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qsocketnotifier.h");
+        /// The QSocketNotifier class provides support for monitoring activity on a file descriptor.
+        ///
+        /// This allows for file descriptors owned by Rust, such as unix sockets, inotify or timerfd descriptors,
+        /// to be monitored by the Qt event loop without a separate thread.
+        #[qobject]
+        type QSocketNotifier;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt/connection.h");
+        #[namespace = "rust::cxxqt1"]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+
+        /// Returns true if the notifier is enabled; otherwise returns false.
+        #[rust_name = "is_enabled"]
+        fn isEnabled(self: &QSocketNotifier) -> bool;
+
+        /// If enable is true, the notifier is enabled; otherwise the notifier is disabled.
+        ///
+        /// When the notifier is enabled, the activated closures are called whenever a socket event
+        /// corresponding to its type occurs. When it is disabled, they are not.
+        #[rust_name = "set_enabled"]
+        fn setEnabled(self: Pin<&mut QSocketNotifier>, enable: bool);
+
+        /// Returns the socket event type specified to the constructor.
+        #[cxx_name = "type"]
+        fn notifier_type(self: &QSocketNotifier) -> QSocketNotifierType;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QSocketNotifierType;
+
+        #[doc(hidden)]
+        #[rust_name = "qsocketnotifier_new"]
+        fn qsocketnotifierNew(
+            socket: isize,
+            notifier_type: QSocketNotifierType,
+        ) -> UniquePtr<QSocketNotifier>;
+
+        #[doc(hidden)]
+        #[rust_name = "qsocketnotifier_on_activated"]
+        fn qsocketnotifierOnActivated(
+            notifier: Pin<&mut QSocketNotifier>,
+            func: fn(&mut QSocketNotifierActivatedHandler, Pin<&mut QSocketNotifier>),
+            arg: Box<QSocketNotifierActivatedHandler>,
+        ) -> QMetaObjectConnection;
+
+        #[doc(hidden)]
+        #[rust_name = "qsocketnotifier_socket"]
+        fn qsocketnotifierSocket(notifier: &QSocketNotifier) -> isize;
+    }
+
+    extern "Rust" {
+        #[namespace = "rust::cxxqtlib1"]
+        type QSocketNotifierActivatedHandler;
+    }
+
+    // QSocketNotifier is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QSocketNotifier>::isRelocatable);
+    impl UniquePtr<QSocketNotifier> {}
+}
+
+use core::pin::Pin;
+use cxx_qt::QMetaObjectConnection;
+
+pub use ffi::{QSocketNotifier, QSocketNotifierType};
+
+impl QSocketNotifier {
+    /// Constructs a socket notifier which monitors the given socket or file descriptor for events of the given type.
+    ///
+    /// The notifier is enabled by default. The descriptor is not owned by the notifier,
+    /// so it must stay open until the notifier is destroyed.
+    pub fn new(socket: isize, notifier_type: QSocketNotifierType) -> cxx::UniquePtr<Self> {
+        ffi::qsocketnotifier_new(socket, notifier_type)
+    }
+
+    /// Connect a closure which is called by the Qt event loop when the socket is ready for the type of event of the notifier.
+    ///
+    /// For a [QSocketNotifierType::Read] notifier the closure should read the available data,
+    /// otherwise it is called again immediately.
+    ///
+    /// The returned [QMetaObjectConnection] can be used to disconnect the closure.
+    /// Note that the notifier must not be destroyed from within the closure.
+    pub fn on_activated<F>(self: Pin<&mut Self>, closure: F) -> QMetaObjectConnection
+    where
+        F: FnMut(Pin<&mut QSocketNotifier>) + 'static,
+    {
+        fn func(
+            handler: &mut QSocketNotifierActivatedHandler,
+            notifier: Pin<&mut QSocketNotifier>,
+        ) {
+            (handler.inner)(notifier)
+        }
+        let arg = QSocketNotifierActivatedHandler {
+            inner: Box::new(closure),
+        };
+        ffi::qsocketnotifier_on_activated(self, func, Box::new(arg))
+    }
+
+    /// Returns the socket or file descriptor which is monitored by the notifier.
+    pub fn socket(&self) -> isize {
+        ffi::qsocketnotifier_socket(self)
+    }
+}
+
+#[doc(hidden)]
+pub struct QSocketNotifierActivatedHandler {
+    inner: ActivatedFn,
+}
+
+type ActivatedFn = Box<dyn FnMut(Pin<&mut QSocketNotifier>)>;
@@ -0,0 +1,74 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qstringconverter.h"
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qstringdecoderDecode(QStringDecoder& decoder,
+                     ::rust::Slice<const ::std::uint8_t> data)
+{
+  // The decoded data refers to the raw data, so convert to a QString
+  // before the raw data goes out of scope
+  const QString string = decoder(
+    QByteArray::fromRawData(reinterpret_cast<const char*>(data.data()),
+                            static_cast<qsizetype>(data.size())));
+  return string;
+}
+
+::std::unique_ptr<QStringDecoder>
+qstringdecoderFromName(::rust::Str name)
+{
+  const auto nameBytes = QByteArray(name.data(), name.size());
+  return ::std::make_unique<QStringDecoder>(nameBytes.constData());
+}
+
+QString
+qstringdecoderName(const QStringDecoder& decoder)
+{
+  return QString::fromLatin1(decoder.name());
+}
+
+::std::unique_ptr<QStringDecoder>
+qstringdecoderNew(QStringConverterEncoding encoding)
+{
+  return ::std::make_unique<QStringDecoder>(encoding);
+}
+
+QByteArray
+qstringencoderEncode(QStringEncoder& encoder, const QString& string)
+{
+  const QByteArray bytes = encoder(string);
+  return bytes;
+}
+
+::std::unique_ptr<QStringEncoder>
+qstringencoderFromName(::rust::Str name)
+{
+  const auto nameBytes = QByteArray(name.data(), name.size());
+  return ::std::make_unique<QStringEncoder>(nameBytes.constData());
+}
+
+QString
+qstringencoderName(const QStringEncoder& encoder)
+{
+  return QString::fromLatin1(encoder.name());
+}
+
+::std::unique_ptr<QStringEncoder>
+qstringencoderNew(QStringConverterEncoding encoding)
+{
+  return ::std::make_unique<QStringEncoder>(encoding);
+}
+
+}
+}
+
+#endif
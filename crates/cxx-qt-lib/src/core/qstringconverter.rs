@@ -0,0 +1,182 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// QStringDecoder and QStringEncoder are only available in Qt 6,
+// so every item of the bridge is behind a cfg for the C++ generation.
+#[cxx::bridge]
+mod ffi {
+    /// The encodings which are built into Qt.
+    #[cfg(cxxqt_qt_version_at_least_6)]
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QStringConverterEncoding {
+        /// Create a converter to or from UTF-8.
+        Utf8 = 0,
+        /// Create a converter to or from UTF-16. When decoding, the byte order will get automatically
+        /// detected by a leading byte order mark. If none exists or when encoding, the system byte order will be assumed.
+        Utf16 = 1,
+        /// Create a converter to or from little-endian UTF-16.
+        Utf16LE = 2,
+        /// Create a converter to or from big-endian UTF-16.
+        Utf16BE = 3,
+        /// Create a converter to or from UTF-32. When decoding, the byte order will get automatically
+        /// detected by a leading byte order mark. If none exists or when encoding, the system byte order will be assumed.
+        Utf32 = 4,
+        /// Create a converter to or from little-endian UTF-32.
+        Utf32LE = 5,
+        /// Create a converter to or from big-endian UTF-32.
+        Utf32BE = 6,
+        /// Create a converter to or from ISO-8859-1 (Latin1).
+        Latin1 = 7,
+        /// Create a converter to or from the underlying encoding of the operating systems locale.
+        System = 8,
+    }
+
+    #[cfg(cxxqt_qt_version_at_least_6)]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstringconverter.h");
+        /// The QStringDecoder class provides a state-based decoder for text.
+        ///
+        /// A decoder converts text from an encoded format into Qt's internal representation,
+        /// so text in legacy encodings is decoded with the same tables as on the C++ side.
+        type QStringDecoder;
+        /// The QStringEncoder class provides a state-based encoder for text.
+        ///
+        /// An encoder converts text from Qt's internal representation into an encoded text format.
+        type QStringEncoder;
+
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// Returns true if a conversion could not correctly convert a character.
+        /// This could for example get triggered by an invalid UTF-8 sequence or when a character can't get converted due to limitations in the target encoding.
+        #[rust_name = "has_error"]
+        fn hasError(self: &QStringDecoder) -> bool;
+
+        /// Returns true if this is a valid string converter that can be used for encoding or decoding text.
+        ///
+        /// Default constructed string converters or converters constructed with an unsupported name are not valid.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QStringDecoder) -> bool;
+
+        /// Resets the internal state of the converter, clearing potential errors or partial conversions.
+        #[rust_name = "reset_state"]
+        fn resetState(self: Pin<&mut QStringDecoder>);
+
+        /// Returns true if a conversion could not correctly convert a character.
+        /// This could for example get triggered by an invalid UTF-8 sequence or when a character can't get converted due to limitations in the target encoding.
+        #[rust_name = "has_error"]
+        fn hasError(self: &QStringEncoder) -> bool;
+
+        /// Returns true if this is a valid string converter that can be used for encoding or decoding text.
+        ///
+        /// Default constructed string converters or converters constructed with an unsupported name are not valid.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QStringEncoder) -> bool;
+
+        /// Resets the internal state of the converter, clearing potential errors or partial conversions.
+        #[rust_name = "reset_state"]
+        fn resetState(self: Pin<&mut QStringEncoder>);
+    }
+
+    #[cfg(cxxqt_qt_version_at_least_6)]
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QStringConverterEncoding;
+
+        #[doc(hidden)]
+        #[rust_name = "qstringdecoder_decode"]
+        fn qstringdecoderDecode(decoder: Pin<&mut QStringDecoder>, data: &[u8]) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qstringdecoder_from_name"]
+        fn qstringdecoderFromName(name: &str) -> UniquePtr<QStringDecoder>;
+        #[doc(hidden)]
+        #[rust_name = "qstringdecoder_name"]
+        fn qstringdecoderName(decoder: &QStringDecoder) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qstringdecoder_new"]
+        fn qstringdecoderNew(encoding: QStringConverterEncoding) -> UniquePtr<QStringDecoder>;
+
+        #[doc(hidden)]
+        #[rust_name = "qstringencoder_encode"]
+        fn qstringencoderEncode(encoder: Pin<&mut QStringEncoder>, string: &QString) -> QByteArray;
+        #[doc(hidden)]
+        #[rust_name = "qstringencoder_from_name"]
+        fn qstringencoderFromName(name: &str) -> UniquePtr<QStringEncoder>;
+        #[doc(hidden)]
+        #[rust_name = "qstringencoder_name"]
+        fn qstringencoderName(encoder: &QStringEncoder) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qstringencoder_new"]
+        fn qstringencoderNew(encoding: QStringConverterEncoding) -> UniquePtr<QStringEncoder>;
+    }
+
+    // QStringDecoder and QStringEncoder are move only and are not relocatable in Qt,
+    // so we cannot mark them as a trivial type and need to use references or pointers.
+    #[cfg(cxxqt_qt_version_at_least_6)]
+    impl UniquePtr<QStringDecoder> {}
+    #[cfg(cxxqt_qt_version_at_least_6)]
+    impl UniquePtr<QStringEncoder> {}
+}
+
+use crate::{QByteArray, QString};
+use core::pin::Pin;
+use cxx::UniquePtr;
+
+pub use ffi::{QStringConverterEncoding, QStringDecoder, QStringEncoder};
+
+impl QStringDecoder {
+    /// Converts data from the encoding of this decoder into a QString.
+    ///
+    /// The state of the decoder is kept between calls, so data can be decoded in chunks
+    /// and partial characters at the end of a chunk are completed by the next chunk.
+    pub fn decode(self: Pin<&mut Self>, data: &[u8]) -> QString {
+        ffi::qstringdecoder_decode(self, data)
+    }
+
+    /// Creates a decoder for the encoding with the given name, such as "Shift_JIS" or "windows-1252".
+    ///
+    /// If the encoding name is not known, the decoder is not valid, see [QStringDecoder::is_valid].
+    pub fn from_name(name: &str) -> UniquePtr<Self> {
+        ffi::qstringdecoder_from_name(name)
+    }
+
+    /// Returns the canonical name of the encoding this decoder can decode.
+    pub fn name(&self) -> QString {
+        ffi::qstringdecoder_name(self)
+    }
+
+    /// Creates a decoder for the given built-in encoding.
+    pub fn new(encoding: QStringConverterEncoding) -> UniquePtr<Self> {
+        ffi::qstringdecoder_new(encoding)
+    }
+}
+
+impl QStringEncoder {
+    /// Converts string into the encoding of this encoder.
+    pub fn encode(self: Pin<&mut Self>, string: &QString) -> QByteArray {
+        ffi::qstringencoder_encode(self, string)
+    }
+
+    /// Creates an encoder for the encoding with the given name, such as "Shift_JIS" or "windows-1252".
+    ///
+    /// If the encoding name is not known, the encoder is not valid, see [QStringEncoder::is_valid].
+    pub fn from_name(name: &str) -> UniquePtr<Self> {
+        ffi::qstringencoder_from_name(name)
+    }
+
+    /// Returns the canonical name of the encoding this encoder can encode.
+    pub fn name(&self) -> QString {
+        ffi::qstringencoder_name(self)
+    }
+
+    /// Creates an encoder for the given built-in encoding.
+    pub fn new(encoding: QStringConverterEncoding) -> UniquePtr<Self> {
+        ffi::qstringencoder_new(encoding)
+    }
+}
@@ -48,6 +48,44 @@ mod ffi {
         TimeZone,
     }
 
+    /// The timer type indicates how accurate a timer can be.
+    #[repr(i32)]
+    enum TimerType {
+        /// Precise timers try to keep millisecond accuracy.
+        PreciseTimer,
+        /// Coarse timers try to keep accuracy within 5% of the desired interval.
+        CoarseTimer,
+        /// Very coarse timers only keep full second accuracy.
+        VeryCoarseTimer,
+    }
+
+    /// This enum type is used to specify the current state of the application.
+    #[repr(i32)]
+    enum ApplicationState {
+        /// The application is about to suspend. When entering this state, the application should save its state,
+        /// cease all activities, and be prepared for code execution to stop.
+        ApplicationSuspended = 0x00000000,
+        /// The application is hidden and runs in the background.
+        ApplicationHidden = 0x00000001,
+        /// The application is visible, but not selected to be in front.
+        ApplicationInactive = 0x00000002,
+        /// The application is visible and selected to be in front.
+        ApplicationActive = 0x00000004,
+    }
+
+    /// This enum specifies where the ellipsis should appear when displaying texts that don't fit.
+    #[repr(i32)]
+    enum TextElideMode {
+        /// The ellipsis should appear at the beginning of the text.
+        ElideLeft,
+        /// The ellipsis should appear at the end of the text.
+        ElideRight,
+        /// The ellipsis should appear in the middle of the text.
+        ElideMiddle,
+        /// Ellipsis should NOT appear in the text.
+        ElideNone,
+    }
+
     /// This enum type defines whether image transformations (e.g., scaling) should be smooth or not.
     #[repr(i32)]
     enum TransformationMode {
@@ -76,6 +114,50 @@ mod ffi {
         CustomDashLine,
     }
 
+    /// This enum type defines the brush styles supported by Qt,
+    /// i.e. the fill pattern of shapes drawn using QPainter.
+    #[repr(i32)]
+    enum BrushStyle {
+        /// No brush pattern.
+        NoBrush = 0,
+        /// Uniform color.
+        SolidPattern = 1,
+        /// Extremely dense brush pattern.
+        Dense1Pattern = 2,
+        /// Very dense brush pattern.
+        Dense2Pattern = 3,
+        /// Somewhat dense brush pattern.
+        Dense3Pattern = 4,
+        /// Half dense brush pattern.
+        Dense4Pattern = 5,
+        /// Somewhat sparse brush pattern.
+        Dense5Pattern = 6,
+        /// Very sparse brush pattern.
+        Dense6Pattern = 7,
+        /// Extremely sparse brush pattern.
+        Dense7Pattern = 8,
+        /// Horizontal lines.
+        HorPattern = 9,
+        /// Vertical lines.
+        VerPattern = 10,
+        /// Crossing horizontal and vertical lines.
+        CrossPattern = 11,
+        /// Backward diagonal lines.
+        BDiagPattern = 12,
+        /// Forward diagonal lines.
+        FDiagPattern = 13,
+        /// Crossing diagonal lines.
+        DiagCrossPattern = 14,
+        /// Linear gradient.
+        LinearGradientPattern = 15,
+        /// Radial gradient.
+        RadialGradientPattern = 16,
+        /// Conical gradient.
+        ConicalGradientPattern = 17,
+        /// Custom pattern.
+        TexturePattern = 24,
+    }
+
     /// This enum type defines the line endcap style
     #[repr(i32)]
     enum PenCapStyle {
@@ -145,14 +227,264 @@ mod ffi {
         RelativeSize,
     }
 
+    /// This enum type specifies the hints given to the input method of a text control.
+    ///
+    /// The flags can be combined using the `|` operator, for example to set the
+    /// `inputMethodHints` property of a QML text control from a Rust property.
+    #[repr(i32)]
+    enum InputMethodHint {
+        /// No hints.
+        ImhNone = 0x0,
+        /// The input method should not show the characters while typing.
+        ImhHiddenText = 0x1,
+        /// Typed text should not be stored by the active input method in any persistent storage like predictive user dictionary.
+        ImhSensitiveData = 0x2,
+        /// The input method should not try to automatically switch to upper case when a sentence ends.
+        ImhNoAutoUppercase = 0x4,
+        /// Numbers are preferred (but not required).
+        ImhPreferNumbers = 0x8,
+        /// Upper case letters are preferred (but not required).
+        ImhPreferUppercase = 0x10,
+        /// Lower case letters are preferred (but not required).
+        ImhPreferLowercase = 0x20,
+        /// Do not use predictive text (i.e. dictionary lookup) while typing.
+        ImhNoPredictiveText = 0x40,
+        /// The text editor functions as a date field.
+        ImhDate = 0x80,
+        /// The text editor functions as a time field.
+        ImhTime = 0x100,
+        /// Latin characters are preferred (but not required).
+        ImhPreferLatin = 0x200,
+        /// Multiple lines can be entered into the text field.
+        ImhMultiLine = 0x400,
+        /// Do not show the edit menu.
+        ImhNoEditMenu = 0x800,
+        /// Do not show the text handles.
+        ImhNoTextHandles = 0x1000,
+        /// Only digits are allowed.
+        ImhDigitsOnly = 0x10000,
+        /// Only number input is allowed. This includes decimal point and minus sign.
+        ImhFormattedNumbersOnly = 0x20000,
+        /// Only upper case letter input is allowed.
+        ImhUppercaseOnly = 0x40000,
+        /// Only lower case letter input is allowed.
+        ImhLowercaseOnly = 0x80000,
+        /// Only characters suitable for phone dialing are allowed.
+        ImhDialableCharactersOnly = 0x100000,
+        /// Only characters suitable for email addresses are allowed.
+        ImhEmailCharactersOnly = 0x200000,
+        /// Only characters suitable for URLs are allowed.
+        ImhUrlCharactersOnly = 0x400000,
+        /// Only latin based input is allowed.
+        ImhLatinOnly = 0x800000,
+    }
+
+    /// This enum type describes the different mouse buttons.
+    ///
+    /// The flags can be combined using the `|` operator.
+    #[repr(i32)]
+    enum MouseButton {
+        /// The button state does not refer to any button.
+        NoButton = 0x0,
+        /// The left button is pressed, or an event refers to the left button.
+        LeftButton = 0x1,
+        /// The right button.
+        RightButton = 0x2,
+        /// The middle button.
+        MiddleButton = 0x4,
+        /// The 'Back' button. (Typically present on the 'thumb' side of a mouse with extra buttons.)
+        BackButton = 0x8,
+        /// The 'Forward' button. (Typically present beside the 'Back' button, and also pressed by the thumb.)
+        ForwardButton = 0x10,
+        /// The 'Task' button.
+        TaskButton = 0x20,
+    }
+
+    /// This enum describes the modifier keys.
+    ///
+    /// The flags can be combined using the `|` operator.
+    #[repr(i32)]
+    enum KeyboardModifier {
+        /// No modifier key is pressed.
+        NoModifier = 0x00000000,
+        /// A Shift key on the keyboard is pressed.
+        ShiftModifier = 0x02000000,
+        /// A Ctrl key on the keyboard is pressed.
+        ControlModifier = 0x04000000,
+        /// An Alt key on the keyboard is pressed.
+        AltModifier = 0x08000000,
+        /// A Meta key on the keyboard is pressed.
+        MetaModifier = 0x10000000,
+        /// A keypad button is pressed.
+        KeypadModifier = 0x20000000,
+        /// X11 only (unless activated on Windows by a command line argument).
+        /// A Mode_switch key on the keyboard is pressed.
+        GroupSwitchModifier = 0x40000000,
+    }
+
+    /// The key names used by Qt.
+    ///
+    /// Note that only a subset of the keys of Qt are available.
+    #[repr(i32)]
+    enum Key {
+        /// The Escape key.
+        Key_Escape = 0x1000000,
+        /// The Tab key.
+        Key_Tab = 0x1000001,
+        /// The Backtab key, which is Shift+Tab.
+        Key_Backtab = 0x1000002,
+        /// The Backspace key.
+        Key_Backspace = 0x1000003,
+        /// The Return key.
+        Key_Return = 0x1000004,
+        /// The Enter key, typically located on the keypad.
+        Key_Enter = 0x1000005,
+        /// The Insert key.
+        Key_Insert = 0x1000006,
+        /// The Delete key.
+        Key_Delete = 0x1000007,
+        /// The Pause/Break key.
+        Key_Pause = 0x1000008,
+        /// The Print Screen key.
+        Key_Print = 0x1000009,
+        /// The Home key.
+        Key_Home = 0x1000010,
+        /// The End key.
+        Key_End = 0x1000011,
+        /// The Left arrow key.
+        Key_Left = 0x1000012,
+        /// The Up arrow key.
+        Key_Up = 0x1000013,
+        /// The Right arrow key.
+        Key_Right = 0x1000014,
+        /// The Down arrow key.
+        Key_Down = 0x1000015,
+        /// The Page Up key.
+        Key_PageUp = 0x1000016,
+        /// The Page Down key.
+        Key_PageDown = 0x1000017,
+        /// The Shift key.
+        Key_Shift = 0x1000020,
+        /// The Control key, on macOS this is the Command key.
+        Key_Control = 0x1000021,
+        /// The Meta key, on macOS this is the Control key.
+        Key_Meta = 0x1000022,
+        /// The Alt key.
+        Key_Alt = 0x1000023,
+        /// The Caps Lock key.
+        Key_CapsLock = 0x1000024,
+        /// The F1 key.
+        Key_F1 = 0x1000030,
+        /// The F2 key.
+        Key_F2 = 0x1000031,
+        /// The F3 key.
+        Key_F3 = 0x1000032,
+        /// The F4 key.
+        Key_F4 = 0x1000033,
+        /// The F5 key.
+        Key_F5 = 0x1000034,
+        /// The F6 key.
+        Key_F6 = 0x1000035,
+        /// The F7 key.
+        Key_F7 = 0x1000036,
+        /// The F8 key.
+        Key_F8 = 0x1000037,
+        /// The F9 key.
+        Key_F9 = 0x1000038,
+        /// The F10 key.
+        Key_F10 = 0x1000039,
+        /// The F11 key.
+        Key_F11 = 0x100003a,
+        /// The F12 key.
+        Key_F12 = 0x100003b,
+        /// The Space key.
+        Key_Space = 0x20,
+        /// The 0 key.
+        Key_0 = 0x30,
+        /// The 1 key.
+        Key_1 = 0x31,
+        /// The 2 key.
+        Key_2 = 0x32,
+        /// The 3 key.
+        Key_3 = 0x33,
+        /// The 4 key.
+        Key_4 = 0x34,
+        /// The 5 key.
+        Key_5 = 0x35,
+        /// The 6 key.
+        Key_6 = 0x36,
+        /// The 7 key.
+        Key_7 = 0x37,
+        /// The 8 key.
+        Key_8 = 0x38,
+        /// The 9 key.
+        Key_9 = 0x39,
+        /// The A key.
+        Key_A = 0x41,
+        /// The B key.
+        Key_B = 0x42,
+        /// The C key.
+        Key_C = 0x43,
+        /// The D key.
+        Key_D = 0x44,
+        /// The E key.
+        Key_E = 0x45,
+        /// The F key.
+        Key_F = 0x46,
+        /// The G key.
+        Key_G = 0x47,
+        /// The H key.
+        Key_H = 0x48,
+        /// The I key.
+        Key_I = 0x49,
+        /// The J key.
+        Key_J = 0x4a,
+        /// The K key.
+        Key_K = 0x4b,
+        /// The L key.
+        Key_L = 0x4c,
+        /// The M key.
+        Key_M = 0x4d,
+        /// The N key.
+        Key_N = 0x4e,
+        /// The O key.
+        Key_O = 0x4f,
+        /// The P key.
+        Key_P = 0x50,
+        /// The Q key.
+        Key_Q = 0x51,
+        /// The R key.
+        Key_R = 0x52,
+        /// The S key.
+        Key_S = 0x53,
+        /// The T key.
+        Key_T = 0x54,
+        /// The U key.
+        Key_U = 0x55,
+        /// The V key.
+        Key_V = 0x56,
+        /// The W key.
+        Key_W = 0x57,
+        /// The X key.
+        Key_X = 0x58,
+        /// The Y key.
+        Key_Y = 0x59,
+        /// The Z key.
+        Key_Z = 0x5a,
+    }
+
     unsafe extern "C++" {
         include!("cxx-qt-lib/qt.h");
+        type ApplicationState;
         type AspectRatioMode;
         type CaseSensitivity;
         type DateFormat;
         type SplitBehaviorFlags;
         type TimeSpec;
+        type TimerType;
         type TransformationMode;
+        type TextElideMode;
+        type BrushStyle;
         type PenStyle;
         type PenCapStyle;
         type PenJoinStyle;
@@ -161,14 +493,49 @@ mod ffi {
         type BGMode;
         type ClipOperation;
         type SizeMode;
+        type InputMethodHint;
+        type MouseButton;
+        type KeyboardModifier;
+        type Key;
     }
 }
 
 pub use ffi::{
-    AspectRatioMode, BGMode, CaseSensitivity, ClipOperation, DateFormat, FillRule, LayoutDirection,
-    PenCapStyle, PenJoinStyle, PenStyle, SizeMode, SplitBehaviorFlags, TimeSpec,
-    TransformationMode,
+    ApplicationState, AspectRatioMode, BGMode, BrushStyle, CaseSensitivity, ClipOperation,
+    DateFormat, FillRule, InputMethodHint, Key, KeyboardModifier, LayoutDirection, MouseButton,
+    PenCapStyle, PenJoinStyle, PenStyle, SizeMode, SplitBehaviorFlags, TextElideMode, TimeSpec,
+    TimerType, TransformationMode,
 };
 
+impl core::ops::BitOr for InputMethodHint {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self {
+            repr: self.repr | other.repr,
+        }
+    }
+}
+
+impl core::ops::BitOr for MouseButton {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self {
+            repr: self.repr | other.repr,
+        }
+    }
+}
+
+impl core::ops::BitOr for KeyboardModifier {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self {
+            repr: self.repr | other.repr,
+        }
+    }
+}
+
 // Reexport ConnectionType from cxx-qt
 pub use cxx_qt::ConnectionType;
@@ -0,0 +1,20 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qtimer.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QTimer>
+qtimerNew()
+{
+  return ::std::make_unique<QTimer>();
+}
+
+}
+}
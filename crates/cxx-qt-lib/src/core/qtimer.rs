@@ -0,0 +1,104 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod ffi {
+    #[helper_namespace = "cxx_qt_lib"]
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qtimer.h");
+        /// The QTimer class provides repetitive and single-shot timers.
+        ///
+        /// The timer is driven by the Qt event loop of the thread it lives in,
+        /// so closures connected to [QTimer::on_timeout] can safely access other QObjects of that thread.
+        #[qobject]
+        type QTimer;
+
+        /// This signal is emitted when the timer times out.
+        #[qsignal]
+        #[private_signal]
+        fn timeout(self: Pin<&mut QTimer>);
+    }
+
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type TimerType = crate::TimerType;
+    }
+
+    unsafe extern "C++" {
+        /// Returns the timeout interval in milliseconds.
+        fn interval(self: &QTimer) -> i32;
+
+        /// Returns true if the timer is running (pending); otherwise returns false.
+        #[rust_name = "is_active"]
+        fn isActive(self: &QTimer) -> bool;
+
+        /// Returns whether the timer is a single-shot timer.
+        #[rust_name = "is_single_shot"]
+        fn isSingleShot(self: &QTimer) -> bool;
+
+        /// Returns the timer's remaining value in milliseconds left until the timeout.
+        /// If the timer is inactive, the returned value will be -1.
+        /// If the timer is overdue, the returned value will be 0.
+        #[rust_name = "remaining_time"]
+        fn remainingTime(self: &QTimer) -> i32;
+
+        /// Sets the timeout interval in milliseconds.
+        ///
+        /// A timer with an interval of 0 will time out as soon as all the events in the window system's event queue have been processed.
+        #[rust_name = "set_interval"]
+        fn setInterval(self: Pin<&mut QTimer>, msec: i32);
+
+        /// Sets whether the timer is a single-shot timer.
+        ///
+        /// A single-shot timer fires only once, non-single-shot timers fire every interval milliseconds.
+        #[rust_name = "set_single_shot"]
+        fn setSingleShot(self: Pin<&mut QTimer>, single_shot: bool);
+
+        /// Sets the accuracy of the timer.
+        #[rust_name = "set_timer_type"]
+        fn setTimerType(self: Pin<&mut QTimer>, timer_type: TimerType);
+
+        /// Starts or restarts the timer with the timeout specified in interval.
+        ///
+        /// If the timer is already running, it will be stopped and restarted.
+        fn start(self: Pin<&mut QTimer>);
+
+        /// Starts or restarts the timer with a timeout interval of msec milliseconds.
+        ///
+        /// If the timer is already running, it will be stopped and restarted.
+        #[cxx_name = "start"]
+        fn start_with_interval(self: Pin<&mut QTimer>, msec: i32);
+
+        /// Stops the timer.
+        fn stop(self: Pin<&mut QTimer>);
+
+        /// Returns the accuracy of the timer.
+        #[rust_name = "timer_type"]
+        fn timerType(self: &QTimer) -> TimerType;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qtimer_new"]
+        fn qtimerNew() -> UniquePtr<QTimer>;
+    }
+
+    // QTimer is a QObject so is not trivial to CXX and is not relocatable in Qt
+    // as the following fails in C++. So we cannot mark it as a trivial type
+    // and need to use references or pointers.
+    // static_assert(QTypeInfo<QTimer>::isRelocatable);
+    impl UniquePtr<QTimer> {}
+}
+
+pub use ffi::QTimer;
+
+impl QTimer {
+    /// Constructs a timer, which is inactive until it is started.
+    pub fn new() -> cxx::UniquePtr<Self> {
+        ffi::qtimer_new()
+    }
+}
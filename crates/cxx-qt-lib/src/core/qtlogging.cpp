@@ -0,0 +1,159 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qtlogging.h"
+
+#include <QtCore/QByteArray>
+#include <QtCore/QHash>
+#include <QtCore/QLoggingCategory>
+#include <QtCore/QMutex>
+
+namespace {
+
+QMutex&
+messageHandlerMutex()
+{
+  static QMutex mutex;
+  return mutex;
+}
+
+::std::shared_ptr<const ::rust::cxxqtlib1::QtMessageHandlerFunction>&
+messageHandler()
+{
+  static ::std::shared_ptr<const ::rust::cxxqtlib1::QtMessageHandlerFunction>
+    handler;
+  return handler;
+}
+
+void
+messageHandlerTrampoline(QtMsgType type,
+                         const QMessageLogContext& context,
+                         const QString& message)
+{
+  // Take a reference to the handler so that the lock is not held while
+  // calling it, as the handler could log a message itself or be replaced
+  ::std::shared_ptr<const ::rust::cxxqtlib1::QtMessageHandlerFunction> handler;
+  {
+    const QMutexLocker locker(&messageHandlerMutex());
+    handler = messageHandler();
+  }
+
+  if (handler) {
+    (*handler)(type, context, message);
+  }
+}
+
+QLoggingCategory&
+loggingCategory(::rust::Str name)
+{
+  static QMutex mutex;
+  static QHash<QByteArray, QLoggingCategory*> categories;
+
+  const QMutexLocker locker(&mutex);
+  const auto key = QByteArray(name.data(), static_cast<int>(name.size()));
+  auto it = categories.find(key);
+  if (it == categories.end()) {
+    // QLoggingCategory keeps a pointer to its name and registers itself with
+    // the logging rules, so the categories and their names are never freed
+    it = categories.insert(key, nullptr);
+    *it = new QLoggingCategory(it.key().constData());
+  }
+  return **it;
+}
+
+}
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qtInstallMessageHandlerFunction(QtMessageHandlerFunction handler)
+{
+  {
+    const QMutexLocker locker(&messageHandlerMutex());
+    messageHandler() =
+      ::std::make_shared<const QtMessageHandlerFunction>(::std::move(handler));
+  }
+  qInstallMessageHandler(messageHandlerTrampoline);
+}
+
+void
+qtResetMessageHandler()
+{
+  qInstallMessageHandler(nullptr);
+
+  const QMutexLocker locker(&messageHandlerMutex());
+  messageHandler().reset();
+}
+
+QString
+qmessagelogcontextCategory(const QMessageLogContext& context)
+{
+  return QString::fromUtf8(context.category);
+}
+
+QString
+qmessagelogcontextFile(const QMessageLogContext& context)
+{
+  return QString::fromUtf8(context.file);
+}
+
+QString
+qmessagelogcontextFunction(const QMessageLogContext& context)
+{
+  return QString::fromUtf8(context.function);
+}
+
+::std::int32_t
+qmessagelogcontextLine(const QMessageLogContext& context)
+{
+  return static_cast<::std::int32_t>(context.line);
+}
+
+bool
+qtLoggingCategoryIsEnabled(::rust::Str category, QtMsgType type)
+{
+  return loggingCategory(category).isEnabled(type);
+}
+
+void
+qtLogMessage(::rust::Str category,
+             QtMsgType type,
+             ::rust::Str file,
+             ::std::int32_t line,
+             const QString& message)
+{
+  const auto& logCategory = loggingCategory(category);
+  if (!logCategory.isEnabled(type)) {
+    return;
+  }
+
+  const auto fileName = QByteArray(file.data(), static_cast<int>(file.size()));
+  QMessageLogger logger(fileName.constData(),
+                        static_cast<int>(line),
+                        nullptr,
+                        logCategory.categoryName());
+  switch (type) {
+    case QtDebugMsg:
+      logger.debug("%s", qUtf8Printable(message));
+      break;
+    case QtInfoMsg:
+      logger.info("%s", qUtf8Printable(message));
+      break;
+    case QtWarningMsg:
+      logger.warning("%s", qUtf8Printable(message));
+      break;
+    case QtCriticalMsg:
+      logger.critical("%s", qUtf8Printable(message));
+      break;
+    case QtFatalMsg:
+      logger.fatal("%s", qUtf8Printable(message));
+  }
+}
+
+}
+}
@@ -0,0 +1,285 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the messages that can be sent to a message handler.
+    #[repr(i32)]
+    #[derive(Debug)]
+    enum QtMsgType {
+        /// A message generated by the qDebug() function.
+        QtDebugMsg = 0,
+        /// A message generated by the qWarning() function.
+        QtWarningMsg = 1,
+        /// A message generated by the qCritical() function.
+        QtCriticalMsg = 2,
+        /// A message generated by the qFatal() function.
+        QtFatalMsg = 3,
+        /// A message generated by the qInfo() function.
+        QtInfoMsg = 4,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtlogging.h");
+        type QtMsgType;
+
+        /// The QMessageLogContext class provides additional information about a log message.
+        ///
+        /// Note that the file, function and line are only available when `QT_MESSAGELOGCONTEXT` is defined
+        /// for the C++ code which created the message, which is the default in debug builds.
+        type QMessageLogContext;
+
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qt_install_message_handler"]
+        fn qtInstallMessageHandler(
+            func: fn(&QtMessageHandler, QtMsgType, &QMessageLogContext, &QString),
+            arg: Box<QtMessageHandler>,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qt_reset_message_handler"]
+        fn qtResetMessageHandler();
+
+        #[doc(hidden)]
+        #[rust_name = "qmessagelogcontext_category"]
+        fn qmessagelogcontextCategory(context: &QMessageLogContext) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "qmessagelogcontext_file"]
+        fn qmessagelogcontextFile(context: &QMessageLogContext) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "qmessagelogcontext_function"]
+        fn qmessagelogcontextFunction(context: &QMessageLogContext) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "qmessagelogcontext_line"]
+        fn qmessagelogcontextLine(context: &QMessageLogContext) -> i32;
+
+        #[doc(hidden)]
+        #[rust_name = "qt_logging_category_is_enabled"]
+        fn qtLoggingCategoryIsEnabled(category: &str, msg_type: QtMsgType) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qt_log_message"]
+        fn qtLogMessage(
+            category: &str,
+            msg_type: QtMsgType,
+            file: &str,
+            line: i32,
+            message: &QString,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    extern "Rust" {
+        type QtMessageHandler;
+    }
+}
+
+use crate::QString;
+
+pub use ffi::{QMessageLogContext, QtMsgType};
+
+impl QMessageLogContext {
+    /// Returns the name of the logging category of the message, which is `default` for messages without a category.
+    pub fn category(&self) -> QString {
+        ffi::qmessagelogcontext_category(self)
+    }
+
+    /// Returns the source file which created the message, or an empty string if it is not available.
+    pub fn file(&self) -> QString {
+        ffi::qmessagelogcontext_file(self)
+    }
+
+    /// Returns the signature of the function which created the message, or an empty string if it is not available.
+    pub fn function(&self) -> QString {
+        ffi::qmessagelogcontext_function(self)
+    }
+
+    /// Returns the line in the source file which created the message, or zero if it is not available.
+    pub fn line(&self) -> i32 {
+        ffi::qmessagelogcontext_line(self)
+    }
+}
+
+/// Install a message handler which is called for all messages from Qt, such as those from qDebug() and qWarning()
+/// in C++ or warnings from the QML engine, instead of printing them.
+///
+/// This replaces any message handler which was installed before, including one installed by C++.
+/// The handler can be called from any thread, so it must be thread safe.
+///
+/// This can be used to capture warnings, for example to fail tests when a QML binding fails.
+///
+/// ```ignore
+/// cxx_qt_lib::install_message_handler(|msg_type, context, message| {
+///     if msg_type == QtMsgType::QtWarningMsg {
+///         eprintln!("{}: {message}", context.category());
+///     }
+/// });
+/// ```
+///
+/// Note that Qt aborts after calling the handler for a [QtMsgType::QtFatalMsg].
+pub fn install_message_handler<F>(handler: F)
+where
+    F: Fn(QtMsgType, &QMessageLogContext, &QString) + Send + Sync + 'static,
+{
+    fn func(
+        handler: &QtMessageHandler,
+        msg_type: QtMsgType,
+        context: &QMessageLogContext,
+        message: &QString,
+    ) {
+        (handler.inner)(msg_type, context, message)
+    }
+    let arg = QtMessageHandler {
+        inner: Box::new(handler),
+    };
+    ffi::qt_install_message_handler(func, Box::new(arg));
+}
+
+/// Restore the default message handler of Qt, which prints messages to stderr or the system log.
+pub fn reset_message_handler() {
+    ffi::qt_reset_message_handler()
+}
+
+#[doc(hidden)]
+pub struct QtMessageHandler {
+    inner: MessageHandlerFn,
+}
+
+type MessageHandlerFn = Box<dyn Fn(QtMsgType, &QMessageLogContext, &QString) + Send + Sync>;
+
+/// Log a message to the [QLoggingCategory](https://doc.qt.io/qt-6/qloggingcategory.html) with the given name,
+/// this is used by the [qt_debug!](crate::qt_debug) family of macros.
+///
+/// The message is only formatted when the category is enabled for the type of message.
+#[doc(hidden)]
+pub fn qt_log(
+    category: &str,
+    msg_type: QtMsgType,
+    file: &str,
+    line: u32,
+    args: std::fmt::Arguments,
+) {
+    if ffi::qt_logging_category_is_enabled(category, msg_type) {
+        let message = QString::from(&std::fmt::format(args));
+        ffi::qt_log_message(
+            category,
+            msg_type,
+            file,
+            i32::try_from(line).unwrap_or(i32::MAX),
+            &message,
+        );
+    }
+}
+
+/// Install a Qt message handler which forwards the messages from Qt to the [log] crate.
+///
+/// This includes messages from qDebug(), qWarning() and qCritical() in C++, warnings from the QML engine,
+/// and messages from the [qt_debug!](crate::qt_debug) family of macros, so that all messages end up in one logger.
+/// The category of the message is used as the target of the log record.
+///
+/// Note that the [QT_LOGGING_RULES](https://doc.qt.io/qt-6/qloggingcategory.html#logging-rules) still apply
+/// before messages are forwarded to the logger.
+#[cfg(feature = "log")]
+pub fn install_log_message_handler() {
+    install_message_handler(|msg_type, context, message| {
+        let level = match msg_type {
+            QtMsgType::QtDebugMsg => log::Level::Debug,
+            QtMsgType::QtInfoMsg => log::Level::Info,
+            QtMsgType::QtWarningMsg => log::Level::Warn,
+            _ => log::Level::Error,
+        };
+        if level > log::max_level() {
+            return;
+        }
+
+        let category = String::from(&context.category());
+        let file = String::from(&context.file());
+        log::logger().log(
+            &log::Record::builder()
+                .args(format_args!("{message}"))
+                .level(level)
+                .target(if category.is_empty() { "qt" } else { &category })
+                .file(Some(file.as_str()).filter(|file| !file.is_empty()))
+                .line(u32::try_from(context.line()).ok().filter(|line| *line > 0))
+                .build(),
+        );
+    });
+}
+
+/// Log a debug message to the [QLoggingCategory](https://doc.qt.io/qt-6/qloggingcategory.html) with the given name.
+///
+/// The message is formatted with the same syntax as [format!] and is filtered by the
+/// [QT_LOGGING_RULES](https://doc.qt.io/qt-6/qloggingcategory.html#logging-rules) of the category,
+/// so that messages from Rust can be enabled and disabled in the same way as messages from C++.
+///
+/// ```ignore
+/// cxx_qt_lib::qt_debug!("com.kdab.demo", "The value is {}", value);
+/// ```
+#[macro_export]
+macro_rules! qt_debug {
+    ($category:expr, $($arg:tt)+) => {
+        $crate::qt_log(
+            $category,
+            $crate::QtMsgType::QtDebugMsg,
+            ::core::file!(),
+            ::core::line!(),
+            ::core::format_args!($($arg)+),
+        )
+    };
+}
+
+/// Log an informational message to the [QLoggingCategory](https://doc.qt.io/qt-6/qloggingcategory.html) with the given name,
+/// see [qt_debug!](crate::qt_debug).
+#[macro_export]
+macro_rules! qt_info {
+    ($category:expr, $($arg:tt)+) => {
+        $crate::qt_log(
+            $category,
+            $crate::QtMsgType::QtInfoMsg,
+            ::core::file!(),
+            ::core::line!(),
+            ::core::format_args!($($arg)+),
+        )
+    };
+}
+
+/// Log a warning message to the [QLoggingCategory](https://doc.qt.io/qt-6/qloggingcategory.html) with the given name,
+/// see [qt_debug!](crate::qt_debug).
+#[macro_export]
+macro_rules! qt_warning {
+    ($category:expr, $($arg:tt)+) => {
+        $crate::qt_log(
+            $category,
+            $crate::QtMsgType::QtWarningMsg,
+            ::core::file!(),
+            ::core::line!(),
+            ::core::format_args!($($arg)+),
+        )
+    };
+}
+
+/// Log a critical message to the [QLoggingCategory](https://doc.qt.io/qt-6/qloggingcategory.html) with the given name,
+/// see [qt_debug!](crate::qt_debug).
+#[macro_export]
+macro_rules! qt_critical {
+    ($category:expr, $($arg:tt)+) => {
+        $crate::qt_log(
+            $category,
+            $crate::QtMsgType::QtCriticalMsg,
+            ::core::file!(),
+            ::core::line!(),
+            ::core::format_args!($($arg)+),
+        )
+    };
+}
@@ -47,18 +47,36 @@ qurlAuthority(const QUrl& url)
   return url.authority();
 }
 
+QString
+qurlAuthorityWithOptions(const QUrl& url, QUrlComponentFormattingOption options)
+{
+  return url.authority(options);
+}
+
 QString
 qurlFileName(const QUrl& url)
 {
   return url.fileName();
 }
 
+QString
+qurlFileNameWithOptions(const QUrl& url, QUrlComponentFormattingOption options)
+{
+  return url.fileName(options);
+}
+
 QString
 qurlFragment(const QUrl& url)
 {
   return url.fragment();
 }
 
+QString
+qurlFragmentWithOptions(const QUrl& url, QUrlComponentFormattingOption options)
+{
+  return url.fragment(options);
+}
+
 QUrl
 qurlFromEncoded(const QByteArray& input)
 {
@@ -89,6 +107,12 @@ qurlHost(const QUrl& url)
   return url.host();
 }
 
+QString
+qurlHostWithOptions(const QUrl& url, QUrlComponentFormattingOption options)
+{
+  return url.host(options);
+}
+
 QStringList
 qurlIdnWhitelist()
 {
@@ -101,18 +125,36 @@ qurlPath(const QUrl& url)
   return url.path();
 }
 
+QString
+qurlPathWithOptions(const QUrl& url, QUrlComponentFormattingOption options)
+{
+  return url.path(options);
+}
+
 QString
 qurlPassword(const QUrl& url)
 {
   return url.password();
 }
 
+QString
+qurlPasswordWithOptions(const QUrl& url, QUrlComponentFormattingOption options)
+{
+  return url.password(options);
+}
+
 QString
 qurlQuery(const QUrl& url)
 {
   return url.query();
 }
 
+QString
+qurlQueryWithOptions(const QUrl& url, QUrlComponentFormattingOption options)
+{
+  return url.query(options);
+}
+
 void
 qurlSetAuthority(QUrl& url, const QString& authority)
 {
@@ -191,6 +233,12 @@ qurlToEncoded(const QUrl& url)
   return url.toEncoded();
 }
 
+QByteArray
+qurlToEncodedWithOptions(const QUrl& url, QUrlComponentFormattingOption options)
+{
+  return url.toEncoded(options);
+}
+
 QByteArray
 qurlToPercentEncoding(const QString& input,
                       const QByteArray& exclude,
@@ -205,17 +253,35 @@ qurlToQString(const QUrl& url)
   return url.toString();
 }
 
+QString
+qurlToQStringWithOptions(const QUrl& url, QUrlComponentFormattingOption options)
+{
+  return url.toString(options);
+}
+
 QString
 qurlUserInfo(const QUrl& url)
 {
   return url.userInfo();
 }
 
+QString
+qurlUserInfoWithOptions(const QUrl& url, QUrlComponentFormattingOption options)
+{
+  return url.userInfo(options);
+}
+
 QString
 qurlUserName(const QUrl& url)
 {
   return url.userName();
 }
 
+QString
+qurlUserNameWithOptions(const QUrl& url, QUrlComponentFormattingOption options)
+{
+  return url.userName(options);
+}
+
 }
 }
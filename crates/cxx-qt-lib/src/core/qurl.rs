@@ -2,12 +2,56 @@
 // SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
+use crate::QUrlQuery;
 use cxx::{type_id, ExternType};
 use std::fmt;
 use std::mem::MaybeUninit;
 
 #[cxx::bridge]
 mod ffi {
+    /// The component formatting options define how the components of a URL will be formatted.
+    ///
+    /// The flags can be combined using the `|` operator.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QUrlComponentFormattingOption {
+        /// The component is returned in a "pretty form".
+        PrettyDecoded = 0x000000,
+        /// Leave space characters in their encoded form ("%20").
+        EncodeSpaces = 0x100000,
+        /// Leave non-US-ASCII characters encoded in their UTF-8 percent-encoded form (e.g., "%C3%A9" for the U+00E9 codepoint).
+        EncodeUnicode = 0x200000,
+        /// Leave certain delimiters in their encoded form, as would appear in the URL when the full URL is represented as text.
+        EncodeDelimiters = 0xc00000,
+        /// Leave US-ASCII characters not permitted in the URL by the specification in their encoded form.
+        EncodeReserved = 0x1000000,
+        /// Decode the US-ASCII characters that the URL specification does not allow to appear in the URL.
+        DecodeReserved = 0x2000000,
+        /// Leave all characters in their properly-encoded form, as this component would appear as part of a URL.
+        FullyEncoded = 0x1f00000,
+        /// Attempt to decode as much as possible.
+        ///
+        /// This may return data which cannot be parsed again into a QUrl, so it should only be used for display.
+        FullyDecoded = 0x7f00000,
+    }
+
+    /// The parsing mode controls the way QUrl parses strings.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QUrlParsingMode {
+        /// QUrl will try to correct some common errors in URLs.
+        TolerantMode,
+        /// Only valid URLs are accepted.
+        StrictMode,
+        /// QUrl will interpret the URL component in the fully-decoded form,
+        /// where percent characters stand for themselves, not as the beginning of a percent-encoded sequence.
+        ///
+        /// This mode is only valid for the setters setting components of a URL.
+        DecodedMode,
+    }
+
     unsafe extern "C++" {
         include!("cxx-qt-lib/qbytearray.h");
         type QByteArray = crate::QByteArray;
@@ -17,6 +61,8 @@ mod ffi {
         type QStringList = crate::QStringList;
         include!("cxx-qt-lib/qurl.h");
         type QUrl = super::QUrl;
+        include!("cxx-qt-lib/qurlquery.h");
+        type QUrlQuery = crate::QUrlQuery;
 
         /// Resets the content of the QUrl. After calling this function,
         /// the QUrl is equal to one that has been constructed with the default empty constructor.
@@ -75,6 +121,48 @@ mod ffi {
         #[rust_name = "scheme_or_default"]
         fn scheme(self: &QUrl) -> QString;
 
+        /// Sets the authority of the URL to authority, which is parsed using mode.
+        #[rust_name = "set_authority_with_mode"]
+        fn setAuthority(self: &mut QUrl, authority: &QString, mode: QUrlParsingMode);
+
+        /// Sets the fragment of the URL to fragment, which is parsed using mode.
+        #[rust_name = "set_fragment_with_mode"]
+        fn setFragment(self: &mut QUrl, fragment: &QString, mode: QUrlParsingMode);
+
+        /// Sets the host of the URL to host, which is parsed using mode.
+        #[rust_name = "set_host_with_mode"]
+        fn setHost(self: &mut QUrl, host: &QString, mode: QUrlParsingMode);
+
+        /// Sets the URL's password to password, which is parsed using mode.
+        #[rust_name = "set_password_with_mode"]
+        fn setPassword(self: &mut QUrl, password: &QString, mode: QUrlParsingMode);
+
+        /// Sets the path of the URL to path, which is parsed using mode.
+        #[rust_name = "set_path_with_mode"]
+        fn setPath(self: &mut QUrl, path: &QString, mode: QUrlParsingMode);
+
+        /// Sets the query string of the URL to query, which is parsed using mode.
+        #[rust_name = "set_query_with_mode"]
+        fn setQuery(self: &mut QUrl, query: &QString, mode: QUrlParsingMode);
+
+        /// Sets the query string of the URL to the encoded form of query.
+        ///
+        /// This allows for building the query from key-value pairs with [QUrlQuery].
+        #[rust_name = "set_url_query"]
+        fn setQuery(self: &mut QUrl, query: &QUrlQuery);
+
+        /// Parses url using mode and sets this object to that value.
+        #[rust_name = "set_url_with_mode"]
+        fn setUrl(self: &mut QUrl, url: &QString, mode: QUrlParsingMode);
+
+        /// Sets the user info of the URL to user_info, which is parsed using mode.
+        #[rust_name = "set_user_info_with_mode"]
+        fn setUserInfo(self: &mut QUrl, user_info: &QString, mode: QUrlParsingMode);
+
+        /// Sets the URL's user name to user_name, which is parsed using mode.
+        #[rust_name = "set_user_name_with_mode"]
+        fn setUserName(self: &mut QUrl, user_name: &QString, mode: QUrlParsingMode);
+
         /// Sets the port of the URL to port.
         ///
         /// port must be between 0 and 65535 inclusive. Setting the port to -1 indicates that the port is unspecified.
@@ -87,9 +175,13 @@ mod ffi {
         fn toLocalFile(self: &QUrl) -> QString;
     }
 
-    // Bitwise enums don't work well with Rust and CXX, so lets just use the defaults for now
+    // Bitwise enums don't work well with Rust and CXX, so the helpers without options use the defaults
+    // and the helpers with options convert the enum into the flags
     #[namespace = "rust::cxxqtlib1"]
     unsafe extern "C++" {
+        type QUrlComponentFormattingOption;
+        type QUrlParsingMode;
+
         #[doc(hidden)]
         #[rust_name = "qurl_init_from_string"]
         fn qurlInitFromString(string: &str) -> QUrl;
@@ -99,10 +191,16 @@ mod ffi {
 
         #[rust_name = "qurl_authority"]
         fn qurlAuthority(url: &QUrl) -> QString;
+        #[rust_name = "qurl_authority_with_options"]
+        fn qurlAuthorityWithOptions(url: &QUrl, options: QUrlComponentFormattingOption) -> QString;
         #[rust_name = "qurl_file_name"]
         fn qurlFileName(url: &QUrl) -> QString;
+        #[rust_name = "qurl_file_name_with_options"]
+        fn qurlFileNameWithOptions(url: &QUrl, options: QUrlComponentFormattingOption) -> QString;
         #[rust_name = "qurl_fragment"]
         fn qurlFragment(url: &QUrl) -> QString;
+        #[rust_name = "qurl_fragment_with_options"]
+        fn qurlFragmentWithOptions(url: &QUrl, options: QUrlComponentFormattingOption) -> QString;
         #[rust_name = "qurl_from_encoded"]
         fn qurlFromEncoded(input: &QByteArray) -> QUrl;
         #[rust_name = "qurl_from_local_file"]
@@ -113,14 +211,22 @@ mod ffi {
         fn qurlFromUserInput(user_input: &QString, working_directory: &QString) -> QUrl;
         #[rust_name = "qurl_host"]
         fn qurlHost(url: &QUrl) -> QString;
+        #[rust_name = "qurl_host_with_options"]
+        fn qurlHostWithOptions(url: &QUrl, options: QUrlComponentFormattingOption) -> QString;
         #[rust_name = "qurl_idn_whitelist"]
         fn qurlIdnWhitelist() -> QStringList;
         #[rust_name = "qurl_path"]
         fn qurlPath(url: &QUrl) -> QString;
+        #[rust_name = "qurl_path_with_options"]
+        fn qurlPathWithOptions(url: &QUrl, options: QUrlComponentFormattingOption) -> QString;
         #[rust_name = "qurl_password"]
         fn qurlPassword(url: &QUrl) -> QString;
+        #[rust_name = "qurl_password_with_options"]
+        fn qurlPasswordWithOptions(url: &QUrl, options: QUrlComponentFormattingOption) -> QString;
         #[rust_name = "qurl_query"]
         fn qurlQuery(url: &QUrl) -> QString;
+        #[rust_name = "qurl_query_with_options"]
+        fn qurlQueryWithOptions(url: &QUrl, options: QUrlComponentFormattingOption) -> QString;
         #[rust_name = "qurl_set_authority"]
         fn qurlSetAuthority(url: &mut QUrl, authority: &QString);
         #[rust_name = "qurl_set_fragment"]
@@ -147,6 +253,11 @@ mod ffi {
         fn qurlToDisplayString(url: &QUrl) -> QString;
         #[rust_name = "qurl_to_encoded"]
         fn qurlToEncoded(url: &QUrl) -> QByteArray;
+        #[rust_name = "qurl_to_encoded_with_options"]
+        fn qurlToEncodedWithOptions(
+            url: &QUrl,
+            options: QUrlComponentFormattingOption,
+        ) -> QByteArray;
         #[rust_name = "qurl_to_percent_encoding"]
         fn qurlToPercentEncoding(
             input: &QString,
@@ -156,10 +267,17 @@ mod ffi {
         #[doc(hidden)]
         #[rust_name = "qurl_to_qstring"]
         fn qurlToQString(url: &QUrl) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qurl_to_qstring_with_options"]
+        fn qurlToQStringWithOptions(url: &QUrl, options: QUrlComponentFormattingOption) -> QString;
         #[rust_name = "qurl_user_info"]
         fn qurlUserInfo(url: &QUrl) -> QString;
+        #[rust_name = "qurl_user_info_with_options"]
+        fn qurlUserInfoWithOptions(url: &QUrl, options: QUrlComponentFormattingOption) -> QString;
         #[rust_name = "qurl_user_name"]
         fn qurlUserName(url: &QUrl) -> QString;
+        #[rust_name = "qurl_user_name_with_options"]
+        fn qurlUserNameWithOptions(url: &QUrl, options: QUrlComponentFormattingOption) -> QString;
     }
 
     #[namespace = "rust::cxxqtlib1"]
@@ -190,6 +308,18 @@ mod ffi {
     }
 }
 
+pub use ffi::{QUrlComponentFormattingOption, QUrlParsingMode};
+
+impl core::ops::BitOr for QUrlComponentFormattingOption {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self {
+            repr: self.repr | other.repr,
+        }
+    }
+}
+
 /// The QUrl class provides a convenient interface for working with URLs.
 #[repr(C)]
 pub struct QUrl {
@@ -197,11 +327,27 @@ pub struct QUrl {
 }
 
 impl QUrl {
+    /// Returns the authority of the URL if it is defined.
+    pub fn authority(&self) -> Option<ffi::QString> {
+        let authority = self.authority_or_default();
+        if authority.is_empty() {
+            None
+        } else {
+            Some(authority)
+        }
+    }
+
     /// Returns the authority of the URL if it is defined; otherwise an empty string is returned.
     pub fn authority_or_default(&self) -> ffi::QString {
         ffi::qurl_authority(self)
     }
 
+    /// Returns the authority of the URL formatted using options,
+    /// if it is defined; otherwise an empty string is returned.
+    pub fn authority_with_options(&self, options: QUrlComponentFormattingOption) -> ffi::QString {
+        ffi::qurl_authority_with_options(self, options)
+    }
+
     /// Returns the name of the file, excluding the directory path.
     ///
     /// Note that, if this QUrl object is given a path ending in a slash, the name of the file is considered empty.
@@ -211,6 +357,11 @@ impl QUrl {
         ffi::qurl_file_name(self)
     }
 
+    /// Returns the name of the file, excluding the directory path, formatted using options.
+    pub fn file_name_with_options(&self, options: QUrlComponentFormattingOption) -> ffi::QString {
+        ffi::qurl_file_name_with_options(self, options)
+    }
+
     /// Returns the fragment of the URL.
     pub fn fragment(&self) -> Option<ffi::QString> {
         if self.has_fragment() {
@@ -225,6 +376,12 @@ impl QUrl {
         ffi::qurl_fragment(self)
     }
 
+    /// Returns the fragment of the URL formatted using options,
+    /// if it is defined; otherwise an empty string is returned.
+    pub fn fragment_with_options(&self, options: QUrlComponentFormattingOption) -> ffi::QString {
+        ffi::qurl_fragment_with_options(self, options)
+    }
+
     /// Parses input and returns the corresponding QUrl. input is assumed to be in encoded form, containing only ASCII characters.
     pub fn from_encoded(input: &ffi::QByteArray) -> Self {
         ffi::qurl_from_encoded(input)
@@ -248,26 +405,73 @@ impl QUrl {
         ffi::qurl_from_user_input(user_input, working_directory)
     }
 
+    /// Returns the host of the URL if it is defined.
+    pub fn host(&self) -> Option<ffi::QString> {
+        let host = self.host_or_default();
+        if host.is_empty() {
+            None
+        } else {
+            Some(host)
+        }
+    }
+
     /// Returns the host of the URL if it is defined; otherwise an empty string is returned.
     pub fn host_or_default(&self) -> ffi::QString {
         ffi::qurl_host(self)
     }
 
+    /// Returns the host of the URL formatted using options,
+    /// if it is defined; otherwise an empty string is returned.
+    pub fn host_with_options(&self, options: QUrlComponentFormattingOption) -> ffi::QString {
+        ffi::qurl_host_with_options(self, options)
+    }
+
     /// Returns the current whitelist of top-level domains that are allowed to have non-ASCII characters in their compositions.
     pub fn idn_whitelist() -> ffi::QStringList {
         ffi::qurl_idn_whitelist()
     }
 
+    /// Returns the password of the URL if it is defined.
+    pub fn password(&self) -> Option<ffi::QString> {
+        let password = self.password_or_default();
+        if password.is_empty() {
+            None
+        } else {
+            Some(password)
+        }
+    }
+
     /// Returns the password of the URL if it is defined; otherwise an empty string is returned.
     pub fn password_or_default(&self) -> ffi::QString {
         ffi::qurl_password(self)
     }
 
+    /// Returns the password of the URL formatted using options,
+    /// if it is defined; otherwise an empty string is returned.
+    pub fn password_with_options(&self, options: QUrlComponentFormattingOption) -> ffi::QString {
+        ffi::qurl_password_with_options(self, options)
+    }
+
     /// Returns the path of the URL.
     pub fn path(&self) -> ffi::QString {
         ffi::qurl_path(self)
     }
 
+    /// Returns the path of the URL formatted using options.
+    pub fn path_with_options(&self, options: QUrlComponentFormattingOption) -> ffi::QString {
+        ffi::qurl_path_with_options(self, options)
+    }
+
+    /// Returns the port of the URL, or None if the port is unspecified.
+    pub fn port(&self) -> Option<i32> {
+        let port = self.port_or(-1);
+        if port < 0 {
+            None
+        } else {
+            Some(port)
+        }
+    }
+
     /// Returns the query string of the URL if there's a query string
     pub fn query(&self) -> Option<ffi::QString> {
         if self.has_query() {
@@ -282,6 +486,18 @@ impl QUrl {
         ffi::qurl_query(self)
     }
 
+    /// Returns the query string of the URL formatted using options,
+    /// if it is defined; otherwise an empty string is returned.
+    pub fn query_with_options(&self, options: QUrlComponentFormattingOption) -> ffi::QString {
+        ffi::qurl_query_with_options(self, options)
+    }
+
+    /// Returns the query string of the URL as a [QUrlQuery],
+    /// so that the key-value pairs of the query can be inspected.
+    pub fn url_query(&self) -> QUrlQuery {
+        QUrlQuery::from(self)
+    }
+
     /// Returns the scheme of the URL. If the Option is None,
     /// this means the scheme is undefined and the URL is then relative.
     ///
@@ -370,6 +586,15 @@ impl QUrl {
         ffi::qurl_to_encoded(self)
     }
 
+    /// Returns the encoded representation of the URL formatted using options if it's valid;
+    /// otherwise an empty QByteArray is returned.
+    pub fn to_encoded_with_options(
+        &self,
+        options: QUrlComponentFormattingOption,
+    ) -> ffi::QByteArray {
+        ffi::qurl_to_encoded_with_options(self, options)
+    }
+
     /// Returns the path of this URL formatted as a local file path.
     /// The path returned will use forward slashes, even if it was originally created from one with backslashes.
     pub fn to_local_file(&self) -> Option<ffi::QString> {
@@ -397,15 +622,52 @@ impl QUrl {
         ffi::qurl_to_qstring(self)
     }
 
+    /// Returns a QString representation of the URL formatted using options.
+    pub fn to_qstring_with_options(&self, options: QUrlComponentFormattingOption) -> ffi::QString {
+        ffi::qurl_to_qstring_with_options(self, options)
+    }
+
+    /// Returns the user info of the URL if it is defined.
+    pub fn user_info(&self) -> Option<ffi::QString> {
+        let user_info = self.user_info_or_default();
+        if user_info.is_empty() {
+            None
+        } else {
+            Some(user_info)
+        }
+    }
+
     /// Returns the user info of the URL, or an empty string if the user info is undefined.
     pub fn user_info_or_default(&self) -> ffi::QString {
         ffi::qurl_user_info(self)
     }
 
+    /// Returns the user info of the URL formatted using options,
+    /// or an empty string if the user info is undefined.
+    pub fn user_info_with_options(&self, options: QUrlComponentFormattingOption) -> ffi::QString {
+        ffi::qurl_user_info_with_options(self, options)
+    }
+
+    /// Returns the user name of the URL if it is defined.
+    pub fn user_name(&self) -> Option<ffi::QString> {
+        let user_name = self.user_name_or_default();
+        if user_name.is_empty() {
+            None
+        } else {
+            Some(user_name)
+        }
+    }
+
     /// Returns the user name of the URL if it is defined; otherwise an empty string is returned.
     pub fn user_name_or_default(&self) -> ffi::QString {
         ffi::qurl_user_name(self)
     }
+
+    /// Returns the user name of the URL formatted using options,
+    /// if it is defined; otherwise an empty string is returned.
+    pub fn user_name_with_options(&self, options: QUrlComponentFormattingOption) -> ffi::QString {
+        ffi::qurl_user_name_with_options(self, options)
+    }
 }
 
 impl Clone for QUrl {
@@ -0,0 +1,79 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qurlquery.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QUrlQuery has one QSharedDataPointer member, which has one pointer as a
+// member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/io/qurlquery.h?h=v5.15.6-lts-lgpl#n114
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/io/qurlquery.h?h=v6.2.4#n107
+assert_alignment_and_size(QUrlQuery, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QUrlQuery>::value);
+static_assert(!::std::is_trivially_copy_constructible<QUrlQuery>::value);
+
+static_assert(!::std::is_trivially_destructible<QUrlQuery>::value);
+
+static_assert(QTypeInfo<QUrlQuery>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QStringList
+qurlqueryAllQueryItemValues(const QUrlQuery& query,
+                            const QString& key,
+                            QUrl::ComponentFormattingOption options)
+{
+  return query.allQueryItemValues(key, options);
+}
+
+QString
+qurlqueryQuery(const QUrlQuery& query, QUrl::ComponentFormattingOption options)
+{
+  return query.query(options);
+}
+
+QStringList
+qurlqueryQueryItemKeys(const QUrlQuery& query,
+                       QUrl::ComponentFormattingOption options)
+{
+  QStringList keys;
+  for (const auto& item : query.queryItems(options)) {
+    keys.append(item.first);
+  }
+  return keys;
+}
+
+QString
+qurlqueryQueryItemValue(const QUrlQuery& query,
+                        const QString& key,
+                        QUrl::ComponentFormattingOption options)
+{
+  return query.queryItemValue(key, options);
+}
+
+QStringList
+qurlqueryQueryItemValues(const QUrlQuery& query,
+                         QUrl::ComponentFormattingOption options)
+{
+  QStringList values;
+  for (const auto& item : query.queryItems(options)) {
+    values.append(item.second);
+  }
+  return values;
+}
+
+QString
+qurlqueryToQString(const QUrlQuery& query)
+{
+  return query.toString(QUrl::FullyEncoded);
+}
+
+}
+}
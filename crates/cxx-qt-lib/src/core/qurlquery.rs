@@ -0,0 +1,272 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qurl.h");
+        type QUrlComponentFormattingOption = crate::QUrlComponentFormattingOption;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = crate::QStringList;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+        include!("cxx-qt-lib/qurlquery.h");
+        type QUrlQuery = super::QUrlQuery;
+
+        /// Appends the pair key = value to the end of the query string of the URL.
+        /// This method does not overwrite existing items that might exist with the same key.
+        ///
+        /// The key and value are expected to be in the same form as for [QUrlQuery::set_query],
+        /// so delimiters such as "&" and "=" in them need to be percent encoded.
+        #[rust_name = "add_query_item"]
+        fn addQueryItem(self: &mut QUrlQuery, key: &QString, value: &QString);
+
+        /// Clears this QUrlQuery object by removing all of the key-value pairs currently stored.
+        fn clear(self: &mut QUrlQuery);
+
+        /// Returns true if there is a query string pair whose key is equal to key from the URL.
+        #[rust_name = "has_query_item"]
+        fn hasQueryItem(self: &QUrlQuery, key: &QString) -> bool;
+
+        /// Returns true if this QUrlQuery object contains no key-value pairs, such as after being default-constructed or after parsing an empty query string.
+        #[rust_name = "is_empty"]
+        fn isEmpty(self: &QUrlQuery) -> bool;
+
+        /// Searches the query string for all pairs whose key is equal to key and removes them from the URL.
+        #[rust_name = "remove_all_query_items"]
+        fn removeAllQueryItems(self: &mut QUrlQuery, key: &QString);
+
+        /// Removes the query string pair whose key is equal to key from the URL.
+        /// If there are multiple items with a key equal to key, it removes the first item in the order they were present in the query string or added.
+        #[rust_name = "remove_query_item"]
+        fn removeQueryItem(self: &mut QUrlQuery, key: &QString);
+
+        /// Parses the query string in query and sets the internal items to the values found there.
+        #[rust_name = "set_query"]
+        fn setQuery(self: &mut QUrlQuery, query: &QString);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qurlquery_all_query_item_values"]
+        fn qurlqueryAllQueryItemValues(
+            query: &QUrlQuery,
+            key: &QString,
+            options: QUrlComponentFormattingOption,
+        ) -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qurlquery_query"]
+        fn qurlqueryQuery(query: &QUrlQuery, options: QUrlComponentFormattingOption) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qurlquery_query_item_keys"]
+        fn qurlqueryQueryItemKeys(
+            query: &QUrlQuery,
+            options: QUrlComponentFormattingOption,
+        ) -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qurlquery_query_item_value"]
+        fn qurlqueryQueryItemValue(
+            query: &QUrlQuery,
+            key: &QString,
+            options: QUrlComponentFormattingOption,
+        ) -> QString;
+        #[doc(hidden)]
+        #[rust_name = "qurlquery_query_item_values"]
+        fn qurlqueryQueryItemValues(
+            query: &QUrlQuery,
+            options: QUrlComponentFormattingOption,
+        ) -> QStringList;
+        #[doc(hidden)]
+        #[rust_name = "qurlquery_to_qstring"]
+        fn qurlqueryToQString(query: &QUrlQuery) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qurlquery_drop"]
+        fn drop(query: &mut QUrlQuery);
+
+        #[doc(hidden)]
+        #[rust_name = "qurlquery_init_default"]
+        fn construct() -> QUrlQuery;
+        #[doc(hidden)]
+        #[rust_name = "qurlquery_init_from_qstring"]
+        fn construct(query: &QString) -> QUrlQuery;
+        #[doc(hidden)]
+        #[rust_name = "qurlquery_init_from_qurl"]
+        fn construct(url: &QUrl) -> QUrlQuery;
+        #[doc(hidden)]
+        #[rust_name = "qurlquery_init_from_qurlquery"]
+        fn construct(query: &QUrlQuery) -> QUrlQuery;
+
+        #[doc(hidden)]
+        #[rust_name = "qurlquery_eq"]
+        fn operatorEq(a: &QUrlQuery, b: &QUrlQuery) -> bool;
+    }
+}
+
+use crate::{QList, QString, QStringList, QUrlComponentFormattingOption};
+
+/// The QUrlQuery class provides a way to manipulate key-value pairs in a URL's query.
+///
+/// This allows for building the query of a URL from Rust without having to
+/// take care of the delimiters and the percent encoding of the query string.
+#[repr(C)]
+pub struct QUrlQuery {
+    _d: MaybeUninit<usize>,
+}
+
+impl QUrlQuery {
+    /// Returns a list of all the values for the query string with the given key,
+    /// in the order they were present in the query string or added, formatted using options.
+    pub fn all_query_item_values(
+        &self,
+        key: &QString,
+        options: QUrlComponentFormattingOption,
+    ) -> QStringList {
+        ffi::qurlquery_all_query_item_values(self, key, options)
+    }
+
+    /// Returns the reconstructed query string, formed from the key-value pairs
+    /// currently stored in this QUrlQuery object and separated by the query delimiters,
+    /// with each key and value formatted using options.
+    pub fn query(&self, options: QUrlComponentFormattingOption) -> QString {
+        ffi::qurlquery_query(self, options)
+    }
+
+    /// Returns the query string of the URL as a list of key-value pairs,
+    /// in the order they were present in the query string or added, formatted using options.
+    pub fn query_items(&self, options: QUrlComponentFormattingOption) -> Vec<(QString, QString)> {
+        let keys = QList::<QString>::from(&ffi::qurlquery_query_item_keys(self, options));
+        let values = QList::<QString>::from(&ffi::qurlquery_query_item_values(self, options));
+        keys.iter().cloned().zip(values.iter().cloned()).collect()
+    }
+
+    /// Returns the query value associated with key key from the URL, formatted using options.
+    ///
+    /// If the key is not found, an empty string is returned,
+    /// if multiple items with the same key are present only the first is returned.
+    pub fn query_item_value(
+        &self,
+        key: &QString,
+        options: QUrlComponentFormattingOption,
+    ) -> QString {
+        ffi::qurlquery_query_item_value(self, key, options)
+    }
+
+    /// Returns this QUrlQuery as a QString, with all of the items fully encoded.
+    ///
+    /// The result can be appended to a URL or passed to [QUrl::set_query](crate::QUrl::set_query).
+    pub fn to_qstring(&self) -> QString {
+        ffi::qurlquery_to_qstring(self)
+    }
+}
+
+impl Clone for QUrlQuery {
+    /// Copies the contents of the other QUrlQuery object.
+    fn clone(&self) -> Self {
+        ffi::qurlquery_init_from_qurlquery(self)
+    }
+}
+
+impl Default for QUrlQuery {
+    /// Constructs an empty QUrlQuery object.
+    fn default() -> Self {
+        ffi::qurlquery_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QUrlQuery {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qurlquery_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QUrlQuery {}
+
+impl fmt::Display for QUrlQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_qstring())
+    }
+}
+
+impl fmt::Debug for QUrlQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl Drop for QUrlQuery {
+    /// Destroys this QUrlQuery object.
+    fn drop(&mut self) {
+        ffi::qurlquery_drop(self)
+    }
+}
+
+impl From<&QString> for QUrlQuery {
+    /// Constructs a QUrlQuery object and parses the query string found in the QString,
+    /// which should be without the leading question mark.
+    fn from(query: &QString) -> Self {
+        ffi::qurlquery_init_from_qstring(query)
+    }
+}
+
+impl From<&ffi::QUrl> for QUrlQuery {
+    /// Constructs a QUrlQuery object and parses the query string found in the QUrl.
+    fn from(url: &ffi::QUrl) -> Self {
+        ffi::qurlquery_init_from_qurl(url)
+    }
+}
+
+#[cfg(feature = "url")]
+impl From<&url::Url> for QUrlQuery {
+    fn from(value: &url::Url) -> Self {
+        QUrlQuery::from(&QString::from(value.query().unwrap_or_default()))
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QUrlQuery {
+    type Id = type_id!("QUrlQuery");
+    type Kind = cxx::kind::Trivial;
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "url")]
+    use super::*;
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn test_url() {
+        let url = url::Url::parse("https://github.com/kdab/cxx-qt?a=1&b=x%26y").unwrap();
+        let query = QUrlQuery::from(&url);
+        assert_eq!(url.query().unwrap(), query.to_string());
+        assert_eq!(
+            query
+                .query_item_value(
+                    &QString::from("b"),
+                    QUrlComponentFormattingOption::FullyDecoded
+                )
+                .to_string(),
+            "x&y"
+        );
+    }
+}
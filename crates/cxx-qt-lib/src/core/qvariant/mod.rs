@@ -169,6 +169,8 @@ impl_qvariant_value!(crate::QColor, qvariant_qcolor);
 impl_qvariant_value!(crate::QDate, qvariant_qdate);
 #[cfg(not(target_os = "emscripten"))]
 impl_qvariant_value!(crate::QDateTime, qvariant_qdatetime);
+impl_qvariant_value!(crate::QMargins, qvariant_qmargins);
+impl_qvariant_value!(crate::QMarginsF, qvariant_qmarginsf);
 impl_qvariant_value!(crate::QModelIndex, qvariant_qmodelindex);
 impl_qvariant_value!(crate::QPersistentModelIndex, qvariant_qpersistentmodelindex);
 impl_qvariant_value!(crate::QPoint, qvariant_qpoint);
@@ -71,6 +71,8 @@ CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QColor, QColor)
 #endif
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QDate, QDate)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QDateTime, QDateTime)
+CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QMargins, QMargins)
+CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QMarginsF, QMarginsF)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QModelIndex, QModelIndex)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QPersistentModelIndex, QPersistentModelIndex)
 CXX_QT_QVARIANT_CAN_CONVERT_IMPL(::QPoint, QPoint)
@@ -0,0 +1,37 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmargins.h");
+        type QMargins = crate::QMargins;
+
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvariant"]
+    unsafe extern "C++" {
+        #[rust_name = "can_convert_QMargins"]
+        fn qvariantCanConvertQMargins(variant: &QVariant) -> bool;
+        #[rust_name = "construct_QMargins"]
+        fn qvariantConstruct(value: &QMargins) -> QVariant;
+        #[rust_name = "value_or_default_QMargins"]
+        fn qvariantValueOrDefault(variant: &QVariant) -> QMargins;
+    }
+}
+
+pub(crate) fn can_convert(variant: &ffi::QVariant) -> bool {
+    ffi::can_convert_QMargins(variant)
+}
+
+pub(crate) fn construct(value: &ffi::QMargins) -> ffi::QVariant {
+    ffi::construct_QMargins(value)
+}
+
+pub(crate) fn value_or_default(variant: &ffi::QVariant) -> ffi::QMargins {
+    ffi::value_or_default_QMargins(variant)
+}
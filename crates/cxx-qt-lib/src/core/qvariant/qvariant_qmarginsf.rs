@@ -0,0 +1,37 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmarginsf.h");
+        type QMarginsF = crate::QMarginsF;
+
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+    }
+
+    #[namespace = "rust::cxxqtlib1::qvariant"]
+    unsafe extern "C++" {
+        #[rust_name = "can_convert_QMarginsF"]
+        fn qvariantCanConvertQMarginsF(variant: &QVariant) -> bool;
+        #[rust_name = "construct_QMarginsF"]
+        fn qvariantConstruct(value: &QMarginsF) -> QVariant;
+        #[rust_name = "value_or_default_QMarginsF"]
+        fn qvariantValueOrDefault(variant: &QVariant) -> QMarginsF;
+    }
+}
+
+pub(crate) fn can_convert(variant: &ffi::QVariant) -> bool {
+    ffi::can_convert_QMarginsF(variant)
+}
+
+pub(crate) fn construct(value: &ffi::QMarginsF) -> ffi::QVariant {
+    ffi::construct_QMarginsF(value)
+}
+
+pub(crate) fn value_or_default(variant: &ffi::QVariant) -> ffi::QMarginsF {
+    ffi::value_or_default_QMarginsF(variant)
+}
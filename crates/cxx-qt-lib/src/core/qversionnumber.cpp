@@ -0,0 +1,89 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qversionnumber.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+#include <QtCore/QVector>
+
+// QVersionNumber has a union of a pointer and inline segments as its only member
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/tools/qversionnumber.h?h=v5.15.6-lts-lgpl#n62
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/tools/qversionnumber.h?h=v6.2.4#n41
+assert_alignment_and_size(QVersionNumber, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QVersionNumber>::value);
+static_assert(!::std::is_trivially_copy_constructible<QVersionNumber>::value);
+
+static_assert(!::std::is_trivially_destructible<QVersionNumber>::value);
+
+static_assert(QTypeInfo<QVersionNumber>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QVersionNumber
+qversionnumberCommonPrefix(const QVersionNumber& v1, const QVersionNumber& v2)
+{
+  return QVersionNumber::commonPrefix(v1, v2);
+}
+
+QVersionNumber
+qversionnumberFromSlice(::rust::Slice<const ::std::int32_t> segments)
+{
+  QVector<int> vector;
+  for (const auto segment : segments) {
+    vector.append(segment);
+  }
+  return QVersionNumber(vector);
+}
+
+QVersionNumber
+qversionnumberFromString(const QString& string)
+{
+  return QVersionNumber::fromString(string);
+}
+
+QVersionNumber
+qversionnumberNormalized(const QVersionNumber& version)
+{
+  // Qt 6 has ref-qualified overloads of normalized so we can't bind directly
+  return version.normalized();
+}
+
+::std::int32_t
+qversionnumberSegmentAt(const QVersionNumber& version, ::rust::isize index)
+{
+  Q_ASSERT(index >= 0);
+  // Qt 5 has an int Qt 6 has a qsizetype
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return version.segmentAt(static_cast<qsizetype>(index));
+#else
+  return version.segmentAt(static_cast<int>(index));
+#endif
+}
+
+::rust::isize
+qversionnumberSegmentCount(const QVersionNumber& version)
+{
+  // In Qt 5 the type was int now it is qsizetype, so we need to ensure the type
+  // is the same for CXX
+  return static_cast<::rust::isize>(version.segmentCount());
+}
+
+::rust::Vec<::std::int32_t>
+qversionnumberSegments(const QVersionNumber& version)
+{
+  ::rust::Vec<::std::int32_t> segments;
+  for (const auto segment : version.segments()) {
+    segments.push_back(segment);
+  }
+  return segments;
+}
+
+}
+}
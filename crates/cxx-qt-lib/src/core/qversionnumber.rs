@@ -0,0 +1,229 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::cmp::Ordering;
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qversionnumber.h");
+        type QVersionNumber = super::QVersionNumber;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// Returns true if the version number does not contain any segments; otherwise returns false.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QVersionNumber) -> bool;
+
+        /// Returns true if the version number does not have any trailing zeros, otherwise returns false.
+        #[rust_name = "is_normalized"]
+        fn isNormalized(self: &QVersionNumber) -> bool;
+
+        /// Returns true if the current version number is contained in the other version number,
+        /// otherwise returns false.
+        #[rust_name = "is_prefix_of"]
+        fn isPrefixOf(self: &QVersionNumber, other: &QVersionNumber) -> bool;
+
+        /// Returns the major version number, that is, the first segment.
+        /// This function is equivalent to segment_at(0).
+        /// If this QVersionNumber object is null, this function returns 0.
+        #[rust_name = "major_version"]
+        fn majorVersion(self: &QVersionNumber) -> i32;
+
+        /// Returns the micro version number, that is, the third segment.
+        /// This function is equivalent to segment_at(2).
+        /// If this QVersionNumber object does not contain the micro number, this function returns 0.
+        #[rust_name = "micro_version"]
+        fn microVersion(self: &QVersionNumber) -> i32;
+
+        /// Returns the minor version number, that is, the second segment.
+        /// This function is equivalent to segment_at(1).
+        /// If this QVersionNumber object does not contain the minor number, this function returns 0.
+        #[rust_name = "minor_version"]
+        fn minorVersion(self: &QVersionNumber) -> i32;
+
+        /// Returns a string with all of the segments delimited by a period (.).
+        #[rust_name = "to_qstring"]
+        fn toString(self: &QVersionNumber) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qversionnumber_common_prefix"]
+        fn qversionnumberCommonPrefix(v1: &QVersionNumber, v2: &QVersionNumber) -> QVersionNumber;
+        #[doc(hidden)]
+        #[rust_name = "qversionnumber_from_slice"]
+        fn qversionnumberFromSlice(segments: &[i32]) -> QVersionNumber;
+        #[doc(hidden)]
+        #[rust_name = "qversionnumber_from_string"]
+        fn qversionnumberFromString(string: &QString) -> QVersionNumber;
+        #[doc(hidden)]
+        #[rust_name = "qversionnumber_normalized"]
+        fn qversionnumberNormalized(version: &QVersionNumber) -> QVersionNumber;
+        #[doc(hidden)]
+        #[rust_name = "qversionnumber_segment_at"]
+        fn qversionnumberSegmentAt(version: &QVersionNumber, index: isize) -> i32;
+        #[doc(hidden)]
+        #[rust_name = "qversionnumber_segment_count"]
+        fn qversionnumberSegmentCount(version: &QVersionNumber) -> isize;
+        #[doc(hidden)]
+        #[rust_name = "qversionnumber_segments"]
+        fn qversionnumberSegments(version: &QVersionNumber) -> Vec<i32>;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qversionnumber_clone"]
+        fn construct(version: &QVersionNumber) -> QVersionNumber;
+        #[doc(hidden)]
+        #[rust_name = "qversionnumber_cmp"]
+        fn operatorCmp(a: &QVersionNumber, b: &QVersionNumber) -> i8;
+        #[doc(hidden)]
+        #[rust_name = "qversionnumber_default"]
+        fn construct() -> QVersionNumber;
+        #[doc(hidden)]
+        #[rust_name = "qversionnumber_drop"]
+        fn drop(version: &mut QVersionNumber);
+        #[doc(hidden)]
+        #[rust_name = "qversionnumber_eq"]
+        fn operatorEq(a: &QVersionNumber, b: &QVersionNumber) -> bool;
+    }
+}
+
+/// The QVersionNumber class contains a version number with an arbitrary number of segments.
+#[repr(C)]
+pub struct QVersionNumber {
+    /// QVersionNumber has a union of a pointer to the segments
+    /// and inline segments packed into a pointer-sized value
+    _space: MaybeUninit<usize>,
+}
+
+impl Clone for QVersionNumber {
+    /// Constructs a copy of other.
+    fn clone(&self) -> Self {
+        ffi::qversionnumber_clone(self)
+    }
+}
+
+impl Default for QVersionNumber {
+    /// Produces a null version.
+    fn default() -> Self {
+        ffi::qversionnumber_default()
+    }
+}
+
+impl Drop for QVersionNumber {
+    /// Destroys the version number.
+    fn drop(&mut self) {
+        ffi::qversionnumber_drop(self)
+    }
+}
+
+impl PartialEq for QVersionNumber {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qversionnumber_eq(self, other)
+    }
+}
+
+impl Eq for QVersionNumber {}
+
+impl PartialOrd for QVersionNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QVersionNumber {
+    /// Compares the segments of the version numbers, where a missing segment
+    /// is considered to be older than a present one (e.g. 1.0 is newer than 1).
+    fn cmp(&self, other: &Self) -> Ordering {
+        ffi::qversionnumber_cmp(self, other).cmp(&0)
+    }
+}
+
+impl fmt::Display for QVersionNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_qstring())
+    }
+}
+
+impl fmt::Debug for QVersionNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl From<&[i32]> for QVersionNumber {
+    /// Constructs a version number from the given segments.
+    fn from(segments: &[i32]) -> Self {
+        ffi::qversionnumber_from_slice(segments)
+    }
+}
+
+impl From<&QVersionNumber> for Vec<i32> {
+    /// Converts a QVersionNumber to a vector containing its segments.
+    fn from(version: &QVersionNumber) -> Self {
+        ffi::qversionnumber_segments(version)
+    }
+}
+
+impl QVersionNumber {
+    /// Returns a version number that is a parent version of both v1 and v2.
+    pub fn common_prefix(v1: &Self, v2: &Self) -> Self {
+        ffi::qversionnumber_common_prefix(v1, v2)
+    }
+
+    /// Constructs a QVersionNumber from a specially formatted string of
+    /// non-negative decimal numbers delimited by a period (.).
+    ///
+    /// Parsing stops at the first character that is not part of a version number,
+    /// if no version number could be parsed a null version is returned.
+    pub fn from_qstring(string: &ffi::QString) -> Self {
+        ffi::qversionnumber_from_string(string)
+    }
+
+    /// Constructs a version number with the given major, minor and micro segments.
+    pub fn new(major: i32, minor: i32, micro: i32) -> Self {
+        Self::from([major, minor, micro].as_slice())
+    }
+
+    /// Returns an equivalent version number but with all trailing zeros removed.
+    pub fn normalized(&self) -> Self {
+        ffi::qversionnumber_normalized(self)
+    }
+
+    /// Returns the segment value at index, or None if the index does not exist.
+    pub fn segment_at(&self, index: isize) -> Option<i32> {
+        if index >= 0 && index < self.segment_count() {
+            Some(ffi::qversionnumber_segment_at(self, index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of integers stored in segments().
+    pub fn segment_count(&self) -> isize {
+        ffi::qversionnumber_segment_count(self)
+    }
+
+    /// Returns all of the numerical segments.
+    pub fn segments(&self) -> Vec<i32> {
+        ffi::qversionnumber_segments(self)
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QVersionNumber {
+    type Id = type_id!("QVersionNumber");
+    type Kind = cxx::kind::Trivial;
+}
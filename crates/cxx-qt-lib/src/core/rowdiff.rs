@@ -0,0 +1,262 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// An operation which changes the rows of a list, the positions refer to the list
+/// after all of the previous operations have been applied.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum RowOperation {
+    /// Remove the rows from first to last inclusive
+    Remove { first: usize, last: usize },
+    /// Move the row at from, so that it is at to once it has been moved
+    Move { from: usize, to: usize },
+    /// Insert the rows of the new list from first to last inclusive at the same positions
+    Insert { first: usize, last: usize },
+    /// Replace the rows from first to last inclusive with the rows of the new list at the same positions
+    Change { first: usize, last: usize },
+}
+
+/// Compute the operations which turn the old rows into the new rows, where rows with the same key are the same row.
+///
+/// Rows are removed and inserted in contiguous ranges, the fewest rows are moved
+/// by keeping the longest subsequence of rows which are in the same order in both lists,
+/// and rows which are not equal after moving are changed.
+///
+/// Returns None if the keys are not unique in either list.
+pub(crate) fn diff_rows<T, K, F>(old: &[T], new: &[T], key: F) -> Option<Vec<RowOperation>>
+where
+    T: PartialEq,
+    K: Eq + Hash,
+    F: Fn(&T) -> K,
+{
+    let new_positions: HashMap<K, usize> = new
+        .iter()
+        .enumerate()
+        .map(|(position, row)| (key(row), position))
+        .collect();
+    let mut old_keys = HashSet::with_capacity(old.len());
+    if new_positions.len() != new.len() || !old.iter().all(|row| old_keys.insert(key(row))) {
+        return None;
+    }
+
+    let mut operations = vec![];
+
+    // The position in the new list of each old row, which is None when the row is removed
+    let targets: Vec<Option<usize>> = old
+        .iter()
+        .map(|row| new_positions.get(&key(row)).copied())
+        .collect();
+
+    // Remove rows from the end, so that the positions of earlier runs stay the same
+    let mut position = targets.len();
+    while position > 0 {
+        if targets[position - 1].is_some() {
+            position -= 1;
+            continue;
+        }
+        let last = position - 1;
+        while position > 0 && targets[position - 1].is_none() {
+            position -= 1;
+        }
+        operations.push(RowOperation::Remove {
+            first: position,
+            last,
+        });
+    }
+
+    // Rows which are kept and are not equal to the new row are changed once they are in place
+    let mut kept = vec![false; new.len()];
+    let mut changed = vec![false; new.len()];
+    for (old_row, target) in old.iter().zip(&targets) {
+        if let Some(target) = *target {
+            kept[target] = true;
+            changed[target] = *old_row != new[target];
+        }
+    }
+
+    // The current list as the positions of the rows in the new list
+    let mut current: Vec<usize> = targets.into_iter().flatten().collect();
+
+    // Move the rows which are not in the longest increasing subsequence, in the order of the new list,
+    // so that each moved row is placed directly after the placed row which precedes it in the new list
+    let mut placed = vec![false; new.len()];
+    for &target in &longest_increasing_subsequence(&current) {
+        placed[current[target]] = true;
+    }
+    let mut unplaced: Vec<usize> = current
+        .iter()
+        .copied()
+        .filter(|target| !placed[*target])
+        .collect();
+    unplaced.sort_unstable();
+    for target in unplaced {
+        let from = current.iter().position(|row| *row == target).unwrap();
+        current.remove(from);
+        let to = current
+            .iter()
+            .rposition(|row| *row < target && placed[*row])
+            .map_or(0, |position| position + 1);
+        current.insert(to, target);
+        placed[target] = true;
+        if from != to {
+            operations.push(RowOperation::Move { from, to });
+        }
+    }
+
+    // The kept rows are now in the order of the new list, so insert the other rows in runs
+    operations.extend(runs(&kept, false).map(|(first, last)| RowOperation::Insert { first, last }));
+    operations
+        .extend(runs(&changed, true).map(|(first, last)| RowOperation::Change { first, last }));
+
+    Some(operations)
+}
+
+/// The positions of the values which form the longest strictly increasing subsequence
+fn longest_increasing_subsequence(values: &[usize]) -> Vec<usize> {
+    // The position of the last value of the smallest tail of each length of subsequence
+    let mut tails: Vec<usize> = vec![];
+    // The position of the previous value in the subsequence ending at each position
+    let mut previous = vec![None; values.len()];
+    for (position, value) in values.iter().enumerate() {
+        let length = tails.partition_point(|tail| values[*tail] < *value);
+        previous[position] = length.checked_sub(1).map(|length| tails[length]);
+        if length == tails.len() {
+            tails.push(position);
+        } else {
+            tails[length] = position;
+        }
+    }
+
+    let mut subsequence = vec![];
+    let mut position = tails.last().copied();
+    while let Some(current) = position {
+        subsequence.push(current);
+        position = previous[current];
+    }
+    subsequence.reverse();
+    subsequence
+}
+
+/// The inclusive ranges of contiguous positions which have the given flag
+fn runs(flags: &[bool], flag: bool) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let mut position = 0;
+    std::iter::from_fn(move || {
+        while position < flags.len() && flags[position] != flag {
+            position += 1;
+        }
+        if position == flags.len() {
+            return None;
+        }
+        let first = position;
+        while position < flags.len() && flags[position] == flag {
+            position += 1;
+        }
+        Some((first, position - 1))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Apply the operations to the old rows in the same way as a model does
+    fn apply(
+        old: &[(char, i32)],
+        new: &[(char, i32)],
+        operations: &[RowOperation],
+    ) -> Vec<(char, i32)> {
+        let mut rows = old.to_vec();
+        for operation in operations {
+            match *operation {
+                RowOperation::Remove { first, last } => {
+                    rows.drain(first..=last);
+                }
+                RowOperation::Move { from, to } => {
+                    let row = rows.remove(from);
+                    rows.insert(to, row);
+                }
+                RowOperation::Insert { first, last } => {
+                    rows.splice(first..first, new[first..=last].iter().copied());
+                }
+                RowOperation::Change { first, last } => {
+                    rows[first..=last].copy_from_slice(&new[first..=last]);
+                }
+            }
+        }
+        rows
+    }
+
+    fn diff(old: &[(char, i32)], new: &[(char, i32)]) -> Vec<RowOperation> {
+        let operations = diff_rows(old, new, |row| row.0).unwrap();
+        assert_eq!(apply(old, new, &operations), new);
+        operations
+    }
+
+    #[test]
+    fn diff_rows_unchanged() {
+        let rows = [('a', 1), ('b', 2), ('c', 3)];
+        assert!(diff(&rows, &rows).is_empty());
+    }
+
+    #[test]
+    fn diff_rows_insert_and_remove() {
+        let old = [('a', 1), ('b', 2), ('c', 3), ('d', 4)];
+        let new = [('x', 0), ('a', 1), ('d', 4), ('y', 5), ('z', 6)];
+        assert_eq!(
+            diff(&old, &new),
+            vec![
+                RowOperation::Remove { first: 1, last: 2 },
+                RowOperation::Insert { first: 0, last: 0 },
+                RowOperation::Insert { first: 3, last: 4 },
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_rows_move() {
+        let old = [('a', 1), ('b', 2), ('c', 3), ('d', 4)];
+        let new = [('b', 2), ('c', 3), ('d', 4), ('a', 1)];
+        assert_eq!(
+            diff(&old, &new),
+            vec![RowOperation::Move { from: 0, to: 3 }]
+        );
+
+        let new = [('d', 4), ('a', 1), ('b', 2), ('c', 3)];
+        assert_eq!(
+            diff(&old, &new),
+            vec![RowOperation::Move { from: 3, to: 0 }]
+        );
+    }
+
+    #[test]
+    fn diff_rows_change() {
+        let old = [('a', 1), ('b', 2), ('c', 3)];
+        let new = [('c', 30), ('a', 10), ('b', 2)];
+        assert_eq!(
+            diff(&old, &new),
+            vec![
+                RowOperation::Move { from: 2, to: 0 },
+                RowOperation::Change { first: 0, last: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_rows_mixed() {
+        let old = [('a', 1), ('b', 2), ('c', 3), ('d', 4), ('e', 5), ('f', 6)];
+        let new = [('f', 6), ('x', 0), ('c', 3), ('a', 10), ('e', 5), ('y', 0)];
+        diff(&old, &new);
+    }
+
+    #[test]
+    fn diff_rows_duplicate_keys() {
+        let old = [('a', 1), ('a', 2)];
+        let new = [('a', 1)];
+        assert!(diff_rows(&old, &new, |row| row.0).is_none());
+        assert!(diff_rows(&new, &old, |row| row.0).is_none());
+    }
+}
@@ -3,6 +3,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod qbrush;
+pub use qbrush::QBrush;
+
 mod qcolor;
 pub use qcolor::{QColor, QColorNameFormat, QColorSpec};
 
@@ -36,6 +39,30 @@ pub use qfont::{
     QFontStyleHint, QFontStyleStrategy,
 };
 
+mod qfontmetricsf;
+pub use qfontmetricsf::QFontMetricsF;
+
+mod qgradient;
+pub use qgradient::{QGradient, QGradientCoordinateMode, QGradientSpread, QGradientType};
+
+mod qlineargradient;
+pub use qlineargradient::QLinearGradient;
+
+mod qradialgradient;
+pub use qradialgradient::QRadialGradient;
+
+mod qmatrix4x4;
+pub use qmatrix4x4::QMatrix4x4;
+
+mod qquaternion;
+pub use qquaternion::QQuaternion;
+
+mod qtransform;
+pub use qtransform::QTransform;
+
+mod qpalette;
+pub use qpalette::{QPalette, QPaletteColorGroup, QPaletteColorRole};
+
 mod qpainterpath;
 pub use qpainterpath::QPainterPath;
 
@@ -43,4 +70,7 @@ mod qpainter;
 pub use qpainter::{QPainter, QPainterCompositionMode, QPainterRenderHint};
 
 mod qregion;
-pub use qregion::QRegion;
+pub use qregion::{QRegion, QRegionRegionType};
+
+mod qvalidator;
+pub use qvalidator::{QValidator, QValidatorState};
@@ -0,0 +1,23 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qbrush.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QBrush has a single d pointer
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qbrush.h?h=v5.15.6-lts-lgpl#n144
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qbrush.h?h=v6.2.4#n120
+assert_alignment_and_size(QBrush, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QBrush>::value);
+static_assert(!::std::is_trivially_copy_constructible<QBrush>::value);
+
+static_assert(!::std::is_trivially_destructible<QBrush>::value);
+
+static_assert(QTypeInfo<QBrush>::isRelocatable);
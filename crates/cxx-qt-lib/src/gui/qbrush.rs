@@ -0,0 +1,177 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type BrushStyle = crate::BrushStyle;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbrush.h");
+        type QBrush = super::QBrush;
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = crate::QColor;
+        include!("cxx-qt-lib/qgradient.h");
+        type QGradient = crate::QGradient;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// Returns the brush color.
+        fn color(self: &QBrush) -> &QColor;
+
+        #[doc(hidden)]
+        #[cxx_name = "gradient"]
+        fn gradient_ptr(self: &QBrush) -> *const QGradient;
+
+        /// Returns true if the brush is fully opaque otherwise false.
+        #[rust_name = "is_opaque"]
+        fn isOpaque(self: &QBrush) -> bool;
+
+        /// Sets the brush color to the given color.
+        ///
+        /// Note that calling set_color() will not make a difference if the brush is a gradient.
+        #[rust_name = "set_color"]
+        fn setColor(self: &mut QBrush, color: &QColor);
+
+        /// Sets the brush style to style.
+        #[rust_name = "set_style"]
+        fn setStyle(self: &mut QBrush, style: BrushStyle);
+
+        /// Returns the brush style.
+        fn style(self: &QBrush) -> BrushStyle;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qbrush_init_default"]
+        fn construct() -> QBrush;
+
+        #[doc(hidden)]
+        #[rust_name = "qbrush_init_from_brushstyle"]
+        fn construct(style: &BrushStyle) -> QBrush;
+
+        #[doc(hidden)]
+        #[rust_name = "qbrush_init_from_qcolor"]
+        fn construct(color: &QColor) -> QBrush;
+
+        #[doc(hidden)]
+        #[rust_name = "qbrush_init_from_qgradient"]
+        fn construct(gradient: &QGradient) -> QBrush;
+
+        #[doc(hidden)]
+        #[rust_name = "qbrush_init_from_qcolor_and_brushstyle"]
+        fn construct(color: &QColor, style: &BrushStyle) -> QBrush;
+
+        #[doc(hidden)]
+        #[rust_name = "qbrush_drop"]
+        fn drop(brush: &mut QBrush);
+
+        #[doc(hidden)]
+        #[rust_name = "qbrush_clone"]
+        fn construct(brush: &QBrush) -> QBrush;
+
+        #[doc(hidden)]
+        #[rust_name = "qbrush_eq"]
+        fn operatorEq(a: &QBrush, b: &QBrush) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qbrush_to_qstring"]
+        fn toQString(value: &QBrush) -> QString;
+    }
+}
+
+/// The QBrush class defines the fill pattern of shapes drawn by QPainter.
+#[repr(C)]
+pub struct QBrush {
+    _d: MaybeUninit<usize>,
+}
+
+impl QBrush {
+    /// Returns the gradient describing this brush, if the brush is a gradient brush.
+    pub fn gradient(&self) -> Option<&ffi::QGradient> {
+        // SAFETY: the gradient is either null or owned by the brush,
+        // so it is valid for as long as the brush is borrowed.
+        unsafe { self.gradient_ptr().as_ref() }
+    }
+
+    /// Constructs a brush with the given color and style.
+    pub fn new(color: &ffi::QColor, style: ffi::BrushStyle) -> Self {
+        ffi::qbrush_init_from_qcolor_and_brushstyle(color, &style)
+    }
+}
+
+impl Default for QBrush {
+    /// Constructs a default black brush with the style Qt::NoBrush (i.e. this brush will not fill shapes).
+    fn default() -> Self {
+        ffi::qbrush_init_default()
+    }
+}
+
+impl Drop for QBrush {
+    fn drop(&mut self) {
+        ffi::qbrush_drop(self);
+    }
+}
+
+impl Clone for QBrush {
+    fn clone(&self) -> Self {
+        ffi::qbrush_clone(self)
+    }
+}
+
+impl PartialEq for QBrush {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qbrush_eq(self, other)
+    }
+}
+
+impl Eq for QBrush {}
+
+impl From<&ffi::QColor> for QBrush {
+    /// Constructs a brush with the given color and the style Qt::SolidPattern.
+    fn from(color: &ffi::QColor) -> Self {
+        ffi::qbrush_init_from_qcolor(color)
+    }
+}
+
+impl From<&ffi::BrushStyle> for QBrush {
+    /// Constructs a black brush with the given style.
+    fn from(style: &ffi::BrushStyle) -> Self {
+        ffi::qbrush_init_from_brushstyle(style)
+    }
+}
+
+impl From<&ffi::QGradient> for QBrush {
+    /// Constructs a brush based on the given gradient.
+    ///
+    /// The brush style is set to the corresponding gradient style,
+    /// such as Qt::LinearGradientPattern for a [QLinearGradient](crate::QLinearGradient).
+    fn from(gradient: &ffi::QGradient) -> Self {
+        ffi::qbrush_init_from_qgradient(gradient)
+    }
+}
+
+impl fmt::Display for QBrush {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qbrush_to_qstring(self))
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QBrush {
+    type Id = type_id!("QBrush");
+    type Kind = cxx::kind::Trivial;
+}
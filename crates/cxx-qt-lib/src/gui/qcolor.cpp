@@ -144,6 +144,16 @@ qcolorInitFromRustString(::rust::Str string)
   return QColor(qstringInitFromRustString(string));
 }
 
+bool
+qcolorIsValidColorName(const QString& name)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 4, 0))
+  return QColor::isValidColorName(name);
+#else
+  return QColor::isValidColor(name);
+#endif
+}
+
 // Qt 5 uses qreal and Qt 6 uses float, so cast all to floats
 
 float
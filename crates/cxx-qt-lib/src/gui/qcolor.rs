@@ -177,6 +177,9 @@ mod ffi {
         #[doc(hidden)]
         #[rust_name = "qcolor_init_from_rust_string"]
         fn qcolorInitFromRustString(string: &str) -> QColor;
+        #[doc(hidden)]
+        #[rust_name = "qcolor_is_valid_color_name"]
+        fn qcolorIsValidColorName(name: &QString) -> bool;
 
         #[doc(hidden)]
         #[rust_name = "qcolor_alpha_f"]
@@ -299,6 +302,29 @@ impl QColor {
         ffi::qcolor_color_names()
     }
 
+    /// Returns this color composited over the background color with the source-over operator,
+    /// which is how a translucent color appears when it is painted over the background.
+    ///
+    /// Both colors are converted to RGB and the alpha of the result is the combined opacity of both colors.
+    pub fn composite_over(&self, background: &QColor) -> Self {
+        let alpha = self.alpha_f();
+        let background_alpha = background.alpha_f() * (1.0 - alpha);
+        let result_alpha = alpha + background_alpha;
+        if result_alpha <= 0.0 {
+            return Self::from_rgba_f(0.0, 0.0, 0.0, 0.0);
+        }
+
+        let channel = |foreground: f32, background: f32| {
+            (foreground * alpha + background * background_alpha) / result_alpha
+        };
+        Self::from_rgba_f(
+            channel(self.red_f(), background.red_f()),
+            channel(self.green_f(), background.green_f()),
+            channel(self.blue_f(), background.blue_f()),
+            result_alpha,
+        )
+    }
+
     /// Returns the cyan color component of this color.
     pub fn cyan_f(&self) -> f32 {
         ffi::qcolor_cyan_f(self)
@@ -420,6 +446,12 @@ impl QColor {
         ffi::qcolor_hue_f(self)
     }
 
+    /// Returns true if the name can be parsed as a color, such as `#RRGGBB` or one of the
+    /// SVG color keyword names that are listed by [QColor::color_names], otherwise returns false.
+    pub fn is_valid_color_name(name: &ffi::QString) -> bool {
+        ffi::qcolor_is_valid_color_name(name)
+    }
+
     /// Returns the lightness color component of this color.
     pub fn lightness_f(&self) -> f32 {
         ffi::qcolor_lightness_f(self)
@@ -430,6 +462,21 @@ impl QColor {
         ffi::qcolor_magenta_f(self)
     }
 
+    /// Returns a color which is linearly interpolated between this color and the other color,
+    /// including the alpha, where a factor of 0.0 returns this color and 1.0 returns the other color.
+    ///
+    /// Both colors are converted to RGB and the factor is clamped to the range 0.0-1.0.
+    pub fn mix(&self, other: &QColor, factor: f32) -> Self {
+        let factor = factor.clamp(0.0, 1.0);
+        let channel = |from: f32, to: f32| from + (to - from) * factor;
+        Self::from_rgba_f(
+            channel(self.red_f(), other.red_f()),
+            channel(self.green_f(), other.green_f()),
+            channel(self.blue_f(), other.blue_f()),
+            channel(self.alpha_f(), other.alpha_f()),
+        )
+    }
+
     /// Returns the red color component of this color.
     pub fn red_f(&self) -> f32 {
         ffi::qcolor_red_f(self)
@@ -501,6 +548,20 @@ impl QColor {
         ffi::qcolor_value_f(self)
     }
 
+    /// Returns a copy of this color with the alpha set to alpha, which is specified in the range 0-255.
+    pub fn with_alpha(&self, alpha: i32) -> Self {
+        let mut color = self.clone();
+        color.set_alpha(alpha);
+        color
+    }
+
+    /// Returns a copy of this color with the alpha set to alpha, which is specified in the range 0.0-1.0.
+    pub fn with_alpha_f(&self, alpha: f32) -> Self {
+        let mut color = self.clone();
+        color.set_alpha_f(alpha);
+        color
+    }
+
     /// Returns the yellow color component of this color.
     pub fn yellow_f(self: &QColor) -> f32 {
         ffi::qcolor_yellow_f(self)
@@ -632,6 +693,39 @@ impl From<&QColor> for rgb::RGBA8 {
     }
 }
 
+#[cfg(feature = "palette")]
+impl From<&palette::Srgb> for QColor {
+    fn from(value: &palette::Srgb) -> Self {
+        Self::from_rgb_f(value.red, value.green, value.blue)
+    }
+}
+
+#[cfg(feature = "palette")]
+impl From<&palette::Srgba> for QColor {
+    fn from(value: &palette::Srgba) -> Self {
+        Self::from_rgba_f(value.red, value.green, value.blue, value.alpha)
+    }
+}
+
+#[cfg(feature = "palette")]
+impl From<&QColor> for palette::Srgb {
+    fn from(value: &QColor) -> Self {
+        Self::new(value.red_f(), value.green_f(), value.blue_f())
+    }
+}
+
+#[cfg(feature = "palette")]
+impl From<&QColor> for palette::Srgba {
+    fn from(value: &QColor) -> Self {
+        Self::new(
+            value.red_f(),
+            value.green_f(),
+            value.blue_f(),
+            value.alpha_f(),
+        )
+    }
+}
+
 // Safety:
 //
 // Static checks on the C++ side to ensure the size is the same.
@@ -642,7 +736,7 @@ unsafe impl ExternType for QColor {
 
 #[cfg(test)]
 mod tests {
-    #[cfg(feature = "rgb")]
+    #[cfg(any(feature = "palette", feature = "rgb"))]
     use super::*;
 
     #[cfg(feature = "rgb")]
@@ -681,4 +775,30 @@ mod tests {
         let rgba_color = rgb::RGBA8::from(&qcolor);
         assert_eq!(color, rgba_color);
     }
+
+    #[cfg(feature = "palette")]
+    #[test]
+    fn test_palette_srgb() {
+        let color = palette::Srgb::new(0.0, 0.4, 1.0);
+        let qcolor = QColor::from(&color);
+        assert_eq!(qcolor.red(), 0);
+        assert_eq!(qcolor.green(), 102);
+        assert_eq!(qcolor.blue(), 255);
+        assert_eq!(qcolor.alpha(), 255);
+
+        let srgb_color = palette::Srgb::from(&qcolor);
+        assert!((srgb_color.green - color.green).abs() < 0.001);
+    }
+
+    #[cfg(feature = "palette")]
+    #[test]
+    fn test_palette_srgba() {
+        let color = palette::Srgba::new(0.0, 0.4, 1.0, 0.4);
+        let qcolor = QColor::from(&color);
+        assert_eq!(qcolor.green(), 102);
+        assert_eq!(qcolor.alpha(), 102);
+
+        let srgba_color = palette::Srgba::from(&qcolor);
+        assert!((srgba_color.alpha - color.alpha).abs() < 0.001);
+    }
 }
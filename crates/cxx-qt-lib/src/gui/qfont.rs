@@ -219,6 +219,10 @@ mod ffi {
         #[rust_name = "point_size"]
         fn pointSize(self: &QFont) -> i32;
 
+        /// Returns the point size of the font, or -1 if the font size was specified in pixels.
+        #[rust_name = "point_size_f"]
+        fn pointSizeF(self: &QFont) -> f64;
+
         /// Returns a new QFont that has attributes copied from other that have not been previously set on this font.
         fn resolve(self: &QFont, other: &QFont) -> QFont;
 
@@ -271,6 +275,15 @@ mod ffi {
         #[rust_name = "set_pixel_size"]
         fn setPixelSize(self: &mut QFont, pixelSize: i32);
 
+        /// Sets the point size to pointSize. The point size must be greater than zero.
+        #[rust_name = "set_point_size"]
+        fn setPointSize(self: &mut QFont, pointSize: i32);
+
+        /// Sets the point size to pointSize. The point size must be greater than zero.
+        /// The requested precision may not be achieved on all platforms.
+        #[rust_name = "set_point_size_f"]
+        fn setPointSizeF(self: &mut QFont, pointSize: f64);
+
         /// Sets the stretch factor for the font.
         #[rust_name = "set_stretch"]
         fn setStretch(self: &mut QFont, factor: i32);
@@ -342,6 +355,10 @@ mod ffi {
         #[rust_name = "qfont_init_default"]
         fn construct() -> QFont;
 
+        #[doc(hidden)]
+        #[rust_name = "qfont_init_from_family"]
+        fn construct(family: &QString, point_size: &i32) -> QFont;
+
         #[doc(hidden)]
         #[rust_name = "qfont_drop"]
         fn drop(pen: &mut QFont);
@@ -405,6 +422,12 @@ impl PartialEq for QFont {
 impl Eq for QFont {}
 
 impl QFont {
+    /// Constructs a font object with the specified family and point size,
+    /// a point size of -1 uses the point size of the application font.
+    pub fn new(family: &ffi::QString, point_size: i32) -> Self {
+        ffi::qfont_init_from_family(family, &point_size)
+    }
+
     /// Returns the bounding rectangle of the current clip if there is a clip;
     /// otherwise returns `None`. Note that the clip region is given in logical coordinates.
     pub fn family(&self) -> Option<ffi::QString> {
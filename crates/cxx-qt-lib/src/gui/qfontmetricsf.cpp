@@ -0,0 +1,51 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qfontmetricsf.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QFontMetricsF has a single d pointer
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/text/qfontmetrics.h?h=v5.15.6-lts-lgpl#n222
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/text/qfontmetrics.h?h=v6.2.4#n192
+assert_alignment_and_size(QFontMetricsF, { ::std::size_t a0; });
+
+static_assert(!::std::is_trivially_copy_assignable<QFontMetricsF>::value);
+static_assert(!::std::is_trivially_copy_constructible<QFontMetricsF>::value);
+
+static_assert(!::std::is_trivially_destructible<QFontMetricsF>::value);
+
+static_assert(QTypeInfo<QFontMetricsF>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QString
+qfontmetricsfElidedText(const QFontMetricsF& metrics,
+                        const QString& text,
+                        Qt::TextElideMode mode,
+                        qreal width)
+{
+  return metrics.elidedText(text, mode, width);
+}
+
+qreal
+qfontmetricsfHorizontalAdvance(const QFontMetricsF& metrics,
+                               const QString& text)
+{
+  return metrics.horizontalAdvance(text);
+}
+
+QSizeF
+qfontmetricsfSize(const QFontMetricsF& metrics, const QString& text)
+{
+  return metrics.size(0, text);
+}
+
+}
+}
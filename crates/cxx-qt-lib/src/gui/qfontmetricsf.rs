@@ -0,0 +1,186 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type TextElideMode = crate::TextElideMode;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qfontmetricsf.h");
+        type QFontMetricsF = super::QFontMetricsF;
+        include!("cxx-qt-lib/qfont.h");
+        type QFont = crate::QFont;
+        include!("cxx-qt-lib/qrectf.h");
+        type QRectF = crate::QRectF;
+        include!("cxx-qt-lib/qsizef.h");
+        type QSizeF = crate::QSizeF;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// Returns the ascent of the font, which is the distance from the baseline
+        /// to the highest position characters extend to.
+        fn ascent(self: &QFontMetricsF) -> f64;
+
+        /// Returns the average width of glyphs in the font.
+        #[rust_name = "average_char_width"]
+        fn averageCharWidth(self: &QFontMetricsF) -> f64;
+
+        /// Returns the bounding rectangle of the characters in the given text,
+        /// relative to the left-most point on the baseline.
+        ///
+        /// Note that the bounding rectangle may extend to the left of (0, 0) and its width
+        /// may be different than the advance given by [QFontMetricsF::horizontal_advance].
+        #[rust_name = "bounding_rect"]
+        fn boundingRect(self: &QFontMetricsF, text: &QString) -> QRectF;
+
+        /// Returns the cap height of the font, which is the height of capital letters above the baseline.
+        #[rust_name = "cap_height"]
+        fn capHeight(self: &QFontMetricsF) -> f64;
+
+        /// Returns the descent of the font, which is the distance from the baseline
+        /// to the lowest point characters extend to.
+        fn descent(self: &QFontMetricsF) -> f64;
+
+        /// Returns the height of the font, which is always equal to ascent() + descent().
+        fn height(self: &QFontMetricsF) -> f64;
+
+        /// Returns the leading of the font, which is the natural inter-line spacing.
+        fn leading(self: &QFontMetricsF) -> f64;
+
+        /// Returns the distance from one base line to the next, which is leading() + height().
+        #[rust_name = "line_spacing"]
+        fn lineSpacing(self: &QFontMetricsF) -> f64;
+
+        /// Returns the width of the widest character in the font.
+        #[rust_name = "max_width"]
+        fn maxWidth(self: &QFontMetricsF) -> f64;
+
+        /// Returns a tight bounding rectangle around the characters in the given text,
+        /// relative to the left-most point on the baseline.
+        ///
+        /// Note that this is slower than [QFontMetricsF::bounding_rect].
+        #[rust_name = "tight_bounding_rect"]
+        fn tightBoundingRect(self: &QFontMetricsF, text: &QString) -> QRectF;
+
+        /// Returns the x height of the font, which is often but not always the same as the height of the character 'x'.
+        #[rust_name = "x_height"]
+        fn xHeight(self: &QFontMetricsF) -> f64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qfontmetricsf_elided_text"]
+        fn qfontmetricsfElidedText(
+            metrics: &QFontMetricsF,
+            text: &QString,
+            mode: TextElideMode,
+            width: f64,
+        ) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "qfontmetricsf_horizontal_advance"]
+        fn qfontmetricsfHorizontalAdvance(metrics: &QFontMetricsF, text: &QString) -> f64;
+
+        #[doc(hidden)]
+        #[rust_name = "qfontmetricsf_size"]
+        fn qfontmetricsfSize(metrics: &QFontMetricsF, text: &QString) -> QSizeF;
+
+        #[doc(hidden)]
+        #[rust_name = "qfontmetricsf_init_from_qfont"]
+        fn construct(font: &QFont) -> QFontMetricsF;
+
+        #[doc(hidden)]
+        #[rust_name = "qfontmetricsf_drop"]
+        fn drop(metrics: &mut QFontMetricsF);
+
+        #[doc(hidden)]
+        #[rust_name = "qfontmetricsf_clone"]
+        fn construct(metrics: &QFontMetricsF) -> QFontMetricsF;
+
+        #[doc(hidden)]
+        #[rust_name = "qfontmetricsf_eq"]
+        fn operatorEq(a: &QFontMetricsF, b: &QFontMetricsF) -> bool;
+    }
+}
+
+/// The QFontMetricsF class provides font metrics information,
+/// which allows for text to be measured the way that Qt renders it.
+///
+/// ```ignore
+/// let metrics = QFontMetricsF::new(&font);
+/// let text = metrics.elided_text(&QString::from("Some long text"), TextElideMode::ElideRight, 100.0);
+/// ```
+#[repr(C)]
+pub struct QFontMetricsF {
+    _d: MaybeUninit<usize>,
+}
+
+impl QFontMetricsF {
+    /// Constructs font metrics for the given font, which are compatible with the paint device
+    /// that was used to create the font.
+    pub fn new(font: &ffi::QFont) -> Self {
+        ffi::qfontmetricsf_init_from_qfont(font)
+    }
+
+    /// Returns text elided with the given mode if it is wider than width,
+    /// otherwise the text is returned unchanged.
+    pub fn elided_text(
+        &self,
+        text: &ffi::QString,
+        mode: ffi::TextElideMode,
+        width: f64,
+    ) -> ffi::QString {
+        ffi::qfontmetricsf_elided_text(self, text, mode, width)
+    }
+
+    /// Returns the horizontal advance of the given text, which is the distance
+    /// to the position where the next string should be drawn.
+    pub fn horizontal_advance(&self, text: &ffi::QString) -> f64 {
+        ffi::qfontmetricsf_horizontal_advance(self, text)
+    }
+
+    /// Returns the size in pixels of the characters in the given text,
+    /// where newline characters in the text result in multiple lines.
+    pub fn size(&self, text: &ffi::QString) -> ffi::QSizeF {
+        ffi::qfontmetricsf_size(self, text)
+    }
+}
+
+impl Drop for QFontMetricsF {
+    fn drop(&mut self) {
+        ffi::qfontmetricsf_drop(self);
+    }
+}
+
+impl Clone for QFontMetricsF {
+    fn clone(&self) -> Self {
+        ffi::qfontmetricsf_clone(self)
+    }
+}
+
+impl PartialEq for QFontMetricsF {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qfontmetricsf_eq(self, other)
+    }
+}
+
+impl Eq for QFontMetricsF {}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QFontMetricsF {
+    type Id = type_id!("QFontMetricsF");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,104 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt-lib/qgradient.h"
+
+// QLinearGradient and QRadialGradient do not add any members to QGradient,
+// so they can be used in place of a QGradient.
+static_assert(sizeof(QLinearGradient) == sizeof(QGradient));
+static_assert(sizeof(QRadialGradient) == sizeof(QGradient));
+
+namespace rust {
+namespace cxxqtlib1 {
+
+void
+qgradientClearStops(QGradient& gradient)
+{
+  gradient.setStops(QGradientStops());
+}
+
+::std::unique_ptr<QGradient>
+qgradientClone(const QGradient& gradient)
+{
+  return ::std::make_unique<QGradient>(gradient);
+}
+
+QColor
+qgradientStopColor(const QGradient& gradient, ::rust::isize index)
+{
+  Q_ASSERT(index >= 0);
+  Q_ASSERT(index < gradient.stops().size());
+  return gradient.stops().at(index).second;
+}
+
+::rust::isize
+qgradientStopCount(const QGradient& gradient)
+{
+  return static_cast<::rust::isize>(gradient.stops().size());
+}
+
+double
+qgradientStopPosition(const QGradient& gradient, ::rust::isize index)
+{
+  Q_ASSERT(index >= 0);
+  Q_ASSERT(index < gradient.stops().size());
+  return static_cast<double>(gradient.stops().at(index).first);
+}
+
+const QGradient&
+qlineargradientAsQGradient(const QLinearGradient& gradient)
+{
+  return gradient;
+}
+
+QGradient&
+qlineargradientAsQGradientMut(QLinearGradient& gradient)
+{
+  return gradient;
+}
+
+::std::unique_ptr<QLinearGradient>
+qlineargradientClone(const QLinearGradient& gradient)
+{
+  return ::std::make_unique<QLinearGradient>(gradient);
+}
+
+::std::unique_ptr<QLinearGradient>
+qlineargradientNew(const QPointF& start, const QPointF& finalStop)
+{
+  return ::std::make_unique<QLinearGradient>(start, finalStop);
+}
+
+const QGradient&
+qradialgradientAsQGradient(const QRadialGradient& gradient)
+{
+  return gradient;
+}
+
+QGradient&
+qradialgradientAsQGradientMut(QRadialGradient& gradient)
+{
+  return gradient;
+}
+
+::std::unique_ptr<QRadialGradient>
+qradialgradientClone(const QRadialGradient& gradient)
+{
+  return ::std::make_unique<QRadialGradient>(gradient);
+}
+
+::std::unique_ptr<QRadialGradient>
+qradialgradientNew(const QPointF& center,
+                   double centerRadius,
+                   const QPointF& focalPoint,
+                   double focalRadius)
+{
+  return ::std::make_unique<QRadialGradient>(
+    center, centerRadius, focalPoint, focalRadius);
+}
+
+}
+}
@@ -0,0 +1,172 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum specifies how the coordinates of a gradient are interpreted.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QGradientCoordinateMode {
+        /// This is the default mode. The gradient coordinates are specified logical space just like the object coordinates.
+        LogicalMode,
+        /// In this mode the gradient coordinates are relative to the bounding rectangle of the paint device,
+        /// with (0,0) in the top left corner, and (1,1) in the bottom right corner of the paint device.
+        StretchToDeviceMode,
+        /// In this mode the gradient coordinates are relative to the bounding rectangle of the object being drawn,
+        /// with (0,0) in the top left corner, and (1,1) in the bottom right corner of the object's bounding rectangle.
+        ObjectBoundingMode,
+        /// In this mode the gradient coordinates are relative to the bounding rectangle of the object being drawn,
+        /// with (0,0) in the top left corner, and (1,1) in the bottom right corner of the object's bounding rectangle.
+        /// This value is equivalent to ObjectBoundingMode except that the brush transform is also relative to the object.
+        ObjectMode,
+    }
+
+    /// Specifies how the area outside the gradient area should be filled.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QGradientSpread {
+        /// The area is filled with the closest stop color. This is the default.
+        PadSpread,
+        /// The gradient is reflected outside the gradient area.
+        ReflectSpread,
+        /// The gradient is repeated outside the gradient area.
+        RepeatSpread,
+    }
+
+    /// Specifies the type of gradient.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QGradientType {
+        /// Interpolates colors between start and end points.
+        LinearGradient,
+        /// Interpolate colors between a focal point and end points on a circle surrounding it.
+        RadialGradient,
+        /// Interpolate colors around a center point.
+        ConicalGradient,
+        /// No gradient is used.
+        NoGradient,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = crate::QColor;
+
+        include!("cxx-qt-lib/qgradient.h");
+        /// The QGradient class is used in combination with QBrush to specify gradient fills.
+        ///
+        /// This is the common base of [QLinearGradient](crate::QLinearGradient) and
+        /// [QRadialGradient](crate::QRadialGradient), which can be used as a QGradient with their
+        /// `as_gradient` and `as_gradient_mut` methods.
+        //
+        // QGradient contains a list of stops but is not declared as relocatable by Qt,
+        // so use an opaque type.
+        type QGradient;
+
+        /// Returns the coordinate mode of this gradient. The default mode is LogicalMode.
+        #[rust_name = "coordinate_mode"]
+        fn coordinateMode(self: &QGradient) -> QGradientCoordinateMode;
+
+        /// Creates a stop point at the given position with the given color. The given position must be in the range 0 to 1.
+        #[rust_name = "set_color_at"]
+        fn setColorAt(self: Pin<&mut QGradient>, position: f64, color: &QColor);
+
+        /// Sets the coordinate mode of this gradient to mode. The default mode is LogicalMode.
+        #[rust_name = "set_coordinate_mode"]
+        fn setCoordinateMode(self: Pin<&mut QGradient>, mode: QGradientCoordinateMode);
+
+        /// Specifies the spread method that should be used for this gradient.
+        ///
+        /// Note that this function only has effect for linear and radial gradients.
+        #[rust_name = "set_spread"]
+        fn setSpread(self: Pin<&mut QGradient>, spread: QGradientSpread);
+
+        /// Returns the spread method use by this gradient. The default is PadSpread.
+        fn spread(self: &QGradient) -> QGradientSpread;
+
+        /// Returns the type of gradient.
+        #[cxx_name = "type"]
+        fn gradient_type(self: &QGradient) -> QGradientType;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QGradientCoordinateMode;
+        type QGradientSpread;
+        type QGradientType;
+
+        #[doc(hidden)]
+        #[rust_name = "qgradient_clear_stops"]
+        fn qgradientClearStops(gradient: Pin<&mut QGradient>);
+        #[doc(hidden)]
+        #[rust_name = "qgradient_clone"]
+        fn qgradientClone(gradient: &QGradient) -> UniquePtr<QGradient>;
+        #[doc(hidden)]
+        #[rust_name = "qgradient_stop_color"]
+        fn qgradientStopColor(gradient: &QGradient, index: isize) -> QColor;
+        #[doc(hidden)]
+        #[rust_name = "qgradient_stop_count"]
+        fn qgradientStopCount(gradient: &QGradient) -> isize;
+        #[doc(hidden)]
+        #[rust_name = "qgradient_stop_position"]
+        fn qgradientStopPosition(gradient: &QGradient, index: isize) -> f64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qgradient_eq"]
+        fn operatorEq(a: &QGradient, b: &QGradient) -> bool;
+    }
+
+    // QGradient is not declared as relocatable by Qt, so use an opaque type.
+    impl UniquePtr<QGradient> {}
+}
+
+pub use ffi::{QGradient, QGradientCoordinateMode, QGradientSpread, QGradientType};
+
+impl QGradient {
+    /// Replaces the current set of stop points with the given stop points,
+    /// which are pairs of a position in the range 0 to 1 and a color.
+    pub fn set_stops(mut self: Pin<&mut Self>, stops: &[(f64, ffi::QColor)]) {
+        ffi::qgradient_clear_stops(self.as_mut());
+        for (position, color) in stops {
+            self.as_mut().set_color_at(*position, color);
+        }
+    }
+
+    /// Returns the stop points for this gradient, which are pairs of a position and a color,
+    /// sorted by their position.
+    ///
+    /// If no stop points have been specified, a gradient of black at 0 to white at 1 is used.
+    pub fn stops(&self) -> Vec<(f64, ffi::QColor)> {
+        (0..ffi::qgradient_stop_count(self))
+            .map(|index| {
+                (
+                    ffi::qgradient_stop_position(self, index),
+                    ffi::qgradient_stop_color(self, index),
+                )
+            })
+            .collect()
+    }
+
+    /// Copy constructor, create a copy of the QGradient.
+    pub fn to_owned(&self) -> cxx::UniquePtr<Self> {
+        ffi::qgradient_clone(self)
+    }
+}
+
+impl std::cmp::PartialEq for QGradient {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qgradient_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QGradient {}
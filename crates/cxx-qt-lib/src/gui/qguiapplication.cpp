@@ -41,5 +41,17 @@ qguiapplicationFont(const QGuiApplication& app)
   return app.font();
 }
 
+QPalette
+qguiapplicationPalette(const QGuiApplication& app)
+{
+  return app.palette();
+}
+
+void
+qguiapplicationSetPalette(QGuiApplication& app, const QPalette& palette)
+{
+  app.setPalette(palette);
+}
+
 }
 }
@@ -4,11 +4,29 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::{QByteArray, QFont, QString, QStringList, QVector};
+use crate::{QByteArray, QFont, QPalette, QString, QStringList, QVector};
 use core::pin::Pin;
 
-#[cxx::bridge]
+#[cxx_qt::bridge]
 mod ffi {
+    #[helper_namespace = "cxx_qt_lib"]
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qguiapplication.h");
+        #[qobject]
+        type QGuiApplication;
+
+        /// This signal is emitted when the state of the application changes.
+        #[qsignal]
+        #[cxx_name = "applicationStateChanged"]
+        fn application_state_changed(self: Pin<&mut QGuiApplication>, state: ApplicationState);
+    }
+
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type ApplicationState = crate::ApplicationState;
+    }
+
     unsafe extern "C++" {
         include!("cxx-qt-lib/qbytearray.h");
         type QByteArray = crate::QByteArray;
@@ -20,9 +38,8 @@ mod ffi {
         type QVector_QByteArray = crate::QVector<QByteArray>;
         include!("cxx-qt-lib/qfont.h");
         type QFont = crate::QFont;
-
-        include!("cxx-qt-lib/qguiapplication.h");
-        type QGuiApplication;
+        include!("cxx-qt-lib/qpalette.h");
+        type QPalette = crate::QPalette;
     }
 
     #[namespace = "rust::cxxqtlib1"]
@@ -75,6 +92,12 @@ mod ffi {
         #[rust_name = "qguiapplication_font"]
         fn qguiapplicationFont(app: &QGuiApplication) -> QFont;
         #[doc(hidden)]
+        #[rust_name = "qguiapplication_palette"]
+        fn qguiapplicationPalette(app: &QGuiApplication) -> QPalette;
+        #[doc(hidden)]
+        #[rust_name = "qguiapplication_set_palette"]
+        fn qguiapplicationSetPalette(app: Pin<&mut QGuiApplication>, palette: &QPalette);
+        #[doc(hidden)]
         #[rust_name = "qguiapplication_set_library_paths"]
         fn qapplicationSetLibraryPaths(app: Pin<&mut QGuiApplication>, paths: &QStringList);
         #[doc(hidden)]
@@ -153,6 +176,12 @@ impl QGuiApplication {
         ffi::qguiapplication_new(&vector)
     }
 
+    /// Returns the current application palette,
+    /// which is the platform palette unless it has been changed with [QGuiApplication::set_palette].
+    pub fn palette(&self) -> QPalette {
+        ffi::qguiapplication_palette(self)
+    }
+
     /// The Internet domain of the organization that wrote this application
     pub fn organization_domain(&self) -> QString {
         ffi::qguiapplication_organization_domain(self)
@@ -183,6 +212,11 @@ impl QGuiApplication {
         ffi::qguiapplication_set_font(self, font);
     }
 
+    /// Changes the application palette to palette, the colors of QML controls follow the application palette.
+    pub fn set_palette(self: Pin<&mut Self>, palette: &QPalette) {
+        ffi::qguiapplication_set_palette(self, palette);
+    }
+
     /// Sets the list of directories to search when loading plugins with QLibrary to paths.
     /// All existing paths will be deleted and the path list will consist of the paths given in paths and the path to the application.
     pub fn set_library_paths(self: Pin<&mut Self>, paths: &QStringList) {
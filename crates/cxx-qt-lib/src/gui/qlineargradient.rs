@@ -0,0 +1,104 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qgradient.h");
+        type QGradient = crate::QGradient;
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = crate::QPointF;
+
+        /// The QLinearGradient class is used in combination with QBrush to specify a linear gradient brush.
+        ///
+        /// Linear gradients interpolate colors between start and end points, outside these points
+        /// the gradient is either padded, reflected or repeated depending on the currently set spread.
+        //
+        // QLinearGradient is not declared as relocatable by Qt, so use an opaque type.
+        type QLinearGradient;
+
+        /// Returns the final stop point of this linear gradient in logical coordinates.
+        #[rust_name = "final_stop"]
+        fn finalStop(self: &QLinearGradient) -> QPointF;
+
+        /// Sets the final stop point of this linear gradient in logical coordinates to stop.
+        #[rust_name = "set_final_stop"]
+        fn setFinalStop(self: Pin<&mut QLinearGradient>, stop: &QPointF);
+
+        /// Sets the start point of this linear gradient in logical coordinates to start.
+        #[rust_name = "set_start"]
+        fn setStart(self: Pin<&mut QLinearGradient>, start: &QPointF);
+
+        /// Returns the start point of this linear gradient in logical coordinates.
+        fn start(self: &QLinearGradient) -> QPointF;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qlineargradient_as_qgradient"]
+        fn qlineargradientAsQGradient(gradient: &QLinearGradient) -> &QGradient;
+        #[doc(hidden)]
+        #[rust_name = "qlineargradient_as_qgradient_mut"]
+        fn qlineargradientAsQGradientMut(
+            gradient: Pin<&mut QLinearGradient>,
+        ) -> Pin<&mut QGradient>;
+        #[doc(hidden)]
+        #[rust_name = "qlineargradient_clone"]
+        fn qlineargradientClone(gradient: &QLinearGradient) -> UniquePtr<QLinearGradient>;
+        #[doc(hidden)]
+        #[rust_name = "qlineargradient_new"]
+        fn qlineargradientNew(start: &QPointF, final_stop: &QPointF) -> UniquePtr<QLinearGradient>;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qlineargradient_eq"]
+        fn operatorEq(a: &QLinearGradient, b: &QLinearGradient) -> bool;
+    }
+
+    // QLinearGradient is not declared as relocatable by Qt, so use an opaque type.
+    impl UniquePtr<QLinearGradient> {}
+}
+
+pub use ffi::QLinearGradient;
+
+impl QLinearGradient {
+    /// Returns this linear gradient as a [QGradient](crate::QGradient),
+    /// which has the stop points and spread of the gradient.
+    pub fn as_gradient(&self) -> &ffi::QGradient {
+        ffi::qlineargradient_as_qgradient(self)
+    }
+
+    /// Returns this linear gradient as a mutable [QGradient](crate::QGradient),
+    /// which can be used to set the stop points and spread of the gradient.
+    pub fn as_gradient_mut(self: Pin<&mut Self>) -> Pin<&mut ffi::QGradient> {
+        ffi::qlineargradient_as_qgradient_mut(self)
+    }
+
+    /// Constructs a linear gradient with interpolation area between the given start point and final stop.
+    ///
+    /// Note that the coordinates are in logical coordinates, unless the coordinate mode is changed.
+    pub fn new(start: &ffi::QPointF, final_stop: &ffi::QPointF) -> cxx::UniquePtr<Self> {
+        ffi::qlineargradient_new(start, final_stop)
+    }
+
+    /// Copy constructor, create a copy of the QLinearGradient.
+    pub fn to_owned(&self) -> cxx::UniquePtr<Self> {
+        ffi::qlineargradient_clone(self)
+    }
+}
+
+impl std::cmp::PartialEq for QLinearGradient {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qlineargradient_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QLinearGradient {}
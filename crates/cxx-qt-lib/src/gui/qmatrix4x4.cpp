@@ -0,0 +1,41 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qmatrix4x4.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QMatrix4x4 has a 4x4 array of floats in column-major order and the flag bits
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/math3d/qmatrix4x4.h?h=v5.15.6-lts-lgpl#n190
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/math3d/qmatrix4x4.h?h=v6.2.4#n183
+assert_alignment_and_size(QMatrix4x4, {
+  float a0[16];
+  ::std::int32_t a1;
+});
+
+static_assert(::std::is_trivially_copyable<QMatrix4x4>::value,
+              "QMatrix4x4 should be trivially copyable");
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QMatrix4x4
+qmatrix4x4FromRowMajor(::rust::Slice<const float> values)
+{
+  Q_ASSERT(values.size() == 16);
+  return QMatrix4x4(values.data());
+}
+
+QMatrix4x4
+qmatrix4x4Inverted(const QMatrix4x4& matrix, bool& invertible)
+{
+  return matrix.inverted(&invertible);
+}
+
+}
+}
@@ -0,0 +1,286 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+use std::fmt;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmatrix4x4.h");
+        type QMatrix4x4 = super::QMatrix4x4;
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = crate::QPointF;
+        include!("cxx-qt-lib/qquaternion.h");
+        type QQuaternion = crate::QQuaternion;
+        include!("cxx-qt-lib/qrectf.h");
+        type QRectF = crate::QRectF;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qtransform.h");
+        type QTransform = crate::QTransform;
+        include!("cxx-qt-lib/qvector3d.h");
+        type QVector3D = crate::QVector3D;
+        include!("cxx-qt-lib/qvector4d.h");
+        type QVector4D = crate::QVector4D;
+
+        /// Returns the elements of column index as a 4D vector.
+        fn column(self: &QMatrix4x4, index: i32) -> QVector4D;
+
+        /// Returns the determinant of this matrix.
+        fn determinant(self: &QMatrix4x4) -> f64;
+
+        /// Returns true if this matrix is affine matrix, otherwise returns false.
+        #[rust_name = "is_affine"]
+        fn isAffine(self: &QMatrix4x4) -> bool;
+
+        /// Returns true if this matrix is the identity, otherwise returns false.
+        #[rust_name = "is_identity"]
+        fn isIdentity(self: &QMatrix4x4) -> bool;
+
+        /// Multiplies this matrix by a viewing matrix derived from an eye point.
+        ///
+        /// The center value indicates the center of the view that the eye is looking at.
+        /// The up value indicates which direction should be considered up with respect to the eye.
+        #[rust_name = "look_at"]
+        fn lookAt(self: &mut QMatrix4x4, eye: &QVector3D, center: &QVector3D, up: &QVector3D);
+
+        /// Maps point by multiplying this matrix by point. The matrix is applied pre-point.
+        fn map(self: &QMatrix4x4, point: &QPointF) -> QPointF;
+
+        /// Maps rect by multiplying this matrix by the corners of rect and then forming a new rectangle from the results.
+        /// The returned rectangle will be an ordinary 2D rectangle with sides parallel to the horizontal and vertical axes.
+        #[rust_name = "map_rect"]
+        fn mapRect(self: &QMatrix4x4, rectangle: &QRectF) -> QRectF;
+
+        /// Maps point by multiplying this matrix by point extended to a 4D vector by assuming 1.0 for the w coordinate.
+        /// The matrix is applied pre-point.
+        #[rust_name = "map_vector3d"]
+        fn map(self: &QMatrix4x4, point: &QVector3D) -> QVector3D;
+
+        /// Maps point by multiplying this matrix by point. The matrix is applied pre-point.
+        #[rust_name = "map_vector4d"]
+        fn map(self: &QMatrix4x4, point: &QVector4D) -> QVector4D;
+
+        /// Multiplies this matrix by another that applies an orthographic projection for a window
+        /// with lower-left corner (left, bottom), upper-right corner (right, top),
+        /// and the specified near_plane and far_plane clipping planes.
+        fn ortho(
+            self: &mut QMatrix4x4,
+            left: f32,
+            right: f32,
+            bottom: f32,
+            top: f32,
+            near_plane: f32,
+            far_plane: f32,
+        );
+
+        /// Multiplies this matrix by another that applies a perspective projection.
+        ///
+        /// The vertical field of view will be vertical_angle degrees within a window with a given aspect ratio
+        /// that determines the horizontal field of view. The projection will have the specified near_plane
+        /// and far_plane clipping planes which are the distances from the viewer to the corresponding planes.
+        fn perspective(
+            self: &mut QMatrix4x4,
+            vertical_angle: f32,
+            aspect_ratio: f32,
+            near_plane: f32,
+            far_plane: f32,
+        );
+
+        /// Multiples this matrix by another that rotates coordinates through angle degrees about the vector (x, y, z).
+        fn rotate(self: &mut QMatrix4x4, angle: f32, x: f32, y: f32, z: f32);
+
+        /// Multiples this matrix by another that rotates coordinates according to a specified quaternion.
+        #[rust_name = "rotate_quaternion"]
+        fn rotate(self: &mut QMatrix4x4, quaternion: &QQuaternion);
+
+        /// Returns the elements of row index as a 4D vector.
+        fn row(self: &QMatrix4x4, index: i32) -> QVector4D;
+
+        /// Multiplies this matrix by another that scales coordinates by the components x, y, and z.
+        fn scale(self: &mut QMatrix4x4, x: f32, y: f32, z: f32);
+
+        /// Sets this matrix to the identity.
+        #[rust_name = "set_to_identity"]
+        fn setToIdentity(self: &mut QMatrix4x4);
+
+        /// Returns the conventional Qt 2D transformation matrix that corresponds to this matrix.
+        ///
+        /// The returned QTransform is formed by simply dropping the third row and third column of the QMatrix4x4.
+        /// This is suitable for implementing orthographic projections where the z coordinate should be dropped rather than projected.
+        #[rust_name = "to_transform"]
+        fn toTransform(self: &QMatrix4x4) -> QTransform;
+
+        /// Multiplies this matrix by another that translates coordinates by the components x, y, and z.
+        fn translate(self: &mut QMatrix4x4, x: f32, y: f32, z: f32);
+
+        /// Returns this matrix, transposed about its diagonal.
+        fn transposed(self: &QMatrix4x4) -> QMatrix4x4;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_from_row_major"]
+        fn qmatrix4x4FromRowMajor(values: &[f32]) -> QMatrix4x4;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_inverted"]
+        fn qmatrix4x4Inverted(matrix: &QMatrix4x4, invertible: &mut bool) -> QMatrix4x4;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_init_default"]
+        fn construct() -> QMatrix4x4;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_init_qtransform"]
+        fn construct(transform: &QTransform) -> QMatrix4x4;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_eq"]
+        fn operatorEq(a: &QMatrix4x4, b: &QMatrix4x4) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_mul"]
+        fn operatorMul(a: QMatrix4x4, b: &QMatrix4x4) -> QMatrix4x4;
+        #[doc(hidden)]
+        #[rust_name = "qmatrix4x4_to_qstring"]
+        fn toQString(value: &QMatrix4x4) -> QString;
+    }
+}
+
+/// The QMatrix4x4 class represents a 4x4 transformation matrix in 3D space.
+#[derive(Clone)]
+#[repr(C)]
+pub struct QMatrix4x4 {
+    m: [[f32; 4]; 4],
+    _flag_bits: i32,
+}
+
+impl QMatrix4x4 {
+    /// Returns the elements of this matrix in column-major order.
+    pub fn data(&self) -> [f32; 16] {
+        std::array::from_fn(|index| self.m[index / 4][index % 4])
+    }
+
+    /// Returns the inverse of this matrix, or None if the matrix cannot be inverted.
+    pub fn inverted(&self) -> Option<Self> {
+        let mut invertible = false;
+        let inverted = ffi::qmatrix4x4_inverted(self, &mut invertible);
+        invertible.then_some(inverted)
+    }
+
+    /// Constructs a matrix from the given 16 floating-point values, which are specified in row-major order.
+    pub fn new(values: &[f32; 16]) -> Self {
+        ffi::qmatrix4x4_from_row_major(values)
+    }
+}
+
+impl Default for QMatrix4x4 {
+    /// Constructs an identity matrix.
+    fn default() -> Self {
+        ffi::qmatrix4x4_init_default()
+    }
+}
+
+impl From<&crate::QTransform> for QMatrix4x4 {
+    /// Constructs a 4x4 matrix from the conventional Qt 2D transformation matrix.
+    fn from(transform: &crate::QTransform) -> Self {
+        ffi::qmatrix4x4_init_qtransform(transform)
+    }
+}
+
+impl std::cmp::PartialEq for QMatrix4x4 {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qmatrix4x4_eq(self, other)
+    }
+}
+
+impl fmt::Display for QMatrix4x4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qmatrix4x4_to_qstring(self))
+    }
+}
+
+impl fmt::Debug for QMatrix4x4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl std::ops::Mul for QMatrix4x4 {
+    type Output = Self;
+
+    /// Returns the result of multiplying this matrix by the given matrix,
+    /// so that the given matrix is applied to a vector before this matrix.
+    fn mul(self, rhs: Self) -> Self {
+        ffi::qmatrix4x4_mul(self, &rhs)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<&glam::Mat4> for QMatrix4x4 {
+    fn from(value: &glam::Mat4) -> Self {
+        Self::new(&value.transpose().to_cols_array())
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<&QMatrix4x4> for glam::Mat4 {
+    fn from(value: &QMatrix4x4) -> Self {
+        Self::from_cols_array(&value.data())
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&nalgebra::Matrix4<f32>> for QMatrix4x4 {
+    fn from(value: &nalgebra::Matrix4<f32>) -> Self {
+        let mut values = [0.0; 16];
+        values.copy_from_slice(value.transpose().as_slice());
+        Self::new(&values)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&QMatrix4x4> for nalgebra::Matrix4<f32> {
+    fn from(value: &QMatrix4x4) -> Self {
+        Self::from_column_slice(&value.data())
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side ensure that QMatrix4x4 is trivial.
+unsafe impl ExternType for QMatrix4x4 {
+    type Id = type_id!("QMatrix4x4");
+    type Kind = cxx::kind::Trivial;
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "glam", feature = "nalgebra"))]
+    use super::*;
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn test_glam() {
+        let translation = glam::Mat4::from_translation(glam::Vec3::new(1.0, 2.0, 3.0));
+        let matrix = QMatrix4x4::from(&translation);
+        assert_eq!(matrix.data()[12..15], [1.0, 2.0, 3.0]);
+        assert_eq!(glam::Mat4::from(&matrix), translation);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_nalgebra() {
+        let translation =
+            nalgebra::Matrix4::new_translation(&nalgebra::Vector3::new(1.0, 2.0, 3.0));
+        let matrix = QMatrix4x4::from(&translation);
+        assert_eq!(matrix.data()[12..15], [1.0, 2.0, 3.0]);
+        assert_eq!(nalgebra::Matrix4::from(&matrix), translation);
+    }
+}
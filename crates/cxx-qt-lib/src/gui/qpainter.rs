@@ -159,6 +159,8 @@ mod ffi {
         type QPoint = crate::QPoint;
         include!("cxx-qt-lib/qline.h");
         type QLine = crate::QLine;
+        include!("cxx-qt-lib/qbrush.h");
+        type QBrush = crate::QBrush;
         include!("cxx-qt-lib/qcolor.h");
         type QColor = crate::QColor;
         include!("cxx-qt-lib/qimage.h");
@@ -180,6 +182,9 @@ mod ffi {
         #[rust_name = "background_mode"]
         fn backgroundMode(self: &QPainter) -> BGMode;
 
+        /// Returns the painter's current brush.
+        fn brush(self: &QPainter) -> &QBrush;
+
         /// Returns the currently set brush origin.
         #[rust_name = "brush_origin"]
         fn brushOrigin(self: &QPainter) -> QPoint;
@@ -289,6 +294,10 @@ mod ffi {
         #[rust_name = "fill_rect"]
         fn fillRect(self: Pin<&mut QPainter>, rectangle: &QRectF, color: &QColor);
 
+        /// Fills the given rectangle with the brush specified, such as a gradient brush.
+        #[rust_name = "fill_rect_with_brush"]
+        fn fillRect(self: Pin<&mut QPainter>, rectangle: &QRectF, brush: &QBrush);
+
         /// Returns the currently set font used for drawing text.
         fn font(self: &QPainter) -> &QFont;
 
@@ -318,6 +327,10 @@ mod ffi {
         #[rust_name = "set_background_mode"]
         fn setBackgroundMode(self: Pin<&mut QPainter>, mode: BGMode);
 
+        /// Sets the painter's brush to the given brush, which is used to fill shapes.
+        #[rust_name = "set_brush"]
+        fn setBrush(self: Pin<&mut QPainter>, brush: &QBrush);
+
         /// Enables clipping if enable is true, or disables clipping if enable is false.
         #[rust_name = "set_clipping"]
         fn setClipping(self: Pin<&mut QPainter>, enable: bool);
@@ -31,6 +31,8 @@ mod ffi {
         type QPolygonF = crate::QPolygonF;
         include!("cxx-qt-lib/qregion.h");
         type QRegion = crate::QRegion;
+        include!("cxx-qt-lib/qtransform.h");
+        type QTransform = crate::QTransform;
 
         /// Creates an ellipse within the specified boundingRectangle and adds it to the painter
         /// path as a closed subpath.
@@ -99,6 +101,15 @@ mod ffi {
         /// Returns true if the given point is inside the path, otherwise returns false.
         fn contains(self: &QPainterPath, point: &QPointF) -> bool;
 
+        /// Returns true if the given path p is contained inside the current path.
+        /// Returns false if any edges of the current path and p intersect.
+        #[rust_name = "contains_path"]
+        fn contains(self: &QPainterPath, p: &QPainterPath) -> bool;
+
+        /// Returns true if the given rectangle is inside the path, otherwise returns false.
+        #[rust_name = "contains_rect"]
+        fn contains(self: &QPainterPath, rectangle: &QRectF) -> bool;
+
         /// Returns the rectangle containing all the points and control points in this path.
         #[rust_name = "control_point_rect"]
         fn controlPointRect(self: &QPainterPath) -> QRectF;
@@ -129,6 +140,10 @@ mod ffi {
         /// Also returns true if the current path contains or is contained by any part of p.
         fn intersects(self: &QPainterPath, p: &QPainterPath) -> bool;
 
+        /// Returns true if any point in the given rectangle intersects the path; otherwise returns false.
+        #[rust_name = "intersects_rect"]
+        fn intersects(self: &QPainterPath, rectangle: &QRectF) -> bool;
+
         /// Returns true if either there are no elements in this path,
         /// or if the only element is a MoveToElement; otherwise returns false.
         #[rust_name = "is_empty"]
@@ -167,6 +182,13 @@ mod ffi {
         /// Returns a copy of the path that is translated by the given offset.
         fn translated(self: &QPainterPath, offset: &QPointF) -> QPainterPath;
 
+        /// Converts the path into a polygon using the given transformation matrix, and returns the polygon.
+        ///
+        /// The polygon is created by first converting all subpaths to polygons,
+        /// then using a rewinding technique to make sure that overlapping subpaths can be filled using the correct fill rule.
+        #[rust_name = "to_fill_polygon"]
+        fn toFillPolygon(self: &QPainterPath, matrix: &QTransform) -> QPolygonF;
+
         /// Creates and returns a reversed copy of the path.
         #[rust_name = "to_reversed"]
         fn toReversed(self: &QPainterPath) -> QPainterPath;
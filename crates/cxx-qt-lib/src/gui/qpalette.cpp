@@ -0,0 +1,27 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qpalette.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QPalette has a d pointer and the current color group,
+// which is a bit field with the resolve mask in Qt 5
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/kernel/qpalette.h?h=v5.15.6-lts-lgpl#n195
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/kernel/qpalette.h?h=v6.2.4#n200
+assert_alignment_and_size(QPalette, {
+  ::std::size_t a0;
+  ::std::uint32_t a1;
+});
+
+static_assert(!::std::is_trivially_copy_assignable<QPalette>::value);
+static_assert(!::std::is_trivially_copy_constructible<QPalette>::value);
+
+static_assert(!::std::is_trivially_destructible<QPalette>::value);
+
+static_assert(QTypeInfo<QPalette>::isRelocatable);
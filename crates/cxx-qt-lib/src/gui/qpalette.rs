@@ -0,0 +1,238 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    /// The state of the widget or item for which the colors of the palette are used.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QPaletteColorGroup {
+        /// The colors used for the window which has focus.
+        Active = 0,
+        /// The colors used for disabled widgets or items.
+        Disabled = 1,
+        /// The colors used for the other windows.
+        Inactive = 2,
+        /// The current color group of the palette, see [QPalette::current_color_group].
+        Current = 4,
+        /// All of the color groups, which can only be used when setting a color or brush.
+        All = 5,
+    }
+
+    /// The roles of the colors in a palette.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QPaletteColorRole {
+        /// A general foreground color.
+        WindowText = 0,
+        /// The general button background color.
+        Button = 1,
+        /// Lighter than Button color.
+        Light = 2,
+        /// Between Button and Light.
+        Midlight = 3,
+        /// Darker than Button.
+        Dark = 4,
+        /// Between Button and Dark.
+        Mid = 5,
+        /// The foreground color used with Base.
+        Text = 6,
+        /// A text color that is very different from WindowText, and contrasts well with e.g. Dark.
+        BrightText = 7,
+        /// A foreground color used with the Button color.
+        ButtonText = 8,
+        /// Used mostly as the background color for text entry widgets.
+        Base = 9,
+        /// A general background color.
+        Window = 10,
+        /// A very dark color.
+        Shadow = 11,
+        /// A color to indicate a selected item or the current item.
+        Highlight = 12,
+        /// A text color that contrasts with Highlight.
+        HighlightedText = 13,
+        /// A text color used for unvisited hyperlinks.
+        Link = 14,
+        /// A text color used for already visited hyperlinks.
+        LinkVisited = 15,
+        /// Used as the alternate background color in views with alternating row colors.
+        AlternateBase = 16,
+        /// No role, this special role is often used to indicate that a role has not been assigned.
+        NoRole = 17,
+        /// Used as the background color for tooltips.
+        ToolTipBase = 18,
+        /// Used as the foreground color for tooltips.
+        ToolTipText = 19,
+        /// Used as the placeholder color for various text input widgets.
+        PlaceholderText = 20,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpalette.h");
+        type QPalette = super::QPalette;
+        include!("cxx-qt-lib/qbrush.h");
+        type QBrush = crate::QBrush;
+        include!("cxx-qt-lib/qcolor.h");
+        type QColor = crate::QColor;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// Returns the brush in the specified color group, used for the given color role.
+        fn brush(self: &QPalette, group: QPaletteColorGroup, role: QPaletteColorRole) -> &QBrush;
+
+        /// Returns the color in the specified color group, used for the given color role.
+        fn color(self: &QPalette, group: QPaletteColorGroup, role: QPaletteColorRole) -> &QColor;
+
+        /// Returns the palette's current color group.
+        #[rust_name = "current_color_group"]
+        fn currentColorGroup(self: &QPalette) -> QPaletteColorGroup;
+
+        /// Returns true if this palette and other are copies of each other,
+        /// i.e. one of them was created as a copy of the other and neither was subsequently modified.
+        #[rust_name = "is_copy_of"]
+        fn isCopyOf(self: &QPalette, other: &QPalette) -> bool;
+
+        /// Returns true if the color groups cg1 and cg2 are equal.
+        #[rust_name = "is_equal"]
+        fn isEqual(self: &QPalette, cg1: QPaletteColorGroup, cg2: QPaletteColorGroup) -> bool;
+
+        /// Returns a new QPalette that is a union of this instance and other.
+        /// Color roles set in this instance take precedence.
+        fn resolve(self: &QPalette, other: &QPalette) -> QPalette;
+
+        /// Sets the brush in the specified color group, used for the given color role.
+        #[rust_name = "set_brush"]
+        fn setBrush(
+            self: &mut QPalette,
+            group: QPaletteColorGroup,
+            role: QPaletteColorRole,
+            brush: &QBrush,
+        );
+
+        /// Sets the color in the specified color group, used for the given color role, to the specified solid color.
+        #[rust_name = "set_color"]
+        fn setColor(
+            self: &mut QPalette,
+            group: QPaletteColorGroup,
+            role: QPaletteColorRole,
+            color: &QColor,
+        );
+
+        /// Set the palette's current color group to the given group.
+        #[rust_name = "set_current_color_group"]
+        fn setCurrentColorGroup(self: &mut QPalette, group: QPaletteColorGroup);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+        type QPaletteColorGroup;
+        type QPaletteColorRole;
+
+        #[doc(hidden)]
+        #[rust_name = "qpalette_init_default"]
+        fn construct() -> QPalette;
+
+        #[doc(hidden)]
+        #[rust_name = "qpalette_init_from_qcolor"]
+        fn construct(button: &QColor) -> QPalette;
+
+        #[doc(hidden)]
+        #[rust_name = "qpalette_init_from_qcolors"]
+        fn construct(button: &QColor, window: &QColor) -> QPalette;
+
+        #[doc(hidden)]
+        #[rust_name = "qpalette_drop"]
+        fn drop(palette: &mut QPalette);
+
+        #[doc(hidden)]
+        #[rust_name = "qpalette_clone"]
+        fn construct(palette: &QPalette) -> QPalette;
+
+        #[doc(hidden)]
+        #[rust_name = "qpalette_eq"]
+        fn operatorEq(a: &QPalette, b: &QPalette) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qpalette_to_qstring"]
+        fn toQString(value: &QPalette) -> QString;
+    }
+}
+
+pub use ffi::{QPaletteColorGroup, QPaletteColorRole};
+
+/// The QPalette class contains color groups for each widget or item state.
+///
+/// A palette consists of three color groups: Active, Disabled, and Inactive,
+/// each of which has a brush for each color role.
+#[repr(C)]
+pub struct QPalette {
+    _d: MaybeUninit<usize>,
+    _current_group: MaybeUninit<u32>,
+}
+
+impl QPalette {
+    /// Constructs a palette from a button color and a window color,
+    /// the other colors are automatically calculated from these colors.
+    pub fn new(button: &ffi::QColor, window: &ffi::QColor) -> Self {
+        ffi::qpalette_init_from_qcolors(button, window)
+    }
+}
+
+impl Default for QPalette {
+    /// Constructs an empty palette object with no color roles set.
+    ///
+    /// When used as the palette of a QGuiApplication, the platform default palette is used instead.
+    fn default() -> Self {
+        ffi::qpalette_init_default()
+    }
+}
+
+impl Drop for QPalette {
+    fn drop(&mut self) {
+        ffi::qpalette_drop(self);
+    }
+}
+
+impl Clone for QPalette {
+    fn clone(&self) -> Self {
+        ffi::qpalette_clone(self)
+    }
+}
+
+impl PartialEq for QPalette {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qpalette_eq(self, other)
+    }
+}
+
+impl Eq for QPalette {}
+
+impl From<&ffi::QColor> for QPalette {
+    /// Constructs a palette from the button color,
+    /// the other colors are automatically calculated from this color.
+    fn from(button: &ffi::QColor) -> Self {
+        ffi::qpalette_init_from_qcolor(button)
+    }
+}
+
+impl fmt::Display for QPalette {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qpalette_to_qstring(self))
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QPalette {
+    type Id = type_id!("QPalette");
+    type Kind = cxx::kind::Trivial;
+}
@@ -19,10 +19,17 @@ mod ffi {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qpen.h");
         type QPen = super::QPen;
+        include!("cxx-qt-lib/qbrush.h");
+        type QBrush = crate::QBrush;
         include!("cxx-qt-lib/qcolor.h");
         type QColor = crate::QColor;
         include!("cxx-qt-lib/qstring.h");
         type QString = crate::QString;
+        include!("cxx-qt-lib/qvector.h");
+        type QVector_f64 = crate::QVector<f64>;
+
+        /// Returns the brush used to fill strokes generated with this pen.
+        fn brush(self: &QPen) -> QBrush;
 
         /// Returns the pen's cap style.
         #[rust_name = "cap_style"]
@@ -31,6 +38,10 @@ mod ffi {
         /// Returns the color of this pen's brush.
         fn color(self: &QPen) -> QColor;
 
+        /// Returns the dash pattern of this pen.
+        #[rust_name = "dash_pattern"]
+        fn dashPattern(self: &QPen) -> QVector_f64;
+
         /// Returns the dash offset for the pen.
         #[rust_name = "dash_offset"]
         fn dashOffset(self: &QPen) -> f64;
@@ -52,6 +63,10 @@ mod ffi {
         #[rust_name = "miter_limit"]
         fn miterLimit(self: &QPen) -> f64;
 
+        /// Sets the brush used to fill strokes generated with this pen to the given brush.
+        #[rust_name = "set_brush"]
+        fn setBrush(self: &mut QPen, brush: &QBrush);
+
         /// Sets the pen's cap style to the given style. The default value is Qt::SquareCap.
         #[rust_name = "set_cap_style"]
         fn setCapStyle(self: &mut QPen, style: PenCapStyle);
@@ -70,6 +85,14 @@ mod ffi {
         #[rust_name = "set_dash_offset"]
         fn setDashOffset(self: &mut QPen, offset: f64);
 
+        /// Sets the dash pattern for this pen to the given pattern.
+        /// This implicitly converts the style of the pen to Qt::CustomDashLine.
+        ///
+        /// The pattern must be specified as an even number of positive entries
+        /// where the entries 1, 3, 5... are the dashes and 2, 4, 6... are the spaces.
+        #[rust_name = "set_dash_pattern"]
+        fn setDashPattern(self: &mut QPen, pattern: &QVector_f64);
+
         /// Sets the pen's join style to the given style. The default value is Qt::BevelJoin.
         #[rust_name = "set_join_style"]
         fn setJoinStyle(self: &mut QPen, style: PenJoinStyle);
@@ -86,11 +109,19 @@ mod ffi {
         #[rust_name = "set_width"]
         fn setWidth(self: &mut QPen, width: i32);
 
+        /// Sets the pen width to the given width in pixels with floating point precision.
+        #[rust_name = "set_width_f"]
+        fn setWidthF(self: &mut QPen, width: f64);
+
         /// Returns the pen style.
         fn style(self: &QPen) -> PenStyle;
 
         /// Returns the pen width with integer precision.
         fn width(self: &QPen) -> i32;
+
+        /// Returns the pen width with floating point precision.
+        #[rust_name = "width_f"]
+        fn widthF(self: &QPen) -> f64;
     }
 
     #[namespace = "rust::cxxqtlib1"]
@@ -109,6 +140,16 @@ mod ffi {
         #[rust_name = "qpen_init_from_penstyle"]
         fn construct(penstyle: &PenStyle) -> QPen;
 
+        #[doc(hidden)]
+        #[rust_name = "qpen_init_from_qbrush"]
+        fn construct(
+            brush: &QBrush,
+            width: &f64,
+            style: &PenStyle,
+            cap: &PenCapStyle,
+            join: &PenJoinStyle,
+        ) -> QPen;
+
         #[doc(hidden)]
         #[rust_name = "qpen_drop"]
         fn drop(pen: &mut QPen);
@@ -132,6 +173,19 @@ pub struct QPen {
     _cspec: MaybeUninit<usize>,
 }
 
+impl QPen {
+    /// Constructs a pen with the specified brush, width, pen style, cap style and join style.
+    pub fn new(
+        brush: &ffi::QBrush,
+        width: f64,
+        style: ffi::PenStyle,
+        cap: ffi::PenCapStyle,
+        join: ffi::PenJoinStyle,
+    ) -> Self {
+        ffi::qpen_init_from_qbrush(brush, &width, &style, &cap, &join)
+    }
+}
+
 impl Default for QPen {
     /// Constructs a default black solid line pen with 1 width.
     fn default() -> Self {
@@ -32,3 +32,26 @@ static_assert(!::std::is_trivially_copy_constructible<QPolygonF>::value);
 static_assert(!::std::is_trivially_destructible<QPolygonF>::value);
 
 static_assert(QTypeInfo<QPolygonF>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::rust::Slice<const QPointF>
+qpolygonfAsSlice(const QPolygonF& polygon)
+{
+  return ::rust::Slice<const QPointF>(
+    polygon.constData(), static_cast<::std::size_t>(polygon.size()));
+}
+
+QPolygonF
+qpolygonfFromSlice(::rust::Slice<const QPointF> points)
+{
+  QPolygonF polygon;
+  for (const auto& point : points) {
+    polygon.append(point);
+  }
+  return polygon;
+}
+
+}
+}
@@ -62,6 +62,17 @@ mod ffi {
         fn united(self: &QPolygonF, r: &QPolygonF) -> QPolygonF;
     }
 
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qpolygonf_as_slice"]
+        fn qpolygonfAsSlice(polygon: &QPolygonF) -> &[QPointF];
+
+        #[doc(hidden)]
+        #[rust_name = "qpolygonf_from_slice"]
+        fn qpolygonfFromSlice(points: &[QPointF]) -> QPolygonF;
+    }
+
     #[namespace = "rust::cxxqtlib1"]
     unsafe extern "C++" {
         include!("cxx-qt-lib/common.h");
@@ -70,6 +81,10 @@ mod ffi {
         #[rust_name = "qpolygonf_init_default"]
         fn construct() -> QPolygonF;
 
+        #[doc(hidden)]
+        #[rust_name = "qpolygonf_init_qrectf"]
+        fn construct(rect: &QRectF) -> QPolygonF;
+
         #[doc(hidden)]
         #[rust_name = "qpolygonf_drop"]
         fn drop(pen: &mut QPolygonF);
@@ -108,6 +123,13 @@ impl Default for QPolygonF {
     }
 }
 
+impl QPolygonF {
+    /// Returns the points of the polygon as a slice.
+    pub fn as_slice(&self) -> &[ffi::QPointF] {
+        ffi::qpolygonf_as_slice(self)
+    }
+}
+
 impl Drop for QPolygonF {
     fn drop(&mut self) {
         ffi::qpolygonf_drop(self);
@@ -120,6 +142,29 @@ impl Clone for QPolygonF {
     }
 }
 
+impl AsRef<[ffi::QPointF]> for QPolygonF {
+    fn as_ref(&self) -> &[ffi::QPointF] {
+        self.as_slice()
+    }
+}
+
+impl From<&[ffi::QPointF]> for QPolygonF {
+    /// Constructs a polygon containing the given points.
+    fn from(points: &[ffi::QPointF]) -> Self {
+        ffi::qpolygonf_from_slice(points)
+    }
+}
+
+impl From<&ffi::QRectF> for QPolygonF {
+    /// Constructs a closed polygon from the given rectangle.
+    ///
+    /// The polygon contains the four vertices of the rectangle in clockwise order
+    /// starting and ending with the top-left vertex.
+    fn from(rect: &ffi::QRectF) -> Self {
+        ffi::qpolygonf_init_qrectf(rect)
+    }
+}
+
 impl PartialEq for QPolygonF {
     fn eq(&self, other: &Self) -> bool {
         ffi::qpolygonf_eq(self, other)
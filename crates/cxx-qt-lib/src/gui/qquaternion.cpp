@@ -0,0 +1,59 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qquaternion.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QQuaternion has four float members - wp, xp, yp, and zp
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/math3d/qquaternion.h?h=v5.15.6-lts-lgpl#n167
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/math3d/qquaternion.h?h=v6.2.4#n148
+assert_alignment_and_size(QQuaternion, {
+  float a0;
+  float a1;
+  float a2;
+  float a3;
+});
+
+static_assert(::std::is_trivially_copyable<QQuaternion>::value,
+              "QQuaternion should be trivially copyable");
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QQuaternion
+qquaternionFromAxisAndAngle(const QVector3D& axis, float angle)
+{
+  return QQuaternion::fromAxisAndAngle(axis, angle);
+}
+
+QQuaternion
+qquaternionFromEulerAngles(float pitch, float yaw, float roll)
+{
+  return QQuaternion::fromEulerAngles(pitch, yaw, roll);
+}
+
+QQuaternion
+qquaternionNlerp(const QQuaternion& q1, const QQuaternion& q2, float t)
+{
+  return QQuaternion::nlerp(q1, q2, t);
+}
+
+QQuaternion
+qquaternionRotationTo(const QVector3D& from, const QVector3D& to)
+{
+  return QQuaternion::rotationTo(from, to);
+}
+
+QQuaternion
+qquaternionSlerp(const QQuaternion& q1, const QQuaternion& q2, float t)
+{
+  return QQuaternion::slerp(q1, q2, t);
+}
+
+}
+}
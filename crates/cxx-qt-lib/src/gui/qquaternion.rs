@@ -0,0 +1,263 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx::{type_id, ExternType};
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qquaternion.h");
+        type QQuaternion = super::QQuaternion;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qvector3d.h");
+        type QVector3D = crate::QVector3D;
+        include!("cxx-qt-lib/qvector4d.h");
+        type QVector4D = crate::QVector4D;
+
+        /// Returns the conjugate of this quaternion, which is (-x, -y, -z, scalar).
+        fn conjugated(self: &QQuaternion) -> QQuaternion;
+
+        /// Returns the inverse of this quaternion. If this quaternion is null, then a null quaternion is returned.
+        fn inverted(self: &QQuaternion) -> QQuaternion;
+
+        /// Returns true if the x, y, and z components of this quaternion are set to 0.0,
+        /// and the scalar component is set to 1.0, otherwise returns false.
+        #[rust_name = "is_identity"]
+        fn isIdentity(self: &QQuaternion) -> bool;
+
+        /// Returns true if the x, y, z, and scalar components of this quaternion are set to 0.0, otherwise returns false.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QQuaternion) -> bool;
+
+        /// Returns the length of the quaternion. This is also called the "norm".
+        fn length(self: &QQuaternion) -> f32;
+
+        /// Returns the squared length of the quaternion.
+        #[rust_name = "length_squared"]
+        fn lengthSquared(self: &QQuaternion) -> f32;
+
+        /// Normalizes the current quaternion in place.
+        /// Nothing happens if this is a null quaternion or the length of the quaternion is very close to 1.
+        fn normalize(self: &mut QQuaternion);
+
+        /// Returns the normalized unit form of this quaternion.
+        ///
+        /// If this quaternion is null, then a null quaternion is returned.
+        /// If the length of the quaternion is very close to 1, then the quaternion will be returned as-is.
+        /// Otherwise the normalized form of the quaternion of length 1 will be returned.
+        fn normalized(self: &QQuaternion) -> QQuaternion;
+
+        /// Rotates vector with this quaternion to produce a new vector in 3D space.
+        #[rust_name = "rotated_vector"]
+        fn rotatedVector(self: &QQuaternion, vector: &QVector3D) -> QVector3D;
+
+        /// Returns the scalar component of this quaternion.
+        fn scalar(self: &QQuaternion) -> f32;
+
+        /// Sets the scalar component of this quaternion to scalar.
+        #[rust_name = "set_scalar"]
+        fn setScalar(self: &mut QQuaternion, scalar: f32);
+        /// Sets the x coordinate of this quaternion's vector to the given x coordinate.
+        #[rust_name = "set_x"]
+        fn setX(self: &mut QQuaternion, x: f32);
+        /// Sets the y coordinate of this quaternion's vector to the given y coordinate.
+        #[rust_name = "set_y"]
+        fn setY(self: &mut QQuaternion, y: f32);
+        /// Sets the z coordinate of this quaternion's vector to the given z coordinate.
+        #[rust_name = "set_z"]
+        fn setZ(self: &mut QQuaternion, z: f32);
+
+        /// Calculates roll, pitch, and yaw Euler angles (in degrees) that correspond to this quaternion,
+        /// which are returned as the x, y, and z of a 3D vector.
+        #[rust_name = "to_euler_angles"]
+        fn toEulerAngles(self: &QQuaternion) -> QVector3D;
+
+        /// Returns this quaternion as a 4D vector, with the scalar component as the w coordinate.
+        #[rust_name = "to_vector4d"]
+        fn toVector4D(self: &QQuaternion) -> QVector4D;
+
+        /// Returns the vector component of this quaternion.
+        fn vector(self: &QQuaternion) -> QVector3D;
+
+        /// Returns the x coordinate of this quaternion's vector.
+        fn x(self: &QQuaternion) -> f32;
+        /// Returns the y coordinate of this quaternion's vector.
+        fn y(self: &QQuaternion) -> f32;
+        /// Returns the z coordinate of this quaternion's vector.
+        fn z(self: &QQuaternion) -> f32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_from_axis_and_angle"]
+        fn qquaternionFromAxisAndAngle(axis: &QVector3D, angle: f32) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_from_euler_angles"]
+        fn qquaternionFromEulerAngles(pitch: f32, yaw: f32, roll: f32) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_nlerp"]
+        fn qquaternionNlerp(q1: &QQuaternion, q2: &QQuaternion, t: f32) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_rotation_to"]
+        fn qquaternionRotationTo(from: &QVector3D, to: &QVector3D) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_slerp"]
+        fn qquaternionSlerp(q1: &QQuaternion, q2: &QQuaternion, t: f32) -> QQuaternion;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_init_default"]
+        fn construct() -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_init"]
+        fn construct(scalar: f32, x: f32, y: f32, z: f32) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_mul"]
+        fn operatorMul(a: QQuaternion, b: &QQuaternion) -> QQuaternion;
+        #[doc(hidden)]
+        #[rust_name = "qquaternion_to_qstring"]
+        fn toQString(value: &QQuaternion) -> QString;
+    }
+}
+
+/// The QQuaternion class represents a quaternion consisting of a vector and scalar,
+/// which are used to represent rotations in 3D space.
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct QQuaternion {
+    wp: f32,
+    xp: f32,
+    yp: f32,
+    zp: f32,
+}
+
+impl QQuaternion {
+    /// Creates a normalized quaternion that corresponds to rotating through angle degrees about the specified 3D axis.
+    pub fn from_axis_and_angle(axis: &crate::QVector3D, angle: f32) -> Self {
+        ffi::qquaternion_from_axis_and_angle(axis, angle)
+    }
+
+    /// Creates a quaternion that corresponds to a rotation of roll degrees around the z axis,
+    /// pitch degrees around the x axis, and yaw degrees around the y axis (applied in that order).
+    pub fn from_euler_angles(pitch: f32, yaw: f32, roll: f32) -> Self {
+        ffi::qquaternion_from_euler_angles(pitch, yaw, roll)
+    }
+
+    /// Constructs a quaternion vector from the specified vector and scalar.
+    pub fn new(scalar: f32, x: f32, y: f32, z: f32) -> Self {
+        ffi::qquaternion_init(scalar, x, y, z)
+    }
+
+    /// Interpolates along the shortest linear path between the rotational positions q1 and q2.
+    /// The value t should be between 0 and 1, indicating the distance to travel between q1 and q2.
+    ///
+    /// This function is typically faster than [QQuaternion::slerp] and will give approximate results
+    /// to spherical interpolation that are good enough for some applications.
+    pub fn nlerp(q1: &QQuaternion, q2: &QQuaternion, t: f32) -> Self {
+        ffi::qquaternion_nlerp(q1, q2, t)
+    }
+
+    /// Returns the shortest arc quaternion to rotate from the direction described by the vector from
+    /// to the direction described by the vector to.
+    pub fn rotation_to(from: &crate::QVector3D, to: &crate::QVector3D) -> Self {
+        ffi::qquaternion_rotation_to(from, to)
+    }
+
+    /// Interpolates along the shortest spherical path between the rotational positions q1 and q2.
+    /// The value t should be between 0 and 1, indicating the spherical distance to travel between q1 and q2.
+    pub fn slerp(q1: &QQuaternion, q2: &QQuaternion, t: f32) -> Self {
+        ffi::qquaternion_slerp(q1, q2, t)
+    }
+}
+
+impl Default for QQuaternion {
+    /// Constructs an identity quaternion (1, 0, 0, 0), i.e. with the vector (0, 0, 0) and scalar 1.
+    fn default() -> Self {
+        ffi::qquaternion_init_default()
+    }
+}
+
+impl std::fmt::Display for QQuaternion {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", ffi::qquaternion_to_qstring(self))
+    }
+}
+
+impl std::ops::Mul for QQuaternion {
+    type Output = Self;
+
+    /// Returns the product of this quaternion and the given quaternion,
+    /// which rotates by the given quaternion followed by this quaternion.
+    fn mul(self, rhs: Self) -> Self {
+        ffi::qquaternion_mul(self, &rhs)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<&glam::Quat> for QQuaternion {
+    fn from(value: &glam::Quat) -> Self {
+        let [x, y, z, w] = value.to_array();
+        Self::new(w, x, y, z)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<&QQuaternion> for glam::Quat {
+    fn from(value: &QQuaternion) -> Self {
+        Self::from_xyzw(value.x(), value.y(), value.z(), value.scalar())
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&nalgebra::Quaternion<f32>> for QQuaternion {
+    fn from(value: &nalgebra::Quaternion<f32>) -> Self {
+        Self::new(value.w, value.i, value.j, value.k)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&QQuaternion> for nalgebra::Quaternion<f32> {
+    fn from(value: &QQuaternion) -> Self {
+        Self::new(value.scalar(), value.x(), value.y(), value.z())
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side ensure that QQuaternion is trivial.
+unsafe impl ExternType for QQuaternion {
+    type Id = type_id!("QQuaternion");
+    type Kind = cxx::kind::Trivial;
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "glam", feature = "nalgebra"))]
+    use super::*;
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn test_glam() {
+        let quat = glam::Quat::from_xyzw(0.5, 0.5, 0.5, 0.5);
+        let quaternion = QQuaternion::from(&quat);
+        assert_eq!(quaternion, QQuaternion::new(0.5, 0.5, 0.5, 0.5));
+        assert_eq!(glam::Quat::from(&quaternion), quat);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_nalgebra() {
+        let quat = nalgebra::Quaternion::new(1.0, 0.0, 0.0, 0.0);
+        let quaternion = QQuaternion::from(&quat);
+        assert!(quaternion.is_identity());
+        assert_eq!(nalgebra::Quaternion::from(&quaternion), quat);
+    }
+}
@@ -0,0 +1,135 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use core::pin::Pin;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qgradient.h");
+        type QGradient = crate::QGradient;
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = crate::QPointF;
+
+        /// The QRadialGradient class is used in combination with QBrush to specify a radial gradient brush.
+        ///
+        /// Radial gradients interpolate colors between a focal point and end points on a circle surrounding it,
+        /// outside the end points the gradient is either padded, reflected or repeated depending on the currently set spread.
+        //
+        // QRadialGradient is not declared as relocatable by Qt, so use an opaque type.
+        type QRadialGradient;
+
+        /// Returns the center of this radial gradient in logical coordinates.
+        fn center(self: &QRadialGradient) -> QPointF;
+
+        /// Returns the center radius of this radial gradient in logical coordinates.
+        #[rust_name = "center_radius"]
+        fn centerRadius(self: &QRadialGradient) -> f64;
+
+        /// Returns the focal point of this radial gradient in logical coordinates.
+        #[rust_name = "focal_point"]
+        fn focalPoint(self: &QRadialGradient) -> QPointF;
+
+        /// Returns the focal radius of this radial gradient in logical coordinates.
+        #[rust_name = "focal_radius"]
+        fn focalRadius(self: &QRadialGradient) -> f64;
+
+        /// Sets the center of this radial gradient in logical coordinates to center.
+        #[rust_name = "set_center"]
+        fn setCenter(self: Pin<&mut QRadialGradient>, center: &QPointF);
+
+        /// Sets the center radius of this radial gradient in logical coordinates to radius.
+        #[rust_name = "set_center_radius"]
+        fn setCenterRadius(self: Pin<&mut QRadialGradient>, radius: f64);
+
+        /// Sets the focal point of this radial gradient in logical coordinates to focal_point.
+        #[rust_name = "set_focal_point"]
+        fn setFocalPoint(self: Pin<&mut QRadialGradient>, focal_point: &QPointF);
+
+        /// Sets the focal radius of this radial gradient in logical coordinates to radius.
+        #[rust_name = "set_focal_radius"]
+        fn setFocalRadius(self: Pin<&mut QRadialGradient>, radius: f64);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qradialgradient_as_qgradient"]
+        fn qradialgradientAsQGradient(gradient: &QRadialGradient) -> &QGradient;
+        #[doc(hidden)]
+        #[rust_name = "qradialgradient_as_qgradient_mut"]
+        fn qradialgradientAsQGradientMut(
+            gradient: Pin<&mut QRadialGradient>,
+        ) -> Pin<&mut QGradient>;
+        #[doc(hidden)]
+        #[rust_name = "qradialgradient_clone"]
+        fn qradialgradientClone(gradient: &QRadialGradient) -> UniquePtr<QRadialGradient>;
+        #[doc(hidden)]
+        #[rust_name = "qradialgradient_new"]
+        fn qradialgradientNew(
+            center: &QPointF,
+            center_radius: f64,
+            focal_point: &QPointF,
+            focal_radius: f64,
+        ) -> UniquePtr<QRadialGradient>;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qradialgradient_eq"]
+        fn operatorEq(a: &QRadialGradient, b: &QRadialGradient) -> bool;
+    }
+
+    // QRadialGradient is not declared as relocatable by Qt, so use an opaque type.
+    impl UniquePtr<QRadialGradient> {}
+}
+
+pub use ffi::QRadialGradient;
+
+impl QRadialGradient {
+    /// Returns this radial gradient as a [QGradient](crate::QGradient),
+    /// which has the stop points and spread of the gradient.
+    pub fn as_gradient(&self) -> &ffi::QGradient {
+        ffi::qradialgradient_as_qgradient(self)
+    }
+
+    /// Returns this radial gradient as a mutable [QGradient](crate::QGradient),
+    /// which can be used to set the stop points and spread of the gradient.
+    pub fn as_gradient_mut(self: Pin<&mut Self>) -> Pin<&mut ffi::QGradient> {
+        ffi::qradialgradient_as_qgradient_mut(self)
+    }
+
+    /// Constructs a simple radial gradient with the given center and radius,
+    /// where the focal point is the same as the center.
+    pub fn new(center: &ffi::QPointF, radius: f64) -> cxx::UniquePtr<Self> {
+        ffi::qradialgradient_new(center, radius, center, 0.0)
+    }
+
+    /// Constructs an extended radial gradient with the given center, center radius,
+    /// focal point, and focal radius.
+    pub fn new_extended(
+        center: &ffi::QPointF,
+        center_radius: f64,
+        focal_point: &ffi::QPointF,
+        focal_radius: f64,
+    ) -> cxx::UniquePtr<Self> {
+        ffi::qradialgradient_new(center, center_radius, focal_point, focal_radius)
+    }
+
+    /// Copy constructor, create a copy of the QRadialGradient.
+    pub fn to_owned(&self) -> cxx::UniquePtr<Self> {
+        ffi::qradialgradient_clone(self)
+    }
+}
+
+impl std::cmp::PartialEq for QRadialGradient {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qradialgradient_eq(self, other)
+    }
+}
+
+impl std::cmp::Eq for QRadialGradient {}
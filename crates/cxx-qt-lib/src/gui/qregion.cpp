@@ -19,3 +19,24 @@ static_assert(!::std::is_trivially_copy_constructible<QRegion>::value);
 static_assert(!::std::is_trivially_destructible<QRegion>::value);
 
 static_assert(QTypeInfo<QRegion>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::rust::Slice<const QRect>
+qregionAsSlice(const QRegion& region)
+{
+  return ::rust::Slice<const QRect>(
+    region.begin(), static_cast<::std::size_t>(region.rectCount()));
+}
+
+QRegion
+qregionFromSlice(::rust::Slice<const QRect> rects)
+{
+  QRegion region;
+  region.setRects(rects.data(), static_cast<int>(rects.size()));
+  return region;
+}
+
+}
+}
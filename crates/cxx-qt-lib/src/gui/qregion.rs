@@ -3,10 +3,28 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use cxx::{type_id, ExternType};
+use std::fmt;
 use std::mem::MaybeUninit;
 
 #[cxx::bridge]
 mod ffi {
+    /// Specifies the shape of the region to be created.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QRegionRegionType {
+        /// The region covers the entire rectangle.
+        Rectangle = 0,
+        /// The region is an ellipse inside the rectangle.
+        Ellipse = 1,
+    }
+
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type FillRule = crate::FillRule;
+    }
+
     unsafe extern "C++" {
         include!("cxx-qt-lib/qregion.h");
         type QRegion = super::QRegion;
@@ -17,6 +35,12 @@ mod ffi {
         include!("cxx-qt-lib/qpoint.h");
         type QPoint = crate::QPoint;
 
+        include!("cxx-qt-lib/qpolygon.h");
+        type QPolygon = crate::QPolygon;
+
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
         /// Returns the bounding rectangle of this region. An empty region gives a rectangle that is QRect::isNul
         #[rust_name = "bounding_rect"]
         fn boundingRect(self: &QRegion) -> QRect;
@@ -24,9 +48,20 @@ mod ffi {
         /// Returns true if the region overlaps the rectangle r; otherwise returns false.
         fn contains(self: &QRegion, r: &QRect) -> bool;
 
+        /// Returns true if the region contains the point p; otherwise returns false.
+        #[rust_name = "contains_point"]
+        fn contains(self: &QRegion, p: &QPoint) -> bool;
+
         /// Returns a region which is the intersection of this region and r.
         fn intersected(self: &QRegion, r: &QRegion) -> QRegion;
 
+        /// Returns true if this region intersects with region, otherwise returns false.
+        fn intersects(self: &QRegion, region: &QRegion) -> bool;
+
+        /// Returns true if this region intersects with rect, otherwise returns false.
+        #[rust_name = "intersects_rect"]
+        fn intersects(self: &QRegion, rect: &QRect) -> bool;
+
         /// Returns true if the region is empty; otherwise returns false. An empty region is a region that contains no points.
         #[rust_name = "is_empty"]
         fn isEmpty(self: &QRegion) -> bool;
@@ -59,6 +94,19 @@ mod ffi {
         fn xored(self: &QRegion, r: &QRegion) -> QRegion;
     }
 
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QRegionRegionType;
+
+        #[doc(hidden)]
+        #[rust_name = "qregion_as_slice"]
+        fn qregionAsSlice(region: &QRegion) -> &[QRect];
+
+        #[doc(hidden)]
+        #[rust_name = "qregion_from_slice"]
+        fn qregionFromSlice(rects: &[QRect]) -> QRegion;
+    }
+
     #[namespace = "rust::cxxqtlib1"]
     unsafe extern "C++" {
         include!("cxx-qt-lib/common.h");
@@ -67,6 +115,14 @@ mod ffi {
         #[rust_name = "qregion_init_default"]
         fn construct() -> QRegion;
 
+        #[doc(hidden)]
+        #[rust_name = "qregion_init_qrect"]
+        fn construct(rect: &QRect, region_type: QRegionRegionType) -> QRegion;
+
+        #[doc(hidden)]
+        #[rust_name = "qregion_init_qpolygon"]
+        fn construct(polygon: &QPolygon, fill_rule: FillRule) -> QRegion;
+
         #[doc(hidden)]
         #[rust_name = "qregion_drop"]
         fn drop(r: &mut QRegion);
@@ -74,14 +130,46 @@ mod ffi {
         #[doc(hidden)]
         #[rust_name = "qregion_clone"]
         fn construct(r: &QRegion) -> QRegion;
+
+        #[doc(hidden)]
+        #[rust_name = "qregion_eq"]
+        fn operatorEq(a: &QRegion, b: &QRegion) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "qregion_to_qstring"]
+        fn toQString(value: &QRegion) -> QString;
     }
 }
 
+pub use ffi::QRegionRegionType;
+
+/// The QRegion class specifies a clip region for a painter, which is a set of non-overlapping rectangles.
+///
+/// It can also be used for hit-testing against shapes made from rectangles, ellipses, and polygons.
 #[repr(C)]
 pub struct QRegion {
     _cspec: MaybeUninit<usize>,
 }
 
+impl QRegion {
+    /// Returns the non-overlapping rectangles that make up the region as a slice.
+    pub fn as_slice(&self) -> &[ffi::QRect] {
+        ffi::qregion_as_slice(self)
+    }
+
+    /// Creates a region from the given rectangle, which is either the whole rectangle
+    /// or an ellipse inside the rectangle depending on the region type.
+    pub fn new(rect: &ffi::QRect, region_type: QRegionRegionType) -> Self {
+        ffi::qregion_init_qrect(rect, region_type)
+    }
+
+    /// Constructs a polygon region from the given polygon,
+    /// which is filled using the given fill rule.
+    pub fn from_polygon(polygon: &ffi::QPolygon, fill_rule: ffi::FillRule) -> Self {
+        ffi::qregion_init_qpolygon(polygon, fill_rule)
+    }
+}
+
 impl Default for QRegion {
     /// Constructs an empty region.
     fn default() -> Self {
@@ -101,6 +189,40 @@ impl Clone for QRegion {
     }
 }
 
+impl AsRef<[ffi::QRect]> for QRegion {
+    fn as_ref(&self) -> &[ffi::QRect] {
+        self.as_slice()
+    }
+}
+
+impl From<&ffi::QRect> for QRegion {
+    /// Creates a region covering the given rectangle.
+    fn from(rect: &ffi::QRect) -> Self {
+        ffi::qregion_init_qrect(rect, QRegionRegionType::Rectangle)
+    }
+}
+
+impl From<&[ffi::QRect]> for QRegion {
+    /// Creates a region which is the union of the given rectangles.
+    fn from(rects: &[ffi::QRect]) -> Self {
+        ffi::qregion_from_slice(rects)
+    }
+}
+
+impl PartialEq for QRegion {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qregion_eq(self, other)
+    }
+}
+
+impl Eq for QRegion {}
+
+impl fmt::Display for QRegion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qregion_to_qstring(self))
+    }
+}
+
 // Safety:
 //
 // Static checks on the C++ side to ensure the size is the same.
@@ -0,0 +1,71 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qtransform.h"
+
+#include <cxx-qt-lib/assertion_utils.h>
+
+// QTransform has a 3x3 matrix of qreals, the type and dirty flags,
+// and an unused d pointer.
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qtransform.h?h=v5.15.6-lts-lgpl#n176
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/gui/painting/qtransform.h?h=v6.2.4#n170
+assert_alignment_and_size(QTransform, {
+  double a0[9];
+  ::std::uint32_t a1;
+  ::std::size_t a2;
+});
+
+static_assert(::std::is_trivially_destructible<QTransform>::value);
+
+static_assert(QTypeInfo<QTransform>::isRelocatable);
+
+namespace rust {
+namespace cxxqtlib1 {
+
+QTransform
+qtransformFromMatrix(::rust::Slice<const double> values)
+{
+  Q_ASSERT(values.size() == 9);
+  return QTransform(values[0],
+                    values[1],
+                    values[2],
+                    values[3],
+                    values[4],
+                    values[5],
+                    values[6],
+                    values[7],
+                    values[8]);
+}
+
+QTransform
+qtransformFromScale(double sx, double sy)
+{
+  return QTransform::fromScale(sx, sy);
+}
+
+QTransform
+qtransformFromTranslate(double dx, double dy)
+{
+  return QTransform::fromTranslate(dx, dy);
+}
+
+QTransform
+qtransformInverted(const QTransform& transform, bool& invertible)
+{
+  return transform.inverted(&invertible);
+}
+
+// Qt 6 has an extra distanceToPlane argument
+void
+qtransformRotate(QTransform& transform, double angle)
+{
+  transform.rotate(angle);
+}
+
+}
+}
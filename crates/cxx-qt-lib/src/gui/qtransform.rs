@@ -0,0 +1,307 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use cxx::{type_id, ExternType};
+use std::fmt;
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtransform.h");
+        type QTransform = super::QTransform;
+        include!("cxx-qt-lib/qpointf.h");
+        type QPointF = crate::QPointF;
+        include!("cxx-qt-lib/qrectf.h");
+        type QRectF = crate::QRectF;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+
+        /// Returns the matrix's determinant.
+        fn determinant(self: &QTransform) -> f64;
+
+        /// Returns the horizontal translation factor.
+        fn dx(self: &QTransform) -> f64;
+
+        /// Returns the vertical translation factor.
+        fn dy(self: &QTransform) -> f64;
+
+        /// Returns true if the matrix represent an affine transformation, otherwise returns false.
+        #[rust_name = "is_affine"]
+        fn isAffine(self: &QTransform) -> bool;
+
+        /// Returns true if the matrix is the identity matrix, otherwise returns false.
+        #[rust_name = "is_identity"]
+        fn isIdentity(self: &QTransform) -> bool;
+
+        /// Returns true if the matrix is invertible, otherwise returns false.
+        #[rust_name = "is_invertible"]
+        fn isInvertible(self: &QTransform) -> bool;
+
+        /// Returns true if the matrix represents some kind of a rotating transformation, otherwise returns false.
+        #[rust_name = "is_rotating"]
+        fn isRotating(self: &QTransform) -> bool;
+
+        /// Returns true if the matrix represents a scaling transformation, otherwise returns false.
+        #[rust_name = "is_scaling"]
+        fn isScaling(self: &QTransform) -> bool;
+
+        /// Returns true if the matrix represents a translating transformation, otherwise returns false.
+        #[rust_name = "is_translating"]
+        fn isTranslating(self: &QTransform) -> bool;
+
+        /// Returns the horizontal scaling factor.
+        fn m11(self: &QTransform) -> f64;
+        /// Returns the vertical shearing factor.
+        fn m12(self: &QTransform) -> f64;
+        /// Returns the horizontal projection factor.
+        fn m13(self: &QTransform) -> f64;
+        /// Returns the horizontal shearing factor.
+        fn m21(self: &QTransform) -> f64;
+        /// Returns the vertical scaling factor.
+        fn m22(self: &QTransform) -> f64;
+        /// Returns the vertical projection factor.
+        fn m23(self: &QTransform) -> f64;
+        /// Returns the horizontal translation factor.
+        fn m31(self: &QTransform) -> f64;
+        /// Returns the vertical translation factor.
+        fn m32(self: &QTransform) -> f64;
+        /// Returns the division factor.
+        fn m33(self: &QTransform) -> f64;
+
+        /// Creates and returns a point that is a copy of the given point, mapped into the coordinate system defined by this matrix.
+        fn map(self: &QTransform, point: &QPointF) -> QPointF;
+
+        /// Creates and returns a rectangle that is a copy of the given rectangle, mapped into the coordinate system defined by this matrix.
+        ///
+        /// If rotation or shearing has been specified, this function returns the bounding rectangle.
+        #[rust_name = "map_rect"]
+        fn mapRect(self: &QTransform, rectangle: &QRectF) -> QRectF;
+
+        /// Scales the coordinate system by sx horizontally and sy vertically, and returns a reference to the matrix.
+        fn scale(self: &mut QTransform, sx: f64, sy: f64) -> &mut QTransform;
+
+        /// Moves the coordinate system dx along the x axis and dy along the y axis, and returns a reference to the matrix.
+        fn translate(self: &mut QTransform, dx: f64, dy: f64) -> &mut QTransform;
+
+        /// Returns the transpose of this matrix.
+        fn transposed(self: &QTransform) -> QTransform;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[rust_name = "qtransform_from_matrix"]
+        fn qtransformFromMatrix(values: &[f64]) -> QTransform;
+        #[doc(hidden)]
+        #[rust_name = "qtransform_from_scale"]
+        fn qtransformFromScale(sx: f64, sy: f64) -> QTransform;
+        #[doc(hidden)]
+        #[rust_name = "qtransform_from_translate"]
+        fn qtransformFromTranslate(dx: f64, dy: f64) -> QTransform;
+        #[doc(hidden)]
+        #[rust_name = "qtransform_inverted"]
+        fn qtransformInverted(transform: &QTransform, invertible: &mut bool) -> QTransform;
+        #[doc(hidden)]
+        #[rust_name = "qtransform_rotate"]
+        fn qtransformRotate(transform: &mut QTransform, angle: f64);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[doc(hidden)]
+        #[rust_name = "qtransform_init_default"]
+        fn construct() -> QTransform;
+        #[doc(hidden)]
+        #[rust_name = "qtransform_init"]
+        fn construct(m11: f64, m12: f64, m21: f64, m22: f64, dx: f64, dy: f64) -> QTransform;
+        #[doc(hidden)]
+        #[rust_name = "qtransform_eq"]
+        fn operatorEq(a: &QTransform, b: &QTransform) -> bool;
+        #[doc(hidden)]
+        #[rust_name = "qtransform_mul"]
+        fn operatorMul(a: QTransform, b: &QTransform) -> QTransform;
+        #[doc(hidden)]
+        #[rust_name = "qtransform_to_qstring"]
+        fn toQString(value: &QTransform) -> QString;
+    }
+}
+
+/// The QTransform class specifies 2D transformations of a coordinate system.
+///
+/// A transformation specifies how to translate, scale, shear, rotate or project the coordinate system,
+/// and is typically used when rendering graphics.
+#[derive(Clone)]
+#[repr(C)]
+pub struct QTransform {
+    _m: MaybeUninit<[f64; 9]>,
+    _flags: MaybeUninit<u32>,
+    _d: MaybeUninit<usize>,
+}
+
+impl QTransform {
+    /// Constructs a matrix with the given elements in row-major order,
+    /// which are m11, m12, m13, m21, m22, m23, m31, m32, and m33.
+    pub fn from_matrix(values: &[f64; 9]) -> Self {
+        ffi::qtransform_from_matrix(values)
+    }
+
+    /// Creates a matrix which corresponds to a scaling of sx horizontally and sy vertically.
+    pub fn from_scale(sx: f64, sy: f64) -> Self {
+        ffi::qtransform_from_scale(sx, sy)
+    }
+
+    /// Creates a matrix which corresponds to a translation of dx along the x axis and dy along the y axis.
+    pub fn from_translate(dx: f64, dy: f64) -> Self {
+        ffi::qtransform_from_translate(dx, dy)
+    }
+
+    /// Returns an inverted copy of this matrix, or None if the matrix is not invertible.
+    pub fn inverted(&self) -> Option<Self> {
+        let mut invertible = false;
+        let inverted = ffi::qtransform_inverted(self, &mut invertible);
+        invertible.then_some(inverted)
+    }
+
+    /// Constructs an affine matrix with the elements m11, m12, m21, m22, dx and dy.
+    pub fn new(m11: f64, m12: f64, m21: f64, m22: f64, dx: f64, dy: f64) -> Self {
+        ffi::qtransform_init(m11, m12, m21, m22, dx, dy)
+    }
+
+    /// Rotates the coordinate system counterclockwise by the given angle in degrees,
+    /// and returns a reference to the matrix.
+    pub fn rotate(&mut self, angle: f64) -> &mut Self {
+        ffi::qtransform_rotate(self, angle);
+        self
+    }
+}
+
+impl Default for QTransform {
+    /// Constructs an identity matrix.
+    fn default() -> Self {
+        ffi::qtransform_init_default()
+    }
+}
+
+impl std::cmp::PartialEq for QTransform {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qtransform_eq(self, other)
+    }
+}
+
+impl fmt::Display for QTransform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", ffi::qtransform_to_qstring(self))
+    }
+}
+
+impl fmt::Debug for QTransform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl std::ops::Mul for QTransform {
+    type Output = Self;
+
+    /// Returns the result of multiplying this matrix by the given matrix,
+    /// which applies this transformation followed by the given transformation.
+    fn mul(self, rhs: Self) -> Self {
+        ffi::qtransform_mul(self, &rhs)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<&glam::DMat3> for QTransform {
+    /// Constructs a QTransform from a column vector matrix,
+    /// which is the transpose of the row vector matrix of QTransform.
+    fn from(value: &glam::DMat3) -> Self {
+        Self::from_matrix(&value.to_cols_array())
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<&QTransform> for glam::DMat3 {
+    /// Constructs a column vector matrix from a QTransform,
+    /// which is the transpose of the row vector matrix of QTransform.
+    fn from(value: &QTransform) -> Self {
+        Self::from_cols_array(&[
+            value.m11(),
+            value.m12(),
+            value.m13(),
+            value.m21(),
+            value.m22(),
+            value.m23(),
+            value.m31(),
+            value.m32(),
+            value.m33(),
+        ])
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&nalgebra::Matrix3<f64>> for QTransform {
+    /// Constructs a QTransform from a column vector matrix,
+    /// which is the transpose of the row vector matrix of QTransform.
+    fn from(value: &nalgebra::Matrix3<f64>) -> Self {
+        let mut values = [0.0; 9];
+        values.copy_from_slice(value.as_slice());
+        Self::from_matrix(&values)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<&QTransform> for nalgebra::Matrix3<f64> {
+    /// Constructs a column vector matrix from a QTransform,
+    /// which is the transpose of the row vector matrix of QTransform.
+    fn from(value: &QTransform) -> Self {
+        Self::new(
+            value.m11(),
+            value.m21(),
+            value.m31(),
+            value.m12(),
+            value.m22(),
+            value.m32(),
+            value.m13(),
+            value.m23(),
+            value.m33(),
+        )
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QTransform {
+    type Id = type_id!("QTransform");
+    type Kind = cxx::kind::Trivial;
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "glam", feature = "nalgebra"))]
+    use super::*;
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn test_glam() {
+        let translation = glam::DMat3::from_translation(glam::DVec2::new(1.0, 2.0));
+        let transform = QTransform::from(&translation);
+        assert_eq!(transform.dx(), 1.0);
+        assert_eq!(transform.dy(), 2.0);
+        assert_eq!(glam::DMat3::from(&transform), translation);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_nalgebra() {
+        let translation = nalgebra::Matrix3::new_translation(&nalgebra::Vector2::new(1.0, 2.0));
+        let transform = QTransform::from(&translation);
+        assert_eq!(transform.dx(), 1.0);
+        assert_eq!(transform.dy(), 2.0);
+        assert_eq!(nalgebra::Matrix3::from(&transform), translation);
+    }
+}
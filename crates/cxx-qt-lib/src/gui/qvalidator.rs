@@ -0,0 +1,46 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum type defines the states in which a validated string can exist.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QValidatorState {
+        /// The string is clearly invalid.
+        Invalid,
+        /// The string is a plausible intermediate value.
+        Intermediate,
+        /// The string is acceptable as a final result; i.e. it is valid.
+        Acceptable,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qvalidator.h");
+        /// The QValidator class provides validation of input text.
+        ///
+        /// This can be used as the base of a QObject which overrides `validate` in Rust,
+        /// an instance of which can then be used as the `validator` of a QML TextField or TextInput.
+        ///
+        /// ```ignore
+        /// #[qobject]
+        /// #[qml_element]
+        /// #[base = QValidator]
+        /// type MyValidator = super::MyValidatorRust;
+        ///
+        /// #[cxx_override]
+        /// fn validate(self: &MyValidator, input: &mut QString, pos: &mut i32) -> QValidatorState;
+        /// ```
+        type QValidator;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QValidatorState;
+    }
+}
+
+pub use ffi::{QValidator, QValidatorState};
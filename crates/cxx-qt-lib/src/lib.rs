@@ -25,3 +25,13 @@ pub use crate::qml::*;
 mod quickcontrols;
 #[cfg(feature = "qt_quickcontrols")]
 pub use crate::quickcontrols::*;
+
+#[cfg(feature = "prost-reflect")]
+mod protobuf;
+#[cfg(feature = "prost-reflect")]
+pub use crate::protobuf::*;
+
+#[cfg(feature = "rusqlite")]
+mod sql;
+#[cfg(feature = "rusqlite")]
+pub use crate::sql::*;
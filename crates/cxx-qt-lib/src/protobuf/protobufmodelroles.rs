@@ -0,0 +1,187 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    QByteArray, QHash, QHashPair_i32_QByteArray, QList, QMap, QMapPair_QString_QVariant, QString,
+    QStringList, QVariant,
+};
+use prost_reflect::{DynamicMessage, ReflectMessage, Value};
+
+/// The role of the first field of a message, this is the value of `Qt::UserRole`
+const FIRST_FIELD_ROLE: i32 = 0x0100;
+
+/// Expose the fields of a protobuf message as model roles and QML values.
+///
+/// This is implemented for any message which implements [`ReflectMessage`],
+/// for example by using `prost-reflect-build` to generate the message types.
+///
+/// Each field of the message becomes a role starting at `Qt::UserRole`,
+/// in the order the fields are declared in the message. The role name is the
+/// JSON name of the field, which is the lowerCamelCase name that QML expects.
+///
+/// Scalar, string, bytes, and enum fields are converted to a [`QVariant`],
+/// repeated string fields become a [`QStringList`], other fields are an invalid [`QVariant`].
+pub trait ProtobufModelRoles: ReflectMessage + Default {
+    /// The role names of the fields of the message,
+    /// this can be returned from `QAbstractItemModel::roleNames`
+    fn role_names() -> QHash<QHashPair_i32_QByteArray> {
+        let mut role_names = QHash::<QHashPair_i32_QByteArray>::default();
+        for (role, field) in (FIRST_FIELD_ROLE..).zip(Self::default().descriptor().fields()) {
+            role_names.insert(role, QByteArray::from(field.json_name()));
+        }
+        role_names
+    }
+
+    /// The value of the field for the given role,
+    /// this can be returned from `QAbstractItemModel::data`
+    fn role_data(&self, role: i32) -> QVariant {
+        let message = self.transcode_to_dynamic();
+        usize::try_from(role - FIRST_FIELD_ROLE)
+            .ok()
+            .and_then(|index| message.descriptor().fields().nth(index))
+            .map(|field| qvariant_from_value(&message.get_field(&field)))
+            .unwrap_or_default()
+    }
+
+    /// Convert the message into a map from the JSON name of each field to the value of the field,
+    /// which is a JavaScript object when passed to QML
+    fn to_qvariantmap(&self) -> QMap<QMapPair_QString_QVariant> {
+        qvariantmap_from_message(&self.transcode_to_dynamic())
+    }
+}
+
+impl<T> ProtobufModelRoles for T where T: ReflectMessage + Default {}
+
+fn qvariantmap_from_message(message: &DynamicMessage) -> QMap<QMapPair_QString_QVariant> {
+    let mut map = QMap::<QMapPair_QString_QVariant>::default();
+    for field in message.descriptor().fields() {
+        map.insert(
+            QString::from(field.json_name()),
+            qvariant_from_value(&message.get_field(&field)),
+        );
+    }
+    map
+}
+
+fn qvariant_from_value(value: &Value) -> QVariant {
+    match value {
+        Value::Bool(value) => QVariant::from(value),
+        Value::I32(value) | Value::EnumNumber(value) => QVariant::from(value),
+        Value::I64(value) => QVariant::from(value),
+        Value::U32(value) => QVariant::from(value),
+        Value::U64(value) => QVariant::from(value),
+        Value::F32(value) => QVariant::from(value),
+        Value::F64(value) => QVariant::from(value),
+        Value::String(value) => QVariant::from(&QString::from(value)),
+        Value::Bytes(value) => QVariant::from(&QByteArray::from(value.as_ref())),
+        Value::List(values) => {
+            let mut list = QList::<QString>::default();
+            for value in values {
+                if let Value::String(value) = value {
+                    list.append(QString::from(value));
+                } else {
+                    return QVariant::default();
+                }
+            }
+            QVariant::from(&QStringList::from(&list))
+        }
+        Value::Message(_) | Value::Map(_) => QVariant::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost_reflect::prost_types::{field, Any, Field, FieldMask};
+
+    fn field() -> Field {
+        Field {
+            kind: field::Kind::TypeString.into(),
+            number: 3,
+            name: "title".to_owned(),
+            packed: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn role_names() {
+        let role_names = Field::role_names();
+        assert_eq!(role_names.len(), 10);
+        assert_eq!(role_names.get(&256), Some(QByteArray::from("kind")));
+        assert_eq!(role_names.get(&260), Some(QByteArray::from("typeUrl")));
+        assert_eq!(role_names.get(&264), Some(QByteArray::from("jsonName")));
+        assert_eq!(role_names.get(&266), None);
+    }
+
+    #[test]
+    fn role_data() {
+        let field = field();
+        assert_eq!(
+            field.role_data(256).value::<i32>(),
+            Some(field::Kind::TypeString as i32)
+        );
+        assert_eq!(field.role_data(258).value::<i32>(), Some(field.number));
+        assert_eq!(
+            field.role_data(259).value::<QString>().map(String::from),
+            Some(field.name.clone())
+        );
+        assert_eq!(field.role_data(262).value::<bool>(), Some(field.packed));
+        // Repeated messages and unknown roles are an invalid QVariant
+        assert!(field.role_data(263) == QVariant::default());
+        assert!(field.role_data(266) == QVariant::default());
+        assert!(field.role_data(0) == QVariant::default());
+    }
+
+    #[test]
+    fn role_data_bytes_and_strings() {
+        let any = Any {
+            type_url: "type.googleapis.com/Book".to_owned(),
+            value: vec![1, 2, 3],
+        };
+        assert_eq!(
+            any.role_data(257)
+                .value::<QByteArray>()
+                .map(|value| Vec::from(&value)),
+            Some(any.value)
+        );
+
+        let mask = FieldMask {
+            paths: vec!["title".to_owned(), "pages".to_owned()],
+        };
+        let paths = mask.role_data(256).value::<QStringList>().unwrap();
+        assert_eq!(
+            Vec::from(&QList::<QString>::from(&paths))
+                .iter()
+                .map(String::from)
+                .collect::<Vec<_>>(),
+            mask.paths
+        );
+    }
+
+    #[test]
+    fn to_qvariantmap() {
+        let field = field();
+        let map = field.to_qvariantmap();
+        assert_eq!(map.len(), 10);
+        assert_eq!(
+            map.get(&QString::from("name"))
+                .and_then(|value| value.value::<QString>())
+                .map(String::from),
+            Some(field.name.clone())
+        );
+        assert_eq!(
+            map.get(&QString::from("number"))
+                .and_then(|value| value.value::<i32>()),
+            Some(field.number)
+        );
+        // Unset fields have their default value
+        assert_eq!(
+            map.get(&QString::from("typeUrl"))
+                .and_then(|value| value.value::<QString>()),
+            Some(QString::default())
+        );
+    }
+}
@@ -6,5 +6,11 @@
 mod qqmlapplicationengine;
 pub use qqmlapplicationengine::QQmlApplicationEngine;
 
+mod qqmlcomponent;
+pub use qqmlcomponent::{QQmlComponent, QQmlComponentStatus};
+
+mod qqmlcontext;
+pub use qqmlcontext::QQmlContext;
+
 mod qqmlengine;
-pub use qqmlengine::QQmlEngine;
+pub use qqmlengine::{QQmlAbstractUrlInterceptorDataType, QQmlEngine, QmlWarning};
@@ -7,6 +7,11 @@
 
 #include "cxx-qt-lib/qqmlapplicationengine.h"
 
+#include <QtCore/QDirIterator>
+#include <QtCore/QFileSystemWatcher>
+#include <QtCore/QTimer>
+#include <QtQml/QQmlContext>
+
 namespace rust {
 namespace cxxqtlib1 {
 
@@ -22,5 +27,89 @@ qqmlapplicationengineAsQQmlEngine(QQmlApplicationEngine& engine)
   return static_cast<QQmlEngine&>(engine);
 }
 
+void
+qqmlapplicationengineReload(QQmlApplicationEngine& engine)
+{
+  // Find the URLs which the root objects were created from
+  QList<QUrl> urls;
+  const auto rootObjects = engine.rootObjects();
+  for (auto* object : rootObjects) {
+    if (auto* context = QQmlEngine::contextForObject(object)) {
+      urls.append(context->baseUrl());
+    }
+    delete object;
+  }
+
+  engine.clearComponentCache();
+
+  for (const auto& url : urls) {
+    engine.load(url);
+  }
+}
+
+void
+qqmlapplicationengineReloadOnChanges(QQmlApplicationEngine& engine,
+                                     const QStringList& directories)
+{
+  auto* watcher = new QFileSystemWatcher(&engine);
+
+  // Editors often replace files when saving, which removes them from the
+  // watcher, so the paths are added again after each change
+  const auto watchDirectories = [watcher, directories]() {
+    for (const auto& directory : directories) {
+      watcher->addPath(directory);
+
+      // Only the files in the directory itself are watched, the directories
+      // of any nested QML files are in the list of directories
+      QDirIterator it(directory,
+                      { QStringLiteral("*.qml"),
+                        QStringLiteral("*.js"),
+                        QStringLiteral("*.mjs") },
+                      QDir::Files);
+      while (it.hasNext()) {
+        watcher->addPath(it.next());
+      }
+    }
+  };
+  watchDirectories();
+
+  // Wait for a short time so that saving multiple files only reloads once
+  auto* timer = new QTimer(watcher);
+  timer->setInterval(100);
+  timer->setSingleShot(true);
+  QObject::connect(watcher,
+                   &QFileSystemWatcher::fileChanged,
+                   timer,
+                   static_cast<void (QTimer::*)()>(&QTimer::start));
+  QObject::connect(watcher,
+                   &QFileSystemWatcher::directoryChanged,
+                   timer,
+                   static_cast<void (QTimer::*)()>(&QTimer::start));
+  QObject::connect(
+    timer, &QTimer::timeout, &engine, [&engine, watchDirectories]() {
+      watchDirectories();
+      qqmlapplicationengineReload(engine);
+    });
+}
+
+QObject*
+qqmlapplicationengineRootObjectAt(const QQmlApplicationEngine& engine,
+                                  ::rust::isize index)
+{
+  Q_ASSERT(index >= 0);
+  // Qt 5 has an int Qt 6 has a qsizetype
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  return engine.rootObjects().at(static_cast<qsizetype>(index));
+#else
+  return engine.rootObjects().at(static_cast<int>(index));
+#endif
+}
+
+::rust::isize
+qqmlapplicationengineRootObjectsLen(const QQmlApplicationEngine& engine)
+{
+  return static_cast<::rust::isize>(engine.rootObjects().size());
+}
+
 }
 }
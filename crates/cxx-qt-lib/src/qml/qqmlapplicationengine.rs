@@ -3,9 +3,31 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-#[cxx::bridge]
+#[cxx_qt::bridge]
 mod ffi {
+    unsafe extern "C++Qt" {
+        include!("cxx-qt-lib/qqmlapplicationengine.h");
+        #[qobject]
+        type QQmlApplicationEngine;
+
+        /// This signal is emitted when an object finishes loading. If loading was successful,
+        /// object contains a pointer to the loaded object, otherwise the pointer is null.
+        ///
+        /// The url to the component the object came from is also provided.
+        #[qsignal]
+        #[cxx_name = "objectCreated"]
+        unsafe fn object_created(
+            self: Pin<&mut QQmlApplicationEngine>,
+            object: *mut QObject,
+            url: &QUrl,
+        );
+    }
+
     unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
         include!("cxx-qt-lib/qstring.h");
         type QString = crate::QString;
         include!("cxx-qt-lib/qstringlist.h");
@@ -13,9 +35,6 @@ mod ffi {
         include!("cxx-qt-lib/qurl.h");
         type QUrl = crate::QUrl;
 
-        include!("cxx-qt-lib/qqmlapplicationengine.h");
-        type QQmlApplicationEngine;
-
         /// Adds path as a directory where the engine searches for installed modules in a URL-based directory structure.
         #[rust_name = "add_import_path"]
         fn addImportPath(self: Pin<&mut QQmlApplicationEngine>, path: &QString);
@@ -29,6 +48,13 @@ mod ffi {
         #[rust_name = "base_url"]
         fn baseUrl(self: &QQmlApplicationEngine) -> QUrl;
 
+        /// Clears the engine's internal component cache.
+        ///
+        /// This function causes the property metadata of all components previously loaded by the engine to be destroyed.
+        /// All previously loaded components and the property bindings for all extant objects created from those components will cease to function.
+        #[rust_name = "clear_component_cache"]
+        fn clearComponentCache(self: Pin<&mut QQmlApplicationEngine>);
+
         /// Returns the list of directories where the engine searches for installed modules in a URL-based directory structure.
         #[rust_name = "import_path_list"]
         fn importPathList(self: &QQmlApplicationEngine) -> QStringList;
@@ -36,6 +62,12 @@ mod ffi {
         /// Loads the root QML file located at url.
         fn load(self: Pin<&mut QQmlApplicationEngine>, url: &QUrl);
 
+        /// Loads the QML given in data. The object tree defined by data is instantiated immediately.
+        ///
+        /// If a url is specified it is used as the base url of the component. This affects relative paths within the data and error messages.
+        #[rust_name = "load_data"]
+        fn loadData(self: Pin<&mut QQmlApplicationEngine>, data: &QByteArray, url: &QUrl);
+
         /// This property holds the directory for storing offline user data
         #[rust_name = "offline_storage_path"]
         fn offlineStoragePath(self: &QQmlApplicationEngine) -> QString;
@@ -77,6 +109,28 @@ mod ffi {
         fn qqmlapplicationengineAsQQmlEngine(
             ptr: Pin<&mut QQmlApplicationEngine>,
         ) -> Pin<&mut QQmlEngine>;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_reload"]
+        fn qqmlapplicationengineReload(engine: Pin<&mut QQmlApplicationEngine>);
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_reload_on_changes"]
+        fn qqmlapplicationengineReloadOnChanges(
+            engine: Pin<&mut QQmlApplicationEngine>,
+            directories: &QStringList,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_root_object_at"]
+        fn qqmlapplicationengineRootObjectAt(
+            engine: &QQmlApplicationEngine,
+            index: isize,
+        ) -> *mut QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlapplicationengine_root_objects_len"]
+        fn qqmlapplicationengineRootObjectsLen(engine: &QQmlApplicationEngine) -> isize;
     }
 
     // QQmlApplicationEngine is not a trivial to CXX and is not relocatable in Qt
@@ -86,19 +140,143 @@ mod ffi {
     impl UniquePtr<QQmlApplicationEngine> {}
 }
 
-use crate::QQmlEngine;
+use crate::{
+    QList, QObject, QQmlAbstractUrlInterceptorDataType, QQmlContext, QQmlEngine, QString,
+    QStringList, QUrl,
+};
 use core::pin::Pin;
+use cxx_qt::hotreload::{self, HotReloadSource};
+use std::path::PathBuf;
 
 pub use ffi::QQmlApplicationEngine;
 
 impl QQmlApplicationEngine {
     /// Convert the existing [QQmlApplicationEngine] to a [QQmlEngine]
+    ///
+    /// This can be used to observe the warnings of the engine with [QQmlEngine::on_warnings].
     pub fn as_qqmlengine<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut QQmlEngine> {
         ffi::qqmlapplicationengine_as_qqmlengine(self)
     }
 
+    /// Enable reloading the QML of the engine whenever it is changed in its source directories.
+    ///
+    /// This is intended for development, so that changes to QML files are visible without rebuilding the application.
+    /// The `sources` are the value of the `CXX_QT_QML_HOT_RELOAD` environment variable which is set by
+    /// `CxxQtBuilder::qml_hot_reload` in debug builds, so it is typically enabled as follows.
+    ///
+    /// ```ignore
+    /// if let Some(sources) = option_env!("CXX_QT_QML_HOT_RELOAD") {
+    ///     engine.pin_mut().enable_hot_reload(sources);
+    /// }
+    /// ```
+    ///
+    /// The QML and JavaScript files of the QML modules are then loaded from their source directories instead of
+    /// the Qt resource system and the engine is [reloaded](QQmlApplicationEngine::reload) whenever the files in
+    /// the directories containing the `qml_files` of the modules change.
+    /// This replaces any URL interceptor which was set with [QQmlEngine::set_url_interceptor].
+    pub fn enable_hot_reload(mut self: Pin<&mut Self>, sources: &str) {
+        let mut prefixes: Vec<(String, PathBuf)> = vec![];
+        let mut directories = QList::<QString>::default();
+        for source in hotreload::read_sources(sources) {
+            match source {
+                HotReloadSource::Prefix { prefix, directory } => {
+                    prefixes.push((prefix.to_owned(), PathBuf::from(directory)))
+                }
+                HotReloadSource::Watch(directory) => directories.append(QString::from(directory)),
+            }
+        }
+
+        self.as_mut()
+            .as_qqmlengine()
+            .set_url_interceptor(move |url, data_type| {
+                // The qmldir files are generated during the build so only exist in the Qt resources
+                if data_type == QQmlAbstractUrlInterceptorDataType::QmldirFile {
+                    return url.clone();
+                }
+
+                match hot_reload_local_file(&prefixes, &url.to_string()) {
+                    Some(path) => QUrl::from_local_file(&QString::from(&*path.to_string_lossy())),
+                    None => url.clone(),
+                }
+            });
+        ffi::qqmlapplicationengine_reload_on_changes(self, &QStringList::from(&directories));
+    }
+
     /// Create a new QQmlApplicationEngine
     pub fn new() -> cxx::UniquePtr<Self> {
         ffi::qqmlapplicationengine_new()
     }
+
+    /// Reload the QML of the engine.
+    ///
+    /// The [root objects](QQmlApplicationEngine::root_objects) are destroyed, the component cache is cleared
+    /// and the URLs which the root objects were created from are loaded again.
+    /// Any pointers to the previous root objects or objects created by them are dangling afterwards.
+    ///
+    /// Note that singletons are not recreated, so any state in Rust singletons is preserved.
+    pub fn reload(self: Pin<&mut Self>) {
+        ffi::qqmlapplicationengine_reload(self)
+    }
+
+    /// Returns the engine's root context, see [QQmlEngine::root_context].
+    pub fn root_context(self: Pin<&mut Self>) -> Pin<&mut QQmlContext> {
+        self.as_qqmlengine().root_context()
+    }
+
+    /// Returns a list of all the root objects instantiated by the QQmlApplicationEngine.
+    /// This will only contain objects loaded via [QQmlApplicationEngine::load] or a convenience constructor.
+    pub fn root_objects(&self) -> Vec<*mut QObject> {
+        (0..ffi::qqmlapplicationengine_root_objects_len(self))
+            .map(|index| ffi::qqmlapplicationengine_root_object_at(self, index))
+            .collect()
+    }
+}
+
+/// The file in the source directories which the URL is loaded from with hot reload,
+/// or [None] if the URL does not start with any of the prefixes or the file does not exist
+fn hot_reload_local_file(prefixes: &[(String, PathBuf)], url: &str) -> Option<PathBuf> {
+    prefixes.iter().find_map(|(prefix, directory)| {
+        let path = directory.join(url.strip_prefix(prefix.as_str())?);
+        path.is_file().then_some(path)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn hot_reload_local_file_from_prefix() {
+        let directory =
+            std::env::temp_dir().join(format!("cxx-qt-lib-hot-reload-{}", std::process::id()));
+        fs::create_dir_all(directory.join("qml")).unwrap();
+        fs::write(directory.join("qml/main.qml"), "").unwrap();
+
+        let other = std::env::temp_dir().join("cxx-qt-lib-hot-reload-missing");
+        let prefixes = vec![
+            ("qrc:/qt/qml/com/kdab/other/".to_owned(), other),
+            ("qrc:/qt/qml/com/kdab/demo/".to_owned(), directory.clone()),
+        ];
+
+        assert_eq!(
+            hot_reload_local_file(&prefixes, "qrc:/qt/qml/com/kdab/demo/qml/main.qml"),
+            Some(directory.join("qml/main.qml"))
+        );
+        // Files which do not exist in the source directory are loaded from the Qt resources
+        assert_eq!(
+            hot_reload_local_file(&prefixes, "qrc:/qt/qml/com/kdab/demo/qml/missing.qml"),
+            None
+        );
+        assert_eq!(
+            hot_reload_local_file(&prefixes, "qrc:/qt/qml/com/kdab/other/qml/main.qml"),
+            None
+        );
+        assert_eq!(
+            hot_reload_local_file(&prefixes, "qrc:/qt/qml/com/kdab/unknown/qml/main.qml"),
+            None
+        );
+
+        fs::remove_dir_all(directory).unwrap();
+    }
 }
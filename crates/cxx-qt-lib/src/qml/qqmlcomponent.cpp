@@ -0,0 +1,27 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#include "cxx-qt-lib/qqmlcomponent.h"
+
+namespace rust {
+namespace cxxqtlib1 {
+
+::std::unique_ptr<QObject>
+qqmlcomponentCreate(QQmlComponent& component)
+{
+  // The ownership of the created object is transferred to the caller
+  return ::std::unique_ptr<QObject>(component.create());
+}
+
+::std::unique_ptr<QQmlComponent>
+qqmlcomponentNew(QQmlEngine& engine)
+{
+  return ::std::make_unique<QQmlComponent>(&engine);
+}
+
+}
+}
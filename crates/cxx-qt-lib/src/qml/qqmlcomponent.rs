@@ -0,0 +1,156 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    /// Specifies the loading status of the QQmlComponent.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QQmlComponentStatus {
+        /// This QQmlComponent has no data. Call loadUrl() or setData() to add QML content.
+        Null,
+        /// This QQmlComponent is ready and create() may be called.
+        Ready,
+        /// This QQmlComponent is loading network data.
+        Loading,
+        /// An error has occurred. Call errors() to retrieve a list of errors.
+        Error,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = crate::QByteArray;
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = crate::QQmlEngine;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+
+        include!("cxx-qt-lib/qqmlcomponent.h");
+        /// Components are reusable, encapsulated QML types with well-defined interfaces.
+        ///
+        /// A QQmlComponent instance can be created from a QML file or from QML data,
+        /// and then used to create instances of the component with [QQmlComponent::create].
+        type QQmlComponent;
+
+        /// Returns true if status() == QQmlComponent::Error.
+        #[rust_name = "is_error"]
+        fn isError(self: &QQmlComponent) -> bool;
+
+        /// Returns true if status() == QQmlComponent::Loading.
+        #[rust_name = "is_loading"]
+        fn isLoading(self: &QQmlComponent) -> bool;
+
+        /// Returns true if status() == QQmlComponent::Null.
+        #[rust_name = "is_null"]
+        fn isNull(self: &QQmlComponent) -> bool;
+
+        /// Returns true if status() == QQmlComponent::Ready.
+        #[rust_name = "is_ready"]
+        fn isReady(self: &QQmlComponent) -> bool;
+
+        /// Load the QQmlComponent from the provided url.
+        ///
+        /// Ensure that the URL provided is full and correct, in particular, use QUrl::fromLocalFile() when loading a file from the local filesystem.
+        #[rust_name = "load_url"]
+        fn loadUrl(self: Pin<&mut QQmlComponent>, url: &QUrl);
+
+        /// The progress of loading the component, from 0.0 (nothing loaded) to 1.0 (finished).
+        fn progress(self: &QQmlComponent) -> f64;
+
+        /// Sets the QQmlComponent to use the given QML data.
+        ///
+        /// If url is provided, it is used to set the component name and to provide a base path for items resolved by this component.
+        #[rust_name = "set_data"]
+        fn setData(self: Pin<&mut QQmlComponent>, data: &QByteArray, url: &QUrl);
+
+        /// Returns the component's current status.
+        fn status(self: &QQmlComponent) -> QQmlComponentStatus;
+
+        /// The component URL. This is the URL passed to either the constructor, or the loadUrl(), or setData() methods.
+        fn url(self: &QQmlComponent) -> QUrl;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        type QQmlComponentStatus;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlcomponent_create"]
+        fn qqmlcomponentCreate(component: Pin<&mut QQmlComponent>) -> UniquePtr<QObject>;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlcomponent_errors"]
+        fn qqmlcomponentErrors(
+            component: &QQmlComponent,
+            func: fn(&mut QQmlComponentErrors, &QUrl, i32, i32, &QString),
+            errors: &mut QQmlComponentErrors,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlcomponent_new"]
+        fn qqmlcomponentNew(engine: Pin<&mut QQmlEngine>) -> UniquePtr<QQmlComponent>;
+    }
+
+    extern "Rust" {
+        #[namespace = "rust::cxxqtlib1"]
+        type QQmlComponentErrors;
+    }
+
+    // QQmlComponent is a QObject so it cannot be moved and needs to use references or pointers.
+    impl UniquePtr<QQmlComponent> {}
+}
+
+use crate::{QObject, QQmlEngine, QString, QUrl, QmlWarning};
+use core::pin::Pin;
+
+pub use ffi::{QQmlComponent, QQmlComponentStatus};
+
+impl QQmlComponent {
+    /// Create a QQmlComponent with no data for the given engine.
+    /// Set the data with [QQmlComponent::load_url] or [QQmlComponent::set_data].
+    pub fn new(engine: Pin<&mut QQmlEngine>) -> cxx::UniquePtr<Self> {
+        ffi::qqmlcomponent_new(engine)
+    }
+
+    /// Create an object instance from this component, within the root context of the engine.
+    /// Returns a null pointer if creation failed, in which case [QQmlComponent::errors] describes why.
+    ///
+    /// The ownership of the created object is transferred to the caller.
+    pub fn create(self: Pin<&mut Self>) -> cxx::UniquePtr<QObject> {
+        ffi::qqmlcomponent_create(self)
+    }
+
+    /// Returns the list of errors that occurred during the last compile or create operation.
+    /// An empty list is returned if [QQmlComponent::is_error] is not set.
+    pub fn errors(&self) -> Vec<QmlWarning> {
+        fn func(
+            errors: &mut QQmlComponentErrors,
+            url: &QUrl,
+            line: i32,
+            column: i32,
+            description: &QString,
+        ) {
+            errors.inner.push(QmlWarning {
+                url: url.clone(),
+                line,
+                column,
+                description: description.clone(),
+            });
+        }
+        let mut errors = QQmlComponentErrors { inner: vec![] };
+        ffi::qqmlcomponent_errors(self, func, &mut errors);
+        errors.inner
+    }
+}
+
+#[doc(hidden)]
+pub struct QQmlComponentErrors {
+    inner: Vec<QmlWarning>,
+}
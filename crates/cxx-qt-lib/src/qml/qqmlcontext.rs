@@ -0,0 +1,64 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = crate::QObject;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = crate::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = crate::QUrl;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = crate::QVariant;
+
+        include!("cxx-qt-lib/qqmlcontext.h");
+        /// The QQmlContext class defines a context within a QML engine.
+        ///
+        /// Contexts hold the properties which are available to the QML components instantiated in them by name.
+        /// The root context of an engine is available from [QQmlEngine::root_context](crate::QQmlEngine::root_context).
+        type QQmlContext;
+
+        /// Returns the base url of the component, or the containing component if none is set.
+        #[rust_name = "base_url"]
+        fn baseUrl(self: &QQmlContext) -> QUrl;
+
+        /// Returns the value of the name property for this context as a QVariant.
+        #[rust_name = "context_property"]
+        fn contextProperty(self: &QQmlContext, name: &QString) -> QVariant;
+
+        /// Returns whether the context is valid.
+        ///
+        /// To be valid, a context must have a engine, and it's contextObject(), if any, must not have been deleted.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QQmlContext) -> bool;
+
+        /// Explicitly sets the url both resolved_url and base_url will use for this context to base_url.
+        #[rust_name = "set_base_url"]
+        fn setBaseUrl(self: Pin<&mut QQmlContext>, base_url: &QUrl);
+
+        /// Set the value of the name property on this context.
+        #[rust_name = "set_context_property"]
+        fn setContextProperty(self: Pin<&mut QQmlContext>, name: &QString, value: &QVariant);
+
+        /// Set the value of the name property on this context to the given object.
+        ///
+        /// QQmlContext does not take ownership of value.
+        ///
+        /// # Safety
+        ///
+        /// The value must be a valid pointer or null, and must outlive its use by QML.
+        #[cxx_name = "setContextProperty"]
+        #[rust_name = "set_context_property_object"]
+        unsafe fn setContextProperty(
+            self: Pin<&mut QQmlContext>,
+            name: &QString,
+            value: *mut QObject,
+        );
+    }
+}
+
+pub use ffi::QQmlContext;
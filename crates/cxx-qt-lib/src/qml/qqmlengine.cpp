@@ -7,14 +7,154 @@
 
 #include "cxx-qt-lib/qqmlengine.h"
 
+#include <mutex>
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QHash>
+#include <QtCore/QList>
+
+#include <cxx-qt/thread.h>
+
+namespace {
+
+// The shutdown state of an engine which has been used from Rust
+struct ShutdownState
+{
+  QList<::std::function<void()>> callbacks;
+  bool shutdown = false;
+};
+
+::std::mutex shutdownStatesMutex;
+QHash<const QQmlEngine*, ShutdownState> shutdownStates;
+
+// Whether the QObject belongs to an engine which has been shut down
+bool
+isShutdown(const QObject& qobject)
+{
+  const auto* engine = qmlEngine(&qobject);
+  if (!engine) {
+    return false;
+  }
+
+  const auto guard = ::std::unique_lock(shutdownStatesMutex);
+  const auto it = shutdownStates.constFind(engine);
+  return it != shutdownStates.constEnd() && it->shutdown;
+}
+
+// Returns the shutdown state of the engine, creating it if needed
+//
+// This must be called with the mutex locked
+ShutdownState&
+shutdownState(QQmlEngine& engine)
+{
+  auto enginePtr = &engine;
+  if (!shutdownStates.contains(enginePtr)) {
+    // Run the callbacks before the event loop returns, which is before the
+    // engine and the objects created by QML are destroyed
+    if (auto app = QCoreApplication::instance()) {
+      QObject::connect(
+        app, &QCoreApplication::aboutToQuit, enginePtr, [enginePtr]() {
+          ::rust::cxxqtlib1::qqmlengineShutdown(*enginePtr);
+        });
+    }
+
+    // If the engine is destroyed without shutting down then drop the
+    // callbacks without calling them
+    QObject::connect(enginePtr, &QObject::destroyed, [enginePtr]() {
+      // Take the state so that the callbacks are dropped outside of the lock
+      ShutdownState state;
+      {
+        const auto guard = ::std::unique_lock(shutdownStatesMutex);
+        state = shutdownStates.take(enginePtr);
+      }
+    });
+  }
+
+  return shutdownStates[enginePtr];
+}
+
+}
+
 namespace rust {
 namespace cxxqtlib1 {
 
+QQmlEngineUrlInterceptorBase::QQmlEngineUrlInterceptorBase(QQmlEngine& engine)
+  : QObject(&engine)
+{
+}
+
 ::std::unique_ptr<QQmlEngine>
 qqmlengineNew()
 {
   return ::std::make_unique<QQmlEngine>();
 }
 
+void
+qqmlengineAddShutdownCallback(QQmlEngine& engine,
+                              ::std::function<void()> callback)
+{
+  const auto guard = ::std::unique_lock(shutdownStatesMutex);
+  shutdownState(engine).callbacks.append(::std::move(callback));
+}
+
+void
+qqmlengineReplaceUrlInterceptor(QQmlEngine& engine,
+                                QQmlEngineUrlInterceptorBase* interceptor)
+{
+  // Remove the interceptor which was previously set from Rust, if there is one
+  for (auto* child : engine.children()) {
+    if (auto* previous = dynamic_cast<QQmlEngineUrlInterceptorBase*>(child)) {
+      if (previous != interceptor) {
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+        engine.removeUrlInterceptor(previous);
+#endif
+        delete previous;
+        break;
+      }
+    }
+  }
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  engine.addUrlInterceptor(interceptor);
+#else
+  engine.setUrlInterceptor(interceptor);
+#endif
+}
+
+QQmlContext&
+qqmlengineRootContext(QQmlEngine& engine)
+{
+  return *engine.rootContext();
+}
+
+void
+qqmlengineShutdown(QQmlEngine& engine)
+{
+  // Take the callbacks so that they are only called once, and so that the
+  // callbacks can register further callbacks without deadlocking
+  decltype(ShutdownState::callbacks) callbacks;
+  {
+    const auto guard = ::std::unique_lock(shutdownStatesMutex);
+    callbacks.swap(shutdownState(engine).callbacks);
+  }
+
+  for (const auto& callback : callbacks) {
+    callback();
+  }
+
+  // Reject any closures which are queued with CxxQtThread to the QObjects of
+  // the engine from now on, as the event loop may not run again
+  {
+    const auto guard = ::std::unique_lock(shutdownStatesMutex);
+    shutdownState(engine).shutdown = true;
+  }
+  ::rust::cxxqt1::cxxQtThreadShutdownCheck().store(isShutdown);
+
+  // Drain any closures which were queued with CxxQtThread before the shutdown
+  // while the QObjects are still alive
+  QCoreApplication::sendPostedEvents(
+    nullptr, ::rust::cxxqt1::CxxQtThreadEventBase::eventType());
+}
+
 }
 }
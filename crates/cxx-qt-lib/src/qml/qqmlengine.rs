@@ -5,6 +5,21 @@
 
 #[cxx_qt::bridge]
 mod ffi {
+    /// The type of file which a URL refers to when it is passed to a URL interceptor.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtlib1"]
+    #[derive(Debug)]
+    enum QQmlAbstractUrlInterceptorDataType {
+        /// The URL refers to a QML file.
+        QmlFile = 0,
+        /// The URL refers to a JavaScript file.
+        JavaScriptFile = 1,
+        /// The URL refers to a qmldir file.
+        QmldirFile = 2,
+        /// The URL was a string in QML which is being converted to a URL.
+        UrlString = 0x1000,
+    }
+
     unsafe extern "C++Qt" {
         include!("cxx-qt-lib/qqmlengine.h");
         #[qobject]
@@ -20,12 +35,17 @@ mod ffi {
     }
 
     unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlcontext.h");
+        type QQmlContext = crate::QQmlContext;
         include!("cxx-qt-lib/qstring.h");
         type QString = crate::QString;
         include!("cxx-qt-lib/qstringlist.h");
         type QStringList = crate::QStringList;
         include!("cxx-qt-lib/qurl.h");
         type QUrl = crate::QUrl;
+        include!("cxx-qt/connection.h");
+        #[namespace = "rust::cxxqt1"]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
 
         /// Adds path as a directory where the engine searches for installed modules in a URL-based directory structure.
         #[rust_name = "add_import_path"]
@@ -40,6 +60,13 @@ mod ffi {
         #[rust_name = "base_url"]
         fn baseUrl(self: &QQmlEngine) -> QUrl;
 
+        /// Clears the engine's internal component cache.
+        ///
+        /// This function causes the property metadata of all components previously loaded by the engine to be destroyed.
+        /// All previously loaded components and the property bindings for all extant objects created from those components will cease to function.
+        #[rust_name = "clear_component_cache"]
+        fn clearComponentCache(self: Pin<&mut QQmlEngine>);
+
         /// Returns the list of directories where the engine searches for installed modules in a URL-based directory structure.
         #[rust_name = "import_path_list"]
         fn importPathList(self: &QQmlEngine) -> QStringList;
@@ -79,9 +106,58 @@ mod ffi {
 
     #[namespace = "rust::cxxqtlib1"]
     unsafe extern "C++" {
+        type QQmlAbstractUrlInterceptorDataType;
+
         #[doc(hidden)]
         #[rust_name = "qqmlengine_new"]
         fn qqmlengineNew() -> UniquePtr<QQmlEngine>;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_on_shutdown"]
+        fn qqmlengineOnShutdown(
+            engine: Pin<&mut QQmlEngine>,
+            func: fn(Pin<&mut QQmlEngine>, Box<QQmlEngineShutdownCallback>),
+            arg: Box<QQmlEngineShutdownCallback>,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_root_context"]
+        fn qqmlengineRootContext(engine: Pin<&mut QQmlEngine>) -> Pin<&mut QQmlContext>;
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_set_url_interceptor"]
+        fn qqmlengineSetUrlInterceptor(
+            engine: Pin<&mut QQmlEngine>,
+            func: fn(
+                &QQmlEngineUrlInterceptorHandler,
+                &QUrl,
+                QQmlAbstractUrlInterceptorDataType,
+            ) -> QUrl,
+            arg: Box<QQmlEngineUrlInterceptorHandler>,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_shutdown"]
+        fn qqmlengineShutdown(engine: Pin<&mut QQmlEngine>);
+
+        #[doc(hidden)]
+        #[rust_name = "qqmlengine_on_warnings"]
+        fn qqmlengineOnWarnings(
+            engine: Pin<&mut QQmlEngine>,
+            func: fn(&mut QQmlEngineWarningHandler, &QUrl, i32, i32, &QString),
+            arg: Box<QQmlEngineWarningHandler>,
+        ) -> QMetaObjectConnection;
+    }
+
+    extern "Rust" {
+        #[namespace = "rust::cxxqtlib1"]
+        type QQmlEngineShutdownCallback;
+
+        #[namespace = "rust::cxxqtlib1"]
+        type QQmlEngineUrlInterceptorHandler;
+
+        #[namespace = "rust::cxxqtlib1"]
+        type QQmlEngineWarningHandler;
     }
 
     // QQmlEngine is not a trivial to CXX and is not relocatable in Qt
@@ -91,11 +167,167 @@ mod ffi {
     impl UniquePtr<QQmlEngine> {}
 }
 
-pub use ffi::QQmlEngine;
+use core::pin::Pin;
+use cxx_qt::QMetaObjectConnection;
+
+use crate::{QQmlContext, QString, QUrl};
+
+pub use ffi::{QQmlAbstractUrlInterceptorDataType, QQmlEngine};
+
+/// A warning reported by a [QQmlEngine], such as a binding error or an uncaught JavaScript exception.
+///
+/// This contains the same information as a `QQmlError` in C++.
+/// Note that Qt does not provide the JavaScript stack of an exception, only the location where it was thrown.
+#[derive(Clone, Debug)]
+pub struct QmlWarning {
+    /// The URL of the file which caused the warning
+    pub url: QUrl,
+    /// The line number in the file, or -1 if it is unknown
+    pub line: i32,
+    /// The column number in the line, or -1 if it is unknown
+    pub column: i32,
+    /// The description of the warning
+    pub description: QString,
+}
+
+impl std::fmt::Display for QmlWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {}",
+            self.url, self.line, self.column, self.description
+        )
+    }
+}
 
 impl QQmlEngine {
     /// Create a new QQmlEngine
     pub fn new() -> cxx::UniquePtr<Self> {
         ffi::qqmlengine_new()
     }
+
+    /// Returns the engine's root context.
+    ///
+    /// The root context is automatically created by the engine.
+    /// Data that should be available to all QML component instances instantiated by the engine
+    /// should be put in the root context, for example with [QQmlContext::set_context_property].
+    pub fn root_context(self: Pin<&mut Self>) -> Pin<&mut QQmlContext> {
+        ffi::qqmlengine_root_context(self)
+    }
+
+    /// Register a callback which is called when the engine is shut down,
+    /// before the engine destroys the objects which were created by QML.
+    ///
+    /// The callbacks are called in the order that they were registered,
+    /// either when [QQmlEngine::shutdown] is called or when the `QCoreApplication` is about to quit.
+    /// This allows for stopping background threads and dropping Rust state which refers to QML objects
+    /// while those objects are still alive.
+    ///
+    /// If the engine is destroyed without being shut down the callbacks are dropped without being called.
+    pub fn on_shutdown<F>(self: Pin<&mut Self>, callback: F)
+    where
+        F: FnOnce(Pin<&mut QQmlEngine>) + 'static,
+    {
+        // Wrap the given closure and pass in to C++ function as an opaque type
+        // to work around the cxx limitation.
+        // https://github.com/dtolnay/cxx/issues/114
+        #[allow(clippy::boxed_local)]
+        fn func(engine: Pin<&mut QQmlEngine>, arg: Box<QQmlEngineShutdownCallback>) {
+            (arg.inner)(engine)
+        }
+        let arg = QQmlEngineShutdownCallback {
+            inner: Box::new(callback),
+        };
+        ffi::qqmlengine_on_shutdown(self, func, Box::new(arg));
+    }
+
+    /// Connect a closure which is called for each warning that the engine reports,
+    /// such as QML binding errors and uncaught JavaScript exceptions.
+    ///
+    /// This allows for collecting the errors of the UI in the same place as other errors.
+    /// Warnings are still written to stderr unless [QQmlEngine::set_output_warnings_to_standard_error] is used to disable this.
+    ///
+    /// The returned [QMetaObjectConnection] can be used to disconnect the closure.
+    pub fn on_warnings<F>(self: Pin<&mut Self>, closure: F) -> QMetaObjectConnection
+    where
+        F: FnMut(&QmlWarning) + 'static,
+    {
+        fn func(
+            handler: &mut QQmlEngineWarningHandler,
+            url: &QUrl,
+            line: i32,
+            column: i32,
+            description: &QString,
+        ) {
+            (handler.inner)(&QmlWarning {
+                url: url.clone(),
+                line,
+                column,
+                description: description.clone(),
+            })
+        }
+        let arg = QQmlEngineWarningHandler {
+            inner: Box::new(closure),
+        };
+        ffi::qqmlengine_on_warnings(self, func, Box::new(arg))
+    }
+
+    /// Set a closure which intercepts the URLs used by QML, such as the URLs of QML and JavaScript files
+    /// which are loaded and URLs which are converted from strings, and returns the URL to use instead.
+    ///
+    /// This can be used to redirect files to a different location, for example to select files depending on the platform.
+    /// Any interceptor which was previously set with this method is replaced.
+    ///
+    /// Note that the closure may be called from the threads which load QML files in the background.
+    pub fn set_url_interceptor<F>(self: Pin<&mut Self>, interceptor: F)
+    where
+        F: Fn(&QUrl, QQmlAbstractUrlInterceptorDataType) -> QUrl + Send + Sync + 'static,
+    {
+        fn func(
+            handler: &QQmlEngineUrlInterceptorHandler,
+            url: &QUrl,
+            data_type: QQmlAbstractUrlInterceptorDataType,
+        ) -> QUrl {
+            (handler.inner)(url, data_type)
+        }
+        let arg = QQmlEngineUrlInterceptorHandler {
+            inner: Box::new(interceptor),
+        };
+        ffi::qqmlengine_set_url_interceptor(self, func, Box::new(arg));
+    }
+
+    /// Shut down the engine by calling the callbacks registered with [QQmlEngine::on_shutdown],
+    /// then executing any closures which are pending from [cxx_qt::CxxQtThread::queue].
+    ///
+    /// Once the callbacks have been called, closures which are queued with [cxx_qt::CxxQtThread]
+    /// to QObjects of this engine, such as the QObjects created by QML, are rejected with an error.
+    ///
+    /// This is called automatically when the `QCoreApplication` is about to quit,
+    /// it only needs to be called when the engine is destroyed before then.
+    /// Callbacks are only called once, even if the engine is shut down multiple times.
+    pub fn shutdown(self: Pin<&mut Self>) {
+        ffi::qqmlengine_shutdown(self)
+    }
 }
+
+#[doc(hidden)]
+pub struct QQmlEngineShutdownCallback {
+    // An opaque Rust type is required to be Sized.
+    // https://github.com/dtolnay/cxx/issues/665
+    inner: ShutdownFn,
+}
+
+type ShutdownFn = Box<dyn FnOnce(Pin<&mut QQmlEngine>)>;
+
+#[doc(hidden)]
+pub struct QQmlEngineWarningHandler {
+    inner: Box<dyn FnMut(&QmlWarning)>,
+}
+
+#[doc(hidden)]
+pub struct QQmlEngineUrlInterceptorHandler {
+    inner: UrlInterceptorFn,
+}
+
+type UrlInterceptorFn =
+    Box<dyn Fn(&QUrl, QQmlAbstractUrlInterceptorDataType) -> QUrl + Send + Sync>;
@@ -0,0 +1,279 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{QByteArray, QHash, QHashPair_i32_QByteArray, QString, QVariant};
+use rusqlite::{types::ValueRef, Connection, Result};
+
+/// The role of the first column of a query, this is the value of `Qt::UserRole`
+const FIRST_COLUMN_ROLE: i32 = 0x0100;
+
+/// The default number of rows which are fetched at once
+const DEFAULT_BATCH_SIZE: usize = 256;
+
+/// The rows of an SQL query which can back a list model, similar to a `QSqlQueryModel`.
+///
+/// Each column of the query becomes a role starting at `Qt::UserRole` with the column name as the role name.
+///
+/// Rows are fetched in batches so that large results are loaded incrementally as the view scrolls,
+/// this is designed to be used from the `canFetchMore` and `fetchMore` methods of a `QAbstractListModel`.
+///
+/// ```ignore
+/// fn fetch_more(mut self: Pin<&mut Self>, _parent: &QModelIndex) {
+///     let batch = self.rows.fetch_batch(&self.connection).unwrap();
+///     match batch.last_row() {
+///         Some(last_row) => unsafe {
+///             self.as_mut().begin_insert_rows(&QModelIndex::default(), batch.first_row(), last_row);
+///             self.as_mut().rust_mut().rows.append(batch);
+///             self.as_mut().end_insert_rows();
+///         },
+///         // Appending an empty batch marks the end of the query
+///         None => self.as_mut().rust_mut().rows.append(batch),
+///     }
+/// }
+/// ```
+pub struct SqlQueryRows {
+    query: String,
+    columns: Vec<String>,
+    rows: Vec<Vec<QVariant>>,
+    batch_size: usize,
+    at_end: bool,
+}
+
+/// A batch of rows fetched by [`SqlQueryRows::fetch_batch`] which have not been appended yet.
+pub struct SqlQueryBatch {
+    first_row: i32,
+    rows: Vec<Vec<QVariant>>,
+}
+
+impl SqlQueryBatch {
+    /// The index of the first row of the batch in the model
+    pub fn first_row(&self) -> i32 {
+        self.first_row
+    }
+
+    /// The index of the last row of the batch in the model, or [None] if the batch is empty
+    pub fn last_row(&self) -> Option<i32> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.first_row + self.len() - 1)
+        }
+    }
+
+    /// Returns true if the batch contains no rows
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// The number of rows in the batch
+    pub fn len(&self) -> i32 {
+        i32::try_from(self.rows.len()).unwrap_or(i32::MAX)
+    }
+}
+
+impl SqlQueryRows {
+    /// Prepare the given query to find the columns, no rows are fetched until [`SqlQueryRows::fetch_batch`] is called
+    ///
+    /// The query must be a single `SELECT` statement without a trailing semicolon, as it is wrapped to fetch each batch.
+    pub fn new(connection: &Connection, query: &str) -> Result<Self> {
+        let statement = connection.prepare(query)?;
+        let columns = statement
+            .column_names()
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+
+        Ok(Self {
+            query: query.to_owned(),
+            columns,
+            rows: vec![],
+            batch_size: DEFAULT_BATCH_SIZE,
+            at_end: false,
+        })
+    }
+
+    /// Set the number of rows which are fetched in each batch
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Append a batch of rows, this should be called between `beginInsertRows` and `endInsertRows`
+    pub fn append(&mut self, batch: SqlQueryBatch) {
+        // A batch which is not full means that the end of the query was reached
+        if batch.rows.len() < self.batch_size {
+            self.at_end = true;
+        }
+        self.rows.extend(batch.rows);
+    }
+
+    /// Returns true if there may be more rows in the query which have not been fetched yet
+    pub fn can_fetch_more(&self) -> bool {
+        !self.at_end
+    }
+
+    /// Returns the names of the columns of the query
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// The value of the given column role in the given row, or an invalid [`QVariant`] if it does not exist
+    pub fn data(&self, row: i32, role: i32) -> QVariant {
+        usize::try_from(row)
+            .ok()
+            .zip(usize::try_from(role - FIRST_COLUMN_ROLE).ok())
+            .and_then(|(row, column)| self.rows.get(row)?.get(column).cloned())
+            .unwrap_or_default()
+    }
+
+    /// Fetch the next batch of rows from the query, the batch is empty when all the rows have been fetched
+    pub fn fetch_batch(&self, connection: &Connection) -> Result<SqlQueryBatch> {
+        let first_row = self.rows.len();
+        let mut rows = vec![];
+
+        if !self.at_end {
+            let mut statement = connection.prepare(&format!(
+                "SELECT * FROM ({}) LIMIT {} OFFSET {}",
+                self.query, self.batch_size, first_row
+            ))?;
+            let column_count = statement.column_count();
+            let mut query_rows = statement.query([])?;
+            while let Some(query_row) = query_rows.next()? {
+                let mut row = Vec::with_capacity(column_count);
+                for column in 0..column_count {
+                    row.push(qvariant_from_value_ref(query_row.get_ref(column)?));
+                }
+                rows.push(row);
+            }
+        }
+
+        Ok(SqlQueryBatch {
+            first_row: i32::try_from(first_row).unwrap_or(i32::MAX),
+            rows,
+        })
+    }
+
+    /// The role names of the columns of the query,
+    /// this can be returned from `QAbstractItemModel::roleNames`
+    pub fn role_names(&self) -> QHash<QHashPair_i32_QByteArray> {
+        let mut role_names = QHash::<QHashPair_i32_QByteArray>::default();
+        for (role, column) in (FIRST_COLUMN_ROLE..).zip(self.columns.iter()) {
+            role_names.insert(role, QByteArray::from(column));
+        }
+        role_names
+    }
+
+    /// The number of rows which have been fetched
+    pub fn row_count(&self) -> i32 {
+        i32::try_from(self.rows.len()).unwrap_or(i32::MAX)
+    }
+}
+
+fn qvariant_from_value_ref(value: ValueRef) -> QVariant {
+    match value {
+        ValueRef::Null => QVariant::default(),
+        ValueRef::Integer(value) => QVariant::from(&value),
+        ValueRef::Real(value) => QVariant::from(&value),
+        ValueRef::Text(value) => QVariant::from(&QString::from(&*String::from_utf8_lossy(value))),
+        ValueRef::Blob(value) => QVariant::from(&QByteArray::from(value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn connection() -> Connection {
+        let connection = Connection::open_in_memory().unwrap();
+        connection
+            .execute_batch(
+                "CREATE TABLE books (title TEXT, pages INTEGER, rating REAL, cover BLOB);
+                INSERT INTO books VALUES ('Rust', 500, 4.5, x'0102');
+                INSERT INTO books VALUES ('Qt', 300, NULL, NULL);
+                INSERT INTO books VALUES ('C++', 1000, 3.0, NULL);",
+            )
+            .unwrap();
+        connection
+    }
+
+    #[test]
+    fn columns_and_role_names() {
+        let rows = SqlQueryRows::new(&connection(), "SELECT title, pages FROM books").unwrap();
+        assert_eq!(rows.columns(), ["title", "pages"]);
+        assert_eq!(rows.row_count(), 0);
+
+        let role_names = rows.role_names();
+        assert_eq!(role_names.len(), 2);
+        assert_eq!(role_names.get(&256), Some(QByteArray::from("title")));
+        assert_eq!(role_names.get(&257), Some(QByteArray::from("pages")));
+    }
+
+    #[test]
+    fn fetch_batches() {
+        let connection = connection();
+        let mut rows = SqlQueryRows::new(&connection, "SELECT title FROM books ORDER BY pages")
+            .unwrap()
+            .with_batch_size(2);
+
+        let batch = rows.fetch_batch(&connection).unwrap();
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch.first_row(), 0);
+        assert_eq!(batch.last_row(), Some(1));
+        rows.append(batch);
+        assert!(rows.can_fetch_more());
+        assert_eq!(rows.row_count(), 2);
+
+        let batch = rows.fetch_batch(&connection).unwrap();
+        assert_eq!(batch.len(), 1);
+        assert_eq!(batch.first_row(), 2);
+        assert_eq!(batch.last_row(), Some(2));
+        rows.append(batch);
+        // The batch was not full, so the end of the query was reached
+        assert!(!rows.can_fetch_more());
+        assert_eq!(rows.row_count(), 3);
+
+        let batch = rows.fetch_batch(&connection).unwrap();
+        assert!(batch.is_empty());
+        assert_eq!(batch.first_row(), 3);
+        assert_eq!(batch.last_row(), None);
+    }
+
+    #[test]
+    fn fetch_empty_batch_at_end() {
+        let connection = connection();
+        let mut rows = SqlQueryRows::new(&connection, "SELECT title FROM books")
+            .unwrap()
+            .with_batch_size(3);
+
+        rows.append(rows.fetch_batch(&connection).unwrap());
+        // A full batch may be followed by more rows
+        assert!(rows.can_fetch_more());
+
+        let batch = rows.fetch_batch(&connection).unwrap();
+        assert_eq!(batch.last_row(), None);
+        rows.append(batch);
+        assert!(!rows.can_fetch_more());
+        assert_eq!(rows.row_count(), 3);
+    }
+
+    #[test]
+    fn data() {
+        let connection = connection();
+        let mut rows =
+            SqlQueryRows::new(&connection, "SELECT * FROM books ORDER BY pages DESC").unwrap();
+        rows.append(rows.fetch_batch(&connection).unwrap());
+
+        assert!(rows.data(1, 256) == QVariant::from(&QString::from("Rust")));
+        assert!(rows.data(1, 257) == QVariant::from(&500_i64));
+        assert!(rows.data(1, 258) == QVariant::from(&4.5_f64));
+        assert!(rows.data(1, 259) == QVariant::from(&QByteArray::from(&[1_u8, 2][..])));
+        // NULL values and missing rows or roles are an invalid QVariant
+        assert!(rows.data(2, 258) == QVariant::default());
+        assert!(rows.data(3, 256) == QVariant::default());
+        assert!(rows.data(0, 260) == QVariant::default());
+        assert!(rows.data(-1, 256) == QVariant::default());
+        assert!(rows.data(0, 0) == QVariant::default());
+    }
+}
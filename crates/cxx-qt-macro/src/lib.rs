@@ -7,8 +7,10 @@
 #![deny(missing_docs)]
 //! The cxx-qt-macro crate provides the procedural attribute macros which are used with cxx-qt.
 
+mod qabstractlistmodelrow;
+
 use proc_macro::TokenStream;
-use syn::{parse_macro_input, ItemMod};
+use syn::{parse_macro_input, DeriveInput, ItemMod};
 
 use cxx_qt_gen::{write_rust, GeneratedRustBlocks, Parser};
 
@@ -117,6 +119,34 @@ pub fn qobject(_args: TokenStream, _input: TokenStream) -> TokenStream {
     unreachable!("qobject should not be used as a macro by itself. Instead it should be used within a cxx_qt::bridge definition")
 }
 
+/// A derive macro which implements `cxx_qt_lib::QAbstractListModelRow` for a struct,
+/// so that it can be used as the row of a list model.
+///
+/// Each field of the struct becomes a role starting at `Qt::UserRole`, in the order the fields are declared,
+/// with the name of the field in camel case as the role name. The type of each field must be a `QVariantValue`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[derive(Default, cxx_qt_lib::QAbstractListModelRow)]
+/// pub struct Book {
+///     title: QString,
+///     page_count: i32,
+/// }
+/// ```
+///
+/// This gives the roles `title` and `pageCount`.
+///
+/// The implementation refers to the `cxx_qt_lib` crate, if it is renamed or re-exported
+/// then the path can be given with `#[cxx_qt_lib(crate = path::to::cxx_qt_lib)]` on the struct.
+#[proc_macro_derive(QAbstractListModelRow, attributes(cxx_qt_lib))]
+pub fn qabstractlistmodelrow(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    qabstractlistmodelrow::generate(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
 // Take the module and C++ namespace and generate the rust code
 fn extract_and_generate(module: ItemMod) -> TokenStream {
     Parser::from(module)
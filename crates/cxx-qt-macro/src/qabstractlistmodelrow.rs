@@ -0,0 +1,226 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use convert_case::{Case, Casing};
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Error, Fields, Path, Result};
+
+/// The role of the first field of a row, this is the value of `Qt::UserRole`
+const FIRST_FIELD_ROLE: i32 = 0x0100;
+
+/// The path of the cxx-qt-lib crate, which can be changed with `#[cxx_qt_lib(crate = path)]`
+fn cxx_qt_lib_path(input: &DeriveInput) -> Result<Path> {
+    let mut path = parse_quote! { ::cxx_qt_lib };
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cxx_qt_lib"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                path = meta.value()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("Unsupported cxx_qt_lib attribute, expected crate = path"))
+            }
+        })?;
+    }
+    Ok(path)
+}
+
+/// Generate the implementation of `cxx_qt_lib::QAbstractListModelRow` for a struct with named fields
+pub fn generate(input: DeriveInput) -> Result<TokenStream> {
+    let cxx_qt_lib = cxx_qt_lib_path(&input)?;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "QAbstractListModelRow can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "QAbstractListModelRow can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut role_names = vec![];
+    let mut role_data = vec![];
+    let mut set_role_data = vec![];
+    for (role, field) in (FIRST_FIELD_ROLE..).zip(fields) {
+        // Named fields always have an ident
+        let ident = field.ident.as_ref().unwrap();
+        let role = Literal::i32_unsuffixed(role);
+        let name = ident.to_string().to_case(Case::Camel);
+        role_names.push(quote! {
+            role_names.insert(#role, #cxx_qt_lib::QByteArray::from(#name));
+        });
+        role_data.push(quote! {
+            #role => #cxx_qt_lib::QVariant::from(&self.#ident),
+        });
+        set_role_data.push(quote! {
+            #role => {
+                if let Some(value) = value.value() {
+                    self.#ident = value;
+                    true
+                } else {
+                    false
+                }
+            }
+        });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #cxx_qt_lib::QAbstractListModelRow for #ident #ty_generics #where_clause {
+            fn role_names() -> #cxx_qt_lib::QHash<#cxx_qt_lib::QHashPair_i32_QByteArray> {
+                let mut role_names = #cxx_qt_lib::QHash::<#cxx_qt_lib::QHashPair_i32_QByteArray>::default();
+                #(#role_names)*
+                role_names
+            }
+
+            fn role_data(&self, role: i32) -> #cxx_qt_lib::QVariant {
+                match role {
+                    #(#role_data)*
+                    _ => #cxx_qt_lib::QVariant::default(),
+                }
+            }
+
+            fn set_role_data(&mut self, role: i32, value: &#cxx_qt_lib::QVariant) -> bool {
+                match role {
+                    #(#set_role_data)*
+                    _ => false,
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use quote::quote;
+
+    #[test]
+    fn test_generate() {
+        let generated = generate(parse_quote! {
+            struct Book {
+                title: QString,
+                page_count: i32,
+            }
+        })
+        .unwrap();
+
+        let expected = quote! {
+            impl ::cxx_qt_lib::QAbstractListModelRow for Book {
+                fn role_names() -> ::cxx_qt_lib::QHash<::cxx_qt_lib::QHashPair_i32_QByteArray> {
+                    let mut role_names = ::cxx_qt_lib::QHash::<::cxx_qt_lib::QHashPair_i32_QByteArray>::default();
+                    role_names.insert(256, ::cxx_qt_lib::QByteArray::from("title"));
+                    role_names.insert(257, ::cxx_qt_lib::QByteArray::from("pageCount"));
+                    role_names
+                }
+
+                fn role_data(&self, role: i32) -> ::cxx_qt_lib::QVariant {
+                    match role {
+                        256 => ::cxx_qt_lib::QVariant::from(&self.title),
+                        257 => ::cxx_qt_lib::QVariant::from(&self.page_count),
+                        _ => ::cxx_qt_lib::QVariant::default(),
+                    }
+                }
+
+                fn set_role_data(&mut self, role: i32, value: &::cxx_qt_lib::QVariant) -> bool {
+                    match role {
+                        256 => {
+                            if let Some(value) = value.value() {
+                                self.title = value;
+                                true
+                            } else {
+                                false
+                            }
+                        }
+                        257 => {
+                            if let Some(value) = value.value() {
+                                self.page_count = value;
+                                true
+                            } else {
+                                false
+                            }
+                        }
+                        _ => false,
+                    }
+                }
+            }
+        };
+        assert_eq!(generated.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_generate_generics() {
+        let generated = generate(parse_quote! {
+            struct Wrapper<T: Clone> where T: Default {
+                value: T,
+            }
+        })
+        .unwrap()
+        .to_string();
+
+        assert!(generated.starts_with(
+            &quote! {
+                impl<T: Clone> ::cxx_qt_lib::QAbstractListModelRow for Wrapper<T> where T: Default
+            }
+            .to_string()
+        ));
+    }
+
+    #[test]
+    fn test_generate_crate_path() {
+        let generated = generate(parse_quote! {
+            #[cxx_qt_lib(crate = my_crate::qt)]
+            struct Book {
+                title: QString,
+            }
+        })
+        .unwrap()
+        .to_string();
+
+        assert!(generated.starts_with(
+            &quote! { impl my_crate::qt::QAbstractListModelRow for Book }.to_string()
+        ));
+        assert!(generated.contains(
+            &quote! { my_crate::qt::QHash<my_crate::qt::QHashPair_i32_QByteArray> }.to_string()
+        ));
+        assert!(!generated.contains("cxx_qt_lib"));
+    }
+
+    #[test]
+    fn test_generate_invalid() {
+        // Tuple structs have no field names for the roles
+        assert!(generate(parse_quote! {
+            struct Book(QString, i32);
+        })
+        .is_err());
+        assert!(generate(parse_quote! {
+            enum Book {
+                Title(QString),
+            }
+        })
+        .is_err());
+        assert!(generate(parse_quote! {
+            #[cxx_qt_lib(path = my_crate::qt)]
+            struct Book {
+                title: QString,
+            }
+        })
+        .is_err());
+    }
+}
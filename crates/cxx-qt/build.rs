@@ -36,10 +36,12 @@ fn main() {
     // ensure src/lib write_headers is consistent
     for (file_contents, file_name) in [
         (include_str!("include/connection.h"), "connection.h"),
+        (include_str!("include/lazy.h"), "lazy.h"),
         (include_str!("include/signalhandler.h"), "signalhandler.h"),
         (include_str!("include/thread.h"), "thread.h"),
         (include_str!("include/threading.h"), "threading.h"),
         (include_str!("include/type.h"), "type.h"),
+        (include_str!("include/upcast.h"), "upcast.h"),
     ] {
         let h_path = format!("{directory}/{file_name}");
         let mut header = File::create(h_path).expect("Could not create cxx-qt header");
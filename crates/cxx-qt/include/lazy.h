@@ -0,0 +1,107 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#pragma once
+
+#include <atomic>
+
+#include <QtCore/QMetaObject>
+#include <QtCore/QThread>
+
+namespace rust::cxxqt1 {
+
+// Tracks the state of a QObject which is initialized on first use,
+// so that the initialization only happens once even if it is requested again
+// while the initialization is running.
+class CxxQtLazyInitialization
+{
+public:
+  virtual ~CxxQtLazyInitialization() = default;
+
+  bool cxxQtLazyInitializeBegin() noexcept
+  {
+    auto expected = State::Uninitialized;
+    return m_cxxQtLazyState.compare_exchange_strong(expected,
+                                                    State::Initializing);
+  }
+
+  void cxxQtLazyInitializeFinish() noexcept
+  {
+    m_cxxQtLazyState.store(State::Initialized);
+  }
+
+  bool cxxQtLazyIsInitialized() const noexcept
+  {
+    return m_cxxQtLazyState.load() == State::Initialized;
+  }
+
+private:
+  enum class State
+  {
+    Uninitialized,
+    Initializing,
+    Initialized,
+  };
+
+  ::std::atomic<State> m_cxxQtLazyState{ State::Uninitialized };
+};
+
+// Starts the initialization of the QObject if it has not been started yet.
+//
+// The initialization is run immediately when called from the thread of the
+// QObject, otherwise it is queued to the event loop of that thread.
+template<typename T>
+void
+lazyEnsureInitialized(T& qobject)
+{
+  if (!static_cast<CxxQtLazyInitialization&>(qobject)
+         .cxxQtLazyInitializeBegin()) {
+    return;
+  }
+
+  if (qobject.thread() == QThread::currentThread()) {
+    qobject.cxxQtLazyInitialize();
+  } else {
+    // The QObject is the context, so the call is dropped if it is destroyed
+    QMetaObject::invokeMethod(
+      &qobject,
+      [&qobject]() { qobject.cxxQtLazyInitialize(); },
+      Qt::QueuedConnection);
+  }
+}
+
+// As lazyEnsureInitialized, but the initialization is always queued, for when
+// it is triggered while Qt is in the middle of another operation.
+template<typename T>
+void
+lazyEnsureInitializedQueued(T& qobject)
+{
+  if (static_cast<CxxQtLazyInitialization&>(qobject)
+        .cxxQtLazyInitializeBegin()) {
+    QMetaObject::invokeMethod(
+      &qobject,
+      [&qobject]() { qobject.cxxQtLazyInitialize(); },
+      Qt::QueuedConnection);
+  }
+}
+
+template<typename T>
+void
+lazyInitializeFinish(T& qobject)
+{
+  static_cast<CxxQtLazyInitialization&>(qobject).cxxQtLazyInitializeFinish();
+}
+
+template<typename T>
+bool
+lazyIsInitialized(const T& qobject)
+{
+  return static_cast<const CxxQtLazyInitialization&>(qobject)
+    .cxxQtLazyIsInitialized();
+}
+
+} // namespace rust::cxxqt1
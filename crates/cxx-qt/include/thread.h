@@ -6,19 +6,92 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #pragma once
 
+#include <atomic>
+#include <cstddef>
+#include <cstdint>
 #include <memory>
 #include <mutex>
 #include <shared_mutex>
 #include <stdexcept>
 
+#include <QtCore/QCoreApplication>
 #include <QtCore/QDebug>
-#include <QtCore/QMetaObject>
+#include <QtCore/QEvent>
+#include <QtCore/QObject>
 
 #include "rust/cxx.h"
 
 namespace rust {
 namespace cxxqt1 {
 
+class CxxQtThreadEventBase : public QEvent
+{
+public:
+  CxxQtThreadEventBase()
+    : QEvent(eventType())
+  {
+  }
+
+  virtual void run() = 0;
+
+  static QEvent::Type eventType()
+  {
+    static const auto type =
+      static_cast<QEvent::Type>(QEvent::registerEventType());
+    return type;
+  }
+};
+
+template<typename F>
+class CxxQtThreadEvent final : public CxxQtThreadEventBase
+{
+public:
+  CxxQtThreadEvent(F func, ::std::atomic<::std::size_t>& pending)
+    : m_func(::std::move(func))
+    , m_pending(pending)
+  {
+  }
+
+  // The event is destroyed after it has been run, or without being run if the
+  // QObject is destroyed, either way it is no longer pending
+  ~CxxQtThreadEvent() override { m_pending.fetch_sub(1); }
+
+  void run() override { m_func(); }
+
+private:
+  F m_func;
+  ::std::atomic<::std::size_t>& m_pending;
+};
+
+// Run the closure of the event if it was queued with a CxxQtThread
+//
+// This is called from the customEvent of the QObject, returns false if the
+// event was not queued with a CxxQtThread
+inline bool
+cxxQtThreadEvent(QEvent* event)
+{
+  if (event->type() != CxxQtThreadEventBase::eventType()) {
+    return false;
+  }
+
+  static_cast<CxxQtThreadEventBase*>(event)->run();
+  return true;
+}
+
+// Returns whether the QObject has been shut down, after which closures can no
+// longer be queued to it
+//
+// This is set by integrations which shut down the QObjects that they own, such
+// as the QQmlEngine of cxx-qt-lib, and is null when there are none
+using CxxQtThreadShutdownCheck = bool (*)(const QObject& qobject);
+
+inline ::std::atomic<CxxQtThreadShutdownCheck>&
+cxxQtThreadShutdownCheck()
+{
+  static ::std::atomic<CxxQtThreadShutdownCheck> check{ nullptr };
+  return check;
+}
+
 template<typename T>
 class CxxQtGuardedPointer final
 {
@@ -30,6 +103,7 @@ public:
 
   T* ptr;
   ::std::shared_mutex mutex;
+  ::std::atomic<::std::size_t> pending{ 0 };
 };
 
 template<typename T>
@@ -46,15 +120,31 @@ public:
   CxxQtThread(CxxQtThread<T>&& other) = default;
 
   template<typename A>
-  void queue(::rust::Fn<void(T& self, ::rust::Box<A> arg)> func,
-             ::rust::Box<A> arg) const
+  bool queue(::rust::Fn<void(T& self, ::rust::Box<A> arg)> func,
+             ::rust::Box<A> arg,
+             ::std::int32_t priority,
+             ::std::size_t capacity) const
   {
     // Ensure that we can read the pointer and it's not being written to
     const auto guard = ::std::shared_lock(m_obj->mutex);
     if (!m_obj->ptr) {
       throw ::std::runtime_error(
         "Cannot queue function pointer as object has been destroyed");
-      return;
+      return false;
+    }
+
+    // The event loop of a QObject which has been shut down may not run again,
+    // so reject the closure rather than leaving it pending
+    const auto isShutdown = cxxQtThreadShutdownCheck().load();
+    if (isShutdown && isShutdown(*m_obj->ptr)) {
+      throw ::std::runtime_error(
+        "Cannot queue function pointer as object has been shut down");
+    }
+
+    // Reserve a place in the queue, or reject the closure if it is full
+    if (m_obj->pending.fetch_add(1) >= capacity) {
+      m_obj->pending.fetch_sub(1);
+      return false;
     }
 
     // Construct the lambda
@@ -72,14 +162,34 @@ public:
       }
     };
 
-    // Add the lambda to the queue
-    if (!QMetaObject::invokeMethod(
-          m_obj->ptr, ::std::move(lambda), Qt::QueuedConnection)) {
-      throw ::std::runtime_error(
-        "Cannot queue function pointer as invokeMethod on object failed");
+    // Add the lambda to the queue of the QObject, the event takes ownership of
+    // the lambda and releases the place in the queue when it is destroyed.
+    //
+    // As the event is posted to the QObject it follows the QObject when it is
+    // moved to another thread and is dropped when the QObject is destroyed.
+    QCoreApplication::postEvent(
+      m_obj->ptr,
+      new CxxQtThreadEvent<decltype(lambda)>(::std::move(lambda),
+                                             m_obj->pending),
+      static_cast<int>(priority));
+    return true;
+  }
+
+  // Prevent the QObject from being destroyed until unlock is called, returns
+  // nullptr without locking if the QObject has already been destroyed
+  const T* lock() const
+  {
+    m_obj->mutex.lock_shared();
+    if (!m_obj->ptr) {
+      m_obj->mutex.unlock_shared();
+      return nullptr;
     }
+
+    return m_obj->ptr;
   }
 
+  void unlock() const { m_obj->mutex.unlock_shared(); }
+
 private:
   ::std::shared_ptr<CxxQtGuardedPointer<T>> m_obj;
 };
@@ -99,12 +209,29 @@ cxxQtThreadDrop(CxxQtThread<T>& cxxQtThread)
 }
 
 template<typename A, typename T>
-void
+bool
 cxxQtThreadQueue(const CxxQtThread<T>& cxxQtThread,
                  ::rust::Fn<void(T& self, ::rust::Box<A> arg)> func,
-                 ::rust::Box<A> arg)
+                 ::rust::Box<A> arg,
+                 ::std::int32_t priority,
+                 ::std::size_t capacity)
+{
+  return cxxQtThread.queue(
+    ::std::move(func), ::std::move(arg), priority, capacity);
+}
+
+template<typename T>
+const T*
+cxxQtThreadLock(const CxxQtThread<T>& cxxQtThread)
+{
+  return cxxQtThread.lock();
+}
+
+template<typename T>
+void
+cxxQtThreadUnlock(const CxxQtThread<T>& cxxQtThread)
 {
-  cxxQtThread.queue(::std::move(func), ::std::move(arg));
+  cxxQtThread.unlock();
 }
 
 } // namespace cxxqt1
@@ -0,0 +1,44 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <type_traits>
+
+#include <QtCore/QObject>
+
+namespace rust {
+namespace cxxqt1 {
+
+// Both template arguments are deduced by CXX from the declared signature,
+// which allows for declaring an upcast for any pair of types in a bridge.
+template<typename Sub, typename Base>
+const Base*
+upcastPtr(const Sub* sub)
+{
+  static_assert(::std::is_base_of<Base, Sub>::value,
+                "upcastPtr requires Base to be a base class of Sub");
+  return static_cast<const Base*>(sub);
+}
+
+// Returns nullptr if the base is not an instance of Sub
+template<typename Base, typename Sub>
+const Sub*
+downcastPtr(const Base* base)
+{
+  static_assert(::std::is_base_of<Base, Sub>::value,
+                "downcastPtr requires Base to be a base class of Sub");
+  if constexpr (QtPrivate::HasQ_OBJECT_Macro<Sub>::Value) {
+    return qobject_cast<const Sub*>(base);
+  } else if constexpr (::std::is_polymorphic<Base>::value) {
+    return dynamic_cast<const Sub*>(base);
+  } else {
+    return nullptr;
+  }
+}
+
+}
+}
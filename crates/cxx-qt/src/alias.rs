@@ -0,0 +1,31 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Helpers for the generated code of `ALIAS` properties
+
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::HashMap,
+};
+
+thread_local! {
+    static DEFAULTS: RefCell<HashMap<TypeId, &'static dyn Any>> = RefCell::new(HashMap::new());
+}
+
+/// A reference to the default value of the given type, which is read from an alias when its child is null
+///
+/// The default value is created once for each type in each thread and lives for the rest of the program.
+pub fn default_ref<T: Default + 'static>() -> &'static T {
+    DEFAULTS.with(|defaults| {
+        let value: &'static dyn Any = *defaults
+            .borrow_mut()
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::leak(Box::new(T::default())));
+        value
+            .downcast_ref::<T>()
+            .expect("default value is stored by its TypeId")
+    })
+}
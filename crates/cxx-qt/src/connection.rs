@@ -29,7 +29,8 @@ mod ffi {
 
     /// This enum describes the types of connection that can be used with signals.
     ///
-    /// Note that UniqueConnection is not supported.
+    /// Note that UniqueConnection is not supported, as Qt only supports it when connecting
+    /// to a member function of a QObject rather than to a Rust closure.
     #[namespace = "Qt"]
     #[repr(i32)]
     enum ConnectionType {
@@ -47,12 +48,23 @@ mod ffi {
         BlockingQueuedConnection,
     }
 
+    unsafe extern "C++" {
+        /// The QObject class is the base class of all Qt objects.
+        ///
+        /// This is an opaque type which can be used to refer to any QObject by pointer or reference,
+        /// for example as the context object of a signal connection.
+        type QObject;
+    }
+
     // We need to tell CXX that the type already exists, otherwise the following error ocucrs
     // "scoped/unscoped mismatch in enum"
     #[namespace = "Qt"]
     unsafe extern "C++" {
         type ConnectionType;
     }
+
+    // Allow for QObjects to be owned by Rust, for example when they are created by a QQmlComponent
+    impl UniquePtr<QObject> {}
 }
 
 /// Represents a handle to a signal-slot (or signal-functor) connection.
@@ -94,4 +106,22 @@ unsafe impl ExternType for QMetaObjectConnection {
     type Kind = cxx::kind::Trivial;
 }
 
-pub use ffi::ConnectionType;
+pub use ffi::{ConnectionType, QObject};
+
+/// An object which can be used as the context of a signal connection,
+/// so that the connected closure is executed in the thread of the context.
+///
+/// This is implemented for [QObject] and for a [CxxQtThread](crate::CxxQtThread) of a QObject,
+/// which allows for a background thread to connect to a signal with the QObject of the thread as the context.
+pub trait ConnectionContext {
+    /// Call the given function with the QObject of this context,
+    /// returning [None] if the QObject has been destroyed.
+    #[doc(hidden)]
+    fn with_context<R>(&self, f: impl FnOnce(&QObject) -> R) -> Option<R>;
+}
+
+impl ConnectionContext for QObject {
+    fn with_context<R>(&self, f: impl FnOnce(&QObject) -> R) -> Option<R> {
+        Some(f(self))
+    }
+}
@@ -0,0 +1,116 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! The format of the QML hot reload sources, which are written by `CxxQtBuilder::qml_hot_reload`
+//! in cxx-qt-build and read by `QQmlApplicationEngine::enable_hot_reload` in cxx-qt-lib
+
+/// The environment variable of the crate which contains the sources
+pub const ENV_VAR: &str = "CXX_QT_QML_HOT_RELOAD";
+
+/// The separator between the sources
+pub const SOURCE_SEPARATOR: char = ';';
+
+/// The separator between the prefix and the directory of a source
+pub const PREFIX_SEPARATOR: char = '=';
+
+/// The prefix of the directories which are watched for changes
+pub const WATCH_PREFIX: &str = "watch";
+
+/// A source of QML hot reload
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HotReloadSource<'a> {
+    /// The URLs starting with the prefix are loaded from the directory instead
+    Prefix {
+        /// The prefix of the URLs in the Qt resources
+        prefix: &'a str,
+        /// The directory containing the files
+        directory: &'a str,
+    },
+    /// The directory is watched for changes
+    Watch(&'a str),
+}
+
+/// Whether the directory can be written as part of a source, as the separator cannot be escaped
+pub fn is_valid_directory(directory: &str) -> bool {
+    !directory.contains(SOURCE_SEPARATOR)
+}
+
+/// Write the sources as the value of the [ENV_VAR] environment variable
+pub fn write_sources<'a>(sources: impl IntoIterator<Item = HotReloadSource<'a>>) -> String {
+    sources
+        .into_iter()
+        .map(|source| match source {
+            HotReloadSource::Prefix { prefix, directory } => {
+                format!("{prefix}{PREFIX_SEPARATOR}{directory}")
+            }
+            HotReloadSource::Watch(directory) => {
+                format!("{WATCH_PREFIX}{PREFIX_SEPARATOR}{directory}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(&SOURCE_SEPARATOR.to_string())
+}
+
+/// Read the sources from the value of the [ENV_VAR] environment variable, skipping any invalid sources
+pub fn read_sources(sources: &str) -> impl Iterator<Item = HotReloadSource<'_>> {
+    sources
+        .split(SOURCE_SEPARATOR)
+        .filter_map(|source| source.split_once(PREFIX_SEPARATOR))
+        .map(|(prefix, directory)| {
+            if prefix == WATCH_PREFIX {
+                HotReloadSource::Watch(directory)
+            } else {
+                HotReloadSource::Prefix { prefix, directory }
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_and_read_sources() {
+        let sources = vec![
+            HotReloadSource::Prefix {
+                prefix: "qrc:/qt/qml/com/kdab/demo/",
+                directory: "/home/user/demo",
+            },
+            HotReloadSource::Watch("/home/user/demo/qml"),
+            HotReloadSource::Watch("/home/user/demo/qml/pages"),
+        ];
+
+        let written = write_sources(sources.iter().copied());
+        assert_eq!(
+            written,
+            "qrc:/qt/qml/com/kdab/demo/=/home/user/demo;watch=/home/user/demo/qml;watch=/home/user/demo/qml/pages"
+        );
+        assert_eq!(read_sources(&written).collect::<Vec<_>>(), sources);
+    }
+
+    #[test]
+    fn read_sources_with_separator_in_directory() {
+        // Only the first separator splits the prefix from the directory
+        assert_eq!(
+            read_sources("watch=/home/user/a=b").collect::<Vec<_>>(),
+            vec![HotReloadSource::Watch("/home/user/a=b")]
+        );
+    }
+
+    #[test]
+    fn read_invalid_sources() {
+        assert_eq!(read_sources("").count(), 0);
+        assert_eq!(
+            read_sources("invalid;;watch=/qml").collect::<Vec<_>>(),
+            vec![HotReloadSource::Watch("/qml")]
+        );
+    }
+
+    #[test]
+    fn valid_directory() {
+        assert!(is_valid_directory("/home/user/demo"));
+        assert!(!is_valid_directory("/home/user/a;b"));
+    }
+}
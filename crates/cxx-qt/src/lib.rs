@@ -11,18 +11,25 @@
 
 use std::{fs::File, io::Write, path::Path};
 
+#[doc(hidden)]
+pub mod alias;
 mod connection;
 mod connectionguard;
 #[doc(hidden)]
+pub mod hotreload;
+#[doc(hidden)]
 pub mod signalhandler;
 mod threading;
 
 pub use cxx_qt_macro::bridge;
 pub use cxx_qt_macro::qobject;
+// The derive generates code for cxx-qt-lib, so it is documented where cxx-qt-lib re-exports it
+#[doc(hidden)]
+pub use cxx_qt_macro::QAbstractListModelRow;
 
-pub use connection::{ConnectionType, QMetaObjectConnection};
+pub use connection::{ConnectionContext, ConnectionType, QMetaObjectConnection, QObject};
 pub use connectionguard::QMetaObjectConnectionGuard;
-pub use threading::CxxQtThread;
+pub use threading::{CxxQtThread, CxxQtThreadScope, EventPriority, TryQueueError};
 
 // Export static assertions that can then be used in cxx-qt-gen generation
 //
@@ -106,7 +113,12 @@ pub trait Threading: Sized {
     fn qt_thread(&self) -> CxxQtThread<Self>;
 
     #[doc(hidden)]
-    fn queue<F>(cxx_qt_thread: &CxxQtThread<Self>, f: F) -> Result<(), cxx::Exception>
+    fn queue<F>(
+        cxx_qt_thread: &CxxQtThread<Self>,
+        f: F,
+        priority: EventPriority,
+        capacity: usize,
+    ) -> Result<bool, cxx::Exception>
     where
         F: FnOnce(core::pin::Pin<&mut Self>),
         F: Send + 'static;
@@ -116,12 +128,107 @@ pub trait Threading: Sized {
 
     #[doc(hidden)]
     fn threading_drop(cxx_qt_thread: &mut CxxQtThread<Self>);
+
+    #[doc(hidden)]
+    fn threading_lock(cxx_qt_thread: &CxxQtThread<Self>) -> *const Self;
+
+    /// # Safety
+    ///
+    /// Must only be called after a successful call to `threading_lock`.
+    #[doc(hidden)]
+    unsafe fn threading_unlock(cxx_qt_thread: &CxxQtThread<Self>);
 }
 
-/// Placeholder for upcasting objects, suppresses dead code warning
-#[allow(dead_code)]
-#[doc(hidden)]
-pub trait Upcast<T> {}
+/// This trait is automatically implemented by CXX-Qt for a QObject and allows for
+/// upcasting it to its base class and to [QObject], and checked casting back again.
+///
+/// This is used to make the methods of a base class available on the QObject,
+/// for example the `QObjectExt` trait in cxx-qt-lib is implemented for any `T: Upcast<QObject>`.
+///
+/// # Safety
+///
+/// Implementations must guarantee that `T` is a base class of `Self`, so that
+/// `upcast_ptr` returns a pointer to the same object viewed as `T`.
+/// `from_base_ptr` must only return a non-null pointer if the object is an instance of `Self`,
+/// as [Downcast] trusts it to create a reference to `Self`.
+pub unsafe trait Upcast<T> {
+    /// # Safety
+    ///
+    /// The pointer must point to a valid instance of Self.
+    #[doc(hidden)]
+    unsafe fn upcast_ptr(this: *const Self) -> *const T;
+
+    /// Casts a pointer to the base class to Self, returning a null pointer
+    /// if the object is not an instance of Self.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to a valid instance of T.
+    #[doc(hidden)]
+    unsafe fn from_base_ptr(base: *const T) -> *const Self;
+
+    /// Upcast a reference to a reference to the base class
+    fn upcast(&self) -> &T {
+        // SAFETY: the reference is a valid instance of Self
+        unsafe { &*Self::upcast_ptr(self) }
+    }
+
+    /// Upcast a pinned mutable reference to a pinned mutable reference to the base class
+    fn upcast_pin(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut T> {
+        // SAFETY: the reference is a valid instance of Self and the upcast
+        // pointer refers to the same object, which is not moved
+        unsafe {
+            let this = self.get_unchecked_mut() as *mut Self;
+            core::pin::Pin::new_unchecked(&mut *(Self::upcast_ptr(this) as *mut T))
+        }
+    }
+}
+
+// SAFETY: a QObject is trivially an instance of itself
+unsafe impl Upcast<QObject> for QObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const QObject {
+        this
+    }
+
+    unsafe fn from_base_ptr(base: *const QObject) -> *const Self {
+        base
+    }
+}
+
+/// This trait is implemented for all types and allows for checked casting from a base class,
+/// such as [QObject], to a type which can be upcast to it.
+///
+/// For generated QObjects the cast uses `qobject_cast`, so it succeeds when the object
+/// is an instance of the requested type or of a type derived from it.
+pub trait Downcast: Sized {
+    /// Try to downcast a reference to a reference to the subclass,
+    /// returning None if the object is not an instance of it.
+    fn downcast<Sub>(&self) -> Option<&Sub>
+    where
+        Sub: Upcast<Self>,
+    {
+        // SAFETY: the reference is a valid instance of Self
+        unsafe { Sub::from_base_ptr(self).as_ref() }
+    }
+
+    /// Try to downcast a pinned mutable reference to a pinned mutable reference to the subclass,
+    /// returning None if the object is not an instance of it.
+    fn downcast_pin<Sub>(self: core::pin::Pin<&mut Self>) -> Option<core::pin::Pin<&mut Sub>>
+    where
+        Sub: Upcast<Self>,
+    {
+        // SAFETY: the reference is a valid instance of Self and the downcast
+        // pointer refers to the same object, which is not moved
+        unsafe {
+            let this = self.get_unchecked_mut() as *mut Self;
+            (Sub::from_base_ptr(this) as *mut Sub)
+                .as_mut()
+                .map(|sub| core::pin::Pin::new_unchecked(sub))
+        }
+    }
+}
+
+impl<T> Downcast for T {}
 
 /// This trait can be implemented on any [CxxQtType] to define a
 /// custom constructor in C++ for the QObject.
@@ -326,6 +433,45 @@ where
     }
 }
 
+/// This trait can be implemented on a `#[qml_singleton(lazy)]` QObject to perform expensive initialization,
+/// such as I/O, when the singleton is first used rather than when it is constructed.
+///
+/// The initialization is started on first access, which is when something first connects to a signal of the QObject.
+/// QML does this when a binding first reads one of its properties. It is then queued to the event loop of the thread of the QObject.
+///
+/// CXX-Qt also generates an `ensure_initialized` method on the QObject, which is a `Q_INVOKABLE` named
+/// `ensureInitialized`, to start the initialization explicitly. When called from the thread of the QObject
+/// the initialization runs immediately, when called from any other thread it is queued to the thread of the QObject.
+///
+/// Once [LazyInitialize::lazy_initialize] has run the generated `initialized` signal is emitted, it only runs once.
+/// Whether this has happened can be checked with the generated `is_initialized` method.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[cxx_qt::bridge]
+/// mod qobject {
+///     extern "RustQt" {
+///         #[qobject]
+///         #[qml_element]
+///         #[qml_singleton(lazy)]
+///         #[qproperty(QString, settings_path)]
+///         type Settings = super::SettingsRust;
+///     }
+/// }
+///
+/// impl cxx_qt::LazyInitialize for qobject::Settings {
+///     fn lazy_initialize(self: core::pin::Pin<&mut Self>) {
+///         let contents = std::fs::read_to_string(self.settings_path.to_string()).unwrap_or_default();
+///         // Parse the settings ...
+///     }
+/// }
+/// ```
+pub trait LazyInitialize: CxxQtType {
+    /// This function is called once on the thread of the QObject when it is first accessed or `ensure_initialized` is called.
+    fn lazy_initialize(self: core::pin::Pin<&mut Self>);
+}
+
 #[doc(hidden)]
 // Write the cxx-qt headers to the specified directory.
 pub fn write_headers(directory: impl AsRef<Path>) {
@@ -334,6 +480,7 @@ pub fn write_headers(directory: impl AsRef<Path>) {
     // Note ensure that the build script is consistent with files that are copied
     for (file_contents, file_name) in [
         (include_str!("../include/connection.h"), "connection.h"),
+        (include_str!("../include/lazy.h"), "lazy.h"),
         (
             include_str!("../include/signalhandler.h"),
             "signalhandler.h",
@@ -341,6 +488,7 @@ pub fn write_headers(directory: impl AsRef<Path>) {
         (include_str!("../include/thread.h"), "thread.h"),
         (include_str!("../include/threading.h"), "threading.h"),
         (include_str!("../include/type.h"), "type.h"),
+        (include_str!("../include/upcast.h"), "upcast.h"),
     ] {
         // Note that we do not need rerun-if-changed for these files
         // as include_str causes a rerun when the header changes
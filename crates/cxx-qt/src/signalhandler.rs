@@ -52,3 +52,36 @@ where
     type Kind = cxx::kind::Trivial;
     type Id = T::Id;
 }
+
+// A pointer to a QObject which is moved into a signal handler closure
+//
+// This is used for connections which have the QObject as their context object,
+// so that the closure can refer back to the QObject that it is called for.
+#[doc(hidden)]
+pub struct CxxQtContextPointer<T>(*mut T);
+
+// Safety:
+//
+// The pointer is only dereferenced by the closure of a connection which has the QObject as its
+// context object, which is only called in the thread of the QObject while it is alive.
+unsafe impl<T> Send for CxxQtContextPointer<T> {}
+
+impl<T> CxxQtContextPointer<T> {
+    /// Create a new context pointer to the given QObject
+    ///
+    /// # Safety
+    ///
+    /// The pointer must only be used in the thread of the QObject while it is alive.
+    pub unsafe fn new(object: *mut T) -> Self {
+        Self(object)
+    }
+
+    /// A pinned mutable reference to the QObject
+    ///
+    /// # Safety
+    ///
+    /// The pointer must only be used in the thread of the QObject while it is alive.
+    pub unsafe fn pin_mut(&mut self) -> core::pin::Pin<&mut T> {
+        core::pin::Pin::new_unchecked(&mut *self.0)
+    }
+}
@@ -3,10 +3,17 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use core::{marker::PhantomData, mem::MaybeUninit, pin::Pin};
+use core::{
+    future::Future,
+    marker::PhantomData,
+    mem::MaybeUninit,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
 use cxx::ExternType;
+use std::sync::{Arc, Condvar, Mutex};
 
-use crate::Threading;
+use crate::{ConnectionContext, QObject, Threading, Upcast};
 
 /// A threading helper which is created from a QObject that implements [Threading].
 ///
@@ -17,6 +24,15 @@ use crate::Threading;
 /// This closure will be executed on the thread the QObject lives in while holding a lock on the Rust object.
 /// Updating the QObject is then thread-safe.
 ///
+/// Closures which borrow data from the background thread instead of owning it can be queued with [CxxQtThread::scope].
+///
+/// The closures are posted as custom events to the QObject, so they follow the QObject when it is moved
+/// to another thread and pending closures are dropped when the QObject is destroyed.
+/// The generated QObject handles these events in its `customEvent` override, so `customEvent` cannot
+/// also be overridden with `#[cxx_override]` when [Threading] is enabled.
+///
+/// A [CxxQtThread] can also be used as the context of a signal connection, see [ConnectionContext].
+///
 /// See the [Threading] example for more information.
 #[repr(C)]
 pub struct CxxQtThread<T>
@@ -78,6 +94,341 @@ where
         F: FnOnce(Pin<&mut T>),
         F: Send + 'static,
     {
-        T::queue(self, f)
+        self.queue_with_priority(EventPriority::Normal, f)
+    }
+
+    /// Queue the given closure onto the Qt event loop for this QObject with the given [EventPriority]
+    ///
+    /// Closures with a higher priority are executed before pending closures and events with a lower priority.
+    /// This is the same as [CxxQtThread::queue] when the priority is [EventPriority::Normal].
+    pub fn queue_with_priority<F>(
+        &self,
+        priority: EventPriority,
+        f: F,
+    ) -> Result<(), cxx::Exception>
+    where
+        F: FnOnce(Pin<&mut T>),
+        F: Send + 'static,
+    {
+        T::queue(self, f, priority, usize::MAX).map(|_| ())
+    }
+
+    /// Try to queue the given closure onto the Qt event loop for this QObject,
+    /// unless `capacity` or more closures are already pending for the QObject.
+    ///
+    /// This allows for a background thread to apply backpressure instead of flooding the Qt event loop
+    /// when it produces updates faster than they can be processed.
+    /// When [TryQueueError::QueueFull] is returned the closure is dropped without being executed.
+    ///
+    /// ```rust,ignore
+    /// match qt_thread.try_queue(16, move |qobject| qobject.set_value(value)) {
+    ///     // The Qt event loop is busy, skip this update
+    ///     Err(TryQueueError::QueueFull) => {}
+    ///     result => result.unwrap(),
+    /// }
+    /// ```
+    pub fn try_queue<F>(&self, capacity: usize, f: F) -> Result<(), TryQueueError>
+    where
+        F: FnOnce(Pin<&mut T>),
+        F: Send + 'static,
+    {
+        self.try_queue_with_priority(capacity, EventPriority::Normal, f)
+    }
+
+    /// Try to queue the given closure onto the Qt event loop for this QObject with the given [EventPriority],
+    /// unless `capacity` or more closures are already pending for the QObject.
+    ///
+    /// See [CxxQtThread::try_queue] and [CxxQtThread::queue_with_priority].
+    pub fn try_queue_with_priority<F>(
+        &self,
+        capacity: usize,
+        priority: EventPriority,
+        f: F,
+    ) -> Result<(), TryQueueError>
+    where
+        F: FnOnce(Pin<&mut T>),
+        F: Send + 'static,
+    {
+        match T::queue(self, f, priority, capacity) {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(TryQueueError::QueueFull),
+            Err(err) => Err(TryQueueError::Exception(err)),
+        }
+    }
+
+    /// Queue the given closure onto the Qt event loop for this QObject and return a [Future]
+    /// which resolves to the return value of the closure once it has been executed.
+    ///
+    /// This allows for an async task to run a closure on the Qt thread and await the result,
+    /// without needing to create a channel for every call.
+    ///
+    /// The [Future] resolves to [None] if the closure was not executed,
+    /// for example when the QObject has been destroyed.
+    ///
+    /// ```rust,ignore
+    /// let qt_thread = self.qt_thread();
+    /// tokio::spawn(async move {
+    ///     let number = qt_thread.queue_async(|qobject| *qobject.number()).await;
+    /// });
+    /// ```
+    pub fn queue_async<F, R>(&self, f: F) -> impl Future<Output = Option<R>>
+    where
+        F: FnOnce(Pin<&mut T>) -> R,
+        F: Send + 'static,
+        R: Send + 'static,
+    {
+        let state = Arc::new(Mutex::new(QueueAsyncState::default()));
+        let sender = QueueAsyncSender(state.clone());
+
+        // If queueing fails the closure is dropped, which drops the sender and resolves the future
+        let _ = self.queue(move |qobject| sender.send(f(qobject)));
+
+        QueueAsyncFuture { state }
+    }
+
+    /// Create a scope for queueing closures which borrow non-`'static` data
+    ///
+    /// This is similar to [std::thread::scope], the closure `f` is given a [CxxQtThreadScope]
+    /// which can queue closures that borrow from the stack of the calling thread.
+    /// Before this function returns it blocks until all of the closures queued with the scope
+    /// have been executed on the Qt event loop, or dropped as the QObject has been destroyed.
+    ///
+    /// As this blocks until the Qt event loop has processed the closures,
+    /// it must be called from a background thread and not from the thread the QObject lives in,
+    /// otherwise it will deadlock.
+    ///
+    /// ```rust,ignore
+    /// let qt_thread = self.qt_thread();
+    /// std::thread::spawn(move || {
+    ///     let values = vec![1, 2, 3];
+    ///
+    ///     qt_thread.scope(|scope| {
+    ///         // The closure borrows values rather than requiring it to be cloned or moved
+    ///         scope.queue(|qobject| {
+    ///             qobject.set_sum(values.iter().sum());
+    ///         }).unwrap();
+    ///     });
+    ///
+    ///     // All of the queued closures have executed here, so values can be used again
+    ///     println!("{values:?}");
+    /// });
+    /// ```
+    pub fn scope<'env, F, R>(&self, f: F) -> R
+    where
+        F: for<'scope> FnOnce(&'scope CxxQtThreadScope<'scope, 'env, T>) -> R,
+    {
+        let scope = CxxQtThreadScope {
+            cxx_qt_thread: self.clone(),
+            pending: Arc::new(ScopePending::default()),
+            scope: PhantomData,
+            env: PhantomData,
+        };
+
+        // Wait for the queued closures even if f panics, as they may borrow from the environment
+        let _wait = ScopeWait(&scope.pending);
+        f(&scope)
+    }
+}
+
+// The QObject is locked while the connection is made, so it cannot be destroyed by its own thread
+// and a connection to a destroyed QObject is never made
+impl<T> ConnectionContext for CxxQtThread<T>
+where
+    T: Threading + Upcast<QObject>,
+{
+    fn with_context<R>(&self, f: impl FnOnce(&QObject) -> R) -> Option<R> {
+        let ptr = T::threading_lock(self);
+        if ptr.is_null() {
+            return None;
+        }
+
+        // Unlock the QObject even if f panics
+        let _unlock = ThreadingUnlock(self);
+        // SAFETY: the QObject is valid as it has not been destroyed and is locked
+        Some(f(unsafe { &*T::upcast_ptr(ptr) }))
+    }
+}
+
+/// Unlocks a QObject locked by [ConnectionContext::with_context] of a [CxxQtThread] when dropped
+struct ThreadingUnlock<'a, T: Threading>(&'a CxxQtThread<T>);
+
+impl<T> Drop for ThreadingUnlock<'_, T>
+where
+    T: Threading,
+{
+    fn drop(&mut self) {
+        // SAFETY: only created after a successful lock
+        unsafe { T::threading_unlock(self.0) }
+    }
+}
+
+/// The priority of a closure queued with [CxxQtThread::queue_with_priority]
+///
+/// This maps to the `Qt::EventPriority` of the event which is posted to the Qt event loop.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventPriority {
+    /// The closure is executed before events with a normal or low priority.
+    High = 1,
+    /// The closure is executed in the order that it was queued, this is the default priority.
+    Normal = 0,
+    /// The closure is executed after events with a high or normal priority.
+    Low = -1,
+}
+
+/// The error returned by [CxxQtThread::try_queue]
+#[derive(Debug)]
+pub enum TryQueueError {
+    /// The capacity of pending closures for the QObject has been reached
+    QueueFull,
+    /// The closure could not be queued, for example as the QObject has been destroyed
+    Exception(cxx::Exception),
+}
+
+impl std::fmt::Display for TryQueueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::QueueFull => write!(f, "Cannot queue closure as the queue is full"),
+            Self::Exception(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for TryQueueError {}
+
+/// The result of a closure queued with [CxxQtThread::queue_async]
+struct QueueAsyncState<R> {
+    result: Option<R>,
+    done: bool,
+    waker: Option<Waker>,
+}
+
+impl<R> Default for QueueAsyncState<R> {
+    fn default() -> Self {
+        Self {
+            result: None,
+            done: false,
+            waker: None,
+        }
+    }
+}
+
+/// Completes a [QueueAsyncFuture] when it is dropped, with the result if one was sent
+struct QueueAsyncSender<R>(Arc<Mutex<QueueAsyncState<R>>>);
+
+impl<R> QueueAsyncSender<R> {
+    fn send(self, result: R) {
+        self.0.lock().unwrap().result = Some(result);
+    }
+}
+
+impl<R> Drop for QueueAsyncSender<R> {
+    fn drop(&mut self) {
+        let mut state = self.0.lock().unwrap();
+        state.done = true;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// A [Future] which resolves to the result of a closure queued with [CxxQtThread::queue_async]
+struct QueueAsyncFuture<R> {
+    state: Arc<Mutex<QueueAsyncState<R>>>,
+}
+
+impl<R> Future for QueueAsyncFuture<R> {
+    type Output = Option<R>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+        if state.done {
+            Poll::Ready(state.result.take())
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+/// A scope for queueing closures which borrow non-`'static` data, created by [CxxQtThread::scope].
+///
+/// All of the closures queued with this scope are executed or dropped before [CxxQtThread::scope] returns.
+pub struct CxxQtThreadScope<'scope, 'env: 'scope, T>
+where
+    T: Threading,
+{
+    cxx_qt_thread: CxxQtThread<T>,
+    pending: Arc<ScopePending>,
+    // Invariance over the lifetimes, the same as std::thread::Scope
+    scope: PhantomData<&'scope mut &'scope ()>,
+    env: PhantomData<&'env mut &'env ()>,
+}
+
+impl<'scope, T> CxxQtThreadScope<'scope, '_, T>
+where
+    T: Threading + 'static,
+{
+    /// Queue the given closure onto the Qt event loop for this QObject
+    ///
+    /// This is the same as [CxxQtThread::queue] but the closure may borrow data which outlives the scope.
+    pub fn queue<F>(&'scope self, f: F) -> Result<(), cxx::Exception>
+    where
+        F: FnOnce(Pin<&mut T>),
+        F: Send + 'scope,
+    {
+        // The guard is moved into the closure so that it is released when the closure
+        // has been executed or when it is dropped without being executed
+        let guard = ScopePendingGuard::new(self.pending.clone());
+        let f: ScopedQueuedFn<'scope, T> = Box::new(move |qobject| {
+            let _guard = guard;
+            f(qobject);
+        });
+
+        // SAFETY:
+        // CxxQtThread::scope does not return until all of the pending guards have been released,
+        // so the closure cannot outlive the data that it borrows.
+        let f: ScopedQueuedFn<'static, T> = unsafe { core::mem::transmute(f) };
+        self.cxx_qt_thread.queue(f)
+    }
+}
+
+/// A closure queued by a [CxxQtThreadScope] with the lifetime of the data that it borrows
+type ScopedQueuedFn<'a, T> = Box<dyn FnOnce(Pin<&mut T>) + Send + 'a>;
+
+/// The number of closures queued by a [CxxQtThreadScope] which have not been executed or dropped yet
+#[derive(Default)]
+struct ScopePending {
+    count: Mutex<usize>,
+    released: Condvar,
+}
+
+/// Holds one of the pending closures of a [CxxQtThreadScope]
+struct ScopePendingGuard(Arc<ScopePending>);
+
+impl ScopePendingGuard {
+    fn new(pending: Arc<ScopePending>) -> Self {
+        *pending.count.lock().unwrap() += 1;
+        Self(pending)
+    }
+}
+
+impl Drop for ScopePendingGuard {
+    fn drop(&mut self) {
+        let mut count = self.0.count.lock().unwrap();
+        *count -= 1;
+        if *count == 0 {
+            self.0.released.notify_all();
+        }
+    }
+}
+
+/// Waits for all of the pending closures of a [CxxQtThreadScope] when dropped
+struct ScopeWait<'a>(&'a ScopePending);
+
+impl Drop for ScopeWait<'_> {
+    fn drop(&mut self) {
+        let count = self.0.count.lock().unwrap();
+        let _count = self.0.released.wait_while(count, |count| *count > 0);
     }
 }
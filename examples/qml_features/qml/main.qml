@@ -120,6 +120,10 @@ ApplicationWindow {
                     name: "Custom Parent Class"
                     source: "pages/CustomParentClassPage.qml"
                 }
+                ListElement {
+                    name: "Custom Validator"
+                    source: "pages/CustomValidatorPage.qml"
+                }
                 ListElement {
                     name: "ExternCxxQt"
                     source: "pages/ExternCxxQtPage.qml"
@@ -0,0 +1,39 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQuick 2.12
+import QtQuick.Controls 2.12
+import QtQuick.Layouts 1.12
+
+import com.kdab.cxx_qt.demo 1.0
+
+Page {
+    ColumnLayout {
+        anchors.left: parent.left
+        anchors.right: parent.right
+        anchors.verticalCenter: parent.verticalCenter
+
+        TextField {
+            id: textField
+            Layout.alignment: Qt.AlignHCenter
+            placeholderText: qsTr("#RRGGBB")
+            validator: CustomValidator {}
+        }
+
+        Rectangle {
+            Layout.alignment: Qt.AlignHCenter
+            color: textField.acceptableInput ? textField.text : "transparent"
+            border.width: 1
+            height: 50
+            width: 50
+        }
+
+        Label {
+            Layout.fillWidth: true
+            horizontalAlignment: Text.AlignHCenter
+            text: qsTr("In this demo the text of the TextField is validated in Rust by implementing a QValidator.")
+            wrapMode: Text.Wrap
+        }
+    }
+}
@@ -15,6 +15,7 @@ fn main() {
                 "src/containers.rs",
                 "src/custom_base_class.rs",
                 "src/custom_parent_class.rs",
+                "src/custom_validator.rs",
                 "src/empty_bridge.rs",
                 "src/externcxxqt.rs",
                 "src/invokables.rs",
@@ -34,6 +35,7 @@ fn main() {
                 "../qml/pages/ContainersPage.qml",
                 "../qml/pages/CustomBaseClassPage.qml",
                 "../qml/pages/CustomParentClassPage.qml",
+                "../qml/pages/CustomValidatorPage.qml",
                 "../qml/pages/ExternCxxQtPage.qml",
                 "../qml/pages/InvokablesPage.qml",
                 "../qml/pages/MultipleQObjectsPage.qml",
@@ -18,10 +18,6 @@ pub mod qobject {
     // ANCHOR_END: book_base_include
 
     unsafe extern "C++" {
-        include!("cxx-qt-lib/qhash.h");
-        /// QHash<i32, QByteArray> from cxx_qt_lib
-        type QHash_i32_QByteArray = cxx_qt_lib::QHash<cxx_qt_lib::QHashPair_i32_QByteArray>;
-
         include!("cxx-qt-lib/qvariant.h");
         /// QVariant from cxx_qt_lib
         type QVariant = cxx_qt_lib::QVariant;
@@ -35,7 +31,9 @@ pub mod qobject {
         type QVector_i32 = cxx_qt_lib::QVector<i32>;
     }
 
+    // ANCHOR: book_model_roles
     #[qenum(CustomBaseClass)]
+    #[model_roles]
     /// Roles for the CustomBaseClass list model
     enum Roles {
         /// The index of the row
@@ -43,6 +41,7 @@ pub mod qobject {
         /// The value of the row
         Value,
     }
+    // ANCHOR_END: book_model_roles
 
     // ANCHOR: book_qenum_in_qobject
     #[qenum(CustomBaseClass)]
@@ -193,11 +192,6 @@ pub mod qobject {
     // ANCHOR_END: book_inherit_can_fetch_more_signature
 
     unsafe extern "RustQt" {
-        /// Return the role names for the QAbstractListModel
-        #[qinvokable]
-        #[cxx_override]
-        fn role_names(self: &CustomBaseClass) -> QHash_i32_QByteArray;
-
         /// Return the row count for the QAbstractListModel
         #[qinvokable]
         #[cxx_override]
@@ -227,7 +221,7 @@ pub mod qobject {
 
 use core::pin::Pin;
 use cxx_qt::{CxxQtType, Threading};
-use cxx_qt_lib::{QByteArray, QHash, QHashPair_i32_QByteArray, QModelIndex, QVariant, QVector};
+use cxx_qt_lib::{QModelIndex, QVariant, QVector};
 
 impl Default for qobject::State {
     fn default() -> Self {
@@ -378,14 +372,6 @@ impl qobject::CustomBaseClass {
 // ANCHOR_END: book_inherit_can_fetch_more
 
 impl qobject::CustomBaseClass {
-    /// Return the role names for the QAbstractListModel
-    pub fn role_names(&self) -> QHash<QHashPair_i32_QByteArray> {
-        let mut roles = QHash::<QHashPair_i32_QByteArray>::default();
-        roles.insert(qobject::Roles::Id.repr, QByteArray::from("id"));
-        roles.insert(qobject::Roles::Value.repr, QByteArray::from("value"));
-        roles
-    }
-
     /// Return the row count for the QAbstractListModel
     pub fn row_count(&self, _parent: &QModelIndex) -> i32 {
         self.vector.len() as i32
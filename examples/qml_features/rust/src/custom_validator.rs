@@ -0,0 +1,67 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! This example shows how a QValidator can be implemented in Rust and used as the validator of a QML TextField.
+
+/// A CXX-Qt bridge which shows a custom validator written in Rust
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        /// QString from cxx_qt_lib
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-lib/qvalidator.h");
+        /// QValidator from cxx_qt_lib
+        type QValidator = cxx_qt_lib::QValidator;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        /// QValidatorState from cxx_qt_lib
+        type QValidatorState = cxx_qt_lib::QValidatorState;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[base = QValidator]
+        type CustomValidator = super::CustomValidatorRust;
+
+        /// Override QValidator::validate to validate a hex color such as #00FF00 in Rust
+        #[cxx_override]
+        fn validate(self: &CustomValidator, input: &mut QString, pos: &mut i32) -> QValidatorState;
+    }
+
+    impl cxx_qt::Constructor<()> for CustomValidator {}
+}
+
+use cxx_qt_lib::{QString, QValidatorState};
+
+/// A struct which inherits from QValidator
+///
+/// Which accepts hex colors of the form #RRGGBB.
+#[derive(Default)]
+pub struct CustomValidatorRust;
+
+impl qobject::CustomValidator {
+    /// Override QValidator::validate to validate a hex color such as #00FF00 in Rust
+    ///
+    /// The input is converted to upper case while typing, a partially typed color is intermediate.
+    pub fn validate(&self, input: &mut QString, _pos: &mut i32) -> QValidatorState {
+        let text = String::from(&*input).to_uppercase();
+        let state = match text.strip_prefix('#') {
+            Some(digits) if !digits.chars().all(|c| c.is_ascii_hexdigit()) => {
+                QValidatorState::Invalid
+            }
+            Some(digits) if digits.len() == 6 => QValidatorState::Acceptable,
+            Some(digits) if digits.len() < 6 => QValidatorState::Intermediate,
+            None if text.is_empty() => QValidatorState::Intermediate,
+            _ => QValidatorState::Invalid,
+        };
+        *input = QString::from(&text);
+        state
+    }
+}
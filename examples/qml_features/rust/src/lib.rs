@@ -13,6 +13,7 @@
 pub mod containers;
 pub mod custom_base_class;
 pub mod custom_parent_class;
+pub mod custom_validator;
 pub mod externcxxqt;
 pub mod invokables;
 pub mod multiple_qobjects;
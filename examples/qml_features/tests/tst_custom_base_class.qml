@@ -84,8 +84,9 @@ TestCase {
     }
 
     function test_roles_qenum() {
-        compare(CustomBaseClass.Id, 0);
-        compare(CustomBaseClass.Value, 1);
+        // The roles of a model start at Qt.UserRole
+        compare(CustomBaseClass.Id, Qt.UserRole);
+        compare(CustomBaseClass.Value, Qt.UserRole + 1);
 
         const model = createTemporaryObject(componentCustomBaseClass, null, {});
 
@@ -0,0 +1,39 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+import QtQuick 2.12
+import QtQuick.Controls 2.12
+import QtTest 1.12
+
+import com.kdab.cxx_qt.demo 1.0
+
+TestCase {
+    name: "CustomValidatorTests"
+
+    Component {
+        id: componentTextField
+
+        TextField {
+            validator: CustomValidator {}
+        }
+    }
+
+    function test_acceptable() {
+        const textField = createTemporaryObject(componentTextField, null, {});
+        textField.text = "#00ff00";
+        compare(textField.acceptableInput, true);
+    }
+
+    function test_intermediate() {
+        const textField = createTemporaryObject(componentTextField, null, {});
+        textField.text = "#00ff";
+        compare(textField.acceptableInput, false);
+    }
+
+    function test_invalid() {
+        const textField = createTemporaryObject(componentTextField, null, {});
+        textField.text = "#00gg00";
+        compare(textField.acceptableInput, false);
+    }
+}
@@ -7,11 +7,14 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 #include <QtCore/QThread>
 #include <QtCore/QTimer>
+#include <QtQml/QQmlContext>
+#include <QtQml/QQmlEngine>
 #include <QtTest/QSignalSpy>
 #include <QtTest/QTest>
 
 #include "basic_cxx_qt/src/data.cxxqt.h"
 #include "basic_cxx_qt/src/empty.cxxqt.h"
+#include "basic_cxx_qt/src/fetchrows.cxxqt.h"
 #include "basic_cxx_qt/src/lib.cxxqt.h"
 #include "basic_cxx_qt/src/naming.cxxqt.h"
 #include "basic_cxx_qt/src/types.cxxqt.h"
@@ -129,6 +132,102 @@ private Q_SLOTS:
     QTRY_COMPARE(obj.fetchUpdateCallCount(), 100);
   }
 
+  // CXX-Qt allows Rust code to queue requests which borrow from a scope
+  void test_queue_requests_scoped()
+  {
+    cxx_qt::my_object::MyObject obj;
+    QCOMPARE(obj.fetchUpdateCallCount(), 0);
+    obj.queueTestScoped();
+    QTRY_COMPARE(obj.fetchUpdateCallCount(), 6);
+  }
+
+  // CXX-Qt allows Rust code to await the result of a queued request
+  void test_queue_request_async()
+  {
+    cxx_qt::my_object::MyObject obj;
+    QCOMPARE(obj.fetchUpdateCallCount(), 0);
+    obj.queueTestAsync();
+    QTRY_COMPARE(obj.fetchUpdateCallCount(), 2);
+  }
+
+  // CXX-Qt allows Rust code to limit the number of pending requests
+  void test_queue_requests_bounded()
+  {
+    cxx_qt::my_object::MyObject obj;
+    QCOMPARE(obj.fetchUpdateCallCount(), 0);
+    obj.queueTestBounded();
+    QTRY_COMPARE(obj.fetchUpdateCallCount(), 2);
+  }
+
+  // CXX-Qt allows Rust code to queue requests with a priority
+  void test_queue_requests_priority()
+  {
+    cxx_qt::my_object::MyObject obj;
+    QCOMPARE(obj.fetchUpdateCallCount(), 0);
+    obj.queueTestPriority();
+    QTRY_COMPARE(obj.fetchUpdateCallCount(), 21);
+  }
+
+  // CXX-Qt threading does not add hidden children to the QObject
+  void test_threading_no_children()
+  {
+    cxx_qt::my_object::MyObject obj;
+    QCOMPARE(obj.children().size(), 0);
+    obj.queueTest();
+    QCOMPARE(obj.children().size(), 0);
+    QTRY_COMPARE(obj.fetchUpdateCallCount(), 1);
+  }
+
+  // CXX-Qt queues requests as events which are posted to the QObject itself
+  void test_threading_posted_to_qobject()
+  {
+    cxx_qt::my_object::MyObject obj;
+    obj.queueTest();
+    QCOMPARE(obj.fetchUpdateCallCount(), 0);
+    QCoreApplication::sendPostedEvents(
+      &obj, ::rust::cxxqt1::CxxQtThreadEventBase::eventType());
+    QCOMPARE(obj.fetchUpdateCallCount(), 1);
+  }
+
+  // CXX-Qt drains the closures queued while shutting down a QQmlEngine and
+  // rejects any which are queued to its QObjects afterwards
+  void test_queue_qqmlengine_shutdown()
+  {
+    QQmlEngine engine;
+    cxx_qt::my_object::MyObject obj;
+    QQmlEngine::setContextForObject(&obj, engine.rootContext());
+
+    obj.queueTestOnShutdown(engine);
+    QCOMPARE(obj.fetchUpdateCallCount(), 0);
+    ::rust::cxxqtlib1::qqmlengineShutdown(engine);
+    QCOMPARE(obj.fetchUpdateCallCount(), 1);
+
+    // The callbacks are only called once
+    ::rust::cxxqtlib1::qqmlengineShutdown(engine);
+    QCOMPARE(obj.fetchUpdateCallCount(), 1);
+
+    QVERIFY(obj.queueTestAfterShutdown());
+
+    // QObjects which do not belong to the engine are not affected
+    cxx_qt::my_object::MyObject other;
+    QVERIFY(!other.queueTestAfterShutdown());
+  }
+
+  // CXX-Qt drops the QQmlEngine shutdown callbacks without calling them when
+  // the engine is destroyed without being shut down
+  void test_queue_qqmlengine_destroyed()
+  {
+    cxx_qt::my_object::MyObject obj;
+    {
+      QQmlEngine engine;
+      obj.queueTestOnShutdown(engine);
+    }
+
+    QCoreApplication::processEvents();
+    QCOMPARE(obj.fetchUpdateCallCount(), 0);
+    QVERIFY(!obj.queueTestAfterShutdown());
+  }
+
   // CXX-Qt types are exposed to C++ correctly
   void test_primitive_types()
   {
@@ -199,9 +298,69 @@ private Q_SLOTS:
     QCOMPARE(types.getUint32(), 4);
   }
 
+  // CXX-Qt allows Rust code to downcast a QObject to a generated QObject
+  void test_downcast()
+  {
+    cxx_qt::my_object::MyObject obj;
+    QCOMPARE(obj.parentNumber(), -1);
+
+    QObject plainParent;
+    obj.setParent(&plainParent);
+    QCOMPARE(obj.parentNumber(), -1);
+
+    cxx_qt::my_object::MyObject parent;
+    parent.setNumber(4);
+    obj.setParent(&parent);
+    QCOMPARE(obj.parentNumber(), 4);
+
+    obj.setParent(nullptr);
+  }
+
   // Tests that we can build an empty QObject end to end
   void testEmpty() { Empty empty; }
 
+  // Rows are fetched in batches which start after the rows fetched so far
+  void testFetchRows()
+  {
+    FetchRowsModel model;
+    const auto value = [&model](int row) {
+      return model.data(model.index(row), Qt::UserRole).toInt();
+    };
+    QSignalSpy insertedSpy(&model, &QAbstractItemModel::rowsInserted);
+    QCOMPARE(model.rowCount(QModelIndex()), 0);
+    QVERIFY(model.canFetchMore(QModelIndex()));
+
+    model.fetchMore(QModelIndex());
+    QCOMPARE(insertedSpy.count(), 1);
+    QCOMPARE(model.rowCount(QModelIndex()), 2);
+    QVERIFY(model.canFetchMore(QModelIndex()));
+
+    // Removing a row does not change where the next batch starts
+    model.removeFirstRow();
+    model.fetchMore(QModelIndex());
+    QCOMPARE(model.rowCount(QModelIndex()), 3);
+    QCOMPARE(value(0), 1);
+    QCOMPARE(value(1), 2);
+    QCOMPARE(value(2), 3);
+    QVERIFY(model.canFetchMore(QModelIndex()));
+
+    // A batch which is not full is the last batch
+    model.fetchMore(QModelIndex());
+    QCOMPARE(model.rowCount(QModelIndex()), 4);
+    QCOMPARE(value(3), 4);
+    QVERIFY(!model.canFetchMore(QModelIndex()));
+
+    model.fetchMore(QModelIndex());
+    QCOMPARE(insertedSpy.count(), 3);
+    QCOMPARE(model.rowCount(QModelIndex()), 4);
+
+    model.refetch();
+    QCOMPARE(model.rowCount(QModelIndex()), 0);
+    QVERIFY(model.canFetchMore(QModelIndex()));
+    model.fetchMore(QModelIndex());
+    QCOMPARE(value(0), 0);
+  }
+
   void testThrowException()
   {
     cxx_qt::my_object::MyObject obj;
@@ -0,0 +1,135 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+#[cxx_qt::bridge]
+mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qabstractlistmodel.h");
+        type QAbstractListModel = cxx_qt_lib::QAbstractListModel;
+
+        include!("cxx-qt-lib/qhash.h");
+        type QHash_i32_QByteArray = cxx_qt_lib::QHash<cxx_qt_lib::QHashPair_i32_QByteArray>;
+
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = cxx_qt_lib::QModelIndex;
+
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[base = QAbstractListModel]
+        type FetchRowsModel = super::FetchRowsModelRust;
+
+        #[cxx_override]
+        #[rust_name = "row_count"]
+        fn rowCount(self: &FetchRowsModel, parent: &QModelIndex) -> i32;
+
+        #[cxx_override]
+        fn data(self: &FetchRowsModel, index: &QModelIndex, role: i32) -> QVariant;
+
+        #[cxx_override]
+        #[rust_name = "role_names"]
+        fn roleNames(self: &FetchRowsModel) -> QHash_i32_QByteArray;
+
+        #[cxx_override]
+        #[rust_name = "can_fetch_more"]
+        fn canFetchMore(self: &FetchRowsModel, parent: &QModelIndex) -> bool;
+
+        #[cxx_override]
+        #[rust_name = "fetch_more"]
+        fn fetchMore(self: Pin<&mut FetchRowsModel>, parent: &QModelIndex);
+
+        #[qinvokable]
+        fn remove_first_row(self: Pin<&mut FetchRowsModel>);
+
+        #[qinvokable]
+        fn refetch(self: Pin<&mut FetchRowsModel>);
+    }
+}
+
+use core::pin::Pin;
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::{
+    QAbstractListModelFetchRows, QAbstractListModelFetchState, QAbstractListModelRow,
+    QAbstractListModelRows, QHash, QHashPair_i32_QByteArray, QModelIndex, QVariant,
+};
+
+/// The number of rows which can be fetched
+const TOTAL_ROWS: usize = 5;
+
+#[derive(QAbstractListModelRow)]
+pub struct Number {
+    value: i32,
+}
+
+#[derive(Default)]
+pub struct FetchRowsModelRust {
+    numbers: Vec<Number>,
+    fetch_state: QAbstractListModelFetchState,
+}
+
+impl QAbstractListModelRows for qobject::FetchRowsModel {
+    type Row = Number;
+
+    fn rows(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    fn rows_mut(self: Pin<&mut Self>) -> &mut Vec<Number> {
+        &mut self.rust_mut().get_mut().numbers
+    }
+}
+
+impl QAbstractListModelFetchRows for qobject::FetchRowsModel {
+    const BATCH_SIZE: usize = 2;
+
+    fn fetch_rows(&self, offset: usize, limit: usize) -> Vec<Number> {
+        (offset..TOTAL_ROWS.min(offset + limit))
+            .map(|value| Number {
+                value: value as i32,
+            })
+            .collect()
+    }
+
+    fn fetch_state(&self) -> &QAbstractListModelFetchState {
+        &self.fetch_state
+    }
+
+    fn fetch_state_mut(self: Pin<&mut Self>) -> &mut QAbstractListModelFetchState {
+        &mut self.rust_mut().get_mut().fetch_state
+    }
+}
+
+impl qobject::FetchRowsModel {
+    fn row_count(&self, _parent: &QModelIndex) -> i32 {
+        self.rows_row_count()
+    }
+
+    fn data(&self, index: &QModelIndex, role: i32) -> QVariant {
+        self.rows_data(index, role)
+    }
+
+    fn role_names(&self) -> QHash<QHashPair_i32_QByteArray> {
+        self.rows_role_names()
+    }
+
+    fn can_fetch_more(&self, _parent: &QModelIndex) -> bool {
+        self.rows_can_fetch_more()
+    }
+
+    fn fetch_more(self: Pin<&mut Self>, _parent: &QModelIndex) {
+        self.rows_fetch_more();
+    }
+
+    fn remove_first_row(self: Pin<&mut Self>) {
+        self.remove_row(0);
+    }
+
+    fn refetch(self: Pin<&mut Self>) {
+        self.refetch_rows();
+    }
+}
@@ -6,6 +6,7 @@
 
 mod data;
 mod empty;
+mod fetchrows;
 mod naming;
 mod types;
 
@@ -15,6 +16,9 @@ mod qobject {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qstring.h");
         type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = cxx_qt_lib::QQmlEngine;
     }
 
     unsafe extern "RustQt" {
@@ -39,15 +43,29 @@ mod qobject {
 
         fn queue_test_multi_thread(self: Pin<&mut MyObject>);
 
+        fn queue_test_scoped(self: Pin<&mut MyObject>);
+
+        fn queue_test_async(self: Pin<&mut MyObject>);
+
+        fn queue_test_bounded(self: Pin<&mut MyObject>);
+
+        fn queue_test_priority(self: Pin<&mut MyObject>);
+
+        fn queue_test_on_shutdown(self: Pin<&mut MyObject>, engine: Pin<&mut QQmlEngine>);
+
+        fn queue_test_after_shutdown(self: Pin<&mut MyObject>) -> bool;
+
         fn fetch_update_call_count(self: &MyObject) -> i32;
 
         fn throw_exception(self: &MyObject) -> Result<i32>;
+
+        fn parent_number(self: &MyObject) -> i32;
     }
 }
 
 use core::pin::Pin;
-use cxx_qt::{CxxQtType, Threading};
-use cxx_qt_lib::QString;
+use cxx_qt::{CxxQtType, Downcast, Threading};
+use cxx_qt_lib::{QObjectExt, QQmlEngine, QString};
 
 pub struct MyObjectRust {
     number: i32,
@@ -66,6 +84,27 @@ impl Default for MyObjectRust {
     }
 }
 
+/// Run a future to completion on the current thread, to avoid depending on an async runtime
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    struct ThreadWaker(std::thread::Thread);
+
+    impl std::task::Wake for ThreadWaker {
+        fn wake(self: std::sync::Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = std::sync::Arc::new(ThreadWaker(std::thread::current())).into();
+    let mut context = std::task::Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+        std::thread::park();
+    }
+}
+
 impl qobject::MyObject {
     fn double_number_self(self: Pin<&mut Self>) {
         let value = self.number() * 2;
@@ -118,6 +157,97 @@ impl qobject::MyObject {
         );
     }
 
+    fn queue_test_scoped(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        std::thread::spawn(move || {
+            let increments = vec![1, 2, 3];
+            qt_thread.scope(|scope| {
+                // The queued closures borrow from increments instead of moving it
+                for increment in &increments {
+                    scope
+                        .queue(move |qobject| {
+                            qobject.rust_mut().update_call_count += increment;
+                        })
+                        .unwrap();
+                }
+            });
+        });
+    }
+
+    fn queue_test_async(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        std::thread::spawn(move || {
+            let count = block_on(qt_thread.queue_async(|mut qobject| {
+                qobject.as_mut().rust_mut().update_call_count += 1;
+                qobject.update_call_count
+            }))
+            .unwrap();
+
+            // Use the result of the first closure in a second closure
+            qt_thread
+                .queue(move |qobject| {
+                    qobject.rust_mut().update_call_count += count;
+                })
+                .unwrap();
+        });
+    }
+
+    fn queue_test_bounded(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        // The event loop is not running here, so the first two closures are still pending
+        for _ in 0..2 {
+            qt_thread
+                .try_queue(2, |qobject| {
+                    qobject.rust_mut().update_call_count += 1;
+                })
+                .unwrap();
+        }
+        assert!(matches!(
+            qt_thread.try_queue(2, |qobject| {
+                qobject.rust_mut().update_call_count += 1;
+            }),
+            Err(cxx_qt::TryQueueError::QueueFull)
+        ));
+    }
+
+    fn queue_test_priority(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        qt_thread
+            .queue(|mut qobject| {
+                let count = qobject.update_call_count;
+                qobject.as_mut().rust_mut().update_call_count = count * 10 + 1;
+            })
+            .unwrap();
+        // This closure is executed first even though it was queued last
+        qt_thread
+            .queue_with_priority(cxx_qt::EventPriority::High, |mut qobject| {
+                let count = qobject.update_call_count;
+                qobject.as_mut().rust_mut().update_call_count = count * 10 + 2;
+            })
+            .unwrap();
+    }
+
+    fn queue_test_on_shutdown(self: Pin<&mut Self>, engine: Pin<&mut QQmlEngine>) {
+        let qt_thread = self.qt_thread();
+        engine.on_shutdown(move |_| {
+            // This closure is executed before the shutdown returns
+            qt_thread
+                .queue(|qobject| {
+                    qobject.rust_mut().update_call_count += 1;
+                })
+                .unwrap();
+        });
+    }
+
+    /// Returns whether queueing a closure is rejected
+    fn queue_test_after_shutdown(self: Pin<&mut Self>) -> bool {
+        self.qt_thread()
+            .queue(|qobject| {
+                qobject.rust_mut().update_call_count += 1;
+            })
+            .is_err()
+    }
+
     fn fetch_update_call_count(&self) -> i32 {
         self.update_call_count
     }
@@ -125,4 +255,13 @@ impl qobject::MyObject {
     fn throw_exception(&self) -> Result<i32, String> {
         Err("RustException".to_string())
     }
+
+    /// Downcast the parent to MyObject and return its number, or -1 if it is not a MyObject
+    fn parent_number(&self) -> i32 {
+        // SAFETY: the parent outlives its children
+        unsafe { self.parent().as_ref() }
+            .and_then(|parent| parent.downcast::<Self>())
+            .map(|parent| *parent.number())
+            .unwrap_or(-1)
+    }
 }
@@ -7,11 +7,17 @@
 #include <QtCore/QScopedPointer>
 #include <QtTest/QTest>
 
+#include "qabstractlistmodelrow.h"
+#include "qbitarray.h"
 #include "qbytearray.h"
+#include "qbrush.h"
+#include "qcollator.h"
 #include "qcolor.h"
 #include "qcoreapplication.h"
 #include "qdate.h"
 #include "qdatetime.h"
+#include "qdeadlinetimer.h"
+#include "qfontmetricsf.h"
 #include "qguiapplication.h"
 #include "qhash.h"
 #include "qline.h"
@@ -20,8 +26,11 @@
 #include "qmap.h"
 #include "qmargins.h"
 #include "qmarginsf.h"
+#include "qmatrix4x4.h"
 #include "qmetaobjectconnection.h"
 #include "qmodelindex.h"
+#include "qobject.h"
+#include "qpalette.h"
 #include "qpen.h"
 #include "qpersistentmodelindex.h"
 #include "qpoint.h"
@@ -29,23 +38,34 @@
 #include "qpolygon.h"
 #include "qpolygonf.h"
 #include "qqmlapplicationengine.h"
+#include "qqmlcomponent.h"
 #include "qqmlengine.h"
+#include "qquaternion.h"
+#include "qquickwindow.h"
+#include "qrandomgenerator.h"
 #include "qrect.h"
 #include "qrectf.h"
 #include "qregion.h"
 #include "qset.h"
 #include "qsize.h"
 #include "qsizef.h"
+#include "qsocketnotifier.h"
 #include "qstring.h"
+#include "qstringconverter.h"
 #include "qstringlist.h"
 #include "qtime.h"
+#include "qtimer.h"
+#include "qtlogging.h"
 #include "qtimezone.h"
+#include "qtransform.h"
 #include "qurl.h"
+#include "qurlquery.h"
 #include "qvariant.h"
 #include "qvector.h"
 #include "qvector2d.h"
 #include "qvector3d.h"
 #include "qvector4d.h"
+#include "qversionnumber.h"
 
 int
 main(int argc, char* argv[])
@@ -59,11 +79,17 @@ main(int argc, char* argv[])
     }
   };
 
+  runTest(QScopedPointer<QObject>(new QAbstractListModelRowTest));
+  runTest(QScopedPointer<QObject>(new QBitArrayTest));
   runTest(QScopedPointer<QObject>(new QByteArrayTest));
+  runTest(QScopedPointer<QObject>(new QBrushTest));
+  runTest(QScopedPointer<QObject>(new QCollatorTest));
   runTest(QScopedPointer<QObject>(new QColorTest));
   runTest(QScopedPointer<QObject>(new QCoreApplicationTest));
   runTest(QScopedPointer<QObject>(new QDateTest));
   runTest(QScopedPointer<QObject>(new QDateTimeTest));
+  runTest(QScopedPointer<QObject>(new QDeadlineTimerTest));
+  runTest(QScopedPointer<QObject>(new QFontMetricsFTest));
   runTest(QScopedPointer<QObject>(new QGuiApplicationTest));
   runTest(QScopedPointer<QObject>(new QHashTest));
   runTest(QScopedPointer<QObject>(new QLineTest));
@@ -72,29 +98,45 @@ main(int argc, char* argv[])
   runTest(QScopedPointer<QObject>(new QMapTest));
   runTest(QScopedPointer<QObject>(new QMarginsTest));
   runTest(QScopedPointer<QObject>(new QMarginsFTest));
+  runTest(QScopedPointer<QObject>(new QMatrix4x4Test));
   runTest(QScopedPointer<QObject>(new QMetaObjectConnectionTest));
   runTest(QScopedPointer<QObject>(new QModelIndexTest));
+  runTest(QScopedPointer<QObject>(new QObjectTest));
   runTest(QScopedPointer<QObject>(new QPersistentModelIndexTest));
   runTest(QScopedPointer<QObject>(new QPointTest));
   runTest(QScopedPointer<QObject>(new QPointFTest));
   runTest(QScopedPointer<QObject>(new QQmlApplicationEngineTest));
+  runTest(QScopedPointer<QObject>(new QQmlComponentTest));
   runTest(QScopedPointer<QObject>(new QQmlEngineTest));
+  runTest(QScopedPointer<QObject>(new QQuaternionTest));
+  runTest(QScopedPointer<QObject>(new QQuickWindowTest));
+  runTest(QScopedPointer<QObject>(new QRandomGeneratorTest));
   runTest(QScopedPointer<QObject>(new QRectTest));
   runTest(QScopedPointer<QObject>(new QRectFTest));
   runTest(QScopedPointer<QObject>(new QSetTest));
   runTest(QScopedPointer<QObject>(new QSizeTest));
   runTest(QScopedPointer<QObject>(new QSizeFTest));
+  runTest(QScopedPointer<QObject>(new QSocketNotifierTest));
   runTest(QScopedPointer<QObject>(new QStringTest));
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+  runTest(QScopedPointer<QObject>(new QStringConverterTest));
+#endif
   runTest(QScopedPointer<QObject>(new QStringListTest));
   runTest(QScopedPointer<QObject>(new QTimeTest));
+  runTest(QScopedPointer<QObject>(new QTimerTest));
   runTest(QScopedPointer<QObject>(new QTimeZoneTest));
+  runTest(QScopedPointer<QObject>(new QtLoggingTest));
+  runTest(QScopedPointer<QObject>(new QTransformTest));
   runTest(QScopedPointer<QObject>(new QUrlTest));
+  runTest(QScopedPointer<QObject>(new QUrlQueryTest));
   runTest(QScopedPointer<QObject>(new QVariantTest));
   runTest(QScopedPointer<QObject>(new QVectorTest));
   runTest(QScopedPointer<QObject>(new QVector2DTest));
   runTest(QScopedPointer<QObject>(new QVector3DTest));
   runTest(QScopedPointer<QObject>(new QVector4DTest));
+  runTest(QScopedPointer<QObject>(new QVersionNumberTest));
   runTest(QScopedPointer<QObject>(new QPolygonTest));
+  runTest(QScopedPointer<QObject>(new QPaletteTest));
   runTest(QScopedPointer<QObject>(new QPenTest));
   runTest(QScopedPointer<QObject>(new QPolygonFTest));
   runTest(QScopedPointer<QObject>(new QRegionTest));
@@ -0,0 +1,56 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QByteArray>
+#include <QtCore/QHash>
+#include <QtCore/QPoint>
+#include <QtCore/QVariant>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qabstractlistmodelrow.cxx.h"
+
+class QAbstractListModelRowTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void roleNames()
+  {
+    const auto roleNames = role_names_qabstractlistmodelrow();
+    QCOMPARE(roleNames.size(), 2);
+    QCOMPARE(roleNames.value(Qt::UserRole), QByteArray("title"));
+    QCOMPARE(roleNames.value(Qt::UserRole + 1), QByteArray("pageCount"));
+  }
+
+  void roleData()
+  {
+    QCOMPARE(role_data_qabstractlistmodelrow(Qt::UserRole),
+             QVariant::fromValue(QStringLiteral("KDAB")));
+    QCOMPARE(role_data_qabstractlistmodelrow(Qt::UserRole + 1),
+             QVariant::fromValue(42));
+    QVERIFY(!role_data_qabstractlistmodelrow(Qt::DisplayRole).isValid());
+  }
+
+  void setRoleData()
+  {
+    QVERIFY(set_role_data_qabstractlistmodelrow(
+      Qt::UserRole, QVariant::fromValue(QStringLiteral("Qt"))));
+    QVERIFY(set_role_data_qabstractlistmodelrow(Qt::UserRole + 1,
+                                                QVariant::fromValue(7)));
+  }
+
+  void setRoleDataInvalid()
+  {
+    // The value cannot be converted to the type of the field
+    QVERIFY(!set_role_data_qabstractlistmodelrow(
+      Qt::UserRole + 1, QVariant::fromValue(QPoint(1, 2))));
+    // There is no field for the role
+    QVERIFY(!set_role_data_qabstractlistmodelrow(Qt::DisplayRole,
+                                                 QVariant::fromValue(7)));
+  }
+};
@@ -0,0 +1,62 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QBitArray>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qbitarray.cxx.h"
+
+class QBitArrayTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto b = construct_qbitarray();
+    QCOMPARE(b.size(), 4);
+    QVERIFY(b.testBit(0));
+    QVERIFY(!b.testBit(1));
+    QVERIFY(b.testBit(2));
+    QVERIFY(b.testBit(3));
+  }
+
+  void read()
+  {
+    auto b = QBitArray(3);
+    b.setBit(1);
+    b.setBit(2);
+    QVERIFY(read_qbitarray(b));
+  }
+
+  void clone()
+  {
+    auto b = QBitArray(2);
+    b.setBit(0);
+    const auto c = clone_qbitarray(b);
+    QCOMPARE(c, b);
+  }
+
+  void bitwiseAnd()
+  {
+    auto a = QBitArray(3, true);
+    auto b = QBitArray(2);
+    b.setBit(1);
+    const auto c = and_qbitarray(a, b);
+    QCOMPARE(c, a & b);
+    QCOMPARE(c.size(), 3);
+    QCOMPARE(c.count(true), 1);
+  }
+
+  void toggle()
+  {
+    auto b = QBitArray(2);
+    QVERIFY(!toggle_qbitarray(b, 1));
+    QVERIFY(b.testBit(1));
+  }
+};
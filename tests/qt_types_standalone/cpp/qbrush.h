@@ -0,0 +1,58 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QBrush>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qbrush.cxx.h"
+
+class QBrushTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto b = construct_qbrush();
+    QCOMPARE(b.style(), Qt::CrossPattern);
+    QCOMPARE(b.color(), QColor(255, 0, 0));
+  }
+
+  void clone()
+  {
+    const auto b = QBrush(Qt::blue, Qt::HorPattern);
+    const auto c = clone_qbrush(b);
+    QCOMPARE(c.style(), Qt::HorPattern);
+    QCOMPARE(c.color(), QColor(Qt::blue));
+  }
+  void constructGradient()
+  {
+    const auto linear = construct_qbrush_linear_gradient();
+    QCOMPARE(linear.style(), Qt::LinearGradientPattern);
+    QVERIFY(linear.gradient() != nullptr);
+    QCOMPARE(linear.gradient()->spread(), QGradient::ReflectSpread);
+    QCOMPARE(linear.gradient()->stops().size(), 2);
+
+    const auto radial = construct_qbrush_radial_gradient();
+    QCOMPARE(radial.style(), Qt::RadialGradientPattern);
+    const auto gradient =
+      static_cast<const QRadialGradient*>(radial.gradient());
+    QCOMPARE(gradient->center(), QPointF(50.0, 50.0));
+    QCOMPARE(gradient->centerRadius(), 25.0);
+    QCOMPARE(gradient->stops().size(), 1);
+  }
+
+  void readGradient()
+  {
+    auto gradient = QLinearGradient(QPointF(0.0, 0.0), QPointF(0.0, 100.0));
+    gradient.setColorAt(0.0, Qt::red);
+    gradient.setColorAt(1.0, Qt::blue);
+    QVERIFY(read_qbrush_gradient(QBrush(gradient)));
+    QVERIFY(!read_qbrush_gradient(QBrush(Qt::red)));
+  }
+};
@@ -0,0 +1,50 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCollator>
+#include <QtCore/QLocale>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qcollator.cxx.h"
+
+class QCollatorTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto c = construct_qcollator();
+    QCOMPARE(c.locale(), QLocale(QStringLiteral("en_US")));
+    QCOMPARE(c.caseSensitivity(), Qt::CaseInsensitive);
+    QVERIFY(c.ignorePunctuation());
+  }
+
+  void read()
+  {
+    auto c = QCollator(QLocale(QStringLiteral("de_DE")));
+    c.setCaseSensitivity(Qt::CaseSensitive);
+    c.setIgnorePunctuation(false);
+    QVERIFY(read_qcollator(c));
+  }
+
+  void clone()
+  {
+    auto c = QCollator(QLocale(QStringLiteral("de_DE")));
+    c.setNumericMode(true);
+    const auto cloned = clone_qcollator(c);
+    QCOMPARE(cloned.locale(), c.locale());
+    QVERIFY(cloned.numericMode());
+  }
+
+  void compare()
+  {
+    const auto c = QCollator(QLocale(QStringLiteral("en_US")));
+    QVERIFY(compare_qcollator(c));
+  }
+};
@@ -47,4 +47,33 @@ private Q_SLOTS:
     const auto c = clone_qcolor(color);
     QCOMPARE(c, Qt::GlobalColor::red);
   }
+  void compositeOver()
+  {
+    const auto opaque = composite_over_qcolor(QColor(255, 0, 0, 255),
+                                              QColor(0, 0, 255, 255));
+    QCOMPARE(opaque, QColor(255, 0, 0, 255));
+
+    const auto translucent = composite_over_qcolor(QColor(255, 0, 0, 128),
+                                                   QColor(0, 0, 255, 255));
+    QCOMPARE(translucent.alpha(), 255);
+    QCOMPARE(translucent.red(), 128);
+    QCOMPARE(translucent.blue(), 127);
+
+    const auto transparent = composite_over_qcolor(QColor(255, 0, 0, 0),
+                                                   QColor(0, 0, 0, 0));
+    QCOMPARE(transparent.alpha(), 0);
+  }
+
+  void mix()
+  {
+    const auto black = QColor(0, 0, 0, 0);
+    const auto white = QColor(255, 255, 255, 255);
+    QCOMPARE(mix_qcolor(black, white, 0.0), black);
+    QCOMPARE(mix_qcolor(black, white, 1.0), white);
+    QCOMPARE(mix_qcolor(black, white, 2.0), white);
+
+    const auto grey = mix_qcolor(black, white, 0.5);
+    QCOMPARE(grey.red(), 128);
+    QCOMPARE(grey.alpha(), 128);
+  }
 };
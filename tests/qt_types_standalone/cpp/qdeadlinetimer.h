@@ -0,0 +1,78 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QDeadlineTimer>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qdeadlinetimer.cxx.h"
+
+class QDeadlineTimerTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto t = construct_qdeadlinetimer();
+    QCOMPARE(t.deadline(), qint64(123456));
+    QCOMPARE(t.timerType(), Qt::PreciseTimer);
+    QVERIFY(!t.isForever());
+  }
+
+  void constructForever()
+  {
+    const auto t = construct_qdeadlinetimer_forever();
+    QVERIFY(t.isForever());
+    QVERIFY(!t.hasExpired());
+    QCOMPARE(t.remainingTime(), qint64(-1));
+  }
+
+  void constructDuration()
+  {
+    const auto t = construct_qdeadlinetimer_duration();
+    QVERIFY(!t.hasExpired());
+    QVERIFY(t.remainingTime() > 0);
+    QVERIFY(t.remainingTime() <= 60000);
+    QCOMPARE(t.timerType(), Qt::CoarseTimer);
+  }
+
+  void read()
+  {
+    QDeadlineTimer t;
+    t.setDeadline(123456, Qt::PreciseTimer);
+    QVERIFY(read_qdeadlinetimer(t));
+  }
+
+  void readForever()
+  {
+    const auto t = QDeadlineTimer(QDeadlineTimer::Forever);
+    QVERIFY(read_qdeadlinetimer_forever(t));
+  }
+
+  void clone()
+  {
+    QDeadlineTimer t;
+    t.setDeadline(123456, Qt::PreciseTimer);
+    const auto c = clone_qdeadlinetimer(t);
+    QCOMPARE(c, t);
+    QCOMPARE(c.deadline(), qint64(123456));
+    QCOMPARE(c.timerType(), Qt::PreciseTimer);
+  }
+
+  void add()
+  {
+    QDeadlineTimer t;
+    t.setDeadline(123456);
+    const auto a = add_qdeadlinetimer(t, 1000);
+    QCOMPARE(a.deadline(), qint64(124456));
+    QVERIFY(less_qdeadlinetimer(t, a));
+    QVERIFY(!less_qdeadlinetimer(a, t));
+    // A timer which never expires is later than any other
+    QVERIFY(less_qdeadlinetimer(a, QDeadlineTimer(QDeadlineTimer::Forever)));
+  }
+};
@@ -0,0 +1,63 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QFontMetricsF>
+#include <QtGui/QGuiApplication>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qfontmetricsf.cxx.h"
+
+class QFontMetricsFTest : public QObject
+{
+  Q_OBJECT
+
+private:
+  // The font database requires a QGuiApplication
+  QGuiApplication* createApp()
+  {
+    static std::string path = "/path";
+    static std::vector<char*> args = { path.data() };
+    static int argc = static_cast<int>(args.size());
+    return new QGuiApplication(argc, args.data());
+  }
+
+private Q_SLOTS:
+  void construct()
+  {
+    QScopedPointer<QGuiApplication> app(createApp());
+    const auto font = QFont(QStringLiteral("Sans"), 12);
+    const auto m = construct_qfontmetricsf(font);
+    QCOMPARE(m.height(), QFontMetricsF(font).height());
+  }
+
+  void clone()
+  {
+    QScopedPointer<QGuiApplication> app(createApp());
+    const auto m = QFontMetricsF(QFont(QStringLiteral("Sans"), 12));
+    const auto c = clone_qfontmetricsf(m);
+    QCOMPARE(c, m);
+  }
+
+  void elidedText()
+  {
+    QScopedPointer<QGuiApplication> app(createApp());
+    const auto m = QFontMetricsF(QFont(QStringLiteral("Sans"), 12));
+    const auto text = QStringLiteral("KDAB cxx-qt");
+    QCOMPARE(elided_text_qfontmetricsf(m, 1000.0), text);
+    QCOMPARE(elided_text_qfontmetricsf(m, 20.0),
+             m.elidedText(text, Qt::ElideRight, 20.0));
+  }
+
+  void horizontalAdvance()
+  {
+    QScopedPointer<QGuiApplication> app(createApp());
+    const auto m = QFontMetricsF(QFont(QStringLiteral("Sans"), 12));
+    QCOMPARE(horizontal_advance_qfontmetricsf(m),
+             m.horizontalAdvance(QStringLiteral("KDAB")));
+  }
+};
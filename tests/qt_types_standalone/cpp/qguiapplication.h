@@ -23,6 +23,32 @@ private Q_SLOTS:
     QCOMPARE(app->applicationName(), QStringLiteral("kdab"));
   }
 
+  void applicationStateChanged()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+
+    QGuiApplication app(argc, args.data());
+    QVERIFY(qguiapplication_application_state() != Qt::ApplicationActive);
+
+    const auto connection =
+      qguiapplication_connect_application_state_changed(app);
+    QVERIFY(QMetaObject::invokeMethod(
+      &app,
+      "applicationStateChanged",
+      Q_ARG(Qt::ApplicationState, Qt::ApplicationActive)));
+    QCOMPARE(qguiapplication_application_state(), Qt::ApplicationActive);
+
+    QVERIFY(QObject::disconnect(connection));
+    QVERIFY(QMetaObject::invokeMethod(
+      &app,
+      "applicationStateChanged",
+      Q_ARG(Qt::ApplicationState, Qt::ApplicationHidden)));
+    QCOMPARE(qguiapplication_application_state(), Qt::ApplicationActive);
+  }
+
   void read()
   {
     std::vector<char*> args;
@@ -57,4 +57,10 @@ private Q_SLOTS:
     QCOMPARE(m3.right(), 5);
     QCOMPARE(m3.bottom(), 5);
   }
+
+  void negateMargins()
+  {
+    const auto m = QMargins(1, 2, 3, 4);
+    QCOMPARE(negate_margins(m), -m);
+  }
 };
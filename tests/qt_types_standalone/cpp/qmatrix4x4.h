@@ -0,0 +1,49 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QMatrix4x4>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qmatrix4x4.cxx.h"
+
+class QMatrix4x4Test : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    QMatrix4x4 expected;
+    expected.translate(1.0f, 2.0f, 3.0f);
+    QCOMPARE(construct_qmatrix4x4(), expected);
+  }
+
+  void read()
+  {
+    QMatrix4x4 m;
+    m.translate(1.0f, 2.0f, 3.0f);
+    QVERIFY(read_qmatrix4x4(m));
+  }
+
+  void clone()
+  {
+    QMatrix4x4 m;
+    m.rotate(45.0f, 0.0f, 0.0f, 1.0f);
+    const auto c = clone_qmatrix4x4(m);
+    QCOMPARE(c, m);
+  }
+
+  void multiply()
+  {
+    QMatrix4x4 a;
+    a.scale(2.0f, 3.0f, 4.0f);
+    QMatrix4x4 b;
+    b.translate(1.0f, 2.0f, 3.0f);
+    QCOMPARE(multiply_qmatrix4x4(a, b), a * b);
+  }
+};
@@ -0,0 +1,149 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QObject>
+#include <QtCore/QPointer>
+#include <QtCore/QStringListModel>
+#include <QtCore/QThread>
+#include <QtCore/QTimer>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qobject.cxx.h"
+
+class QObjectTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void deleteLater()
+  {
+    QPointer<QObject> object(new QObject);
+    qobject_delete_later(*object);
+    QVERIFY(!object.isNull());
+
+    QCoreApplication::sendPostedEvents(nullptr, QEvent::DeferredDelete);
+    QVERIFY(object.isNull());
+  }
+
+  void destroyed()
+  {
+    const auto destroyed = qobject_destroyed_kdab();
+
+    auto dropped = new QObject;
+    dropped->setObjectName(QStringLiteral("kdab"));
+    qobject_connect_destroyed_dropped(*dropped);
+    delete dropped;
+    QCOMPARE(qobject_destroyed_kdab(), destroyed);
+
+    // The object name is still readable while destroyed is emitted
+    auto connected = new QObject;
+    connected->setObjectName(QStringLiteral("kdab"));
+    qobject_connect_destroyed(*connected);
+    delete connected;
+    QCOMPARE(qobject_destroyed_kdab(), destroyed + 1);
+
+    auto disconnected = new QObject;
+    disconnected->setObjectName(QStringLiteral("kdab"));
+    QVERIFY(QObject::disconnect(qobject_connect_destroyed(*disconnected)));
+    delete disconnected;
+    QCOMPARE(qobject_destroyed_kdab(), destroyed + 1);
+  }
+
+  void findChild()
+  {
+    QObject parent;
+    auto child = new QObject(&parent);
+    auto grandchild = new QObject(child);
+    QCOMPARE(qobject_find_child(parent), nullptr);
+    QCOMPARE(qobject_find_children_len(parent), 2);
+
+    grandchild->setObjectName(QStringLiteral("kdab"));
+    QCOMPARE(qobject_find_child(parent), grandchild);
+
+    child->setObjectName(QStringLiteral("kdab"));
+    QCOMPARE(qobject_find_child(parent), child);
+  }
+
+  void invokeMethod()
+  {
+    QStringListModel model(
+      QStringList{ QStringLiteral("a"), QStringLiteral("b") });
+    QCOMPARE(qobject_invoke_row_count(model), 2);
+
+    QTimer timer;
+    QVERIFY(qobject_invoke_start(timer));
+    QVERIFY(timer.isActive());
+    QCOMPARE(timer.interval(), 100);
+  }
+
+  void invokeMethodThread()
+  {
+    auto model = new QStringListModel(
+      QStringList{ QStringLiteral("a"), QStringLiteral("b") });
+    // The member is invoked directly when the object lives in the current thread
+    QCOMPARE(qobject_invoke_row_count_auto(*model), 2);
+    // Blocking would deadlock, so the member is not invoked
+    QCOMPARE(qobject_invoke_row_count_blocking(*model), -1);
+
+    QThread thread;
+    QObject::connect(&thread, &QThread::finished, model, &QObject::deleteLater);
+    model->moveToThread(&thread);
+    thread.start();
+
+    // The member is queued when the object lives in another thread,
+    // so there is no return value
+    QCOMPARE(qobject_invoke_row_count_auto(*model), 0);
+    QCOMPARE(qobject_invoke_row_count_blocking(*model), 2);
+
+    thread.quit();
+    QVERIFY(thread.wait());
+  }
+
+  void metaObject()
+  {
+    QTimer timer;
+    QVERIFY(qobject_meta_object_is_timer(timer));
+  }
+
+  void objectName()
+  {
+    QObject object;
+    qobject_write_object_name(object);
+    QCOMPARE(object.objectName(), QStringLiteral("kdab"));
+    QVERIFY(qobject_read_object_name(object));
+  }
+
+  void parent()
+  {
+    QObject parent;
+    auto child = new QObject;
+    QCOMPARE(qobject_children_len(parent), 0);
+
+    qobject_set_parent(*child, &parent);
+    QCOMPARE(child->parent(), &parent);
+    QVERIFY(qobject_is_parent(*child, parent));
+    QCOMPARE(qobject_children_len(parent), 1);
+
+    qobject_set_parent(*child, nullptr);
+    QCOMPARE(qobject_children_len(parent), 0);
+    delete child;
+  }
+
+  void property()
+  {
+    QObject object;
+    QVERIFY(!qobject_write_property(object));
+    QCOMPARE(object.property("kdab").toInt(), 42);
+    QCOMPARE(object.dynamicPropertyNames().size(), 1);
+    QVERIFY(qobject_read_property(object));
+
+    object.setProperty("objectName", QStringLiteral("kdab"));
+    QVERIFY(qobject_read_object_name(object));
+  }
+};
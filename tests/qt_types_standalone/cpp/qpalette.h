@@ -0,0 +1,36 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QPalette>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qpalette.cxx.h"
+
+class QPaletteTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto p = construct_qpalette();
+    QCOMPARE(p.color(QPalette::Active, QPalette::Button), QColor(0, 0, 255));
+    QCOMPARE(p.color(QPalette::Active, QPalette::Highlight),
+             QColor(255, 0, 0));
+    QCOMPARE(p.color(QPalette::Disabled, QPalette::Highlight),
+             QColor(255, 0, 0));
+  }
+
+  void clone()
+  {
+    const auto p = QPalette(Qt::green, Qt::white);
+    const auto c = clone_qpalette(p);
+    QCOMPARE(c, p);
+    QCOMPARE(c.color(QPalette::Window), QColor(Qt::white));
+  }
+};
@@ -32,4 +32,19 @@ private Q_SLOTS:
     QCOMPARE(c.toPolygon().point(0), QPoint(1, 2));
     QCOMPARE(c.toPolygon().point(1), QPoint(3, 4));
   }
+
+  void constructFromPoints()
+  {
+    const auto m = construct_qpolygonf_from_points();
+    QCOMPARE(m.size(), 3);
+    QCOMPARE(m.at(2), QPointF(10.0, 10.0));
+    QVERIFY(m.containsPoint(QPointF(8.0, 2.0), Qt::OddEvenFill));
+    QVERIFY(!m.containsPoint(QPointF(2.0, 8.0), Qt::OddEvenFill));
+  }
+
+  void read()
+  {
+    const auto m = QPolygonF() << QPointF(1.0, 2.0) << QPointF(3.0, 4.0);
+    QVERIFY(read_qpolygonf(m));
+  }
 };
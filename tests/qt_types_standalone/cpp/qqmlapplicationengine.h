@@ -8,6 +8,7 @@
 
 #include <QtCore/QCoreApplication>
 #include <QtQml/QQmlApplicationEngine>
+#include <QtQml/QQmlContext>
 #include <QtTest/QTest>
 
 #include "qt_types_standalone/src/qqmlapplicationengine.cxx.h"
@@ -44,4 +45,34 @@ private Q_SLOTS:
     engine.setBaseUrl(QUrl(QStringLiteral("qrc:/kdab.qml")));
     QVERIFY(read_qqmlapplicationengine(engine));
   }
+
+  void loadData()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QQmlApplicationEngine engine;
+    QVERIFY(load_data_qqmlapplicationengine(engine));
+    QCOMPARE(engine.rootObjects().size(), 1);
+  }
+
+  void setContextProperty()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QQmlApplicationEngine engine;
+    set_context_property_qqmlapplicationengine(engine);
+    QCOMPARE(
+      engine.rootContext()->contextProperty(QStringLiteral("kdab")).toInt(),
+      42);
+  }
 };
@@ -0,0 +1,45 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtQml/QQmlEngine>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qqmlcomponent.cxx.h"
+
+class QQmlComponentTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void create()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    QVERIFY(create_qqmlcomponent(engine));
+  }
+
+  void errors()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    QVERIFY(errors_qqmlcomponent(engine));
+  }
+};
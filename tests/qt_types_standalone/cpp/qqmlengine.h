@@ -7,6 +7,7 @@
 #pragma once
 
 #include <QtCore/QCoreApplication>
+#include <QtQml/QQmlAbstractUrlInterceptor>
 #include <QtQml/QQmlEngine>
 #include <QtTest/QTest>
 
@@ -44,4 +45,33 @@ private Q_SLOTS:
     engine.setBaseUrl(QUrl(QStringLiteral("qrc:/kdab.qml")));
     QVERIFY(read_qqmlengine(engine));
   }
+
+  void urlInterceptor()
+  {
+    // QQmlEngine requires a QApplication
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    QQmlEngine engine;
+    // Setting the interceptor twice replaces the first interceptor
+    set_qqmlengine_url_interceptor(engine);
+    set_qqmlengine_url_interceptor(engine);
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+    QCOMPARE(engine.urlInterceptors().size(), 1);
+    auto* interceptor = engine.urlInterceptors().first();
+#else
+    auto* interceptor = engine.urlInterceptor();
+#endif
+    QVERIFY(interceptor != nullptr);
+    QCOMPARE(interceptor->intercept(QUrl(QStringLiteral("qrc:/kdab.qml")),
+                                    QQmlAbstractUrlInterceptor::QmlFile),
+             QUrl(QStringLiteral("qrc:/redirected.qml")));
+    QCOMPARE(interceptor->intercept(QUrl(QStringLiteral("qrc:/kdab.qml")),
+                                    QQmlAbstractUrlInterceptor::UrlString),
+             QUrl(QStringLiteral("qrc:/kdab.qml")));
+  }
 };
@@ -0,0 +1,46 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QQuaternion>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qquaternion.cxx.h"
+
+class QQuaternionTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto q = construct_qquaternion();
+    QCOMPARE(q,
+             QQuaternion::fromAxisAndAngle(QVector3D(0.0f, 0.0f, 1.0f), 90.0f));
+  }
+
+  void read()
+  {
+    const auto q =
+      QQuaternion::fromAxisAndAngle(QVector3D(0.0f, 0.0f, 1.0f), 90.0f);
+    QVERIFY(read_qquaternion(q));
+  }
+
+  void clone()
+  {
+    const auto q = QQuaternion(1.0f, 2.0f, 3.0f, 4.0f);
+    const auto c = clone_qquaternion(q);
+    QCOMPARE(c, q);
+  }
+
+  void multiply()
+  {
+    const auto a = QQuaternion::fromEulerAngles(10.0f, 20.0f, 30.0f);
+    const auto b = QQuaternion::fromEulerAngles(40.0f, 50.0f, 60.0f);
+    QCOMPARE(multiply_qquaternion(a, b), a * b);
+  }
+};
@@ -0,0 +1,76 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtGui/QCloseEvent>
+#include <QtGui/QGuiApplication>
+#include <QtQuick/QQuickWindow>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qquickwindow.cxx.h"
+
+class QQuickWindowTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void beforeRendering()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+
+    QGuiApplication app(argc, args.data());
+    QQuickWindow window;
+    const auto renders = qquickwindow_renders();
+
+    // The closure is connected directly, so it is called by the emitting thread
+    const auto connection = qquickwindow_connect_before_rendering(window);
+    QVERIFY(QMetaObject::invokeMethod(&window, "beforeRendering"));
+    QCOMPARE(qquickwindow_renders(), renders + 1);
+
+    QVERIFY(QObject::disconnect(connection));
+    QVERIFY(QMetaObject::invokeMethod(&window, "beforeRendering"));
+    QCOMPARE(qquickwindow_renders(), renders + 1);
+  }
+
+  void closing()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+
+    QGuiApplication app(argc, args.data());
+    QQuickWindow window;
+    const auto closes = qquickwindow_closes();
+
+    // The window emits closing with its close event when it receives a close
+    const auto connection = qquickwindow_connect_closing(window);
+    QCloseEvent event;
+    QCoreApplication::sendEvent(&window, &event);
+    QCOMPARE(qquickwindow_closes(), closes + 1);
+
+    QVERIFY(QObject::disconnect(connection));
+    QCoreApplication::sendEvent(&window, &event);
+    QCOMPARE(qquickwindow_closes(), closes + 1);
+  }
+
+  void read()
+  {
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+
+    QGuiApplication app(argc, args.data());
+    QQuickWindow window;
+    QVERIFY(read_qquickwindow(window));
+  }
+};
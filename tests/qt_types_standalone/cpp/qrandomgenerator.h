@@ -0,0 +1,81 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <array>
+
+#include <QtCore/QRandomGenerator>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qrandomgenerator.cxx.h"
+
+class QRandomGeneratorTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto g = construct_qrandomgenerator(42);
+    QRandomGenerator expected(42);
+    for (int i = 0; i < 8; ++i) {
+      QCOMPARE(g->generate(), expected.generate());
+    }
+  }
+
+  void constructFromSeeds()
+  {
+    const ::std::array<quint32, 3> seeds{ 1, 2, 3 };
+    const auto g = construct_qrandomgenerator_from_seeds(
+      ::rust::Slice<const ::std::uint32_t>(seeds.data(), seeds.size()));
+    QRandomGenerator expected(seeds.data(), seeds.data() + seeds.size());
+    for (int i = 0; i < 8; ++i) {
+      QCOMPARE(g->generate(), expected.generate());
+    }
+  }
+
+  void read()
+  {
+    QRandomGenerator g(42);
+    QVERIFY(read_qrandomgenerator(g, 42));
+  }
+
+  void clone()
+  {
+    QRandomGenerator g(42);
+    g.discard(10);
+    const auto c = clone_qrandomgenerator(g);
+    // The clone continues from the current state of the generator
+    for (int i = 0; i < 8; ++i) {
+      QCOMPARE(c->generate(), g.generate());
+    }
+  }
+
+  void generate()
+  {
+    QRandomGenerator g(42);
+    QRandomGenerator expected(42);
+    QCOMPARE(generate_qrandomgenerator(g), expected.generate());
+    QCOMPARE(static_cast<quint64>(generate64_qrandomgenerator(g)),
+             expected.generate64());
+    QCOMPARE(bounded_qrandomgenerator(g, 10), expected.bounded(10u));
+  }
+
+  void fillRange()
+  {
+    QRandomGenerator g(42);
+    QRandomGenerator expected(42);
+    ::std::array<quint32, 4> buffer{};
+    ::std::array<quint32, 4> expectedBuffer{};
+    fill_range_qrandomgenerator(
+      g, ::rust::Slice<::std::uint32_t>(buffer.data(), buffer.size()));
+    expected.fillRange(expectedBuffer.data(), expectedBuffer.size());
+    for (::std::size_t i = 0; i < buffer.size(); ++i) {
+      QCOMPARE(buffer[i], expectedBuffer[i]);
+    }
+  }
+};
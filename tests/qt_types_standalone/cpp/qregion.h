@@ -28,4 +28,19 @@ private Q_SLOTS:
     const auto c = clone_qregion(p);
     QCOMPARE(p, c);
   }
+
+  void constructFromRects()
+  {
+    const auto p = construct_qregion_from_rects();
+    QCOMPARE(p.rectCount(), 2);
+    QCOMPARE(p.boundingRect(), QRect(0, 0, 30, 10));
+    QVERIFY(p.contains(QPoint(25, 5)));
+    QVERIFY(!p.contains(QPoint(15, 5)));
+  }
+
+  void read()
+  {
+    const auto p = QRegion(2, 4, 5, 3);
+    QVERIFY(read_qregion(p));
+  }
 };
@@ -0,0 +1,54 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QCoreApplication>
+#include <QtCore/QSocketNotifier>
+#include <QtTest/QTest>
+
+#ifdef Q_OS_UNIX
+#include <unistd.h>
+#endif
+
+#include "qt_types_standalone/src/qsocketnotifier.cxx.h"
+
+class QSocketNotifierTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void activated()
+  {
+#ifdef Q_OS_UNIX
+    std::vector<char*> args;
+    std::string path = "/path";
+    args.push_back(path.data());
+    auto argc = static_cast<int>(args.size());
+    QCoreApplication app(argc, args.data());
+
+    int fds[2];
+    QCOMPARE(pipe(fds), 0);
+
+    auto notifier = construct_qsocketnotifier(fds[0]);
+    QVERIFY(notifier != nullptr);
+    QCOMPARE(notifier->socket(), static_cast<qintptr>(fds[0]));
+    QCOMPARE(notifier->type(), QSocketNotifier::Read);
+    QVERIFY(notifier->isEnabled());
+    QCOMPARE(qsocketnotifier_activations(), 0u);
+
+    QCOMPARE(write(fds[1], "a", 1), static_cast<ssize_t>(1));
+    QTRY_COMPARE(qsocketnotifier_activations(), 1u);
+    QVERIFY(!notifier->isEnabled());
+
+    notifier.reset();
+    close(fds[0]);
+    close(fds[1]);
+#else
+    QSKIP("Pipes are only tested on Unix");
+#endif
+  }
+};
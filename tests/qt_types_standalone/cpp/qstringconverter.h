@@ -0,0 +1,95 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QtGlobal>
+
+// QStringDecoder and QStringEncoder are only available in Qt 6
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 0, 0))
+
+#include <QtCore/QStringDecoder>
+#include <QtCore/QStringEncoder>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qstringconverter.cxx.h"
+
+class QStringConverterTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void constructDecoder()
+  {
+    const auto decoder = construct_qstringdecoder(QStringConverter::Utf16LE);
+    QVERIFY(decoder->isValid());
+    QCOMPARE(QString::fromLatin1(decoder->name()), QStringLiteral("UTF-16LE"));
+    QCOMPARE((*decoder)(QByteArray("a\0b\0", 4)), QStringLiteral("ab"));
+  }
+
+  void constructDecoderFromName()
+  {
+    const auto decoder = construct_qstringdecoder_from_name("ISO-8859-1");
+    QVERIFY(decoder->isValid());
+    QCOMPARE((*decoder)(QByteArray("\xe9")), QStringLiteral("é"));
+  }
+
+  void constructEncoder()
+  {
+    const auto encoder = construct_qstringencoder(QStringConverter::Utf16BE);
+    QVERIFY(encoder->isValid());
+    QCOMPARE(QString::fromLatin1(encoder->name()), QStringLiteral("UTF-16BE"));
+    QCOMPARE(QByteArray((*encoder)(QStringLiteral("ab"))),
+             QByteArray("\0a\0b", 4));
+  }
+
+  void constructInvalid()
+  {
+    const auto decoder = construct_qstringdecoder_from_name("not-an-encoding");
+    QVERIFY(!decoder->isValid());
+    const auto encoder = construct_qstringencoder_from_name("not-an-encoding");
+    QVERIFY(!encoder->isValid());
+  }
+
+  void decode()
+  {
+    QStringDecoder decoder(QStringConverter::Utf8);
+    const QByteArray bytes = QStringLiteral("héllo").toUtf8();
+    QCOMPARE(decode_qstringdecoder(
+               decoder,
+               ::rust::Slice<const ::std::uint8_t>(
+                 reinterpret_cast<const ::std::uint8_t*>(bytes.constData()),
+                 static_cast<::std::size_t>(bytes.size()))),
+             QStringLiteral("héllo"));
+    QVERIFY(!decoder.hasError());
+  }
+
+  void encode()
+  {
+    QStringEncoder encoder(QStringConverter::Latin1);
+    QCOMPARE(encode_qstringencoder(encoder, QStringLiteral("héllo")),
+             QByteArray("h\xe9llo"));
+    QVERIFY(!encoder.hasError());
+
+    // Characters which cannot be represented in the encoding are an error
+    encode_qstringencoder(encoder, QStringLiteral("€"));
+    QVERIFY(encoder.hasError());
+  }
+
+  void roundTrip()
+  {
+    const auto string = QStringLiteral("héllo €");
+    QCOMPARE(round_trip_qstringconverter(string, "UTF-8"), string);
+    QCOMPARE(round_trip_qstringconverter(string, "UTF-16"), string);
+    QCOMPARE(round_trip_qstringconverter(string, "UTF-32BE"), string);
+  }
+
+  void decodeInvalid() { QVERIFY(read_qstringdecoder_invalid_utf8()); }
+
+  void decodeChunks() { QVERIFY(read_qstringdecoder_chunks()); }
+};
+
+#endif
@@ -0,0 +1,67 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QTimer>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qtimer.cxx.h"
+
+class QTimerTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto timer = construct_qtimer();
+    QVERIFY(timer != nullptr);
+    QCOMPARE(timer->interval(), 100);
+    QVERIFY(timer->isSingleShot());
+    QCOMPARE(timer->timerType(), Qt::VeryCoarseTimer);
+  }
+
+  void read()
+  {
+    QTimer timer;
+    timer.setInterval(100);
+    timer.setSingleShot(true);
+    timer.setTimerType(Qt::VeryCoarseTimer);
+    QVERIFY(read_qtimer(timer));
+  }
+
+  void startStop()
+  {
+    QTimer timer;
+    qtimer_start(timer);
+    QVERIFY(timer.isActive());
+    QCOMPARE(timer.interval(), 1000);
+    QVERIFY(timer.remainingTime() >= 0);
+
+    qtimer_stop(timer);
+    QVERIFY(!timer.isActive());
+    QCOMPARE(timer.remainingTime(), -1);
+  }
+
+  void timeout()
+  {
+    QTimer timer;
+    const auto timeouts = qtimer_timeouts();
+
+    qtimer_connect_timeout_dropped(timer);
+    QVERIFY(QMetaObject::invokeMethod(&timer, "timeout"));
+    QCOMPARE(qtimer_timeouts(), timeouts);
+
+    auto connection = qtimer_connect_timeout(timer);
+    QVERIFY(QMetaObject::invokeMethod(&timer, "timeout"));
+    QCOMPARE(qtimer_timeouts(), timeouts + 1);
+
+    QVERIFY(QObject::disconnect(connection));
+    QVERIFY(QMetaObject::invokeMethod(&timer, "timeout"));
+    QCOMPARE(qtimer_timeouts(), timeouts + 1);
+  }
+};
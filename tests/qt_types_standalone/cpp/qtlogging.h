@@ -0,0 +1,76 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QLoggingCategory>
+#include <QtCore/QStringList>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qtlogging.cxx.h"
+
+namespace {
+
+QStringList messages;
+
+void
+captureMessage(QtMsgType type,
+               const QMessageLogContext& context,
+               const QString& message)
+{
+  messages.append(QStringLiteral("%1 %2 %3")
+                    .arg(static_cast<int>(type))
+                    .arg(QString::fromUtf8(context.category))
+                    .arg(message));
+}
+
+}
+
+class QtLoggingTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void logToCategory()
+  {
+    messages.clear();
+    const auto previous = qInstallMessageHandler(captureMessage);
+    QLoggingCategory::setFilterRules(
+      QStringLiteral("cxx_qt.test.debug=true\n"
+                     "cxx_qt.test.disabled.debug=false"));
+
+    log_qt_warning("cxx_qt.test", 42);
+    log_qt_debug("cxx_qt.test.disabled");
+    log_qt_debug("cxx_qt.test");
+
+    QLoggingCategory::setFilterRules(QString());
+    qInstallMessageHandler(previous);
+
+    QCOMPARE(messages.size(), 2);
+    QCOMPARE(messages.at(0),
+             QStringLiteral("%1 cxx_qt.test The value is 42")
+               .arg(static_cast<int>(QtWarningMsg)));
+    QCOMPARE(messages.at(1),
+             QStringLiteral("%1 cxx_qt.test debug message")
+               .arg(static_cast<int>(QtDebugMsg)));
+  }
+
+  void messageHandler()
+  {
+    install_capturing_message_handler();
+    qDebug() << "ignored";
+    qWarning() << "first warning";
+    log_qt_warning("cxx_qt.test", 1);
+    qInstallMessageHandler(nullptr);
+    qWarning() << "not captured";
+
+    const auto captured = take_captured_messages();
+    QCOMPARE(captured.size(), 2);
+    QCOMPARE(std::string(captured[0]), std::string("default: first warning"));
+    QCOMPARE(std::string(captured[1]),
+             std::string("cxx_qt.test: The value is 1"));
+  }
+};
@@ -0,0 +1,45 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtGui/QTransform>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qtransform.cxx.h"
+
+class QTransformTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto t = construct_qtransform();
+    QCOMPARE(t, QTransform(2.0, 0.0, 0.0, 3.0, 10.0, 20.0));
+  }
+
+  void read()
+  {
+    auto t = QTransform::fromTranslate(10.0, 20.0);
+    t.scale(2.0, 3.0);
+    QVERIFY(read_qtransform(t));
+  }
+
+  void clone()
+  {
+    const auto t = QTransform::fromScale(2.0, 3.0);
+    const auto c = clone_qtransform(t);
+    QCOMPARE(c, t);
+  }
+
+  void multiply()
+  {
+    const auto a = QTransform::fromScale(2.0, 3.0);
+    const auto b = QTransform::fromTranslate(10.0, 20.0);
+    QCOMPARE(multiply_qtransform(a, b), a * b);
+  }
+};
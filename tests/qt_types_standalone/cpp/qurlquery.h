@@ -0,0 +1,46 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QUrl>
+#include <QtCore/QUrlQuery>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qurlquery.cxx.h"
+
+class QUrlQueryTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto q = construct_qurlquery();
+    QCOMPARE(q.toString(), QStringLiteral("a=1&a=3"));
+  }
+
+  void read()
+  {
+    const auto q =
+      QUrlQuery(QStringLiteral("key=value%20with%20space&other=x"));
+    QVERIFY(read_qurlquery(q));
+  }
+
+  void clone()
+  {
+    const auto q = QUrlQuery(QStringLiteral("a=1&b=2"));
+    const auto c = clone_qurlquery(q);
+    QCOMPARE(c, q);
+  }
+
+  void setQuery()
+  {
+    const auto u = set_qurl_query(
+      QUrl(QStringLiteral("https://kdab.com/search?q=rust&page=1")));
+    QCOMPARE(u, QUrl(QStringLiteral("https://kdab.com/search?q=rust&page=2")));
+  }
+};
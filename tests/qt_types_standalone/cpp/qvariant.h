@@ -50,6 +50,10 @@ test_constructed_qvariant(const QVariant& v, VariantTest test)
              v.value<QDateTime>().time().second() == 3 &&
              v.value<QDateTime>().time().msec() == 4 &&
              v.value<QDateTime>().offsetFromUtc() == 0;
+    case VariantTest::QMargins:
+      return v.value<QMargins>() == QMargins(1, 2, 3, 4);
+    case VariantTest::QMarginsF:
+      return v.value<QMarginsF>() == QMarginsF(1.5, 2.5, 3.5, 4.5);
     case VariantTest::QPoint:
       return v.value<QPoint>().x() == 1 && v.value<QPoint>().y() == 3;
     case VariantTest::QPointF:
@@ -116,6 +120,8 @@ private Q_SLOTS:
     QTest::newRow("QColor") << VariantTest::QColor;
     QTest::newRow("QDate") << VariantTest::QDate;
     QTest::newRow("QDateTime") << VariantTest::QDateTime;
+    QTest::newRow("QMargins") << VariantTest::QMargins;
+    QTest::newRow("QMarginsF") << VariantTest::QMarginsF;
     QTest::newRow("QPoint") << VariantTest::QPoint;
     QTest::newRow("QPointF") << VariantTest::QPointF;
     QTest::newRow("QRect") << VariantTest::QRect;
@@ -165,6 +171,12 @@ private Q_SLOTS:
       << QVariant::fromValue<QDateTime>(
            QDateTime(QDate(2021, 12, 31), QTime(4, 3, 2, 1), Qt::UTC))
       << VariantTest::QDateTime;
+    QTest::newRow("QMargins")
+      << QVariant::fromValue<QMargins>(QMargins(8, 9, 10, 11))
+      << VariantTest::QMargins;
+    QTest::newRow("QMarginsF")
+      << QVariant::fromValue<QMarginsF>(QMarginsF(8.5, 9.5, 10.5, 11.5))
+      << VariantTest::QMarginsF;
     QTest::newRow("QPoint")
       << QVariant::fromValue<QPoint>(QPoint(8, 9)) << VariantTest::QPoint;
     QTest::newRow("QPointF") << QVariant::fromValue<QPointF>(QPointF(8.0, 9.0))
@@ -0,0 +1,50 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QVersionNumber>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qversionnumber.cxx.h"
+
+class QVersionNumberTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void construct()
+  {
+    const auto v = construct_qversionnumber();
+    QCOMPARE(v, QVersionNumber(5, 15, 2));
+  }
+
+  void parse()
+  {
+    const auto v = parse_qversionnumber();
+    QCOMPARE(v, QVersionNumber(6, 2, 4));
+  }
+
+  void read()
+  {
+    const auto v = QVersionNumber(1, 2, 0);
+    QVERIFY(read_qversionnumber(v));
+  }
+
+  void clone()
+  {
+    const auto v = QVersionNumber(1, 2, 3, 4);
+    const auto c = clone_qversionnumber(v);
+    QCOMPARE(c, v);
+  }
+
+  void lessThan()
+  {
+    QVERIFY(less_than_qversionnumber(QVersionNumber(1), QVersionNumber(1, 0)));
+    QVERIFY(less_than_qversionnumber(QVersionNumber(5, 15), QVersionNumber(6)));
+    QVERIFY(!less_than_qversionnumber(QVersionNumber(6), QVersionNumber(6)));
+  }
+};
@@ -7,11 +7,17 @@ use cxx_qt_build::CxxQtBuilder;
 
 fn main() {
     CxxQtBuilder::new()
+        .file("src/qabstractlistmodelrow.rs")
+        .file("src/qbitarray.rs")
         .file("src/qbytearray.rs")
+        .file("src/qbrush.rs")
+        .file("src/qcollator.rs")
         .file("src/qcolor.rs")
         .file("src/qcoreapplication.rs")
         .file("src/qdate.rs")
         .file("src/qdatetime.rs")
+        .file("src/qdeadlinetimer.rs")
+        .file("src/qfontmetricsf.rs")
         .file("src/qguiapplication.rs")
         .file("src/qhash.rs")
         .file("src/qline.rs")
@@ -20,8 +26,11 @@ fn main() {
         .file("src/qmap.rs")
         .file("src/qmargins.rs")
         .file("src/qmarginsf.rs")
+        .file("src/qmatrix4x4.rs")
         .file("src/qmetaobjectconnection.rs")
         .file("src/qmodelindex.rs")
+        .file("src/qobject.rs")
+        .file("src/qpalette.rs")
         .file("src/qpen.rs")
         .file("src/qpersistentmodelindex.rs")
         .file("src/qpoint.rs")
@@ -29,22 +38,33 @@ fn main() {
         .file("src/qpolygon.rs")
         .file("src/qpolygonf.rs")
         .file("src/qqmlapplicationengine.rs")
+        .file("src/qqmlcomponent.rs")
         .file("src/qqmlengine.rs")
+        .file("src/qquaternion.rs")
+        .file("src/qquickwindow.rs")
+        .file("src/qrandomgenerator.rs")
         .file("src/qrect.rs")
         .file("src/qrectf.rs")
         .file("src/qregion.rs")
         .file("src/qset.rs")
         .file("src/qsize.rs")
         .file("src/qsizef.rs")
+        .file("src/qsocketnotifier.rs")
         .file("src/qstring.rs")
+        .file("src/qstringconverter.rs")
         .file("src/qstringlist.rs")
         .file("src/qtime.rs")
+        .file("src/qtimer.rs")
         .file("src/qtimezone.rs")
+        .file("src/qtlogging.rs")
+        .file("src/qtransform.rs")
         .file("src/qurl.rs")
+        .file("src/qurlquery.rs")
         .file("src/qvariant.rs")
         .file("src/qvector.rs")
         .file("src/qvector2d.rs")
         .file("src/qvector3d.rs")
         .file("src/qvector4d.rs")
+        .file("src/qversionnumber.rs")
         .build();
 }
@@ -4,11 +4,17 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod qabstractlistmodelrow;
+mod qbitarray;
 mod qbytearray;
+mod qbrush;
+mod qcollator;
 mod qcolor;
 mod qcoreapplication;
 mod qdate;
 mod qdatetime;
+mod qdeadlinetimer;
+mod qfontmetricsf;
 mod qguiapplication;
 mod qhash;
 mod qline;
@@ -17,8 +23,11 @@ mod qlist;
 mod qmap;
 mod qmargins;
 mod qmarginsf;
+mod qmatrix4x4;
 mod qmetaobjectconnection;
 mod qmodelindex;
+mod qobject;
+mod qpalette;
 mod qpen;
 mod qpersistentmodelindex;
 mod qpoint;
@@ -26,20 +35,32 @@ mod qpointf;
 mod qpolygon;
 mod qpolygonf;
 mod qqmlapplicationengine;
+mod qqmlcomponent;
 mod qqmlengine;
+mod qquaternion;
+mod qquickwindow;
+mod qrandomgenerator;
 mod qrect;
 mod qrectf;
 mod qregion;
 mod qset;
 mod qsize;
 mod qsizef;
+mod qsocketnotifier;
 mod qstring;
+#[cfg(cxxqt_qt_version_at_least_6)]
+mod qstringconverter;
 mod qstringlist;
 mod qtime;
+mod qtimer;
 mod qtimezone;
+mod qtlogging;
+mod qtransform;
 mod qurl;
+mod qurlquery;
 mod qvariant;
 mod qvector;
 mod qvector2d;
 mod qvector3d;
 mod qvector4d;
+mod qversionnumber;
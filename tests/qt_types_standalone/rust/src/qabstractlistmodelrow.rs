@@ -0,0 +1,49 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QAbstractListModelRow, QHash, QHashPair_i32_QByteArray, QString, QVariant};
+
+#[cxx::bridge]
+mod qabstractlistmodelrow_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qhash.h");
+        type QHash_i32_QByteArray = cxx_qt_lib::QHash<cxx_qt_lib::QHashPair_i32_QByteArray>;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+    }
+
+    extern "Rust" {
+        fn role_names_qabstractlistmodelrow() -> QHash_i32_QByteArray;
+        fn role_data_qabstractlistmodelrow(role: i32) -> QVariant;
+        fn set_role_data_qabstractlistmodelrow(role: i32, value: &QVariant) -> bool;
+    }
+}
+
+#[derive(QAbstractListModelRow)]
+struct Book {
+    title: QString,
+    page_count: i32,
+}
+
+fn book() -> Book {
+    Book {
+        title: QString::from("KDAB"),
+        page_count: 42,
+    }
+}
+
+fn role_names_qabstractlistmodelrow() -> QHash<QHashPair_i32_QByteArray> {
+    Book::role_names()
+}
+
+fn role_data_qabstractlistmodelrow(role: i32) -> QVariant {
+    book().role_data(role)
+}
+
+fn set_role_data_qabstractlistmodelrow(role: i32, value: &QVariant) -> bool {
+    let mut book = book();
+    // Read the field back to check that the value was stored
+    book.set_role_data(role, value) && book.role_data(role) == *value
+}
@@ -0,0 +1,43 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::QBitArray;
+
+#[cxx::bridge]
+mod qbitarray_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbitarray.h");
+
+        type QBitArray = cxx_qt_lib::QBitArray;
+    }
+
+    extern "Rust" {
+        fn construct_qbitarray() -> QBitArray;
+        fn read_qbitarray(b: &QBitArray) -> bool;
+        fn clone_qbitarray(b: &QBitArray) -> QBitArray;
+        fn and_qbitarray(a: &QBitArray, b: &QBitArray) -> QBitArray;
+        fn toggle_qbitarray(b: &mut QBitArray, i: isize) -> bool;
+    }
+}
+
+fn construct_qbitarray() -> QBitArray {
+    QBitArray::from([true, false, true, true].as_slice())
+}
+
+fn read_qbitarray(b: &QBitArray) -> bool {
+    Vec::from(b) == vec![false, true, true] && b.count(true) == 2 && b.get(3).is_none()
+}
+
+fn clone_qbitarray(b: &QBitArray) -> QBitArray {
+    b.clone()
+}
+
+fn and_qbitarray(a: &QBitArray, b: &QBitArray) -> QBitArray {
+    a.clone() & b.clone()
+}
+
+fn toggle_qbitarray(b: &mut QBitArray, i: isize) -> bool {
+    b.toggle_bit(i)
+}
@@ -0,0 +1,67 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{
+    BrushStyle, QBrush, QColor, QGradientSpread, QGradientType, QLinearGradient, QPointF,
+    QRadialGradient,
+};
+
+#[cxx::bridge]
+mod qbrush_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qbrush.h");
+
+        type QBrush = cxx_qt_lib::QBrush;
+    }
+
+    extern "Rust" {
+        fn construct_qbrush() -> QBrush;
+        fn clone_qbrush(b: &QBrush) -> QBrush;
+        fn construct_qbrush_linear_gradient() -> QBrush;
+        fn construct_qbrush_radial_gradient() -> QBrush;
+        fn read_qbrush_gradient(b: &QBrush) -> bool;
+    }
+}
+
+fn construct_qbrush() -> QBrush {
+    QBrush::new(&QColor::from_rgb(255, 0, 0), BrushStyle::CrossPattern)
+}
+
+fn clone_qbrush(b: &QBrush) -> QBrush {
+    b.clone()
+}
+
+fn construct_qbrush_linear_gradient() -> QBrush {
+    let mut gradient = QLinearGradient::new(&QPointF::new(0.0, 0.0), &QPointF::new(100.0, 0.0));
+    let mut base = gradient.pin_mut().as_gradient_mut();
+    base.as_mut().set_spread(QGradientSpread::ReflectSpread);
+    base.as_mut().set_stops(&[
+        (0.0, QColor::from_rgb(255, 0, 0)),
+        (1.0, QColor::from_rgb(0, 0, 255)),
+    ]);
+    QBrush::from(gradient.as_gradient())
+}
+
+fn construct_qbrush_radial_gradient() -> QBrush {
+    let mut gradient = QRadialGradient::new(&QPointF::new(50.0, 50.0), 25.0);
+    gradient
+        .pin_mut()
+        .as_gradient_mut()
+        .set_color_at(0.5, &QColor::from_rgb(0, 255, 0));
+    QBrush::from(gradient.as_gradient())
+}
+
+fn read_qbrush_gradient(b: &QBrush) -> bool {
+    match b.gradient() {
+        Some(gradient) => {
+            let stops = gradient.stops();
+            gradient.gradient_type() == QGradientType::LinearGradient
+                && stops.len() == 2
+                && stops[0] == (0.0, QColor::from_rgb(255, 0, 0))
+                && stops[1] == (1.0, QColor::from_rgb(0, 0, 255))
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,50 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{CaseSensitivity, QCollator, QString};
+use std::cmp::Ordering;
+
+#[cxx::bridge]
+mod qcollator_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qcollator.h");
+
+        type QCollator = cxx_qt_lib::QCollator;
+    }
+
+    extern "Rust" {
+        fn construct_qcollator() -> QCollator;
+        fn read_qcollator(c: &QCollator) -> bool;
+        fn clone_qcollator(c: &QCollator) -> QCollator;
+        fn compare_qcollator(c: &QCollator) -> bool;
+    }
+}
+
+fn construct_qcollator() -> QCollator {
+    let mut c = QCollator::from_locale_name(&QString::from("en_US"));
+    c.set_case_sensitivity(CaseSensitivity::CaseInsensitive);
+    c.set_ignore_punctuation(true);
+    c
+}
+
+fn read_qcollator(c: &QCollator) -> bool {
+    c.locale_name() == QString::from("de_DE")
+        && c.case_sensitivity() == CaseSensitivity::CaseSensitive
+        && !c.ignore_punctuation()
+}
+
+fn clone_qcollator(c: &QCollator) -> QCollator {
+    c.clone()
+}
+
+fn compare_qcollator(c: &QCollator) -> bool {
+    let a = QString::from("a");
+    let b = QString::from("b");
+    c.compare(&a, &b) == Ordering::Less
+        && c.compare(&b, &a) == Ordering::Greater
+        && c.compare(&a, &a) == Ordering::Equal
+        && c.sort_key(&a) < c.sort_key(&b)
+        && c.sort_key(&a) == c.sort_key(&a)
+}
@@ -24,6 +24,8 @@ mod qcolor_cxx {
         fn construct_qcolor(test: ColorTest) -> QColor;
         fn read_qcolor(c: &QColor, test: ColorTest) -> bool;
         fn clone_qcolor(c: &QColor) -> QColor;
+        fn composite_over_qcolor(c: &QColor, background: &QColor) -> QColor;
+        fn mix_qcolor(c: &QColor, other: &QColor, factor: f32) -> QColor;
     }
 }
 
@@ -60,3 +62,11 @@ fn read_qcolor(color: &QColor, test: ColorTest) -> bool {
 fn clone_qcolor(c: &QColor) -> QColor {
     c.clone()
 }
+
+fn composite_over_qcolor(c: &QColor, background: &QColor) -> QColor {
+    c.composite_over(background)
+}
+
+fn mix_qcolor(c: &QColor, other: &QColor, factor: f32) -> QColor {
+    c.mix(other, factor)
+}
@@ -0,0 +1,75 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::TimerType;
+use cxx_qt_lib_extras::QDeadlineTimer;
+use std::time::Duration;
+
+#[cxx::bridge]
+mod qdeadlinetimer_cxx {
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type TimerType = cxx_qt_lib::TimerType;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qdeadlinetimer.h");
+
+        type QDeadlineTimer = cxx_qt_lib_extras::QDeadlineTimer;
+    }
+
+    extern "Rust" {
+        fn construct_qdeadlinetimer() -> QDeadlineTimer;
+        fn construct_qdeadlinetimer_forever() -> QDeadlineTimer;
+        fn construct_qdeadlinetimer_duration() -> QDeadlineTimer;
+        fn read_qdeadlinetimer(t: &QDeadlineTimer) -> bool;
+        fn read_qdeadlinetimer_forever(t: &QDeadlineTimer) -> bool;
+        fn clone_qdeadlinetimer(t: &QDeadlineTimer) -> QDeadlineTimer;
+        fn add_qdeadlinetimer(t: &QDeadlineTimer, msecs: i64) -> QDeadlineTimer;
+        fn less_qdeadlinetimer(a: &QDeadlineTimer, b: &QDeadlineTimer) -> bool;
+    }
+}
+
+fn construct_qdeadlinetimer() -> QDeadlineTimer {
+    let mut t = QDeadlineTimer::default();
+    t.set_deadline(123456, TimerType::PreciseTimer);
+    t
+}
+
+fn construct_qdeadlinetimer_forever() -> QDeadlineTimer {
+    QDeadlineTimer::forever(TimerType::CoarseTimer)
+}
+
+fn construct_qdeadlinetimer_duration() -> QDeadlineTimer {
+    QDeadlineTimer::from(Duration::from_secs(60))
+}
+
+fn read_qdeadlinetimer(t: &QDeadlineTimer) -> bool {
+    t.deadline() == 123456
+        && t.deadline_nsecs() == 123456000000
+        && t.timer_type() == TimerType::PreciseTimer
+        && !t.is_forever()
+}
+
+fn read_qdeadlinetimer_forever(t: &QDeadlineTimer) -> bool {
+    t.is_forever()
+        && !t.has_expired()
+        && t.deadline() == i64::MAX
+        && t.remaining_time() == -1
+        && t.remaining_duration().is_none()
+}
+
+fn clone_qdeadlinetimer(t: &QDeadlineTimer) -> QDeadlineTimer {
+    t.clone()
+}
+
+fn add_qdeadlinetimer(t: &QDeadlineTimer, msecs: i64) -> QDeadlineTimer {
+    t.clone() + msecs
+}
+
+fn less_qdeadlinetimer(a: &QDeadlineTimer, b: &QDeadlineTimer) -> bool {
+    a < b
+}
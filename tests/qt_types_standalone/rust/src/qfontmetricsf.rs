@@ -0,0 +1,45 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QFont, QFontMetricsF, QString, TextElideMode};
+
+#[cxx::bridge]
+mod qfontmetricsf_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qfont.h");
+        type QFont = cxx_qt_lib::QFont;
+        include!("cxx-qt-lib/qfontmetricsf.h");
+        type QFontMetricsF = cxx_qt_lib::QFontMetricsF;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "Rust" {
+        fn construct_qfontmetricsf(f: &QFont) -> QFontMetricsF;
+        fn clone_qfontmetricsf(m: &QFontMetricsF) -> QFontMetricsF;
+        fn elided_text_qfontmetricsf(m: &QFontMetricsF, width: f64) -> QString;
+        fn horizontal_advance_qfontmetricsf(m: &QFontMetricsF) -> f64;
+    }
+}
+
+fn construct_qfontmetricsf(f: &QFont) -> QFontMetricsF {
+    QFontMetricsF::new(f)
+}
+
+fn clone_qfontmetricsf(m: &QFontMetricsF) -> QFontMetricsF {
+    m.clone()
+}
+
+fn elided_text_qfontmetricsf(m: &QFontMetricsF, width: f64) -> QString {
+    m.elided_text(
+        &QString::from("KDAB cxx-qt"),
+        TextElideMode::ElideRight,
+        width,
+    )
+}
+
+fn horizontal_advance_qfontmetricsf(m: &QFontMetricsF) -> f64 {
+    m.horizontal_advance(&QString::from("KDAB"))
+}
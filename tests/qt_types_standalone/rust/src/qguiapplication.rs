@@ -3,7 +3,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use cxx_qt_lib::{QGuiApplication, QString};
+use core::pin::Pin;
+use cxx_qt_lib::{ApplicationState, QGuiApplication, QMetaObjectConnection, QString};
+use std::sync::atomic::{AtomicI32, Ordering};
 
 #[cxx::bridge]
 mod qguiapplication_cxx {
@@ -12,12 +14,31 @@ mod qguiapplication_cxx {
         type QGuiApplication = cxx_qt_lib::QGuiApplication;
     }
 
+    #[namespace = "Qt"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qt.h");
+        type ApplicationState = cxx_qt_lib::ApplicationState;
+    }
+
+    #[namespace = "rust::cxxqt1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        type QMetaObjectConnection = cxx_qt_lib::QMetaObjectConnection;
+    }
+
     extern "Rust" {
         fn construct_qguiapplication() -> UniquePtr<QGuiApplication>;
+        fn qguiapplication_application_state() -> ApplicationState;
+        fn qguiapplication_connect_application_state_changed(
+            app: Pin<&mut QGuiApplication>,
+        ) -> QMetaObjectConnection;
         fn read_qguiapplication(c: &QGuiApplication) -> bool;
     }
 }
 
+/// The last state seen by a closure connected to an application state changed signal
+static APPLICATION_STATE: AtomicI32 = AtomicI32::new(ApplicationState::ApplicationSuspended.repr);
+
 fn construct_qguiapplication() -> cxx::UniquePtr<QGuiApplication> {
     let mut app = QGuiApplication::new();
     if let Some(app) = app.as_mut() {
@@ -26,6 +47,21 @@ fn construct_qguiapplication() -> cxx::UniquePtr<QGuiApplication> {
     app
 }
 
+fn qguiapplication_application_state() -> ApplicationState {
+    ApplicationState {
+        repr: APPLICATION_STATE.load(Ordering::SeqCst),
+    }
+}
+
+fn qguiapplication_connect_application_state_changed(
+    app: Pin<&mut QGuiApplication>,
+) -> QMetaObjectConnection {
+    app.on_application_state_changed(|_, state| {
+        APPLICATION_STATE.store(state.repr, Ordering::SeqCst);
+    })
+    .release()
+}
+
 fn read_qguiapplication(app: &QGuiApplication) -> bool {
     app.application_name().to_string() == "kdab"
 }
@@ -18,6 +18,7 @@ mod qmargins_cxx {
         fn clone_qmargins(m: &QMargins) -> QMargins;
         fn test_is_null(m: &QMargins) -> bool;
         fn add_margins(m1: QMargins, m2: QMargins) -> QMargins;
+        fn negate_margins(m: QMargins) -> QMargins;
     }
 }
 
@@ -40,3 +41,7 @@ fn test_is_null(m: &QMargins) -> bool {
 fn add_margins(m1: QMargins, m2: QMargins) -> QMargins {
     m1 + m2
 }
+
+fn negate_margins(m: QMargins) -> QMargins {
+    -m
+}
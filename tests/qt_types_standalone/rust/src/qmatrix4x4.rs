@@ -0,0 +1,45 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QMatrix4x4, QVector3D};
+
+#[cxx::bridge]
+mod qmatrix4x4_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmatrix4x4.h");
+        type QMatrix4x4 = cxx_qt_lib::QMatrix4x4;
+    }
+
+    extern "Rust" {
+        fn construct_qmatrix4x4() -> QMatrix4x4;
+        fn read_qmatrix4x4(m: &QMatrix4x4) -> bool;
+        fn clone_qmatrix4x4(m: &QMatrix4x4) -> QMatrix4x4;
+        fn multiply_qmatrix4x4(a: &QMatrix4x4, b: &QMatrix4x4) -> QMatrix4x4;
+    }
+}
+
+fn construct_qmatrix4x4() -> QMatrix4x4 {
+    QMatrix4x4::new(&[
+        1.0, 0.0, 0.0, 1.0, //
+        0.0, 1.0, 0.0, 2.0, //
+        0.0, 0.0, 1.0, 3.0, //
+        0.0, 0.0, 0.0, 1.0, //
+    ])
+}
+
+fn read_qmatrix4x4(m: &QMatrix4x4) -> bool {
+    let inverted = m.inverted().unwrap();
+    m.map_vector3d(&QVector3D::default()) == QVector3D::new(1.0, 2.0, 3.0)
+        && inverted.map_vector3d(&QVector3D::new(1.0, 2.0, 3.0)) == QVector3D::default()
+        && m.data()[12..15] == [1.0, 2.0, 3.0]
+}
+
+fn clone_qmatrix4x4(m: &QMatrix4x4) -> QMatrix4x4 {
+    m.clone()
+}
+
+fn multiply_qmatrix4x4(a: &QMatrix4x4, b: &QMatrix4x4) -> QMatrix4x4 {
+    a.clone() * b.clone()
+}
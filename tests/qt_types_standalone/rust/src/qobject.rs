@@ -0,0 +1,169 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{
+    ConnectionType, QByteArray, QList, QMetaMethodMethodType, QMetaObjectConnection, QObject,
+    QObjectExt, QString, QVariant,
+};
+use std::sync::atomic::{AtomicI32, Ordering};
+
+#[cxx::bridge]
+mod qobject_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qobject.h");
+        type QObject = cxx_qt_lib::QObject;
+    }
+
+    #[namespace = "rust::cxxqt1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        type QMetaObjectConnection = cxx_qt_lib::QMetaObjectConnection;
+    }
+
+    extern "Rust" {
+        fn qobject_children_len(object: &QObject) -> usize;
+        fn qobject_connect_destroyed(object: Pin<&mut QObject>) -> QMetaObjectConnection;
+        fn qobject_connect_destroyed_dropped(object: Pin<&mut QObject>);
+        unsafe fn qobject_delete_later(object: Pin<&mut QObject>);
+        fn qobject_destroyed_kdab() -> i32;
+        fn qobject_find_child(object: &QObject) -> *mut QObject;
+        fn qobject_find_children_len(object: &QObject) -> usize;
+        fn qobject_invoke_row_count(object: Pin<&mut QObject>) -> i32;
+        fn qobject_invoke_row_count_auto(object: Pin<&mut QObject>) -> i32;
+        fn qobject_invoke_row_count_blocking(object: Pin<&mut QObject>) -> i32;
+        fn qobject_invoke_start(object: Pin<&mut QObject>) -> bool;
+        fn qobject_is_parent(object: &QObject, parent: &QObject) -> bool;
+        fn qobject_meta_object_is_timer(object: &QObject) -> bool;
+        fn qobject_read_object_name(object: &QObject) -> bool;
+        fn qobject_read_property(object: &QObject) -> bool;
+        unsafe fn qobject_set_parent(object: Pin<&mut QObject>, parent: *mut QObject);
+        fn qobject_write_object_name(object: Pin<&mut QObject>);
+        fn qobject_write_property(object: Pin<&mut QObject>) -> bool;
+    }
+}
+
+/// The number of destroyed objects named "kdab" seen by a closure connected to a destroyed signal
+static DESTROYED_KDAB: AtomicI32 = AtomicI32::new(0);
+
+fn on_destroyed(object: &QObject) {
+    if object.object_name().to_string() == "kdab" {
+        DESTROYED_KDAB.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn qobject_children_len(object: &QObject) -> usize {
+    object.children().len()
+}
+
+fn qobject_connect_destroyed(object: Pin<&mut QObject>) -> QMetaObjectConnection {
+    object.on_destroyed(on_destroyed).release()
+}
+
+fn qobject_connect_destroyed_dropped(object: Pin<&mut QObject>) {
+    // The connection is disconnected as soon as the guard is dropped
+    drop(object.on_destroyed(on_destroyed));
+}
+
+fn qobject_find_child(object: &QObject) -> *mut QObject {
+    object
+        .find_child::<QObject>(&QString::from("kdab"))
+        .unwrap_or(std::ptr::null_mut())
+}
+
+fn qobject_find_children_len(object: &QObject) -> usize {
+    object.find_children::<QObject>(&QString::default()).len()
+}
+
+unsafe fn qobject_delete_later(object: Pin<&mut QObject>) {
+    object.delete_later();
+}
+
+fn qobject_destroyed_kdab() -> i32 {
+    DESTROYED_KDAB.load(Ordering::SeqCst)
+}
+
+/// Returns -1 if the method could not be invoked and 0 if it did not return a value
+fn invoke_row_count(object: Pin<&mut QObject>, connection_type: ConnectionType) -> i32 {
+    object
+        .invoke_method(
+            &QByteArray::from("rowCount"),
+            connection_type,
+            &QList::default(),
+        )
+        .map(|value| value.value::<i32>().unwrap_or(0))
+        .unwrap_or(-1)
+}
+
+fn qobject_invoke_row_count(object: Pin<&mut QObject>) -> i32 {
+    invoke_row_count(object, ConnectionType::DirectConnection)
+}
+
+fn qobject_invoke_row_count_auto(object: Pin<&mut QObject>) -> i32 {
+    invoke_row_count(object, ConnectionType::AutoConnection)
+}
+
+fn qobject_invoke_row_count_blocking(object: Pin<&mut QObject>) -> i32 {
+    invoke_row_count(object, ConnectionType::BlockingQueuedConnection)
+}
+
+fn qobject_invoke_start(object: Pin<&mut QObject>) -> bool {
+    let mut args = QList::default();
+    args.append(QVariant::from(&100_i32));
+    object
+        .invoke_method(
+            &QByteArray::from("start"),
+            ConnectionType::DirectConnection,
+            &args,
+        )
+        .is_some()
+}
+
+fn qobject_is_parent(object: &QObject, parent: &QObject) -> bool {
+    std::ptr::eq(object.parent(), parent)
+}
+
+fn qobject_meta_object_is_timer(object: &QObject) -> bool {
+    let meta_object = object.meta_object();
+    let has_interval = (meta_object.property_offset()..meta_object.property_count()).any(|index| {
+        meta_object.property_name(index) == Some(QString::from("interval"))
+            && meta_object.property_type_name(index) == Some(QString::from("int"))
+    });
+    let has_start = (meta_object.method_offset()..meta_object.method_count()).any(|index| {
+        meta_object.method_signature(index) == Some(QByteArray::from("start(int)"))
+            && meta_object.method_type(index) == Some(QMetaMethodMethodType::Slot)
+    });
+
+    meta_object.class_name() == QString::from("QTimer")
+        && meta_object
+            .super_class()
+            .map(|super_class| super_class.class_name())
+            == Some(QString::from("QObject"))
+        && has_interval
+        && has_start
+}
+
+fn qobject_read_object_name(object: &QObject) -> bool {
+    object.object_name().to_string() == "kdab"
+}
+
+fn qobject_read_property(object: &QObject) -> bool {
+    let names = object.dynamic_property_names();
+    names.len() == 1
+        && names.get(0) == Some(&QByteArray::from("kdab"))
+        && object.property(&QByteArray::from("kdab")).value::<i32>() == Some(42)
+}
+
+unsafe fn qobject_set_parent(object: Pin<&mut QObject>, parent: *mut QObject) {
+    object.set_parent(parent);
+}
+
+fn qobject_write_object_name(object: Pin<&mut QObject>) {
+    object.set_object_name(&QString::from("kdab"));
+}
+
+fn qobject_write_property(object: Pin<&mut QObject>) -> bool {
+    object.set_property(&QByteArray::from("kdab"), &QVariant::from(&42_i32))
+}
@@ -0,0 +1,34 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QColor, QPalette, QPaletteColorGroup, QPaletteColorRole};
+
+#[cxx::bridge]
+mod qpalette_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qpalette.h");
+
+        type QPalette = cxx_qt_lib::QPalette;
+    }
+
+    extern "Rust" {
+        fn construct_qpalette() -> QPalette;
+        fn clone_qpalette(p: &QPalette) -> QPalette;
+    }
+}
+
+fn construct_qpalette() -> QPalette {
+    let mut palette = QPalette::from(&QColor::from_rgb(0, 0, 255));
+    palette.set_color(
+        QPaletteColorGroup::All,
+        QPaletteColorRole::Highlight,
+        &QColor::from_rgb(255, 0, 0),
+    );
+    palette
+}
+
+fn clone_qpalette(p: &QPalette) -> QPalette {
+    p.clone()
+}
@@ -3,7 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use cxx_qt_lib::QPolygonF;
+use cxx_qt_lib::{QPointF, QPolygonF};
 
 #[cxx::bridge]
 mod qpolygonf_cxx {
@@ -16,6 +16,8 @@ mod qpolygonf_cxx {
     extern "Rust" {
         fn clone_qpolygonf(p: &QPolygonF) -> QPolygonF;
         fn construct_qpolygonf() -> QPolygonF;
+        fn construct_qpolygonf_from_points() -> QPolygonF;
+        fn read_qpolygonf(p: &QPolygonF) -> bool;
     }
 }
 
@@ -26,3 +28,18 @@ fn construct_qpolygonf() -> QPolygonF {
 fn clone_qpolygonf(p: &QPolygonF) -> QPolygonF {
     p.clone()
 }
+
+fn construct_qpolygonf_from_points() -> QPolygonF {
+    QPolygonF::from(
+        [
+            QPointF::new(0.0, 0.0),
+            QPointF::new(10.0, 0.0),
+            QPointF::new(10.0, 10.0),
+        ]
+        .as_slice(),
+    )
+}
+
+fn read_qpolygonf(p: &QPolygonF) -> bool {
+    p.as_slice() == [QPointF::new(1.0, 2.0), QPointF::new(3.0, 4.0)]
+}
@@ -3,7 +3,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use cxx_qt_lib::{QQmlApplicationEngine, QUrl};
+use core::pin::Pin;
+use cxx_qt_lib::{QByteArray, QObjectExt, QQmlApplicationEngine, QString, QUrl, QVariant};
+use std::{cell::Cell, rc::Rc};
 
 #[cxx::bridge]
 mod qqmlapplicationengine_cxx {
@@ -15,6 +17,8 @@ mod qqmlapplicationengine_cxx {
     extern "Rust" {
         fn construct_qqmlapplicationengine() -> UniquePtr<QQmlApplicationEngine>;
         fn read_qqmlapplicationengine(c: &QQmlApplicationEngine) -> bool;
+        fn load_data_qqmlapplicationengine(c: Pin<&mut QQmlApplicationEngine>) -> bool;
+        fn set_context_property_qqmlapplicationengine(c: Pin<&mut QQmlApplicationEngine>);
     }
 }
 
@@ -29,3 +33,28 @@ fn construct_qqmlapplicationengine() -> cxx::UniquePtr<QQmlApplicationEngine> {
 fn read_qqmlapplicationengine(engine: &QQmlApplicationEngine) -> bool {
     engine.base_url().to_string() == "qrc:/kdab.qml"
 }
+
+fn load_data_qqmlapplicationengine(mut engine: Pin<&mut QQmlApplicationEngine>) -> bool {
+    let created = Rc::new(Cell::new(false));
+    let _guard = engine.as_mut().on_object_created({
+        let created = created.clone();
+        move |_, object, _| created.set(!object.is_null())
+    });
+
+    engine.as_mut().load_data(
+        &QByteArray::from("import QtQml 2.0\nQtObject { objectName: \"kdab\" }"),
+        &QUrl::default(),
+    );
+
+    let root_objects = engine.root_objects();
+    created.get()
+        && root_objects.len() == 1
+        // SAFETY: the root objects are owned by the engine which is still alive
+        && unsafe { &*root_objects[0] }.object_name() == QString::from("kdab")
+}
+
+fn set_context_property_qqmlapplicationengine(engine: Pin<&mut QQmlApplicationEngine>) {
+    engine
+        .root_context()
+        .set_context_property(&QString::from("kdab"), &QVariant::from(&42_i32));
+}
@@ -0,0 +1,60 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{
+    QByteArray, QObjectExt, QQmlComponent, QQmlComponentStatus, QQmlEngine, QString, QUrl,
+};
+
+#[cxx::bridge]
+mod qqmlcomponent_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlengine.h");
+        type QQmlEngine = cxx_qt_lib::QQmlEngine;
+    }
+
+    extern "Rust" {
+        fn create_qqmlcomponent(engine: Pin<&mut QQmlEngine>) -> bool;
+        fn errors_qqmlcomponent(engine: Pin<&mut QQmlEngine>) -> bool;
+    }
+}
+
+fn create_qqmlcomponent(engine: Pin<&mut QQmlEngine>) -> bool {
+    let mut component = QQmlComponent::new(engine);
+    let Some(mut component) = component.as_mut() else {
+        return false;
+    };
+    component.as_mut().set_data(
+        &QByteArray::from("import QtQml 2.0\nQtObject { objectName: \"kdab\" }"),
+        &QUrl::default(),
+    );
+    if component.status() != QQmlComponentStatus::Ready {
+        return false;
+    }
+
+    let object = component.create();
+    object
+        .as_ref()
+        .map(|object| object.object_name() == QString::from("kdab"))
+        .unwrap_or(false)
+}
+
+fn errors_qqmlcomponent(engine: Pin<&mut QQmlEngine>) -> bool {
+    let mut component = QQmlComponent::new(engine);
+    let Some(mut component) = component.as_mut() else {
+        return false;
+    };
+    component.as_mut().set_data(
+        &QByteArray::from("import QtQml 2.0\nQtObject { unknownProperty: 1 }"),
+        &QUrl::from("qrc:/kdab.qml"),
+    );
+
+    let errors = component.errors();
+    component.is_error()
+        && errors.len() == 1
+        && errors[0].url == QUrl::from("qrc:/kdab.qml")
+        && errors[0].line == 2
+        && component.create().is_null()
+}
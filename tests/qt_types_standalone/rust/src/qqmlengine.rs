@@ -3,7 +3,8 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use cxx_qt_lib::{QQmlEngine, QUrl};
+use core::pin::Pin;
+use cxx_qt_lib::{QQmlAbstractUrlInterceptorDataType, QQmlEngine, QUrl};
 
 #[cxx::bridge]
 mod qqmlengine_cxx {
@@ -15,6 +16,7 @@ mod qqmlengine_cxx {
     extern "Rust" {
         fn construct_qqmlengine() -> UniquePtr<QQmlEngine>;
         fn read_qqmlengine(c: &QQmlEngine) -> bool;
+        fn set_qqmlengine_url_interceptor(engine: Pin<&mut QQmlEngine>);
     }
 }
 
@@ -29,3 +31,15 @@ fn construct_qqmlengine() -> cxx::UniquePtr<QQmlEngine> {
 fn read_qqmlengine(engine: &QQmlEngine) -> bool {
     engine.base_url().to_string() == "qrc:/kdab.qml"
 }
+
+fn set_qqmlengine_url_interceptor(engine: Pin<&mut QQmlEngine>) {
+    engine.set_url_interceptor(|url, data_type| {
+        if data_type == QQmlAbstractUrlInterceptorDataType::QmlFile
+            && url.to_string() == "qrc:/kdab.qml"
+        {
+            QUrl::from("qrc:/redirected.qml")
+        } else {
+            url.clone()
+        }
+    });
+}
@@ -0,0 +1,40 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QQuaternion, QVector3D};
+
+#[cxx::bridge]
+mod qquaternion_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qquaternion.h");
+        type QQuaternion = cxx_qt_lib::QQuaternion;
+    }
+
+    extern "Rust" {
+        fn construct_qquaternion() -> QQuaternion;
+        fn read_qquaternion(q: &QQuaternion) -> bool;
+        fn clone_qquaternion(q: &QQuaternion) -> QQuaternion;
+        fn multiply_qquaternion(a: &QQuaternion, b: &QQuaternion) -> QQuaternion;
+    }
+}
+
+fn construct_qquaternion() -> QQuaternion {
+    QQuaternion::from_axis_and_angle(&QVector3D::new(0.0, 0.0, 1.0), 90.0)
+}
+
+fn read_qquaternion(q: &QQuaternion) -> bool {
+    let rotated = q.rotated_vector(&QVector3D::new(1.0, 0.0, 0.0));
+    (rotated.x().abs() < 0.0001)
+        && ((rotated.y() - 1.0).abs() < 0.0001)
+        && rotated.z().abs() < 0.0001
+}
+
+fn clone_qquaternion(q: &QQuaternion) -> QQuaternion {
+    q.clone()
+}
+
+fn multiply_qquaternion(a: &QQuaternion, b: &QQuaternion) -> QQuaternion {
+    a.clone() * b.clone()
+}
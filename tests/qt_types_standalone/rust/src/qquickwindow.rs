@@ -0,0 +1,69 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::QMetaObjectConnection;
+use cxx_qt_lib_extras::{QQuickWindow, QSGRendererInterfaceGraphicsApi};
+use std::sync::atomic::{AtomicI32, Ordering};
+
+#[cxx::bridge]
+mod qquickwindow_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib-extras/qquickwindow.h");
+        type QQuickWindow = cxx_qt_lib_extras::QQuickWindow;
+    }
+
+    #[namespace = "rust::cxxqt1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        type QMetaObjectConnection = cxx_qt_lib::QMetaObjectConnection;
+    }
+
+    extern "Rust" {
+        fn qquickwindow_closes() -> i32;
+        fn qquickwindow_connect_before_rendering(
+            window: Pin<&mut QQuickWindow>,
+        ) -> QMetaObjectConnection;
+        fn qquickwindow_connect_closing(window: Pin<&mut QQuickWindow>) -> QMetaObjectConnection;
+        fn qquickwindow_renders() -> i32;
+        fn read_qquickwindow(window: &QQuickWindow) -> bool;
+    }
+}
+
+/// The number of close events seen by a closure connected to a closing signal
+static CLOSES: AtomicI32 = AtomicI32::new(0);
+/// The number of times a closure connected to a before rendering signal has been called
+static RENDERS: AtomicI32 = AtomicI32::new(0);
+
+fn qquickwindow_closes() -> i32 {
+    CLOSES.load(Ordering::SeqCst)
+}
+
+fn qquickwindow_connect_before_rendering(window: Pin<&mut QQuickWindow>) -> QMetaObjectConnection {
+    window
+        .on_before_rendering_in_render_thread(|_| {
+            RENDERS.fetch_add(1, Ordering::SeqCst);
+        })
+        .release()
+}
+
+fn qquickwindow_connect_closing(window: Pin<&mut QQuickWindow>) -> QMetaObjectConnection {
+    window
+        .on_closing(|_, close| {
+            if !close.is_null() {
+                CLOSES.fetch_add(1, Ordering::SeqCst);
+            }
+        })
+        .release()
+}
+
+fn qquickwindow_renders() -> i32 {
+    RENDERS.load(Ordering::SeqCst)
+}
+
+fn read_qquickwindow(window: &QQuickWindow) -> bool {
+    // The scene graph is not initialized until the window is exposed
+    window.graphics_api() == QSGRendererInterfaceGraphicsApi::Unknown
+}
@@ -3,7 +3,7 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use cxx_qt_lib::QRegion;
+use cxx_qt_lib::{QRect, QRegion};
 
 #[cxx::bridge]
 mod qregion_cxx {
@@ -15,7 +15,9 @@ mod qregion_cxx {
 
     extern "Rust" {
         fn construct_qregion() -> QRegion;
+        fn construct_qregion_from_rects() -> QRegion;
         fn clone_qregion(p: &QRegion) -> QRegion;
+        fn read_qregion(p: &QRegion) -> bool;
     }
 }
 
@@ -26,3 +28,11 @@ fn construct_qregion() -> QRegion {
 fn clone_qregion(p: &QRegion) -> QRegion {
     p.clone()
 }
+
+fn construct_qregion_from_rects() -> QRegion {
+    QRegion::from([QRect::new(0, 0, 10, 10), QRect::new(20, 0, 10, 10)].as_slice())
+}
+
+fn read_qregion(p: &QRegion) -> bool {
+    p.as_slice() == [QRect::new(2, 4, 5, 3)]
+}
@@ -0,0 +1,39 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QSocketNotifier, QSocketNotifierType};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[cxx::bridge]
+mod qsocketnotifier_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qsocketnotifier.h");
+        type QSocketNotifier = cxx_qt_lib::QSocketNotifier;
+    }
+
+    extern "Rust" {
+        fn construct_qsocketnotifier(socket: isize) -> UniquePtr<QSocketNotifier>;
+        fn qsocketnotifier_activations() -> u32;
+    }
+}
+
+static ACTIVATIONS: AtomicU32 = AtomicU32::new(0);
+
+fn construct_qsocketnotifier(socket: isize) -> cxx::UniquePtr<QSocketNotifier> {
+    let mut notifier = QSocketNotifier::new(socket, QSocketNotifierType::Read);
+    if let Some(notifier) = notifier.as_mut() {
+        notifier.on_activated(|notifier: Pin<&mut QSocketNotifier>| {
+            ACTIVATIONS.fetch_add(1, Ordering::SeqCst);
+            // The data is not read, so disable the notifier to only be activated once
+            notifier.set_enabled(false);
+        });
+    }
+    notifier
+}
+
+fn qsocketnotifier_activations() -> u32 {
+    ACTIVATIONS.load(Ordering::SeqCst)
+}
@@ -0,0 +1,95 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QByteArray, QString, QStringConverterEncoding, QStringDecoder, QStringEncoder};
+
+// QStringDecoder and QStringEncoder are only available in Qt 6,
+// so every item of the bridge is behind a cfg for the C++ generation.
+#[cxx::bridge]
+mod qstringconverter_cxx {
+    #[cfg(cxxqt_qt_version_at_least_6)]
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstringconverter.h");
+        type QStringConverterEncoding = cxx_qt_lib::QStringConverterEncoding;
+    }
+
+    #[cfg(cxxqt_qt_version_at_least_6)]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstringconverter.h");
+        type QStringDecoder = cxx_qt_lib::QStringDecoder;
+        type QStringEncoder = cxx_qt_lib::QStringEncoder;
+
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    #[cfg(cxxqt_qt_version_at_least_6)]
+    extern "Rust" {
+        fn construct_qstringdecoder(
+            encoding: QStringConverterEncoding,
+        ) -> UniquePtr<QStringDecoder>;
+        fn construct_qstringdecoder_from_name(name: &str) -> UniquePtr<QStringDecoder>;
+        fn construct_qstringencoder(
+            encoding: QStringConverterEncoding,
+        ) -> UniquePtr<QStringEncoder>;
+        fn construct_qstringencoder_from_name(name: &str) -> UniquePtr<QStringEncoder>;
+        fn decode_qstringdecoder(decoder: Pin<&mut QStringDecoder>, data: &[u8]) -> QString;
+        fn encode_qstringencoder(encoder: Pin<&mut QStringEncoder>, string: &QString)
+            -> QByteArray;
+        fn round_trip_qstringconverter(string: &QString, name: &str) -> QString;
+        fn read_qstringdecoder_invalid_utf8() -> bool;
+        fn read_qstringdecoder_chunks() -> bool;
+    }
+}
+
+fn construct_qstringdecoder(encoding: QStringConverterEncoding) -> cxx::UniquePtr<QStringDecoder> {
+    QStringDecoder::new(encoding)
+}
+
+fn construct_qstringdecoder_from_name(name: &str) -> cxx::UniquePtr<QStringDecoder> {
+    QStringDecoder::from_name(name)
+}
+
+fn construct_qstringencoder(encoding: QStringConverterEncoding) -> cxx::UniquePtr<QStringEncoder> {
+    QStringEncoder::new(encoding)
+}
+
+fn construct_qstringencoder_from_name(name: &str) -> cxx::UniquePtr<QStringEncoder> {
+    QStringEncoder::from_name(name)
+}
+
+fn decode_qstringdecoder(decoder: Pin<&mut QStringDecoder>, data: &[u8]) -> QString {
+    decoder.decode(data)
+}
+
+fn encode_qstringencoder(encoder: Pin<&mut QStringEncoder>, string: &QString) -> QByteArray {
+    encoder.encode(string)
+}
+
+fn round_trip_qstringconverter(string: &QString, name: &str) -> QString {
+    let bytes = QStringEncoder::from_name(name).pin_mut().encode(string);
+    QStringDecoder::from_name(name)
+        .pin_mut()
+        .decode(bytes.as_slice())
+}
+
+fn read_qstringdecoder_invalid_utf8() -> bool {
+    let mut decoder = QStringDecoder::new(QStringConverterEncoding::Utf8);
+    let string = decoder.pin_mut().decode(&[b'a', 0xff, b'b']);
+    // Invalid sequences are replaced by the replacement character
+    decoder.has_error() && string == QString::from("a\u{fffd}b")
+}
+
+fn read_qstringdecoder_chunks() -> bool {
+    // "é" is split across two chunks in UTF-8
+    let mut decoder = QStringDecoder::new(QStringConverterEncoding::Utf8);
+    let first = decoder.pin_mut().decode(&[b'a', 0xc3]);
+    let second = decoder.pin_mut().decode(&[0xa9, b'b']);
+    !decoder.has_error() && first == QString::from("a") && second == QString::from("\u{e9}b")
+}
@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt_lib::{QMetaObjectConnection, QTimer, TimerType};
+use std::sync::atomic::{AtomicI32, Ordering};
+
+#[cxx::bridge]
+mod qtimer_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtimer.h");
+        type QTimer = cxx_qt_lib::QTimer;
+    }
+
+    #[namespace = "rust::cxxqt1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qmetaobjectconnection.h");
+        type QMetaObjectConnection = cxx_qt_lib::QMetaObjectConnection;
+    }
+
+    extern "Rust" {
+        fn construct_qtimer() -> UniquePtr<QTimer>;
+        fn read_qtimer(timer: &QTimer) -> bool;
+        fn qtimer_connect_timeout(timer: Pin<&mut QTimer>) -> QMetaObjectConnection;
+        fn qtimer_connect_timeout_dropped(timer: Pin<&mut QTimer>);
+        fn qtimer_start(timer: Pin<&mut QTimer>);
+        fn qtimer_stop(timer: Pin<&mut QTimer>);
+        fn qtimer_timeouts() -> i32;
+    }
+}
+
+/// The number of times a closure connected to a timeout signal has been called
+static TIMEOUTS: AtomicI32 = AtomicI32::new(0);
+
+fn construct_qtimer() -> cxx::UniquePtr<QTimer> {
+    let mut timer = QTimer::new();
+    if let Some(mut timer) = timer.as_mut() {
+        timer.as_mut().set_interval(100);
+        timer.as_mut().set_single_shot(true);
+        timer.set_timer_type(TimerType::VeryCoarseTimer);
+    }
+    timer
+}
+
+fn read_qtimer(timer: &QTimer) -> bool {
+    timer.interval() == 100
+        && timer.is_single_shot()
+        && timer.timer_type() == TimerType::VeryCoarseTimer
+        && !timer.is_active()
+        && timer.remaining_time() == -1
+}
+
+fn qtimer_connect_timeout(timer: Pin<&mut QTimer>) -> QMetaObjectConnection {
+    timer
+        .on_timeout(|_| {
+            TIMEOUTS.fetch_add(1, Ordering::SeqCst);
+        })
+        .release()
+}
+
+fn qtimer_connect_timeout_dropped(timer: Pin<&mut QTimer>) {
+    // The connection is disconnected as soon as the guard is dropped
+    drop(timer.on_timeout(|_| {
+        TIMEOUTS.fetch_add(1, Ordering::SeqCst);
+    }));
+}
+
+fn qtimer_start(timer: Pin<&mut QTimer>) {
+    timer.start_with_interval(1000);
+}
+
+fn qtimer_stop(timer: Pin<&mut QTimer>) {
+    timer.stop();
+}
+
+fn qtimer_timeouts() -> i32 {
+    TIMEOUTS.load(Ordering::SeqCst)
+}
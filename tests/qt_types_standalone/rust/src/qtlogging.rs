@@ -0,0 +1,42 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::QtMsgType;
+use std::sync::Mutex;
+
+#[cxx::bridge]
+mod qtlogging_cxx {
+    extern "Rust" {
+        fn install_capturing_message_handler();
+        fn log_qt_debug(category: &str);
+        fn log_qt_warning(category: &str, value: i32);
+        fn take_captured_messages() -> Vec<String>;
+    }
+}
+
+static CAPTURED_MESSAGES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn install_capturing_message_handler() {
+    cxx_qt_lib::install_message_handler(|msg_type, context, message| {
+        if msg_type == QtMsgType::QtWarningMsg {
+            CAPTURED_MESSAGES
+                .lock()
+                .unwrap()
+                .push(format!("{}: {message}", context.category()));
+        }
+    });
+}
+
+fn log_qt_debug(category: &str) {
+    cxx_qt_lib::qt_debug!(category, "debug message");
+}
+
+fn log_qt_warning(category: &str, value: i32) {
+    cxx_qt_lib::qt_warning!(category, "The value is {value}");
+}
+
+fn take_captured_messages() -> Vec<String> {
+    std::mem::take(&mut CAPTURED_MESSAGES.lock().unwrap())
+}
@@ -0,0 +1,49 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QPointF, QTransform};
+
+#[cxx::bridge]
+mod qtransform_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qtransform.h");
+        type QTransform = cxx_qt_lib::QTransform;
+    }
+
+    extern "Rust" {
+        fn construct_qtransform() -> QTransform;
+        fn read_qtransform(t: &QTransform) -> bool {
+            let mapped = t.map(&QPointF::new(1.0, 1.0));
+            let unmapped = t.inverted().unwrap().map(&mapped);
+            ((mapped.x() - 12.0).abs() < f64::EPSILON)
+                && ((mapped.y() - 23.0).abs() < f64::EPSILON)
+                && ((unmapped.x() - 1.0).abs() < 0.0001)
+                && ((unmapped.y() - 1.0).abs() < 0.0001)
+        }
+
+        fn clone_qtransform(t: &QTransform) -> QTransform;
+        fn multiply_qtransform(a: &QTransform, b: &QTransform) -> QTransform;
+    }
+}
+
+fn construct_qtransform() -> QTransform {
+    let mut transform = QTransform::from_translate(10.0, 20.0);
+    transform.scale(2.0, 3.0);
+    transform
+}
+
+fn read_qtransform(t: &QTransform) -> bool {
+    let inverted = t.inverted().unwrap();
+    t.map(&QPointF::new(1.0, 1.0)) == QPointF::new(12.0, 23.0)
+        && inverted.map(&QPointF::new(12.0, 23.0)) == QPointF::new(1.0, 1.0)
+}
+
+fn clone_qtransform(t: &QTransform) -> QTransform {
+    t.clone()
+}
+
+fn multiply_qtransform(a: &QTransform, b: &QTransform) -> QTransform {
+    a.clone() * b.clone()
+}
@@ -0,0 +1,58 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QString, QUrl, QUrlComponentFormattingOption, QUrlQuery};
+
+#[cxx::bridge]
+mod qurlquery_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qurl.h");
+        include!("cxx-qt-lib/qurlquery.h");
+
+        type QUrl = cxx_qt_lib::QUrl;
+        type QUrlQuery = cxx_qt_lib::QUrlQuery;
+    }
+
+    extern "Rust" {
+        fn construct_qurlquery() -> QUrlQuery;
+        fn read_qurlquery(q: &QUrlQuery) -> bool;
+        fn clone_qurlquery(q: &QUrlQuery) -> QUrlQuery;
+        fn set_qurl_query(u: &QUrl) -> QUrl;
+    }
+}
+
+fn construct_qurlquery() -> QUrlQuery {
+    let mut q = QUrlQuery::default();
+    q.add_query_item(&QString::from("a"), &QString::from("1"));
+    q.add_query_item(&QString::from("b"), &QString::from("2"));
+    q.add_query_item(&QString::from("a"), &QString::from("3"));
+    q.remove_query_item(&QString::from("b"));
+    q
+}
+
+fn read_qurlquery(q: &QUrlQuery) -> bool {
+    let options = QUrlComponentFormattingOption::FullyDecoded;
+    q.has_query_item(&QString::from("key"))
+        && q.query_item_value(&QString::from("key"), options) == QString::from("value with space")
+        && q.query_items(options)
+            == vec![
+                (QString::from("key"), QString::from("value with space")),
+                (QString::from("other"), QString::from("x")),
+            ]
+}
+
+fn clone_qurlquery(q: &QUrlQuery) -> QUrlQuery {
+    q.clone()
+}
+
+fn set_qurl_query(u: &QUrl) -> QUrl {
+    let mut q = u.url_query();
+    q.remove_all_query_items(&QString::from("page"));
+    q.add_query_item(&QString::from("page"), &QString::from("2"));
+
+    let mut u = u.clone();
+    u.set_url_query(&q);
+    u
+}
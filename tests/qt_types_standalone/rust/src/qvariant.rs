@@ -4,8 +4,8 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use cxx_qt_lib::{
-    QByteArray, QColor, QDate, QDateTime, QPoint, QPointF, QRect, QRectF, QSize, QSizeF, QString,
-    QTime, QTimeZone, QUrl, QVariant,
+    QByteArray, QColor, QDate, QDateTime, QMargins, QMarginsF, QPoint, QPointF, QRect, QRectF,
+    QSize, QSizeF, QString, QTime, QTimeZone, QUrl, QVariant,
 };
 
 #[cxx::bridge]
@@ -21,6 +21,8 @@ mod qvariant_cxx {
         QColor,
         QDate,
         QDateTime,
+        QMargins,
+        QMarginsF,
         QPoint,
         QPointF,
         QRect,
@@ -66,6 +68,8 @@ fn construct_qvariant(test: VariantTest) -> QVariant {
             &QTime::new(1, 2, 3, 4),
             &QTimeZone::from_offset_seconds(0),
         )),
+        VariantTest::QMargins => QVariant::from(&QMargins::new(1, 2, 3, 4)),
+        VariantTest::QMarginsF => QVariant::from(&QMarginsF::new(1.5, 2.5, 3.5, 4.5)),
         VariantTest::QPoint => QVariant::from(&QPoint::new(1, 3)),
         VariantTest::QPointF => QVariant::from(&QPointF::new(1.0, 3.0)),
         VariantTest::QRect => QVariant::from(&QRect::new(123, 456, 246, 912)),
@@ -138,6 +142,14 @@ fn read_qvariant(v: &cxx_qt_lib::QVariant, test: VariantTest) -> bool {
             }
             None => false,
         },
+        VariantTest::QMargins => match v.value::<QMargins>() {
+            Some(margins) => margins == QMargins::new(8, 9, 10, 11),
+            None => false,
+        },
+        VariantTest::QMarginsF => match v.value::<QMarginsF>() {
+            Some(margins) => margins == QMarginsF::new(8.5, 9.5, 10.5, 11.5),
+            None => false,
+        },
         VariantTest::QPoint => match v.value::<QPoint>() {
             Some(point) => point.x() == 8 && point.y() == 9,
             None => false,
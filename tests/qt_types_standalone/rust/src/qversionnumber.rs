@@ -0,0 +1,45 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// SPDX-FileContributor: agent <agent@local>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use cxx_qt_lib::{QString, QVersionNumber};
+
+#[cxx::bridge]
+mod qversionnumber_cxx {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qversionnumber.h");
+
+        type QVersionNumber = cxx_qt_lib::QVersionNumber;
+    }
+
+    extern "Rust" {
+        fn construct_qversionnumber() -> QVersionNumber;
+        fn parse_qversionnumber() -> QVersionNumber;
+        fn read_qversionnumber(v: &QVersionNumber) -> bool;
+        fn clone_qversionnumber(v: &QVersionNumber) -> QVersionNumber;
+        fn less_than_qversionnumber(a: &QVersionNumber, b: &QVersionNumber) -> bool;
+    }
+}
+
+fn construct_qversionnumber() -> QVersionNumber {
+    QVersionNumber::new(5, 15, 2)
+}
+
+fn parse_qversionnumber() -> QVersionNumber {
+    QVersionNumber::from_qstring(&QString::from("6.2.4-lts"))
+}
+
+fn read_qversionnumber(v: &QVersionNumber) -> bool {
+    v.segments() == vec![1, 2, 0]
+        && v.segment_at(3).is_none()
+        && v.normalized() == QVersionNumber::from([1, 2].as_slice())
+}
+
+fn clone_qversionnumber(v: &QVersionNumber) -> QVersionNumber {
+    v.clone()
+}
+
+fn less_than_qversionnumber(a: &QVersionNumber, b: &QVersionNumber) -> bool {
+    a < b
+}